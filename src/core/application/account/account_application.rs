@@ -0,0 +1,57 @@
+use std::str::FromStr;
+use std::sync::Arc;
+use color_eyre::{Report, Result};
+use solana_sdk::pubkey::Pubkey;
+use crate::core::domain::DomainLayer;
+use crate::core::domain::account::entity::account_inspection_entity::{AccountInspection, DecodedAccountData};
+use crate::core::infrastructure::InfrastructureLayer;
+
+/// # Description
+///     账户查询应用服务，读取链上账户并按所有者程序解码
+/// # Fields
+///     domain_layer: Arc<DomainLayer> - 领域层
+///     infrastructure_layer: Arc<InfrastructureLayer> - 基础设施层
+pub struct AccountApplication {
+    domain_layer: Arc<DomainLayer>,
+    infrastructure_layer: Arc<InfrastructureLayer>,
+}
+
+impl AccountApplication {
+    /// # Description
+    ///     创建新的账户查询应用服务实例
+    /// # Params
+    ///     infrastructure_layer: Arc<InfrastructureLayer> - 基础设施层的引用，用于获取 RPC
+    ///     domain_layer: Arc<DomainLayer> - 领域层的引用，用于获取账户查询与指令解码领域服务
+    /// # Return
+    ///     Self: 返回一个新的 `AccountApplication` 实例
+    pub fn new(infrastructure_layer: Arc<InfrastructureLayer>, domain_layer: Arc<DomainLayer>) -> Self {
+        Self { domain_layer, infrastructure_layer }
+    }
+
+    /// # Description
+    ///     查询账户：所有者、余额、可执行标记、免租状态及解码后的数据。
+    ///     内置程序无法识别时，按所有者程序已加载的 Anchor IDL 解码
+    /// # Params
+    ///     address: &str - 账户地址
+    /// # Return
+    ///     Result<AccountInspection, Report>: 账户查询结果
+    pub async fn inspect(&self, address: &str) -> Result<AccountInspection, Report> {
+        let pubkey = Pubkey::from_str(address).map_err(|_| Report::msg("账户地址不合法"))?;
+        let client = &self.infrastructure_layer.rpc.client;
+        let account = client.get_account_with_commitment(&pubkey, client.commitment()).await
+            .map_err(|e| Report::msg(format!("获取账户失败: {}", e)))?
+            .value
+            .ok_or_else(|| Report::msg("账户不存在"))?;
+
+        let owner = account.owner.to_string();
+        let instruction_service = &self.domain_layer.instruction_domain.domain_service;
+        let anchor = instruction_service.decode_anchor_account(&owner, &account.data)
+            .map(|decoded| DecodedAccountData::Anchor {
+                program: instruction_service.find_idl(&owner).map(|idl| idl.program_name()).unwrap_or_default(),
+                account_type: decoded.account_type,
+                data: decoded.data,
+            });
+
+        Ok(self.domain_layer.account_domain.domain_service.inspect(&pubkey, &account, anchor))
+    }
+}
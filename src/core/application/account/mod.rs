@@ -0,0 +1,3 @@
+mod account_application;
+
+pub use account_application::AccountApplication;
@@ -0,0 +1,362 @@
+use std::sync::Arc;
+use chrono::{DateTime, Utc};
+use color_eyre::{Report, Result};
+use serde::Serialize;
+use serde_json::{json, Value};
+use tracing::info;
+use crate::core::application::rbac::{RbacApplication, UserRoles};
+use crate::core::application::user::UserApplication;
+use crate::core::domain::DomainLayer;
+use crate::core::domain::user::entity::admin_audit_entity::{ActiveModel as AdminAuditActiveModel, AdminAction, Model as AdminAuditModel};
+use crate::core::domain::user::entity::user_entity::Model as UserModel;
+use crate::core::domain::user::repository_interface::{AdminAuditRepositoryInterface, UserFilter, UserRepositoryInterface};
+use crate::core::domain::wallet::entity::Model as WalletModel;
+use crate::core::domain::wallet::repository::WalletRepositoryInterface;
+use crate::core::infrastructure::InfrastructureLayer;
+
+/// # Description
+///     管理后台展示的用户，不包含密码
+/// # Fields
+///     id: i32 - 用户id
+///     account: String - 账号
+///     nickname: Option<String> - 昵称
+///     avatar: Option<String> - 头像地址
+///     mobile: Option<String> - 手机号
+///     email: Option<String> - 邮箱
+///     disable: bool - 是否已禁用
+///     level: i8 - 用户级别
+///     reg_type: i8 - 注册类型
+///     created_at: DateTime<Utc> - 注册时间
+///     updated_at: DateTime<Utc> - 更新时间
+///     deleted_at: Option<DateTime<Utc>> - 注销时间，未注销为空
+#[derive(Debug, Serialize)]
+pub struct AdminUserView {
+    pub id: i32,
+    pub account: String,
+    pub nickname: Option<String>,
+    pub avatar: Option<String>,
+    pub mobile: Option<String>,
+    pub email: Option<String>,
+    pub disable: bool,
+    pub level: i8,
+    pub reg_type: i8,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub deleted_at: Option<DateTime<Utc>>,
+}
+
+impl From<UserModel> for AdminUserView {
+    fn from(model: UserModel) -> Self {
+        Self {
+            id: model.id,
+            account: model.account,
+            nickname: model.nickname,
+            avatar: model.avatar,
+            mobile: model.mobile.filter(|mobile| !mobile.is_empty()),
+            email: model.email.filter(|email| !email.is_empty()),
+            disable: model.disable,
+            level: model.level,
+            reg_type: model.reg_type,
+            created_at: model.created_at,
+            updated_at: model.updated_at,
+            deleted_at: model.deleted_at,
+        }
+    }
+}
+
+/// # Description
+///     用户分页结果
+/// # Fields
+///     page: u64 - 页码，从 1 开始
+///     page_size: u64 - 每页数量
+///     total: u64 - 符合条件的用户总数
+///     items: Vec<AdminUserView> - 当前页的用户
+#[derive(Debug, Serialize)]
+pub struct AdminUserPage {
+    pub page: u64,
+    pub page_size: u64,
+    pub total: u64,
+    pub items: Vec<AdminUserView>,
+}
+
+/// # Description
+///     管理后台展示的钱包，不包含私钥
+/// # Fields
+///     id: i32 - 钱包id
+///     pub_key: Option<String> - 钱包地址
+///     balance: f64 - 余额（SOL）
+///     disable: bool - 是否已禁用
+///     created_at: DateTime<Utc> - 创建时间
+#[derive(Debug, Serialize)]
+pub struct AdminWalletView {
+    pub id: i32,
+    pub pub_key: Option<String>,
+    pub balance: f64,
+    pub disable: bool,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<WalletModel> for AdminWalletView {
+    fn from(model: WalletModel) -> Self {
+        Self {
+            id: model.id,
+            pub_key: model.pub_key,
+            balance: model.balance,
+            disable: model.disable,
+            created_at: model.created_at,
+        }
+    }
+}
+
+/// # Description
+///     用户的钱包与余额
+/// # Fields
+///     user: AdminUserView - 用户
+///     total_balance: f64 - 全部钱包的余额合计（SOL）
+///     wallets: Vec<AdminWalletView> - 未删除的钱包
+#[derive(Debug, Serialize)]
+pub struct AdminUserWallets {
+    pub user: AdminUserView,
+    pub total_balance: f64,
+    pub wallets: Vec<AdminWalletView>,
+}
+
+/// # Description
+///     强制重置密码结果
+/// # Fields
+///     user: AdminUserView - 用户
+///     reset_link_sent: bool - 是否已向用户邮箱发送重置密码链接
+#[derive(Debug, Serialize)]
+pub struct AdminPasswordReset {
+    pub user: AdminUserView,
+    pub reset_link_sent: bool,
+}
+
+/// # Description
+///     审计记录分页结果
+/// # Fields
+///     page: u64 - 页码，从 1 开始
+///     page_size: u64 - 每页数量
+///     total: u64 - 审计记录总数
+///     items: Vec<AdminAuditModel> - 当前页的审计记录
+#[derive(Debug, Serialize)]
+pub struct AdminAuditPage {
+    pub page: u64,
+    pub page_size: u64,
+    pub total: u64,
+    pub items: Vec<AdminAuditModel>,
+}
+
+/// # Description
+///     用户管理后台应用服务：查询用户，并代管理员执行启用禁用、级别角色调整、
+///     强制重置密码与恢复注销账号，每次操作写入审计记录
+/// # Fields
+///     domain_layer: Arc<DomainLayer> - 领域层
+///     infrastructure_layer: Arc<InfrastructureLayer> - 基础设施层
+///     user_application: Arc<UserApplication> - 用户应用，负责修改用户状态与下线会话
+///     rbac_application: Arc<RbacApplication> - 角色权限应用，负责分配角色
+pub struct AdminApplication {
+    domain_layer: Arc<DomainLayer>,
+    infrastructure_layer: Arc<InfrastructureLayer>,
+    user_application: Arc<UserApplication>,
+    rbac_application: Arc<RbacApplication>,
+}
+
+impl AdminApplication {
+    /// # Description
+    ///     创建新的用户管理后台应用服务实例
+    /// # Params
+    ///     infrastructure_layer: Arc<InfrastructureLayer> - 基础设施层的引用，用于获取仓储与配置
+    ///     domain_layer: Arc<DomainLayer> - 领域层的引用，用于获取用户领域服务
+    ///     user_application: Arc<UserApplication> - 用户应用
+    ///     rbac_application: Arc<RbacApplication> - 角色权限应用
+    /// # Return
+    ///     Self: 返回一个新的 `AdminApplication` 实例
+    pub fn new(
+        infrastructure_layer: Arc<InfrastructureLayer>,
+        domain_layer: Arc<DomainLayer>,
+        user_application: Arc<UserApplication>,
+        rbac_application: Arc<RbacApplication>,
+    ) -> Self {
+        Self { domain_layer, infrastructure_layer, user_application, rbac_application }
+    }
+
+    /// # Description
+    ///     按条件分页查询用户，最新注册的在前
+    /// # Params
+    ///     filter: UserFilter - 过滤条件
+    ///     page: Option<u64> - 页码，从 1 开始
+    ///     page_size: Option<u64> - 每页数量
+    /// # Return
+    ///     Result<AdminUserPage, Report>: 用户分页结果
+    pub async fn list_users(&self, filter: UserFilter, page: Option<u64>, page_size: Option<u64>) -> Result<AdminUserPage, Report> {
+        let (page, page_size, offset) = self.paging(page, page_size)?;
+        let filter = self.domain_layer.user_domain.domain_service.normalize_user_filter(filter)?;
+        let repository = &self.infrastructure_layer.persistence.repository.user_repository;
+
+        let total = repository.count(&filter).await?;
+        let items = repository.search(&filter, offset, page_size).await?
+            .into_iter()
+            .map(AdminUserView::from)
+            .collect();
+
+        Ok(AdminUserPage { page, page_size, total, items })
+    }
+
+    /// # Description
+    ///     启用或禁用用户，禁用后该用户的所有会话下线；管理员不能禁用自己
+    /// # Params
+    ///     actor_id: i32 - 执行操作的管理员
+    ///     user_id: i32 - 用户id
+    ///     disabled: bool - true 禁用，false 启用
+    /// # Return
+    ///     Result<AdminUserView, Report>: 操作后的用户
+    pub async fn set_disabled(&self, actor_id: i32, user_id: i32, disabled: bool) -> Result<AdminUserView, Report> {
+        if disabled && actor_id == user_id {
+            return Err(Report::msg("不能禁用自己的账号"));
+        }
+        let action = if disabled { AdminAction::DisableUser } else { AdminAction::EnableUser };
+        let audit = self.audit_entry(actor_id, action, user_id, None);
+        let user = if disabled {
+            self.user_application.disable_user(user_id, audit).await?
+        } else {
+            self.user_application.enable_user(user_id, audit).await?
+        };
+        self.audited(actor_id, action, user_id);
+
+        Ok(user.into())
+    }
+
+    /// # Description
+    ///     调整用户级别
+    /// # Params
+    ///     actor_id: i32 - 执行操作的管理员
+    ///     user_id: i32 - 用户id
+    ///     level: i8 - 新的用户级别
+    /// # Return
+    ///     Result<AdminUserView, Report>: 操作后的用户
+    pub async fn change_level(&self, actor_id: i32, user_id: i32, level: i8) -> Result<AdminUserView, Report> {
+        let audit = self.audit_entry(actor_id, AdminAction::ChangeLevel, user_id, Some(json!({ "level": level })));
+        let user = self.user_application.change_level(user_id, level, audit).await?;
+        self.audited(actor_id, AdminAction::ChangeLevel, user_id);
+
+        Ok(user.into())
+    }
+
+    /// # Description
+    ///     设置用户的角色，整体替换
+    /// # Params
+    ///     actor_id: i32 - 执行操作的管理员
+    ///     user_id: i32 - 用户id
+    ///     roles: Vec<String> - 角色名
+    /// # Return
+    ///     Result<UserRoles, Report>: 设置后的角色与权限
+    pub async fn assign_roles(&self, actor_id: i32, user_id: i32, roles: Vec<String>) -> Result<UserRoles, Report> {
+        let audit = self.audit_entry(actor_id, AdminAction::AssignRoles, user_id, Some(json!({ "roles": roles })));
+        let user_roles = self.rbac_application.assign_roles(user_id, roles, Some(audit)).await?;
+        self.audited(actor_id, AdminAction::AssignRoles, user_id);
+
+        Ok(user_roles)
+    }
+
+    /// # Description
+    ///     强制重置用户密码，旧密码立即失效且所有会话下线，绑定了邮箱时发送重置密码链接
+    /// # Params
+    ///     actor_id: i32 - 执行操作的管理员
+    ///     user_id: i32 - 用户id
+    /// # Return
+    ///     Result<AdminPasswordReset, Report>: 重置结果
+    pub async fn force_password_reset(&self, actor_id: i32, user_id: i32) -> Result<AdminPasswordReset, Report> {
+        let audit = self.audit_entry(actor_id, AdminAction::ForcePasswordReset, user_id, None);
+        let (user, reset_link_sent) = self.user_application.force_password_reset(user_id, audit).await?;
+        self.audited(actor_id, AdminAction::ForcePasswordReset, user_id);
+
+        Ok(AdminPasswordReset { user: user.into(), reset_link_sent })
+    }
+
+    /// # Description
+    ///     查看用户的钱包与余额，不返回私钥
+    /// # Params
+    ///     actor_id: i32 - 执行操作的管理员
+    ///     user_id: i32 - 用户id
+    /// # Return
+    ///     Result<AdminUserWallets, Report>: 用户的钱包与余额
+    pub async fn user_wallets(&self, actor_id: i32, user_id: i32) -> Result<AdminUserWallets, Report> {
+        let repository = &self.infrastructure_layer.persistence.repository;
+        let user = repository.user_repository.find_by_id(user_id).await?
+            .ok_or_else(|| Report::msg("用户不存在"))?;
+
+        let wallets: Vec<AdminWalletView> = repository.wallet_repository.find_by_user(user_id).await?
+            .into_iter()
+            .map(AdminWalletView::from)
+            .collect();
+        let total_balance: f64 = wallets.iter().map(|wallet| wallet.balance).sum();
+
+        // 查看操作没有数据变更，审计记录写入失败时不返回钱包信息
+        repository.admin_audit_repository.save(self.audit_entry(actor_id, AdminAction::ViewWallets, user_id, None)).await
+            .map_err(|e| Report::msg(format!("写入审计记录失败: {}", e)))?;
+        self.audited(actor_id, AdminAction::ViewWallets, user_id);
+
+        Ok(AdminUserWallets { user: user.into(), total_balance, wallets })
+    }
+
+    /// # Description
+    ///     恢复已注销（软删除）的用户
+    /// # Params
+    ///     actor_id: i32 - 执行操作的管理员
+    ///     user_id: i32 - 用户id
+    /// # Return
+    ///     Result<AdminUserView, Report>: 恢复后的用户
+    pub async fn restore_user(&self, actor_id: i32, user_id: i32) -> Result<AdminUserView, Report> {
+        let audit = self.audit_entry(actor_id, AdminAction::RestoreUser, user_id, None);
+        let user = self.user_application.restore_user(user_id, audit).await?;
+        self.audited(actor_id, AdminAction::RestoreUser, user_id);
+
+        Ok(user.into())
+    }
+
+    /// # Description
+    ///     分页查询针对某个用户的管理员操作审计记录，最新的在前
+    /// # Params
+    ///     user_id: i32 - 被操作的用户id
+    ///     page: Option<u64> - 页码，从 1 开始
+    ///     page_size: Option<u64> - 每页数量
+    /// # Return
+    ///     Result<AdminAuditPage, Report>: 审计记录分页结果
+    pub async fn audit_log(&self, user_id: i32, page: Option<u64>, page_size: Option<u64>) -> Result<AdminAuditPage, Report> {
+        let (page, page_size, offset) = self.paging(page, page_size)?;
+        let repository = &self.infrastructure_layer.persistence.repository.admin_audit_repository;
+
+        let total = repository.count_by_target(user_id).await?;
+        let items = repository.find_by_target(user_id, offset, page_size).await?;
+
+        Ok(AdminAuditPage { page, page_size, total, items })
+    }
+
+    /// # Description
+    ///     校验分页参数，缺省时使用第 1 页与默认每页数量，返回页码、每页数量与偏移量
+    fn paging(&self, page: Option<u64>, page_size: Option<u64>) -> Result<(u64, u64, u64), Report> {
+        let config = &self.infrastructure_layer.config.admin;
+        let page = page.unwrap_or(1);
+        let page_size = page_size.unwrap_or(config.default_page_size);
+        if page == 0 || page_size == 0 || page_size > config.max_page_size {
+            return Err(Report::msg(format!("页码从 1 开始，每页数量为 1 到 {}", config.max_page_size)));
+        }
+
+        let offset = (page - 1).checked_mul(page_size).ok_or_else(|| Report::msg("页码过大"))?;
+
+        Ok((page, page_size, offset))
+    }
+
+    /// # Description
+    ///     创建审计记录，交给仓储与变更在同一事务写入，写入失败时操作不生效
+    fn audit_entry(&self, actor_id: i32, action: AdminAction, target_user_id: i32, detail: Option<Value>) -> AdminAuditActiveModel {
+        AdminAuditModel::new(actor_id, action, target_user_id, detail.map(|detail| detail.to_string()))
+    }
+
+    /// # Description
+    ///     记录已审计的操作日志
+    fn audited(&self, actor_id: i32, action: AdminAction, target_user_id: i32) {
+        info!("+ApplicationLayer [Admin] {} on user {} by user {}.", action.as_str(), target_user_id, actor_id);
+    }
+}
@@ -0,0 +1,3 @@
+mod admin_application;
+
+pub use admin_application::{AdminApplication, AdminAuditPage, AdminPasswordReset, AdminUserPage, AdminUserView, AdminUserWallets, AdminWalletView};
\ No newline at end of file
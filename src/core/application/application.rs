@@ -1,6 +1,27 @@
 use std::sync::Arc;
 use crate::core::application::user::UserApplication;
 use crate::core::application::wallet::WalletApplication;
+use crate::core::application::price::PriceApplication;
+use crate::core::application::order::OrderApplication;
+use crate::core::application::dca::DcaApplication;
+use crate::core::application::market::MarketApplication;
+use crate::core::application::indexer::IndexerApplication;
+use crate::core::application::network::NetworkApplication;
+use crate::core::application::history::HistoryApplication;
+use crate::core::application::instruction::InstructionApplication;
+use crate::core::application::account::AccountApplication;
+use crate::core::application::webhook::WebhookApplication;
+use crate::core::application::watcher::PubsubApplication;
+use crate::core::application::stream::StreamApplication;
+use crate::core::application::reorg::ReorgApplication;
+use crate::core::application::rbac::RbacApplication;
+use crate::core::application::verification::VerificationApplication;
+use crate::core::application::mail::MailApplication;
+use crate::core::application::sms::SmsApplication;
+use crate::core::application::two_factor::TwoFactorApplication;
+use crate::core::application::withdrawal::WithdrawalApplication;
+use crate::core::application::throttle::LoginThrottleApplication;
+use crate::core::application::admin::AdminApplication;
 use crate::core::domain::DomainLayer;
 use crate::core::infrastructure::InfrastructureLayer;
 
@@ -9,10 +30,52 @@ use crate::core::infrastructure::InfrastructureLayer;
 /// # Fields
 ///     user_application: Arc<UserApplication> - 用户应用
 ///     wallet_application: Arc<WalletApplication>, - 用户应用
+///     price_application: Arc<PriceApplication>, - 价格应用
+///     order_application: Arc<OrderApplication>, - 条件单应用
+///     dca_application: Arc<DcaApplication>, - 定投应用
+///     market_application: Arc<MarketApplication>, - OpenBook 行情应用
+///     indexer_application: Arc<IndexerApplication>, - 区块索引应用
+///     network_application: Arc<NetworkApplication>, - 网络统计应用
+///     history_application: Arc<HistoryApplication>, - 钱包交易历史应用
+///     instruction_application: Arc<InstructionApplication>, - 指令解码应用
+///     account_application: Arc<AccountApplication>, - 账户查询应用
+///     webhook_application: Arc<WebhookApplication>, - Webhook 通知应用
+///     pubsub_application: Arc<PubsubApplication>, - 链上订阅应用（充值监听与确认跟踪）
+///     stream_application: Arc<StreamApplication>, - 实时推送应用
+///     reorg_application: Arc<ReorgApplication>, - 最终确认与分叉回滚应用
+///     rbac_application: Arc<RbacApplication>, - 角色权限应用
+///     verification_application: Arc<VerificationApplication>, - 验证码应用（签发、校验与消费一次性验证码）
+///     mail_application: Arc<MailApplication>, - 邮件应用（邮箱验证码与重置密码）
+///     sms_application: Arc<SmsApplication>, - 短信应用（手机号验证码）
+///     two_factor_application: Arc<TwoFactorApplication>, - 两步验证应用（TOTP、恢复码与敏感操作再次验证）
+///     withdrawal_application: Arc<WithdrawalApplication>, - 提现应用（提现白名单与提现）
+///     login_throttle_application: Arc<LoginThrottleApplication>, - 登录限流应用（失败计数、渐进延迟与锁定）
+///     admin_application: Arc<AdminApplication>, - 用户管理后台应用（用户查询、状态调整与审计记录）
 pub struct ApplicationLayer {
     // pub jwt_service: Arc<JwtService>,
     pub user_application: Arc<UserApplication>,
     pub wallet_application: Arc<WalletApplication>,
+    pub price_application: Arc<PriceApplication>,
+    pub order_application: Arc<OrderApplication>,
+    pub dca_application: Arc<DcaApplication>,
+    pub market_application: Arc<MarketApplication>,
+    pub indexer_application: Arc<IndexerApplication>,
+    pub network_application: Arc<NetworkApplication>,
+    pub history_application: Arc<HistoryApplication>,
+    pub instruction_application: Arc<InstructionApplication>,
+    pub account_application: Arc<AccountApplication>,
+    pub webhook_application: Arc<WebhookApplication>,
+    pub pubsub_application: Arc<PubsubApplication>,
+    pub stream_application: Arc<StreamApplication>,
+    pub reorg_application: Arc<ReorgApplication>,
+    pub rbac_application: Arc<RbacApplication>,
+    pub verification_application: Arc<VerificationApplication>,
+    pub mail_application: Arc<MailApplication>,
+    pub sms_application: Arc<SmsApplication>,
+    pub two_factor_application: Arc<TwoFactorApplication>,
+    pub withdrawal_application: Arc<WithdrawalApplication>,
+    pub login_throttle_application: Arc<LoginThrottleApplication>,
+    pub admin_application: Arc<AdminApplication>,
 }
 
 impl ApplicationLayer {
@@ -26,18 +89,111 @@ impl ApplicationLayer {
         // 初始化 jwt 服务应用
         // let jwt_service = Arc::new(JwtService::new(infrastructure_layer.jwt.clone()));
 
+        // 初始化 verification 应用
+        let verification_application = Arc::new(VerificationApplication::new(infrastructure_layer.clone(), domain_layer.clone()));
+
+        // 初始化 mail 应用
+        let mail_application = Arc::new(MailApplication::new(infrastructure_layer.clone(), domain_layer.clone(), verification_application.clone()));
+
+        // 初始化 sms 应用
+        let sms_application = Arc::new(SmsApplication::new(infrastructure_layer.clone(), domain_layer.clone(), verification_application.clone()));
+
+        // 初始化 two_factor 应用
+        let two_factor_application = Arc::new(TwoFactorApplication::new(infrastructure_layer.clone(), domain_layer.clone(), verification_application.clone()));
+
+        // 初始化 login_throttle 应用
+        let login_throttle_application = Arc::new(LoginThrottleApplication::new(infrastructure_layer.clone(), domain_layer.clone()));
+
         // 初始化 user 应用
-        let user_application = Arc::new(UserApplication::new(infrastructure_layer.clone(), domain_layer.clone()));
+        let user_application = Arc::new(UserApplication::new(infrastructure_layer.clone(), domain_layer.clone(), mail_application.clone(), sms_application.clone(), two_factor_application.clone(), login_throttle_application.clone()));
+
+        // 初始化 rbac 应用，并写入内置角色与权限
+        let rbac_application = Arc::new(RbacApplication::new(infrastructure_layer.clone(), domain_layer.clone()));
+        rbac_application.spawn_bootstrap();
+
+        // 初始化 admin 应用
+        let admin_application = Arc::new(AdminApplication::new(infrastructure_layer.clone(), domain_layer.clone(), user_application.clone(), rbac_application.clone()));
+
+        // 初始化 stream 应用
+        let stream_application = Arc::new(StreamApplication::new(infrastructure_layer.clone(), domain_layer.clone()));
+
+        // 初始化 webhook 应用，并启动投递任务
+        let webhook_application = Arc::new(WebhookApplication::new(infrastructure_layer.clone(), domain_layer.clone(), stream_application.clone()));
+        webhook_application.spawn_dispatcher();
+
+        // 初始化 wallet 应用，并加密仍以明文保存的历史钱包私钥
+        let wallet_application = Arc::new(WalletApplication::new(infrastructure_layer.clone(), domain_layer.clone(), webhook_application.clone()));
+        wallet_application.spawn_key_sealer();
+
+        // 初始化 pubsub 应用，并启动链上订阅与轮询任务
+        let pubsub_application = Arc::new(PubsubApplication::new(infrastructure_layer.clone(), domain_layer.clone(), wallet_application.clone(), webhook_application.clone(), stream_application.clone()));
+        pubsub_application.spawn_pubsub();
+
+        // 初始化 withdrawal 应用
+        let withdrawal_application = Arc::new(WithdrawalApplication::new(infrastructure_layer.clone(), domain_layer.clone(), webhook_application.clone(), pubsub_application.clone()));
+
+        // 初始化 price 应用，并启动价格推送任务
+        let price_application = Arc::new(PriceApplication::new(infrastructure_layer.clone(), domain_layer.clone()));
+        stream_application.spawn_price_ticker(price_application.clone());
+
+        // 初始化 order 应用，并启动条件单评估任务
+        let order_application = Arc::new(OrderApplication::new(infrastructure_layer.clone(), domain_layer.clone(), price_application.clone(), webhook_application.clone()));
+        order_application.spawn_evaluator();
+
+        // 初始化 dca 应用，并启动定投任务
+        let dca_application = Arc::new(DcaApplication::new(infrastructure_layer.clone(), domain_layer.clone(), webhook_application.clone(), pubsub_application.clone(), price_application.clone()));
+        dca_application.spawn_runner();
+
+        // 初始化 market 应用
+        let market_application = Arc::new(MarketApplication::new(infrastructure_layer.clone(), domain_layer.clone()));
+
+        // 初始化 indexer 应用，并启动区块索引任务
+        let indexer_application = Arc::new(IndexerApplication::new(infrastructure_layer.clone(), domain_layer.clone()));
+        indexer_application.spawn_indexer();
+
+        // 初始化 reorg 应用，并启动最终确认核对任务
+        let reorg_application = Arc::new(ReorgApplication::new(infrastructure_layer.clone(), domain_layer.clone(), indexer_application.clone(), stream_application.clone()));
+        reorg_application.spawn_finalizer();
+
+        // 初始化 network 应用，并启动网络吞吐采样任务
+        let network_application = Arc::new(NetworkApplication::new(infrastructure_layer.clone(), domain_layer.clone(), indexer_application.clone()));
+        network_application.spawn_sampler();
 
-        // 初始化 wallet 应用
-        let wallet_application = Arc::new(WalletApplication::new(infrastructure_layer.clone(), domain_layer.clone()));
+        // 初始化 history 应用
+        let history_application = Arc::new(HistoryApplication::new(infrastructure_layer.clone(), domain_layer.clone()));
 
+        // 初始化 instruction 应用，并加载 Anchor IDL
+        let instruction_application = Arc::new(InstructionApplication::new(infrastructure_layer.clone(), domain_layer.clone()));
+        instruction_application.spawn_idl_loader();
 
+        // 初始化 account 应用
+        let account_application = Arc::new(AccountApplication::new(infrastructure_layer.clone(), domain_layer.clone()));
 
         Self {
             // jwt_service,
             user_application,
-            wallet_application
+            wallet_application,
+            price_application,
+            order_application,
+            dca_application,
+            market_application,
+            indexer_application,
+            network_application,
+            history_application,
+            instruction_application,
+            account_application,
+            webhook_application,
+            pubsub_application,
+            stream_application,
+            reorg_application,
+            rbac_application,
+            verification_application,
+            mail_application,
+            sms_application,
+            two_factor_application,
+            withdrawal_application,
+            login_throttle_application,
+            admin_application,
         }
     }
 }
@@ -0,0 +1,387 @@
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Duration;
+use chrono::{DateTime, Utc};
+use color_eyre::{Report, Result};
+use serde::Serialize;
+use serde_json::json;
+use solana_program::pubkey::Pubkey;
+use solana_sdk::signature::Signer;
+use tokio::time::MissedTickBehavior;
+use tracing::{error, info, warn};
+use crate::core::application::price::PriceApplication;
+use crate::core::application::wallet::WalletApplication;
+use crate::core::application::webhook::WebhookApplication;
+use crate::core::application::watcher::PubsubApplication;
+use crate::core::domain::DomainLayer;
+use crate::core::domain::dca::entity::dca_schedule_entity::{Model as DcaScheduleModel, DcaKind, DcaStatus};
+use crate::core::domain::dca::entity::dca_execution_entity::Model as DcaExecutionModel;
+use crate::core::domain::dca::repository::DcaRepositoryInterface;
+use crate::core::domain::dca::service::DcaPlan;
+use crate::core::domain::wallet::repository::WalletRepositoryInterface;
+use crate::core::domain::watcher::entity::tracked_signature_entity::TrackedSignature;
+use crate::core::domain::webhook::entity::webhook_event_entity::WebhookEventType;
+use crate::core::infrastructure::InfrastructureLayer;
+
+/// # Description
+///     定投计划详情，包含最近的执行记录
+/// # Fields
+///     schedule: DcaScheduleModel - 定投计划
+///     executions: Vec<DcaExecutionModel> - 最近的执行记录
+#[derive(Debug, Serialize)]
+pub struct DcaDetail {
+    pub schedule: DcaScheduleModel,
+    pub executions: Vec<DcaExecutionModel>,
+}
+
+/// # Description
+///     定投应用服务，负责计划管理以及后台按周期执行兑换与转账
+/// # Fields
+///     domain_layer: Arc<DomainLayer> - 领域层
+///     infrastructure_layer: Arc<InfrastructureLayer> - 基础设施层
+///     webhook_application: Arc<WebhookApplication> - Webhook 通知应用，推送兑换与转出事件
+///     pubsub_application: Arc<PubsubApplication> - 链上订阅应用，跟踪转出交易直到最终确认
+///     price_application: Arc<PriceApplication> - 价格应用，兑换前校验报价滑点
+pub struct DcaApplication {
+    domain_layer: Arc<DomainLayer>,
+    infrastructure_layer: Arc<InfrastructureLayer>,
+    webhook_application: Arc<WebhookApplication>,
+    pubsub_application: Arc<PubsubApplication>,
+    price_application: Arc<PriceApplication>,
+}
+
+impl DcaApplication {
+    /// # Description
+    ///     创建新的定投应用服务实例
+    /// # Params
+    ///     infrastructure_layer: Arc<InfrastructureLayer> - 基础设施层的引用，用于获取仓储和 RPC
+    ///     domain_layer: Arc<DomainLayer> - 领域层的引用，用于获取定投领域服务
+    ///     webhook_application: Arc<WebhookApplication> - Webhook 通知应用的引用
+    ///     pubsub_application: Arc<PubsubApplication> - 链上订阅应用的引用
+    ///     price_application: Arc<PriceApplication> - 价格应用的引用
+    /// # Return
+    ///     Self: 返回一个新的 `DcaApplication` 实例
+    pub fn new(
+        infrastructure_layer: Arc<InfrastructureLayer>,
+        domain_layer: Arc<DomainLayer>,
+        webhook_application: Arc<WebhookApplication>,
+        pubsub_application: Arc<PubsubApplication>,
+        price_application: Arc<PriceApplication>,
+    ) -> Self {
+        Self { domain_layer, infrastructure_layer, webhook_application, pubsub_application, price_application }
+    }
+
+    /// # Description
+    ///     创建定投计划，校验钱包归属与地址格式
+    /// # Params
+    ///     user_id: i32 - 用户id
+    ///     wallet_id: i32 - 执行计划的钱包id
+    ///     plan: DcaPlan - 计划参数
+    /// # Return
+    ///     Result<DcaScheduleModel, Report>: 成功返回定投计划，失败返回错误信息
+    pub async fn create_schedule(&self, user_id: i32, wallet_id: i32, plan: DcaPlan) -> Result<DcaScheduleModel, Report> {
+        Pubkey::from_str(&plan.input_mint).map_err(|_| Report::msg("输入代币地址不合法"))?;
+        if let Some(output_mint) = &plan.output_mint {
+            Pubkey::from_str(output_mint).map_err(|_| Report::msg("输出代币地址不合法"))?;
+        }
+        if let Some(recipient) = &plan.recipient {
+            Pubkey::from_str(recipient).map_err(|_| Report::msg("接收地址不合法"))?;
+        }
+
+        let repository = &self.infrastructure_layer.persistence.repository;
+        let wallet = repository.wallet_repository.find_by_id(wallet_id as u64).await?
+            .ok_or_else(|| Report::msg("钱包不存在"))?;
+        if wallet.user_id != user_id || wallet.deleted_at.is_some() {
+            return Err(Report::msg("钱包不存在"));
+        }
+        if wallet.disable {
+            return Err(Report::msg("钱包已禁用"));
+        }
+
+        let config = &self.infrastructure_layer.config.job.dca;
+        let new_schedule = self.domain_layer.dca_domain.domain_service.create_schedule(
+            user_id,
+            wallet_id,
+            plan,
+            config.min_interval,
+            config.max_slippage,
+            Utc::now(),
+        )?;
+
+        repository.dca_repository.save(new_schedule).await
+    }
+
+    /// # Description
+    ///     暂停定投计划
+    /// # Params
+    ///     user_id: i32 - 用户id
+    ///     schedule_id: i32 - 计划id
+    /// # Return
+    ///     Result<DcaScheduleModel, Report>: 成功返回更新后的计划，失败返回错误信息
+    pub async fn pause_schedule(&self, user_id: i32, schedule_id: i32) -> Result<DcaScheduleModel, Report> {
+        let mut schedule = self.find_user_schedule(user_id, schedule_id).await?;
+        schedule.pause().map_err(Report::msg)?;
+
+        self.update_schedule(schedule, DcaStatus::Active).await
+    }
+
+    /// # Description
+    ///     恢复定投计划，从当前时间之后的周期开始执行
+    /// # Params
+    ///     user_id: i32 - 用户id
+    ///     schedule_id: i32 - 计划id
+    /// # Return
+    ///     Result<DcaScheduleModel, Report>: 成功返回更新后的计划，失败返回错误信息
+    pub async fn resume_schedule(&self, user_id: i32, schedule_id: i32) -> Result<DcaScheduleModel, Report> {
+        let mut schedule = self.find_user_schedule(user_id, schedule_id).await?;
+        let now = Utc::now();
+        let next_run_at = self.domain_layer.dca_domain.domain_service.next_run(&schedule, now)?;
+        schedule.resume(next_run_at).map_err(Report::msg)?;
+
+        self.update_schedule(schedule, DcaStatus::Paused).await
+    }
+
+    /// # Description
+    ///     取消定投计划
+    /// # Params
+    ///     user_id: i32 - 用户id
+    ///     schedule_id: i32 - 计划id
+    /// # Return
+    ///     Result<DcaScheduleModel, Report>: 成功返回更新后的计划，失败返回错误信息
+    pub async fn cancel_schedule(&self, user_id: i32, schedule_id: i32) -> Result<DcaScheduleModel, Report> {
+        let mut schedule = self.find_user_schedule(user_id, schedule_id).await?;
+        let expected = schedule.status.clone();
+        schedule.cancel().map_err(Report::msg)?;
+
+        self.update_schedule(schedule, expected).await
+    }
+
+    /// # Description
+    ///     查询用户的全部定投计划
+    /// # Params
+    ///     user_id: i32 - 用户id
+    /// # Return
+    ///     Result<Vec<DcaScheduleModel>, Report>: 成功返回计划列表，失败返回错误信息
+    pub async fn list_schedules(&self, user_id: i32) -> Result<Vec<DcaScheduleModel>, Report> {
+        self.infrastructure_layer.persistence.repository.dca_repository.find_by_user(user_id).await
+    }
+
+    /// # Description
+    ///     查询定投计划详情及最近 50 条执行记录
+    /// # Params
+    ///     user_id: i32 - 用户id
+    ///     schedule_id: i32 - 计划id
+    /// # Return
+    ///     Result<DcaDetail, Report>: 成功返回计划详情，失败返回错误信息
+    pub async fn get_schedule(&self, user_id: i32, schedule_id: i32) -> Result<DcaDetail, Report> {
+        let schedule = self.find_user_schedule(user_id, schedule_id).await?;
+        let executions = self.infrastructure_layer.persistence.repository.dca_repository.find_executions(schedule.id, 50).await?;
+
+        Ok(DcaDetail { schedule, executions })
+    }
+
+    /// # Description
+    ///     启动后台定投任务，按配置的间隔执行到期计划；计划状态保存在数据库中，重启后继续执行
+    /// # Params
+    ///     None
+    /// # Return
+    ///     None
+    pub fn spawn_runner(self: &Arc<Self>) {
+        let config = self.infrastructure_layer.config.job.dca.clone();
+        if !config.enabled {
+            info!("+ApplicationLayer [Dca] runner disabled.");
+            return;
+        }
+
+        let application = Arc::clone(self);
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(Duration::from_secs(config.interval.max(1)));
+            ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+            loop {
+                ticker.tick().await;
+                if let Err(e) = application.run_due_schedules(config.batch_size).await {
+                    error!("-ApplicationLayer [Dca] run failed: {}", e);
+                }
+            }
+        });
+        info!("+ApplicationLayer [Dca] runner started.");
+    }
+
+    /// # Description
+    ///     执行一批到期的定投计划
+    /// # Params
+    ///     batch_size: u64 - 本轮最多执行的计划数量
+    /// # Return
+    ///     Result<(), Report>: 成功返回 Ok()，失败返回错误信息
+    pub async fn run_due_schedules(&self, batch_size: u64) -> Result<(), Report> {
+        let now = Utc::now();
+        let schedules = self.infrastructure_layer.persistence.repository.dca_repository.find_due(now, batch_size).await?;
+
+        for schedule in schedules {
+            let schedule_id = schedule.id;
+            if let Err(e) = self.run_schedule(schedule, now).await {
+                error!("-ApplicationLayer [Dca] schedule {} run failed: {}", schedule_id, e);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// # Description
+    ///     执行单个计划：先把下次执行时间推进到下一周期以占用本周期，再执行并写回结果。
+    ///     占用时同时比对本次到期时间，已被其它任务占用的计划直接跳过；执行中途停机时本周期会被跳过而不会重复扣款。
+    async fn run_schedule(&self, schedule: DcaScheduleModel, now: DateTime<Utc>) -> Result<(), Report> {
+        let repository = &self.infrastructure_layer.persistence.repository.dca_repository;
+        let domain_service = &self.domain_layer.dca_domain.domain_service;
+        let config = &self.infrastructure_layer.config.job.dca;
+
+        // 已达到支出上限的计划直接完成
+        if !schedule.within_cap() {
+            let mut completed = schedule;
+            completed.status = DcaStatus::Completed;
+            completed.updated_at = now;
+            repository.save_if_status(completed, DcaStatus::Active).await?;
+            return Ok(());
+        }
+
+        let scheduled_for = schedule.next_run_at;
+        let next_run_at = domain_service.next_run(&schedule, now)?;
+
+        let mut claimed = schedule.clone();
+        claimed.next_run_at = next_run_at;
+        if !repository.claim_run(claimed, scheduled_for).await? {
+            return Ok(());
+        }
+
+        let result = self.execute(&schedule).await.map_err(|e| e.to_string());
+        let attempt = schedule.retry_count + 1;
+        repository.record_execution(DcaExecutionModel::new(schedule.id, attempt, schedule.amount, &result, scheduled_for)).await?;
+
+        // 重新读取计划，保留执行期间用户对状态的修改
+        let mut latest = repository.find_by_id(schedule.id).await?.unwrap_or(schedule.clone());
+        match &result {
+            Ok(signature) => {
+                info!("+ApplicationLayer [Dca] schedule {} executed: {}", latest.id, signature);
+                latest.record_success(next_run_at);
+            }
+            Err(e) => {
+                warn!("-ApplicationLayer [Dca] schedule {} attempt {} failed: {}", latest.id, attempt, e);
+                let retry_at = domain_service.retry_at(&latest, config.max_retries, config.retry_base, config.retry_max, Utc::now());
+                // 不再重试时才推送转出失败事件
+                if retry_at.is_none() {
+                    self.publish_result(&schedule, &result).await;
+                }
+                latest.record_failure(e.clone(), retry_at, next_run_at);
+            }
+        }
+        // 只写回运行字段，不覆盖执行期间用户对状态的修改
+        repository.save_run(latest).await?;
+
+        if result.is_ok() {
+            self.publish_result(&schedule, &result).await;
+        }
+
+        Ok(())
+    }
+
+    /// # Description
+    ///     推送计划执行结果：兑换成交推送 swap_filled，转账最终失败推送 withdrawal_failed，
+    ///     转账成功则交给确认跟踪器，最终确认后推送 withdrawal_confirmed
+    async fn publish_result(&self, schedule: &DcaScheduleModel, result: &Result<String, String>) {
+        let (event_type, data) = match (&schedule.kind, result) {
+            (DcaKind::Swap, Ok(signature)) => (WebhookEventType::SwapFilled, json!({
+                "source": "dca",
+                "schedule_id": schedule.id,
+                "wallet_id": schedule.wallet_id,
+                "input_mint": schedule.input_mint,
+                "output_mint": schedule.output_mint,
+                "amount_in": schedule.amount,
+                "signature": signature,
+            })),
+            (DcaKind::Swap, Err(_)) => return,
+            (DcaKind::Transfer, Ok(signature)) => {
+                // 转出交易交给确认跟踪器，最终确认或失败后再推送
+                let data = json!({
+                    "source": "dca",
+                    "schedule_id": schedule.id,
+                    "wallet_id": schedule.wallet_id,
+                    "mint": schedule.input_mint,
+                    "amount": schedule.amount,
+                    "recipient": schedule.recipient,
+                });
+                self.pubsub_application.track_signature(TrackedSignature::new(signature.clone(), schedule.user_id, data)).await;
+                return;
+            }
+            (DcaKind::Transfer, Err(error)) => (WebhookEventType::WithdrawalFailed, json!({
+                "source": "dca",
+                "schedule_id": schedule.id,
+                "wallet_id": schedule.wallet_id,
+                "mint": schedule.input_mint,
+                "amount": schedule.amount,
+                "recipient": schedule.recipient,
+                "error": error,
+            })),
+        };
+
+        self.webhook_application.publish(schedule.user_id, event_type, data).await;
+    }
+
+    /// # Description
+    ///     复用钱包应用的转账与兑换逻辑执行一次计划，兑换发送前以预言机价格校验报价的滑点
+    async fn execute(&self, schedule: &DcaScheduleModel) -> Result<String, Report> {
+        let wallet = self.infrastructure_layer.persistence.repository.wallet_repository.find_by_id(schedule.wallet_id as u64).await?
+            .ok_or_else(|| Report::msg("钱包不存在"))?;
+        if wallet.disable || wallet.deleted_at.is_some() {
+            return Err(Report::msg("钱包已禁用"));
+        }
+        let keypair = WalletApplication::load_keypair(&self.infrastructure_layer.keystore, &wallet)?;
+        let client = &self.infrastructure_layer.rpc.client;
+        let input_mint = Pubkey::from_str(&schedule.input_mint)?;
+
+        match schedule.kind {
+            DcaKind::Transfer => {
+                let recipient = schedule.recipient.as_deref().ok_or_else(|| Report::msg("转账计划缺少接收地址"))?;
+                if input_mint == spl_token::native_mint::id() {
+                    WalletApplication::transfer_sol(client, &keypair, recipient, schedule.amount).await
+                } else {
+                    let recipient = Pubkey::from_str(recipient)?;
+                    WalletApplication::transfer_token(client, &keypair, &input_mint, &recipient, schedule.amount).await
+                }
+            }
+            DcaKind::Swap => {
+                let output_mint_address = schedule.output_mint.clone().ok_or_else(|| Report::msg("兑换计划缺少输出代币"))?;
+                let output_mint = Pubkey::from_str(&output_mint_address)?;
+                let quote = WalletApplication::quote_swap(&keypair.pubkey(), &input_mint, &output_mint, schedule.amount, schedule.max_slippage * 100.0)
+                    .await
+                    .map_err(|e| Report::msg(format!("兑换报价失败: {}", e)))?;
+                self.price_application
+                    .check_swap_slippage(&schedule.input_mint, &output_mint_address, schedule.amount, quote.output_amount, schedule.max_slippage)
+                    .await?;
+
+                WalletApplication::execute_swap(client, &keypair, quote)
+                    .await
+                    .map_err(|e| Report::msg(format!("兑换失败: {}", e)))
+            }
+        }
+    }
+
+    /// # Description
+    ///     查询属于该用户的定投计划
+    async fn find_user_schedule(&self, user_id: i32, schedule_id: i32) -> Result<DcaScheduleModel, Report> {
+        match self.infrastructure_layer.persistence.repository.dca_repository.find_by_id(schedule_id).await? {
+            Some(schedule) if schedule.user_id == user_id => Ok(schedule),
+            _ => Err(Report::msg("定投计划不存在")),
+        }
+    }
+
+    /// # Description
+    ///     按预期状态更新计划，状态已被其他操作修改时返回错误
+    async fn update_schedule(&self, schedule: DcaScheduleModel, expected: DcaStatus) -> Result<DcaScheduleModel, Report> {
+        if !self.infrastructure_layer.persistence.repository.dca_repository.save_if_status(schedule.clone(), expected).await? {
+            return Err(Report::msg("计划状态已变更，请刷新后重试"));
+        }
+
+        Ok(schedule)
+    }
+}
@@ -0,0 +1,3 @@
+mod dca_application;
+
+pub use dca_application::{DcaApplication, DcaDetail};
@@ -0,0 +1,368 @@
+use std::collections::HashSet;
+use std::str::FromStr;
+use std::sync::{Arc, Mutex};
+use color_eyre::{Report, Result};
+use futures::StreamExt;
+use serde::Serialize;
+use solana_client::rpc_client::GetConfirmedSignaturesForAddress2Config;
+use solana_client::rpc_config::RpcTransactionConfig;
+use solana_program::pubkey::Pubkey;
+use solana_sdk::commitment_config::CommitmentConfig;
+use solana_sdk::native_token::LAMPORTS_PER_SOL;
+use solana_sdk::signature::Signature;
+use solana_transaction_status::{EncodedConfirmedTransactionWithStatusMeta, UiLoadedAddresses, UiTransactionEncoding};
+use tracing::{error, info};
+use crate::core::domain::DomainLayer;
+use crate::core::domain::history::entity::transaction_detail_entity::{DecodedInstruction, TokenDelta};
+use crate::core::domain::history::entity::wallet_transaction_entity::{ActiveModel as WalletTransactionActiveModel, Model as WalletTransactionModel};
+use crate::core::domain::history::repository::HistoryRepositoryInterface;
+use crate::core::domain::instruction::entity::parsed_instruction_entity::LoadedAddresses;
+use crate::core::domain::wallet::repository::WalletRepositoryInterface;
+use crate::core::infrastructure::InfrastructureLayer;
+
+/// # Description
+///     RPC 单次查询签名的数量上限
+const SIGNATURE_PAGE_LIMIT: usize = 1000;
+
+/// # Description
+///     钱包交易的展示结构
+/// # Fields
+///     signature: String - 交易签名
+///     slot: i64 - 所在区块 slot
+///     block_time: Option<i64> - 出块时间（unix 秒）
+///     success: bool - 交易是否执行成功
+///     error: Option<String> - 失败原因
+///     fee: i64 - 手续费（lamports）
+///     sol_delta: i64 - SOL 净变动（lamports）
+///     sol_delta_ui: f64 - SOL 净变动（SOL）
+///     token_deltas: Vec<TokenDelta> - 代币净变动
+///     instructions: Vec<DecodedInstruction> - 解码后的指令
+#[derive(Debug, Serialize)]
+pub struct WalletTransactionView {
+    pub signature: String,
+    pub slot: i64,
+    pub block_time: Option<i64>,
+    pub success: bool,
+    pub error: Option<String>,
+    pub fee: i64,
+    pub sol_delta: i64,
+    pub sol_delta_ui: f64,
+    pub token_deltas: Vec<TokenDelta>,
+    pub instructions: Vec<DecodedInstruction>,
+}
+
+impl From<WalletTransactionModel> for WalletTransactionView {
+    fn from(model: WalletTransactionModel) -> Self {
+        Self {
+            token_deltas: model.token_delta_list(),
+            instructions: model.instruction_list(),
+            sol_delta_ui: model.sol_delta as f64 / LAMPORTS_PER_SOL as f64,
+            signature: model.signature,
+            slot: model.slot,
+            block_time: model.block_time,
+            success: model.success,
+            error: model.error,
+            fee: model.fee,
+            sol_delta: model.sol_delta,
+        }
+    }
+}
+
+/// # Description
+///     钱包交易分页结果
+/// # Fields
+///     page: u64 - 页码，从 1 开始
+///     page_size: u64 - 每页数量
+///     total: u64 - 已缓存的交易总数
+///     items: Vec<WalletTransactionView> - 当前页的交易
+#[derive(Debug, Serialize)]
+pub struct WalletTransactionPage {
+    pub page: u64,
+    pub page_size: u64,
+    pub total: u64,
+    pub items: Vec<WalletTransactionView>,
+}
+
+/// # Description
+///     钱包交易历史应用服务，从 RPC 拉取交易并缓存到数据库
+/// # Fields
+///     domain_layer: Arc<DomainLayer> - 领域层
+///     infrastructure_layer: Arc<InfrastructureLayer> - 基础设施层
+///     gap_fills: Mutex<HashSet<i32>> - 正在后台补齐新交易的钱包，避免重复启动补齐任务
+pub struct HistoryApplication {
+    domain_layer: Arc<DomainLayer>,
+    infrastructure_layer: Arc<InfrastructureLayer>,
+    gap_fills: Mutex<HashSet<i32>>,
+}
+
+impl HistoryApplication {
+    /// # Description
+    ///     创建新的钱包交易历史应用服务实例
+    /// # Params
+    ///     infrastructure_layer: Arc<InfrastructureLayer> - 基础设施层的引用，用于获取仓储和 RPC
+    ///     domain_layer: Arc<DomainLayer> - 领域层的引用，用于获取交易历史领域服务
+    /// # Return
+    ///     Self: 返回一个新的 `HistoryApplication` 实例
+    pub fn new(infrastructure_layer: Arc<InfrastructureLayer>, domain_layer: Arc<DomainLayer>) -> Self {
+        Self { domain_layer, infrastructure_layer, gap_fills: Mutex::new(HashSet::new()) }
+    }
+
+    /// # Description
+    ///     分页查询钱包交易历史。先同步缓存之后的新交易，缓存不足当前页时再向前补拉更早的交易
+    /// # Params
+    ///     user_id: i32 - 用户id
+    ///     wallet_id: i32 - 钱包id
+    ///     page: Option<u64> - 页码，从 1 开始
+    ///     page_size: Option<u64> - 每页数量
+    /// # Return
+    ///     Result<WalletTransactionPage, Report>: 当前页的交易
+    pub async fn list_transactions(self: &Arc<Self>, user_id: i32, wallet_id: i32, page: Option<u64>, page_size: Option<u64>) -> Result<WalletTransactionPage, Report> {
+        let config = &self.infrastructure_layer.config.history;
+        let page = page.unwrap_or(1);
+        let page_size = page_size.unwrap_or(config.default_page_size);
+        if page == 0 || page_size == 0 || page_size > config.max_page_size {
+            return Err(Report::msg(format!("页码从 1 开始，每页数量为 1 到 {}", config.max_page_size)));
+        }
+        let needed = page.checked_mul(page_size).ok_or_else(|| Report::msg("页码过大"))?;
+
+        let address = self.find_user_wallet_address(user_id, wallet_id).await?;
+        let repository = &self.infrastructure_layer.persistence.repository.history_repository;
+
+        self.sync_recent(wallet_id, &address).await?;
+
+        let cached = repository.count(wallet_id).await?;
+        if cached < needed {
+            if let Some(oldest) = repository.find_oldest(wallet_id).await? {
+                let before = Signature::from_str(&oldest.signature)?;
+                let limit = ((needed - cached) as usize).min(config.sync_limit);
+                let signatures = self.fetch_signatures(&address, Some(before), None, limit).await?;
+                self.cache_transactions(wallet_id, &address, signatures).await?;
+            }
+        }
+
+        let total = repository.count(wallet_id).await?;
+        let items = repository.find_page(wallet_id, (page - 1) * page_size, page_size).await?
+            .into_iter()
+            .map(WalletTransactionView::from)
+            .collect();
+
+        Ok(WalletTransactionPage { page, page_size, total, items })
+    }
+
+    /// # Description
+    ///     同步缓存中最新交易之后的新交易，单次请求最多拉取 sync_limit 笔。新交易超过该数量时先缓存最新的一批，
+    ///     与原缓存之间的缺口交给后台任务补齐；没有缓存时只拉取最近 sync_limit 笔，之后由分页按需向前补拉
+    /// # Params
+    ///     wallet_id: i32 - 钱包id
+    ///     address: &Pubkey - 钱包地址
+    /// # Return
+    ///     Result<(), Report>: 成功返回 Ok()，失败返回错误信息
+    async fn sync_recent(self: &Arc<Self>, wallet_id: i32, address: &Pubkey) -> Result<(), Report> {
+        let config = &self.infrastructure_layer.config.history;
+        let repository = &self.infrastructure_layer.persistence.repository.history_repository;
+
+        let Some(newest) = repository.find_newest(wallet_id).await? else {
+            let signatures = self.fetch_signatures(address, None, None, config.sync_limit).await?;
+            return self.cache_transactions(wallet_id, address, signatures).await;
+        };
+
+        let until = Signature::from_str(&newest.signature)?;
+        let signatures = self.fetch_signatures(address, None, Some(until), config.sync_limit).await?;
+        let gap_before = match signatures.len() >= config.sync_limit {
+            true => signatures.last().copied(),
+            false => None,
+        };
+        self.cache_transactions(wallet_id, address, signatures).await?;
+
+        if let Some(before) = gap_before {
+            self.spawn_gap_fill(wallet_id, *address, before, until);
+        }
+
+        Ok(())
+    }
+
+    /// # Description
+    ///     后台补齐 before 与 until 之间尚未缓存的交易，每批最多 sync_limit 笔；同一钱包同时只运行一个补齐任务
+    /// # Params
+    ///     wallet_id: i32 - 钱包id
+    ///     address: Pubkey - 钱包地址
+    ///     before: Signature - 已缓存的最新一批中最早的签名
+    ///     until: Signature - 补齐前缓存中最新的签名
+    fn spawn_gap_fill(self: &Arc<Self>, wallet_id: i32, address: Pubkey, before: Signature, until: Signature) {
+        if !self.gap_fills.lock().unwrap_or_else(|e| e.into_inner()).insert(wallet_id) {
+            return;
+        }
+
+        let application = Arc::clone(self);
+        tokio::spawn(async move {
+            let sync_limit = application.infrastructure_layer.config.history.sync_limit;
+            let mut before = before;
+            let result: Result<usize, Report> = async {
+                let mut filled = 0;
+                loop {
+                    let signatures = application.fetch_signatures(&address, Some(before), Some(until), sync_limit).await?;
+                    let fetched = signatures.len();
+                    let oldest = signatures.last().copied();
+                    application.cache_transactions(wallet_id, &address, signatures).await?;
+                    filled += fetched;
+
+                    match oldest {
+                        Some(oldest) if fetched >= sync_limit => before = oldest,
+                        _ => return Ok(filled),
+                    }
+                }
+            }.await;
+
+            match result {
+                Ok(filled) => info!("+ApplicationLayer [History] wallet {} gap filled with {} transactions", wallet_id, filled),
+                Err(e) => error!("-ApplicationLayer [History] wallet {} gap fill failed: {}", wallet_id, e),
+            }
+            application.gap_fills.lock().unwrap_or_else(|e| e.into_inner()).remove(&wallet_id);
+        });
+    }
+
+    /// # Description
+    ///     按时间倒序分页拉取地址的交易签名
+    /// # Params
+    ///     address: &Pubkey - 钱包地址
+    ///     before: Option<Signature> - 从该签名之前开始（不包含）
+    ///     until: Option<Signature> - 拉取到该签名为止（不包含）
+    ///     limit: usize - 最多拉取的数量
+    /// # Return
+    ///     Result<Vec<Signature>, Report>: 交易签名，最新的在前
+    async fn fetch_signatures(&self, address: &Pubkey, mut before: Option<Signature>, until: Option<Signature>, limit: usize) -> Result<Vec<Signature>, Report> {
+        let client = &self.infrastructure_layer.rpc.client;
+        let mut signatures = Vec::new();
+
+        while signatures.len() < limit {
+            let page_limit = (limit - signatures.len()).min(SIGNATURE_PAGE_LIMIT);
+            let config = GetConfirmedSignaturesForAddress2Config {
+                before,
+                until,
+                limit: Some(page_limit),
+                commitment: Some(CommitmentConfig::confirmed()),
+            };
+
+            let page = client.get_signatures_for_address_with_config(address, config).await?;
+            let fetched = page.len();
+            for status in page {
+                signatures.push(Signature::from_str(&status.signature)?);
+            }
+
+            if fetched < page_limit {
+                break;
+            }
+            before = signatures.last().copied();
+        }
+
+        Ok(signatures)
+    }
+
+    /// # Description
+    ///     并发拉取交易详情，计算余额变动、解码指令后写入缓存
+    /// # Params
+    ///     wallet_id: i32 - 钱包id
+    ///     address: &Pubkey - 钱包地址
+    ///     signatures: Vec<Signature> - 交易签名
+    /// # Return
+    ///     Result<(), Report>: 成功返回 Ok()，失败返回错误信息
+    async fn cache_transactions(&self, wallet_id: i32, address: &Pubkey, signatures: Vec<Signature>) -> Result<(), Report> {
+        if signatures.is_empty() {
+            return Ok(());
+        }
+
+        let concurrency = self.infrastructure_layer.config.history.concurrency.max(1);
+        let transactions: Vec<Result<EncodedConfirmedTransactionWithStatusMeta, Report>> = futures::stream::iter(signatures)
+            .map(|signature| async move { self.fetch_transaction(&signature).await })
+            .buffer_unordered(concurrency)
+            .collect()
+            .await;
+
+        let address = address.to_string();
+        let mut models: Vec<WalletTransactionActiveModel> = Vec::new();
+        for transaction in transactions {
+            models.push(self.build_transaction(wallet_id, &address, transaction?)?);
+        }
+
+        self.infrastructure_layer.persistence.repository.history_repository.save_all(wallet_id, models).await
+    }
+
+    /// # Description
+    ///     拉取单笔交易详情，交易使用 base64 编码以支持 v0 交易
+    /// # Params
+    ///     signature: &Signature - 交易签名
+    /// # Return
+    ///     Result<EncodedConfirmedTransactionWithStatusMeta, Report>: 交易详情
+    async fn fetch_transaction(&self, signature: &Signature) -> Result<EncodedConfirmedTransactionWithStatusMeta, Report> {
+        let config = RpcTransactionConfig {
+            encoding: Some(UiTransactionEncoding::Base64),
+            commitment: Some(CommitmentConfig::confirmed()),
+            max_supported_transaction_version: Some(0),
+        };
+
+        self.infrastructure_layer.rpc.client.get_transaction_with_config(signature, config).await
+            .map_err(|e| Report::msg(format!("获取交易 {} 失败: {}", signature, e)))
+    }
+
+    /// # Description
+    ///     把交易详情转换为钱包交易记录
+    /// # Params
+    ///     wallet_id: i32 - 钱包id
+    ///     address: &str - 钱包地址
+    ///     confirmed: EncodedConfirmedTransactionWithStatusMeta - 交易详情
+    /// # Return
+    ///     Result<WalletTransactionActiveModel, Report>: 钱包交易记录
+    fn build_transaction(&self, wallet_id: i32, address: &str, confirmed: EncodedConfirmedTransactionWithStatusMeta) -> Result<WalletTransactionActiveModel, Report> {
+        let transaction = confirmed.transaction.transaction.decode()
+            .ok_or_else(|| Report::msg(format!("slot {} 的交易解码失败", confirmed.slot)))?;
+        let meta = confirmed.transaction.meta.as_ref();
+
+        let loaded = meta
+            .and_then(|meta| Option::<&UiLoadedAddresses>::from(meta.loaded_addresses.as_ref()))
+            .map(|loaded| LoadedAddresses { writable: loaded.writable.clone(), readonly: loaded.readonly.clone() })
+            .unwrap_or_default();
+        let instruction_service = &self.domain_layer.instruction_domain.domain_service;
+        let parsed = instruction_service.decode_transaction(&transaction, &loaded);
+
+        let accounts: Vec<String> = parsed.accounts.iter().map(|account| account.pubkey.clone()).collect();
+        let instructions = parsed.instructions.iter()
+            .map(|instruction| DecodedInstruction {
+                program_id: instruction.program_id.clone(),
+                program: instruction.program.clone(),
+                kind: instruction.name.clone(),
+                description: instruction_service.describe(instruction),
+                accounts: instruction.account_pubkeys(),
+            })
+            .collect();
+
+        self.domain_layer.history_domain.domain_service.build_transaction(
+            wallet_id,
+            address,
+            confirmed.slot,
+            confirmed.block_time,
+            &transaction,
+            meta,
+            &accounts,
+            instructions,
+        )
+    }
+
+    /// # Description
+    ///     查找用户名下的钱包地址
+    /// # Params
+    ///     user_id: i32 - 用户id
+    ///     wallet_id: i32 - 钱包id
+    /// # Return
+    ///     Result<Pubkey, Report>: 钱包地址
+    async fn find_user_wallet_address(&self, user_id: i32, wallet_id: i32) -> Result<Pubkey, Report> {
+        let wallet = self.infrastructure_layer.persistence.repository.wallet_repository
+            .find_by_id(wallet_id as u64).await?
+            .ok_or_else(|| Report::msg("钱包不存在"))?;
+        if wallet.user_id != user_id || wallet.deleted_at.is_some() {
+            return Err(Report::msg("钱包不存在"));
+        }
+
+        let pub_key = wallet.pub_key.ok_or_else(|| Report::msg("钱包缺少公钥"))?;
+        Pubkey::from_str(&pub_key).map_err(|_| Report::msg("钱包公钥不合法"))
+    }
+}
@@ -0,0 +1,3 @@
+mod history_application;
+
+pub use history_application::{HistoryApplication, WalletTransactionPage, WalletTransactionView};
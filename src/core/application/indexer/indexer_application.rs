@@ -0,0 +1,263 @@
+use std::sync::Arc;
+use std::time::Duration;
+use color_eyre::{Report, Result};
+use futures::StreamExt;
+use serde::Serialize;
+use solana_client::rpc_config::RpcBlockConfig;
+use solana_sdk::commitment_config::CommitmentConfig;
+use solana_transaction_status::{EncodedConfirmedBlock, TransactionDetails, UiTransactionEncoding};
+use tokio::time::MissedTickBehavior;
+use tracing::{error, info};
+use crate::core::domain::DomainLayer;
+use crate::core::domain::indexer::entity::indexed_block_entity::Model as IndexedBlockModel;
+use crate::core::domain::indexer::entity::indexed_transaction_entity::Model as IndexedTransactionModel;
+use crate::core::domain::indexer::entity::indexer_checkpoint_entity::{BACKFILL_CHECKPOINT, LIVE_CHECKPOINT};
+use crate::core::domain::indexer::repository::IndexerRepositoryInterface;
+use crate::core::infrastructure::InfrastructureLayer;
+
+/// # Description
+///     索引任务进度
+/// # Fields
+///     live_slot: Option<i64> - 实时索引已完成的最后一个 slot
+///     backfill_slot: Option<i64> - 回填已完成的最后一个 slot
+///     latest_block: Option<IndexedBlockModel> - 已索引的最新区块
+#[derive(Debug, Serialize)]
+pub struct IndexerStatus {
+    pub live_slot: Option<i64>,
+    pub backfill_slot: Option<i64>,
+    pub latest_block: Option<IndexedBlockModel>,
+}
+
+/// # Description
+///     已索引区块详情，包含区块内的非投票交易
+/// # Fields
+///     block: IndexedBlockModel - 区块记录
+///     transactions: Vec<IndexedTransactionModel> - 非投票交易
+#[derive(Debug, Serialize)]
+pub struct IndexedBlockDetail {
+    pub block: IndexedBlockModel,
+    pub transactions: Vec<IndexedTransactionModel>,
+}
+
+/// # Description
+///     区块索引应用服务，按 slot 顺序把区块与非投票交易写入数据库，并支持历史区间回填
+/// # Fields
+///     domain_layer: Arc<DomainLayer> - 领域层
+///     infrastructure_layer: Arc<InfrastructureLayer> - 基础设施层
+pub struct IndexerApplication {
+    domain_layer: Arc<DomainLayer>,
+    infrastructure_layer: Arc<InfrastructureLayer>,
+}
+
+impl IndexerApplication {
+    /// # Description
+    ///     创建新的区块索引应用服务实例
+    /// # Params
+    ///     infrastructure_layer: Arc<InfrastructureLayer> - 基础设施层的引用，用于获取仓储和 RPC
+    ///     domain_layer: Arc<DomainLayer> - 领域层的引用，用于获取区块索引领域服务
+    /// # Return
+    ///     Self: 返回一个新的 `IndexerApplication` 实例
+    pub fn new(infrastructure_layer: Arc<InfrastructureLayer>, domain_layer: Arc<DomainLayer>) -> Self {
+        Self { domain_layer, infrastructure_layer }
+    }
+
+    /// # Description
+    ///     启动后台索引任务：实时索引从检查点继续追赶最新 slot，回填任务按配置区间执行一次
+    pub fn spawn_indexer(self: &Arc<Self>) {
+        let config = self.infrastructure_layer.config.job.indexer.clone();
+
+        if config.backfill.enabled {
+            let application = Arc::clone(self);
+            let backfill = config.backfill.clone();
+            tokio::spawn(async move {
+                match application.backfill(backfill.start_slot, backfill.end_slot).await {
+                    Ok(()) => info!("+ApplicationLayer [Indexer] backfill {}..={} complete.", backfill.start_slot, backfill.end_slot),
+                    Err(e) => error!("-ApplicationLayer [Indexer] backfill failed: {}", e),
+                }
+            });
+            info!("+ApplicationLayer [Indexer] backfill started.");
+        }
+
+        if !config.enabled {
+            info!("+ApplicationLayer [Indexer] indexer disabled.");
+            return;
+        }
+
+        let application = Arc::clone(self);
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(Duration::from_secs(config.interval.max(1)));
+            ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+            loop {
+                ticker.tick().await;
+                // 落后较多时连续处理多批，直到追上最新 slot 再等待下一轮
+                loop {
+                    match application.index_live_batch().await {
+                        Ok(true) => continue,
+                        Ok(false) => break,
+                        Err(e) => {
+                            error!("-ApplicationLayer [Indexer] index failed: {}", e);
+                            break;
+                        }
+                    }
+                }
+            }
+        });
+        info!("+ApplicationLayer [Indexer] indexer started.");
+    }
+
+    /// # Description
+    ///     从实时检查点继续索引一批 slot。首次运行时从配置的起始 slot 开始，未配置则从当前最新 slot 开始
+    /// # Return
+    ///     Result<bool, Report>: 是否仍落后于最新 slot
+    pub async fn index_live_batch(&self) -> Result<bool, Report> {
+        let config = &self.infrastructure_layer.config.job.indexer;
+        let client = &self.infrastructure_layer.rpc.client;
+        let repository = &self.infrastructure_layer.persistence.repository.indexer_repository;
+
+        let tip = client.get_slot_with_commitment(CommitmentConfig::confirmed()).await?;
+        let last_indexed = match repository.find_checkpoint(LIVE_CHECKPOINT).await? {
+            Some(checkpoint) => Some(checkpoint.slot as u64),
+            None if config.start_slot > 0 => Some(config.start_slot - 1),
+            None => Some(tip.saturating_sub(1)),
+        };
+
+        let Some((start, end)) = self.domain_layer.indexer_domain.domain_service.next_range(last_indexed, tip, config.batch_size) else {
+            return Ok(false);
+        };
+
+        self.index_range(start, end).await?;
+        repository.save_checkpoint(LIVE_CHECKPOINT, end).await?;
+
+        Ok(end < tip)
+    }
+
+    /// # Description
+    ///     回填历史区间，进度记录在回填检查点中，重复调用会从上次中断的位置继续
+    /// # Params
+    ///     start_slot: u64 - 回填起点（包含）
+    ///     end_slot: u64 - 回填终点（包含）
+    /// # Return
+    ///     Result<(), Report>: 成功返回 Ok()，失败返回错误信息
+    pub async fn backfill(&self, start_slot: u64, end_slot: u64) -> Result<(), Report> {
+        if start_slot > end_slot {
+            return Err(Report::msg("回填起点不能大于终点"));
+        }
+
+        let batch_size = self.infrastructure_layer.config.job.indexer.batch_size;
+        let repository = &self.infrastructure_layer.persistence.repository.indexer_repository;
+
+        // 检查点不在本次区间内时说明是新的回填任务，从起点开始；起点为 0 时没有“上一个 slot”，用 None 表示
+        let mut last_indexed = match repository.find_checkpoint(BACKFILL_CHECKPOINT).await? {
+            Some(checkpoint) if (start_slot as i64..=end_slot as i64).contains(&checkpoint.slot) => Some(checkpoint.slot as u64),
+            _ => start_slot.checked_sub(1),
+        };
+
+        while let Some((start, end)) = self.domain_layer.indexer_domain.domain_service.next_range(last_indexed, end_slot, batch_size) {
+            self.index_range(start, end).await?;
+            repository.save_checkpoint(BACKFILL_CHECKPOINT, end).await?;
+            last_indexed = Some(end);
+        }
+
+        Ok(())
+    }
+
+    /// # Description
+    ///     查询索引任务进度
+    /// # Return
+    ///     Result<IndexerStatus, Report>: 索引进度
+    pub async fn status(&self) -> Result<IndexerStatus, Report> {
+        let repository = &self.infrastructure_layer.persistence.repository.indexer_repository;
+
+        Ok(IndexerStatus {
+            live_slot: repository.find_checkpoint(LIVE_CHECKPOINT).await?.map(|checkpoint| checkpoint.slot),
+            backfill_slot: repository.find_checkpoint(BACKFILL_CHECKPOINT).await?.map(|checkpoint| checkpoint.slot),
+            latest_block: repository.find_latest_block().await?,
+        })
+    }
+
+    /// # Description
+    ///     查询已索引的区块及其非投票交易
+    /// # Params
+    ///     slot: u64 - 区块 slot
+    /// # Return
+    ///     Result<IndexedBlockDetail, Report>: 区块详情
+    pub async fn get_block(&self, slot: u64) -> Result<IndexedBlockDetail, Report> {
+        let repository = &self.infrastructure_layer.persistence.repository.indexer_repository;
+        let block = repository.find_block(slot).await?
+            .ok_or_else(|| Report::msg("区块尚未索引"))?;
+        let transactions = repository.find_transactions_by_slot(slot).await?;
+
+        Ok(IndexedBlockDetail { block, transactions })
+    }
+
+    /// # Description
+    ///     根据签名查询已索引的交易
+    /// # Params
+    ///     signature: &str - 交易签名
+    /// # Return
+    ///     Result<IndexedTransactionModel, Report>: 交易记录
+    pub async fn get_transaction(&self, signature: &str) -> Result<IndexedTransactionModel, Report> {
+        self.infrastructure_layer.persistence.repository.indexer_repository
+            .find_transaction(signature).await?
+            .ok_or_else(|| Report::msg("交易尚未索引"))
+    }
+
+    /// # Description
+    ///     索引闭区间内的全部 slot：没有出块的 slot 记为跳过，已出块的 slot 并发拉取后写入区块与非投票交易
+    /// # Params
+    ///     start: u64 - 区间起点（包含）
+    ///     end: u64 - 区间终点（包含）
+    /// # Return
+    ///     Result<(), Report>: 任意区块拉取或保存失败时返回错误，调用方不推进检查点，下一轮重试整个区间
+    async fn index_range(&self, start: u64, end: u64) -> Result<(), Report> {
+        let client = &self.infrastructure_layer.rpc.client;
+        let repository = &self.infrastructure_layer.persistence.repository.indexer_repository;
+        let domain_service = &self.domain_layer.indexer_domain.domain_service;
+        let concurrency = self.infrastructure_layer.config.job.indexer.concurrency.max(1);
+
+        let mut produced = client.get_blocks_with_commitment(start, Some(end), CommitmentConfig::confirmed()).await?;
+        produced.sort_unstable();
+
+        for slot in domain_service.skipped_slots(start, end, &produced) {
+            repository.save_block(IndexedBlockModel::skipped(slot), Vec::new()).await?;
+        }
+
+        let blocks: Vec<Result<(u64, EncodedConfirmedBlock), Report>> = futures::stream::iter(produced)
+            .map(|slot| async move { self.fetch_block(slot).await.map(|block| (slot, block)) })
+            .buffered(concurrency)
+            .collect()
+            .await;
+
+        for result in blocks {
+            let (slot, block) = result?;
+            let (indexed_block, transactions) = domain_service.build_block(slot, &block)?;
+            repository.save_block(indexed_block, transactions).await?;
+        }
+
+        info!("+ApplicationLayer [Indexer] indexed slots {}..={}.", start, end);
+
+        Ok(())
+    }
+
+    /// # Description
+    ///     拉取单个区块，交易使用 base64 编码以支持 v0 交易，不拉取奖励信息
+    /// # Params
+    ///     slot: u64 - 区块 slot
+    /// # Return
+    ///     Result<EncodedConfirmedBlock, Report>: 链上区块
+    pub async fn fetch_block(&self, slot: u64) -> Result<EncodedConfirmedBlock, Report> {
+        let config = RpcBlockConfig {
+            encoding: Some(UiTransactionEncoding::Base64),
+            transaction_details: Some(TransactionDetails::Full),
+            rewards: Some(false),
+            commitment: Some(CommitmentConfig::confirmed()),
+            max_supported_transaction_version: Some(0),
+        };
+
+        let block = self.infrastructure_layer.rpc.client.get_block_with_config(slot, config).await
+            .map_err(|e| Report::msg(format!("获取区块 {} 失败: {}", slot, e)))?;
+
+        Ok(block.into())
+    }
+}
@@ -0,0 +1,3 @@
+mod indexer_application;
+
+pub use indexer_application::{IndexerApplication, IndexerStatus, IndexedBlockDetail};
@@ -0,0 +1,207 @@
+use std::collections::HashMap;
+use std::path::Path;
+use std::str::FromStr;
+use std::sync::Arc;
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use color_eyre::{Report, Result};
+use serde::Serialize;
+use tracing::{error, info};
+use solana_sdk::message::VersionedMessage;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::transaction::VersionedTransaction;
+use crate::core::domain::DomainLayer;
+use crate::core::domain::instruction::entity::anchor_idl_entity::AnchorIdl;
+use crate::core::domain::instruction::entity::parsed_instruction_entity::ParsedTransaction;
+use crate::core::infrastructure::InfrastructureLayer;
+
+/// # Description
+///     已加载的 Anchor IDL 概要
+/// # Fields
+///     program_id: String - 程序地址
+///     program: String - 程序名称
+///     instructions: usize - 指令数量
+///     accounts: usize - 账户类型数量
+#[derive(Debug, Serialize)]
+pub struct IdlSummary {
+    pub program_id: String,
+    pub program: String,
+    pub instructions: usize,
+    pub accounts: usize,
+}
+
+impl IdlSummary {
+    fn new(program_id: &str, idl: &AnchorIdl) -> Self {
+        Self {
+            program_id: program_id.to_string(),
+            program: idl.program_name(),
+            instructions: idl.instructions.len(),
+            accounts: idl.accounts.len(),
+        }
+    }
+}
+
+/// # Description
+///     指令解码应用服务，解析原始交易并通过 RPC 补全地址查找表
+/// # Fields
+///     domain_layer: Arc<DomainLayer> - 领域层
+///     infrastructure_layer: Arc<InfrastructureLayer> - 基础设施层
+pub struct InstructionApplication {
+    domain_layer: Arc<DomainLayer>,
+    infrastructure_layer: Arc<InfrastructureLayer>,
+}
+
+impl InstructionApplication {
+    /// # Description
+    ///     创建新的指令解码应用服务实例
+    /// # Params
+    ///     infrastructure_layer: Arc<InfrastructureLayer> - 基础设施层的引用，用于获取 RPC
+    ///     domain_layer: Arc<DomainLayer> - 领域层的引用，用于获取指令解码领域服务
+    /// # Return
+    ///     Self: 返回一个新的 `InstructionApplication` 实例
+    pub fn new(infrastructure_layer: Arc<InfrastructureLayer>, domain_layer: Arc<DomainLayer>) -> Self {
+        Self { domain_layer, infrastructure_layer }
+    }
+
+    /// # Description
+    ///     后台加载 Anchor IDL：先加载本地目录，再从链上拉取配置的程序，失败只记录日志
+    pub fn spawn_idl_loader(self: &Arc<Self>) {
+        let application = Arc::clone(self);
+        tokio::spawn(async move {
+            let config = &application.infrastructure_layer.config.idl;
+
+            match application.load_local_idls(&config.directory).await {
+                Ok(count) => info!("+ApplicationLayer [Instruction] {} local IDL loaded.", count),
+                Err(e) => error!("-ApplicationLayer [Instruction] load local IDL failed: {}", e),
+            }
+
+            for program_id in &config.programs {
+                if let Err(e) = application.fetch_idl(program_id).await {
+                    error!("-ApplicationLayer [Instruction] fetch IDL of {} failed: {}", program_id, e);
+                }
+            }
+        });
+        info!("+ApplicationLayer [Instruction] IDL loader started.");
+    }
+
+    /// # Description
+    ///     加载目录下的全部 IDL 文件。程序地址取 IDL 中记录的地址，没有时取文件名
+    /// # Params
+    ///     directory: &str - IDL 目录，不存在时跳过
+    /// # Return
+    ///     Result<usize, Report>: 成功加载的数量
+    pub async fn load_local_idls(&self, directory: &str) -> Result<usize, Report> {
+        if !Path::new(directory).is_dir() {
+            return Ok(0);
+        }
+
+        let service = &self.domain_layer.instruction_domain.domain_service;
+        let mut entries = tokio::fs::read_dir(directory).await?;
+        let mut count = 0;
+        while let Some(entry) = entries.next_entry().await? {
+            let path = entry.path();
+            if path.extension().and_then(|extension| extension.to_str()) != Some("json") {
+                continue;
+            }
+
+            let idl = match service.parse_idl(&tokio::fs::read(&path).await?) {
+                Ok(idl) => idl,
+                Err(e) => {
+                    error!("-ApplicationLayer [Instruction] {}: {}", path.display(), e);
+                    continue;
+                }
+            };
+            let program_id = idl.program_address()
+                .or_else(|| path.file_stem().and_then(|stem| stem.to_str()).map(str::to_string))
+                .filter(|program_id| Pubkey::from_str(program_id).is_ok());
+            match program_id {
+                Some(program_id) => match service.register_idl(&program_id, idl) {
+                    Ok(()) => count += 1,
+                    Err(e) => error!("-ApplicationLayer [Instruction] {}: {}", path.display(), e),
+                },
+                None => error!("-ApplicationLayer [Instruction] {}: 无法确定 IDL 对应的程序地址", path.display()),
+            }
+        }
+
+        Ok(count)
+    }
+
+    /// # Description
+    ///     从链上 IDL 账户拉取程序的 IDL 并注册
+    /// # Params
+    ///     program_id: &str - 程序地址
+    /// # Return
+    ///     Result<IdlSummary, Report>: 已注册的 IDL 概要
+    pub async fn fetch_idl(&self, program_id: &str) -> Result<IdlSummary, Report> {
+        let program = Pubkey::from_str(program_id).map_err(|_| Report::msg("程序地址不合法"))?;
+        let service = &self.domain_layer.instruction_domain.domain_service;
+
+        let address = service.idl_address(&program)?;
+        let data = self.infrastructure_layer.rpc.client.get_account_data(&address).await
+            .map_err(|_| Report::msg(format!("程序 {} 没有链上 IDL 账户", program_id)))?;
+        let idl = service.parse_idl_account(&data)?;
+
+        let summary = IdlSummary::new(program_id, &idl);
+        service.register_idl(program_id, idl)?;
+        info!("+ApplicationLayer [Instruction] IDL of {} ({}) registered.", program_id, summary.program);
+        Ok(summary)
+    }
+
+    /// # Description
+    ///     已加载的 Anchor IDL
+    /// # Return
+    ///     Vec<IdlSummary>: IDL 概要，按程序地址排序
+    pub fn list_idls(&self) -> Vec<IdlSummary> {
+        self.domain_layer.instruction_domain.domain_service.registered_idls()
+            .iter()
+            .map(|(program_id, idl)| IdlSummary::new(program_id, idl))
+            .collect()
+    }
+
+    /// # Description
+    ///     解码 base64 编码的交易（legacy 或 v0），v0 交易引用的地址查找表从链上读取
+    /// # Params
+    ///     encoded: &str - base64 编码的序列化交易
+    /// # Return
+    ///     Result<ParsedTransaction, Report>: 解码后的交易
+    pub async fn decode_transaction(&self, encoded: &str) -> Result<ParsedTransaction, Report> {
+        let bytes = STANDARD.decode(encoded.trim()).map_err(|_| Report::msg("交易不是合法的 base64 编码"))?;
+        let transaction: VersionedTransaction = bincode::deserialize(&bytes).map_err(|_| Report::msg("无法反序列化交易"))?;
+        transaction.sanitize().map_err(|e| Report::msg(format!("交易格式错误: {}", e)))?;
+
+        let service = &self.domain_layer.instruction_domain.domain_service;
+        let tables = self.fetch_lookup_tables(&transaction.message).await?;
+        let loaded = service.resolve_lookups(&transaction.message, &tables)?;
+
+        Ok(service.decode_transaction(&transaction, &loaded))
+    }
+
+    /// # Description
+    ///     读取交易引用的地址查找表
+    /// # Params
+    ///     message: &VersionedMessage - 交易消息
+    /// # Return
+    ///     Result<HashMap<String, Vec<String>>, Report>: 查找表地址到表内地址列表的映射
+    async fn fetch_lookup_tables(&self, message: &VersionedMessage) -> Result<HashMap<String, Vec<String>>, Report> {
+        let keys: Vec<Pubkey> = message.address_table_lookups()
+            .unwrap_or_default()
+            .iter()
+            .map(|lookup| lookup.account_key)
+            .collect();
+        if keys.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        let accounts = self.infrastructure_layer.rpc.client.get_multiple_accounts(&keys).await
+            .map_err(|e| Report::msg(format!("获取地址查找表失败: {}", e)))?;
+
+        let service = &self.domain_layer.instruction_domain.domain_service;
+        let mut tables = HashMap::new();
+        for (key, account) in keys.iter().zip(accounts) {
+            let account = account.ok_or_else(|| Report::msg(format!("地址查找表 {} 不存在", key)))?;
+            tables.insert(key.to_string(), service.lookup_table_addresses(&account.data)?);
+        }
+
+        Ok(tables)
+    }
+}
@@ -0,0 +1,3 @@
+mod instruction_application;
+
+pub use instruction_application::{InstructionApplication, IdlSummary};
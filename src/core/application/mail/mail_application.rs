@@ -0,0 +1,145 @@
+use std::sync::Arc;
+use color_eyre::{Report, Result};
+use tracing::{error, info, warn};
+use crate::core::application::verification::VerificationApplication;
+use crate::core::domain::DomainLayer;
+use crate::core::domain::mail::entity::mail_entity::MailTemplate;
+use crate::core::domain::verification::entity::verification_code_entity::{Model as VerificationCodeModel, VerificationChannel, VerificationPurpose};
+use crate::core::infrastructure::InfrastructureLayer;
+
+/// # Description
+///     邮件应用服务：发送模板邮件，管理邮箱验证码与重置密码令牌
+/// # Fields
+///     domain_layer: Arc<DomainLayer> - 领域层
+///     infrastructure_layer: Arc<InfrastructureLayer> - 基础设施层
+///     verification_application: Arc<VerificationApplication> - 验证码应用
+pub struct MailApplication {
+    domain_layer: Arc<DomainLayer>,
+    infrastructure_layer: Arc<InfrastructureLayer>,
+    verification_application: Arc<VerificationApplication>,
+}
+
+impl MailApplication {
+    /// # Description
+    ///     创建新的邮件应用服务实例
+    /// # Params
+    ///     infrastructure_layer: Arc<InfrastructureLayer> - 基础设施层的引用，用于获取邮件发送与验证码仓储
+    ///     domain_layer: Arc<DomainLayer> - 领域层的引用，用于获取邮件与验证码领域服务
+    ///     verification_application: Arc<VerificationApplication> - 验证码应用
+    /// # Return
+    ///     Self: 返回一个新的 `MailApplication` 实例
+    pub fn new(infrastructure_layer: Arc<InfrastructureLayer>, domain_layer: Arc<DomainLayer>, verification_application: Arc<VerificationApplication>) -> Self {
+        Self { domain_layer, infrastructure_layer, verification_application }
+    }
+
+    /// # Description
+    ///     校验并规范化邮箱
+    /// # Params
+    ///     email: &str - 邮箱
+    /// # Return
+    ///     Result<String, Report>: 规范化后的邮箱
+    pub fn normalize_email(&self, email: &str) -> Result<String, Report> {
+        self.domain_layer.mail_domain.domain_service.normalize_email(email)
+    }
+
+    /// # Description
+    ///     向邮箱发送注册验证码，新验证码发出后旧验证码立即作废
+    /// # Params
+    ///     email: &str - 已规范化的邮箱
+    /// # Return
+    ///     Result<(), Report>: 发送结果
+    pub async fn send_register_code(&self, email: &str) -> Result<(), Report> {
+        let config = &self.infrastructure_layer.config.mail;
+
+        let code = self.domain_layer.verification_domain.domain_service.generate_code(config.code_length);
+        let issued = self.issue(email, VerificationPurpose::Register, &code, config.code_ttl).await?;
+
+        let template = MailTemplate::RegisterCode { code, ttl_minutes: config.code_ttl.div_ceil(60) };
+        self.deliver(email, &template, Some(&issued)).await
+    }
+
+    /// # Description
+    ///     校验邮箱验证码，通过后验证码即被使用
+    /// # Params
+    ///     email: &str - 已规范化的邮箱
+    ///     purpose: VerificationPurpose - 用途
+    ///     code: &str - 用户输入的验证码
+    /// # Return
+    ///     Result<(), Report>: 校验结果
+    pub async fn verify_code(&self, email: &str, purpose: VerificationPurpose, code: &str) -> Result<(), Report> {
+        self.verification_application
+            .verify(VerificationChannel::Email, email, purpose, code, self.infrastructure_layer.config.mail.code_max_attempts)
+            .await
+    }
+
+    /// # Description
+    ///     发送重置密码链接
+    /// # Params
+    ///     email: &str - 已规范化的邮箱
+    /// # Return
+    ///     Result<(), Report>: 发送结果
+    pub async fn send_reset_link(&self, email: &str) -> Result<(), Report> {
+        let config = &self.infrastructure_layer.config.mail;
+
+        let token = self.domain_layer.verification_domain.domain_service.generate_token();
+        let issued = self.issue(email, VerificationPurpose::ResetPassword, &token, config.reset_token_ttl).await?;
+
+        let template = MailTemplate::PasswordReset {
+            link: format!("{}{}", config.reset_url, token),
+            ttl_minutes: config.reset_token_ttl.div_ceil(60),
+        };
+        self.deliver(email, &template, Some(&issued)).await
+    }
+
+    /// # Description
+    ///     使用重置密码令牌，令牌只能使用一次
+    /// # Params
+    ///     token: &str - 邮件中的令牌
+    /// # Return
+    ///     Result<String, Report>: 令牌对应的邮箱
+    pub async fn consume_reset_token(&self, token: &str) -> Result<String, Report> {
+        self.verification_application
+            .consume_token(VerificationChannel::Email, VerificationPurpose::ResetPassword, token)
+            .await?
+            .ok_or_else(|| Report::msg("重置链接无效或已过期"))
+    }
+
+    /// # Description
+    ///     发送通知邮件，失败只记录日志
+    /// # Params
+    ///     email: &str - 收件人
+    ///     template: MailTemplate - 模板
+    pub async fn notify(&self, email: &str, template: MailTemplate) {
+        if let Err(e) = self.deliver(email, &template, None).await {
+            warn!("-ApplicationLayer [Mail] notification to {} failed: {}", email, e);
+        }
+    }
+
+    /// # Description
+    ///     按邮件的发送间隔登记新的验证码
+    async fn issue(&self, email: &str, purpose: VerificationPurpose, secret: &str, ttl: u64) -> Result<VerificationCodeModel, Report> {
+        self.verification_application
+            .issue(VerificationChannel::Email, email, purpose, secret, ttl, self.infrastructure_layer.config.mail.resend_interval)
+            .await
+    }
+
+    /// # Description
+    ///     渲染并发送邮件，发送失败时作废刚登记的验证码
+    async fn deliver(&self, email: &str, template: &MailTemplate, issued: Option<&VerificationCodeModel>) -> Result<(), Report> {
+        let message = self.domain_layer.mail_domain.domain_service.render(&self.infrastructure_layer.config.app.app_name, email, template);
+
+        match self.infrastructure_layer.mailer.send(&message).await {
+            Ok(_) => {
+                info!("+ApplicationLayer [Mail] \"{}\" sent to {}", message.subject, email);
+                Ok(())
+            }
+            Err(e) => {
+                error!("-ApplicationLayer [Mail] sending to {} failed: {}", email, e);
+                if let Some(issued) = issued {
+                    self.verification_application.revoke(issued).await?;
+                }
+                Err(Report::msg("邮件发送失败，请稍后再试"))
+            }
+        }
+    }
+}
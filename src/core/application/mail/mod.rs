@@ -0,0 +1,3 @@
+mod mail_application;
+
+pub use mail_application::MailApplication;
\ No newline at end of file
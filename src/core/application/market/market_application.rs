@@ -0,0 +1,186 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Duration;
+use chrono::Utc;
+use color_eyre::{Report, Result};
+use futures::stream::{self, Stream};
+use solana_program::program_pack::Pack;
+use solana_program::pubkey::Pubkey;
+use tokio::sync::RwLock;
+use crate::core::domain::DomainLayer;
+use crate::core::domain::market::entity::{MarketFill, MarketMeta, MarketSnapshot, OrderBook};
+use crate::core::infrastructure::InfrastructureLayer;
+use crate::core::infrastructure::openbook::{EventQueueAccount, MarketStateAccount, SlabAccount};
+
+/// # Description
+///     OpenBook / Serum 行情应用服务，读取市场账户并返回归一化的订单簿与成交
+/// # Fields
+///     domain_layer: Arc<DomainLayer> - 领域层
+///     infrastructure_layer: Arc<InfrastructureLayer> - 基础设施层
+///     markets: RwLock<HashMap<String, MarketMeta>> - 市场信息缓存，市场账户的 lot 与精度不会变化
+pub struct MarketApplication {
+    domain_layer: Arc<DomainLayer>,
+    infrastructure_layer: Arc<InfrastructureLayer>,
+    markets: RwLock<HashMap<String, MarketMeta>>,
+}
+
+impl MarketApplication {
+    /// # Description
+    ///     创建新的行情应用服务实例
+    /// # Params
+    ///     infrastructure_layer: Arc<InfrastructureLayer> - 基础设施层的引用，用于获取 RPC
+    ///     domain_layer: Arc<DomainLayer> - 领域层的引用，用于获取行情领域服务
+    /// # Return
+    ///     Self: 返回一个新的 `MarketApplication` 实例
+    pub fn new(infrastructure_layer: Arc<InfrastructureLayer>, domain_layer: Arc<DomainLayer>) -> Self {
+        Self { domain_layer, infrastructure_layer, markets: RwLock::new(HashMap::new()) }
+    }
+
+    /// # Description
+    ///     获取市场信息，首次读取后缓存
+    /// # Params
+    ///     market: &str - 市场地址
+    /// # Return
+    ///     Result<MarketMeta, Report>: 成功返回市场信息，失败返回错误信息
+    pub async fn get_market(&self, market: &str) -> Result<MarketMeta, Report> {
+        if let Some(meta) = self.markets.read().await.get(market) {
+            return Ok(meta.clone());
+        }
+
+        let client = &self.infrastructure_layer.rpc.client;
+        let address = Pubkey::from_str(market).map_err(|_| Report::msg("市场地址不合法"))?;
+        let account = client.get_account(&address).await?;
+        let layout = MarketStateAccount::decode(&account.data)?;
+
+        let mints = client.get_multiple_accounts(&[layout.coin_mint, layout.pc_mint]).await?;
+        let decimals = mints
+            .iter()
+            .map(|account| {
+                let account = account.as_ref().ok_or_else(|| Report::msg("代币 mint 账户不存在"))?;
+                Ok(spl_token::state::Mint::unpack(&account.data)?.decimals)
+            })
+            .collect::<Result<Vec<u8>, Report>>()?;
+
+        let meta = MarketMeta {
+            address: market.to_string(),
+            coin_mint: layout.coin_mint.to_string(),
+            pc_mint: layout.pc_mint.to_string(),
+            bids: layout.bids.to_string(),
+            asks: layout.asks.to_string(),
+            event_queue: layout.event_queue.to_string(),
+            coin_lot_size: layout.coin_lot_size,
+            pc_lot_size: layout.pc_lot_size,
+            coin_decimals: decimals[0],
+            pc_decimals: decimals[1],
+        };
+        self.markets.write().await.insert(market.to_string(), meta.clone());
+
+        Ok(meta)
+    }
+
+    /// # Description
+    ///     获取 L2 订单簿
+    /// # Params
+    ///     market: &str - 市场地址
+    ///     depth: Option<usize> - 每侧档位数量
+    /// # Return
+    ///     Result<OrderBook, Report>: 成功返回订单簿，失败返回错误信息
+    pub async fn get_order_book(&self, market: &str, depth: Option<usize>) -> Result<OrderBook, Report> {
+        Ok(self.get_snapshot(market, depth, Some(0)).await?.book)
+    }
+
+    /// # Description
+    ///     获取最近成交
+    /// # Params
+    ///     market: &str - 市场地址
+    ///     limit: Option<usize> - 最大数量
+    /// # Return
+    ///     Result<Vec<MarketFill>, Report>: 成功返回成交记录（从新到旧），失败返回错误信息
+    pub async fn get_fills(&self, market: &str, limit: Option<usize>) -> Result<Vec<MarketFill>, Report> {
+        let meta = self.get_market(market).await?;
+        let event_queue = Pubkey::from_str(&meta.event_queue)?;
+        let account = self.infrastructure_layer.rpc.client.get_account(&event_queue).await?;
+
+        self.decode_fills(&meta, &account.data, limit)
+    }
+
+    /// # Description
+    ///     在一次 RPC 调用中读取买卖盘与事件队列，返回行情快照
+    /// # Params
+    ///     market: &str - 市场地址
+    ///     depth: Option<usize> - 每侧档位数量
+    ///     limit: Option<usize> - 最近成交数量，为 0 时不读取事件队列
+    /// # Return
+    ///     Result<MarketSnapshot, Report>: 成功返回行情快照，失败返回错误信息
+    pub async fn get_snapshot(&self, market: &str, depth: Option<usize>, limit: Option<usize>) -> Result<MarketSnapshot, Report> {
+        let config = &self.infrastructure_layer.config.market;
+        let depth = depth.unwrap_or(config.default_depth).clamp(1, config.max_depth);
+        let meta = self.get_market(market).await?;
+
+        let mut keys = vec![Pubkey::from_str(&meta.bids)?, Pubkey::from_str(&meta.asks)?];
+        if limit != Some(0) {
+            keys.push(Pubkey::from_str(&meta.event_queue)?);
+        }
+        let accounts = self.infrastructure_layer.rpc.client.get_multiple_accounts(&keys).await?;
+        let data = |index: usize| {
+            accounts.get(index).and_then(|account| account.as_ref()).map(|account| account.data.as_slice())
+                .ok_or_else(|| Report::msg("OpenBook 账户不存在"))
+        };
+
+        let bids = SlabAccount::decode_bids(data(0)?)?;
+        let asks = SlabAccount::decode_asks(data(1)?)?;
+        let book = self.domain_layer.market_domain.domain_service.build_book(&meta, bids, asks, depth, Utc::now());
+        let fills = if limit == Some(0) { Vec::new() } else { self.decode_fills(&meta, data(2)?, limit)? };
+
+        Ok(MarketSnapshot { book, fills })
+    }
+
+    /// # Description
+    ///     按配置的间隔轮询行情快照，只在订单簿或成交变化时产出新的快照
+    /// # Params
+    ///     market: String - 市场地址
+    ///     depth: Option<usize> - 每侧档位数量
+    ///     limit: Option<usize> - 最近成交数量
+    /// # Return
+    ///     impl Stream<Item = Result<MarketSnapshot, Report>>: 行情快照流
+    pub fn watch(self: Arc<Self>, market: String, depth: Option<usize>, limit: Option<usize>) -> impl Stream<Item = Result<MarketSnapshot, Report>> {
+        let interval = Duration::from_millis(self.infrastructure_layer.config.market.stream_interval_ms.max(100));
+
+        stream::unfold((self, None::<MarketSnapshot>, false), move |(application, last, started)| {
+            let market = market.clone();
+            async move {
+                let mut started = started;
+                loop {
+                    if started {
+                        tokio::time::sleep(interval).await;
+                    }
+                    started = true;
+
+                    match application.get_snapshot(&market, depth, limit).await {
+                        Ok(snapshot) => {
+                            let unchanged = last.as_ref().is_some_and(|last| {
+                                last.book.bids == snapshot.book.bids && last.book.asks == snapshot.book.asks && last.fills == snapshot.fills
+                            });
+                            if unchanged {
+                                continue;
+                            }
+                            return Some((Ok(snapshot.clone()), (application, Some(snapshot), started)));
+                        }
+                        Err(e) => return Some((Err(e), (application, last, started))),
+                    }
+                }
+            }
+        })
+    }
+
+    fn decode_fills(&self, meta: &MarketMeta, data: &[u8], limit: Option<usize>) -> Result<Vec<MarketFill>, Report> {
+        let config = &self.infrastructure_layer.config.market;
+        let limit = limit.unwrap_or(config.fill_limit).min(config.fill_limit);
+
+        // 每笔成交有挂单、吃单两条事件，多读一倍以保证数量
+        let events = EventQueueAccount::decode(data, limit.saturating_mul(2))?;
+
+        Ok(self.domain_layer.market_domain.domain_service.fills(meta, events, limit))
+    }
+}
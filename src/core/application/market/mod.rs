@@ -0,0 +1,3 @@
+mod market_application;
+
+pub use market_application::MarketApplication;
@@ -1,6 +1,27 @@
 pub mod jwt_service;
-mod user;
+pub(crate) mod user;
 pub(crate) mod wallet;
+pub(crate) mod price;
+pub(crate) mod order;
+pub(crate) mod dca;
+pub(crate) mod market;
+pub(crate) mod indexer;
+pub(crate) mod network;
+pub(crate) mod history;
+pub(crate) mod instruction;
+pub(crate) mod account;
+pub(crate) mod webhook;
+pub(crate) mod watcher;
+pub(crate) mod stream;
+pub(crate) mod reorg;
+pub(crate) mod rbac;
+pub(crate) mod verification;
+pub(crate) mod mail;
+pub(crate) mod sms;
+pub(crate) mod two_factor;
+pub(crate) mod withdrawal;
+pub(crate) mod throttle;
+pub(crate) mod admin;
 pub mod application;
 
 pub use application::ApplicationLayer;
\ No newline at end of file
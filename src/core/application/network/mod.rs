@@ -0,0 +1,3 @@
+mod network_application;
+
+pub use network_application::NetworkApplication;
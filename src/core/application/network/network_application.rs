@@ -0,0 +1,176 @@
+use std::sync::Arc;
+use std::time::Duration;
+use color_eyre::{Report, Result};
+use futures::StreamExt;
+use solana_sdk::commitment_config::CommitmentConfig;
+use solana_transaction_status::EncodedConfirmedBlock;
+use tokio::time::MissedTickBehavior;
+use tracing::{error, info};
+use crate::core::application::indexer::IndexerApplication;
+use crate::core::domain::DomainLayer;
+use crate::core::domain::network::entity::network_stats_entity::NetworkStats;
+use crate::core::domain::network::repository::NetworkRepositoryInterface;
+use crate::core::infrastructure::InfrastructureLayer;
+
+/// # Description
+///     网络统计应用服务，后台按区块采样吞吐数据并提供滑动窗口统计
+/// # Fields
+///     domain_layer: Arc<DomainLayer> - 领域层
+///     infrastructure_layer: Arc<InfrastructureLayer> - 基础设施层
+///     indexer_application: Arc<IndexerApplication> - 区块索引应用，用于拉取区块
+pub struct NetworkApplication {
+    domain_layer: Arc<DomainLayer>,
+    infrastructure_layer: Arc<InfrastructureLayer>,
+    indexer_application: Arc<IndexerApplication>,
+}
+
+impl NetworkApplication {
+    /// # Description
+    ///     创建新的网络统计应用服务实例
+    /// # Params
+    ///     infrastructure_layer: Arc<InfrastructureLayer> - 基础设施层的引用，用于获取仓储和 RPC
+    ///     domain_layer: Arc<DomainLayer> - 领域层的引用，用于获取网络统计领域服务
+    ///     indexer_application: Arc<IndexerApplication> - 区块索引应用
+    /// # Return
+    ///     Self: 返回一个新的 `NetworkApplication` 实例
+    pub fn new(infrastructure_layer: Arc<InfrastructureLayer>, domain_layer: Arc<DomainLayer>, indexer_application: Arc<IndexerApplication>) -> Self {
+        Self { domain_layer, infrastructure_layer, indexer_application }
+    }
+
+    /// # Description
+    ///     启动后台采样任务，每轮采样到最新 slot 后清理超过保留时长的数据
+    pub fn spawn_sampler(self: &Arc<Self>) {
+        let config = self.infrastructure_layer.config.job.network.clone();
+        if !config.enabled {
+            info!("+ApplicationLayer [Network] sampler disabled.");
+            return;
+        }
+
+        let application = Arc::clone(self);
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(Duration::from_secs(config.interval.max(1)));
+            ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+            loop {
+                ticker.tick().await;
+                loop {
+                    match application.sample_latest().await {
+                        Ok(true) => continue,
+                        Ok(false) => break,
+                        Err(e) => {
+                            error!("-ApplicationLayer [Network] sample failed: {}", e);
+                            break;
+                        }
+                    }
+                }
+
+                if let Err(e) = application.prune().await {
+                    error!("-ApplicationLayer [Network] prune failed: {}", e);
+                }
+            }
+        });
+        info!("+ApplicationLayer [Network] sampler started.");
+    }
+
+    /// # Description
+    ///     从最新采样继续，并发拉取下一批区块并保存采样
+    /// # Return
+    ///     Result<bool, Report>: 是否仍落后于最新 slot
+    pub async fn sample_latest(&self) -> Result<bool, Report> {
+        let config = &self.infrastructure_layer.config.job.network;
+        let client = &self.infrastructure_layer.rpc.client;
+        let repository = &self.infrastructure_layer.persistence.repository.network_repository;
+
+        let tip = client.get_slot_with_commitment(CommitmentConfig::confirmed()).await?;
+        // 停机期间落后太多的区块不再补采，时间序列中留空
+        let floor = tip.saturating_sub(config.lookback_slots);
+        let last_sampled = match repository.find_latest().await? {
+            Some(sample) => (sample.slot as u64).max(floor),
+            None => floor,
+        };
+
+        let Some((start, end)) = self.domain_layer.indexer_domain.domain_service.next_range(Some(last_sampled), tip, config.batch_size) else {
+            return Ok(false);
+        };
+
+        let produced = client.get_blocks_with_commitment(start, Some(end), CommitmentConfig::confirmed()).await?;
+        let blocks: Vec<Result<(u64, EncodedConfirmedBlock), Report>> = futures::stream::iter(produced)
+            .map(|slot| async move { self.indexer_application.fetch_block(slot).await.map(|block| (slot, block)) })
+            .buffer_unordered(config.concurrency.max(1))
+            .collect()
+            .await;
+
+        let indexer_service = &self.domain_layer.indexer_domain.domain_service;
+        let network_service = &self.domain_layer.network_domain.domain_service;
+        let mut samples = Vec::new();
+        for result in blocks {
+            let (slot, block) = result?;
+            if let Some(sample) = network_service.summarize_block(slot, &block, |transaction| indexer_service.is_vote_transaction(transaction))? {
+                samples.push(sample);
+            }
+        }
+        repository.save_samples(samples).await?;
+
+        Ok(end < tip)
+    }
+
+    /// # Description
+    ///     查询滑动窗口内的网络吞吐统计，窗口以最新采样的出块时间为终点
+    /// # Params
+    ///     window: Option<i64> - 窗口长度（秒），默认取配置
+    ///     granularity: Option<i64> - 时间桶长度（秒），默认取配置
+    /// # Return
+    ///     Result<NetworkStats, Report>: 窗口汇总与时间序列
+    pub async fn get_stats(&self, window: Option<i64>, granularity: Option<i64>) -> Result<NetworkStats, Report> {
+        let config = &self.infrastructure_layer.config.job.network;
+        let repository = &self.infrastructure_layer.persistence.repository.network_repository;
+        let domain_service = &self.domain_layer.network_domain.domain_service;
+
+        let window = window.unwrap_or(config.default_window);
+        let granularity = granularity.unwrap_or(config.default_granularity);
+        domain_service.validate_query(window, granularity, config.retention, config.max_points)?;
+
+        let latest = repository.find_latest().await?
+            .ok_or_else(|| Report::msg("暂无网络采样数据"))?;
+        let window_end = latest.block_time + 1;
+        let samples = repository.find_since(window_end - window).await?;
+
+        Ok(domain_service.aggregate(&samples, window, granularity, window_end))
+    }
+
+    /// # Description
+    ///     计算最近一段时间内的用户（非投票）交易 TPS：先让采样追上最新 slot，再按窗口汇总已保存的采样
+    /// # Params
+    ///     threshold_seconds: i64 - 时间窗口（秒），早于采样回看范围且后台采样未开启时只统计已有的采样
+    /// # Return
+    ///     Result<f64, Report>: 窗口内每秒非投票交易量
+    pub async fn calculate_for_range(&self, threshold_seconds: i64) -> Result<f64, Report> {
+        while self.sample_latest().await? {}
+
+        let stats = self.get_stats(Some(threshold_seconds), Some(threshold_seconds)).await?;
+        info!(
+            "+ApplicationLayer [Network] {} non-vote tps over {} seconds (slots {:?}..{:?}).",
+            stats.summary.non_vote_tps, threshold_seconds, stats.first_slot, stats.last_slot,
+        );
+
+        Ok(stats.summary.non_vote_tps)
+    }
+
+    /// # Description
+    ///     删除超过保留时长的采样
+    /// # Return
+    ///     Result<(), Report>: 成功返回 Ok()，失败返回错误信息
+    async fn prune(&self) -> Result<(), Report> {
+        let repository = &self.infrastructure_layer.persistence.repository.network_repository;
+        let Some(latest) = repository.find_latest().await? else {
+            return Ok(());
+        };
+
+        let removed = repository.delete_before(latest.block_time - self.infrastructure_layer.config.job.network.retention).await?;
+        if removed > 0 {
+            info!("+ApplicationLayer [Network] pruned {} samples.", removed);
+        }
+
+        Ok(())
+    }
+}
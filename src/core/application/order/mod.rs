@@ -0,0 +1,3 @@
+mod order_application;
+
+pub use order_application::{OrderApplication, OrderDetail};
@@ -0,0 +1,370 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Duration;
+use chrono::{DateTime, Utc};
+use color_eyre::{Report, Result};
+use serde::Serialize;
+use serde_json::json;
+use solana_program::pubkey::Pubkey;
+use tokio::time::MissedTickBehavior;
+use tracing::{error, info, warn};
+use solana_sdk::signature::Signer;
+use crate::core::application::price::PriceApplication;
+use crate::core::application::wallet::WalletApplication;
+use crate::core::application::webhook::WebhookApplication;
+use crate::core::domain::DomainLayer;
+use crate::core::domain::order::entity::order_entity::{Model as OrderModel, OrderDirection, OrderStatus};
+use crate::core::domain::order::entity::order_event_entity::{Model as OrderEventModel};
+use crate::core::domain::order::repository::OrderRepositoryInterface;
+use crate::core::domain::order::service::OrderDecision;
+use crate::core::domain::wallet::repository::WalletRepositoryInterface;
+use crate::core::domain::webhook::entity::webhook_event_entity::WebhookEventType;
+use crate::core::infrastructure::InfrastructureLayer;
+
+/// 订单触发后完成兑换的时限（秒），超过时限仍停留在已触发状态说明执行被中断
+const TRIGGER_TIMEOUT: i64 = 600;
+
+/// # Description
+///     条件单详情，包含完整的状态变更记录
+/// # Fields
+///     order: OrderModel - 条件单
+///     events: Vec<OrderEventModel> - 状态变更记录
+#[derive(Debug, Serialize)]
+pub struct OrderDetail {
+    pub order: OrderModel,
+    pub events: Vec<OrderEventModel>,
+}
+
+/// # Description
+///     条件单应用服务，负责下单、撤单以及后台按预言机价格评估并执行兑换
+/// # Fields
+///     domain_layer: Arc<DomainLayer> - 领域层
+///     infrastructure_layer: Arc<InfrastructureLayer> - 基础设施层
+///     price_application: Arc<PriceApplication> - 价格应用，提供预言机价格
+///     webhook_application: Arc<WebhookApplication> - Webhook 通知应用，推送成交事件
+pub struct OrderApplication {
+    domain_layer: Arc<DomainLayer>,
+    infrastructure_layer: Arc<InfrastructureLayer>,
+    price_application: Arc<PriceApplication>,
+    webhook_application: Arc<WebhookApplication>,
+}
+
+impl OrderApplication {
+    /// # Description
+    ///     创建新的条件单应用服务实例
+    /// # Params
+    ///     infrastructure_layer: Arc<InfrastructureLayer> - 基础设施层的引用，用于获取仓储和 RPC
+    ///     domain_layer: Arc<DomainLayer> - 领域层的引用，用于获取条件单领域服务
+    ///     price_application: Arc<PriceApplication> - 价格应用的引用
+    ///     webhook_application: Arc<WebhookApplication> - Webhook 通知应用的引用
+    /// # Return
+    ///     Self: 返回一个新的 `OrderApplication` 实例
+    pub fn new(
+        infrastructure_layer: Arc<InfrastructureLayer>,
+        domain_layer: Arc<DomainLayer>,
+        price_application: Arc<PriceApplication>,
+        webhook_application: Arc<WebhookApplication>,
+    ) -> Self {
+        Self { domain_layer, infrastructure_layer, price_application, webhook_application }
+    }
+
+    /// # Description
+    ///     创建条件单，校验钱包归属后以 open 状态入库
+    /// # Params
+    ///     user_id: i32 - 用户id
+    ///     wallet_id: i32 - 执行兑换的钱包id
+    ///     input_mint: String - 卖出代币
+    ///     output_mint: String - 买入代币
+    ///     amount_in: f64 - 卖出数量
+    ///     trigger_price: f64 - 触发价格
+    ///     direction: OrderDirection - 触发方向
+    ///     max_slippage: f64 - 最大滑点比例
+    ///     expires_at: Option<DateTime<Utc>> - 过期时间
+    /// # Return
+    ///     Result<OrderModel, Report>: 成功返回条件单，失败返回错误信息
+    #[allow(clippy::too_many_arguments)]
+    pub async fn place_order(
+        &self,
+        user_id: i32,
+        wallet_id: i32,
+        input_mint: String,
+        output_mint: String,
+        amount_in: f64,
+        trigger_price: f64,
+        direction: OrderDirection,
+        max_slippage: f64,
+        expires_at: Option<DateTime<Utc>>,
+    ) -> Result<OrderModel, Report> {
+        Pubkey::from_str(&input_mint).map_err(|_| Report::msg("输入代币地址不合法"))?;
+        Pubkey::from_str(&output_mint).map_err(|_| Report::msg("输出代币地址不合法"))?;
+
+        let repository = &self.infrastructure_layer.persistence.repository;
+        let wallet = repository.wallet_repository.find_by_id(wallet_id as u64).await?
+            .ok_or_else(|| Report::msg("钱包不存在"))?;
+        if wallet.user_id != user_id || wallet.deleted_at.is_some() {
+            return Err(Report::msg("钱包不存在"));
+        }
+        if wallet.disable {
+            return Err(Report::msg("钱包已禁用"));
+        }
+
+        let slippage_limit = self.infrastructure_layer.config.job.order.max_slippage;
+        let new_order = self.domain_layer.order_domain.domain_service.create_order(
+            user_id,
+            wallet_id,
+            input_mint,
+            output_mint,
+            amount_in,
+            trigger_price,
+            direction,
+            max_slippage,
+            slippage_limit,
+            expires_at,
+        )?;
+
+        let order = repository.order_repository.save(new_order).await?;
+        self.record_event(&order, None, None).await;
+
+        Ok(order)
+    }
+
+    /// # Description
+    ///     取消用户自己的未触发条件单
+    /// # Params
+    ///     user_id: i32 - 用户id
+    ///     order_id: i32 - 条件单id
+    /// # Return
+    ///     Result<OrderModel, Report>: 成功返回取消后的条件单，失败返回错误信息
+    pub async fn cancel_order(&self, user_id: i32, order_id: i32) -> Result<OrderModel, Report> {
+        let mut order = self.find_user_order(user_id, order_id).await?;
+
+        order.cancel("用户取消".to_string()).map_err(Report::msg)?;
+        if !self.infrastructure_layer.persistence.repository.order_repository.save_if_status(order.clone(), OrderStatus::Open).await? {
+            return Err(Report::msg("订单已触发，无法取消"));
+        }
+        self.record_event(&order, None, order.error.clone()).await;
+
+        Ok(order)
+    }
+
+    /// # Description
+    ///     查询用户的全部条件单
+    /// # Params
+    ///     user_id: i32 - 用户id
+    /// # Return
+    ///     Result<Vec<OrderModel>, Report>: 成功返回条件单列表，失败返回错误信息
+    pub async fn list_orders(&self, user_id: i32) -> Result<Vec<OrderModel>, Report> {
+        self.infrastructure_layer.persistence.repository.order_repository.find_by_user(user_id).await
+    }
+
+    /// # Description
+    ///     查询条件单详情及状态变更记录
+    /// # Params
+    ///     user_id: i32 - 用户id
+    ///     order_id: i32 - 条件单id
+    /// # Return
+    ///     Result<OrderDetail, Report>: 成功返回条件单详情，失败返回错误信息
+    pub async fn get_order(&self, user_id: i32, order_id: i32) -> Result<OrderDetail, Report> {
+        let order = self.find_user_order(user_id, order_id).await?;
+        let events = self.infrastructure_layer.persistence.repository.order_repository.find_events(order.id).await?;
+
+        Ok(OrderDetail { order, events })
+    }
+
+    /// # Description
+    ///     启动后台评估任务，先恢复上次中断的已触发订单，再按配置的间隔评估所有挂单
+    /// # Params
+    ///     None
+    /// # Return
+    ///     None
+    pub fn spawn_evaluator(self: &Arc<Self>) {
+        let config = self.infrastructure_layer.config.job.order.clone();
+        if !config.enabled {
+            info!("+ApplicationLayer [Order] evaluator disabled.");
+            return;
+        }
+
+        let application = Arc::clone(self);
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(Duration::from_secs(config.interval.max(1)));
+            ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+            loop {
+                ticker.tick().await;
+                if let Err(e) = application.fail_interrupted_orders(Utc::now()).await {
+                    error!("-ApplicationLayer [Order] fail interrupted orders failed: {}", e);
+                }
+                if let Err(e) = application.evaluate_open_orders(config.batch_size).await {
+                    error!("-ApplicationLayer [Order] evaluate failed: {}", e);
+                }
+            }
+        });
+        info!("+ApplicationLayer [Order] evaluator started.");
+    }
+
+    /// # Description
+    ///     把停留在已触发状态超过执行时限的订单标记为失败。这类订单的兑换可能已经上链，
+    ///     重新放回等待触发会重复扣款，因此只标记失败并提示用户核对链上交易，不再自动执行
+    /// # Params
+    ///     now: DateTime<Utc> - 当前时间
+    /// # Return
+    ///     Result<(), Report>: 成功返回 Ok()，失败返回错误信息
+    pub async fn fail_interrupted_orders(&self, now: DateTime<Utc>) -> Result<(), Report> {
+        let repository = &self.infrastructure_layer.persistence.repository;
+        let cutoff = now - chrono::Duration::seconds(TRIGGER_TIMEOUT);
+        for mut order in repository.order_repository.find_triggered_before(cutoff).await? {
+            order.fail("执行中断，兑换结果未知，请核对链上交易".to_string()).map_err(Report::msg)?;
+            if repository.order_repository.save_if_status(order.clone(), OrderStatus::Triggered).await? {
+                self.record_event(&order, order.trigger_at_price, order.error.clone()).await;
+                warn!("-ApplicationLayer [Order] order {} marked failed after interrupted execution", order.id);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// # Description
+    ///     评估一批挂单：过期的取消，满足条件的触发并执行兑换；单个订单失败只记录日志，不影响其它订单
+    /// # Params
+    ///     batch_size: u64 - 本轮最多评估的挂单数量
+    /// # Return
+    ///     Result<(), Report>: 成功返回 Ok()，失败返回错误信息
+    pub async fn evaluate_open_orders(&self, batch_size: u64) -> Result<(), Report> {
+        let orders = self.infrastructure_layer.persistence.repository.order_repository.find_open(batch_size).await?;
+        if orders.is_empty() {
+            return Ok(());
+        }
+
+        // 同一轮内每个代币只取一次价格
+        let mut usd_prices: HashMap<String, Option<f64>> = HashMap::new();
+        for order in &orders {
+            for mint in [&order.input_mint, &order.output_mint] {
+                if !usd_prices.contains_key(mint) {
+                    let price = match self.price_application.get_price(mint).await {
+                        Ok(price) => Some(price.price),
+                        Err(e) => {
+                            warn!("-ApplicationLayer [Order] {} unpriced: {}", mint, e);
+                            None
+                        }
+                    };
+                    usd_prices.insert(mint.clone(), price);
+                }
+            }
+        }
+
+        let domain_service = &self.domain_layer.order_domain.domain_service;
+        let now = Utc::now();
+        for order in orders {
+            let price = match (usd_prices[&order.input_mint], usd_prices[&order.output_mint]) {
+                (Some(price_in), Some(price_out)) => domain_service.cross_price(price_in, price_out),
+                _ => None,
+            };
+
+            let order_id = order.id;
+            let result = match domain_service.evaluate(&order, price, now) {
+                OrderDecision::Hold => Ok(()),
+                OrderDecision::Expire => self.expire_order(order).await,
+                OrderDecision::Trigger(price) => self.execute_order(order, price).await,
+            };
+            if let Err(e) = result {
+                error!("-ApplicationLayer [Order] order {} evaluate failed: {}", order_id, e);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// # Description
+    ///     取消已过期的挂单
+    async fn expire_order(&self, mut order: OrderModel) -> Result<(), Report> {
+        order.cancel("订单已过期".to_string()).map_err(Report::msg)?;
+        if self.infrastructure_layer.persistence.repository.order_repository.save_if_status(order.clone(), OrderStatus::Open).await? {
+            self.record_event(&order, None, order.error.clone()).await;
+        }
+
+        Ok(())
+    }
+
+    /// # Description
+    ///     触发挂单并通过兑换路由执行，兑换结果写回订单
+    async fn execute_order(&self, mut order: OrderModel, price: f64) -> Result<(), Report> {
+        let repository = &self.infrastructure_layer.persistence.repository;
+
+        // 先抢占状态，用户已取消时直接跳过
+        order.trigger(price).map_err(Report::msg)?;
+        if !repository.order_repository.save_if_status(order.clone(), OrderStatus::Open).await? {
+            return Ok(());
+        }
+        self.record_event(&order, Some(price), None).await;
+        info!("+ApplicationLayer [Order] order {} triggered at {}", order.id, price);
+
+        match self.swap(&order).await {
+            Ok(signature) => {
+                order.fill(signature.clone()).map_err(Report::msg)?;
+                repository.order_repository.save_if_status(order.clone(), OrderStatus::Triggered).await?;
+                self.record_event(&order, Some(price), Some(signature.clone())).await;
+                self.webhook_application.publish(order.user_id, WebhookEventType::SwapFilled, json!({
+                    "source": "order",
+                    "order_id": order.id,
+                    "wallet_id": order.wallet_id,
+                    "input_mint": order.input_mint,
+                    "output_mint": order.output_mint,
+                    "amount_in": order.amount_in,
+                    "price": price,
+                    "signature": signature,
+                })).await;
+            }
+            Err(e) => {
+                warn!("-ApplicationLayer [Order] order {} failed: {}", order.id, e);
+                order.fail(e.to_string()).map_err(Report::msg)?;
+                repository.order_repository.save_if_status(order.clone(), OrderStatus::Triggered).await?;
+                self.record_event(&order, Some(price), order.error.clone()).await;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// # Description
+    ///     使用订单钱包执行兑换，最大滑点按百分比传给兑换路由；发送前以预言机价格校验报价的滑点
+    async fn swap(&self, order: &OrderModel) -> Result<String, Report> {
+        let wallet = self.infrastructure_layer.persistence.repository.wallet_repository.find_by_id(order.wallet_id as u64).await?
+            .ok_or_else(|| Report::msg("钱包不存在"))?;
+        if wallet.disable || wallet.deleted_at.is_some() {
+            return Err(Report::msg("钱包已禁用"));
+        }
+        let keypair = WalletApplication::load_keypair(&self.infrastructure_layer.keystore, &wallet)?;
+
+        let input_mint = Pubkey::from_str(&order.input_mint)?;
+        let output_mint = Pubkey::from_str(&order.output_mint)?;
+        let quote = WalletApplication::quote_swap(&keypair.pubkey(), &input_mint, &output_mint, order.amount_in, order.max_slippage * 100.0)
+            .await
+            .map_err(|e| Report::msg(format!("兑换报价失败: {}", e)))?;
+        self.price_application
+            .check_swap_slippage(&order.input_mint, &order.output_mint, order.amount_in, quote.output_amount, order.max_slippage)
+            .await?;
+
+        WalletApplication::execute_swap(&self.infrastructure_layer.rpc.client, &keypair, quote)
+            .await
+            .map_err(|e| Report::msg(format!("兑换失败: {}", e)))
+    }
+
+    /// # Description
+    ///     查询属于该用户的条件单
+    async fn find_user_order(&self, user_id: i32, order_id: i32) -> Result<OrderModel, Report> {
+        match self.infrastructure_layer.persistence.repository.order_repository.find_by_id(order_id).await? {
+            Some(order) if order.user_id == user_id => Ok(order),
+            _ => Err(Report::msg("订单不存在")),
+        }
+    }
+
+    /// # Description
+    ///     记录条件单状态变更，记录失败不影响主流程
+    async fn record_event(&self, order: &OrderModel, price: Option<f64>, message: Option<String>) {
+        let event = OrderEventModel::new(order.id, order.status.clone(), price, message);
+        if let Err(e) = self.infrastructure_layer.persistence.repository.order_repository.record_event(event).await {
+            warn!("-ApplicationLayer [Order] order {} event record failed: {}", order.id, e);
+        }
+    }
+}
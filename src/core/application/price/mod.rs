@@ -0,0 +1,3 @@
+mod price_application;
+
+pub use price_application::PriceApplication;
\ No newline at end of file
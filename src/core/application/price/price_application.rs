@@ -0,0 +1,98 @@
+use std::sync::Arc;
+use chrono::Utc;
+use color_eyre::{Report, Result};
+use futures::future::join_all;
+use tracing::warn;
+use crate::core::domain::DomainLayer;
+use crate::core::domain::price::entity::AggregatedPrice;
+use crate::core::infrastructure::InfrastructureLayer;
+
+/// # Description
+///     价格应用服务，先读缓存，未命中时向价格预言机的所有数据源取价并聚合
+/// # Fields
+///     domain_layer: Arc<DomainLayer> - 领域层
+///     infrastructure_layer: Arc<InfrastructureLayer> - 基础设施层
+pub struct PriceApplication {
+    domain_layer: Arc<DomainLayer>,
+    infrastructure_layer: Arc<InfrastructureLayer>
+}
+
+impl PriceApplication {
+    /// # Description
+    ///     创建新的价格应用服务实例
+    /// # Params
+    ///     infrastructure_layer: Arc<InfrastructureLayer> - 基础设施层的引用，用于获取价格预言机
+    ///     domain_layer: Arc<DomainLayer> - 领域层的引用，用于获取价格领域服务
+    /// # Return
+    ///     Self: 返回一个新的 `PriceApplication` 实例
+    pub fn new(infrastructure_layer: Arc<InfrastructureLayer>, domain_layer: Arc<DomainLayer>) -> Self {
+        Self { domain_layer, infrastructure_layer }
+    }
+
+    /// # Description
+    ///     获取单个代币的聚合价格
+    /// # Params
+    ///     mint: &str - 代币 mint 地址
+    /// # Return
+    ///     Result<AggregatedPrice, Report>: 成功返回聚合价格，失败返回错误信息
+    pub async fn get_price(&self, mint: &str) -> Result<AggregatedPrice, Report> {
+        let oracle = &self.infrastructure_layer.oracle;
+
+        // 优先读取缓存，缓存异常时降级为实时查询
+        match oracle.cache.get(mint).await {
+            Ok(Some(price)) => return Ok(price),
+            Ok(None) => {}
+            Err(e) => warn!("-ApplicationLayer [Price] cache read {} failed: {}", mint, e),
+        }
+
+        // 查询所有数据源并聚合
+        let quotes = oracle.fetch_quotes(mint).await;
+        let price = self.domain_layer.price_domain.domain_service.aggregate(mint, quotes, &oracle.policy, Utc::now())?;
+
+        if let Err(e) = oracle.cache.set(&price, oracle.cache_ttl).await {
+            warn!("-ApplicationLayer [Price] cache write {} failed: {}", mint, e);
+        }
+
+        Ok(price)
+    }
+
+    /// # Description
+    ///     批量获取代币价格，无法定价的代币会被跳过
+    /// # Params
+    ///     mints: Vec<String> - 代币 mint 地址列表
+    /// # Return
+    ///     Result<Vec<AggregatedPrice>, Report>: 成功返回聚合价格列表，失败返回错误信息
+    pub async fn get_prices(&self, mut mints: Vec<String>) -> Result<Vec<AggregatedPrice>, Report> {
+        mints.sort();
+        mints.dedup();
+
+        let results = join_all(mints.iter().map(|mint| self.get_price(mint))).await;
+
+        let mut prices = Vec::with_capacity(results.len());
+        for (mint, result) in mints.iter().zip(results) {
+            match result {
+                Ok(price) => prices.push(price),
+                Err(e) => warn!("-ApplicationLayer [Price] {} unpriced: {}", mint, e),
+            }
+        }
+
+        Ok(prices)
+    }
+
+    /// # Description
+    ///     以预言机价格校验兑换报价的滑点
+    /// # Params
+    ///     input_mint: &str - 输入代币 mint 地址
+    ///     output_mint: &str - 输出代币 mint 地址
+    ///     amount_in: f64 - 输入代币数量
+    ///     quoted_out: f64 - 兑换路由给出的输出数量
+    ///     max_slippage: f64 - 最大允许滑点比例
+    /// # Return
+    ///     Result<f64, Report>: 成功返回实际滑点比例，超过上限或无法定价时返回错误信息
+    pub async fn check_swap_slippage(&self, input_mint: &str, output_mint: &str, amount_in: f64, quoted_out: f64, max_slippage: f64) -> Result<f64, Report> {
+        let price_in = self.get_price(input_mint).await?;
+        let price_out = self.get_price(output_mint).await?;
+
+        self.domain_layer.price_domain.domain_service.check_slippage(amount_in, price_in.price, price_out.price, quoted_out, max_slippage)
+    }
+}
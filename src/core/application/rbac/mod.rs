@@ -0,0 +1,3 @@
+mod rbac_application;
+
+pub use rbac_application::{RbacApplication, RoleDetail, UserRoles};
\ No newline at end of file
@@ -0,0 +1,323 @@
+use std::sync::Arc;
+use color_eyre::{Report, Result};
+use sea_orm::{ActiveModelTrait, IntoActiveModel};
+use serde::Serialize;
+use tracing::{error, info, warn};
+use crate::core::domain::DomainLayer;
+use crate::core::domain::user::entity::admin_audit_entity::ActiveModel as AdminAuditActiveModel;
+use crate::core::domain::user::entity::permission_entity::{Model as PermissionModel, ADMIN_ROLE, BUILTIN_PERMISSIONS};
+use crate::core::domain::user::entity::role_entity::Model as RoleModel;
+use crate::core::domain::user::repository_interface::{RoleRepositoryInterface, UserRepositoryInterface};
+use crate::core::infrastructure::InfrastructureLayer;
+
+/// # Description
+///     角色详情
+/// # Fields
+///     role: RoleModel - 角色
+///     permissions: Vec<String> - 角色拥有的权限编码
+#[derive(Debug, Serialize)]
+pub struct RoleDetail {
+    pub role: RoleModel,
+    pub permissions: Vec<String>,
+}
+
+/// # Description
+///     用户的角色与权限
+/// # Fields
+///     user_id: i32 - 用户id
+///     roles: Vec<String> - 角色名
+///     permissions: Vec<String> - 经由角色获得的权限编码
+#[derive(Debug, Serialize)]
+pub struct UserRoles {
+    pub user_id: i32,
+    pub roles: Vec<String>,
+    pub permissions: Vec<String>,
+}
+
+/// # Description
+///     角色权限应用服务：管理角色、权限及其分配，并为路由权限校验提供查询
+/// # Fields
+///     domain_layer: Arc<DomainLayer> - 领域层
+///     infrastructure_layer: Arc<InfrastructureLayer> - 基础设施层
+pub struct RbacApplication {
+    domain_layer: Arc<DomainLayer>,
+    infrastructure_layer: Arc<InfrastructureLayer>,
+}
+
+impl RbacApplication {
+    /// # Description
+    ///     创建新的角色权限应用服务实例
+    /// # Params
+    ///     infrastructure_layer: Arc<InfrastructureLayer> - 基础设施层的引用，用于获取仓储与配置
+    ///     domain_layer: Arc<DomainLayer> - 领域层的引用，用于获取用户领域服务
+    /// # Return
+    ///     Self: 返回一个新的 `RbacApplication` 实例
+    pub fn new(infrastructure_layer: Arc<InfrastructureLayer>, domain_layer: Arc<DomainLayer>) -> Self {
+        Self { domain_layer, infrastructure_layer }
+    }
+
+    /// # Description
+    ///     启动后台初始化任务：写入内置权限与 admin 角色，并为配置的账号授予 admin 角色
+    pub fn spawn_bootstrap(self: &Arc<Self>) {
+        let application = Arc::clone(self);
+        tokio::spawn(async move {
+            match application.bootstrap().await {
+                Ok(_) => info!("+ApplicationLayer [Rbac] builtin roles ready."),
+                Err(e) => error!("-ApplicationLayer [Rbac] bootstrap failed: {}", e),
+            }
+        });
+    }
+
+    /// # Description
+    ///     写入缺失的内置权限，确保内置 admin 角色拥有全部内置权限，并为配置的账号追加 admin 角色
+    /// # Return
+    ///     Result<(), Report>: 初始化结果
+    pub async fn bootstrap(&self) -> Result<(), Report> {
+        let repository = &self.infrastructure_layer.persistence.repository;
+
+        let codes: Vec<String> = BUILTIN_PERMISSIONS.iter().map(|(code, _)| code.to_string()).collect();
+        let existing: Vec<String> = repository.role_repository.find_permissions_by_codes(&codes).await?
+            .into_iter()
+            .map(|permission| permission.code)
+            .collect();
+        for (code, description) in BUILTIN_PERMISSIONS {
+            if !existing.iter().any(|existing| existing == code) {
+                repository.role_repository.save_permission(PermissionModel::new(code, Some(description.to_string()))).await?;
+            }
+        }
+
+        let admin = match repository.role_repository.find_role_by_name(ADMIN_ROLE).await? {
+            Some(role) => role,
+            None => repository.role_repository.save_role(RoleModel::create_role(ADMIN_ROLE, Some("内置管理员".to_string()), true)).await?,
+        };
+        let mut permission_ids: Vec<i32> = repository.role_repository.find_role_permissions(admin.id).await?
+            .into_iter()
+            .map(|permission| permission.id)
+            .collect();
+        for permission in repository.role_repository.find_permissions_by_codes(&codes).await? {
+            if !permission_ids.contains(&permission.id) {
+                permission_ids.push(permission.id);
+            }
+        }
+        repository.role_repository.set_role_permissions(admin.id, permission_ids).await?;
+
+        for account in &self.infrastructure_layer.config.rbac.admin_accounts {
+            let Some(user) = repository.user_repository.find_by_account(account.clone()).await? else {
+                warn!("-ApplicationLayer [Rbac] admin account {} not found.", account);
+                continue;
+            };
+
+            let mut role_ids: Vec<i32> = repository.role_repository.find_user_roles(user.id).await?
+                .into_iter()
+                .map(|role| role.id)
+                .collect();
+            if !role_ids.contains(&admin.id) {
+                role_ids.push(admin.id);
+                repository.role_repository.set_user_roles(user.id, role_ids, None).await?;
+                info!("+ApplicationLayer [Rbac] admin role granted to account {}.", account);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// # Description
+    ///     检查用户是否拥有权限
+    /// # Params
+    ///     user_id: i32 - 用户id
+    ///     permission: &str - 权限编码
+    /// # Return
+    ///     Result<bool, Report>: 是否拥有
+    pub async fn has_permission(&self, user_id: i32, permission: &str) -> Result<bool, Report> {
+        let permissions = self.infrastructure_layer.persistence.repository.role_repository.find_user_permissions(user_id).await?;
+
+        Ok(self.domain_layer.user_domain.domain_service.has_permission(&permissions, permission))
+    }
+
+    /// # Description
+    ///     查询全部角色及其权限
+    /// # Return
+    ///     Result<Vec<RoleDetail>, Report>: 角色列表
+    pub async fn list_roles(&self) -> Result<Vec<RoleDetail>, Report> {
+        let roles = self.infrastructure_layer.persistence.repository.role_repository.find_roles().await?;
+
+        let mut details = Vec::with_capacity(roles.len());
+        for role in roles {
+            details.push(self.role_detail(role).await?);
+        }
+
+        Ok(details)
+    }
+
+    /// # Description
+    ///     创建角色
+    /// # Params
+    ///     name: String - 角色名
+    ///     description: Option<String> - 角色说明
+    ///     permissions: Vec<String> - 权限编码
+    /// # Return
+    ///     Result<RoleDetail, Report>: 新建的角色
+    pub async fn create_role(&self, name: String, description: Option<String>, permissions: Vec<String>) -> Result<RoleDetail, Report> {
+        let repository = &self.infrastructure_layer.persistence.repository;
+        let name = self.domain_layer.user_domain.domain_service.normalize_role_name(&name)?;
+        if repository.role_repository.find_role_by_name(&name).await?.is_some() {
+            return Err(Report::msg(format!("角色 {} 已存在", name)));
+        }
+        let permission_ids = self.permission_ids(&permissions).await?;
+
+        let role = repository.role_repository.save_role(RoleModel::create_role(&name, description, false)).await?;
+        repository.role_repository.set_role_permissions(role.id, permission_ids).await?;
+
+        self.role_detail(role).await
+    }
+
+    /// # Description
+    ///     更新角色说明与权限，权限整体替换
+    /// # Params
+    ///     role_id: i32 - 角色id
+    ///     description: Option<String> - 角色说明
+    ///     permissions: Vec<String> - 权限编码
+    /// # Return
+    ///     Result<RoleDetail, Report>: 更新后的角色
+    pub async fn update_role(&self, role_id: i32, description: Option<String>, permissions: Vec<String>) -> Result<RoleDetail, Report> {
+        let repository = &self.infrastructure_layer.persistence.repository;
+        let role = repository.role_repository.find_role(role_id).await?
+            .ok_or_else(|| Report::msg("角色不存在"))?;
+        let permission_ids = self.permission_ids(&permissions).await?;
+
+        let role = repository.role_repository.save_role(role.update_description(description).into_active_model().reset_all()).await?;
+        repository.role_repository.set_role_permissions(role.id, permission_ids).await?;
+
+        self.role_detail(role).await
+    }
+
+    /// # Description
+    ///     删除角色，内置角色不能删除
+    /// # Params
+    ///     role_id: i32 - 角色id
+    /// # Return
+    ///     Result<(), Report>: 删除结果
+    pub async fn delete_role(&self, role_id: i32) -> Result<(), Report> {
+        let repository = &self.infrastructure_layer.persistence.repository;
+        let role = repository.role_repository.find_role(role_id).await?
+            .ok_or_else(|| Report::msg("角色不存在"))?;
+        if role.builtin {
+            return Err(Report::msg("内置角色不能删除"));
+        }
+
+        repository.role_repository.delete_role(role.id).await
+    }
+
+    /// # Description
+    ///     查询全部权限
+    /// # Return
+    ///     Result<Vec<PermissionModel>, Report>: 权限列表
+    pub async fn list_permissions(&self) -> Result<Vec<PermissionModel>, Report> {
+        self.infrastructure_layer.persistence.repository.role_repository.find_permissions().await
+    }
+
+    /// # Description
+    ///     创建权限
+    /// # Params
+    ///     code: String - 权限编码
+    ///     description: Option<String> - 权限说明
+    /// # Return
+    ///     Result<PermissionModel, Report>: 新建的权限
+    pub async fn create_permission(&self, code: String, description: Option<String>) -> Result<PermissionModel, Report> {
+        let repository = &self.infrastructure_layer.persistence.repository;
+        let code = self.domain_layer.user_domain.domain_service.normalize_permission_code(&code)?;
+        if !repository.role_repository.find_permissions_by_codes(std::slice::from_ref(&code)).await?.is_empty() {
+            return Err(Report::msg(format!("权限 {} 已存在", code)));
+        }
+
+        repository.role_repository.save_permission(PermissionModel::new(&code, description)).await
+    }
+
+    /// # Description
+    ///     查询用户的角色与权限
+    /// # Params
+    ///     user_id: i32 - 用户id
+    /// # Return
+    ///     Result<UserRoles, Report>: 用户的角色与权限
+    pub async fn user_roles(&self, user_id: i32) -> Result<UserRoles, Report> {
+        let repository = &self.infrastructure_layer.persistence.repository;
+        if repository.user_repository.find_by_id(user_id).await?.is_none() {
+            return Err(Report::msg("用户不存在"));
+        }
+
+        let roles = repository.role_repository.find_user_roles(user_id).await?;
+        let permissions = repository.role_repository.find_user_permissions(user_id).await?;
+
+        Ok(UserRoles {
+            user_id,
+            roles: roles.into_iter().map(|role| role.name).collect(),
+            permissions,
+        })
+    }
+
+    /// # Description
+    ///     设置用户的角色，整体替换
+    /// # Params
+    ///     user_id: i32 - 用户id
+    ///     roles: Vec<String> - 角色名
+    ///     audit: Option<AdminAuditActiveModel> - 管理后台操作时与角色在同一事务写入的审计记录
+    /// # Return
+    ///     Result<UserRoles, Report>: 设置后的角色与权限
+    pub async fn assign_roles(&self, user_id: i32, roles: Vec<String>, audit: Option<AdminAuditActiveModel>) -> Result<UserRoles, Report> {
+        let repository = &self.infrastructure_layer.persistence.repository;
+        let user_service = &self.domain_layer.user_domain.domain_service;
+        if repository.user_repository.find_by_id(user_id).await?.is_none() {
+            return Err(Report::msg("用户不存在"));
+        }
+
+        let mut names = Vec::with_capacity(roles.len());
+        for role in &roles {
+            let name = user_service.normalize_role_name(role)?;
+            if !names.contains(&name) {
+                names.push(name);
+            }
+        }
+        let found = repository.role_repository.find_roles_by_names(&names).await?;
+        let missing = user_service.missing(&names, &found.iter().map(|role| role.name.clone()).collect::<Vec<_>>());
+        if !missing.is_empty() {
+            return Err(Report::msg(format!("角色不存在: {}", missing.join(", "))));
+        }
+
+        repository.role_repository.set_user_roles(user_id, found.into_iter().map(|role| role.id).collect(), audit).await?;
+        info!("+ApplicationLayer [Rbac] roles of user {} set to [{}].", user_id, names.join(", "));
+
+        self.user_roles(user_id).await
+    }
+
+    /// # Description
+    ///     把权限编码解析为权限id，存在未知编码时返回错误
+    async fn permission_ids(&self, permissions: &[String]) -> Result<Vec<i32>, Report> {
+        let user_service = &self.domain_layer.user_domain.domain_service;
+
+        let mut codes = Vec::with_capacity(permissions.len());
+        for permission in permissions {
+            let code = user_service.normalize_permission_code(permission)?;
+            if !codes.contains(&code) {
+                codes.push(code);
+            }
+        }
+        let found = self.infrastructure_layer.persistence.repository.role_repository.find_permissions_by_codes(&codes).await?;
+        let missing = user_service.missing(&codes, &found.iter().map(|permission| permission.code.clone()).collect::<Vec<_>>());
+        if !missing.is_empty() {
+            return Err(Report::msg(format!("权限不存在: {}", missing.join(", "))));
+        }
+
+        Ok(found.into_iter().map(|permission| permission.id).collect())
+    }
+
+    /// # Description
+    ///     组装角色详情
+    async fn role_detail(&self, role: RoleModel) -> Result<RoleDetail, Report> {
+        let permissions = self.infrastructure_layer.persistence.repository.role_repository.find_role_permissions(role.id).await?
+            .into_iter()
+            .map(|permission| permission.code)
+            .collect();
+
+        Ok(RoleDetail { role, permissions })
+    }
+}
@@ -0,0 +1,3 @@
+mod reorg_application;
+
+pub use reorg_application::ReorgApplication;
@@ -0,0 +1,256 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Duration;
+use color_eyre::{Report, Result};
+use serde_json::json;
+use solana_client::rpc_config::RpcBlockConfig;
+use solana_sdk::commitment_config::CommitmentConfig;
+use solana_sdk::signature::Signature;
+use solana_transaction_status::{TransactionDetails, UiTransactionEncoding};
+use tokio::sync::Mutex;
+use tokio::time::MissedTickBehavior;
+use tracing::{error, info, warn};
+use crate::core::application::indexer::IndexerApplication;
+use crate::core::application::stream::StreamApplication;
+use crate::core::domain::DomainLayer;
+use crate::core::domain::indexer::repository::IndexerRepositoryInterface;
+use crate::core::domain::reorg::entity::commitment_entity::{BlockFinality, CommitmentLevel, DepositFinality};
+use crate::core::domain::reorg::entity::ledger_rollback_entity::Model as LedgerRollbackModel;
+use crate::core::domain::reorg::repository::LedgerRollbackRepositoryInterface;
+use crate::core::domain::stream::entity::stream_event_entity::StreamTopic;
+use crate::core::domain::watcher::entity::deposit_entity::Model as DepositModel;
+use crate::core::domain::watcher::repository::DepositRepositoryInterface;
+use crate::core::infrastructure::InfrastructureLayer;
+
+/// # Description
+///     getSignatureStatuses 单次最多查询的签名数量
+const SIGNATURE_STATUS_LIMIT: usize = 256;
+
+/// # Description
+///     单次查询回滚记录的默认数量与上限
+const DEFAULT_ROLLBACK_LIMIT: u64 = 50;
+const MAX_ROLLBACK_LIMIT: u64 = 200;
+
+/// # Description
+///     最终确认与分叉回滚应用服务。索引与充值按 confirmed 级别写入，本服务定期与 finalized 链核对：
+///     一致的数据提升为 finalized；已索引区块所在分叉被丢弃时标记为事后跳过，漏记的区块重新索引；
+///     交易被丢弃的充值扣回入账金额并写入审计记录
+/// # Fields
+///     domain_layer: Arc<DomainLayer> - 领域层
+///     infrastructure_layer: Arc<InfrastructureLayer> - 基础设施层
+///     indexer_application: Arc<IndexerApplication> - 区块索引应用，用于重新拉取漏记的区块
+///     stream_application: Arc<StreamApplication> - 实时推送应用，入账回滚推送给在线连接
+///     misses: Mutex<HashMap<i32, u32>> - 每笔充值已连续确认不在最终确认链上的轮数
+pub struct ReorgApplication {
+    domain_layer: Arc<DomainLayer>,
+    infrastructure_layer: Arc<InfrastructureLayer>,
+    indexer_application: Arc<IndexerApplication>,
+    stream_application: Arc<StreamApplication>,
+    misses: Mutex<HashMap<i32, u32>>,
+}
+
+impl ReorgApplication {
+    /// # Description
+    ///     创建新的最终确认与分叉回滚应用服务实例
+    /// # Params
+    ///     infrastructure_layer: Arc<InfrastructureLayer> - 基础设施层的引用，用于获取仓储、配置和 RPC
+    ///     domain_layer: Arc<DomainLayer> - 领域层的引用，用于获取分叉回滚领域服务
+    ///     indexer_application: Arc<IndexerApplication> - 区块索引应用的引用
+    ///     stream_application: Arc<StreamApplication> - 实时推送应用的引用
+    /// # Return
+    ///     Self: 返回一个新的 `ReorgApplication` 实例
+    pub fn new(
+        infrastructure_layer: Arc<InfrastructureLayer>,
+        domain_layer: Arc<DomainLayer>,
+        indexer_application: Arc<IndexerApplication>,
+        stream_application: Arc<StreamApplication>,
+    ) -> Self {
+        Self { domain_layer, infrastructure_layer, indexer_application, stream_application, misses: Mutex::new(HashMap::new()) }
+    }
+
+    /// # Description
+    ///     启动后台核对任务
+    pub fn spawn_finalizer(self: &Arc<Self>) {
+        let config = self.infrastructure_layer.config.job.reorg.clone();
+        if !config.enabled {
+            info!("+ApplicationLayer [Reorg] finalizer disabled.");
+            return;
+        }
+
+        let application = Arc::clone(self);
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(Duration::from_secs(config.interval.max(1)));
+            ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+            loop {
+                ticker.tick().await;
+                if let Err(e) = application.finalize_blocks().await {
+                    error!("-ApplicationLayer [Reorg] block finalization failed: {}", e);
+                }
+                if let Err(e) = application.finalize_deposits().await {
+                    error!("-ApplicationLayer [Reorg] deposit finalization failed: {}", e);
+                }
+            }
+        });
+        info!("+ApplicationLayer [Reorg] finalizer started.");
+    }
+
+    /// # Description
+    ///     核对一批尚未最终确认的已索引区块
+    /// # Return
+    ///     Result<usize, Report>: 本轮核对的区块数量
+    pub async fn finalize_blocks(&self) -> Result<usize, Report> {
+        let client = &self.infrastructure_layer.rpc.client;
+        let repository = &self.infrastructure_layer.persistence.repository.indexer_repository;
+        let domain_service = &self.domain_layer.reorg_domain.domain_service;
+        let batch_size = self.infrastructure_layer.config.job.reorg.batch_size.max(1);
+
+        let finalized_slot = client.get_slot_with_commitment(CommitmentConfig::finalized()).await?;
+        let blocks = repository.find_unfinalized_blocks(finalized_slot, batch_size).await?;
+        let (Some(first), Some(last)) = (blocks.first(), blocks.last()) else {
+            return Ok(0);
+        };
+
+        let mut finalized = client
+            .get_blocks_with_commitment(first.slot as u64, Some(last.slot as u64), CommitmentConfig::finalized())
+            .await?;
+        finalized.sort_unstable();
+
+        let mut promoted = Vec::with_capacity(blocks.len());
+        for block in &blocks {
+            match domain_service.block_finality(block, &finalized) {
+                BlockFinality::Finalized => promoted.push(block.slot),
+                BlockFinality::Dropped => {
+                    repository.mark_dropped(block.slot as u64).await?;
+                    warn!("-ApplicationLayer [Reorg] slot {} ({:?}) dropped by fork, marked skipped", block.slot, block.blockhash);
+                }
+                BlockFinality::Missed => {
+                    let slot = block.slot as u64;
+                    let fetched = self.indexer_application.fetch_block(slot).await?;
+                    let (indexed_block, transactions) = self.domain_layer.indexer_domain.domain_service.build_block(slot, &fetched)?;
+                    repository.save_block(indexed_block, transactions).await?;
+                    promoted.push(block.slot);
+                    warn!("-ApplicationLayer [Reorg] slot {} recorded as skipped but finalized with a block, re-indexed", block.slot);
+                }
+            }
+        }
+        repository.set_commitment(promoted, CommitmentLevel::Finalized).await?;
+
+        Ok(blocks.len())
+    }
+
+    /// # Description
+    ///     核对一批尚未最终确认的充值：交易已最终确认的提升级别，交易被丢弃或最终执行失败的回滚入账
+    /// # Return
+    ///     Result<usize, Report>: 本轮回滚的充值笔数
+    pub async fn finalize_deposits(&self) -> Result<usize, Report> {
+        let client = &self.infrastructure_layer.rpc.client;
+        let repository = &self.infrastructure_layer.persistence.repository.deposit_repository;
+        let domain_service = &self.domain_layer.reorg_domain.domain_service;
+        let batch_size = self.infrastructure_layer.config.job.reorg.batch_size.max(1);
+
+        let finalized_slot = client.get_slot_with_commitment(CommitmentConfig::finalized()).await?;
+        let deposits = repository.find_unfinalized(finalized_slot, batch_size).await?;
+
+        let required_misses = self.infrastructure_layer.config.job.reorg.drop_confirmations;
+
+        let mut misses = self.misses.lock().await;
+        let mut rolled_back = 0;
+        for chunk in deposits.chunks(SIGNATURE_STATUS_LIMIT) {
+            let signatures = chunk.iter()
+                .map(|deposit| Signature::from_str(&deposit.signature))
+                .collect::<Result<Vec<Signature>, _>>()?;
+            let statuses = client.get_signature_statuses_with_history(&signatures).await?.value;
+
+            for (deposit, status) in chunk.iter().zip(statuses) {
+                let mut status = status.map(|status| (
+                    status.slot,
+                    status.err.as_ref().map(|e| e.to_string()),
+                    status.satisfies_commitment(CommitmentConfig::finalized()),
+                ));
+                // 节点可能未保留交易历史，查不到状态时再到入账 slot 的最终确认区块中核对
+                let mut missed = 0;
+                if status.is_none() && deposit.slot as u64 <= finalized_slot {
+                    if self.in_finalized_block(deposit).await? {
+                        status = Some((deposit.slot as u64, None, true));
+                    } else {
+                        let count = misses.entry(deposit.id).or_insert(0);
+                        *count += 1;
+                        missed = *count;
+                    }
+                }
+                if status.is_some() {
+                    misses.remove(&deposit.id);
+                }
+
+                match domain_service.deposit_finality(deposit.slot as u64, status, finalized_slot, missed, required_misses) {
+                    Some(DepositFinality::Finalized { slot }) => repository.finalize(deposit.id, slot).await?,
+                    Some(DepositFinality::Dropped { reason }) => {
+                        misses.remove(&deposit.id);
+                        if self.rollback(deposit, reason).await?.is_some() {
+                            rolled_back += 1;
+                        }
+                    }
+                    None => {}
+                }
+            }
+        }
+
+        Ok(rolled_back)
+    }
+
+    /// # Description
+    ///     查询入账回滚审计记录
+    /// # Params
+    ///     user_id: Option<i32> - 所属用户，None 查询全部
+    ///     limit: Option<u64> - 返回数量
+    /// # Return
+    ///     Result<Vec<LedgerRollbackModel>, Report>: 审计记录列表
+    pub async fn list_rollbacks(&self, user_id: Option<i32>, limit: Option<u64>) -> Result<Vec<LedgerRollbackModel>, Report> {
+        let limit = limit.unwrap_or(DEFAULT_ROLLBACK_LIMIT).clamp(1, MAX_ROLLBACK_LIMIT);
+        self.infrastructure_layer.persistence.repository.ledger_rollback_repository.list(user_id, limit).await
+    }
+
+    /// # Description
+    ///     判断充值交易是否在入账 slot 的最终确认区块中
+    /// # Return
+    ///     Result<bool, Report>: 是否在区块中，该 slot 在最终确认链上被跳过时为 false
+    async fn in_finalized_block(&self, deposit: &DepositModel) -> Result<bool, Report> {
+        let client = &self.infrastructure_layer.rpc.client;
+        let slot = deposit.slot as u64;
+
+        let produced = client.get_blocks_with_commitment(slot, Some(slot), CommitmentConfig::finalized()).await?;
+        if !produced.contains(&slot) {
+            return Ok(false);
+        }
+
+        let config = RpcBlockConfig {
+            encoding: Some(UiTransactionEncoding::Base64),
+            transaction_details: Some(TransactionDetails::Signatures),
+            rewards: Some(false),
+            commitment: Some(CommitmentConfig::finalized()),
+            max_supported_transaction_version: Some(0),
+        };
+        let block = client.get_block_with_config(slot, config).await
+            .map_err(|e| Report::msg(format!("获取区块 {} 失败: {}", slot, e)))?;
+
+        Ok(block.signatures.is_some_and(|signatures| signatures.contains(&deposit.signature)))
+    }
+
+    /// # Description
+    ///     回滚一笔充值：在同一事务中按钱包当前余额扣回入账金额，已转出无法扣回的部分记为差额
+    async fn rollback(&self, deposit: &DepositModel, reason: String) -> Result<Option<LedgerRollbackModel>, Report> {
+        let Some(rollback) = self.infrastructure_layer.persistence.repository.ledger_rollback_repository.rollback(deposit, reason).await? else {
+            return Ok(None);
+        };
+
+        warn!(
+            "-ApplicationLayer [Reorg] deposit {} to wallet {} rolled back: {} (debited {} SOL, shortfall {} SOL)",
+            rollback.signature, rollback.wallet_id, rollback.reason, rollback.debited, rollback.shortfall,
+        );
+        self.stream_application.publish(StreamTopic::Transaction, Some(rollback.user_id), "deposit_rolled_back", json!(rollback));
+
+        Ok(Some(rollback))
+    }
+}
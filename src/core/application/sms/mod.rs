@@ -0,0 +1,3 @@
+mod sms_application;
+
+pub use sms_application::SmsApplication;
\ No newline at end of file
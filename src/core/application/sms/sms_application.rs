@@ -0,0 +1,97 @@
+use std::sync::Arc;
+use color_eyre::{Report, Result};
+use tracing::{error, info};
+use crate::core::application::verification::VerificationApplication;
+use crate::core::domain::DomainLayer;
+use crate::core::domain::sms::entity::sms_entity::SmsTemplate;
+use crate::core::domain::verification::entity::verification_code_entity::{VerificationChannel, VerificationPurpose};
+use crate::core::infrastructure::InfrastructureLayer;
+
+/// # Description
+///     短信应用服务：规范化手机号，发送与校验手机验证码
+/// # Fields
+///     domain_layer: Arc<DomainLayer> - 领域层
+///     infrastructure_layer: Arc<InfrastructureLayer> - 基础设施层
+///     verification_application: Arc<VerificationApplication> - 验证码应用
+pub struct SmsApplication {
+    domain_layer: Arc<DomainLayer>,
+    infrastructure_layer: Arc<InfrastructureLayer>,
+    verification_application: Arc<VerificationApplication>,
+}
+
+impl SmsApplication {
+    /// # Description
+    ///     创建新的短信应用服务实例
+    /// # Params
+    ///     infrastructure_layer: Arc<InfrastructureLayer> - 基础设施层的引用，用于获取短信发送组件
+    ///     domain_layer: Arc<DomainLayer> - 领域层的引用，用于获取短信与验证码领域服务
+    ///     verification_application: Arc<VerificationApplication> - 验证码应用
+    /// # Return
+    ///     Self: 返回一个新的 `SmsApplication` 实例
+    pub fn new(infrastructure_layer: Arc<InfrastructureLayer>, domain_layer: Arc<DomainLayer>, verification_application: Arc<VerificationApplication>) -> Self {
+        Self { domain_layer, infrastructure_layer, verification_application }
+    }
+
+    /// # Description
+    ///     校验并把手机号规范化为 E.164 格式
+    /// # Params
+    ///     mobile: &str - 手机号
+    /// # Return
+    ///     Result<String, Report>: 规范化后的手机号
+    pub fn normalize_mobile(&self, mobile: &str) -> Result<String, Report> {
+        self.domain_layer.sms_domain.domain_service
+            .normalize_mobile(mobile, &self.infrastructure_layer.config.sms.default_country_code)
+    }
+
+    /// # Description
+    ///     向手机号发送验证码，新验证码发出后旧验证码立即作废
+    /// # Params
+    ///     mobile: &str - 已规范化的手机号
+    ///     purpose: VerificationPurpose - 用途，仅支持注册、登录与绑定手机号
+    /// # Return
+    ///     Result<(), Report>: 发送结果
+    pub async fn send_code(&self, mobile: &str, purpose: VerificationPurpose) -> Result<(), Report> {
+        let config = &self.infrastructure_layer.config.sms;
+
+        let code = self.domain_layer.verification_domain.domain_service.generate_code(config.code_length);
+        let ttl_minutes = config.code_ttl.div_ceil(60);
+        let template = match purpose {
+            VerificationPurpose::Register => SmsTemplate::RegisterCode { code: code.clone(), ttl_minutes },
+            VerificationPurpose::Login => SmsTemplate::LoginCode { code: code.clone(), ttl_minutes },
+            VerificationPurpose::BindMobile => SmsTemplate::BindMobileCode { code: code.clone(), ttl_minutes },
+            _ => return Err(Report::msg("不支持的短信验证码用途")),
+        };
+
+        let issued = self.verification_application
+            .issue(VerificationChannel::Sms, mobile, purpose, &code, config.code_ttl, config.resend_interval)
+            .await?;
+
+        let sms_service = &self.domain_layer.sms_domain.domain_service;
+        let message = sms_service.render(&self.infrastructure_layer.config.app.app_name, mobile, &template);
+        match self.infrastructure_layer.sms.send(&message).await {
+            Ok(_) => {
+                info!("+ApplicationLayer [Sms] code sent to {}", sms_service.mask_mobile(mobile));
+                Ok(())
+            }
+            Err(e) => {
+                error!("-ApplicationLayer [Sms] sending to {} failed: {}", sms_service.mask_mobile(mobile), e);
+                self.verification_application.revoke(&issued).await?;
+                Err(Report::msg("短信发送失败，请稍后再试"))
+            }
+        }
+    }
+
+    /// # Description
+    ///     校验手机验证码，通过后验证码即被使用
+    /// # Params
+    ///     mobile: &str - 已规范化的手机号
+    ///     purpose: VerificationPurpose - 用途
+    ///     code: &str - 用户输入的验证码
+    /// # Return
+    ///     Result<(), Report>: 校验结果
+    pub async fn verify_code(&self, mobile: &str, purpose: VerificationPurpose, code: &str) -> Result<(), Report> {
+        self.verification_application
+            .verify(VerificationChannel::Sms, mobile, purpose, code, self.infrastructure_layer.config.sms.code_max_attempts)
+            .await
+    }
+}
@@ -0,0 +1,3 @@
+mod stream_application;
+
+pub use stream_application::{StreamApplication, StreamSubscription};
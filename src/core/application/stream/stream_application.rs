@@ -0,0 +1,200 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use std::time::Duration;
+use color_eyre::{Report, Result};
+use serde_json::{json, Value};
+use tokio::sync::broadcast;
+use tokio::sync::broadcast::error::RecvError;
+use tokio::time::MissedTickBehavior;
+use tracing::{info, warn};
+use crate::core::application::price::PriceApplication;
+use crate::core::domain::DomainLayer;
+use crate::core::domain::stream::entity::stream_event_entity::{StreamEvent, StreamTopic};
+use crate::core::domain::stream::service::StreamDomainService;
+use crate::core::infrastructure::InfrastructureLayer;
+
+/// # Description
+///     实时推送应用服务：各业务模块发布事件，已认证的连接按用户与订阅主题过滤后接收。
+///     事件经有界广播通道分发，消费过慢的连接会丢弃最早的事件并收到积压提示
+/// # Fields
+///     domain_layer: Arc<DomainLayer> - 领域层
+///     infrastructure_layer: Arc<InfrastructureLayer> - 基础设施层
+///     sender: broadcast::Sender<StreamEvent> - 事件广播通道
+pub struct StreamApplication {
+    domain_layer: Arc<DomainLayer>,
+    infrastructure_layer: Arc<InfrastructureLayer>,
+    sender: broadcast::Sender<StreamEvent>,
+}
+
+impl StreamApplication {
+    /// # Description
+    ///     创建新的实时推送应用服务实例
+    /// # Params
+    ///     infrastructure_layer: Arc<InfrastructureLayer> - 基础设施层的引用，用于获取配置和 JWT
+    ///     domain_layer: Arc<DomainLayer> - 领域层的引用，用于获取实时推送领域服务
+    /// # Return
+    ///     Self: 返回一个新的 `StreamApplication` 实例
+    pub fn new(infrastructure_layer: Arc<InfrastructureLayer>, domain_layer: Arc<DomainLayer>) -> Self {
+        let (sender, _) = broadcast::channel(infrastructure_layer.config.stream.buffer.max(16));
+
+        Self { domain_layer, infrastructure_layer, sender }
+    }
+
+    /// # Description
+    ///     发布事件，没有连接时直接丢弃
+    /// # Params
+    ///     topic: StreamTopic - 主题
+    ///     user_id: Option<i32> - 接收用户，None 为公共事件
+    ///     event: &str - 事件名称
+    ///     data: Value - 事件数据
+    pub fn publish(&self, topic: StreamTopic, user_id: Option<i32>, event: &str, data: Value) {
+        let event = self.domain_layer.stream_domain.domain_service.event(topic, user_id, event, data);
+        let _ = self.sender.send(event);
+    }
+
+    /// # Description
+    ///     连接心跳间隔
+    /// # Return
+    ///     Duration: 心跳间隔
+    pub fn heartbeat(&self) -> Duration {
+        Duration::from_secs(self.infrastructure_layer.config.stream.heartbeat.max(1))
+    }
+
+    /// # Description
+    ///     单条消息的发送超时，超时说明客户端读取过慢，连接将被断开
+    /// # Return
+    ///     Duration: 发送超时
+    pub fn send_timeout(&self) -> Duration {
+        Duration::from_secs(self.infrastructure_layer.config.stream.send_timeout.max(1))
+    }
+
+    /// # Description
+    ///     为连接创建订阅
+    /// # Params
+    ///     user_id: i32 - 连接所属用户
+    ///     topics: Option<&str> - 订阅的主题，逗号分隔，未指定时订阅全部
+    /// # Return
+    ///     Result<StreamSubscription, Report>: 连接订阅
+    pub fn subscribe(&self, user_id: i32, topics: Option<&str>) -> Result<StreamSubscription, Report> {
+        let domain_service = self.domain_layer.stream_domain.domain_service.clone();
+        let topics = domain_service.parse_topics(topics)?;
+
+        Ok(StreamSubscription {
+            user_id,
+            topics,
+            receiver: self.sender.subscribe(),
+            domain_service,
+            lagged: 0,
+            max_lagged: self.infrastructure_layer.config.stream.max_lagged,
+        })
+    }
+
+    /// # Description
+    ///     启动价格推送任务：定期读取预言机配置的代币价格，价格变化时发布公共的 price 事件
+    /// # Params
+    ///     price_application: Arc<PriceApplication> - 价格应用
+    /// # Return
+    ///     None
+    pub fn spawn_price_ticker(self: &Arc<Self>, price_application: Arc<PriceApplication>) {
+        let interval = self.infrastructure_layer.config.stream.price_interval;
+        if interval == 0 {
+            info!("+ApplicationLayer [Stream] price ticker disabled.");
+            return;
+        }
+
+        let application = Arc::clone(self);
+        tokio::spawn(async move {
+            let mints: Vec<String> = application.infrastructure_layer.config.oracle.tokens.iter()
+                .map(|token| token.mint.clone())
+                .collect();
+            let mut last_prices: HashMap<String, f64> = HashMap::new();
+            let mut ticker = tokio::time::interval(Duration::from_secs(interval));
+            ticker.set_missed_tick_behavior(MissedTickBehavior::Skip);
+
+            loop {
+                ticker.tick().await;
+                // 没有连接时不读取价格
+                if application.sender.receiver_count() == 0 {
+                    continue;
+                }
+
+                let prices = match price_application.get_prices(mints.clone()).await {
+                    Ok(prices) => prices,
+                    Err(e) => {
+                        warn!("-ApplicationLayer [Stream] price tick failed: {}", e);
+                        continue;
+                    }
+                };
+                for price in prices {
+                    if last_prices.get(&price.mint) == Some(&price.price) {
+                        continue;
+                    }
+                    last_prices.insert(price.mint.clone(), price.price);
+                    application.publish(StreamTopic::Price, None, "price_tick", json!(price));
+                }
+            }
+        });
+        info!("+ApplicationLayer [Stream] price ticker started.");
+    }
+}
+
+/// # Description
+///     单个连接的订阅：按用户与主题过滤广播事件，并处理客户端的订阅命令
+/// # Fields
+///     user_id: i32 - 连接所属用户
+///     topics: HashSet<StreamTopic> - 当前订阅的主题
+///     receiver: broadcast::Receiver<StreamEvent> - 事件接收端
+///     domain_service: Arc<StreamDomainService> - 实时推送领域服务
+///     lagged: u32 - 已发生积压丢弃的次数
+///     max_lagged: u32 - 允许积压丢弃的次数上限
+pub struct StreamSubscription {
+    user_id: i32,
+    topics: HashSet<StreamTopic>,
+    receiver: broadcast::Receiver<StreamEvent>,
+    domain_service: Arc<StreamDomainService>,
+    lagged: u32,
+    max_lagged: u32,
+}
+
+impl StreamSubscription {
+    /// # Description
+    ///     等待下一条推送给该连接的事件。积压时先返回积压提示；
+    ///     积压次数超过上限或通道关闭时返回 None，调用方应断开连接
+    /// # Return
+    ///     Option<StreamEvent>: 事件
+    pub async fn next(&mut self) -> Option<StreamEvent> {
+        loop {
+            match self.receiver.recv().await {
+                Ok(event) if self.domain_service.visible(&event, self.user_id, &self.topics) => return Some(event),
+                Ok(_) => continue,
+                Err(RecvError::Lagged(skipped)) => {
+                    self.lagged += 1;
+                    if self.lagged > self.max_lagged {
+                        warn!("-ApplicationLayer [Stream] user {} connection too slow, closing", self.user_id);
+                        return None;
+                    }
+                    return Some(self.domain_service.lagged(skipped));
+                }
+                Err(RecvError::Closed) => return None,
+            }
+        }
+    }
+
+    /// # Description
+    ///     处理客户端命令
+    /// # Params
+    ///     command: &str - 命令 JSON
+    /// # Return
+    ///     StreamEvent: 回复给客户端的控制消息
+    pub fn handle_command(&mut self, command: &str) -> StreamEvent {
+        self.domain_service.apply_command(&mut self.topics, command)
+    }
+
+    /// # Description
+    ///     订阅确认消息，连接建立后首先发送
+    /// # Return
+    ///     StreamEvent: 订阅确认
+    pub fn subscribed(&self) -> StreamEvent {
+        self.domain_service.subscribed(&self.topics)
+    }
+}
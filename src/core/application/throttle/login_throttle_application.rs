@@ -0,0 +1,182 @@
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+use chrono::Utc;
+use color_eyre::{Report, Result};
+use tracing::{error, warn};
+use crate::core::domain::DomainLayer;
+use crate::core::domain::throttle::{
+    entity::throttle_entity::{LockoutEvent, LoginThrottled, ThrottlePolicy, ThrottleScope},
+    repository::LockoutHookInterface,
+};
+use crate::core::infrastructure::InfrastructureLayer;
+use crate::core::infrastructure::throttle::LogLockoutHook;
+
+/// # Description
+///     登录限流应用服务：按登录标识与客户端 IP 统计失败次数，
+///     超出免延迟次数后逐次加倍延迟，达到阈值后临时锁定并调用锁定通知钩子
+/// # Fields
+///     domain_layer: Arc<DomainLayer> - 领域层
+///     infrastructure_layer: Arc<InfrastructureLayer> - 基础设施层
+///     hooks: RwLock<Vec<Arc<dyn LockoutHookInterface>>> - 锁定通知钩子
+pub struct LoginThrottleApplication {
+    domain_layer: Arc<DomainLayer>,
+    infrastructure_layer: Arc<InfrastructureLayer>,
+    hooks: RwLock<Vec<Arc<dyn LockoutHookInterface>>>,
+}
+
+impl LoginThrottleApplication {
+    /// # Description
+    ///     创建新的登录限流应用服务实例，默认注册日志锁定通知钩子
+    /// # Params
+    ///     infrastructure_layer: Arc<InfrastructureLayer> - 基础设施层的引用，用于获取失败计数存储
+    ///     domain_layer: Arc<DomainLayer> - 领域层的引用，用于获取登录限流领域服务
+    /// # Return
+    ///     Self: 返回一个新的 `LoginThrottleApplication` 实例
+    pub fn new(infrastructure_layer: Arc<InfrastructureLayer>, domain_layer: Arc<DomainLayer>) -> Self {
+        let hooks: Vec<Arc<dyn LockoutHookInterface>> = vec![Arc::new(LogLockoutHook::new())];
+
+        Self { domain_layer, infrastructure_layer, hooks: RwLock::new(hooks) }
+    }
+
+    /// # Description
+    ///     注册锁定通知钩子
+    /// # Params
+    ///     hook: Arc<dyn LockoutHookInterface> - 锁定通知钩子
+    pub fn register_hook(&self, hook: Arc<dyn LockoutHookInterface>) {
+        self.hooks.write().unwrap_or_else(|e| e.into_inner()).push(hook);
+    }
+
+    /// # Description
+    ///     是否信任 X-Forwarded-For 请求头中的客户端 IP
+    pub fn trust_forwarded_for(&self) -> bool {
+        self.infrastructure_layer.config.login_throttle.trust_forwarded_for
+    }
+
+    /// # Description
+    ///     应用前的可信反向代理层数，用于从 X-Forwarded-For 右侧定位客户端 IP
+    pub fn trusted_proxy_hops(&self) -> usize {
+        self.infrastructure_layer.config.login_throttle.trusted_proxy_hops
+    }
+
+    /// # Description
+    ///     登录前检查：登录标识或 IP 已被锁定时返回 LoginThrottled，否则按失败次数等待渐进延迟；
+    ///     存储异常时放行，避免限流组件故障导致所有用户无法登录
+    /// # Params
+    ///     identifier: &str - 规范化后的登录标识
+    ///     ip: &str - 客户端 IP
+    /// # Return
+    ///     Result<(), Report>: 允许继续登录时返回 Ok
+    pub async fn check(&self, identifier: &str, ip: &str) -> Result<(), Report> {
+        let service = &self.domain_layer.throttle_domain.domain_service;
+        let store = &self.infrastructure_layer.login_throttle.store;
+
+        let mut delay_ms = 0;
+        for (scope, subject) in Self::subjects(identifier, ip) {
+            match store.locked_for(&service.lock_key(scope, subject)).await {
+                Ok(Some(retry_after)) => return Err(Report::new(LoginThrottled { retry_after })),
+                Ok(None) => {}
+                Err(e) => error!("-ApplicationLayer [LoginThrottle] lock lookup failed: {}", e),
+            }
+
+            match store.failures(&service.failure_key(scope, subject)).await {
+                Ok(failures) => delay_ms = delay_ms.max(service.delay_ms(failures, &self.policy(scope))),
+                Err(e) => error!("-ApplicationLayer [LoginThrottle] failure lookup failed: {}", e),
+            }
+        }
+
+        if delay_ms > 0 {
+            tokio::time::sleep(Duration::from_millis(delay_ms)).await;
+        }
+
+        Ok(())
+    }
+
+    /// # Description
+    ///     记录一次登录失败，达到阈值的维度被锁定，失败计数清零并调用锁定通知钩子
+    /// # Params
+    ///     identifier: &str - 规范化后的登录标识
+    ///     ip: &str - 客户端 IP
+    pub async fn record_failure(&self, identifier: &str, ip: &str) {
+        let config = &self.infrastructure_layer.config.login_throttle;
+        let service = &self.domain_layer.throttle_domain.domain_service;
+        let store = &self.infrastructure_layer.login_throttle.store;
+
+        for (scope, subject) in Self::subjects(identifier, ip) {
+            let failure_key = service.failure_key(scope, subject);
+            let failures = match store.record_failure(&failure_key, config.window).await {
+                Ok(failures) => failures,
+                Err(e) => {
+                    error!("-ApplicationLayer [LoginThrottle] record failure failed: {}", e);
+                    continue;
+                }
+            };
+
+            if !service.should_lock(failures, &self.policy(scope)) {
+                continue;
+            }
+
+            if let Err(e) = store.lock(&service.lock_key(scope, subject), config.lockout_duration).await {
+                error!("-ApplicationLayer [LoginThrottle] lock failed: {}", e);
+                continue;
+            }
+            if let Err(e) = store.clear(&failure_key).await {
+                warn!("-ApplicationLayer [LoginThrottle] clear failures failed: {}", e);
+            }
+
+            self.notify(LockoutEvent {
+                scope,
+                subject: subject.to_string(),
+                failures,
+                lockout_duration: config.lockout_duration,
+                locked_at: Utc::now(),
+            }).await;
+        }
+    }
+
+    /// # Description
+    ///     登录成功后清空该登录标识的失败计数；IP 计数保留到窗口过期，
+    ///     避免攻击者用自己的账号登录成功来重置 IP 维度的计数
+    /// # Params
+    ///     identifier: &str - 规范化后的登录标识
+    pub async fn record_success(&self, identifier: &str) {
+        let service = &self.domain_layer.throttle_domain.domain_service;
+        let key = service.failure_key(ThrottleScope::Identifier, identifier);
+
+        if let Err(e) = self.infrastructure_layer.login_throttle.store.clear(&key).await {
+            warn!("-ApplicationLayer [LoginThrottle] clear failures failed: {}", e);
+        }
+    }
+
+    /// # Description
+    ///     调用所有锁定通知钩子，单个钩子失败不影响其他钩子
+    async fn notify(&self, event: LockoutEvent) {
+        let hooks = self.hooks.read().unwrap_or_else(|e| e.into_inner()).clone();
+
+        for hook in hooks {
+            if let Err(e) = hook.on_lockout(&event).await {
+                error!("-ApplicationLayer [LoginThrottle] lockout hook failed: {}", e);
+            }
+        }
+    }
+
+    /// # Description
+    ///     两个维度使用相同的延迟配置与各自的锁定阈值
+    fn policy(&self, scope: ThrottleScope) -> ThrottlePolicy {
+        let config = &self.infrastructure_layer.config.login_throttle;
+        let max_failures = match scope {
+            ThrottleScope::Identifier | ThrottleScope::TwoFactor => config.identifier_max_failures,
+            ThrottleScope::Ip => config.ip_max_failures,
+        };
+
+        ThrottlePolicy {
+            free_attempts: config.free_attempts,
+            base_delay_ms: config.base_delay_ms,
+            max_delay_ms: config.max_delay_ms,
+            max_failures,
+        }
+    }
+
+    fn subjects<'a>(identifier: &'a str, ip: &'a str) -> [(ThrottleScope, &'a str); 2] {
+        [(ThrottleScope::Identifier, identifier), (ThrottleScope::Ip, ip)]
+    }
+}
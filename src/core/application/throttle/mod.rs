@@ -0,0 +1,3 @@
+mod login_throttle_application;
+
+pub use login_throttle_application::LoginThrottleApplication;
\ No newline at end of file
@@ -0,0 +1,3 @@
+mod two_factor_application;
+
+pub use two_factor_application::{TwoFactorApplication, TwoFactorChallenge, TwoFactorEnrollment, TwoFactorStatus};
\ No newline at end of file
@@ -0,0 +1,354 @@
+use std::sync::Arc;
+use chrono::Utc;
+use color_eyre::{Report, Result};
+use serde::Serialize;
+use tracing::{error, info, warn};
+use crate::core::application::verification::VerificationApplication;
+use crate::core::domain::DomainLayer;
+use crate::core::domain::throttle::entity::throttle_entity::{ThrottlePolicy, ThrottleScope};
+use crate::core::domain::two_factor::entity::{
+    two_factor_entity::Model as TwoFactorModel,
+    backup_code_entity::{ActiveModel as BackupCodeActiveModel, Model as BackupCodeModel},
+};
+use crate::core::domain::two_factor::repository::TwoFactorRepositoryInterface;
+use crate::core::domain::verification::entity::verification_code_entity::{VerificationChannel, VerificationPurpose};
+use crate::core::domain::verification::repository::VerificationRepositoryInterface;
+use crate::core::infrastructure::InfrastructureLayer;
+
+/// # Description
+///     两步验证状态
+/// # Fields
+///     enabled: bool - 是否已开启
+///     backup_codes_remaining: u64 - 剩余可用的恢复码数量
+#[derive(Debug, Serialize)]
+pub struct TwoFactorStatus {
+    pub enabled: bool,
+    pub backup_codes_remaining: u64,
+}
+
+/// # Description
+///     两步验证登记信息，只在登记时返回一次
+/// # Fields
+///     secret: String - Base32 编码的密钥，供无法扫码时手动输入
+///     otpauth_uri: String - 验证器应用扫码使用的链接
+#[derive(Debug, Serialize)]
+pub struct TwoFactorEnrollment {
+    pub secret: String,
+    pub otpauth_uri: String,
+}
+
+/// # Description
+///     登录挑战：密码已通过，需要在有效期内提交两步验证码
+/// # Fields
+///     two_factor_required: bool - 固定为 true，便于客户端区分令牌与挑战
+///     challenge_token: String - 挑战令牌
+///     expires_in: u64 - 有效期（秒）
+#[derive(Debug, Serialize)]
+pub struct TwoFactorChallenge {
+    pub two_factor_required: bool,
+    pub challenge_token: String,
+    pub expires_in: u64,
+}
+
+/// # Description
+///     两步验证应用服务：TOTP 登记与确认、恢复码、登录挑战，以及敏感操作前的再次验证
+/// # Fields
+///     domain_layer: Arc<DomainLayer> - 领域层
+///     infrastructure_layer: Arc<InfrastructureLayer> - 基础设施层
+///     verification_application: Arc<VerificationApplication> - 验证码应用，用于登记登录挑战
+pub struct TwoFactorApplication {
+    domain_layer: Arc<DomainLayer>,
+    infrastructure_layer: Arc<InfrastructureLayer>,
+    verification_application: Arc<VerificationApplication>,
+}
+
+impl TwoFactorApplication {
+    /// # Description
+    ///     创建新的两步验证应用服务实例
+    /// # Params
+    ///     infrastructure_layer: Arc<InfrastructureLayer> - 基础设施层的引用，用于获取两步验证与验证码仓储
+    ///     domain_layer: Arc<DomainLayer> - 领域层的引用，用于获取两步验证领域服务
+    ///     verification_application: Arc<VerificationApplication> - 验证码应用
+    /// # Return
+    ///     Self: 返回一个新的 `TwoFactorApplication` 实例
+    pub fn new(infrastructure_layer: Arc<InfrastructureLayer>, domain_layer: Arc<DomainLayer>, verification_application: Arc<VerificationApplication>) -> Self {
+        Self { domain_layer, infrastructure_layer, verification_application }
+    }
+
+    /// # Description
+    ///     查询两步验证状态
+    /// # Params
+    ///     user_id: i32 - 用户id
+    /// # Return
+    ///     Result<TwoFactorStatus, Report>: 两步验证状态
+    pub async fn status(&self, user_id: i32) -> Result<TwoFactorStatus, Report> {
+        let enabled = self.is_enabled(user_id).await?;
+        let backup_codes_remaining = if enabled {
+            self.infrastructure_layer.persistence.repository.two_factor_repository.count_backup_codes(user_id).await?
+        } else {
+            0
+        };
+
+        Ok(TwoFactorStatus { enabled, backup_codes_remaining })
+    }
+
+    /// # Description
+    ///     用户是否已开启两步验证
+    /// # Params
+    ///     user_id: i32 - 用户id
+    /// # Return
+    ///     Result<bool, Report>: 是否已开启
+    pub async fn is_enabled(&self, user_id: i32) -> Result<bool, Report> {
+        let two_factor = self.infrastructure_layer.persistence.repository.two_factor_repository.find_by_user(user_id).await?;
+
+        Ok(two_factor.is_some_and(|two_factor| two_factor.enabled))
+    }
+
+    /// # Description
+    ///     登记两步验证：生成新密钥，确认前不生效；重复登记会替换尚未确认的密钥
+    /// # Params
+    ///     user_id: i32 - 用户id
+    ///     account: &str - 用户账号，显示在验证器应用中
+    /// # Return
+    ///     Result<TwoFactorEnrollment, Report>: 密钥与 otpauth 链接
+    pub async fn enroll(&self, user_id: i32, account: &str) -> Result<TwoFactorEnrollment, Report> {
+        if self.is_enabled(user_id).await? {
+            return Err(Report::msg("两步验证已开启"));
+        }
+
+        let config = &self.infrastructure_layer.config.two_factor;
+        let two_factor_service = &self.domain_layer.two_factor_domain.domain_service;
+
+        let secret = two_factor_service.generate_secret();
+        self.infrastructure_layer.persistence.repository.two_factor_repository
+            .create_pending(TwoFactorModel::new(user_id, secret.clone()))
+            .await?;
+        let otpauth_uri = two_factor_service.otpauth_uri(&config.issuer, account, &secret, config.digits, config.period);
+
+        Ok(TwoFactorEnrollment { secret, otpauth_uri })
+    }
+
+    /// # Description
+    ///     使用验证器应用生成的验证码确认开启两步验证，同时生成恢复码
+    /// # Params
+    ///     user_id: i32 - 用户id
+    ///     code: &str - 验证码
+    /// # Return
+    ///     Result<Vec<String>, Report>: 恢复码明文，只返回这一次
+    pub async fn confirm(&self, user_id: i32, code: &str) -> Result<Vec<String>, Report> {
+        let config = &self.infrastructure_layer.config.two_factor;
+        let repository = &self.infrastructure_layer.persistence.repository.two_factor_repository;
+
+        let two_factor = match repository.find_by_user(user_id).await? {
+            Some(two_factor) if two_factor.enabled => return Err(Report::msg("两步验证已开启")),
+            Some(two_factor) => two_factor,
+            None => return Err(Report::msg("请先登记两步验证")),
+        };
+
+        let step = self.domain_layer.two_factor_domain.domain_service
+            .verify_totp(&two_factor.secret, code, Utc::now().timestamp(), config.period, config.digits, config.skew, two_factor.last_used_step)?
+            .ok_or_else(|| Report::msg("两步验证码错误"))?;
+
+        let (codes, backup_codes) = self.new_backup_codes(user_id);
+        if !repository.enable(two_factor.id, step, backup_codes).await? {
+            return Err(Report::msg("两步验证已开启"));
+        }
+        info!("+ApplicationLayer [TwoFactor] enabled for user {}", user_id);
+
+        Ok(codes)
+    }
+
+    /// # Description
+    ///     关闭两步验证，需要提供验证码或恢复码
+    /// # Params
+    ///     user_id: i32 - 用户id
+    ///     code: &str - 验证码或恢复码
+    /// # Return
+    ///     Result<(), Report>: 关闭结果
+    pub async fn disable(&self, user_id: i32, code: &str) -> Result<(), Report> {
+        self.verify(user_id, code).await?;
+        self.infrastructure_layer.persistence.repository.two_factor_repository.remove(user_id).await?;
+        info!("+ApplicationLayer [TwoFactor] disabled for user {}", user_id);
+
+        Ok(())
+    }
+
+    /// # Description
+    ///     重新生成恢复码，旧恢复码全部作废；只接受验证器应用生成的验证码
+    /// # Params
+    ///     user_id: i32 - 用户id
+    ///     code: &str - 验证码
+    /// # Return
+    ///     Result<Vec<String>, Report>: 新恢复码明文
+    pub async fn regenerate_backup_codes(&self, user_id: i32, code: &str) -> Result<Vec<String>, Report> {
+        let two_factor = self.enabled_two_factor(user_id).await?;
+        self.check_code_throttled(&two_factor, code, false).await?;
+
+        let (codes, backup_codes) = self.new_backup_codes(user_id);
+        self.infrastructure_layer.persistence.repository.two_factor_repository.replace_backup_codes(user_id, backup_codes).await?;
+
+        Ok(codes)
+    }
+
+    /// # Description
+    ///     敏感操作前的再次验证：要求已开启两步验证，并校验验证码或恢复码，每个验证码只能使用一次。
+    ///     失败次数按用户累计，与登录挑战共用，达到阈值后临时锁定
+    /// # Params
+    ///     user_id: i32 - 用户id
+    ///     code: &str - 验证码或恢复码
+    /// # Return
+    ///     Result<(), Report>: 校验结果
+    pub async fn verify(&self, user_id: i32, code: &str) -> Result<(), Report> {
+        let two_factor = self.enabled_two_factor(user_id).await?;
+        self.check_code_throttled(&two_factor, code, true).await
+    }
+
+    /// # Description
+    ///     登记登录挑战，密码通过但需要两步验证时使用
+    /// # Params
+    ///     user_id: i32 - 用户id
+    /// # Return
+    ///     Result<TwoFactorChallenge, Report>: 登录挑战
+    pub async fn issue_challenge(&self, user_id: i32) -> Result<TwoFactorChallenge, Report> {
+        let ttl = self.infrastructure_layer.config.two_factor.challenge_ttl;
+
+        let challenge_token = self.domain_layer.verification_domain.domain_service.generate_token();
+        self.verification_application
+            .issue(VerificationChannel::Totp, &user_id.to_string(), VerificationPurpose::TwoFactorLogin, &challenge_token, ttl, 0)
+            .await?;
+
+        Ok(TwoFactorChallenge { two_factor_required: true, challenge_token, expires_in: ttl })
+    }
+
+    /// # Description
+    ///     完成登录挑战：校验验证码或恢复码，通过后挑战令牌即被使用；错误时累计失败次数
+    /// # Params
+    ///     challenge_token: &str - 挑战令牌
+    ///     code: &str - 验证码或恢复码
+    /// # Return
+    ///     Result<i32, Report>: 挑战所属的用户id
+    pub async fn redeem_challenge(&self, challenge_token: &str, code: &str) -> Result<i32, Report> {
+        let repository = &self.infrastructure_layer.persistence.repository.verification_repository;
+        let max_attempts = self.infrastructure_layer.config.two_factor.challenge_max_attempts;
+
+        let code_hash = self.domain_layer.verification_domain.domain_service.digest(challenge_token);
+        let challenge = repository.find_by_hash(VerificationChannel::Totp, VerificationPurpose::TwoFactorLogin, &code_hash).await?
+            .filter(|challenge| !challenge.is_consumed() && !challenge.is_expired(Utc::now()))
+            .ok_or_else(|| Report::msg("登录验证已失效，请重新登录"))?;
+        if challenge.attempts >= max_attempts {
+            return Err(Report::msg("两步验证码错误次数过多，请重新登录"));
+        }
+        let user_id = challenge.target.parse::<i32>()
+            .map_err(|_| Report::msg("登录验证已失效，请重新登录"))?;
+
+        if let Err(e) = self.verify(user_id, code).await {
+            repository.record_failure(challenge.id).await?;
+            return Err(e);
+        }
+        if !repository.consume(challenge.id).await? {
+            return Err(Report::msg("登录验证已失效，请重新登录"));
+        }
+
+        Ok(user_id)
+    }
+
+    /// # Description
+    ///     加载已开启的两步验证设置
+    async fn enabled_two_factor(&self, user_id: i32) -> Result<TwoFactorModel, Report> {
+        self.infrastructure_layer.persistence.repository.two_factor_repository.find_by_user(user_id).await?
+            .filter(|two_factor| two_factor.enabled)
+            .ok_or_else(|| Report::msg("请先开启两步验证"))
+    }
+
+    /// # Description
+    ///     按用户的失败计数校验验证码：已锁定时直接拒绝，错误时累计失败次数并在达到阈值后锁定，通过后清空计数。
+    ///     计数存储异常时只记录日志，与登录限流一致
+    async fn check_code_throttled(&self, two_factor: &TwoFactorModel, code: &str, allow_backup: bool) -> Result<(), Report> {
+        let config = &self.infrastructure_layer.config.two_factor;
+        let service = &self.domain_layer.throttle_domain.domain_service;
+        let store = &self.infrastructure_layer.login_throttle.store;
+
+        let subject = two_factor.user_id.to_string();
+        let failure_key = service.failure_key(ThrottleScope::TwoFactor, &subject);
+        let lock_key = service.lock_key(ThrottleScope::TwoFactor, &subject);
+
+        match store.locked_for(&lock_key).await {
+            Ok(Some(retry_after)) => return Err(Report::msg(format!("两步验证失败次数过多，请 {} 秒后再试", retry_after))),
+            Ok(None) => {}
+            Err(e) => error!("-ApplicationLayer [TwoFactor] lock lookup failed: {}", e),
+        }
+
+        if self.check_code(two_factor, code, allow_backup).await? {
+            if let Err(e) = store.clear(&failure_key).await {
+                warn!("-ApplicationLayer [TwoFactor] clear failures failed: {}", e);
+            }
+            return Ok(());
+        }
+
+        match store.record_failure(&failure_key, config.failure_window).await {
+            Ok(failures) => {
+                let policy = ThrottlePolicy { free_attempts: 0, base_delay_ms: 0, max_delay_ms: 0, max_failures: config.max_failures };
+                if service.should_lock(failures, &policy) {
+                    match store.lock(&lock_key, config.lockout_duration).await {
+                        Ok(()) => {
+                            warn!("-ApplicationLayer [TwoFactor] user {} locked after {} failed attempts", two_factor.user_id, failures);
+                            if let Err(e) = store.clear(&failure_key).await {
+                                warn!("-ApplicationLayer [TwoFactor] clear failures failed: {}", e);
+                            }
+                        }
+                        Err(e) => error!("-ApplicationLayer [TwoFactor] lock failed: {}", e),
+                    }
+                }
+            }
+            Err(e) => error!("-ApplicationLayer [TwoFactor] record failure failed: {}", e),
+        }
+
+        Err(Report::msg("两步验证码错误"))
+    }
+
+    /// # Description
+    ///     校验验证码；allow_backup 为 true 时非验证码格式的输入按恢复码校验
+    async fn check_code(&self, two_factor: &TwoFactorModel, code: &str, allow_backup: bool) -> Result<bool, Report> {
+        let config = &self.infrastructure_layer.config.two_factor;
+        let repository = &self.infrastructure_layer.persistence.repository.two_factor_repository;
+        let two_factor_service = &self.domain_layer.two_factor_domain.domain_service;
+
+        if two_factor_service.is_totp_code(code, config.digits) {
+            let step = two_factor_service.verify_totp(
+                &two_factor.secret, code, Utc::now().timestamp(), config.period, config.digits, config.skew, two_factor.last_used_step,
+            )?;
+            return match step {
+                Some(step) => repository.advance_step(two_factor.id, step).await,
+                None => Ok(false),
+            };
+        }
+        if !allow_backup {
+            return Ok(false);
+        }
+
+        let code_hash = self.domain_layer.verification_domain.domain_service.digest(&two_factor_service.normalize_backup_code(code));
+        let Some(backup_code) = repository.find_backup_code(two_factor.user_id, &code_hash).await? else {
+            return Ok(false);
+        };
+        if !repository.use_backup_code(backup_code.id).await? {
+            return Ok(false);
+        }
+        warn!("-ApplicationLayer [TwoFactor] user {} used a backup code", two_factor.user_id);
+
+        Ok(true)
+    }
+
+    /// # Description
+    ///     生成一组恢复码，返回明文与待保存的摘要记录
+    fn new_backup_codes(&self, user_id: i32) -> (Vec<String>, Vec<BackupCodeActiveModel>) {
+        let two_factor_service = &self.domain_layer.two_factor_domain.domain_service;
+        let verification_service = &self.domain_layer.verification_domain.domain_service;
+
+        let codes = two_factor_service.generate_backup_codes(self.infrastructure_layer.config.two_factor.backup_code_count);
+        let backup_codes = codes.iter()
+            .map(|code| BackupCodeModel::new(user_id, verification_service.digest(&two_factor_service.normalize_backup_code(code))))
+            .collect();
+
+        (codes, backup_codes)
+    }
+}
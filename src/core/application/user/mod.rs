@@ -1,3 +1,3 @@
 mod user_application;
 
-pub use user_application::UserApplication;
\ No newline at end of file
+pub use user_application::{LoginOutcome, UserApplication, UserProfile, UserSession};
\ No newline at end of file
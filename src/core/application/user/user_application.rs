@@ -1,25 +1,110 @@
-use std::sync::Arc;
-use chrono::{Datelike, Utc};
+use std::str::FromStr;
+use std::sync::{Arc, OnceLock};
+use chrono::{DateTime, Datelike, Utc};
 use color_eyre::eyre::{Report, Result};
 use rand::{Rng, SeedableRng};
+use rand::distributions::Alphanumeric;
 use rand::rngs::StdRng;
-use tracing::{error, info};
-use crate::core::infrastructure::{jwt::TokenOutPut, InfrastructureLayer};
+use sea_orm::{ActiveModelTrait, IntoActiveModel};
+use serde::Serialize;
+use solana_client::rpc_request::TokenAccountsFilter;
+use solana_program::pubkey::Pubkey;
+use solana_sdk::native_token::lamports_to_sol;
+use tracing::{error, info, warn};
+use crate::core::application::mail::MailApplication;
+use crate::core::application::sms::SmsApplication;
+use crate::core::application::two_factor::{TwoFactorApplication, TwoFactorChallenge};
+use crate::core::application::throttle::LoginThrottleApplication;
+use crate::core::infrastructure::{jwt::{JsonWebKeySet, Jwt, TokenOutPut}, InfrastructureLayer};
 use crate::core::domain::{
     user::{
+        entity::{admin_audit_entity::ActiveModel as AdminAuditActiveModel, user_entity::Model as UserModel},
         repository_interface::UserRepositoryInterface
     },
+    auth::entity::token_entity::RefreshRotation,
+    mail::entity::mail_entity::MailTemplate,
+    wallet::{entity::Model as WalletModel, repository::WalletRepositoryInterface},
+    order::{
+        entity::{order_entity::OrderStatus, order_event_entity::Model as OrderEventModel},
+        repository::OrderRepositoryInterface
+    },
+    dca::{entity::dca_schedule_entity::DcaStatus, repository::DcaRepositoryInterface},
+    verification::entity::verification_code_entity::VerificationPurpose,
     DomainLayer
 };
 
+/// # Description
+///     密码登录失败时统一返回的错误，不区分用户不存在与密码错误
+const LOGIN_FAILED_MESSAGE: &str = "账号或密码错误";
+
+/// # Description
+///     登录结果：未开启两步验证时直接返回令牌，已开启时返回登录挑战
+/// # Param
+///     Authenticated(TokenOutPut): 登录成功
+///     TwoFactorRequired(TwoFactorChallenge): 需要提交两步验证码
+#[derive(Debug, Serialize)]
+#[serde(untagged)]
+pub enum LoginOutcome {
+    Authenticated(TokenOutPut),
+    TwoFactorRequired(TwoFactorChallenge),
+}
+
+/// # Description
+///     用户资料，不包含密码等敏感字段
+/// # Fields
+///     id: i32 - 用户id
+///     account: String - 账号
+///     nickname: Option<String> - 昵称
+///     avatar: Option<String> - 头像地址
+///     mobile: Option<String> - E.164 格式的手机号
+///     email: Option<String> - 邮箱
+///     level: i8 - 用户级别
+///     two_factor_enabled: bool - 是否已开启两步验证
+///     created_at: DateTime<Utc> - 注册时间
+#[derive(Debug, Serialize)]
+pub struct UserProfile {
+    pub id: i32,
+    pub account: String,
+    pub nickname: Option<String>,
+    pub avatar: Option<String>,
+    pub mobile: Option<String>,
+    pub email: Option<String>,
+    pub level: i8,
+    pub two_factor_enabled: bool,
+    pub created_at: DateTime<Utc>,
+}
+
+/// # Description
+///     登录会话，对应一个刷新令牌族
+/// # Fields
+///     session_id: String - 会话id（刷新令牌族id）
+///     created_at: Option<DateTime<Utc>> - 登录时间，旧会话没有记录时为空
+///     last_active_at: Option<DateTime<Utc>> - 最近一次登录或刷新令牌的时间
+///     current: bool - 是否为发起请求的会话
+#[derive(Debug, Serialize)]
+pub struct UserSession {
+    pub session_id: String,
+    pub created_at: Option<DateTime<Utc>>,
+    pub last_active_at: Option<DateTime<Utc>>,
+    pub current: bool,
+}
+
 /// # Description
 ///     用户应用服务，负责处理用户相关的应用逻辑，如注册、登录、禁用用户和分配角色。
 /// # Fields
 ///     u_domain_serv: Arc<UserDomainService> - 用户仓储接口的引用
 ///     jwt: Arc<Jwt> - jwt 功能
+///     mail_application: Arc<MailApplication> - 邮件应用，用于邮箱验证码与重置密码
+///     sms_application: Arc<SmsApplication> - 短信应用，用于手机号验证码注册与登录
+///     two_factor_application: Arc<TwoFactorApplication> - 两步验证应用，用于登录时的两步验证
+///     login_throttle_application: Arc<LoginThrottleApplication> - 登录限流应用，用于密码登录的失败计数与锁定
 pub struct UserApplication {
     domain_layer: Arc<DomainLayer>,
-    infrastructure_layer: Arc<InfrastructureLayer>
+    infrastructure_layer: Arc<InfrastructureLayer>,
+    mail_application: Arc<MailApplication>,
+    sms_application: Arc<SmsApplication>,
+    two_factor_application: Arc<TwoFactorApplication>,
+    login_throttle_application: Arc<LoginThrottleApplication>,
 }
 
 impl UserApplication {
@@ -28,33 +113,59 @@ impl UserApplication {
     /// # Params
     ///     infrastructure_layer: Arc<InfrastructureLayer> - 基础设施层的引用，用于获取共享服务如 JWT
     ///     domain_layer: Arc<DomainLayer> - 领域层的引用，用于获取用户领域服务
+    ///     mail_application: Arc<MailApplication> - 邮件应用
+    ///     sms_application: Arc<SmsApplication> - 短信应用
+    ///     two_factor_application: Arc<TwoFactorApplication> - 两步验证应用
+    ///     login_throttle_application: Arc<LoginThrottleApplication> - 登录限流应用
     /// # Return
     ///     Self: 返回一个新的 `UserApplication` 实例
-    pub fn new(infrastructure_layer: Arc<InfrastructureLayer>, domain_layer: Arc<DomainLayer>) -> Self {
-        Self { domain_layer, infrastructure_layer }
+    pub fn new(
+        infrastructure_layer: Arc<InfrastructureLayer>,
+        domain_layer: Arc<DomainLayer>,
+        mail_application: Arc<MailApplication>,
+        sms_application: Arc<SmsApplication>,
+        two_factor_application: Arc<TwoFactorApplication>,
+        login_throttle_application: Arc<LoginThrottleApplication>,
+    ) -> Self {
+        Self { domain_layer, infrastructure_layer, mail_application, sms_application, two_factor_application, login_throttle_application }
     }
 
 
     /// # Description
-    ///     注册新用户，通过手机号或邮箱
+    ///     注册新用户，通过手机号或邮箱；手机号统一保存为 E.164 格式。
+    ///     开启对应渠道的验证时，必须携带发送到该邮箱或手机号的注册验证码
     /// # Params
     ///     identifier: String - 用户的手机号或邮箱
     ///     password: String - 用户的明文密码
+    ///     code: Option<String> - 邮箱或短信验证码
     /// # Return
     ///     Result<(), Report>: 成功返回 Ok() ，失败返回错误信息
-    pub async fn register_user(&self, identifier: String, password: String) -> Result<(), Report> {
+    pub async fn register_user(&self, identifier: String, password: String, code: Option<String>) -> Result<(), Report> {
         // 判断是手机号还是邮箱注册
         let (email, mobile) = if identifier.contains('@') {
-            (identifier.clone(), String::new())
+            (self.mail_application.normalize_email(&identifier)?, String::new())
         } else {
-            (String::new(), identifier.clone())
+            (String::new(), self.sms_application.normalize_mobile(&identifier)?)
         };
+        let identifier = if email.is_empty() { mobile.clone() } else { email.clone() };
 
         // 检查是否被注册
         if self.infrastructure_layer.persistence.repository.user_repository.find_by_mobile_or_email_account(identifier.clone()).await?.is_some() {
             return Err(Report::msg("该手机号或邮箱已被注册"));
         }
 
+        // 邮箱注册校验验证码
+        if !email.is_empty() && self.infrastructure_layer.config.mail.require_verification {
+            let code = code.ok_or_else(|| Report::msg("请输入邮箱验证码"))?;
+            self.mail_application.verify_code(&email, VerificationPurpose::Register, &code).await?;
+        }
+
+        // 手机号注册校验验证码
+        if !mobile.is_empty() && self.infrastructure_layer.config.sms.require_verification {
+            let code = code.ok_or_else(|| Report::msg("请输入短信验证码"))?;
+            self.sms_application.verify_code(&mobile, VerificationPurpose::Register, &code).await?;
+        }
+
         // 生成唯一的账号
         let account = self.generate_unique_account().await?;
 
@@ -83,6 +194,107 @@ impl UserApplication {
         }
     }
 
+    /// # Description
+    ///     发送注册验证码，已注册的邮箱不再发送
+    /// # Params
+    ///     email: String - 邮箱
+    /// # Return
+    ///     Result<(), Report>: 成功返回 Ok() ，失败返回错误信息
+    pub async fn send_register_code(&self, email: String) -> Result<(), Report> {
+        let email = self.mail_application.normalize_email(&email)?;
+        if self.infrastructure_layer.persistence.repository.user_repository.find_by_mobile_or_email_account(email.clone()).await?.is_some() {
+            return Err(Report::msg("该手机号或邮箱已被注册"));
+        }
+
+        self.mail_application.send_register_code(&email).await
+    }
+
+    /// # Description
+    ///     发送短信验证码：注册与绑定手机号验证码只发给未注册的手机号；登录验证码只发给已注册且可用的手机号，
+    ///     未注册时同样返回成功，避免泄露手机号是否已注册
+    /// # Params
+    ///     mobile: String - 手机号
+    ///     purpose: VerificationPurpose - 用途，注册、登录或绑定手机号
+    /// # Return
+    ///     Result<(), Report>: 成功返回 Ok() ，失败返回错误信息
+    pub async fn send_sms_code(&self, mobile: String, purpose: VerificationPurpose) -> Result<(), Report> {
+        let mobile = self.sms_application.normalize_mobile(&mobile)?;
+        let user = self.infrastructure_layer.persistence.repository.user_repository.find_by_mobile_or_email_account(mobile.clone()).await?;
+
+        match purpose {
+            VerificationPurpose::Register | VerificationPurpose::BindMobile => {
+                if user.is_some() {
+                    return Err(Report::msg("该手机号或邮箱已被注册"));
+                }
+                self.sms_application.send_code(&mobile, purpose).await
+            }
+            VerificationPurpose::Login => match user {
+                Some(user) if self.domain_layer.user_domain.domain_service.ensure_active(&user).is_ok() => {
+                    self.sms_application.send_code(&mobile, purpose).await
+                }
+                _ => {
+                    info!("+ApplicationLayer [User] login code requested for unknown or inactive mobile");
+                    Ok(())
+                }
+            },
+            _ => Err(Report::msg("不支持的短信验证码用途")),
+        }
+    }
+
+    /// # Description
+    ///     忘记密码：向邮箱发送重置密码链接。邮箱未注册、账号不可用或发送失败（包括重发冷却）时同样返回成功，
+    ///     避免通过不同的响应泄露邮箱是否已注册
+    /// # Params
+    ///     email: String - 邮箱
+    /// # Return
+    ///     Result<(), Report>: 成功返回 Ok() ，失败返回错误信息
+    pub async fn forgot_password(&self, email: String) -> Result<(), Report> {
+        let email = self.mail_application.normalize_email(&email)?;
+        let user = self.infrastructure_layer.persistence.repository.user_repository.find_by_mobile_or_email_account(email.clone()).await?;
+
+        match user {
+            Some(user) if self.domain_layer.user_domain.domain_service.ensure_active(&user).is_ok() => {
+                if let Err(e) = self.mail_application.send_reset_link(&email).await {
+                    warn!("-ApplicationLayer [User] reset link to user {} not sent: {}", user.id, e);
+                }
+                Ok(())
+            }
+            _ => {
+                info!("+ApplicationLayer [User] password reset requested for unknown or inactive email {}", email);
+                Ok(())
+            }
+        }
+    }
+
+    /// # Description
+    ///     使用邮件中的令牌重置密码，令牌只能使用一次，重置后注销全部会话并发送通知邮件
+    /// # Params
+    ///     token: String - 重置令牌
+    ///     new_password: String - 新密码明文
+    /// # Return
+    ///     Result<(), Report>: 成功返回 Ok() ，失败返回错误信息
+    pub async fn reset_password(&self, token: String, new_password: String) -> Result<(), Report> {
+        let user_service = &self.domain_layer.user_domain.domain_service;
+        user_service.validate_password(&new_password)?;
+
+        let email = self.mail_application.consume_reset_token(&token).await?;
+        let mut user = self.infrastructure_layer.persistence.repository.user_repository.find_by_mobile_or_email_account(email.clone()).await?
+            .ok_or_else(|| Report::msg("重置链接无效或已过期"))?;
+        user_service.ensure_active(&user)?;
+
+        user.reset_password(new_password).map_err(Report::msg)?;
+        user.updated_at = Utc::now();
+        let user_id = user.id;
+        self.infrastructure_layer.persistence.repository.user_repository.save(user.into_active_model().reset_all()).await?;
+        // 重置密码后注销全部会话，被盗用的会话不能继续使用
+        self.revoke_sessions(user_id, None).await?;
+        info!("+ApplicationLayer [User] password reset by email for {}", email);
+
+        self.mail_application.notify(&email, MailTemplate::PasswordChanged).await;
+
+        Ok(())
+    }
+
     /// # Description
     ///     生成唯一用户账号，格式为当前年份 + 六位随机数
     ///     备注：let mut rng = thread_rng(); 异步上下文中的使用thread_rng可能会导致一些问题，特别是在异步函数中使用时。thread_rng()是线程本地的，但是在异步代码中使用时，您可能需要考虑使用rand::rngs::StdRng固定种子以获得更好的控制并避免路由或异步上下文的潜在问题。
@@ -107,79 +319,641 @@ impl UserApplication {
     }
 
     /// # Description
-    ///     用户登录，验证用户账号、手机号或邮箱和密码
+    ///     用户登录，验证用户账号、手机号或邮箱和密码；已开启两步验证时返回登录挑战。
+    ///     按登录标识与客户端 IP 限流：失败次数增加后逐次延迟，达到阈值后临时锁定；
+    ///     用户不存在与密码错误返回相同的错误，避免枚举账号
     /// # Params
     ///     identifier: String - 用户的账号、手机号或邮箱
     ///     password: String - 用户的明文密码
+    ///     ip: String - 客户端 IP
     /// # Return
-    ///     Result<String, Report>: 成功返回 JWT 令牌，失败返回错误信息
-    pub async fn login_user(&self, identifier: String, password: String) -> Result<TokenOutPut, Report> {
-        // 根据账号查找用户
-        let user = match self.infrastructure_layer.persistence.repository.user_repository.find_by_mobile_or_email_account(identifier).await {
-            Ok(Some(user)) => user,
-            Ok(None) => return Err(Report::msg("用户不存在")),
+    ///     Result<LoginOutcome, Report>: 成功返回 JWT 令牌或登录挑战，失败返回错误信息；被限流时错误为 LoginThrottled
+    pub async fn login_user(&self, identifier: String, password: String, ip: String) -> Result<LoginOutcome, Report> {
+        let subject = self.throttle_subject(&identifier);
+        self.login_throttle_application.check(&subject, &ip).await?;
+
+        // 根据账号查找用户，找不到时按规范化后的手机号再查一次
+        let user_repository = &self.infrastructure_layer.persistence.repository.user_repository;
+        let mut found = user_repository.find_by_mobile_or_email_account(identifier.clone()).await;
+        if matches!(found, Ok(None)) && !identifier.contains('@') {
+            if let Ok(mobile) = self.sms_application.normalize_mobile(&identifier) {
+                found = user_repository.find_by_mobile_or_email_account(mobile).await;
+            }
+        }
+        let user = match found {
+            Ok(user) => user,
             Err(e) => return Err(Report::msg(format!("查找用户时出错: {}", e))),
         };
 
-        // 验证密码
-        if user.verify_password(password) {
-            // 生成 JWT
-            let token = self.infrastructure_layer.jwt.create_jwt(&user.id.to_string())?;
+        // 验证密码，用户不存在时同样计算一次哈希，使两种失败的耗时一致
+        let verified = match &user {
+            Some(user) => user.verify_password(password),
+            None => {
+                let _ = bcrypt::verify(password, Self::dummy_password_hash());
+                false
+            }
+        };
 
-            Ok(token)
-        } else {
-            Err(Report::msg("密码错误"))
+        // 已禁用或已删除的账号与密码错误返回相同的错误，不签发令牌也不发起两步验证
+        let user_service = &self.domain_layer.user_domain.domain_service;
+        match user {
+            Some(user) if verified && user_service.ensure_active(&user).is_ok() => {
+                self.login_throttle_application.record_success(&subject).await;
+                // 生成 JWT，开启新的刷新令牌族
+                self.complete_login(user.id).await
+            }
+            _ => {
+                self.login_throttle_application.record_failure(&subject, &ip).await;
+                Err(Report::msg(LOGIN_FAILED_MESSAGE))
+            }
+        }
+    }
+
+    /// # Description
+    ///     登录限流使用的登录标识：邮箱与手机号按规范化后的形式统计，
+    ///     避免同一账号换一种写法绕过计数
+    fn throttle_subject(&self, identifier: &str) -> String {
+        let identifier = identifier.trim();
+        if identifier.contains('@') {
+            if let Ok(email) = self.mail_application.normalize_email(identifier) {
+                return email;
+            }
+        } else if let Ok(mobile) = self.sms_application.normalize_mobile(identifier) {
+            return mobile;
         }
+
+        identifier.to_lowercase()
+    }
+
+    /// # Description
+    ///     用户不存在时用于比对的密码哈希，首次使用时生成
+    fn dummy_password_hash() -> &'static str {
+        static DUMMY_PASSWORD_HASH: OnceLock<String> = OnceLock::new();
+        DUMMY_PASSWORD_HASH.get_or_init(|| bcrypt::hash(Utc::now().to_rfc3339(), bcrypt::DEFAULT_COST).unwrap_or_default())
     }
 
 
     /// # Description
-    ///     禁用用户，通过 user_id 禁用用户账户
+    ///     短信验证码登录，验证码通过后签发令牌；已开启两步验证时返回登录挑战
     /// # Params
-    ///     user_id: i32 - 要禁用的用户 ID
+    ///     mobile: String - 手机号
+    ///     code: String - 短信验证码
     /// # Return
-    ///     Result<(), Report>: 成功返回 Ok() ，失败返回错误信息
-    pub async fn disable_user(&self, user_id: i32) -> Result<(), Report> {
-        // 从用户仓库中通过 user_id 查找用户
-        let user = match self.infrastructure_layer.persistence.repository.user_repository.find_by_id(user_id).await {
-            Ok(Some(user)) => user,
-            Ok(None) => return Err(Report::msg("用户不存在")),
-            Err(e) => return Err(Report::msg(format!("查找用户时出错: {}", e))),
-        };
+    ///     Result<LoginOutcome, Report>: 成功返回 JWT 令牌或登录挑战，失败返回错误信息
+    pub async fn login_with_otp(&self, mobile: String, code: String) -> Result<LoginOutcome, Report> {
+        let mobile = self.sms_application.normalize_mobile(&mobile)?;
+        self.sms_application.verify_code(&mobile, VerificationPurpose::Login, &code).await?;
 
-        // 调用领域服务禁用用户
-        let updated_user = self.domain_layer.user_domain.domain_service.disable_user(user)?;
+        let user = self.infrastructure_layer.persistence.repository.user_repository.find_by_mobile_or_email_account(mobile).await?
+            .ok_or_else(|| Report::msg("用户不存在"))?;
+        self.domain_layer.user_domain.domain_service.ensure_active(&user)?;
 
-        // 保存用户到仓储
-        match self.infrastructure_layer.persistence.repository.user_repository.save(updated_user.into()).await {
-            Ok(_) => Ok(()),
-            Err(e) => Err(Report::msg(format!("禁用用户失败: {}", e))),
+        self.complete_login(user.id).await
+    }
+
+    /// # Description
+    ///     提交两步验证码完成登录，验证码或恢复码通过后签发令牌
+    /// # Params
+    ///     challenge_token: String - 登录挑战令牌
+    ///     code: String - 两步验证码或恢复码
+    /// # Return
+    ///     Result<TokenOutPut, Report>: 成功返回 JWT 令牌，失败返回错误信息
+    pub async fn login_two_factor(&self, challenge_token: String, code: String) -> Result<TokenOutPut, Report> {
+        let user_id = self.two_factor_application.redeem_challenge(&challenge_token, &code).await?;
+
+        let user = self.infrastructure_layer.persistence.repository.user_repository.find_by_id(user_id).await?
+            .ok_or_else(|| Report::msg("用户不存在"))?;
+        self.domain_layer.user_domain.domain_service.ensure_active(&user)?;
+
+        self.issue_tokens(user.id).await
+    }
+
+    /// # Description
+    ///     第一因素通过后完成登录：已开启两步验证时登记登录挑战，否则直接签发令牌
+    async fn complete_login(&self, user_id: i32) -> Result<LoginOutcome, Report> {
+        if self.two_factor_application.is_enabled(user_id).await? {
+            return Ok(LoginOutcome::TwoFactorRequired(self.two_factor_application.issue_challenge(user_id).await?));
         }
+
+        Ok(LoginOutcome::Authenticated(self.issue_tokens(user_id).await?))
     }
 
+    /// # Description
+    ///     校验访问令牌并加载令牌所属用户，已禁用或已注销的用户不能通过认证
+    /// # Params
+    ///     token: &str - 访问令牌
+    /// # Return
+    ///     Result<UserModel, Report>: 成功返回用户，失败返回错误信息
+    pub async fn authenticate(&self, token: &str) -> Result<UserModel, Report> {
+        let claims = self.infrastructure_layer.jwt.validate_jwt(token)
+            .map_err(|_| Report::msg("访问令牌无效或已过期"))?;
+        let user_id = claims.subject().parse::<i32>()
+            .map_err(|_| Report::msg("访问令牌无效或已过期"))?;
+
+        // 已注销或已被轮换（宽限期已过）的访问令牌不能再使用
+        let revoked_at = self.infrastructure_layer.jwt.store.blacklisted_at(claims.id()).await?;
+        if self.domain_layer.auth_domain.domain_service.is_revoked(revoked_at, Utc::now().timestamp()) {
+            return Err(Report::msg("访问令牌已失效"));
+        }
+
+        let user = self.infrastructure_layer.persistence.repository.user_repository.find_by_id(user_id).await?
+            .ok_or_else(|| Report::msg("用户不存在"))?;
+        self.domain_layer.user_domain.domain_service.ensure_active(&user)?;
+
+        Ok(user)
+    }
+
+    /// # Description
+    ///     签发访问令牌与刷新令牌，并登记新的刷新令牌族
+    /// # Params
+    ///     user_id: i32 - 用户id
+    /// # Return
+    ///     Result<TokenOutPut, Report>: 成功返回令牌，失败返回错误信息
+    async fn issue_tokens(&self, user_id: i32) -> Result<TokenOutPut, Report> {
+        let jwt = &self.infrastructure_layer.jwt;
+        let family_id = Jwt::random_id();
+        let token = jwt.create_jwt(&user_id.to_string(), &family_id)?;
+
+        let family = self.domain_layer.auth_domain.domain_service.new_family(
+            user_id,
+            token.refresh_id().to_string(),
+            token.access_id().to_string(),
+            Utc::now().timestamp(),
+        );
+        jwt.store.save_family(&family_id, &family, jwt.refresh_ttl()).await?;
+
+        Ok(token)
+    }
 
     /// # Description
-    ///     为用户分配角色
+    ///     使用刷新令牌换取新令牌。每枚刷新令牌只能使用一次：
+    ///     - 出示当前令牌时轮换，旧访问令牌在黑名单宽限期结束后失效
+    ///     - 宽限期内重复出示刚轮换掉的令牌（并发刷新）时返回同一组新令牌
+    ///     - 其余旧令牌被再次出示视为泄露，整族作废，需要重新登录
     /// # Params
-    ///     user_id: i32 - 要分配角色的用户 ID
-    ///     role: String - 要分配的角色名称
+    ///     refresh_token: &str - 刷新令牌
+    /// # Return
+    ///     Result<TokenOutPut, Report>: 成功返回新令牌，失败返回错误信息
+    pub async fn refresh_token(&self, refresh_token: &str) -> Result<TokenOutPut, Report> {
+        let jwt = &self.infrastructure_layer.jwt;
+        let auth_service = &self.domain_layer.auth_domain.domain_service;
+        let grace_period = jwt.jwt_config.jwt_blacklist_grace_period;
+
+        let claims = jwt.validate_refresh(refresh_token)
+            .map_err(|_| Report::msg("刷新令牌无效或已过期"))?;
+        let user_id = claims.subject().parse::<i32>()
+            .map_err(|_| Report::msg("刷新令牌无效或已过期"))?;
+        let user = self.infrastructure_layer.persistence.repository.user_repository.find_by_id(user_id).await?
+            .ok_or_else(|| Report::msg("用户不存在"))?;
+        self.domain_layer.user_domain.domain_service.ensure_active(&user)?;
+
+        // 比较并替换失败说明同一令牌正在被并发轮换，重新读取后按宽限期处理
+        for _ in 0..2 {
+            let now = Utc::now().timestamp();
+            let family = jwt.store.family(claims.family()).await?
+                .ok_or_else(|| Report::msg("刷新令牌已失效，请重新登录"))?;
+
+            match auth_service.rotation(&family, claims.id(), now, grace_period) {
+                RefreshRotation::Rotate => {
+                    let token = jwt.create_jwt(&user_id.to_string(), claims.family())?;
+                    let next = auth_service.rotate(
+                        &family,
+                        token.refresh_id().to_string(),
+                        token.access_id().to_string(),
+                        serde_json::to_string(&token)?,
+                        now,
+                    );
+
+                    if jwt.store.swap_family(claims.family(), &family, &next, jwt.refresh_ttl()).await? {
+                        jwt.store.blacklist(&family.access, now + grace_period as i64, jwt.jwt_config.jwt_ttl + grace_period).await?;
+                        return Ok(token);
+                    }
+                }
+                RefreshRotation::Replay(successor) => {
+                    return Ok(serde_json::from_str(&successor)?);
+                }
+                RefreshRotation::Reused => {
+                    warn!("-ApplicationLayer [User] refresh token reused, revoking family {} of user {}", claims.family(), user_id);
+                    jwt.store.remove_family(claims.family()).await?;
+                    jwt.store.blacklist(&family.access, now, jwt.jwt_config.jwt_ttl).await?;
+                    return Err(Report::msg("刷新令牌已被使用，请重新登录"));
+                }
+            }
+        }
+
+        Err(Report::msg("刷新令牌已失效，请重新登录"))
+    }
+
+    /// # Description
+    ///     退出登录：访问令牌立即加入黑名单直到过期，同时作废所属的刷新令牌族
+    /// # Params
+    ///     token: &str - 访问令牌
     /// # Return
     ///     Result<(), Report>: 成功返回 Ok() ，失败返回错误信息
-    pub async fn assign_role(&self, user_id: i32, role: String) -> Result<(), Report> {
-        // 从用户仓库中通过 user_id 查找用户
-        let user = match self.infrastructure_layer.persistence.repository.user_repository.find_by_id(user_id).await {
-            Ok(Some(user)) => user,
-            Ok(None) => return Err(Report::msg("用户不存在")),
-            Err(e) => return Err(Report::msg(format!("查找用户时出错: {}", e))),
+    pub async fn logout(&self, token: &str) -> Result<(), Report> {
+        let jwt = &self.infrastructure_layer.jwt;
+        let claims = jwt.validate_jwt(token)
+            .map_err(|_| Report::msg("访问令牌无效或已过期"))?;
+
+        let now = Utc::now().timestamp();
+        let ttl = (claims.expires_at() - now).max(1) as u64;
+        jwt.store.blacklist(claims.id(), now, ttl).await?;
+        jwt.store.remove_family(claims.family()).await?;
+
+        Ok(())
+    }
+
+    /// # Description
+    ///     对外公布的验签公钥，供其他服务验证本服务签发的令牌
+    /// # Return
+    ///     JsonWebKeySet: JWKS 文档
+    pub fn jwks(&self) -> JsonWebKeySet {
+        self.infrastructure_layer.jwt.jwks().clone()
+    }
+
+    /// # Description
+    ///     查询当前用户的资料
+    /// # Params
+    ///     user_id: i32 - 用户id
+    /// # Return
+    ///     Result<UserProfile, Report>: 用户资料
+    pub async fn profile(&self, user_id: i32) -> Result<UserProfile, Report> {
+        let user = self.active_user(user_id).await?;
+        self.to_profile(user).await
+    }
+
+    /// # Description
+    ///     修改昵称、头像或手机号，未传的字段保持不变；
+    ///     开启短信验证时，新手机号需要携带发送到该手机号的绑定验证码
+    /// # Params
+    ///     user_id: i32 - 用户id
+    ///     nickname: Option<String> - 新昵称
+    ///     avatar: Option<String> - 新头像地址
+    ///     mobile: Option<String> - 新手机号
+    ///     code: Option<String> - 新手机号的短信验证码
+    /// # Return
+    ///     Result<UserProfile, Report>: 修改后的用户资料
+    pub async fn update_profile(
+        &self,
+        user_id: i32,
+        nickname: Option<String>,
+        avatar: Option<String>,
+        mobile: Option<String>,
+        code: Option<String>,
+    ) -> Result<UserProfile, Report> {
+        let user_service = &self.domain_layer.user_domain.domain_service;
+        let user_repository = &self.infrastructure_layer.persistence.repository.user_repository;
+        let mut user = self.active_user(user_id).await?;
+
+        let nickname = nickname.map(|nickname| user_service.normalize_nickname(&nickname)).transpose()?;
+        let avatar = avatar.map(|avatar| user_service.normalize_avatar(&avatar)).transpose()?;
+        let mobile = match mobile {
+            Some(mobile) => {
+                let mobile = self.sms_application.normalize_mobile(&mobile)?;
+                if user.mobile.as_deref() == Some(mobile.as_str()) {
+                    None
+                } else {
+                    if user_repository.find_by_mobile_or_email_account(mobile.clone()).await?.is_some() {
+                        return Err(Report::msg("该手机号已被其他账号使用"));
+                    }
+                    if self.infrastructure_layer.config.sms.require_verification {
+                        let code = code.ok_or_else(|| Report::msg("请输入短信验证码"))?;
+                        self.sms_application.verify_code(&mobile, VerificationPurpose::BindMobile, &code).await?;
+                    }
+                    Some(mobile)
+                }
+            }
+            None => None,
         };
 
-        // 调用领域服务为用户分配角色
-        let updated_user = self.domain_layer.user_domain.domain_service.assign_role(user, role)?;
+        user.update_info(nickname, avatar, mobile);
+        user.updated_at = Utc::now();
+        user_repository.save(user.clone().into_active_model().reset_all()).await?;
+        info!("+ApplicationLayer [User] profile of user {} updated", user_id);
 
-        // 保存用户到仓储
-        match self.infrastructure_layer.persistence.repository.user_repository.save(updated_user.into()).await {
-            Ok(_) => Ok(()),
-            Err(e) => Err(Report::msg(format!("分配角色失败: {}", e))),
+        self.to_profile(user).await
+    }
+
+    /// # Description
+    ///     修改密码：校验旧密码与新密码强度，修改后除当前会话外的其他会话全部下线，并发送通知邮件
+    /// # Params
+    ///     user_id: i32 - 用户id
+    ///     token: &str - 当前访问令牌，用于保留当前会话
+    ///     old_password: String - 旧密码
+    ///     new_password: String - 新密码
+    /// # Return
+    ///     Result<(), Report>: 成功返回 Ok() ，失败返回错误信息
+    pub async fn change_password(&self, user_id: i32, token: &str, old_password: String, new_password: String) -> Result<(), Report> {
+        let user_service = &self.domain_layer.user_domain.domain_service;
+        let mut user = self.active_user(user_id).await?;
+
+        if !user.verify_password(old_password.clone()) {
+            return Err(Report::msg("旧密码错误"));
+        }
+        user_service.validate_password(&new_password)?;
+        if old_password == new_password {
+            return Err(Report::msg("新密码不能与旧密码相同"));
+        }
+
+        user.reset_password(new_password).map_err(Report::msg)?;
+        user.updated_at = Utc::now();
+        self.infrastructure_layer.persistence.repository.user_repository.save(user.clone().into_active_model().reset_all()).await?;
+        info!("+ApplicationLayer [User] password changed by user {}", user_id);
+
+        let current_family = self.token_family(token)?;
+        self.revoke_sessions(user_id, Some(&current_family)).await?;
+
+        if let Some(email) = user.email.filter(|email| !email.is_empty()) {
+            self.mail_application.notify(&email, MailTemplate::PasswordChanged).await;
+        }
+
+        Ok(())
+    }
+
+    /// # Description
+    ///     注销当前账号（软删除）：需要校验密码，名下钱包在账本或链上有资产时不能注销；
+    ///     注销时取消等待触发的条件单和未结束的定投计划，注销后所有会话下线
+    /// # Params
+    ///     user_id: i32 - 用户id
+    ///     token: &str - 当前访问令牌
+    ///     password: String - 密码
+    /// # Return
+    ///     Result<(), Report>: 成功返回 Ok() ，失败返回错误信息
+    pub async fn delete_account(&self, user_id: i32, token: &str, password: String) -> Result<(), Report> {
+        let mut user = self.active_user(user_id).await?;
+        if !user.verify_password(password) {
+            return Err(Report::msg("密码错误"));
+        }
+
+        let wallets = self.infrastructure_layer.persistence.repository.wallet_repository.find_by_user(user_id).await?;
+        let holdings = self.on_chain_holdings(&wallets).await?;
+        self.domain_layer.user_domain.domain_service.ensure_deletable(&wallets, &holdings)?;
+        self.cancel_automations(user_id).await?;
+
+        user.soft_delete();
+        user.updated_at = Utc::now();
+        self.infrastructure_layer.persistence.repository.user_repository.save(user.into_active_model().reset_all()).await?;
+        info!("+ApplicationLayer [User] user {} deleted own account", user_id);
+
+        self.revoke_sessions(user_id, None).await?;
+        self.logout(token).await
+    }
+
+    /// # Description
+    ///     查询未删除钱包在链上的 SOL 与 SPL 代币余额，只返回非零的持仓
+    async fn on_chain_holdings(&self, wallets: &[WalletModel]) -> Result<Vec<(String, f64)>, Report> {
+        let client = &self.infrastructure_layer.rpc.client;
+        let mut holdings = Vec::new();
+        for wallet in wallets.iter().filter(|wallet| wallet.deleted_at.is_none()) {
+            let Some(address) = wallet.pub_key.as_deref() else { continue };
+            let owner = Pubkey::from_str(address)?;
+
+            let lamports = client.get_balance(&owner).await?;
+            if lamports > 0 {
+                holdings.push(("SOL".to_string(), lamports_to_sol(lamports)));
+            }
+
+            let accounts = client.get_token_accounts_by_owner(&owner, TokenAccountsFilter::ProgramId(spl_token::id())).await?;
+            for account in accounts {
+                let data = serde_json::to_value(&account.account.data)?;
+                let info = &data["parsed"]["info"];
+                let amount = info["tokenAmount"]["uiAmount"].as_f64().unwrap_or_default();
+                if amount > 0.0 {
+                    let mint = info["mint"].as_str().unwrap_or(&account.pubkey).to_string();
+                    holdings.push((mint, amount));
+                }
+            }
+        }
+
+        Ok(holdings)
+    }
+
+    /// # Description
+    ///     取消用户等待触发的条件单和未结束的定投计划，已触发正在执行的条件单由评估任务收尾
+    async fn cancel_automations(&self, user_id: i32) -> Result<(), Report> {
+        let repository = &self.infrastructure_layer.persistence.repository;
+
+        for mut order in repository.order_repository.find_by_user(user_id).await? {
+            if order.status != OrderStatus::Open {
+                continue;
+            }
+            order.cancel("账号已注销".to_string()).map_err(Report::msg)?;
+            if repository.order_repository.save_if_status(order.clone(), OrderStatus::Open).await? {
+                let event = OrderEventModel::new(order.id, order.status.clone(), None, order.error.clone());
+                if let Err(e) = repository.order_repository.record_event(event).await {
+                    warn!("-ApplicationLayer [User] order {} event record failed: {}", order.id, e);
+                }
+            }
         }
+
+        for mut schedule in repository.dca_repository.find_by_user(user_id).await? {
+            let status = schedule.status.clone();
+            if !matches!(status, DcaStatus::Active | DcaStatus::Paused) {
+                continue;
+            }
+            schedule.cancel().map_err(Report::msg)?;
+            repository.dca_repository.save_if_status(schedule, status).await?;
+        }
+        info!("+ApplicationLayer [User] automations of user {} cancelled", user_id);
+
+        Ok(())
+    }
+
+    /// # Description
+    ///     列出当前用户仍然有效的登录会话，最近活跃的排在前面
+    /// # Params
+    ///     user_id: i32 - 用户id
+    ///     token: &str - 当前访问令牌，用于标记当前会话
+    /// # Return
+    ///     Result<Vec<UserSession>, Report>: 会话列表
+    pub async fn sessions(&self, user_id: i32, token: &str) -> Result<Vec<UserSession>, Report> {
+        let current_family = self.token_family(token)?;
+        let timestamp = |seconds: i64| (seconds > 0).then(|| DateTime::from_timestamp(seconds, 0)).flatten();
+
+        let mut sessions: Vec<UserSession> = self.infrastructure_layer.jwt.store.families(user_id).await?
+            .into_iter()
+            .map(|(family_id, family)| UserSession {
+                current: family_id == current_family,
+                session_id: family_id,
+                created_at: timestamp(family.created_at),
+                last_active_at: timestamp(family.rotated_at),
+            })
+            .collect();
+        sessions.sort_by(|a, b| b.last_active_at.cmp(&a.last_active_at));
+
+        Ok(sessions)
+    }
+
+    /// # Description
+    ///     加载用户并校验可用
+    async fn active_user(&self, user_id: i32) -> Result<UserModel, Report> {
+        let user = self.infrastructure_layer.persistence.repository.user_repository.find_by_id(user_id).await?
+            .ok_or_else(|| Report::msg("用户不存在"))?;
+        self.domain_layer.user_domain.domain_service.ensure_active(&user)?;
+
+        Ok(user)
+    }
+
+    /// # Description
+    ///     组装用户资料，注册时未填写的手机号或邮箱保存为空字符串，展示为空
+    async fn to_profile(&self, user: UserModel) -> Result<UserProfile, Report> {
+        let two_factor_enabled = self.two_factor_application.is_enabled(user.id).await?;
+
+        Ok(UserProfile {
+            id: user.id,
+            account: user.account,
+            nickname: user.nickname,
+            avatar: user.avatar,
+            mobile: user.mobile.filter(|mobile| !mobile.is_empty()),
+            email: user.email.filter(|email| !email.is_empty()),
+            level: user.level,
+            two_factor_enabled,
+            created_at: user.created_at,
+        })
+    }
+
+    /// # Description
+    ///     读取访问令牌所属的刷新令牌族
+    fn token_family(&self, token: &str) -> Result<String, Report> {
+        let claims = self.infrastructure_layer.jwt.validate_jwt(token)
+            .map_err(|_| Report::msg("访问令牌无效或已过期"))?;
+
+        Ok(claims.family().to_string())
+    }
+
+    /// # Description
+    ///     作废用户的刷新令牌族并把对应的访问令牌加入黑名单，keep 指定的会话保留
+    async fn revoke_sessions(&self, user_id: i32, keep: Option<&str>) -> Result<(), Report> {
+        let jwt = &self.infrastructure_layer.jwt;
+        let now = Utc::now().timestamp();
+
+        for (family_id, family) in jwt.store.families(user_id).await? {
+            if keep == Some(family_id.as_str()) {
+                continue;
+            }
+            jwt.store.remove_family(&family_id).await?;
+            jwt.store.blacklist(&family.access, now, jwt.jwt_config.jwt_ttl).await?;
+        }
+
+        Ok(())
+    }
+
+    /// # Description
+    ///     禁用用户，通过 user_id 禁用用户账户，并让该用户的所有会话下线
+    /// # Params
+    ///     user_id: i32 - 要禁用的用户 ID
+    ///     audit: AdminAuditActiveModel - 与用户在同一事务写入的审计记录
+    /// # Return
+    ///     Result<UserModel, Report>: 成功返回禁用后的用户，失败返回错误信息
+    pub async fn disable_user(&self, user_id: i32, audit: AdminAuditActiveModel) -> Result<UserModel, Report> {
+        let user = self.find_user(user_id).await?;
+
+        // 调用领域服务禁用用户
+        let mut user = self.domain_layer.user_domain.domain_service.disable_user(user)?;
+        user.updated_at = Utc::now();
+        self.infrastructure_layer.persistence.repository.user_repository.save_audited(user.clone().into_active_model().reset_all(), audit).await
+            .map_err(|e| Report::msg(format!("禁用用户失败: {}", e)))?;
+        info!("+ApplicationLayer [User] user {} disabled", user_id);
+
+        self.revoke_sessions(user_id, None).await?;
+
+        Ok(user)
+    }
+
+    /// # Description
+    ///     启用被禁用的用户
+    /// # Params
+    ///     user_id: i32 - 要启用的用户 ID
+    ///     audit: AdminAuditActiveModel - 与用户在同一事务写入的审计记录
+    /// # Return
+    ///     Result<UserModel, Report>: 成功返回启用后的用户，失败返回错误信息
+    pub async fn enable_user(&self, user_id: i32, audit: AdminAuditActiveModel) -> Result<UserModel, Report> {
+        let mut user = self.find_user(user_id).await?;
+
+        user.enable_user();
+        user.updated_at = Utc::now();
+        self.infrastructure_layer.persistence.repository.user_repository.save_audited(user.clone().into_active_model().reset_all(), audit).await?;
+        info!("+ApplicationLayer [User] user {} enabled", user_id);
+
+        Ok(user)
+    }
+
+    /// # Description
+    ///     调整用户级别，级别范围由管理后台配置限定
+    /// # Params
+    ///     user_id: i32 - 用户 ID
+    ///     level: i8 - 新的用户级别
+    ///     audit: AdminAuditActiveModel - 与用户在同一事务写入的审计记录
+    /// # Return
+    ///     Result<UserModel, Report>: 成功返回调整后的用户，失败返回错误信息
+    pub async fn change_level(&self, user_id: i32, level: i8, audit: AdminAuditActiveModel) -> Result<UserModel, Report> {
+        let config = &self.infrastructure_layer.config.admin;
+        self.domain_layer.user_domain.domain_service.validate_level(level, config.min_level, config.max_level)?;
+        let mut user = self.find_user(user_id).await?;
+
+        user.change_user_level(level);
+        user.updated_at = Utc::now();
+        self.infrastructure_layer.persistence.repository.user_repository.save_audited(user.clone().into_active_model().reset_all(), audit).await?;
+        info!("+ApplicationLayer [User] level of user {} changed to {}", user_id, level);
+
+        Ok(user)
+    }
+
+    /// # Description
+    ///     强制重置密码：把密码替换为随机值使旧密码失效，所有会话下线，
+    ///     绑定了邮箱时发送重置密码链接；只绑定手机号的用户可以通过短信验证码登录
+    /// # Params
+    ///     user_id: i32 - 用户 ID
+    ///     audit: AdminAuditActiveModel - 与用户在同一事务写入的审计记录
+    /// # Return
+    ///     Result<(UserModel, bool), Report>: 重置后的用户，以及是否已发送重置密码链接
+    pub async fn force_password_reset(&self, user_id: i32, audit: AdminAuditActiveModel) -> Result<(UserModel, bool), Report> {
+        let mut user = self.find_user(user_id).await?;
+
+        let password: String = StdRng::from_entropy().sample_iter(&Alphanumeric).take(32).map(char::from).collect();
+        user.reset_password(password).map_err(Report::msg)?;
+        user.updated_at = Utc::now();
+        self.infrastructure_layer.persistence.repository.user_repository.save_audited(user.clone().into_active_model().reset_all(), audit).await?;
+        info!("+ApplicationLayer [User] password of user {} reset by administrator", user_id);
+
+        self.revoke_sessions(user_id, None).await?;
+
+        let link_sent = match user.email.as_deref().filter(|email| !email.is_empty()) {
+            Some(email) => match self.mail_application.send_reset_link(email).await {
+                Ok(_) => true,
+                Err(e) => {
+                    warn!("-ApplicationLayer [User] reset link to user {} failed: {}", user_id, e);
+                    false
+                }
+            },
+            None => false,
+        };
+
+        Ok((user, link_sent))
+    }
+
+    /// # Description
+    ///     恢复已注销（软删除）的用户
+    /// # Params
+    ///     user_id: i32 - 用户 ID
+    ///     audit: AdminAuditActiveModel - 与用户在同一事务写入的审计记录
+    /// # Return
+    ///     Result<UserModel, Report>: 成功返回恢复后的用户，失败返回错误信息
+    pub async fn restore_user(&self, user_id: i32, audit: AdminAuditActiveModel) -> Result<UserModel, Report> {
+        let user = self.find_user(user_id).await?;
+
+        let mut user = self.domain_layer.user_domain.domain_service.restore_user(user)?;
+        user.updated_at = Utc::now();
+        self.infrastructure_layer.persistence.repository.user_repository.save_audited(user.clone().into_active_model().reset_all(), audit).await?;
+        info!("+ApplicationLayer [User] user {} restored", user_id);
+
+        Ok(user)
+    }
+
+    /// # Description
+    ///     加载用户，包括已禁用和已注销的用户
+    async fn find_user(&self, user_id: i32) -> Result<UserModel, Report> {
+        self.infrastructure_layer.persistence.repository.user_repository.find_by_id(user_id).await?
+            .ok_or_else(|| Report::msg("用户不存在"))
     }
 }
@@ -0,0 +1,3 @@
+mod verification_application;
+
+pub use verification_application::VerificationApplication;
\ No newline at end of file
@@ -0,0 +1,128 @@
+use std::sync::Arc;
+use chrono::Utc;
+use color_eyre::{Report, Result};
+use crate::core::domain::DomainLayer;
+use crate::core::domain::verification::entity::verification_code_entity::{Model as VerificationCodeModel, VerificationChannel, VerificationPurpose};
+use crate::core::domain::verification::repository::VerificationRepositoryInterface;
+use crate::core::domain::verification::service::VerificationCheck;
+use crate::core::infrastructure::InfrastructureLayer;
+
+/// # Description
+///     验证码应用服务：邮件与短信共用的验证码登记、发送间隔、校验与一次性使用
+/// # Fields
+///     domain_layer: Arc<DomainLayer> - 领域层
+///     infrastructure_layer: Arc<InfrastructureLayer> - 基础设施层
+pub struct VerificationApplication {
+    domain_layer: Arc<DomainLayer>,
+    infrastructure_layer: Arc<InfrastructureLayer>,
+}
+
+impl VerificationApplication {
+    /// # Description
+    ///     创建新的验证码应用服务实例
+    /// # Params
+    ///     infrastructure_layer: Arc<InfrastructureLayer> - 基础设施层的引用，用于获取验证码仓储
+    ///     domain_layer: Arc<DomainLayer> - 领域层的引用，用于获取验证码领域服务
+    /// # Return
+    ///     Self: 返回一个新的 `VerificationApplication` 实例
+    pub fn new(infrastructure_layer: Arc<InfrastructureLayer>, domain_layer: Arc<DomainLayer>) -> Self {
+        Self { domain_layer, infrastructure_layer }
+    }
+
+    /// # Description
+    ///     检查发送间隔后登记新的验证码，同一接收方同一用途的旧验证码随之作废
+    /// # Params
+    ///     channel: VerificationChannel - 发送渠道
+    ///     target: &str - 已规范化的接收方
+    ///     purpose: VerificationPurpose - 用途
+    ///     secret: &str - 验证码或令牌明文
+    ///     ttl: u64 - 有效期（秒）
+    ///     resend_interval: u64 - 最小发送间隔（秒）
+    /// # Return
+    ///     Result<VerificationCodeModel, Report>: 登记的验证码
+    pub async fn issue(
+        &self,
+        channel: VerificationChannel,
+        target: &str,
+        purpose: VerificationPurpose,
+        secret: &str,
+        ttl: u64,
+        resend_interval: u64,
+    ) -> Result<VerificationCodeModel, Report> {
+        let repository = &self.infrastructure_layer.persistence.repository.verification_repository;
+        let verification_service = &self.domain_layer.verification_domain.domain_service;
+
+        let latest = repository.find_latest(channel, target, purpose).await?;
+        if let Some(remaining) = verification_service.cooldown(latest.as_ref(), Utc::now(), resend_interval) {
+            return Err(Report::msg(format!("发送过于频繁，请 {} 秒后再试", remaining)));
+        }
+
+        let code = VerificationCodeModel::new(channel, target.to_string(), purpose, verification_service.digest(secret), ttl);
+        repository.issue(code).await
+    }
+
+    /// # Description
+    ///     校验验证码，通过后验证码即被使用；错误时累计失败次数
+    /// # Params
+    ///     channel: VerificationChannel - 发送渠道
+    ///     target: &str - 已规范化的接收方
+    ///     purpose: VerificationPurpose - 用途
+    ///     code: &str - 用户输入的验证码
+    ///     max_attempts: i32 - 最多允许的错误次数
+    /// # Return
+    ///     Result<(), Report>: 校验结果
+    pub async fn verify(&self, channel: VerificationChannel, target: &str, purpose: VerificationPurpose, code: &str, max_attempts: i32) -> Result<(), Report> {
+        let repository = &self.infrastructure_layer.persistence.repository.verification_repository;
+        let latest = repository.find_latest(channel, target, purpose).await?
+            .ok_or_else(|| Report::msg("请先获取验证码"))?;
+
+        match self.domain_layer.verification_domain.domain_service.check(&latest, code, Utc::now(), max_attempts) {
+            VerificationCheck::Passed => {
+                if !repository.consume(latest.id).await? {
+                    return Err(Report::msg("验证码已失效，请重新获取"));
+                }
+                Ok(())
+            }
+            VerificationCheck::Mismatch(remaining) => {
+                repository.record_failure(latest.id).await?;
+                Err(Report::msg(format!("验证码错误，还可尝试 {} 次", remaining)))
+            }
+            VerificationCheck::Expired => Err(Report::msg("验证码已失效，请重新获取")),
+            VerificationCheck::Exhausted => Err(Report::msg("验证码错误次数过多，请重新获取")),
+        }
+    }
+
+    /// # Description
+    ///     使用一次性令牌
+    /// # Params
+    ///     channel: VerificationChannel - 发送渠道
+    ///     purpose: VerificationPurpose - 用途
+    ///     token: &str - 令牌明文
+    /// # Return
+    ///     Result<Option<String>, Report>: 令牌有效时返回对应的接收方
+    pub async fn consume_token(&self, channel: VerificationChannel, purpose: VerificationPurpose, token: &str) -> Result<Option<String>, Report> {
+        let repository = &self.infrastructure_layer.persistence.repository.verification_repository;
+
+        let code_hash = self.domain_layer.verification_domain.domain_service.digest(token);
+        let Some(issued) = repository.find_by_hash(channel, purpose, &code_hash).await? else {
+            return Ok(None);
+        };
+        if issued.is_consumed() || issued.is_expired(Utc::now()) || !repository.consume(issued.id).await? {
+            return Ok(None);
+        }
+
+        Ok(Some(issued.target))
+    }
+
+    /// # Description
+    ///     作废验证码，用于消息发送失败时
+    /// # Params
+    ///     issued: &VerificationCodeModel - 登记的验证码
+    /// # Return
+    ///     Result<(), Report>: 作废结果
+    pub async fn revoke(&self, issued: &VerificationCodeModel) -> Result<(), Report> {
+        self.infrastructure_layer.persistence.repository.verification_repository.consume(issued.id).await?;
+
+        Ok(())
+    }
+}
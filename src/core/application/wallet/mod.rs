@@ -1,3 +1,3 @@
 mod wallet_application;
 
-pub use wallet_application::WalletApplication;
\ No newline at end of file
+pub use wallet_application::{ExportedKey, WalletApplication};
\ No newline at end of file
@@ -12,10 +12,10 @@ use bip39::{Language, Mnemonic, MnemonicType, Seed};
 use chrono::{DateTime, NaiveDateTime, Utc};
 use color_eyre::{Report, Result};
 use reqwest::StatusCode;
-use sea_orm::IntoActiveModel;
+use sea_orm::{ActiveModelTrait, IntoActiveModel};
 use serde::{Deserialize, Serialize};
-use serum_dex::state::MarketState;
-use tracing::error;
+use serde_json::json;
+use tracing::{error, info, warn};
 use spl_token::instruction as token_instruction;
 use solana_client::nonblocking::rpc_client::RpcClient;
 use solana_client::rpc_config::RpcBlockConfig;
@@ -28,20 +28,20 @@ use solana_transaction_status::{
 use spl_associated_token_account::{
     create_associated_token_account,
     get_associated_token_address,
+    instruction::create_associated_token_account_idempotent,
     processor::process_instruction
 };
 use solana_sdk::{
     account::from_account,
     transaction::Transaction,
     commitment_config::CommitmentConfig,
-    signature::{keypair_from_seed, write_keypair_file, Signer, Keypair}
+    signature::{keypair_from_seed, read_keypair, write_keypair, write_keypair_file, Signature, Signer, Keypair}
 };
 use solana_program::{
-    system_instruction::{create_nonce_account, transfer},
+    system_instruction::transfer,
     instruction::{AccountMeta, Instruction},
     program_error::ProgramError,
     pubkey::Pubkey,
-    system_program,
     sysvar,
     program::invoke,
     account_info::AccountInfo,
@@ -55,14 +55,41 @@ use solana_sdk::account::Account;
 use solana_sdk::transaction::VersionedTransaction;
 use spl_token_swap::instruction::{SwapInstruction, Swap, swap};
 use spl_token_swap::solana_program::pubkey;
+use crate::core::application::webhook::WebhookApplication;
 use crate::core::domain::DomainLayer;
+use crate::core::domain::wallet::entity::Model as WalletModel;
 use crate::core::domain::wallet::repository::WalletRepositoryInterface;
+use crate::core::domain::watcher::entity::deposit_entity::{ActiveModel as DepositActiveModel, Model as DepositModel};
+use crate::core::domain::watcher::repository::DepositRepositoryInterface;
+use crate::core::domain::webhook::entity::webhook_event_entity::WebhookEventType;
 use crate::core::infrastructure::InfrastructureLayer;
-
+use crate::core::infrastructure::keystore::KeyStore;
+
+/// # Description
+///     等待交易确认的轮询间隔
+const CONFIRM_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// # Description
+///     等待交易确认的最长时间，区块哈希约 60-90 秒过期，超过后交易不会再上链
+const CONFIRM_TIMEOUT: Duration = Duration::from_secs(120);
+
+/// # Description
+///     导出的钱包密钥，只在通过两步验证后返回
+/// # Fields
+///     wallet_id: i32 - 钱包id
+///     pub_key: Option<String> - 钱包公钥
+///     privy_key: Option<String> - 钱包私钥
+#[derive(Debug, Serialize)]
+pub struct ExportedKey {
+    pub wallet_id: i32,
+    pub pub_key: Option<String>,
+    pub privy_key: Option<String>,
+}
 
 pub struct WalletApplication {
     domain_layer: Arc<DomainLayer>,
-    infrastructure_layer: Arc<InfrastructureLayer>
+    infrastructure_layer: Arc<InfrastructureLayer>,
+    webhook_application: Arc<WebhookApplication>,
 }
 
 /// Solana 钱包基础功能
@@ -72,37 +99,33 @@ impl WalletApplication {
     /// # Params
     ///     infrastructure_layer: Arc<InfrastructureLayer> - 基础设施层的引用，用于获取共享服务如 JWT
     ///     domain_layer: Arc<DomainLayer> - 领域层的引用，用于获取用户领域服务
+    ///     webhook_application: Arc<WebhookApplication> - Webhook 通知应用，用于推送充值入账事件
     /// # Return
     ///     Self: 返回一个新的 `WalletApplication` 实例
-    pub fn new(infrastructure_layer: Arc<InfrastructureLayer>, domain_layer: Arc<DomainLayer>) -> Self {
-        Self { domain_layer, infrastructure_layer }
+    pub fn new(infrastructure_layer: Arc<InfrastructureLayer>, domain_layer: Arc<DomainLayer>, webhook_application: Arc<WebhookApplication>) -> Self {
+        Self { domain_layer, infrastructure_layer, webhook_application }
     }
 
 
     /// # Description
     ///     生成钱包
     /// # Params
-    ///     None
+    ///     user_id: i32 - 钱包所属用户
     /// # Return
     ///     Result<(), Report>: 成功返回 Ok() ，失败返回错误信息
-    pub async fn generation_wallet(&self) -> Result<(), Report> {
-        // 生成公钥
-        let from_pubkey = Pubkey::new_unique();
-        let nonce_pubkey = Pubkey::new_unique();
-        let authorized = nonce_pubkey;
-        let ixs = create_nonce_account(&from_pubkey, &nonce_pubkey, &authorized, 42);
-        assert_eq!(ixs.len(), 2);
-        let ix = &ixs[0];
-        assert_eq!(ix.program_id, system_program::id());
-        let pubkeys: Vec<_> = ix.accounts.iter().map(|am| am.pubkey).collect();
-        assert!(pubkeys.contains(&from_pubkey));
-        assert!(pubkeys.contains(&nonce_pubkey));
+    pub async fn generation_wallet(&self, user_id: i32) -> Result<(), Report> {
+        // 生成密钥对，私钥编码为 keypair 文件相同的 JSON 字节数组格式，以主密钥加密后保存，与 load_keypair 对应
+        let keypair = Keypair::new();
+        let from_pubkey = keypair.pubkey();
+        let privy_key = write_keypair(&keypair, &mut Vec::new())
+            .map_err(|e| Report::msg(format!("钱包私钥编码失败: {}", e)))?;
+        let privy_key = self.infrastructure_layer.keystore.seal(&from_pubkey.to_string(), &privy_key)?;
 
         // 调用领域服务生成钱包实体
         let new_wallet = self.domain_layer.wallet_domain.domain_service.generation_wallet(
-            1,
-            from_pubkey.to_string(),
+            user_id,
             from_pubkey.to_string(),
+            privy_key,
         );
 
         // 调用仓库接口生成钱包实体
@@ -118,28 +141,86 @@ impl WalletApplication {
     }
 
     /// # Description
-    ///     为钱包添加金额
-    /// # Param
-    ///     id: u64: 钱包的唯一标识符
-    ///     amount: f64: 要添加的金额
+    ///     导出钱包私钥，调用前须已通过两步验证
+    /// # Params
+    ///     user_id: i32 - 当前用户
+    ///     wallet_id: i32 - 钱包id
+    /// # Return
+    ///     Result<ExportedKey, Report>: 钱包密钥
+    pub async fn export_key(&self, user_id: i32, wallet_id: i32) -> Result<ExportedKey, Report> {
+        let wallet = match self.infrastructure_layer.persistence.repository.wallet_repository.find_by_id(wallet_id as u64).await? {
+            Some(wallet) if wallet.user_id == user_id && wallet.deleted_at.is_none() => wallet,
+            _ => return Err(Report::msg("钱包不存在")),
+        };
+        let privy_key = Self::open_privy_key(&self.infrastructure_layer.keystore, &wallet)?;
+        warn!("-ApplicationLayer [Wallet] user {} exported the key of wallet {}", user_id, wallet.id);
+
+        Ok(ExportedKey { wallet_id: wallet.id, pub_key: wallet.pub_key, privy_key: Some(privy_key) })
+    }
+
+    /// # Description
+    ///     启动后台任务，把仍以明文保存的历史钱包私钥加密后写回
+    /// # Params
+    ///     None
     /// # Return
-    ///     Result<(), String>: 处理结果
-    pub async fn deposit(&self, id: u64, amount: f64) -> Result<(), Report> {
-        let wallet = self.infrastructure_layer.persistence.repository.wallet_repository.find_by_id(id).await?;
-
-        match wallet {
-            None => {}
-            Some(wallet) => {
-                self.domain_layer.wallet_domain.domain_service.deposit(wallet.clone(), amount)?;
-                // 将 WalletModel 转换为 WalletActiveModel
-                let wallet_model =  wallet.clone().into_active_model();
-                self.infrastructure_layer.persistence.repository.wallet_repository.save(wallet_model).await?;
+    ///     None
+    pub fn spawn_key_sealer(self: &Arc<Self>) {
+        let application = Arc::clone(self);
+        tokio::spawn(async move {
+            if let Err(e) = application.seal_plaintext_keys().await {
+                error!("-ApplicationLayer [Wallet] seal plaintext keys failed: {}", e);
+            }
+        });
+    }
+
+    /// # Description
+    ///     加密仍以明文保存的钱包私钥，单个钱包失败只记录日志
+    /// # Params
+    ///     None
+    /// # Return
+    ///     Result<(), Report>: 成功返回 Ok() ，失败返回错误信息
+    pub async fn seal_plaintext_keys(&self) -> Result<(), Report> {
+        let repository = &self.infrastructure_layer.persistence.repository.wallet_repository;
+        let keystore = &self.infrastructure_layer.keystore;
+
+        for mut wallet in repository.find_unsealed().await? {
+            let (Some(pub_key), Some(privy_key)) = (wallet.pub_key.clone(), wallet.privy_key.clone()) else {
+                continue;
+            };
+            match keystore.seal(&pub_key, &privy_key) {
+                Ok(sealed) => {
+                    wallet.privy_key = Some(sealed);
+                    wallet.updated_at = Utc::now();
+                    repository.save(wallet.into_active_model().reset_all()).await?;
+                }
+                Err(e) => error!("-ApplicationLayer [Wallet] wallet {} key not sealed: {}", wallet.id, e),
             }
         }
+        info!("+ApplicationLayer [Wallet] plaintext wallet keys sealed.");
 
         Ok(())
     }
 
+    /// # Description
+    ///     为钱包入账一笔充值：充值记录写入与余额增加在同一事务中完成，成功后推送充值通知
+    /// # Param
+    ///     deposit: DepositActiveModel: 充值记录
+    /// # Return
+    ///     Result<DepositModel, Report>: 保存后的充值记录，钱包不存在时返回错误
+    pub async fn deposit(&self, deposit: DepositActiveModel) -> Result<DepositModel, Report> {
+        let (deposit, balance) = self.infrastructure_layer.persistence.repository.deposit_repository.credit(deposit).await?;
+
+        self.webhook_application.publish(deposit.user_id, WebhookEventType::DepositCredited, json!({
+            "wallet_id": deposit.wallet_id,
+            "address": deposit.address,
+            "amount": deposit.amount,
+            "balance": balance,
+            "signature": deposit.signature,
+        })).await;
+
+        Ok(deposit)
+    }
+
 
     /// # Description
     ///     获取 Solana 集群的当前版本和时间信息
@@ -278,6 +359,35 @@ impl WalletApplication {
         Ok(account)
     }
 
+    /// # Description
+    ///     解密钱包私钥字段，得到 keypair 文件相同的 JSON 字节数组格式的私钥（见 generation_wallet）
+    /// # Params
+    ///     keystore: &KeyStore - 钱包私钥加密组件
+    ///     wallet: &WalletModel - 钱包
+    /// # Return
+    ///     Result<String, Report>: 成功时返回私钥明文，私钥缺失或无法解密时返回错误信息。
+    pub fn open_privy_key(keystore: &KeyStore, wallet: &WalletModel) -> Result<String, Report> {
+        let privy_key = wallet.privy_key.as_deref()
+            .filter(|privy_key| !privy_key.is_empty())
+            .ok_or_else(|| Report::msg("钱包私钥缺失"))?;
+        let pub_key = wallet.pub_key.as_deref().ok_or_else(|| Report::msg("钱包公钥缺失"))?;
+
+        keystore.open(pub_key, privy_key)
+    }
+
+    /// # Description
+    ///     解密钱包私钥并还原签名密钥对，只在签名时调用
+    /// # Params
+    ///     keystore: &KeyStore - 钱包私钥加密组件
+    ///     wallet: &WalletModel - 钱包
+    /// # Return
+    ///     Result<Keypair, Report>: 成功时返回密钥对，私钥缺失、无法解密或格式错误时返回错误信息。
+    pub fn load_keypair(keystore: &KeyStore, wallet: &WalletModel) -> Result<Keypair, Report> {
+        let privy_key = Self::open_privy_key(keystore, wallet)?;
+
+        read_keypair(&mut privy_key.as_bytes()).map_err(|e| Report::msg(format!("钱包私钥解析失败: {}", e)))
+    }
+
     /// Make a call to the raydium api endpoint to retrieve all liquidity pools.
     pub async fn get_all_liquidity_pools() -> Result<(), Report> {
         let response = reqwest::get("https://api.raydium.io/v2/sdk/liquidity/mainnet.json")
@@ -338,8 +448,8 @@ impl WalletApplication {
     ///     to_key: &str - 要转移的地址
     ///     sol_amount: f64 - 要转移的金额
     /// # Return
-    ///     Result<(), Report>: 成功时返回Ok()，失败时返回错误信息。
-    pub async fn transfer_sol(client: &RpcClient, keypair: &Keypair, to_key: &str, sol_amount: f64) -> Result<(), Report> {
+    ///     Result<String, Report>: 成功时返回转账交易签名，失败时返回错误信息。
+    pub async fn transfer_sol(client: &RpcClient, keypair: &Keypair, to_key: &str, sol_amount: f64) -> Result<String, Report> {
         // 将地址转为 Pubkey 对象
         let to_pubkey = Pubkey::from_str(to_key)?;
 
@@ -349,8 +459,10 @@ impl WalletApplication {
         // 通过系统程序的创建转账指令进行交易
         let transfer_instruction = system_instruction::transfer(&keypair.pubkey(), &to_pubkey, lamports);
 
-        // 获取最新的区块哈希
-        let latest_blockhash = client.get_latest_blockhash().await?;
+        // 获取最新的区块哈希及其最后有效区块高度，超过该高度仍未确认的交易不会再上链
+        let (latest_blockhash, last_valid_block_height) = client
+            .get_latest_blockhash_with_commitment(CommitmentConfig::confirmed())
+            .await?;
 
         // 签署交易需要密钥对和最新的区块哈希
         // 注：由于转账交易会产生相关成本，故必须交易行为进行买单
@@ -361,31 +473,47 @@ impl WalletApplication {
             latest_blockhash,
         );
 
-        // 等待请求获取最后一个区块链 hash 操作
-        let wait_milis = Duration::from_millis(100);
-        print!("Waiting to confirm");
-        io::stdout().flush()?;
-
         // 发送交易
-        match client.send_transaction(&transaction).await {
-            Ok(signature) => loop {
-                // 等待交易被确认
-                if let Ok(confirmed) = client.confirm_transaction(&signature).await {
-                    if confirmed {
-                        println!("\nTransfer of sol was confirmed");
-                        break;
+        let signature = client.send_transaction(&transaction).await
+            .map_err(|e| Report::msg(format!("SOL 转账失败: {}", e)))?;
+
+        Self::confirm_signature(client, &signature, last_valid_block_height).await?;
+        info!("+ApplicationLayer [Wallet] transfer of {} SOL to {} confirmed: {}", sol_amount, to_key, signature);
+
+        Ok(signature.to_string())
+    }
+
+    /// # Description
+    ///     等待交易确认：交易失败、区块哈希过期（超过最后有效区块高度）或超过最长等待时间时返回错误，
+    ///     避免丢失的交易让调用方无限等待
+    /// # Params
+    ///     client: &RpcClient - RPC 客户端实例
+    ///     signature: &Signature - 交易签名
+    ///     last_valid_block_height: u64 - 交易区块哈希的最后有效区块高度
+    /// # Return
+    ///     Result<(), Report>: 确认成功返回 Ok()，否则返回错误信息。
+    async fn confirm_signature(client: &RpcClient, signature: &Signature, last_valid_block_height: u64) -> Result<(), Report> {
+        tokio::time::timeout(CONFIRM_TIMEOUT, Self::poll_signature(client, signature, last_valid_block_height)).await
+            .map_err(|_| Report::msg(format!("交易 {} 确认超时", signature)))?
+    }
+
+    /// # Description
+    ///     轮询交易状态，直到确认、失败或区块哈希过期
+    async fn poll_signature(client: &RpcClient, signature: &Signature, last_valid_block_height: u64) -> Result<(), Report> {
+        let commitment = CommitmentConfig::confirmed();
+        loop {
+            match client.get_signature_status_with_commitment(signature, commitment).await {
+                Ok(Some(Ok(()))) => return Ok(()),
+                Ok(Some(Err(e))) => return Err(Report::msg(format!("交易 {} 执行失败: {}", signature, e))),
+                Ok(None) => {
+                    if client.get_block_height_with_commitment(commitment).await? > last_valid_block_height {
+                        return Err(Report::msg(format!("交易 {} 已过期未确认", signature)));
                     }
                 }
-                print!(".");
-                io::stdout().flush()?;
-                tokio::time::sleep(wait_milis).await;
-            },
-            Err(e) => {
-                println!("Error transferring sol: {}", e);
+                Err(e) => warn!("-ApplicationLayer [Wallet] status of {} unavailable: {}", signature, e),
             }
+            tokio::time::sleep(CONFIRM_POLL_INTERVAL).await;
         }
-
-        Ok(())
     }
 
 
@@ -431,7 +559,8 @@ impl WalletApplication {
         for transaction_status in &block.transactions {
             // 解码这个对象
             // 该对象包含一个 message 字段，该字段包含 instructions 和 static_account_keys。
-            let transaction = transaction_status.transaction.decode().unwrap();
+            let transaction = transaction_status.transaction.decode()
+                .ok_or_else(|| Report::msg("交易解码失败"))?;
             // 此 static_account_keys 数组包含交易中使用的程序 ID（程序公钥）
             let account_keys = transaction.message.static_account_keys();
 
@@ -462,12 +591,11 @@ impl WalletApplication {
         }
 
         // 确定了投票交易总数，方法是从交易总数中减去用户交易总数
-        // 使用 checked_sub 来防止溢出
+        // 使用 saturating_sub 来防止溢出
         let vote_transactions_count = block
             .transactions
             .len()
-            .checked_sub(user_transactions_count as usize)
-            .expect("underflow");
+            .saturating_sub(user_transactions_count as usize);
 
         println!("solana total txs: {}", block.transactions.len());
         println!("solana user txs: {}", user_transactions_count);
@@ -504,80 +632,6 @@ impl WalletApplication {
     }
 
 
-    /// # Description
-    ///     计算在指定时间范围内 Solana 网络的平均每秒交易量（TPS）
-    /// # Params
-    ///     client: &RpcClient - RPC 客户端实例
-    ///     threshold_seconds: i64 - 计算TPS的时间窗口（秒）
-    /// # Return
-    ///     Result<(), Report>: 成功时返回 `Ok(())`，失败时返回错误信息
-    pub async fn calculate_for_range(client: &RpcClient, threshold_seconds: i64) -> Result<(), Report> {
-        // 记录计算开始时间
-        let calculation_start = Utc::now();
-
-        // 获取当前区块号（slot）
-        let latest_block_number = client.get_slot().await?;
-
-        // 获取当前区块的信息
-        let mut current_block = Self::get_block(&client, latest_block_number).await?;
-
-        // 从当前区块获取最新的时间戳
-        let newest_timestamp = current_block.block_time.unwrap();
-
-        // 计算时间戳阈值，确定最早应该查询的时间（最新时间 - 阈值秒数 = 时间戳阈值）
-        let timestamp_threshold = newest_timestamp.checked_sub(threshold_seconds).unwrap();
-
-        // 统计总交易量，用来跟踪用户交易的总数
-        let mut total_transactions_count: u64 = 0;
-
-        // 循环获取之前的区块信息，直到达到时间戳阈值
-        let oldest_timestamp = loop {
-            // 获取上一个区块数
-            let prev_block_number = current_block.parent_slot;
-
-            // 获取上一个区块的信息
-            let prev_block = Self::get_block(client, prev_block_number).await?;
-
-            // 统计当前区块的用户交易量
-            let transactions_count = WalletApplication::count_user_transactions(&current_block).await?;
-
-            // 格式化当前区块的时间并输出
-            let naive_datetime = NaiveDateTime::from_timestamp(current_block.block_time.unwrap(), 0);
-            let utc_dt: DateTime<Utc> = DateTime::from_utc(naive_datetime, Utc);
-            println!("Block time: {}", utc_dt.format("%Y-%m-%d %H:%M:%S"));
-
-            // 累加用户交易量
-            total_transactions_count = total_transactions_count.checked_add(transactions_count).expect("overflow");
-
-            // 检查是否已达到时间戳阈值，如果条件为真，将跳出循环并返回前一个块的时间戳
-            let prev_block_timestamp = prev_block.block_time.unwrap();
-            if prev_block_timestamp <= timestamp_threshold {
-                break prev_block_timestamp;
-            }
-
-            // 检查是否已到达链中的第一个块，如果条件为真，将跳出循环并返回前一个块的时间戳
-            if prev_block.block_height.unwrap() == 0 {
-                break prev_block_timestamp;
-            }
-
-            // 更新当前区块为前一个区块，继续循环
-            current_block = prev_block;
-        };
-
-        // 计算并输出每秒交易量（TPS）
-        let transactions_per_second = Self::calculate_tps(oldest_timestamp, newest_timestamp, total_transactions_count).await?;
-
-        // 记录计算结束时间并计算耗时
-        let calculation_end = Utc::now();
-        let duration = calculation_end.signed_duration_since(calculation_start).to_std()?;
-
-        println!("calculation took: {} seconds", duration.as_secs());
-        println!("total transactions per second over period: {}", transactions_per_second);
-
-        Ok(())
-    }
-
-
     /// 创建账户
     pub async fn create_account(client: &solana_client::rpc_client::RpcClient, fee_payer: &Keypair, new_account: &Keypair, ) -> Result<(), Report> {
         // Specify account data length
@@ -694,7 +748,7 @@ impl WalletApplication {
         source_usdt_account: &Pubkey,   // 来源账户（您的 USDT 代币账户）
         recipient_usdt_account: &Pubkey, // 目标账户（接收 USDT 的账户）
         usdt_amount: u64,              // 转账数量（USDT的最小单位）
-    ) -> Result<(), Box<dyn std::error::Error>> {
+    ) -> Result<String, Box<dyn std::error::Error>> {
         // 获取最新的区块哈希
         let blockhash = connection.get_latest_blockhash().await?;
 
@@ -719,7 +773,66 @@ impl WalletApplication {
         let transfer_signature = connection.send_and_confirm_transaction(&tx).await?;
         println!("USDT transfer confirmed: {}", transfer_signature);
 
-        Ok(())
+        Ok(transfer_signature.to_string())
+    }
+
+    /// # Description
+    ///     按 UI 数量转账 SPL 代币，收发双方均使用关联代币账户
+    /// # Params
+    ///     connection: &RpcClient - RPC 客户端实例
+    ///     owner_keypair: &Keypair - 转出方密钥对
+    ///     mint: &Pubkey - 代币 Mint 公钥
+    ///     recipient: &Pubkey - 接收方钱包地址
+    ///     ui_amount: f64 - 转账数量（按代币精度换算前）
+    /// # Return
+    ///     Result<String, Report> - 成功时返回转账交易签名，失败时返回错误信息。
+    pub async fn transfer_token(
+        connection: &RpcClient,
+        owner_keypair: &Keypair,
+        mint: &Pubkey,
+        recipient: &Pubkey,
+        ui_amount: f64,
+    ) -> Result<String, Report> {
+        // 读取代币精度，将 UI 数量换算为最小单位
+        let mint_account = Self::get_account_info(connection, mint).await?;
+        let mint_state = spl_token::state::Mint::unpack(&mint_account.data)?;
+        let amount = (ui_amount * 10f64.powi(mint_state.decimals as i32)).round() as u64;
+
+        let source = get_associated_token_address(&owner_keypair.pubkey(), mint);
+        let destination = get_associated_token_address(recipient, mint);
+
+        // 接收方的关联代币账户不存在时由转出方付费创建，已存在时该指令不做任何事
+        let create_destination = create_associated_token_account_idempotent(
+            &owner_keypair.pubkey(),
+            recipient,
+            mint,
+            &spl_token::id(),
+        );
+        let transfer_instruction = token_instruction::transfer(
+            &spl_token::id(),
+            &source,
+            &destination,
+            &owner_keypair.pubkey(),
+            &[],
+            amount,
+        )?;
+
+        let (blockhash, last_valid_block_height) = connection
+            .get_latest_blockhash_with_commitment(CommitmentConfig::confirmed())
+            .await?;
+        let transaction = Transaction::new_signed_with_payer(
+            &[create_destination, transfer_instruction],
+            Some(&owner_keypair.pubkey()),
+            &[owner_keypair],
+            blockhash,
+        );
+
+        let signature = connection.send_transaction(&transaction).await
+            .map_err(|e| Report::msg(format!("代币转账失败: {}", e)))?;
+        Self::confirm_signature(connection, &signature, last_valid_block_height).await?;
+        info!("+ApplicationLayer [Wallet] transfer of {} {} to {} confirmed: {}", ui_amount, mint, recipient, signature);
+
+        Ok(signature.to_string())
     }
 
     /// # Description
@@ -733,7 +846,7 @@ impl WalletApplication {
     ///     slip: f64 - 允许的滑点
     ///     recipient_usdt_account: USDT 代币账户
     /// # Return
-    ///     Result<(), Box<dyn std::error::Error>> - 成功时返回 Ok()，失败时返回错误信息。
+    ///     Result<String, Box<dyn std::error::Error>> - 成功时返回兑换交易签名，失败时返回错误信息。
     pub async fn perform_swap(
         connection: &RpcClient,
         buyer_keypair: &Keypair,
@@ -742,16 +855,54 @@ impl WalletApplication {
         sol_amount: f64,
         slip: f64,
         recipient_usdt_account: &Pubkey,
-    ) -> Result<(), Box<dyn Error>> {
+    ) -> Result<String, Box<dyn Error>> {
+        let quote = Self::quote_swap(&buyer_keypair.pubkey(), sol_mint_pubkey, usdt_mint_pubkey, sol_amount, slip).await?;
+        let signature_result = Self::execute_swap(connection, buyer_keypair, quote).await?;
+
+        // 向 USDT 账户转账
+        // let transfer_instruction = token_instruction::transfer(
+        //     &spl_token::id(),
+        //     &usdt_mint_pubkey,                      // 来源账户（USDT 池的代币账户）
+        //     recipient_usdt_account,                 // 目标账户（你的 USDT 代币账户）
+        //     &buyer_keypair.pubkey(),                // 授权签名者
+        //     &[],                      // 任何其他签名者
+        //     sol_amount as u64,                      // 转账数量
+        // )?;
+        //
+        // // 创建交易
+        // let mut tx = Transaction::new_with_payer(
+        //     &[transfer_instruction],
+        //     Some(&buyer_keypair.pubkey()),
+        // );
+        // tx.sign(&[buyer_keypair], blockhash);
+        //
+        // // 发送并确认代币转账交易
+        // let transfer_signature = connection.send_and_confirm_transaction(&tx).await?;
+        // println!("Transfer transaction confirmed: {}", transfer_signature);
+
+        Ok(signature_result)
+    }
+
+    /// # Description
+    ///     向 solxtence API 请求兑换报价与待签名的交易，执行前可按报价的输出数量校验滑点
+    /// # Params
+    ///     payer: &Pubkey - 付款方地址
+    ///     input_mint: &Pubkey - 输入代币的 Mint 公钥
+    ///     output_mint: &Pubkey - 输出代币的 Mint 公钥
+    ///     amount: f64 - 输入代币数量
+    ///     slip: f64 - 允许的滑点（百分比）
+    /// # Return
+    ///     Result<SwapQuote, Box<dyn std::error::Error>> - 成功时返回兑换报价，失败时返回错误信息。
+    pub async fn quote_swap(payer: &Pubkey, input_mint: &Pubkey, output_mint: &Pubkey, amount: f64, slip: f64) -> Result<SwapQuote, Box<dyn Error>> {
         // 定义交易参数
         let params = vec![
-            ("from", sol_mint_pubkey.to_string()),
-            ("to", usdt_mint_pubkey.to_string()),
-            ("amount", sol_amount.to_string()), // 输入 SOL 的数量
-            ("slip", slip.to_string()),         // 滑点
-            ("payer", buyer_keypair.pubkey().to_string()), // 付款方地址
-            ("fee", "0.00009".to_string()),     // 优先费用
-            ("txType", "v0".to_string()),       // 交易版本
+            ("from", input_mint.to_string()),
+            ("to", output_mint.to_string()),
+            ("amount", amount.to_string()), // 输入代币的数量
+            ("slip", slip.to_string()),     // 滑点
+            ("payer", payer.to_string()),   // 付款方地址
+            ("fee", "0.00009".to_string()), // 优先费用
+            ("txType", "v0".to_string()),   // 交易版本
         ];
 
         // 使用 Reqwest 发出 GET 请求获取 swap 交易信息
@@ -761,61 +912,57 @@ impl WalletApplication {
             serde_urlencoded::to_string(&params)?
         );
         let response_data = client.get(&swap_url).send().await?.text().await?;
-        println!("response_data. {:#?}", response_data);
 
         // 解析响应
         let swap_response: SwapResponse = serde_json::from_str(&*response_data)?;
-        println!("response. {:#?}", swap_response);
+        info!(
+            "+ApplicationLayer [Wallet] swap quote {} {} -> {} {} (min {})",
+            swap_response.swap_details.inputAmount, input_mint,
+            swap_response.swap_details.outputAmount, output_mint,
+            swap_response.swap_details.minimumOutputAmount,
+        );
+
+        if swap_response.transaction.tx_type != "v0" {
+            return Err(format!("不支持的交易版本: {}", swap_response.transaction.tx_type).into());
+        }
+
+        Ok(SwapQuote {
+            input_amount: swap_response.swap_details.inputAmount,
+            output_amount: swap_response.swap_details.outputAmount,
+            minimum_output_amount: swap_response.swap_details.minimumOutputAmount,
+            serialized_tx: swap_response.transaction.serialized_tx,
+        })
+    }
 
+    /// # Description
+    ///     签署并发送兑换报价中的交易
+    /// # Params
+    ///     connection: &RpcClient - RPC 客户端实例
+    ///     buyer_keypair: &Keypair - 买家的密钥对
+    ///     quote: SwapQuote - 兑换报价
+    /// # Return
+    ///     Result<String, Box<dyn std::error::Error>> - 成功时返回兑换交易签名，失败时返回错误信息。
+    pub async fn execute_swap(connection: &RpcClient, buyer_keypair: &Keypair, quote: SwapQuote) -> Result<String, Box<dyn Error>> {
         // 获取最新的区块哈希
         let blockhash = connection.get_latest_blockhash().await?;
 
-        // 定义 transaction 为 VersionedTransaction 类型
-        let mut transaction: VersionedTransaction;
-        // 反序列化交易并签署
-        if swap_response.transaction.tx_type == "v0" {
-            // `v0` 交易使用 `VersionedTransaction`
-            let serialized_tx = &swap_response.transaction.serialized_tx;
-
-            // 解码并反序列化
-            let decoded_tx = base64::decode(serialized_tx)?;  // 将 Base64 解码后的字节存储为 Vec<u8>
-            let mut versioned_transaction: VersionedTransaction = bincode::deserialize(decoded_tx.as_slice())?;  // 使用 bincode 反序列化
-
-            // 设置 recent_blockhash
-            versioned_transaction.message.set_recent_blockhash(blockhash);
-
-            // 签署交易
-            // 使用 `sign` 签署交易, 传递一个包含买家 keypair 的数组
-            let signature = buyer_keypair.try_sign_message(&versioned_transaction.message.serialize()).unwrap();
-            versioned_transaction.signatures = vec![signature];
-
-            // 发送并确认交易
-            let signature_result = connection.send_and_confirm_transaction(&versioned_transaction).await?;
-            println!("Transaction confirmed: {}", signature_result);
-
-            // 向 USDT 账户转账
-            // let transfer_instruction = token_instruction::transfer(
-            //     &spl_token::id(),
-            //     &usdt_mint_pubkey,                      // 来源账户（USDT 池的代币账户）
-            //     recipient_usdt_account,                 // 目标账户（你的 USDT 代币账户）
-            //     &buyer_keypair.pubkey(),                // 授权签名者
-            //     &[],                      // 任何其他签名者
-            //     sol_amount as u64,                      // 转账数量
-            // )?;
-            //
-            // // 创建交易
-            // let mut tx = Transaction::new_with_payer(
-            //     &[transfer_instruction],
-            //     Some(&buyer_keypair.pubkey()),
-            // );
-            // tx.sign(&[buyer_keypair], blockhash);
-            //
-            // // 发送并确认代币转账交易
-            // let transfer_signature = connection.send_and_confirm_transaction(&tx).await?;
-            // println!("Transfer transaction confirmed: {}", transfer_signature);
-        }
+        // `v0` 交易使用 `VersionedTransaction`，解码并反序列化
+        let decoded_tx = base64::decode(&quote.serialized_tx)?;  // 将 Base64 解码后的字节存储为 Vec<u8>
+        let mut versioned_transaction: VersionedTransaction = bincode::deserialize(decoded_tx.as_slice())?;  // 使用 bincode 反序列化
 
-        Ok(())
+        // 设置 recent_blockhash
+        versioned_transaction.message.set_recent_blockhash(blockhash);
+
+        // 签署交易
+        // 使用 `sign` 签署交易, 传递一个包含买家 keypair 的数组
+        let signature = buyer_keypair.try_sign_message(&versioned_transaction.message.serialize())?;
+        versioned_transaction.signatures = vec![signature];
+
+        // 发送并确认交易
+        let signature_result = connection.send_and_confirm_transaction(&versioned_transaction).await?;
+        info!("+ApplicationLayer [Wallet] swap confirmed: {}", signature_result);
+
+        Ok(signature_result.to_string())
     }
 
     pub async fn swap_sol_to_usdt_raydium(
@@ -1213,6 +1360,21 @@ impl WalletApplication {
 }
 
 
+/// # Description
+///     兑换报价，由 quote_swap 返回，交给 execute_swap 签署发送
+/// # Fields
+///     input_amount: f64 - 输入代币数量
+///     output_amount: f64 - 报价的输出代币数量
+///     minimum_output_amount: f64 - 按滑点计算的最少输出数量
+///     serialized_tx: String - Base64 编码的待签名 v0 交易
+#[derive(Debug, Clone)]
+pub struct SwapQuote {
+    pub input_amount: f64,
+    pub output_amount: f64,
+    pub minimum_output_amount: f64,
+    serialized_tx: String,
+}
+
 /// SwapResponse - Swap API 的响应格式
 #[derive(Deserialize, Debug)]
 struct SwapResponse {
@@ -0,0 +1,141 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::sync::Arc;
+use chrono::Utc;
+use color_eyre::{Report, Result};
+use serde_json::{json, Value};
+use solana_sdk::commitment_config::CommitmentConfig;
+use solana_sdk::signature::Signature;
+use tokio::sync::Mutex;
+use tracing::{info, warn};
+use crate::core::application::webhook::WebhookApplication;
+use crate::core::domain::DomainLayer;
+use crate::core::domain::watcher::entity::tracked_signature_entity::{TrackOutcome, TrackedSignature};
+use crate::core::domain::webhook::entity::webhook_event_entity::WebhookEventType;
+use crate::core::infrastructure::InfrastructureLayer;
+
+/// # Description
+///     getSignatureStatuses 单次最多查询的签名数量
+const SIGNATURE_STATUS_LIMIT: usize = 256;
+
+/// # Description
+///     确认跟踪器：跟踪已发出的转出交易直到最终确认、执行失败或超时，并推送提现通知
+/// # Fields
+///     domain_layer: Arc<DomainLayer> - 领域层
+///     infrastructure_layer: Arc<InfrastructureLayer> - 基础设施层
+///     webhook_application: Arc<WebhookApplication> - Webhook 通知应用
+///     pending: Mutex<HashMap<String, TrackedSignature>> - 跟踪中的交易，按签名索引
+pub struct ConfirmationTracker {
+    domain_layer: Arc<DomainLayer>,
+    infrastructure_layer: Arc<InfrastructureLayer>,
+    webhook_application: Arc<WebhookApplication>,
+    pending: Mutex<HashMap<String, TrackedSignature>>,
+}
+
+impl ConfirmationTracker {
+    /// # Description
+    ///     创建确认跟踪器实例
+    /// # Params
+    ///     infrastructure_layer: Arc<InfrastructureLayer> - 基础设施层的引用，用于获取配置和 RPC
+    ///     domain_layer: Arc<DomainLayer> - 领域层的引用，用于获取链上监听领域服务
+    ///     webhook_application: Arc<WebhookApplication> - Webhook 通知应用的引用
+    /// # Return
+    ///     Self: 返回一个新的 `ConfirmationTracker` 实例
+    pub fn new(infrastructure_layer: Arc<InfrastructureLayer>, domain_layer: Arc<DomainLayer>, webhook_application: Arc<WebhookApplication>) -> Self {
+        Self { domain_layer, infrastructure_layer, webhook_application, pending: Mutex::new(HashMap::new()) }
+    }
+
+    /// # Description
+    ///     开始跟踪交易
+    /// # Params
+    ///     tracked: TrackedSignature - 跟踪的交易
+    pub async fn track(&self, tracked: TrackedSignature) {
+        self.pending.lock().await.insert(tracked.signature.clone(), tracked);
+    }
+
+    /// # Description
+    ///     跟踪中的交易签名，重连后据此重新订阅
+    /// # Return
+    ///     Vec<String>: 交易签名
+    pub async fn pending_signatures(&self) -> Vec<String> {
+        self.pending.lock().await.keys().cloned().collect()
+    }
+
+    /// # Description
+    ///     处理 signatureSubscribe 推送，订阅使用 finalized 级别，收到推送即为最终结果
+    /// # Params
+    ///     signature: &str - 交易签名
+    ///     slot: u64 - 交易所在 slot
+    ///     error: Option<String> - 失败原因
+    pub async fn on_notification(&self, signature: &str, slot: u64, error: Option<String>) {
+        let Some(tracked) = self.pending.lock().await.remove(signature) else {
+            return;
+        };
+
+        let timeout = self.infrastructure_layer.config.job.pubsub.confirm_timeout;
+        if let Some(outcome) = self.domain_layer.watcher_domain.domain_service.resolve(&tracked, Some((slot, error, true)), timeout, Utc::now()) {
+            self.finish(tracked, outcome).await;
+        }
+    }
+
+    /// # Description
+    ///     轮询跟踪中交易的状态：推送丢失或连接断开时兜底，同时让超时的交易结束跟踪
+    /// # Return
+    ///     Result<(), Report>: 成功返回 Ok()，失败返回错误信息
+    pub async fn poll(&self) -> Result<(), Report> {
+        let tracked: Vec<TrackedSignature> = self.pending.lock().await.values().cloned().collect();
+        let timeout = self.infrastructure_layer.config.job.pubsub.confirm_timeout;
+        let domain_service = &self.domain_layer.watcher_domain.domain_service;
+
+        for chunk in tracked.chunks(SIGNATURE_STATUS_LIMIT) {
+            let signatures = chunk.iter()
+                .map(|tracked| Signature::from_str(&tracked.signature))
+                .collect::<Result<Vec<Signature>, _>>()?;
+            let statuses = self.infrastructure_layer.rpc.client.get_signature_statuses_with_history(&signatures).await?.value;
+
+            let now = Utc::now();
+            for (tracked, status) in chunk.iter().zip(statuses) {
+                let status = status.map(|status| (
+                    status.slot,
+                    status.err.as_ref().map(|e| e.to_string()),
+                    status.satisfies_commitment(CommitmentConfig::finalized()),
+                ));
+                let Some(outcome) = domain_service.resolve(tracked, status, timeout, now) else {
+                    continue;
+                };
+                // 推送可能已先一步处理
+                let removed = self.pending.lock().await.remove(&tracked.signature);
+                if let Some(tracked) = removed {
+                    self.finish(tracked, outcome).await;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// # Description
+    ///     结束跟踪并推送提现确认或失败事件，事件数据为跟踪时的业务数据附加签名与结果
+    async fn finish(&self, tracked: TrackedSignature, outcome: TrackOutcome) {
+        let (event_type, result) = match &outcome {
+            TrackOutcome::Confirmed { slot } => (WebhookEventType::WithdrawalConfirmed, json!({ "slot": slot })),
+            TrackOutcome::Failed { slot, error } => (WebhookEventType::WithdrawalFailed, json!({ "slot": slot, "error": error })),
+            TrackOutcome::Expired => {
+                let timeout = self.infrastructure_layer.config.job.pubsub.confirm_timeout;
+                (WebhookEventType::WithdrawalFailed, json!({ "error": format!("交易在 {} 秒内未确认", timeout) }))
+            }
+        };
+        match &outcome {
+            TrackOutcome::Confirmed { .. } => info!("+ApplicationLayer [Watcher] signature {} finalized", tracked.signature),
+            _ => warn!("-ApplicationLayer [Watcher] signature {} not confirmed: {:?}", tracked.signature, outcome),
+        }
+
+        let mut data = tracked.data;
+        if let (Value::Object(data), Value::Object(result)) = (&mut data, result) {
+            data.insert("signature".to_string(), Value::String(tracked.signature.clone()));
+            data.extend(result);
+        }
+
+        self.webhook_application.publish(tracked.user_id, event_type, data).await;
+    }
+}
@@ -0,0 +1,167 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::sync::Arc;
+use color_eyre::{Report, Result};
+use solana_client::rpc_client::GetConfirmedSignaturesForAddress2Config;
+use solana_client::rpc_config::RpcTransactionConfig;
+use solana_sdk::commitment_config::CommitmentConfig;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signature;
+use solana_transaction_status::{UiLoadedAddresses, UiTransactionEncoding};
+use tokio::sync::Mutex;
+use tracing::info;
+use crate::core::application::wallet::WalletApplication;
+use crate::core::domain::DomainLayer;
+use crate::core::domain::watcher::entity::chain_notification_entity::NotificationSource;
+use crate::core::domain::watcher::entity::deposit_entity::Model as DepositModel;
+use crate::core::domain::watcher::repository::DepositRepositoryInterface;
+use crate::core::infrastructure::InfrastructureLayer;
+
+/// # Description
+///     需要监听的托管地址
+/// # Fields
+///     wallet_id: i32 - 钱包id
+///     user_id: i32 - 钱包所属用户
+///     address: Pubkey - 钱包地址
+#[derive(Clone, Debug, PartialEq)]
+pub struct CustodyAddress {
+    pub wallet_id: i32,
+    pub user_id: i32,
+    pub address: Pubkey,
+}
+
+/// # Description
+///     充值监听：收到托管地址的变动通知后，扫描自上次扫描以来的交易，识别转入的 SOL 并入账
+/// # Fields
+///     domain_layer: Arc<DomainLayer> - 领域层
+///     infrastructure_layer: Arc<InfrastructureLayer> - 基础设施层
+///     wallet_application: Arc<WalletApplication> - 钱包应用，负责入账与充值通知
+///     cursors: Mutex<HashMap<String, Signature>> - 每个地址最近一次扫描到的交易签名，同时串行化扫描
+pub struct DepositWatcher {
+    domain_layer: Arc<DomainLayer>,
+    infrastructure_layer: Arc<InfrastructureLayer>,
+    wallet_application: Arc<WalletApplication>,
+    cursors: Mutex<HashMap<String, Signature>>,
+}
+
+impl DepositWatcher {
+    /// # Description
+    ///     创建充值监听实例
+    /// # Params
+    ///     infrastructure_layer: Arc<InfrastructureLayer> - 基础设施层的引用，用于获取仓储和 RPC
+    ///     domain_layer: Arc<DomainLayer> - 领域层的引用，用于获取链上监听领域服务
+    ///     wallet_application: Arc<WalletApplication> - 钱包应用的引用
+    /// # Return
+    ///     Self: 返回一个新的 `DepositWatcher` 实例
+    pub fn new(infrastructure_layer: Arc<InfrastructureLayer>, domain_layer: Arc<DomainLayer>, wallet_application: Arc<WalletApplication>) -> Self {
+        Self { domain_layer, infrastructure_layer, wallet_application, cursors: Mutex::new(HashMap::new()) }
+    }
+
+    /// # Description
+    ///     扫描托管地址的新交易并为其中的充值入账。
+    ///     游标不在内存中时从最近一笔入账的充值开始扫描；同一交易由充值记录的唯一索引保证只入账一次
+    /// # Params
+    ///     custody: &CustodyAddress - 托管地址
+    ///     source: NotificationSource - 触发扫描的通知来源
+    /// # Return
+    ///     Result<usize, Report>: 本次入账的充值笔数
+    pub async fn scan(&self, custody: &CustodyAddress, source: NotificationSource) -> Result<usize, Report> {
+        let mut cursors = self.cursors.lock().await;
+        let address = custody.address.to_string();
+
+        let until = match cursors.get(&address) {
+            Some(signature) => Some(*signature),
+            None => match self.infrastructure_layer.persistence.repository.deposit_repository.find_latest(custody.wallet_id).await? {
+                Some(deposit) => Some(Signature::from_str(&deposit.signature)?),
+                None => None,
+            },
+        };
+        // 按页向前翻，直到遇到游标签名或某页不足一页，避免两次扫描之间超过一页的交易被跳过
+        let limit = self.infrastructure_layer.config.job.pubsub.signature_limit.max(1);
+        let mut statuses = Vec::new();
+        let mut before = None;
+        loop {
+            let config = GetConfirmedSignaturesForAddress2Config {
+                before,
+                until,
+                limit: Some(limit),
+                commitment: Some(CommitmentConfig::confirmed()),
+            };
+            let page = self.infrastructure_layer.rpc.client
+                .get_signatures_for_address_with_config(&custody.address, config).await?;
+            let Some(last) = page.last() else {
+                break;
+            };
+            before = Some(Signature::from_str(&last.signature)?);
+            let exhausted = page.len() < limit;
+            statuses.extend(page);
+            if exhausted {
+                break;
+            }
+        }
+
+        // 从旧到新处理，出错时游标停在最后一笔处理完成的交易
+        let mut credited = 0;
+        for status in statuses.iter().rev() {
+            let signature = Signature::from_str(&status.signature)?;
+            if status.err.is_none() && self.process(custody, &signature, source).await? {
+                credited += 1;
+            }
+            cursors.insert(address.clone(), signature);
+        }
+
+        Ok(credited)
+    }
+
+    /// # Description
+    ///     处理单笔交易，托管地址余额增加且不是手续费支付方时记为充值并入账
+    /// # Return
+    ///     Result<bool, Report>: 是否入账
+    async fn process(&self, custody: &CustodyAddress, signature: &Signature, source: NotificationSource) -> Result<bool, Report> {
+        let repository = &self.infrastructure_layer.persistence.repository.deposit_repository;
+        if repository.exists(custody.wallet_id, &signature.to_string()).await? {
+            return Ok(false);
+        }
+
+        let config = RpcTransactionConfig {
+            encoding: Some(UiTransactionEncoding::Base64),
+            commitment: Some(CommitmentConfig::confirmed()),
+            max_supported_transaction_version: Some(0),
+        };
+        let confirmed = self.infrastructure_layer.rpc.client.get_transaction_with_config(signature, config).await
+            .map_err(|e| Report::msg(format!("获取交易 {} 失败: {}", signature, e)))?;
+        let transaction = confirmed.transaction.transaction.decode()
+            .ok_or_else(|| Report::msg(format!("交易 {} 解码失败", signature)))?;
+        let Some(meta) = confirmed.transaction.meta.as_ref() else {
+            return Ok(false);
+        };
+        if meta.err.is_some() {
+            return Ok(false);
+        }
+
+        let mut accounts: Vec<String> = transaction.message.static_account_keys().iter().map(|key| key.to_string()).collect();
+        if let Some(loaded) = Option::<&UiLoadedAddresses>::from(meta.loaded_addresses.as_ref()) {
+            accounts.extend(loaded.writable.iter().cloned());
+            accounts.extend(loaded.readonly.iter().cloned());
+        }
+
+        let address = custody.address.to_string();
+        let Some(lamports) = self.domain_layer.watcher_domain.domain_service
+            .deposit_lamports(&address, &accounts, &meta.pre_balances, &meta.post_balances) else {
+            return Ok(false);
+        };
+
+        // 充值记录与余额在同一事务中写入，入账失败时不留下充值记录，下次扫描会重试
+        let deposit = self.wallet_application.deposit(DepositModel::new(
+            custody.wallet_id,
+            custody.user_id,
+            address,
+            signature.to_string(),
+            confirmed.slot,
+            lamports,
+        )).await?;
+        info!("+ApplicationLayer [Watcher] deposit {} of {} SOL credited to wallet {} ({:?})", deposit.signature, deposit.amount, custody.wallet_id, source);
+
+        Ok(true)
+    }
+}
@@ -0,0 +1,7 @@
+mod confirmation_tracker;
+mod deposit_watcher;
+mod pubsub_application;
+
+pub use confirmation_tracker::ConfirmationTracker;
+pub use deposit_watcher::{CustodyAddress, DepositWatcher};
+pub use pubsub_application::PubsubApplication;
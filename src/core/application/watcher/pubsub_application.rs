@@ -0,0 +1,388 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
+use color_eyre::{Report, Result};
+use futures::stream::{BoxStream, SelectAll};
+use futures::StreamExt;
+use serde_json::json;
+use solana_client::nonblocking::pubsub_client::PubsubClient;
+use solana_client::rpc_config::{RpcAccountInfoConfig, RpcSignatureSubscribeConfig, RpcTransactionLogsConfig, RpcTransactionLogsFilter};
+use solana_client::rpc_response::{ProcessedSignatureResult, RpcSignatureResult};
+use solana_sdk::commitment_config::CommitmentConfig;
+use solana_sdk::native_token::LAMPORTS_PER_SOL;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signature;
+use tokio::sync::{mpsc, RwLock};
+use tokio::time::MissedTickBehavior;
+use tracing::{error, info, warn};
+use crate::core::application::stream::StreamApplication;
+use crate::core::application::wallet::WalletApplication;
+use crate::core::application::webhook::WebhookApplication;
+use crate::core::domain::DomainLayer;
+use crate::core::domain::stream::entity::stream_event_entity::StreamTopic;
+use crate::core::domain::wallet::repository::WalletRepositoryInterface;
+use crate::core::domain::watcher::entity::chain_notification_entity::{ChainNotification, NotificationSource};
+use crate::core::domain::watcher::entity::tracked_signature_entity::TrackedSignature;
+use crate::core::infrastructure::InfrastructureLayer;
+use super::confirmation_tracker::ConfirmationTracker;
+use super::deposit_watcher::{CustodyAddress, DepositWatcher};
+
+/// # Description
+///     发给连接任务的订阅命令，连接断开期间的命令在重连时由全量重新订阅覆盖
+enum PubsubCommand {
+    Account(Pubkey),
+    Signature(String),
+}
+
+/// # Description
+///     链上订阅应用服务：通过 Solana PubSub 订阅全部托管地址（accountSubscribe / logsSubscribe）
+///     与跟踪中的交易（signatureSubscribe），断线后指数退避重连并重新订阅，断线期间以轮询补偿。
+///     通知统一分发给充值监听与确认跟踪器
+/// # Fields
+///     domain_layer: Arc<DomainLayer> - 领域层
+///     infrastructure_layer: Arc<InfrastructureLayer> - 基础设施层
+///     deposit_watcher: Arc<DepositWatcher> - 充值监听
+///     confirmation_tracker: Arc<ConfirmationTracker> - 确认跟踪器
+///     stream_application: Arc<StreamApplication> - 实时推送应用，余额变化与新交易推送给在线连接
+///     custody: RwLock<HashMap<Pubkey, CustodyAddress>> - 当前监听的托管地址
+///     connected: AtomicBool - WebSocket 连接是否可用
+///     commands: mpsc::UnboundedSender<PubsubCommand> - 新增订阅的命令通道
+///     receiver: Mutex<Option<mpsc::UnboundedReceiver<PubsubCommand>>> - 命令接收端，启动连接任务时取出
+pub struct PubsubApplication {
+    domain_layer: Arc<DomainLayer>,
+    infrastructure_layer: Arc<InfrastructureLayer>,
+    deposit_watcher: Arc<DepositWatcher>,
+    confirmation_tracker: Arc<ConfirmationTracker>,
+    stream_application: Arc<StreamApplication>,
+    custody: RwLock<HashMap<Pubkey, CustodyAddress>>,
+    connected: AtomicBool,
+    commands: mpsc::UnboundedSender<PubsubCommand>,
+    receiver: Mutex<Option<mpsc::UnboundedReceiver<PubsubCommand>>>,
+}
+
+impl PubsubApplication {
+    /// # Description
+    ///     创建新的链上订阅应用服务实例
+    /// # Params
+    ///     infrastructure_layer: Arc<InfrastructureLayer> - 基础设施层的引用，用于获取配置、仓储和 RPC
+    ///     domain_layer: Arc<DomainLayer> - 领域层的引用，用于获取链上监听领域服务
+    ///     wallet_application: Arc<WalletApplication> - 钱包应用的引用，用于充值入账
+    ///     webhook_application: Arc<WebhookApplication> - Webhook 通知应用的引用，用于推送提现结果
+    ///     stream_application: Arc<StreamApplication> - 实时推送应用的引用，用于推送余额变化
+    /// # Return
+    ///     Self: 返回一个新的 `PubsubApplication` 实例
+    pub fn new(
+        infrastructure_layer: Arc<InfrastructureLayer>,
+        domain_layer: Arc<DomainLayer>,
+        wallet_application: Arc<WalletApplication>,
+        webhook_application: Arc<WebhookApplication>,
+        stream_application: Arc<StreamApplication>,
+    ) -> Self {
+        let (commands, receiver) = mpsc::unbounded_channel();
+
+        Self {
+            deposit_watcher: Arc::new(DepositWatcher::new(infrastructure_layer.clone(), domain_layer.clone(), wallet_application)),
+            confirmation_tracker: Arc::new(ConfirmationTracker::new(infrastructure_layer.clone(), domain_layer.clone(), webhook_application)),
+            stream_application,
+            domain_layer,
+            infrastructure_layer,
+            custody: RwLock::new(HashMap::new()),
+            connected: AtomicBool::new(false),
+            commands,
+            receiver: Mutex::new(Some(receiver)),
+        }
+    }
+
+    /// # Description
+    ///     WebSocket 连接是否可用，不可用时充值扫描由轮询补偿
+    /// # Return
+    ///     bool
+    pub fn is_connected(&self) -> bool {
+        self.connected.load(Ordering::SeqCst)
+    }
+
+    /// # Description
+    ///     跟踪一笔已发出的转出交易，最终确认或失败后推送提现通知
+    /// # Params
+    ///     tracked: TrackedSignature - 跟踪的交易
+    pub async fn track_signature(&self, tracked: TrackedSignature) {
+        let signature = tracked.signature.clone();
+        self.confirmation_tracker.track(tracked).await;
+        let _ = self.commands.send(PubsubCommand::Signature(signature));
+    }
+
+    /// # Description
+    ///     启动后台任务：配置启用时启动 WebSocket 连接任务；轮询任务始终运行，
+    ///     负责刷新托管地址、兜底确认跟踪，并在连接不可用时扫描充值
+    /// # Params
+    ///     None
+    /// # Return
+    ///     None
+    pub fn spawn_pubsub(self: &Arc<Self>) {
+        let config = self.infrastructure_layer.config.job.pubsub.clone();
+
+        if config.enabled {
+            let receiver = self.receiver.lock().unwrap_or_else(|e| e.into_inner()).take();
+            if let Some(mut receiver) = receiver {
+                let config = config.clone();
+                let application = Arc::clone(self);
+                tokio::spawn(async move {
+                    let mut attempt = 0u32;
+                    loop {
+                        let started = Instant::now();
+                        match application.run_connection(&mut receiver).await {
+                            Ok(()) => break,
+                            Err(e) => warn!("-ApplicationLayer [Pubsub] {}, falling back to polling", e),
+                        }
+                        application.connected.store(false, Ordering::SeqCst);
+
+                        // 连接稳定运行过一段时间后重新开始退避
+                        if started.elapsed() >= Duration::from_secs(config.reconnect_max) {
+                            attempt = 0;
+                        }
+                        attempt += 1;
+                        let delay = application.domain_layer.watcher_domain.domain_service
+                            .reconnect_delay(attempt, config.reconnect_base, config.reconnect_max);
+                        tokio::time::sleep(delay).await;
+                    }
+                });
+                info!("+ApplicationLayer [Pubsub] subscriber started.");
+            }
+        } else {
+            info!("+ApplicationLayer [Pubsub] subscriber disabled, polling only.");
+        }
+
+        let application = Arc::clone(self);
+        tokio::spawn(async move {
+            let mut refresh_ticker = tokio::time::interval(Duration::from_secs(config.refresh_interval.max(1)));
+            refresh_ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+            let mut poll_ticker = tokio::time::interval(Duration::from_secs(config.poll_interval.max(1)));
+            poll_ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+            loop {
+                tokio::select! {
+                    _ = refresh_ticker.tick() => {
+                        if let Err(e) = application.refresh_custody().await {
+                            error!("-ApplicationLayer [Pubsub] custody refresh failed: {}", e);
+                        }
+                    }
+                    _ = poll_ticker.tick() => application.poll().await,
+                }
+            }
+        });
+        info!("+ApplicationLayer [Pubsub] poller started.");
+    }
+
+    /// # Description
+    ///     分发一条链上通知：地址变动推送给在线连接并交给充值监听，交易结果交给确认跟踪器
+    /// # Params
+    ///     notification: ChainNotification - 链上通知
+    ///     source: NotificationSource - 通知来源
+    pub async fn handle(&self, notification: ChainNotification, source: NotificationSource) {
+        match notification {
+            // 失败的交易不会带来充值
+            ChainNotification::Logs { failed: true, .. } => {}
+            ChainNotification::Account { address, lamports, slot } => {
+                let Some(custody) = self.custody_of(&address).await else {
+                    return;
+                };
+                self.stream_application.publish(StreamTopic::Balance, Some(custody.user_id), "balance_changed", json!({
+                    "wallet_id": custody.wallet_id,
+                    "address": address,
+                    "lamports": lamports,
+                    "sol": lamports as f64 / LAMPORTS_PER_SOL as f64,
+                    "slot": slot,
+                }));
+                if let Err(e) = self.deposit_watcher.scan(&custody, source).await {
+                    warn!("-ApplicationLayer [Pubsub] deposit scan of {} failed: {}", address, e);
+                }
+            }
+            ChainNotification::Logs { address, signature, slot, .. } => {
+                let Some(custody) = self.custody_of(&address).await else {
+                    return;
+                };
+                self.stream_application.publish(StreamTopic::Transaction, Some(custody.user_id), "transaction_seen", json!({
+                    "wallet_id": custody.wallet_id,
+                    "address": address,
+                    "signature": signature,
+                    "slot": slot,
+                }));
+                if let Err(e) = self.deposit_watcher.scan(&custody, source).await {
+                    warn!("-ApplicationLayer [Pubsub] deposit scan of {} failed: {}", address, e);
+                }
+            }
+            ChainNotification::Signature { signature, slot, error } => {
+                self.confirmation_tracker.on_notification(&signature, slot, error).await;
+            }
+        }
+    }
+
+    /// # Description
+    ///     按地址查找正在监听的托管地址
+    async fn custody_of(&self, address: &str) -> Option<CustodyAddress> {
+        let address = Pubkey::from_str(address).ok()?;
+        self.custody.read().await.get(&address).cloned()
+    }
+
+    /// # Description
+    ///     轮询一轮：确认跟踪始终兜底；连接不可用时扫描全部托管地址
+    pub async fn poll(&self) {
+        if let Err(e) = self.confirmation_tracker.poll().await {
+            warn!("-ApplicationLayer [Pubsub] signature poll failed: {}", e);
+        }
+        if !self.is_connected() {
+            self.scan_all(NotificationSource::Polling).await;
+        }
+    }
+
+    /// # Description
+    ///     扫描全部托管地址的充值
+    async fn scan_all(&self, source: NotificationSource) {
+        let custody: Vec<CustodyAddress> = self.custody.read().await.values().cloned().collect();
+        for custody in custody {
+            if let Err(e) = self.deposit_watcher.scan(&custody, source).await {
+                warn!("-ApplicationLayer [Pubsub] deposit scan of {} failed: {}", custody.address, e);
+            }
+        }
+    }
+
+    /// # Description
+    ///     从数据库刷新托管地址，新增的地址立即订阅；已停用的地址不再处理，其订阅在下次重连时清除
+    async fn refresh_custody(&self) -> Result<(), Report> {
+        let wallets = self.infrastructure_layer.persistence.repository.wallet_repository.find_active().await?;
+        let latest: HashMap<Pubkey, CustodyAddress> = wallets.into_iter()
+            .filter_map(|wallet| {
+                let address = Pubkey::from_str(wallet.pub_key.as_deref()?).ok()?;
+                Some((address, CustodyAddress { wallet_id: wallet.id, user_id: wallet.user_id, address }))
+            })
+            .collect();
+
+        let mut custody = self.custody.write().await;
+        for address in latest.keys().filter(|address| !custody.contains_key(address)) {
+            let _ = self.commands.send(PubsubCommand::Account(*address));
+        }
+        *custody = latest;
+
+        Ok(())
+    }
+
+    /// # Description
+    ///     建立一次 WebSocket 连接并按当前状态全量订阅，之后持续分发通知与处理新增订阅。
+    ///     slot 订阅作为心跳，超时未收到推送视为连接已断开
+    /// # Params
+    ///     receiver: &mut mpsc::UnboundedReceiver<PubsubCommand> - 订阅命令接收端
+    /// # Return
+    ///     Result<(), Report>: 命令通道关闭时返回 Ok()，连接断开时返回错误
+    async fn run_connection(self: &Arc<Self>, receiver: &mut mpsc::UnboundedReceiver<PubsubCommand>) -> Result<(), Report> {
+        let client = PubsubClient::new(&self.infrastructure_layer.rpc.ws_url).await
+            .map_err(|e| Report::msg(format!("PubSub 连接失败: {}", e)))?;
+        let commitment = self.infrastructure_layer.rpc.client.commitment();
+
+        // 断线期间积压的命令已包含在下面的全量订阅中
+        while receiver.try_recv().is_ok() {}
+
+        let (mut slots, _) = client.slot_subscribe().await
+            .map_err(|e| Report::msg(format!("slotSubscribe 失败: {}", e)))?;
+        let mut streams: SelectAll<BoxStream<'_, ChainNotification>> = SelectAll::new();
+
+        let addresses: Vec<Pubkey> = self.custody.read().await.keys().copied().collect();
+        for address in &addresses {
+            Self::subscribe_address(&client, &mut streams, address, commitment).await?;
+        }
+        let signatures = self.confirmation_tracker.pending_signatures().await;
+        for signature in &signatures {
+            Self::subscribe_signature(&client, &mut streams, signature).await?;
+        }
+        self.connected.store(true, Ordering::SeqCst);
+        info!("+ApplicationLayer [Pubsub] connected, {} addresses and {} signatures subscribed", addresses.len(), signatures.len());
+
+        // 补扫断线期间遗漏的充值
+        let application = Arc::clone(self);
+        tokio::spawn(async move { application.scan_all(NotificationSource::Polling).await });
+
+        let heartbeat = Duration::from_secs(self.infrastructure_layer.config.job.pubsub.heartbeat_timeout.max(1));
+        loop {
+            tokio::select! {
+                slot = tokio::time::timeout(heartbeat, slots.next()) => {
+                    if !matches!(slot, Ok(Some(_))) {
+                        return Err(Report::msg("PubSub 连接已断开"));
+                    }
+                }
+                Some(notification) = streams.next(), if !streams.is_empty() => {
+                    let application = Arc::clone(self);
+                    tokio::spawn(async move { application.handle(notification, NotificationSource::PubSub).await });
+                }
+                command = receiver.recv() => match command {
+                    Some(PubsubCommand::Account(address)) => Self::subscribe_address(&client, &mut streams, &address, commitment).await?,
+                    Some(PubsubCommand::Signature(signature)) => Self::subscribe_signature(&client, &mut streams, &signature).await?,
+                    None => return Ok(()),
+                },
+            }
+        }
+    }
+
+    /// # Description
+    ///     订阅托管地址的余额变化与相关交易日志
+    async fn subscribe_address<'a>(
+        client: &'a PubsubClient,
+        streams: &mut SelectAll<BoxStream<'a, ChainNotification>>,
+        address: &Pubkey,
+        commitment: CommitmentConfig,
+    ) -> Result<(), Report> {
+        let account_config = RpcAccountInfoConfig { commitment: Some(commitment), ..RpcAccountInfoConfig::default() };
+        let (accounts, _) = client.account_subscribe(address, Some(account_config)).await
+            .map_err(|e| Report::msg(format!("accountSubscribe {} 失败: {}", address, e)))?;
+        let account_address = address.to_string();
+        streams.push(accounts.map(move |response| ChainNotification::Account {
+            address: account_address.clone(),
+            lamports: response.value.lamports,
+            slot: response.context.slot,
+        }).boxed());
+
+        let filter = RpcTransactionLogsFilter::Mentions(vec![address.to_string()]);
+        let (logs, _) = client.logs_subscribe(filter, RpcTransactionLogsConfig { commitment: Some(commitment) }).await
+            .map_err(|e| Report::msg(format!("logsSubscribe {} 失败: {}", address, e)))?;
+        let logs_address = address.to_string();
+        streams.push(logs.map(move |response| ChainNotification::Logs {
+            address: logs_address.clone(),
+            signature: response.value.signature,
+            slot: response.context.slot,
+            failed: response.value.err.is_some(),
+        }).boxed());
+
+        Ok(())
+    }
+
+    /// # Description
+    ///     订阅交易的最终确认结果
+    async fn subscribe_signature<'a>(
+        client: &'a PubsubClient,
+        streams: &mut SelectAll<BoxStream<'a, ChainNotification>>,
+        signature: &str,
+    ) -> Result<(), Report> {
+        let parsed = Signature::from_str(signature)?;
+        let config = RpcSignatureSubscribeConfig {
+            commitment: Some(CommitmentConfig::finalized()),
+            enable_received_notification: Some(false),
+        };
+        let (statuses, _) = client.signature_subscribe(&parsed, Some(config)).await
+            .map_err(|e| Report::msg(format!("signatureSubscribe {} 失败: {}", signature, e)))?;
+
+        let signature = signature.to_string();
+        streams.push(statuses.filter_map(move |response| {
+            let notification = match response.value {
+                RpcSignatureResult::ProcessedSignature(ProcessedSignatureResult { err }) => Some(ChainNotification::Signature {
+                    signature: signature.clone(),
+                    slot: response.context.slot,
+                    error: err.map(|e| e.to_string()),
+                }),
+                RpcSignatureResult::ReceivedSignature(_) => None,
+            };
+            futures::future::ready(notification)
+        }).boxed());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,3 @@
+mod webhook_application;
+
+pub use webhook_application::{WebhookApplication, WebhookEndpointCreated};
@@ -0,0 +1,476 @@
+use std::collections::{HashMap, HashSet};
+use std::net::SocketAddr;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Duration;
+use chrono::Utc;
+use color_eyre::{Report, Result};
+use futures::future::join_all;
+use sea_orm::{ActiveModelTrait, IntoActiveModel};
+use serde::Serialize;
+use serde_json::{json, Value};
+use solana_sdk::native_token::LAMPORTS_PER_SOL;
+use solana_sdk::pubkey::Pubkey;
+use tokio::sync::Notify;
+use tokio::time::MissedTickBehavior;
+use tracing::{error, info, warn};
+use crate::core::application::stream::StreamApplication;
+use crate::core::domain::DomainLayer;
+use crate::core::domain::wallet::entity::Model as WalletModel;
+use crate::core::domain::wallet::repository::WalletRepositoryInterface;
+use crate::core::domain::webhook::entity::webhook_balance_alert_entity::Model as WebhookBalanceAlertModel;
+use crate::core::domain::webhook::entity::webhook_delivery_entity::{DeliveryStatus, Model as WebhookDeliveryModel};
+use crate::core::domain::webhook::entity::webhook_endpoint_entity::Model as WebhookEndpointModel;
+use crate::core::domain::webhook::entity::webhook_event_entity::{WebhookEvent, WebhookEventType};
+use crate::core::domain::stream::entity::stream_event_entity::StreamTopic;
+use crate::core::domain::webhook::repository::WebhookRepositoryInterface;
+use crate::core::infrastructure::InfrastructureLayer;
+
+/// # Description
+///     单次查询投递记录的默认数量与上限
+const DEFAULT_DELIVERY_LIMIT: u64 = 50;
+const MAX_DELIVERY_LIMIT: u64 = 200;
+
+/// # Description
+///     getMultipleAccounts 单次最多查询的账户数量
+const MULTIPLE_ACCOUNTS_LIMIT: usize = 100;
+
+/// # Description
+///     新建的订阅端点，签名密钥只在创建时返回
+/// # Fields
+///     endpoint: WebhookEndpointModel - 订阅端点
+///     secret: String - 签名密钥
+#[derive(Debug, Serialize)]
+pub struct WebhookEndpointCreated {
+    pub endpoint: WebhookEndpointModel,
+    pub secret: String,
+}
+
+/// # Description
+///     Webhook 应用服务：管理订阅端点，把钱包事件写入投递记录，后台签名投递并按指数退避重试
+/// # Fields
+///     domain_layer: Arc<DomainLayer> - 领域层
+///     infrastructure_layer: Arc<InfrastructureLayer> - 基础设施层
+///     notify: Notify - 有新投递记录时唤醒投递任务
+///     stream_application: Arc<StreamApplication> - 实时推送应用，事件同时推送给在线连接
+pub struct WebhookApplication {
+    domain_layer: Arc<DomainLayer>,
+    infrastructure_layer: Arc<InfrastructureLayer>,
+    stream_application: Arc<StreamApplication>,
+    notify: Notify,
+}
+
+impl WebhookApplication {
+    /// # Description
+    ///     创建新的 Webhook 应用服务实例
+    /// # Params
+    ///     infrastructure_layer: Arc<InfrastructureLayer> - 基础设施层的引用，用于获取仓储、配置和 RPC
+    ///     domain_layer: Arc<DomainLayer> - 领域层的引用，用于获取 Webhook 领域服务
+    ///     stream_application: Arc<StreamApplication> - 实时推送应用
+    /// # Return
+    ///     Self: 返回一个新的 `WebhookApplication` 实例
+    pub fn new(infrastructure_layer: Arc<InfrastructureLayer>, domain_layer: Arc<DomainLayer>, stream_application: Arc<StreamApplication>) -> Self {
+        Self {
+            domain_layer,
+            infrastructure_layer,
+            stream_application,
+            notify: Notify::new(),
+        }
+    }
+
+    /// # Description
+    ///     创建订阅端点
+    /// # Params
+    ///     user_id: Option<i32> - 所属用户，管理员端点为 None
+    ///     url: String - 接收地址
+    ///     events: Vec<WebhookEventType> - 订阅的事件类型
+    ///     balance_threshold: Option<f64> - 余额告警阈值（SOL）
+    /// # Return
+    ///     Result<WebhookEndpointCreated, Report>: 订阅端点与签名密钥
+    pub async fn create_endpoint(
+        &self,
+        user_id: Option<i32>,
+        url: String,
+        events: Vec<WebhookEventType>,
+        balance_threshold: Option<f64>,
+    ) -> Result<WebhookEndpointCreated, Report> {
+        let (endpoint, secret) = self.domain_layer.webhook_domain.domain_service
+            .create_endpoint(user_id, url, events, balance_threshold)?;
+        let endpoint = self.infrastructure_layer.persistence.repository.webhook_repository.save_endpoint(endpoint).await?;
+
+        Ok(WebhookEndpointCreated { endpoint, secret })
+    }
+
+    /// # Description
+    ///     查询订阅端点
+    /// # Params
+    ///     user_id: Option<i32> - 用户id，管理员端点为 None
+    /// # Return
+    ///     Result<Vec<WebhookEndpointModel>, Report>: 订阅端点列表
+    pub async fn list_endpoints(&self, user_id: Option<i32>) -> Result<Vec<WebhookEndpointModel>, Report> {
+        self.infrastructure_layer.persistence.repository.webhook_repository.find_endpoints(user_id).await
+    }
+
+    /// # Description
+    ///     删除订阅端点，已有投递记录保留，未完成的投递会在下次尝试时标记为失败
+    /// # Params
+    ///     user_id: Option<i32> - 用户id，管理员端点为 None
+    ///     endpoint_id: i32 - 端点id
+    /// # Return
+    ///     Result<(), Report>: 成功返回 Ok()，失败返回错误信息
+    pub async fn delete_endpoint(&self, user_id: Option<i32>, endpoint_id: i32) -> Result<(), Report> {
+        let endpoint = self.find_scoped_endpoint(user_id, endpoint_id).await?;
+        self.infrastructure_layer.persistence.repository.webhook_repository.delete_endpoint(endpoint.id).await
+    }
+
+    /// # Description
+    ///     查询端点最近的投递记录
+    /// # Params
+    ///     user_id: Option<i32> - 用户id，管理员端点为 None
+    ///     endpoint_id: i32 - 端点id
+    ///     limit: Option<u64> - 数量
+    /// # Return
+    ///     Result<Vec<WebhookDeliveryModel>, Report>: 投递记录，按id倒序
+    pub async fn list_deliveries(&self, user_id: Option<i32>, endpoint_id: i32, limit: Option<u64>) -> Result<Vec<WebhookDeliveryModel>, Report> {
+        let endpoint = self.find_scoped_endpoint(user_id, endpoint_id).await?;
+        let limit = limit.unwrap_or(DEFAULT_DELIVERY_LIMIT).clamp(1, MAX_DELIVERY_LIMIT);
+
+        self.infrastructure_layer.persistence.repository.webhook_repository.find_deliveries(endpoint.id, limit).await
+    }
+
+    /// # Description
+    ///     重放投递：以相同的事件id与请求体生成新的投递记录并立即投递，原记录保留
+    /// # Params
+    ///     user_id: Option<i32> - 用户id，管理员端点为 None
+    ///     delivery_id: i32 - 投递记录id
+    /// # Return
+    ///     Result<WebhookDeliveryModel, Report>: 新的投递记录
+    pub async fn replay(&self, user_id: Option<i32>, delivery_id: i32) -> Result<WebhookDeliveryModel, Report> {
+        let repository = &self.infrastructure_layer.persistence.repository.webhook_repository;
+        let delivery = repository.find_delivery(delivery_id).await?
+            .ok_or_else(|| Report::msg("投递记录不存在"))?;
+        let endpoint = self.find_scoped_endpoint(user_id, delivery.endpoint_id).await
+            .map_err(|_| Report::msg("投递记录不存在"))?;
+        if !endpoint.enabled {
+            return Err(Report::msg("订阅端点已停用"));
+        }
+
+        let replay = WebhookDeliveryModel::new(endpoint.id, delivery.event_id, delivery.event_type, delivery.payload, Some(delivery.id));
+        let replay = repository.save_delivery(replay).await?;
+        self.notify.notify_one();
+
+        Ok(replay)
+    }
+
+    /// # Description
+    ///     发布事件：推送给用户的在线连接，并为用户端点与管理员端点中订阅了该事件的端点生成投递记录、唤醒投递任务。
+    ///     事件发布失败只记录日志，不影响业务流程
+    /// # Params
+    ///     user_id: i32 - 事件所属用户
+    ///     event_type: WebhookEventType - 事件类型
+    ///     data: Value - 事件数据
+    pub async fn publish(&self, user_id: i32, event_type: WebhookEventType, data: Value) {
+        self.stream_application.publish(StreamTopic::Transaction, Some(user_id), event_type.as_str(), data.clone());
+        let event = self.domain_layer.webhook_domain.domain_service.build_event(user_id, event_type, data);
+
+        let endpoints = match self.infrastructure_layer.persistence.repository.webhook_repository.find_subscribers(user_id).await {
+            Ok(endpoints) => endpoints,
+            Err(e) => {
+                warn!("-ApplicationLayer [Webhook] event {} publish failed: {}", event.id, e);
+                return;
+            }
+        };
+
+        for endpoint in endpoints.iter().filter(|endpoint| endpoint.subscribes(&event.event_type)) {
+            self.enqueue(endpoint, &event).await;
+        }
+    }
+
+    /// # Description
+    ///     启动后台投递任务；配置了余额检查间隔时同时启动余额阈值检查任务
+    /// # Params
+    ///     None
+    /// # Return
+    ///     None
+    pub fn spawn_dispatcher(self: &Arc<Self>) {
+        let config = self.infrastructure_layer.config.job.webhook.clone();
+        if !config.enabled {
+            info!("+ApplicationLayer [Webhook] dispatcher disabled.");
+            return;
+        }
+
+        let application = Arc::clone(self);
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(Duration::from_secs(config.interval.max(1)));
+            ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+            loop {
+                tokio::select! {
+                    _ = ticker.tick() => {}
+                    _ = application.notify.notified() => {}
+                }
+
+                // 一批投递满额时说明可能还有积压，继续处理
+                loop {
+                    match application.dispatch_due(config.batch_size).await {
+                        Ok(count) if count as u64 >= config.batch_size => continue,
+                        Ok(_) => break,
+                        Err(e) => {
+                            error!("-ApplicationLayer [Webhook] dispatch failed: {}", e);
+                            break;
+                        }
+                    }
+                }
+            }
+        });
+        info!("+ApplicationLayer [Webhook] dispatcher started.");
+
+        if config.balance_interval == 0 {
+            return;
+        }
+        let application = Arc::clone(self);
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(Duration::from_secs(config.balance_interval));
+            ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+            loop {
+                ticker.tick().await;
+                if let Err(e) = application.check_balances().await {
+                    error!("-ApplicationLayer [Webhook] balance check failed: {}", e);
+                }
+            }
+        });
+        info!("+ApplicationLayer [Webhook] balance monitor started.");
+    }
+
+    /// # Description
+    ///     投递一批到期的记录
+    /// # Params
+    ///     batch_size: u64 - 本轮最多投递的记录数量
+    /// # Return
+    ///     Result<usize, Report>: 本轮处理的记录数量
+    pub async fn dispatch_due(&self, batch_size: u64) -> Result<usize, Report> {
+        let deliveries = self.infrastructure_layer.persistence.repository.webhook_repository
+            .find_due_deliveries(Utc::now(), batch_size).await?;
+        let count = deliveries.len();
+
+        let results = join_all(deliveries.into_iter().map(|delivery| self.deliver(delivery))).await;
+        for result in results {
+            if let Err(e) = result {
+                error!("-ApplicationLayer [Webhook] delivery save failed: {}", e);
+            }
+        }
+
+        Ok(count)
+    }
+
+    /// # Description
+    ///     检查订阅了余额不足事件的端点：用户任一钱包的链上 SOL 余额低于阈值时告警一次，
+    ///     余额恢复后再次低于阈值会重新告警；已告警状态保存在数据库中，重启后不会重复告警
+    /// # Params
+    ///     None
+    /// # Return
+    ///     Result<(), Report>: 成功返回 Ok()，失败返回错误信息
+    pub async fn check_balances(&self) -> Result<(), Report> {
+        let repository = &self.infrastructure_layer.persistence.repository;
+        let endpoints: Vec<WebhookEndpointModel> = repository.webhook_repository.find_threshold_endpoints().await?
+            .into_iter()
+            .filter(|endpoint| endpoint.subscribes(&WebhookEventType::BalanceBelowThreshold))
+            .collect();
+
+        let mut wallets: HashMap<i32, Vec<WalletModel>> = HashMap::new();
+        for user_id in endpoints.iter().filter_map(|endpoint| endpoint.user_id) {
+            if !wallets.contains_key(&user_id) {
+                wallets.insert(user_id, repository.wallet_repository.find_by_user(user_id).await?);
+            }
+        }
+
+        let addresses: Vec<String> = wallets.values().flatten().filter_map(|wallet| wallet.pub_key.clone()).collect();
+        let balances = self.fetch_balances(&addresses).await?;
+
+        let endpoint_ids: Vec<i32> = endpoints.iter().map(|endpoint| endpoint.id).collect();
+        let alerted: HashSet<(i32, i32)> = repository.webhook_repository.find_balance_alerts(&endpoint_ids).await?
+            .into_iter()
+            .map(|alert| (alert.endpoint_id, alert.wallet_id))
+            .collect();
+
+        let domain_service = &self.domain_layer.webhook_domain.domain_service;
+        for endpoint in &endpoints {
+            let (Some(user_id), Some(threshold)) = (endpoint.user_id, endpoint.balance_threshold) else {
+                continue;
+            };
+            for wallet in wallets.get(&user_id).into_iter().flatten() {
+                let Some(balance) = wallet.pub_key.as_ref().and_then(|address| balances.get(address)) else {
+                    continue;
+                };
+
+                let was_alerted = alerted.contains(&(endpoint.id, wallet.id));
+                if *balance >= threshold {
+                    if was_alerted {
+                        repository.webhook_repository.delete_balance_alert(endpoint.id, wallet.id).await?;
+                    }
+                    continue;
+                }
+
+                // 先写入告警记录，写入成功的实例才发送，避免重复告警
+                if !was_alerted && repository.webhook_repository.save_balance_alert(WebhookBalanceAlertModel::new(endpoint.id, wallet.id, *balance)).await? {
+                    let event = domain_service.build_event(user_id, WebhookEventType::BalanceBelowThreshold, json!({
+                        "wallet_id": wallet.id,
+                        "address": wallet.pub_key,
+                        "balance": balance,
+                        "threshold": threshold,
+                    }));
+                    self.enqueue(endpoint, &event).await;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// # Description
+    ///     发送一次投递请求，请求头携带事件信息、时间戳与签名
+    /// # Params
+    ///     client: &reqwest::Client - HTTP 客户端
+    ///     url: &str - 接收地址
+    ///     delivery: &WebhookDeliveryModel - 投递记录
+    ///     timestamp: i64 - 发送时间（Unix 秒）
+    ///     signature: &str - 请求体签名
+    /// # Return
+    ///     Result<u16, Report>: 接收方返回的 HTTP 状态码，请求未送达时返回错误
+    pub async fn send(client: &reqwest::Client, url: &str, delivery: &WebhookDeliveryModel, timestamp: i64, signature: &str) -> Result<u16, Report> {
+        let response = client.post(url)
+            .header("Content-Type", "application/json")
+            .header("X-Webhook-Id", delivery.event_id.as_str())
+            .header("X-Webhook-Event", delivery.event_type.as_str())
+            .header("X-Webhook-Delivery", delivery.id.to_string())
+            .header("X-Webhook-Timestamp", timestamp.to_string())
+            .header("X-Webhook-Signature", signature)
+            .body(delivery.payload.clone())
+            .send()
+            .await
+            .map_err(|e| Report::msg(format!("请求失败: {}", e)))?;
+
+        Ok(response.status().as_u16())
+    }
+
+    /// # Description
+    ///     为单个端点生成投递记录
+    async fn enqueue(&self, endpoint: &WebhookEndpointModel, event: &WebhookEvent) {
+        let payload = match serde_json::to_string(event) {
+            Ok(payload) => payload,
+            Err(e) => {
+                warn!("-ApplicationLayer [Webhook] event {} serialize failed: {}", event.id, e);
+                return;
+            }
+        };
+
+        let delivery = WebhookDeliveryModel::new(endpoint.id, event.id.clone(), event.event_type.clone(), payload, None);
+        match self.infrastructure_layer.persistence.repository.webhook_repository.save_delivery(delivery).await {
+            Ok(_) => self.notify.notify_one(),
+            Err(e) => warn!("-ApplicationLayer [Webhook] event {} enqueue to endpoint {} failed: {}", event.id, endpoint.id, e),
+        }
+    }
+
+    /// # Description
+    ///     投递一条记录并写回结果：2xx 视为成功，其余按退避时间重试，重试次数耗尽后标记为失败
+    async fn deliver(&self, mut delivery: WebhookDeliveryModel) -> Result<(), Report> {
+        let repository = &self.infrastructure_layer.persistence.repository.webhook_repository;
+        let domain_service = &self.domain_layer.webhook_domain.domain_service;
+        let config = &self.infrastructure_layer.config.job.webhook;
+
+        let endpoint = repository.find_endpoint(delivery.endpoint_id).await?.filter(|endpoint| endpoint.enabled);
+        let result = match &endpoint {
+            None => Err((None, "订阅端点不存在或已停用".to_string())),
+            Some(endpoint) => {
+                let timestamp = Utc::now().timestamp();
+                match domain_service.sign(&endpoint.secret, timestamp, &delivery.payload) {
+                    Err(e) => Err((None, e.to_string())),
+                    Ok(signature) => match self.client_for(&endpoint.url).await {
+                        Err(e) => Err((None, e.to_string())),
+                        Ok(client) => match Self::send(&client, &endpoint.url, &delivery, timestamp, &signature).await {
+                            Ok(status) if (200..300).contains(&status) => Ok(status),
+                            Ok(status) => Err((Some(status as i32), format!("接收方返回 HTTP {}", status))),
+                            Err(e) => Err((None, e.to_string())),
+                        },
+                    },
+                }
+            }
+        };
+
+        let now = Utc::now();
+        match result {
+            Ok(status) => delivery.record_success(status as i32, now),
+            Err((status, error)) => {
+                let retry_at = match endpoint {
+                    Some(_) => domain_service.retry_at(delivery.attempts + 1, config.max_attempts, config.retry_base, config.retry_max, now),
+                    None => None,
+                };
+                warn!("-ApplicationLayer [Webhook] delivery {} attempt {} failed: {}", delivery.id, delivery.attempts + 1, error);
+                delivery.record_failure(status, error, retry_at, now);
+            }
+        }
+        if delivery.status == DeliveryStatus::Failed {
+            warn!("-ApplicationLayer [Webhook] delivery {} gave up after {} attempts", delivery.id, delivery.attempts);
+        }
+        repository.save_delivery(delivery.into_active_model().reset_all()).await?;
+
+        Ok(())
+    }
+
+    /// # Description
+    ///     为一次投递创建 HTTP 客户端：只允许 https，解析接收地址并拒绝非公网地址，
+    ///     客户端固定使用本次解析出的地址且不跟随重定向，避免 DNS 重绑定或重定向绕过校验
+    async fn client_for(&self, url: &str) -> Result<reqwest::Client, Report> {
+        let parsed = reqwest::Url::parse(url).map_err(|e| Report::msg(format!("接收地址不合法: {}", e)))?;
+        if parsed.scheme() != "https" {
+            return Err(Report::msg("接收地址必须是 https 地址"));
+        }
+        let host = parsed.host_str().ok_or_else(|| Report::msg("接收地址缺少主机名"))?;
+        let port = parsed.port_or_known_default().unwrap_or(443);
+
+        let addresses: Vec<SocketAddr> = tokio::net::lookup_host((host.trim_start_matches('[').trim_end_matches(']'), port)).await
+            .map_err(|e| Report::msg(format!("接收地址解析失败: {}", e)))?
+            .collect();
+        let domain_service = &self.domain_layer.webhook_domain.domain_service;
+        if addresses.is_empty() || addresses.iter().any(|address| !domain_service.is_public_ip(address.ip())) {
+            return Err(Report::msg(format!("接收地址 {} 解析到内网或保留地址，已拒绝投递", host)));
+        }
+
+        let timeout = Duration::from_secs(self.infrastructure_layer.config.job.webhook.timeout.max(1));
+        let client = reqwest::Client::builder()
+            .timeout(timeout)
+            .redirect(reqwest::redirect::Policy::none())
+            .resolve(host, addresses[0])
+            .build()?;
+
+        Ok(client)
+    }
+
+    /// # Description
+    ///     批量查询地址的 SOL 余额，账户不存在时余额为 0
+    async fn fetch_balances(&self, addresses: &[String]) -> Result<HashMap<String, f64>, Report> {
+        let mut balances = HashMap::new();
+        let pubkeys: Vec<Pubkey> = addresses.iter().filter_map(|address| Pubkey::from_str(address).ok()).collect();
+
+        for chunk in pubkeys.chunks(MULTIPLE_ACCOUNTS_LIMIT) {
+            let accounts = self.infrastructure_layer.rpc.client.get_multiple_accounts(chunk).await
+                .map_err(|e| Report::msg(format!("获取钱包余额失败: {}", e)))?;
+            for (pubkey, account) in chunk.iter().zip(accounts) {
+                let lamports = account.map(|account| account.lamports).unwrap_or_default();
+                balances.insert(pubkey.to_string(), lamports as f64 / LAMPORTS_PER_SOL as f64);
+            }
+        }
+
+        Ok(balances)
+    }
+
+    /// # Description
+    ///     查询属于该范围（用户或管理员）的订阅端点
+    async fn find_scoped_endpoint(&self, user_id: Option<i32>, endpoint_id: i32) -> Result<WebhookEndpointModel, Report> {
+        match self.infrastructure_layer.persistence.repository.webhook_repository.find_endpoint(endpoint_id).await? {
+            Some(endpoint) if endpoint.user_id == user_id => Ok(endpoint),
+            _ => Err(Report::msg("订阅端点不存在")),
+        }
+    }
+}
@@ -0,0 +1,3 @@
+mod withdrawal_application;
+
+pub use withdrawal_application::WithdrawalApplication;
\ No newline at end of file
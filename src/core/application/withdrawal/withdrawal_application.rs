@@ -0,0 +1,170 @@
+use std::sync::Arc;
+use chrono::Utc;
+use color_eyre::{Report, Result};
+use sea_orm::{ActiveModelTrait, IntoActiveModel};
+use serde_json::json;
+use tracing::{error, info};
+use crate::core::application::wallet::WalletApplication;
+use crate::core::application::webhook::WebhookApplication;
+use crate::core::application::watcher::PubsubApplication;
+use crate::core::domain::DomainLayer;
+use crate::core::domain::wallet::entity::Model as WalletModel;
+use crate::core::domain::wallet::repository::WalletRepositoryInterface;
+use crate::core::domain::watcher::entity::tracked_signature_entity::TrackedSignature;
+use crate::core::domain::webhook::entity::webhook_event_entity::WebhookEventType;
+use crate::core::domain::withdrawal::entity::whitelist_address_entity::Model as WhitelistAddressModel;
+use crate::core::domain::withdrawal::repository::WithdrawalRepositoryInterface;
+use crate::core::infrastructure::InfrastructureLayer;
+
+/// # Description
+///     提现应用服务：管理提现白名单，并把钱包余额提现到白名单地址
+/// # Fields
+///     domain_layer: Arc<DomainLayer> - 领域层
+///     infrastructure_layer: Arc<InfrastructureLayer> - 基础设施层
+///     webhook_application: Arc<WebhookApplication> - Webhook 通知应用，用于推送提现失败事件
+///     pubsub_application: Arc<PubsubApplication> - 链上订阅应用，用于跟踪提现交易的确认
+pub struct WithdrawalApplication {
+    domain_layer: Arc<DomainLayer>,
+    infrastructure_layer: Arc<InfrastructureLayer>,
+    webhook_application: Arc<WebhookApplication>,
+    pubsub_application: Arc<PubsubApplication>,
+}
+
+impl WithdrawalApplication {
+    /// # Description
+    ///     创建新的提现应用服务实例
+    /// # Params
+    ///     infrastructure_layer: Arc<InfrastructureLayer> - 基础设施层的引用，用于获取白名单与钱包仓储以及 RPC
+    ///     domain_layer: Arc<DomainLayer> - 领域层的引用，用于获取提现领域服务
+    ///     webhook_application: Arc<WebhookApplication> - Webhook 通知应用
+    ///     pubsub_application: Arc<PubsubApplication> - 链上订阅应用
+    /// # Return
+    ///     Self: 返回一个新的 `WithdrawalApplication` 实例
+    pub fn new(
+        infrastructure_layer: Arc<InfrastructureLayer>,
+        domain_layer: Arc<DomainLayer>,
+        webhook_application: Arc<WebhookApplication>,
+        pubsub_application: Arc<PubsubApplication>,
+    ) -> Self {
+        Self { domain_layer, infrastructure_layer, webhook_application, pubsub_application }
+    }
+
+    /// # Description
+    ///     查询用户的提现白名单
+    /// # Params
+    ///     user_id: i32 - 用户id
+    /// # Return
+    ///     Result<Vec<WhitelistAddressModel>, Report>: 白名单地址
+    pub async fn list_whitelist(&self, user_id: i32) -> Result<Vec<WhitelistAddressModel>, Report> {
+        self.infrastructure_layer.persistence.repository.withdrawal_repository.find_whitelist(user_id).await
+    }
+
+    /// # Description
+    ///     添加白名单地址，调用前须已通过两步验证
+    /// # Params
+    ///     user_id: i32 - 用户id
+    ///     address: String - 接收地址
+    ///     label: Option<String> - 备注
+    /// # Return
+    ///     Result<WhitelistAddressModel, Report>: 添加的白名单地址
+    pub async fn add_whitelist(&self, user_id: i32, address: String, label: Option<String>) -> Result<WhitelistAddressModel, Report> {
+        let repository = &self.infrastructure_layer.persistence.repository.withdrawal_repository;
+        let withdrawal_service = &self.domain_layer.withdrawal_domain.domain_service;
+
+        let address = withdrawal_service.normalize_address(&address)?;
+        let label = withdrawal_service.normalize_label(label)?;
+        if repository.find_address(user_id, &address).await?.is_some() {
+            return Err(Report::msg("该地址已在白名单中"));
+        }
+
+        let whitelisted = repository.add_address(WhitelistAddressModel::new(user_id, address, label)).await?;
+        info!("+ApplicationLayer [Withdrawal] user {} whitelisted {}", user_id, whitelisted.address);
+
+        Ok(whitelisted)
+    }
+
+    /// # Description
+    ///     删除白名单地址
+    /// # Params
+    ///     user_id: i32 - 用户id
+    ///     id: i32 - 白名单地址id
+    /// # Return
+    ///     Result<(), Report>: 删除结果
+    pub async fn remove_whitelist(&self, user_id: i32, id: i32) -> Result<(), Report> {
+        if !self.infrastructure_layer.persistence.repository.withdrawal_repository.remove_address(user_id, id).await? {
+            return Err(Report::msg("白名单地址不存在"));
+        }
+
+        Ok(())
+    }
+
+    /// # Description
+    ///     提现 SOL 到白名单地址，调用前须已通过两步验证。
+    ///     先扣减余额再发出转账，转账失败时退回余额并推送 withdrawal_failed；
+    ///     转账成功后交给确认跟踪器，最终确认后推送 withdrawal_confirmed
+    /// # Params
+    ///     user_id: i32 - 用户id
+    ///     wallet_id: i32 - 转出钱包
+    ///     address: String - 白名单中的接收地址
+    ///     amount: f64 - 提现金额（SOL）
+    /// # Return
+    ///     Result<String, Report>: 转账交易签名
+    pub async fn withdraw(&self, user_id: i32, wallet_id: i32, address: String, amount: f64) -> Result<String, Report> {
+        let withdrawal_service = &self.domain_layer.withdrawal_domain.domain_service;
+
+        let address = withdrawal_service.normalize_address(&address)?;
+        if self.infrastructure_layer.persistence.repository.withdrawal_repository.find_address(user_id, &address).await?.is_none() {
+            return Err(Report::msg("接收地址不在提现白名单中"));
+        }
+
+        let mut wallet = self.find_user_wallet(user_id, wallet_id).await?;
+        withdrawal_service.check_withdrawal(&wallet, amount)?;
+        let keypair = WalletApplication::load_keypair(&self.infrastructure_layer.keystore, &wallet)?;
+
+        self.adjust_balance(&mut wallet, -amount).await?;
+
+        let data = json!({
+            "source": "withdrawal",
+            "wallet_id": wallet.id,
+            "mint": spl_token::native_mint::id().to_string(),
+            "amount": amount,
+            "recipient": address,
+        });
+        match WalletApplication::transfer_sol(&self.infrastructure_layer.rpc.client, &keypair, &address, amount).await {
+            Ok(signature) => {
+                info!("+ApplicationLayer [Withdrawal] wallet {} sent {} SOL to {}: {}", wallet.id, amount, address, signature);
+                self.pubsub_application.track_signature(TrackedSignature::new(signature.clone(), user_id, data)).await;
+                Ok(signature)
+            }
+            Err(e) => {
+                error!("-ApplicationLayer [Withdrawal] wallet {} transfer failed: {}", wallet.id, e);
+                let mut wallet = self.find_user_wallet(user_id, wallet_id).await?;
+                self.adjust_balance(&mut wallet, amount).await?;
+
+                let mut data = data;
+                data["error"] = json!(e.to_string());
+                self.webhook_application.publish(user_id, WebhookEventType::WithdrawalFailed, data).await;
+                Err(Report::msg("提现转账失败，余额已退回"))
+            }
+        }
+    }
+
+    /// # Description
+    ///     查询属于该用户的钱包
+    async fn find_user_wallet(&self, user_id: i32, wallet_id: i32) -> Result<WalletModel, Report> {
+        match self.infrastructure_layer.persistence.repository.wallet_repository.find_by_id(wallet_id as u64).await? {
+            Some(wallet) if wallet.user_id == user_id => Ok(wallet),
+            _ => Err(Report::msg("钱包不存在")),
+        }
+    }
+
+    /// # Description
+    ///     调整钱包余额并写回
+    async fn adjust_balance(&self, wallet: &mut WalletModel, amount: f64) -> Result<(), Report> {
+        wallet.update_balance(amount).map_err(Report::msg)?;
+        wallet.updated_at = Utc::now();
+        self.infrastructure_layer.persistence.repository.wallet_repository
+            .save(wallet.clone().into_active_model().reset_all())
+            .await
+    }
+}
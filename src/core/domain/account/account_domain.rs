@@ -0,0 +1,20 @@
+use std::sync::Arc;
+use crate::core::domain::account::service::AccountDomainService;
+
+pub struct AccountDomain {
+    pub domain_service: Arc<AccountDomainService>,
+}
+
+impl AccountDomain {
+    /// # Description
+    ///     初始化账户查询领域
+    /// # Return
+    ///     Self: 初始化后的账户查询领域实例
+    pub async fn new() -> Self {
+        let domain_service = Arc::new(AccountDomainService::new());
+
+        Self {
+            domain_service
+        }
+    }
+}
@@ -0,0 +1,104 @@
+use serde::Serialize;
+use serde_json::Value;
+
+/// # Description
+///     账户查询结果
+/// # Fields
+///     pubkey: String - 账户地址
+///     owner: String - 所有者程序
+///     lamports: u64 - 余额（lamports）
+///     sol: f64 - 余额（SOL）
+///     executable: bool - 是否为可执行程序
+///     rent_epoch: u64 - 下次收取租金的 epoch
+///     data_len: usize - 数据长度（字节）
+///     rent_exempt_minimum: u64 - 当前数据长度免租所需的最低余额（lamports）
+///     rent_exempt: bool - 余额是否达到免租要求
+///     decoded: Option<DecodedAccountData> - 按所有者程序解码的数据，无法识别时为 None
+///     raw: Option<RawAccountData> - 原始数据，仅在无法解码且数据非空时返回
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct AccountInspection {
+    pub pubkey: String,
+    pub owner: String,
+    pub lamports: u64,
+    pub sol: f64,
+    pub executable: bool,
+    pub rent_epoch: u64,
+    pub data_len: usize,
+    pub rent_exempt_minimum: u64,
+    pub rent_exempt: bool,
+    pub decoded: Option<DecodedAccountData>,
+    pub raw: Option<RawAccountData>,
+}
+
+/// # Description
+///     原始账户数据
+/// # Fields
+///     base64: String - base64 编码
+///     hex: String - 十六进制编码
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct RawAccountData {
+    pub base64: String,
+    pub hex: String,
+}
+
+/// # Description
+///     解码后的账户数据，type 字段标明账户类型
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum DecodedAccountData {
+    TokenAccount {
+        program: String,
+        mint: String,
+        owner: String,
+        amount: u64,
+        delegate: Option<String>,
+        delegated_amount: u64,
+        state: String,
+        is_native: bool,
+        close_authority: Option<String>,
+        is_associated: bool,
+    },
+    Mint {
+        program: String,
+        mint_authority: Option<String>,
+        supply: u64,
+        decimals: u8,
+        is_initialized: bool,
+        freeze_authority: Option<String>,
+    },
+    Stake {
+        state: String,
+        rent_exempt_reserve: Option<u64>,
+        staker: Option<String>,
+        withdrawer: Option<String>,
+        lockup_unix_timestamp: Option<i64>,
+        lockup_epoch: Option<u64>,
+        custodian: Option<String>,
+        voter: Option<String>,
+        stake: Option<u64>,
+        activation_epoch: Option<u64>,
+        deactivation_epoch: Option<u64>,
+        credits_observed: Option<u64>,
+    },
+    Nonce {
+        authority: String,
+        blockhash: String,
+        lamports_per_signature: u64,
+    },
+    Program {
+        programdata_address: String,
+    },
+    ProgramData {
+        slot: u64,
+        upgrade_authority: Option<String>,
+        program_len: usize,
+    },
+    Buffer {
+        authority: Option<String>,
+    },
+    Anchor {
+        program: String,
+        account_type: String,
+        data: Value,
+    },
+}
@@ -0,0 +1,5 @@
+pub mod entity;
+pub mod service;
+mod account_domain;
+
+pub use account_domain::AccountDomain;
@@ -0,0 +1,212 @@
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use solana_sdk::account::Account;
+use solana_sdk::bpf_loader_upgradeable::{self, UpgradeableLoaderState};
+use solana_sdk::native_token::LAMPORTS_PER_SOL;
+use solana_sdk::nonce::state::{State as NonceState, Versions as NonceVersions};
+use solana_sdk::program_pack::Pack;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::rent::Rent;
+use solana_sdk::stake::state::StakeStateV2;
+use solana_sdk::{stake, system_program};
+use spl_associated_token_account::get_associated_token_address_with_program_id;
+use spl_token::state::{Account as TokenAccount, Mint};
+use super::entity::account_inspection_entity::{AccountInspection, DecodedAccountData, RawAccountData};
+
+/// # Description
+///     Token-2022 程序
+const TOKEN_2022_PROGRAM_ID: Pubkey = solana_sdk::pubkey!("TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb");
+
+/// # Description
+///     Token-2022 扩展账户中账户类型字节的位置（紧跟基础账户布局之后）
+const ACCOUNT_TYPE_OFFSET: usize = TokenAccount::LEN;
+
+/// # Description
+///     Token-2022 账户类型：Mint
+const ACCOUNT_TYPE_MINT: u8 = 1;
+
+/// # Description
+///     Token-2022 账户类型：代币账户
+const ACCOUNT_TYPE_ACCOUNT: u8 = 2;
+
+/// # Description
+///     nonce 账户数据长度
+const NONCE_ACCOUNT_LEN: usize = 80;
+
+/// # Description
+///     账户查询领域服务，按所有者程序解码账户数据
+pub struct AccountDomainService;
+
+impl AccountDomainService {
+    /// # Description
+    ///     创建新的账户查询领域服务实例
+    /// # Return
+    ///     Self: 账户查询领域服务实例
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// # Description
+    ///     生成账户查询结果。内置程序优先解码，其次使用调用方按 IDL 解码的 Anchor 账户，
+    ///     都无法识别时返回原始数据
+    /// # Param
+    ///     pubkey: &Pubkey - 账户地址
+    ///     account: &Account - 链上账户
+    ///     anchor: Option<DecodedAccountData> - 按 Anchor IDL 解码的结果
+    /// # Return
+    ///     AccountInspection: 账户查询结果
+    pub fn inspect(&self, pubkey: &Pubkey, account: &Account, anchor: Option<DecodedAccountData>) -> AccountInspection {
+        let rent_exempt_minimum = Rent::default().minimum_balance(account.data.len());
+        let decoded = self.decode(pubkey, account).or(anchor);
+        let raw = match (&decoded, account.data.is_empty()) {
+            (None, false) => Some(RawAccountData {
+                base64: STANDARD.encode(&account.data),
+                hex: account.data.iter().map(|byte| format!("{:02x}", byte)).collect(),
+            }),
+            _ => None,
+        };
+
+        AccountInspection {
+            pubkey: pubkey.to_string(),
+            owner: account.owner.to_string(),
+            lamports: account.lamports,
+            sol: account.lamports as f64 / LAMPORTS_PER_SOL as f64,
+            executable: account.executable,
+            rent_epoch: account.rent_epoch,
+            data_len: account.data.len(),
+            rent_exempt_minimum,
+            rent_exempt: account.lamports >= rent_exempt_minimum,
+            decoded,
+            raw,
+        }
+    }
+
+    /// # Description
+    ///     按所有者程序解码内置账户类型
+    /// # Param
+    ///     pubkey: &Pubkey - 账户地址
+    ///     account: &Account - 链上账户
+    /// # Return
+    ///     Option<DecodedAccountData>: 解码结果，不是已知类型时为 None
+    pub fn decode(&self, pubkey: &Pubkey, account: &Account) -> Option<DecodedAccountData> {
+        let owner = account.owner;
+        let data = account.data.as_slice();
+
+        if owner == spl_token::id() || owner == TOKEN_2022_PROGRAM_ID {
+            self.decode_token(pubkey, &owner, data)
+        } else if owner == stake::program::id() {
+            self.decode_stake(data)
+        } else if owner == system_program::id() && data.len() == NONCE_ACCOUNT_LEN {
+            self.decode_nonce(data)
+        } else if owner == bpf_loader_upgradeable::id() {
+            self.decode_upgradeable(data)
+        } else {
+            None
+        }
+    }
+
+    /// # Description
+    ///     解码 SPL Token / Token-2022 的代币账户与 Mint，并判断代币账户是否为所有者的关联代币账户
+    fn decode_token(&self, pubkey: &Pubkey, program_id: &Pubkey, data: &[u8]) -> Option<DecodedAccountData> {
+        let account_type = if data.len() == TokenAccount::LEN {
+            ACCOUNT_TYPE_ACCOUNT
+        } else if data.len() == Mint::LEN {
+            ACCOUNT_TYPE_MINT
+        } else if data.len() > ACCOUNT_TYPE_OFFSET && *program_id == TOKEN_2022_PROGRAM_ID {
+            data[ACCOUNT_TYPE_OFFSET]
+        } else {
+            return None;
+        };
+
+        match account_type {
+            ACCOUNT_TYPE_ACCOUNT => {
+                let token = TokenAccount::unpack(&data[..TokenAccount::LEN]).ok()?;
+                let associated = get_associated_token_address_with_program_id(&token.owner, &token.mint, program_id);
+                Some(DecodedAccountData::TokenAccount {
+                    program: program_id.to_string(),
+                    mint: token.mint.to_string(),
+                    owner: token.owner.to_string(),
+                    amount: token.amount,
+                    delegate: Option::<Pubkey>::from(token.delegate).map(|delegate| delegate.to_string()),
+                    delegated_amount: token.delegated_amount,
+                    state: format!("{:?}", token.state).to_lowercase(),
+                    is_native: token.is_native(),
+                    close_authority: Option::<Pubkey>::from(token.close_authority).map(|authority| authority.to_string()),
+                    is_associated: associated == *pubkey,
+                })
+            }
+            ACCOUNT_TYPE_MINT => {
+                let mint = Mint::unpack(&data[..Mint::LEN]).ok()?;
+                Some(DecodedAccountData::Mint {
+                    program: program_id.to_string(),
+                    mint_authority: Option::<Pubkey>::from(mint.mint_authority).map(|authority| authority.to_string()),
+                    supply: mint.supply,
+                    decimals: mint.decimals,
+                    is_initialized: mint.is_initialized,
+                    freeze_authority: Option::<Pubkey>::from(mint.freeze_authority).map(|authority| authority.to_string()),
+                })
+            }
+            _ => None,
+        }
+    }
+
+    /// # Description
+    ///     解码质押账户
+    fn decode_stake(&self, data: &[u8]) -> Option<DecodedAccountData> {
+        let stake_state: StakeStateV2 = bincode::deserialize(data).ok()?;
+        let (state, meta, stake) = match stake_state {
+            StakeStateV2::Uninitialized => ("uninitialized", None, None),
+            StakeStateV2::Initialized(meta) => ("initialized", Some(meta), None),
+            StakeStateV2::Stake(meta, stake, _) => ("delegated", Some(meta), Some(stake)),
+            StakeStateV2::RewardsPool => ("rewardsPool", None, None),
+        };
+
+        Some(DecodedAccountData::Stake {
+            state: state.to_string(),
+            rent_exempt_reserve: meta.map(|meta| meta.rent_exempt_reserve),
+            staker: meta.map(|meta| meta.authorized.staker.to_string()),
+            withdrawer: meta.map(|meta| meta.authorized.withdrawer.to_string()),
+            lockup_unix_timestamp: meta.map(|meta| meta.lockup.unix_timestamp),
+            lockup_epoch: meta.map(|meta| meta.lockup.epoch),
+            custodian: meta.map(|meta| meta.lockup.custodian.to_string()),
+            voter: stake.map(|stake| stake.delegation.voter_pubkey.to_string()),
+            stake: stake.map(|stake| stake.delegation.stake),
+            activation_epoch: stake.map(|stake| stake.delegation.activation_epoch),
+            deactivation_epoch: stake.map(|stake| stake.delegation.deactivation_epoch),
+            credits_observed: stake.map(|stake| stake.credits_observed),
+        })
+    }
+
+    /// # Description
+    ///     解码 nonce 账户，未初始化的 nonce 账户视为普通系统账户
+    fn decode_nonce(&self, data: &[u8]) -> Option<DecodedAccountData> {
+        let versions: NonceVersions = bincode::deserialize(data).ok()?;
+        match versions.state() {
+            NonceState::Uninitialized => None,
+            NonceState::Initialized(nonce) => Some(DecodedAccountData::Nonce {
+                authority: nonce.authority.to_string(),
+                blockhash: nonce.blockhash().to_string(),
+                lamports_per_signature: nonce.fee_calculator.lamports_per_signature,
+            }),
+        }
+    }
+
+    /// # Description
+    ///     解码可升级加载器的程序、程序数据与缓冲区账户
+    fn decode_upgradeable(&self, data: &[u8]) -> Option<DecodedAccountData> {
+        match bincode::deserialize(data).ok()? {
+            UpgradeableLoaderState::Program { programdata_address } => Some(DecodedAccountData::Program {
+                programdata_address: programdata_address.to_string(),
+            }),
+            UpgradeableLoaderState::ProgramData { slot, upgrade_authority_address } => Some(DecodedAccountData::ProgramData {
+                slot,
+                upgrade_authority: upgrade_authority_address.map(|authority| authority.to_string()),
+                program_len: data.len().saturating_sub(UpgradeableLoaderState::size_of_programdata_metadata()),
+            }),
+            UpgradeableLoaderState::Buffer { authority_address } => Some(DecodedAccountData::Buffer {
+                authority: authority_address.map(|authority| authority.to_string()),
+            }),
+            UpgradeableLoaderState::Uninitialized => None,
+        }
+    }
+}
@@ -0,0 +1,20 @@
+use std::sync::Arc;
+use crate::core::domain::auth::service::AuthDomainService;
+
+pub struct AuthDomain {
+    pub domain_service: Arc<AuthDomainService>,
+}
+
+impl AuthDomain {
+    /// # Description
+    ///     初始化认证领域
+    /// # Return
+    ///     Self: 初始化后的认证领域实例
+    pub async fn new() -> Self {
+        let domain_service = Arc::new(AuthDomainService::new());
+
+        Self {
+            domain_service
+        }
+    }
+}
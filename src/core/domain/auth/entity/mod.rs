@@ -0,0 +1 @@
+pub mod token_entity;
\ No newline at end of file
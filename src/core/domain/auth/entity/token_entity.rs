@@ -0,0 +1,48 @@
+use serde::{Deserialize, Serialize};
+
+/// # Description
+///     令牌类型，写入 JWT 的 typ 声明，防止刷新令牌被当作访问令牌使用
+/// # Param
+///     Access: 访问令牌
+///     Refresh: 刷新令牌
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TokenKind {
+    Access,
+    Refresh,
+}
+
+/// # Description
+///     刷新令牌族：同一次登录后轮换出的所有刷新令牌属于同一族，任一时刻只有最新的一枚有效
+/// # Fields
+///     user_id: i32 - 所属用户
+///     current: String - 当前有效的刷新令牌 jti
+///     access: String - 与当前刷新令牌同时签发的访问令牌 jti
+///     previous: Option<String> - 上一次轮换前的刷新令牌 jti
+///     rotated_at: i64 - 上一次轮换时间（Unix 秒）
+///     successor: Option<String> - 上一次轮换签发的令牌（JSON），宽限期内重复刷新时原样返回
+///     created_at: i64 - 登录时间（Unix 秒），旧记录没有该字段时为 0
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct RefreshFamily {
+    pub user_id: i32,
+    pub current: String,
+    pub access: String,
+    pub previous: Option<String>,
+    pub rotated_at: i64,
+    pub successor: Option<String>,
+    #[serde(default)]
+    pub created_at: i64,
+}
+
+/// # Description
+///     出示刷新令牌后的处理结果
+/// # Param
+///     Rotate: 出示的是当前令牌，签发新令牌并轮换
+///     Replay: 宽限期内重复出示刚轮换掉的令牌（并发刷新），返回上一次签发的令牌
+///     Reused: 出示了已轮换的旧令牌，视为令牌泄露，整族作废
+#[derive(Clone, Debug, PartialEq)]
+pub enum RefreshRotation {
+    Rotate,
+    Replay(String),
+    Reused,
+}
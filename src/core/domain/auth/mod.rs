@@ -0,0 +1,6 @@
+pub mod entity;
+pub mod repository;
+pub mod service;
+mod auth_domain;
+
+pub use auth_domain::AuthDomain;
\ No newline at end of file
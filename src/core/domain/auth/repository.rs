@@ -0,0 +1,16 @@
+use axum::async_trait;
+use color_eyre::{Report, Result};
+use crate::core::domain::auth::entity::token_entity::RefreshFamily;
+
+/// # Description
+///     令牌状态存储接口：访问令牌黑名单与刷新令牌族，Redis 与进程内存两种实现
+#[async_trait]
+pub trait TokenStoreInterface: Send + Sync {
+    async fn blacklist(&self, jti: &str, effective_at: i64, ttl: u64) -> Result<(), Report>;
+    async fn blacklisted_at(&self, jti: &str) -> Result<Option<i64>, Report>;
+    async fn family(&self, family_id: &str) -> Result<Option<RefreshFamily>, Report>;
+    async fn save_family(&self, family_id: &str, family: &RefreshFamily, ttl: u64) -> Result<(), Report>;
+    async fn swap_family(&self, family_id: &str, expected: &RefreshFamily, family: &RefreshFamily, ttl: u64) -> Result<bool, Report>;
+    async fn remove_family(&self, family_id: &str) -> Result<(), Report>;
+    async fn families(&self, user_id: i32) -> Result<Vec<(String, RefreshFamily)>, Report>;
+}
@@ -0,0 +1,91 @@
+use super::entity::token_entity::{RefreshFamily, RefreshRotation};
+
+/// # Description
+///     认证领域服务，负责刷新令牌族的轮换判定与黑名单生效判定
+pub struct AuthDomainService;
+
+impl AuthDomainService {
+    /// # Description
+    ///     创建新的认证领域服务实例
+    /// # Return
+    ///     Self: 认证领域服务实例
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// # Description
+    ///     登录时创建新的刷新令牌族
+    /// # Param
+    ///     user_id: i32 - 所属用户
+    ///     refresh_jti: String - 刷新令牌 jti
+    ///     access_jti: String - 访问令牌 jti
+    ///     now: i64 - 当前时间（Unix 秒）
+    /// # Return
+    ///     RefreshFamily: 刷新令牌族
+    pub fn new_family(&self, user_id: i32, refresh_jti: String, access_jti: String, now: i64) -> RefreshFamily {
+        RefreshFamily {
+            user_id,
+            current: refresh_jti,
+            access: access_jti,
+            previous: None,
+            rotated_at: now,
+            successor: None,
+            created_at: now,
+        }
+    }
+
+    /// # Description
+    ///     判定出示的刷新令牌应如何处理：当前令牌正常轮换；刚被轮换掉的令牌在宽限期内视为并发刷新，
+    ///     返回上一次签发的令牌；其余情况都是旧令牌被重复使用
+    /// # Param
+    ///     family: &RefreshFamily - 刷新令牌族
+    ///     jti: &str - 出示的刷新令牌 jti
+    ///     now: i64 - 当前时间（Unix 秒）
+    ///     grace_period: u64 - 宽限期（秒）
+    /// # Return
+    ///     RefreshRotation: 处理结果
+    pub fn rotation(&self, family: &RefreshFamily, jti: &str, now: i64, grace_period: u64) -> RefreshRotation {
+        if family.current == jti {
+            return RefreshRotation::Rotate;
+        }
+
+        let within_grace = now - family.rotated_at <= grace_period as i64;
+        match (&family.previous, &family.successor) {
+            (Some(previous), Some(successor)) if previous == jti && within_grace => RefreshRotation::Replay(successor.clone()),
+            _ => RefreshRotation::Reused,
+        }
+    }
+
+    /// # Description
+    ///     轮换刷新令牌族
+    /// # Param
+    ///     family: &RefreshFamily - 轮换前的刷新令牌族
+    ///     refresh_jti: String - 新刷新令牌 jti
+    ///     access_jti: String - 新访问令牌 jti
+    ///     successor: String - 新签发的令牌（JSON）
+    ///     now: i64 - 当前时间（Unix 秒）
+    /// # Return
+    ///     RefreshFamily: 轮换后的刷新令牌族
+    pub fn rotate(&self, family: &RefreshFamily, refresh_jti: String, access_jti: String, successor: String, now: i64) -> RefreshFamily {
+        RefreshFamily {
+            user_id: family.user_id,
+            current: refresh_jti,
+            access: access_jti,
+            previous: Some(family.current.clone()),
+            rotated_at: now,
+            successor: Some(successor),
+            created_at: family.created_at,
+        }
+    }
+
+    /// # Description
+    ///     黑名单记录是否已生效。刷新后旧访问令牌在宽限期结束才失效，避免刷新期间的并发请求失败
+    /// # Param
+    ///     effective_at: Option<i64> - 黑名单生效时间（Unix 秒），None 表示不在黑名单
+    ///     now: i64 - 当前时间（Unix 秒）
+    /// # Return
+    ///     bool: 是否已失效
+    pub fn is_revoked(&self, effective_at: Option<i64>, now: i64) -> bool {
+        effective_at.is_some_and(|effective_at| effective_at <= now)
+    }
+}
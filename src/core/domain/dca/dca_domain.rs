@@ -0,0 +1,20 @@
+use std::sync::Arc;
+use crate::core::domain::dca::service::DcaDomainService;
+
+pub struct DcaDomain {
+    pub domain_service: Arc<DcaDomainService>,
+}
+
+impl DcaDomain {
+    /// # Description
+    ///     初始化定投领域
+    /// # Return
+    ///     Self: 初始化后的定投领域实例
+    pub async fn new() -> Self {
+        let domain_service = Arc::new(DcaDomainService::new());
+
+        Self {
+            domain_service
+        }
+    }
+}
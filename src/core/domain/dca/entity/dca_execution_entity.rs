@@ -0,0 +1,83 @@
+use chrono::{DateTime, Utc};
+use sea_orm::{ActiveModelBehavior, ActiveValue, DeriveEntityModel, DerivePrimaryKey, DeriveRelation, EnumIter, PrimaryKeyTrait};
+use sea_orm::entity::prelude::*;
+use sea_orm::prelude::DateTimeUtc;
+use serde::{Deserialize, Serialize};
+
+/// # Description
+///     定投执行结果
+/// # Param
+///     Success: 执行成功
+///     Failed: 执行失败
+#[derive(Clone, Debug, PartialEq, Eq, EnumIter, DeriveActiveEnum, Serialize, Deserialize)]
+#[sea_orm(rs_type = "String", db_type = "String(StringLen::N(16))")]
+#[serde(rename_all = "lowercase")]
+pub enum DcaExecutionStatus {
+    #[sea_orm(string_value = "success")]
+    Success,
+    #[sea_orm(string_value = "failed")]
+    Failed,
+}
+
+/// # Description
+///     该结构体代表定投计划的一次执行记录，并映射到数据库中的 `dca_execution` 表。
+/// # Param
+///     id: 主键，自动递增
+///     schedule_id: 所属计划，关联 dca_schedule 表
+///     attempt: 本周期第几次尝试，从 1 开始
+///     amount: 执行数量
+///     status: 执行结果
+///     signature: 交易签名
+///     error: 失败原因
+///     scheduled_for: 计划执行时间
+///     executed_at: 实际执行时间
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "dca_execution")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+    pub schedule_id: i32,
+    pub attempt: i32,
+    pub amount: f64,
+    pub status: DcaExecutionStatus,
+    pub signature: Option<String>,
+    pub error: Option<String>,
+    pub scheduled_for: DateTimeUtc,
+    pub executed_at: DateTimeUtc,
+}
+
+impl Model {
+    /// # Description
+    ///     创建新的执行记录
+    /// # Param
+    ///     schedule_id: i32 - 计划id
+    ///     attempt: i32 - 尝试次数
+    ///     amount: f64 - 执行数量
+    ///     result: &Result<String, String> - 成功时为交易签名，失败时为错误信息
+    ///     scheduled_for: DateTime<Utc> - 计划执行时间
+    /// # Return
+    ///     ActiveModel
+    pub fn new(schedule_id: i32, attempt: i32, amount: f64, result: &Result<String, String>, scheduled_for: DateTime<Utc>) -> ActiveModel {
+        let (status, signature, error) = match result {
+            Ok(signature) => (DcaExecutionStatus::Success, Some(signature.clone()), None),
+            Err(error) => (DcaExecutionStatus::Failed, None, Some(error.clone())),
+        };
+
+        ActiveModel {
+            id: ActiveValue::NotSet,
+            schedule_id: ActiveValue::set(schedule_id),
+            attempt: ActiveValue::set(attempt),
+            amount: ActiveValue::set(amount),
+            status: ActiveValue::set(status),
+            signature: ActiveValue::set(signature),
+            error: ActiveValue::set(error),
+            scheduled_for: ActiveValue::set(scheduled_for),
+            executed_at: ActiveValue::set(Utc::now()),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,258 @@
+use chrono::{DateTime, Utc};
+use sea_orm::{ActiveModelBehavior, ActiveValue, DeriveEntityModel, DerivePrimaryKey, DeriveRelation, EnumIter, PrimaryKeyTrait};
+use sea_orm::entity::prelude::*;
+use sea_orm::prelude::DateTimeUtc;
+use serde::{Deserialize, Serialize};
+
+/// # Description
+///     定投计划类型
+/// # Param
+///     Swap: 定期兑换（例如每天买入 0.1 SOL 的代币 X）
+///     Transfer: 定期转账（例如每月转出 50 USDT）
+#[derive(Clone, Debug, PartialEq, Eq, EnumIter, DeriveActiveEnum, Serialize, Deserialize)]
+#[sea_orm(rs_type = "String", db_type = "String(StringLen::N(16))")]
+#[serde(rename_all = "lowercase")]
+pub enum DcaKind {
+    #[sea_orm(string_value = "swap")]
+    Swap,
+    #[sea_orm(string_value = "transfer")]
+    Transfer,
+}
+
+/// # Description
+///     定投计划状态
+/// # Param
+///     Active: 运行中
+///     Paused: 已暂停
+///     Completed: 已达到支出上限
+///     Cancelled: 已取消
+#[derive(Clone, Debug, PartialEq, Eq, EnumIter, DeriveActiveEnum, Serialize, Deserialize)]
+#[sea_orm(rs_type = "String", db_type = "String(StringLen::N(16))")]
+#[serde(rename_all = "lowercase")]
+pub enum DcaStatus {
+    #[sea_orm(string_value = "active")]
+    Active,
+    #[sea_orm(string_value = "paused")]
+    Paused,
+    #[sea_orm(string_value = "completed")]
+    Completed,
+    #[sea_orm(string_value = "cancelled")]
+    Cancelled,
+}
+
+/// # Description
+///     该结构体代表定投计划，并映射到数据库中的 `dca_schedule` 表。
+///     执行时间只保存在数据库中，服务重启后按 next_run_at 继续执行。
+/// # Param
+///     id: 主键，自动递增
+///     user_id: 计划所属用户，关联 user 表
+///     wallet_id: 执行计划的钱包，关联 wallet 表
+///     kind: 计划类型
+///     input_mint: 兑换时为卖出代币，转账时为转出代币（原生 SOL 使用 wrapped SOL mint）
+///     output_mint: 兑换时的买入代币
+///     recipient: 转账时的接收地址
+///     amount: 每次执行的数量
+///     max_slippage: 兑换时的最大滑点比例
+///     cron: cron 表达式（秒 分 时 日 月 周），与 interval_secs 二选一
+///     interval_secs: 固定执行间隔（秒），与 cron 二选一
+///     spend_cap: 累计支出上限，为空表示不限
+///     spent: 累计已支出数量
+///     retry_count: 当前周期已重试次数
+///     status: 计划状态
+///     next_run_at: 下次执行时间
+///     last_run_at: 上次执行时间
+///     last_error: 上次失败原因
+///     created_at: 创建时间
+///     updated_at: 更新时间
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "dca_schedule")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+    pub user_id: i32,
+    pub wallet_id: i32,
+    pub kind: DcaKind,
+    pub input_mint: String,
+    pub output_mint: Option<String>,
+    pub recipient: Option<String>,
+    pub amount: f64,
+    pub max_slippage: f64,
+    pub cron: Option<String>,
+    pub interval_secs: Option<i64>,
+    pub spend_cap: Option<f64>,
+    pub spent: f64,
+    pub retry_count: i32,
+    pub status: DcaStatus,
+    pub next_run_at: DateTimeUtc,
+    pub last_run_at: Option<DateTime<Utc>>,
+    pub last_error: Option<String>,
+    pub created_at: DateTimeUtc,
+    pub updated_at: DateTimeUtc,
+}
+
+impl Model {
+    /// # Description
+    ///     创建新的定投计划
+    /// # Param
+    ///     user_id: i32 - 用户id
+    ///     wallet_id: i32 - 钱包id
+    ///     kind: DcaKind - 计划类型
+    ///     input_mint: String - 卖出或转出代币
+    ///     output_mint: Option<String> - 买入代币
+    ///     recipient: Option<String> - 接收地址
+    ///     amount: f64 - 每次执行的数量
+    ///     max_slippage: f64 - 最大滑点比例
+    ///     cron: Option<String> - cron 表达式
+    ///     interval_secs: Option<i64> - 固定执行间隔（秒）
+    ///     spend_cap: Option<f64> - 累计支出上限
+    ///     next_run_at: DateTime<Utc> - 首次执行时间
+    /// # Return
+    ///     ActiveModel
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        user_id: i32,
+        wallet_id: i32,
+        kind: DcaKind,
+        input_mint: String,
+        output_mint: Option<String>,
+        recipient: Option<String>,
+        amount: f64,
+        max_slippage: f64,
+        cron: Option<String>,
+        interval_secs: Option<i64>,
+        spend_cap: Option<f64>,
+        next_run_at: DateTime<Utc>,
+    ) -> ActiveModel {
+        let now_datetime = Utc::now();
+
+        ActiveModel {
+            id: ActiveValue::NotSet,
+            user_id: ActiveValue::set(user_id),
+            wallet_id: ActiveValue::set(wallet_id),
+            kind: ActiveValue::set(kind),
+            input_mint: ActiveValue::set(input_mint),
+            output_mint: ActiveValue::set(output_mint),
+            recipient: ActiveValue::set(recipient),
+            amount: ActiveValue::set(amount),
+            max_slippage: ActiveValue::set(max_slippage),
+            cron: ActiveValue::set(cron),
+            interval_secs: ActiveValue::set(interval_secs),
+            spend_cap: ActiveValue::set(spend_cap),
+            spent: ActiveValue::set(0.0),
+            retry_count: ActiveValue::set(0),
+            status: ActiveValue::set(DcaStatus::Active),
+            next_run_at: ActiveValue::set(next_run_at),
+            last_run_at: Default::default(),
+            last_error: Default::default(),
+            created_at: ActiveValue::set(now_datetime),
+            updated_at: ActiveValue::set(now_datetime),
+        }
+    }
+
+    /// # Description
+    ///     判断本次执行后是否仍在支出上限之内
+    /// # Return
+    ///     bool: 是否允许执行
+    pub fn within_cap(&self) -> bool {
+        match self.spend_cap {
+            Some(cap) => self.spent + self.amount <= cap + f64::EPSILON,
+            None => true,
+        }
+    }
+
+    /// # Description
+    ///     记录一次成功执行，累计支出并推进到下一个周期，达到上限时计划完成
+    /// # Param
+    ///     next_run_at: DateTime<Utc> - 下一个周期的执行时间
+    /// # Return
+    ///     ()
+    pub fn record_success(&mut self, next_run_at: DateTime<Utc>) {
+        let now = Utc::now();
+        self.spent += self.amount;
+        self.retry_count = 0;
+        self.last_run_at = Some(now);
+        self.last_error = None;
+        self.next_run_at = next_run_at;
+        self.updated_at = now;
+
+        if !self.within_cap() {
+            self.status = DcaStatus::Completed;
+        }
+    }
+
+    /// # Description
+    ///     记录一次失败执行，有重试时间则按退避重试，否则放弃本周期
+    /// # Param
+    ///     error: String - 失败原因
+    ///     retry_at: Option<DateTime<Utc>> - 重试时间
+    ///     next_run_at: DateTime<Utc> - 下一个周期的执行时间
+    /// # Return
+    ///     ()
+    pub fn record_failure(&mut self, error: String, retry_at: Option<DateTime<Utc>>, next_run_at: DateTime<Utc>) {
+        let now = Utc::now();
+        self.last_run_at = Some(now);
+        self.last_error = Some(error);
+        self.updated_at = now;
+
+        match retry_at {
+            Some(retry_at) => {
+                self.retry_count += 1;
+                self.next_run_at = retry_at;
+            }
+            None => {
+                self.retry_count = 0;
+                self.next_run_at = next_run_at;
+            }
+        }
+    }
+
+    /// # Description
+    ///     暂停计划
+    /// # Return
+    ///     Result<(), String>: 状态不允许时返回错误信息
+    pub fn pause(&mut self) -> Result<(), String> {
+        self.transition(DcaStatus::Active, DcaStatus::Paused)
+    }
+
+    /// # Description
+    ///     恢复计划，从下一个周期开始执行
+    /// # Param
+    ///     next_run_at: DateTime<Utc> - 下一个周期的执行时间
+    /// # Return
+    ///     Result<(), String>: 状态不允许时返回错误信息
+    pub fn resume(&mut self, next_run_at: DateTime<Utc>) -> Result<(), String> {
+        self.transition(DcaStatus::Paused, DcaStatus::Active)?;
+        self.retry_count = 0;
+        self.next_run_at = next_run_at;
+        Ok(())
+    }
+
+    /// # Description
+    ///     取消计划
+    /// # Return
+    ///     Result<(), String>: 状态不允许时返回错误信息
+    pub fn cancel(&mut self) -> Result<(), String> {
+        match self.status {
+            DcaStatus::Active | DcaStatus::Paused => {
+                self.status = DcaStatus::Cancelled;
+                self.updated_at = Utc::now();
+                Ok(())
+            }
+            _ => Err(format!("计划状态为 {:?}，无法取消", self.status)),
+        }
+    }
+
+    fn transition(&mut self, from: DcaStatus, to: DcaStatus) -> Result<(), String> {
+        if self.status != from {
+            return Err(format!("计划状态为 {:?}，无法变更为 {:?}", self.status, to));
+        }
+        self.status = to;
+        self.updated_at = Utc::now();
+        Ok(())
+    }
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
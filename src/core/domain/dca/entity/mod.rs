@@ -0,0 +1,2 @@
+pub mod dca_schedule_entity;
+pub mod dca_execution_entity;
@@ -0,0 +1,6 @@
+pub mod entity;
+pub mod repository;
+pub mod service;
+mod dca_domain;
+
+pub use dca_domain::DcaDomain;
@@ -0,0 +1,20 @@
+use axum::async_trait;
+use chrono::{DateTime, Utc};
+use color_eyre::{Report, Result};
+use crate::core::domain::dca::entity::dca_schedule_entity::{ActiveModel as DcaScheduleActiveModel, Model as DcaScheduleModel, DcaStatus};
+use crate::core::domain::dca::entity::dca_execution_entity::{ActiveModel as DcaExecutionActiveModel, Model as DcaExecutionModel};
+
+/// # Description
+///     定投计划仓储接口
+#[async_trait]
+pub trait DcaRepositoryInterface: Send + Sync {
+    async fn find_by_id(&self, id: i32) -> Result<Option<DcaScheduleModel>, Report>;
+    async fn find_by_user(&self, user_id: i32) -> Result<Vec<DcaScheduleModel>, Report>;
+    async fn find_due(&self, now: DateTime<Utc>, limit: u64) -> Result<Vec<DcaScheduleModel>, Report>;
+    async fn save(&self, schedule: DcaScheduleActiveModel) -> Result<DcaScheduleModel, Report>;
+    async fn save_if_status(&self, schedule: DcaScheduleModel, expected: DcaStatus) -> Result<bool, Report>;
+    async fn claim_run(&self, schedule: DcaScheduleModel, scheduled_for: DateTime<Utc>) -> Result<bool, Report>;
+    async fn save_run(&self, schedule: DcaScheduleModel) -> Result<(), Report>;
+    async fn record_execution(&self, execution: DcaExecutionActiveModel) -> Result<(), Report>;
+    async fn find_executions(&self, schedule_id: i32, limit: u64) -> Result<Vec<DcaExecutionModel>, Report>;
+}
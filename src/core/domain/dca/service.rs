@@ -0,0 +1,192 @@
+use std::str::FromStr;
+use chrono::{DateTime, Duration, Utc};
+use color_eyre::{Report, Result};
+use cron::Schedule;
+use super::entity::dca_schedule_entity::{ActiveModel as DcaScheduleActiveModel, Model as DcaScheduleModel, DcaKind};
+
+/// # Description
+///     定投计划的周期定义
+/// # Param
+///     Cron(String): cron 表达式（秒 分 时 日 月 周）
+///     Interval(i64): 固定执行间隔（秒）
+#[derive(Debug, Clone, PartialEq)]
+pub enum DcaCadence {
+    Cron(String),
+    Interval(i64),
+}
+
+impl DcaCadence {
+    /// # Description
+    ///     由计划字段还原周期定义
+    /// # Param
+    ///     cron: Option<&str> - cron 表达式
+    ///     interval_secs: Option<i64> - 固定执行间隔
+    /// # Return
+    ///     Result<DcaCadence, Report>: 两者必须且只能设置一个
+    pub fn from_parts(cron: Option<&str>, interval_secs: Option<i64>) -> Result<Self, Report> {
+        match (cron, interval_secs) {
+            (Some(cron), None) => Ok(Self::Cron(cron.trim().to_string())),
+            (None, Some(interval)) => Ok(Self::Interval(interval)),
+            _ => Err(Report::msg("cron 与 interval_secs 必须且只能设置一个")),
+        }
+    }
+
+    /// # Description
+    ///     计算指定时间之后的下一次执行时间
+    /// # Param
+    ///     after: DateTime<Utc> - 起始时间
+    /// # Return
+    ///     Result<DateTime<Utc>, Report>: 下一次执行时间
+    pub fn next_after(&self, after: DateTime<Utc>) -> Result<DateTime<Utc>, Report> {
+        match self {
+            Self::Cron(expression) => {
+                let schedule = Schedule::from_str(expression).map_err(|e| Report::msg(format!("cron 表达式不合法: {}", e)))?;
+                schedule.after(&after).next().ok_or_else(|| Report::msg("cron 表达式没有后续执行时间"))
+            }
+            Self::Interval(interval) => Ok(after + Duration::seconds(*interval)),
+        }
+    }
+}
+
+/// # Description
+///     定投计划参数
+/// # Fields
+///     kind: DcaKind - 计划类型
+///     input_mint: String - 卖出或转出代币
+///     output_mint: Option<String> - 买入代币
+///     recipient: Option<String> - 接收地址
+///     amount: f64 - 每次执行的数量
+///     max_slippage: f64 - 最大滑点比例
+///     cadence: DcaCadence - 执行周期
+///     spend_cap: Option<f64> - 累计支出上限
+#[derive(Debug, Clone)]
+pub struct DcaPlan {
+    pub kind: DcaKind,
+    pub input_mint: String,
+    pub output_mint: Option<String>,
+    pub recipient: Option<String>,
+    pub amount: f64,
+    pub max_slippage: f64,
+    pub cadence: DcaCadence,
+    pub spend_cap: Option<f64>,
+}
+
+/// # Description
+///     定投领域服务
+pub struct DcaDomainService {}
+
+impl DcaDomainService {
+    /// # Description
+    ///     创建新的定投领域服务
+    /// # Return
+    ///     DcaDomainService: 定投领域服务实例
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    /// # Description
+    ///     校验参数并创建定投计划实体
+    /// # Param
+    ///     user_id: i32 - 用户id
+    ///     wallet_id: i32 - 钱包id
+    ///     plan: DcaPlan - 计划参数
+    ///     min_interval: i64 - 允许的最小执行间隔（秒）
+    ///     slippage_limit: f64 - 允许的最大滑点比例
+    ///     now: DateTime<Utc> - 当前时间
+    /// # Return
+    ///     Result<DcaScheduleActiveModel, Report>: 成功返回计划实体，参数不合法时返回错误信息
+    pub fn create_schedule(
+        &self,
+        user_id: i32,
+        wallet_id: i32,
+        plan: DcaPlan,
+        min_interval: i64,
+        slippage_limit: f64,
+        now: DateTime<Utc>,
+    ) -> Result<DcaScheduleActiveModel, Report> {
+        if !plan.amount.is_finite() || plan.amount <= 0.0 {
+            return Err(Report::msg("每次执行数量必须大于 0"));
+        }
+        if plan.spend_cap.is_some_and(|cap| cap < plan.amount) {
+            return Err(Report::msg("支出上限不能小于每次执行数量"));
+        }
+
+        let (output_mint, recipient, max_slippage) = match plan.kind {
+            DcaKind::Swap => {
+                let output_mint = plan.output_mint.ok_or_else(|| Report::msg("兑换计划必须指定 output_mint"))?;
+                if output_mint == plan.input_mint {
+                    return Err(Report::msg("输入代币与输出代币不能相同"));
+                }
+                if !(plan.max_slippage > 0.0 && plan.max_slippage <= slippage_limit) {
+                    return Err(Report::msg(format!("滑点必须在 0 到 {} 之间", slippage_limit)));
+                }
+                (Some(output_mint), None, plan.max_slippage)
+            }
+            DcaKind::Transfer => {
+                let recipient = plan.recipient.ok_or_else(|| Report::msg("转账计划必须指定 recipient"))?;
+                (None, Some(recipient), 0.0)
+            }
+        };
+
+        // 以连续两次执行的间隔校验频率，防止过于频繁的计划
+        let first_run = plan.cadence.next_after(now)?;
+        let second_run = plan.cadence.next_after(first_run)?;
+        if (second_run - first_run).num_seconds() < min_interval {
+            return Err(Report::msg(format!("执行间隔不能小于 {} 秒", min_interval)));
+        }
+
+        let (cron, interval_secs) = match plan.cadence {
+            DcaCadence::Cron(expression) => (Some(expression), None),
+            DcaCadence::Interval(interval) => (None, Some(interval)),
+        };
+
+        Ok(DcaScheduleModel::new(
+            user_id,
+            wallet_id,
+            plan.kind,
+            plan.input_mint,
+            output_mint,
+            recipient,
+            plan.amount,
+            max_slippage,
+            cron,
+            interval_secs,
+            plan.spend_cap,
+            first_run,
+        ))
+    }
+
+    /// # Description
+    ///     计算计划的下一个周期执行时间；停机期间错过的周期不补跑，直接从当前时间之后的周期开始
+    /// # Param
+    ///     schedule: &DcaScheduleModel - 定投计划
+    ///     now: DateTime<Utc> - 当前时间
+    /// # Return
+    ///     Result<DateTime<Utc>, Report>: 下一次执行时间
+    pub fn next_run(&self, schedule: &DcaScheduleModel, now: DateTime<Utc>) -> Result<DateTime<Utc>, Report> {
+        let cadence = DcaCadence::from_parts(schedule.cron.as_deref(), schedule.interval_secs)?;
+
+        cadence.next_after(now.max(schedule.next_run_at))
+    }
+
+    /// # Description
+    ///     计算失败后的重试时间，重试间隔按 2 的指数退避并设上限；超过重试次数返回 None
+    /// # Param
+    ///     schedule: &DcaScheduleModel - 定投计划
+    ///     max_retries: i32 - 最大重试次数
+    ///     retry_base: i64 - 基础退避时间（秒）
+    ///     retry_max: i64 - 最大退避时间（秒）
+    ///     now: DateTime<Utc> - 当前时间
+    /// # Return
+    ///     Option<DateTime<Utc>>: 重试时间
+    pub fn retry_at(&self, schedule: &DcaScheduleModel, max_retries: i32, retry_base: i64, retry_max: i64, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        if schedule.retry_count >= max_retries {
+            return None;
+        }
+
+        let factor = 1i64 << schedule.retry_count.clamp(0, 30);
+        let delay = retry_base.saturating_mul(factor).min(retry_max);
+
+        Some(now + Duration::seconds(delay))
+    }
+}
@@ -2,12 +2,52 @@ use std::sync::Arc;
 use tracing::info;
 use crate::core::domain::user::UserDomain;
 use crate::core::domain::wallet::WalletDomain;
+use crate::core::domain::price::PriceDomain;
+use crate::core::domain::order::OrderDomain;
+use crate::core::domain::dca::DcaDomain;
+use crate::core::domain::market::MarketDomain;
+use crate::core::domain::indexer::IndexerDomain;
+use crate::core::domain::network::NetworkDomain;
+use crate::core::domain::history::HistoryDomain;
+use crate::core::domain::instruction::InstructionDomain;
+use crate::core::domain::account::AccountDomain;
+use crate::core::domain::webhook::WebhookDomain;
+use crate::core::domain::watcher::WatcherDomain;
+use crate::core::domain::stream::StreamDomain;
+use crate::core::domain::reorg::ReorgDomain;
+use crate::core::domain::auth::AuthDomain;
+use crate::core::domain::verification::VerificationDomain;
+use crate::core::domain::mail::MailDomain;
+use crate::core::domain::sms::SmsDomain;
+use crate::core::domain::two_factor::TwoFactorDomain;
+use crate::core::domain::withdrawal::WithdrawalDomain;
+use crate::core::domain::throttle::ThrottleDomain;
 
 /// # Description
 ///     领域层，管理领域逻辑的核心组件
 pub struct DomainLayer {
     pub wallet_domain: Arc<WalletDomain>,
     pub user_domain: Arc<UserDomain>,
+    pub price_domain: Arc<PriceDomain>,
+    pub order_domain: Arc<OrderDomain>,
+    pub dca_domain: Arc<DcaDomain>,
+    pub market_domain: Arc<MarketDomain>,
+    pub indexer_domain: Arc<IndexerDomain>,
+    pub network_domain: Arc<NetworkDomain>,
+    pub history_domain: Arc<HistoryDomain>,
+    pub instruction_domain: Arc<InstructionDomain>,
+    pub account_domain: Arc<AccountDomain>,
+    pub webhook_domain: Arc<WebhookDomain>,
+    pub watcher_domain: Arc<WatcherDomain>,
+    pub stream_domain: Arc<StreamDomain>,
+    pub reorg_domain: Arc<ReorgDomain>,
+    pub auth_domain: Arc<AuthDomain>,
+    pub verification_domain: Arc<VerificationDomain>,
+    pub mail_domain: Arc<MailDomain>,
+    pub sms_domain: Arc<SmsDomain>,
+    pub two_factor_domain: Arc<TwoFactorDomain>,
+    pub withdrawal_domain: Arc<WithdrawalDomain>,
+    pub throttle_domain: Arc<ThrottleDomain>,
 }
 
 impl DomainLayer {
@@ -26,9 +66,109 @@ impl DomainLayer {
         let wallet_domain = Arc::new(WalletDomain::new().await);
         info!("+DomainLayer [WalletDomain] Instant config complete.");
 
+        // 初始化价格子领域
+        let price_domain = Arc::new(PriceDomain::new().await);
+        info!("+DomainLayer [PriceDomain] Instant config complete.");
+
+        // 初始化条件单子领域
+        let order_domain = Arc::new(OrderDomain::new().await);
+        info!("+DomainLayer [OrderDomain] Instant config complete.");
+
+        // 初始化定投子领域
+        let dca_domain = Arc::new(DcaDomain::new().await);
+        info!("+DomainLayer [DcaDomain] Instant config complete.");
+
+        // 初始化市场行情子领域
+        let market_domain = Arc::new(MarketDomain::new().await);
+        info!("+DomainLayer [MarketDomain] Instant config complete.");
+
+        // 初始化区块索引子领域
+        let indexer_domain = Arc::new(IndexerDomain::new().await);
+        info!("+DomainLayer [IndexerDomain] Instant config complete.");
+
+        // 初始化网络统计子领域
+        let network_domain = Arc::new(NetworkDomain::new().await);
+        info!("+DomainLayer [NetworkDomain] Instant config complete.");
+
+        // 初始化交易历史子领域
+        let history_domain = Arc::new(HistoryDomain::new().await);
+        info!("+DomainLayer [HistoryDomain] Instant config complete.");
+
+        // 初始化指令解码子领域
+        let instruction_domain = Arc::new(InstructionDomain::new().await);
+        info!("+DomainLayer [InstructionDomain] Instant config complete.");
+
+        // 初始化账户查询子领域
+        let account_domain = Arc::new(AccountDomain::new().await);
+        info!("+DomainLayer [AccountDomain] Instant config complete.");
+
+        // 初始化 Webhook 通知子领域
+        let webhook_domain = Arc::new(WebhookDomain::new().await);
+        info!("+DomainLayer [WebhookDomain] Instant config complete.");
+
+        // 初始化链上监听子领域
+        let watcher_domain = Arc::new(WatcherDomain::new().await);
+        info!("+DomainLayer [WatcherDomain] Instant config complete.");
+
+        // 初始化实时推送子领域
+        let stream_domain = Arc::new(StreamDomain::new().await);
+        info!("+DomainLayer [StreamDomain] Instant config complete.");
+
+        // 初始化分叉回滚子领域
+        let reorg_domain = Arc::new(ReorgDomain::new().await);
+        info!("+DomainLayer [ReorgDomain] Instant config complete.");
+
+        // 初始化认证子领域
+        let auth_domain = Arc::new(AuthDomain::new().await);
+        info!("+DomainLayer [AuthDomain] Instant config complete.");
+
+        // 初始化验证码子领域
+        let verification_domain = Arc::new(VerificationDomain::new().await);
+        info!("+DomainLayer [VerificationDomain] Instant config complete.");
+
+        // 初始化邮件子领域
+        let mail_domain = Arc::new(MailDomain::new().await);
+        info!("+DomainLayer [MailDomain] Instant config complete.");
+
+        // 初始化短信子领域
+        let sms_domain = Arc::new(SmsDomain::new().await);
+        info!("+DomainLayer [SmsDomain] Instant config complete.");
+
+        // 初始化两步验证子领域
+        let two_factor_domain = Arc::new(TwoFactorDomain::new().await);
+        info!("+DomainLayer [TwoFactorDomain] Instant config complete.");
+
+        // 初始化提现子领域
+        let withdrawal_domain = Arc::new(WithdrawalDomain::new().await);
+        info!("+DomainLayer [WithdrawalDomain] Instant config complete.");
+
+        // 初始化登录限流子领域
+        let throttle_domain = Arc::new(ThrottleDomain::new().await);
+        info!("+DomainLayer [ThrottleDomain] Instant config complete.");
+
         Self {
             user_domain: user_domain.clone(),
             wallet_domain: wallet_domain.clone(),
+            price_domain: price_domain.clone(),
+            order_domain: order_domain.clone(),
+            dca_domain: dca_domain.clone(),
+            market_domain: market_domain.clone(),
+            indexer_domain: indexer_domain.clone(),
+            network_domain: network_domain.clone(),
+            history_domain: history_domain.clone(),
+            instruction_domain: instruction_domain.clone(),
+            account_domain: account_domain.clone(),
+            webhook_domain: webhook_domain.clone(),
+            watcher_domain: watcher_domain.clone(),
+            stream_domain: stream_domain.clone(),
+            reorg_domain: reorg_domain.clone(),
+            auth_domain: auth_domain.clone(),
+            verification_domain: verification_domain.clone(),
+            mail_domain: mail_domain.clone(),
+            sms_domain: sms_domain.clone(),
+            two_factor_domain: two_factor_domain.clone(),
+            withdrawal_domain: withdrawal_domain.clone(),
+            throttle_domain: throttle_domain.clone(),
         }
     }
 }
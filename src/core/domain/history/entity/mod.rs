@@ -0,0 +1,2 @@
+pub mod wallet_transaction_entity;
+pub mod transaction_detail_entity;
@@ -0,0 +1,33 @@
+use serde::{Deserialize, Serialize};
+
+/// # Description
+///     交易中某个代币的净变动
+/// # Fields
+///     mint: String - 代币 mint
+///     amount: String - 净变动的原始数量（可为负数）
+///     decimals: u8 - 代币精度
+///     ui_amount: f64 - 按精度换算后的净变动
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TokenDelta {
+    pub mint: String,
+    pub amount: String,
+    pub decimals: u8,
+    pub ui_amount: f64,
+}
+
+/// # Description
+///     解码后的指令
+/// # Fields
+///     program_id: String - 程序地址
+///     program: String - 程序名称，未知程序为 unknown
+///     kind: String - 指令类型
+///     description: String - 可读描述
+///     accounts: Vec<String> - 指令涉及的账户
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DecodedInstruction {
+    pub program_id: String,
+    pub program: String,
+    pub kind: String,
+    pub description: String,
+    pub accounts: Vec<String>,
+}
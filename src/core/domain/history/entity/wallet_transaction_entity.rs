@@ -0,0 +1,106 @@
+use chrono::Utc;
+use sea_orm::{ActiveModelBehavior, ActiveValue, DeriveEntityModel, DerivePrimaryKey, DeriveRelation, EnumIter, PrimaryKeyTrait};
+use sea_orm::entity::prelude::*;
+use sea_orm::prelude::DateTimeUtc;
+use serde::{Deserialize, Serialize};
+use super::transaction_detail_entity::{DecodedInstruction, TokenDelta};
+
+/// # Description
+///     该结构体代表钱包的一笔历史交易，并映射到数据库中的 `wallet_transaction` 表，作为链上交易历史的缓存。
+/// # Param
+///     id: 主键，自动递增
+///     wallet_id: 所属钱包，关联 wallet 表
+///     signature: 交易签名
+///     slot: 所在区块 slot
+///     block_time: 出块时间（unix 秒）
+///     success: 交易是否执行成功
+///     error: 失败原因
+///     fee: 交易手续费（lamports）
+///     sol_delta: 钱包 SOL 净变动（lamports，含手续费）
+///     token_deltas: 钱包代币净变动（JSON 数组）
+///     instructions: 解码后的顶层指令（JSON 数组）
+///     created_at: 缓存时间
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "wallet_transaction")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i64,
+    pub wallet_id: i32,
+    pub signature: String,
+    pub slot: i64,
+    pub block_time: Option<i64>,
+    pub success: bool,
+    pub error: Option<String>,
+    pub fee: i64,
+    pub sol_delta: i64,
+    #[sea_orm(column_type = "Text")]
+    pub token_deltas: String,
+    #[sea_orm(column_type = "Text")]
+    pub instructions: String,
+    pub created_at: DateTimeUtc,
+}
+
+impl Model {
+    /// # Description
+    ///     创建新的钱包交易记录
+    /// # Param
+    ///     wallet_id: i32 - 钱包id
+    ///     signature: String - 交易签名
+    ///     slot: u64 - 所在区块 slot
+    ///     block_time: Option<i64> - 出块时间
+    ///     error: Option<String> - 失败原因，None 表示成功
+    ///     fee: u64 - 手续费
+    ///     sol_delta: i64 - SOL 净变动
+    ///     token_deltas: &[TokenDelta] - 代币净变动
+    ///     instructions: &[DecodedInstruction] - 解码后的指令
+    /// # Return
+    ///     ActiveModel
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        wallet_id: i32,
+        signature: String,
+        slot: u64,
+        block_time: Option<i64>,
+        error: Option<String>,
+        fee: u64,
+        sol_delta: i64,
+        token_deltas: &[TokenDelta],
+        instructions: &[DecodedInstruction],
+    ) -> ActiveModel {
+        ActiveModel {
+            id: ActiveValue::NotSet,
+            wallet_id: ActiveValue::set(wallet_id),
+            signature: ActiveValue::set(signature),
+            slot: ActiveValue::set(slot as i64),
+            block_time: ActiveValue::set(block_time),
+            success: ActiveValue::set(error.is_none()),
+            error: ActiveValue::set(error),
+            fee: ActiveValue::set(fee as i64),
+            sol_delta: ActiveValue::set(sol_delta),
+            token_deltas: ActiveValue::set(serde_json::to_string(token_deltas).unwrap_or_default()),
+            instructions: ActiveValue::set(serde_json::to_string(instructions).unwrap_or_default()),
+            created_at: ActiveValue::set(Utc::now()),
+        }
+    }
+
+    /// # Description
+    ///     解析代币净变动
+    /// # Return
+    ///     Vec<TokenDelta>: 代币净变动列表
+    pub fn token_delta_list(&self) -> Vec<TokenDelta> {
+        serde_json::from_str(&self.token_deltas).unwrap_or_default()
+    }
+
+    /// # Description
+    ///     解析解码后的指令
+    /// # Return
+    ///     Vec<DecodedInstruction>: 指令列表
+    pub fn instruction_list(&self) -> Vec<DecodedInstruction> {
+        serde_json::from_str(&self.instructions).unwrap_or_default()
+    }
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,20 @@
+use std::sync::Arc;
+use crate::core::domain::history::service::HistoryDomainService;
+
+pub struct HistoryDomain {
+    pub domain_service: Arc<HistoryDomainService>,
+}
+
+impl HistoryDomain {
+    /// # Description
+    ///     初始化交易历史领域
+    /// # Return
+    ///     Self: 初始化后的交易历史领域实例
+    pub async fn new() -> Self {
+        let domain_service = Arc::new(HistoryDomainService::new());
+
+        Self {
+            domain_service
+        }
+    }
+}
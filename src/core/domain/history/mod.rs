@@ -0,0 +1,6 @@
+pub mod entity;
+pub mod repository;
+pub mod service;
+mod history_domain;
+
+pub use history_domain::HistoryDomain;
@@ -0,0 +1,14 @@
+use axum::async_trait;
+use color_eyre::{Report, Result};
+use crate::core::domain::history::entity::wallet_transaction_entity::{ActiveModel as WalletTransactionActiveModel, Model as WalletTransactionModel};
+
+/// # Description
+///     钱包交易历史仓储接口
+#[async_trait]
+pub trait HistoryRepositoryInterface: Send + Sync {
+    async fn find_page(&self, wallet_id: i32, offset: u64, limit: u64) -> Result<Vec<WalletTransactionModel>, Report>;
+    async fn count(&self, wallet_id: i32) -> Result<u64, Report>;
+    async fn find_newest(&self, wallet_id: i32) -> Result<Option<WalletTransactionModel>, Report>;
+    async fn find_oldest(&self, wallet_id: i32) -> Result<Option<WalletTransactionModel>, Report>;
+    async fn save_all(&self, wallet_id: i32, transactions: Vec<WalletTransactionActiveModel>) -> Result<(), Report>;
+}
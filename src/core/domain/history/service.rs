@@ -0,0 +1,121 @@
+use std::collections::BTreeMap;
+use color_eyre::{Report, Result};
+use solana_sdk::transaction::VersionedTransaction;
+use solana_transaction_status::{UiTransactionStatusMeta, UiTransactionTokenBalance};
+use super::entity::transaction_detail_entity::{DecodedInstruction, TokenDelta};
+use super::entity::wallet_transaction_entity::{ActiveModel as WalletTransactionActiveModel, Model as WalletTransactionModel};
+
+/// # Description
+///     交易历史领域服务，负责计算钱包余额变动
+pub struct HistoryDomainService;
+
+impl HistoryDomainService {
+    /// # Description
+    ///     创建新的交易历史领域服务实例
+    /// # Return
+    ///     Self: 交易历史领域服务实例
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// # Description
+    ///     把一笔链上交易转换为钱包交易记录
+    /// # Param
+    ///     wallet_id: i32 - 钱包id
+    ///     address: &str - 钱包地址
+    ///     slot: u64 - 所在区块 slot
+    ///     block_time: Option<i64> - 出块时间
+    ///     transaction: &VersionedTransaction - 已解码的交易
+    ///     meta: Option<&UiTransactionStatusMeta> - 交易元数据
+    ///     accounts: &[String] - 交易涉及的全部账户（含地址查找表加载的账户）
+    ///     instructions: Vec<DecodedInstruction> - 已解码的指令
+    /// # Return
+    ///     Result<WalletTransactionActiveModel, Report>: 钱包交易记录
+    #[allow(clippy::too_many_arguments)]
+    pub fn build_transaction(
+        &self,
+        wallet_id: i32,
+        address: &str,
+        slot: u64,
+        block_time: Option<i64>,
+        transaction: &VersionedTransaction,
+        meta: Option<&UiTransactionStatusMeta>,
+        accounts: &[String],
+        instructions: Vec<DecodedInstruction>,
+    ) -> Result<WalletTransactionActiveModel, Report> {
+        let signature = transaction.signatures.first()
+            .ok_or_else(|| Report::msg("交易缺少签名"))?
+            .to_string();
+
+        let (fee, error, sol_delta, token_deltas) = match meta {
+            Some(meta) => {
+                let pre_token_balances: Option<&Vec<UiTransactionTokenBalance>> = meta.pre_token_balances.as_ref().into();
+                let post_token_balances: Option<&Vec<UiTransactionTokenBalance>> = meta.post_token_balances.as_ref().into();
+                (
+                    meta.fee,
+                    meta.err.as_ref().map(|err| err.to_string()),
+                    self.sol_delta(address, accounts, &meta.pre_balances, &meta.post_balances),
+                    self.token_deltas(
+                        address,
+                        pre_token_balances.map(Vec::as_slice).unwrap_or_default(),
+                        post_token_balances.map(Vec::as_slice).unwrap_or_default(),
+                    ),
+                )
+            }
+            None => (0, None, 0, Vec::new()),
+        };
+
+        Ok(WalletTransactionModel::new(wallet_id, signature, slot, block_time, error, fee, sol_delta, &token_deltas, &instructions))
+    }
+
+    /// # Description
+    ///     计算钱包的 SOL 净变动，钱包作为手续费支付方时包含手续费
+    /// # Param
+    ///     address: &str - 钱包地址
+    ///     accounts: &[String] - 交易涉及的全部账户
+    ///     pre_balances: &[u64] - 交易前余额
+    ///     post_balances: &[u64] - 交易后余额
+    /// # Return
+    ///     i64: 净变动（lamports），钱包不在交易账户中时为 0
+    pub fn sol_delta(&self, address: &str, accounts: &[String], pre_balances: &[u64], post_balances: &[u64]) -> i64 {
+        accounts.iter()
+            .position(|account| account == address)
+            .and_then(|index| Some(*post_balances.get(index)? as i64 - *pre_balances.get(index)? as i64))
+            .unwrap_or_default()
+    }
+
+    /// # Description
+    ///     按 mint 汇总钱包名下全部代币账户的净变动，忽略没有变化的代币
+    /// # Param
+    ///     address: &str - 钱包地址
+    ///     pre: &[UiTransactionTokenBalance] - 交易前代币余额
+    ///     post: &[UiTransactionTokenBalance] - 交易后代币余额
+    /// # Return
+    ///     Vec<TokenDelta>: 代币净变动，按 mint 排序
+    pub fn token_deltas(&self, address: &str, pre: &[UiTransactionTokenBalance], post: &[UiTransactionTokenBalance]) -> Vec<TokenDelta> {
+        let mut deltas: BTreeMap<String, (i128, u8)> = BTreeMap::new();
+
+        for (balances, sign) in [(pre, -1i128), (post, 1i128)] {
+            for balance in balances {
+                let owner: Option<&String> = balance.owner.as_ref().into();
+                if owner.map(String::as_str) != Some(address) {
+                    continue;
+                }
+
+                let amount = balance.ui_token_amount.amount.parse::<i128>().unwrap_or_default();
+                let entry = deltas.entry(balance.mint.clone()).or_insert((0, balance.ui_token_amount.decimals));
+                entry.0 += sign * amount;
+            }
+        }
+
+        deltas.into_iter()
+            .filter(|(_, (amount, _))| *amount != 0)
+            .map(|(mint, (amount, decimals))| TokenDelta {
+                mint,
+                amount: amount.to_string(),
+                decimals,
+                ui_amount: amount as f64 / 10f64.powi(decimals as i32),
+            })
+            .collect()
+    }
+}
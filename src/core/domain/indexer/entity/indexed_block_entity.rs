@@ -0,0 +1,111 @@
+use chrono::Utc;
+use sea_orm::{ActiveModelBehavior, ActiveValue, DeriveEntityModel, DerivePrimaryKey, DeriveRelation, EnumIter, PrimaryKeyTrait};
+use sea_orm::entity::prelude::*;
+use sea_orm::prelude::DateTimeUtc;
+use serde::{Deserialize, Serialize};
+use crate::core::domain::reorg::entity::commitment_entity::CommitmentLevel;
+
+/// # Description
+///     该结构体代表已索引的区块，并映射到数据库中的 `indexed_block` 表。被跳过的 slot 同样记录一行，`skipped` 为 true。
+/// # Param
+///     slot: 主键，区块所在 slot
+///     parent_slot: 父区块 slot
+///     blockhash: 区块哈希
+///     previous_blockhash: 父区块哈希
+///     block_time: 出块时间（unix 秒）
+///     block_height: 区块高度
+///     transaction_count: 区块内交易总数
+///     vote_transaction_count: 区块内投票交易数量
+///     total_fee: 区块内交易手续费合计（lamports）
+///     skipped: 是否为被跳过的 slot
+///     commitment: 确认级别，按 confirmed 写入，最终确认后提升为 finalized，所在分叉被丢弃时为 dropped
+///     indexed_at: 索引时间
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "indexed_block")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub slot: i64,
+    pub parent_slot: Option<i64>,
+    pub blockhash: Option<String>,
+    pub previous_blockhash: Option<String>,
+    pub block_time: Option<i64>,
+    pub block_height: Option<i64>,
+    pub transaction_count: i32,
+    pub vote_transaction_count: i32,
+    pub total_fee: i64,
+    pub skipped: bool,
+    pub commitment: CommitmentLevel,
+    pub indexed_at: DateTimeUtc,
+}
+
+impl Model {
+    /// # Description
+    ///     创建已出块 slot 的索引记录
+    /// # Param
+    ///     slot: u64 - 区块 slot
+    ///     parent_slot: u64 - 父区块 slot
+    ///     blockhash: String - 区块哈希
+    ///     previous_blockhash: String - 父区块哈希
+    ///     block_time: Option<i64> - 出块时间
+    ///     block_height: Option<u64> - 区块高度
+    ///     transaction_count: usize - 交易总数
+    ///     vote_transaction_count: usize - 投票交易数量
+    ///     total_fee: u64 - 手续费合计
+    /// # Return
+    ///     ActiveModel
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        slot: u64,
+        parent_slot: u64,
+        blockhash: String,
+        previous_blockhash: String,
+        block_time: Option<i64>,
+        block_height: Option<u64>,
+        transaction_count: usize,
+        vote_transaction_count: usize,
+        total_fee: u64,
+    ) -> ActiveModel {
+        ActiveModel {
+            slot: ActiveValue::set(slot as i64),
+            parent_slot: ActiveValue::set(Some(parent_slot as i64)),
+            blockhash: ActiveValue::set(Some(blockhash)),
+            previous_blockhash: ActiveValue::set(Some(previous_blockhash)),
+            block_time: ActiveValue::set(block_time),
+            block_height: ActiveValue::set(block_height.map(|height| height as i64)),
+            transaction_count: ActiveValue::set(transaction_count as i32),
+            vote_transaction_count: ActiveValue::set(vote_transaction_count as i32),
+            total_fee: ActiveValue::set(total_fee as i64),
+            skipped: ActiveValue::set(false),
+            commitment: ActiveValue::set(CommitmentLevel::Confirmed),
+            indexed_at: ActiveValue::set(Utc::now()),
+        }
+    }
+
+    /// # Description
+    ///     创建被跳过 slot 的索引记录，该 slot 的领导者未出块
+    /// # Param
+    ///     slot: u64 - 被跳过的 slot
+    /// # Return
+    ///     ActiveModel
+    pub fn skipped(slot: u64) -> ActiveModel {
+        ActiveModel {
+            slot: ActiveValue::set(slot as i64),
+            parent_slot: ActiveValue::set(None),
+            blockhash: ActiveValue::set(None),
+            previous_blockhash: ActiveValue::set(None),
+            block_time: ActiveValue::set(None),
+            block_height: ActiveValue::set(None),
+            transaction_count: ActiveValue::set(0),
+            vote_transaction_count: ActiveValue::set(0),
+            total_fee: ActiveValue::set(0),
+            skipped: ActiveValue::set(true),
+            commitment: ActiveValue::set(CommitmentLevel::Confirmed),
+            indexed_at: ActiveValue::set(Utc::now()),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,109 @@
+use chrono::Utc;
+use sea_orm::{ActiveModelBehavior, ActiveValue, DeriveEntityModel, DerivePrimaryKey, DeriveRelation, EnumIter, PrimaryKeyTrait};
+use sea_orm::entity::prelude::*;
+use sea_orm::prelude::DateTimeUtc;
+use serde::{Deserialize, Serialize};
+use crate::core::domain::reorg::entity::commitment_entity::CommitmentLevel;
+
+/// # Description
+///     该结构体代表已索引的非投票交易，并映射到数据库中的 `indexed_transaction` 表。
+/// # Param
+///     id: 主键，自动递增
+///     signature: 交易签名
+///     slot: 所在区块 slot
+///     tx_index: 交易在区块内的序号
+///     block_time: 出块时间（unix 秒）
+///     fee: 交易手续费（lamports）
+///     success: 交易是否执行成功
+///     error: 失败原因
+///     compute_units: 消耗的计算单元
+///     accounts: 交易涉及的全部账户（JSON 数组，含地址查找表加载的账户）
+///     program_ids: 交易调用的程序（JSON 数组，去重）
+///     commitment: 确认级别，与所在区块一致
+///     created_at: 索引时间
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "indexed_transaction")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i64,
+    pub signature: String,
+    pub slot: i64,
+    pub tx_index: i32,
+    pub block_time: Option<i64>,
+    pub fee: i64,
+    pub success: bool,
+    pub error: Option<String>,
+    pub compute_units: Option<i64>,
+    #[sea_orm(column_type = "Text")]
+    pub accounts: String,
+    #[sea_orm(column_type = "Text")]
+    pub program_ids: String,
+    pub commitment: CommitmentLevel,
+    pub created_at: DateTimeUtc,
+}
+
+impl Model {
+    /// # Description
+    ///     创建新的交易索引记录
+    /// # Param
+    ///     signature: String - 交易签名
+    ///     slot: u64 - 所在区块 slot
+    ///     tx_index: usize - 区块内序号
+    ///     block_time: Option<i64> - 出块时间
+    ///     fee: u64 - 手续费
+    ///     error: Option<String> - 失败原因，None 表示成功
+    ///     compute_units: Option<u64> - 消耗的计算单元
+    ///     accounts: &[String] - 交易涉及的账户
+    ///     program_ids: &[String] - 交易调用的程序
+    /// # Return
+    ///     ActiveModel
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        signature: String,
+        slot: u64,
+        tx_index: usize,
+        block_time: Option<i64>,
+        fee: u64,
+        error: Option<String>,
+        compute_units: Option<u64>,
+        accounts: &[String],
+        program_ids: &[String],
+    ) -> ActiveModel {
+        ActiveModel {
+            id: ActiveValue::NotSet,
+            signature: ActiveValue::set(signature),
+            slot: ActiveValue::set(slot as i64),
+            tx_index: ActiveValue::set(tx_index as i32),
+            block_time: ActiveValue::set(block_time),
+            fee: ActiveValue::set(fee as i64),
+            success: ActiveValue::set(error.is_none()),
+            error: ActiveValue::set(error),
+            compute_units: ActiveValue::set(compute_units.map(|units| units as i64)),
+            accounts: ActiveValue::set(serde_json::to_string(accounts).unwrap_or_default()),
+            program_ids: ActiveValue::set(serde_json::to_string(program_ids).unwrap_or_default()),
+            commitment: ActiveValue::set(CommitmentLevel::Confirmed),
+            created_at: ActiveValue::set(Utc::now()),
+        }
+    }
+
+    /// # Description
+    ///     解析交易涉及的账户列表
+    /// # Return
+    ///     Vec<String>: 账户地址列表
+    pub fn account_list(&self) -> Vec<String> {
+        serde_json::from_str(&self.accounts).unwrap_or_default()
+    }
+
+    /// # Description
+    ///     解析交易调用的程序列表
+    /// # Return
+    ///     Vec<String>: 程序地址列表
+    pub fn program_id_list(&self) -> Vec<String> {
+        serde_json::from_str(&self.program_ids).unwrap_or_default()
+    }
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,50 @@
+use chrono::Utc;
+use sea_orm::{ActiveModelBehavior, ActiveValue, DeriveEntityModel, DerivePrimaryKey, DeriveRelation, EnumIter, PrimaryKeyTrait};
+use sea_orm::entity::prelude::*;
+use sea_orm::prelude::DateTimeUtc;
+use serde::{Deserialize, Serialize};
+
+/// # Description
+///     实时索引任务的检查点名称
+pub const LIVE_CHECKPOINT: &str = "live";
+
+/// # Description
+///     回填任务的检查点名称
+pub const BACKFILL_CHECKPOINT: &str = "backfill";
+
+/// # Description
+///     该结构体代表索引任务的检查点，并映射到数据库中的 `indexer_checkpoint` 表。
+/// # Param
+///     name: 主键，检查点名称（live / backfill）
+///     slot: 已完成索引的最后一个 slot
+///     updated_at: 更新时间
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "indexer_checkpoint")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub name: String,
+    pub slot: i64,
+    pub updated_at: DateTimeUtc,
+}
+
+impl Model {
+    /// # Description
+    ///     创建或覆盖检查点
+    /// # Param
+    ///     name: &str - 检查点名称
+    ///     slot: u64 - 已完成索引的最后一个 slot
+    /// # Return
+    ///     ActiveModel
+    pub fn new(name: &str, slot: u64) -> ActiveModel {
+        ActiveModel {
+            name: ActiveValue::set(name.to_string()),
+            slot: ActiveValue::set(slot as i64),
+            updated_at: ActiveValue::set(Utc::now()),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,3 @@
+pub mod indexed_block_entity;
+pub mod indexed_transaction_entity;
+pub mod indexer_checkpoint_entity;
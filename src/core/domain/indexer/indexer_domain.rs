@@ -0,0 +1,20 @@
+use std::sync::Arc;
+use crate::core::domain::indexer::service::IndexerDomainService;
+
+pub struct IndexerDomain {
+    pub domain_service: Arc<IndexerDomainService>,
+}
+
+impl IndexerDomain {
+    /// # Description
+    ///     初始化区块索引领域
+    /// # Return
+    ///     Self: 初始化后的区块索引领域实例
+    pub async fn new() -> Self {
+        let domain_service = Arc::new(IndexerDomainService::new());
+
+        Self {
+            domain_service
+        }
+    }
+}
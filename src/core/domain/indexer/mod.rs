@@ -0,0 +1,6 @@
+pub mod entity;
+pub mod repository;
+pub mod service;
+mod indexer_domain;
+
+pub use indexer_domain::IndexerDomain;
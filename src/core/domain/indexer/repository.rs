@@ -0,0 +1,22 @@
+use axum::async_trait;
+use color_eyre::{Report, Result};
+use crate::core::domain::indexer::entity::indexed_block_entity::{ActiveModel as IndexedBlockActiveModel, Model as IndexedBlockModel};
+use crate::core::domain::indexer::entity::indexed_transaction_entity::{ActiveModel as IndexedTransactionActiveModel, Model as IndexedTransactionModel};
+use crate::core::domain::indexer::entity::indexer_checkpoint_entity::Model as IndexerCheckpointModel;
+use crate::core::domain::reorg::entity::commitment_entity::CommitmentLevel;
+
+/// # Description
+///     区块索引仓储接口
+#[async_trait]
+pub trait IndexerRepositoryInterface: Send + Sync {
+    async fn find_checkpoint(&self, name: &str) -> Result<Option<IndexerCheckpointModel>, Report>;
+    async fn save_checkpoint(&self, name: &str, slot: u64) -> Result<(), Report>;
+    async fn save_block(&self, block: IndexedBlockActiveModel, transactions: Vec<IndexedTransactionActiveModel>) -> Result<(), Report>;
+    async fn find_block(&self, slot: u64) -> Result<Option<IndexedBlockModel>, Report>;
+    async fn find_latest_block(&self) -> Result<Option<IndexedBlockModel>, Report>;
+    async fn find_transaction(&self, signature: &str) -> Result<Option<IndexedTransactionModel>, Report>;
+    async fn find_transactions_by_slot(&self, slot: u64) -> Result<Vec<IndexedTransactionModel>, Report>;
+    async fn find_unfinalized_blocks(&self, max_slot: u64, limit: u64) -> Result<Vec<IndexedBlockModel>, Report>;
+    async fn set_commitment(&self, slots: Vec<i64>, commitment: CommitmentLevel) -> Result<(), Report>;
+    async fn mark_dropped(&self, slot: u64) -> Result<(), Report>;
+}
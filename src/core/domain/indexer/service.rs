@@ -0,0 +1,186 @@
+use color_eyre::{Report, Result};
+use solana_sdk::transaction::VersionedTransaction;
+use solana_transaction_status::{EncodedConfirmedBlock, UiLoadedAddresses, UiTransactionStatusMeta};
+use super::entity::indexed_block_entity::{ActiveModel as IndexedBlockActiveModel, Model as IndexedBlockModel};
+use super::entity::indexed_transaction_entity::{ActiveModel as IndexedTransactionActiveModel, Model as IndexedTransactionModel};
+
+/// # Description
+///     区块索引领域服务，负责把链上区块转换为待持久化的区块与交易记录
+pub struct IndexerDomainService;
+
+impl IndexerDomainService {
+    /// # Description
+    ///     创建新的区块索引领域服务实例
+    /// # Return
+    ///     Self: 区块索引领域服务实例
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// # Description
+    ///     计算下一批需要索引的 slot 范围
+    /// # Param
+    ///     last_indexed: Option<u64> - 已完成索引的最后一个 slot，None 表示尚未索引任何 slot，从 slot 0 开始
+    ///     tip: u64 - 当前链上最新 slot
+    ///     batch_size: u64 - 每批最多索引的 slot 数量
+    /// # Return
+    ///     Option<(u64, u64)>: 闭区间 [start, end]，已追上最新 slot 时返回 None
+    pub fn next_range(&self, last_indexed: Option<u64>, tip: u64, batch_size: u64) -> Option<(u64, u64)> {
+        let start = match last_indexed {
+            Some(last_indexed) if last_indexed >= tip => return None,
+            Some(last_indexed) => last_indexed + 1,
+            None => 0,
+        };
+
+        let end = tip.min(start.saturating_add(batch_size.max(1) - 1));
+        Some((start, end))
+    }
+
+    /// # Description
+    ///     找出区间内没有出块的 slot
+    /// # Param
+    ///     start: u64 - 区间起点（包含）
+    ///     end: u64 - 区间终点（包含）
+    ///     produced: &[u64] - 节点返回的已出块 slot 列表
+    /// # Return
+    ///     Vec<u64>: 被跳过的 slot，按升序排列
+    pub fn skipped_slots(&self, start: u64, end: u64, produced: &[u64]) -> Vec<u64> {
+        (start..=end)
+            .filter(|slot| produced.binary_search(slot).is_err())
+            .collect()
+    }
+
+    /// # Description
+    ///     判断交易是否为投票交易：所有指令都调用投票程序
+    /// # Param
+    ///     transaction: &VersionedTransaction - 已解码的交易
+    /// # Return
+    ///     bool: 是否为投票交易
+    pub fn is_vote_transaction(&self, transaction: &VersionedTransaction) -> bool {
+        let account_keys = transaction.message.static_account_keys();
+        let instructions = transaction.message.instructions();
+
+        !instructions.is_empty() && instructions.iter().all(|instruction| {
+            account_keys
+                .get(usize::from(instruction.program_id_index))
+                .map(|program_id| *program_id == solana_sdk::vote::program::id())
+                .unwrap_or(false)
+        })
+    }
+
+    /// # Description
+    ///     列出交易涉及的全部账户：静态账户之后依次追加地址查找表加载的可写、只读账户，与指令中的账户索引一致
+    /// # Param
+    ///     transaction: &VersionedTransaction - 已解码的交易
+    ///     loaded_addresses: Option<&UiLoadedAddresses> - 交易元数据中的查找表加载地址
+    /// # Return
+    ///     Vec<String>: 账户地址列表
+    pub fn account_keys(&self, transaction: &VersionedTransaction, loaded_addresses: Option<&UiLoadedAddresses>) -> Vec<String> {
+        let mut accounts: Vec<String> = transaction.message.static_account_keys()
+            .iter()
+            .map(|key| key.to_string())
+            .collect();
+
+        if let Some(loaded) = loaded_addresses {
+            accounts.extend(loaded.writable.iter().cloned());
+            accounts.extend(loaded.readonly.iter().cloned());
+        }
+
+        accounts
+    }
+
+    /// # Description
+    ///     列出交易顶层指令调用的程序，按首次出现的顺序去重
+    /// # Param
+    ///     transaction: &VersionedTransaction - 已解码的交易
+    ///     accounts: &[String] - 交易涉及的全部账户
+    /// # Return
+    ///     Vec<String>: 程序地址列表
+    pub fn program_ids(&self, transaction: &VersionedTransaction, accounts: &[String]) -> Vec<String> {
+        let mut program_ids: Vec<String> = Vec::new();
+
+        for instruction in transaction.message.instructions() {
+            if let Some(program_id) = accounts.get(usize::from(instruction.program_id_index)) {
+                if !program_ids.contains(program_id) {
+                    program_ids.push(program_id.clone());
+                }
+            }
+        }
+
+        program_ids
+    }
+
+    /// # Description
+    ///     把一笔交易转换为交易索引记录
+    /// # Param
+    ///     slot: u64 - 所在区块 slot
+    ///     tx_index: usize - 区块内序号
+    ///     block_time: Option<i64> - 出块时间
+    ///     transaction: &VersionedTransaction - 已解码的交易
+    ///     meta: Option<&UiTransactionStatusMeta> - 交易元数据
+    /// # Return
+    ///     Result<IndexedTransactionActiveModel, Report>: 交易索引记录
+    pub fn build_transaction(
+        &self,
+        slot: u64,
+        tx_index: usize,
+        block_time: Option<i64>,
+        transaction: &VersionedTransaction,
+        meta: Option<&UiTransactionStatusMeta>,
+    ) -> Result<IndexedTransactionActiveModel, Report> {
+        let signature = transaction.signatures.first()
+            .ok_or_else(|| Report::msg(format!("slot {} 第 {} 笔交易缺少签名", slot, tx_index)))?
+            .to_string();
+
+        let loaded_addresses = meta.and_then(|meta| Option::<&UiLoadedAddresses>::from(meta.loaded_addresses.as_ref()));
+        let accounts = self.account_keys(transaction, loaded_addresses);
+        let program_ids = self.program_ids(transaction, &accounts);
+
+        let fee = meta.map(|meta| meta.fee).unwrap_or_default();
+        let error = meta.and_then(|meta| meta.err.as_ref()).map(|err| err.to_string());
+        let compute_units = meta.and_then(|meta| Option::<u64>::from(meta.compute_units_consumed.clone()));
+
+        Ok(IndexedTransactionModel::new(signature, slot, tx_index, block_time, fee, error, compute_units, &accounts, &program_ids))
+    }
+
+    /// # Description
+    ///     把链上区块转换为区块索引记录与非投票交易记录
+    /// # Param
+    ///     slot: u64 - 区块 slot
+    ///     block: &EncodedConfirmedBlock - 链上区块（交易需为 base64 编码）
+    /// # Return
+    ///     Result<(IndexedBlockActiveModel, Vec<IndexedTransactionActiveModel>), Report>: 区块记录与非投票交易记录
+    pub fn build_block(&self, slot: u64, block: &EncodedConfirmedBlock) -> Result<(IndexedBlockActiveModel, Vec<IndexedTransactionActiveModel>), Report> {
+        let mut transactions = Vec::new();
+        let mut vote_transaction_count = 0;
+        let mut total_fee: u64 = 0;
+
+        for (tx_index, transaction_status) in block.transactions.iter().enumerate() {
+            let transaction = transaction_status.transaction.decode()
+                .ok_or_else(|| Report::msg(format!("slot {} 第 {} 笔交易解码失败", slot, tx_index)))?;
+            let meta = transaction_status.meta.as_ref();
+            total_fee = total_fee.saturating_add(meta.map(|meta| meta.fee).unwrap_or_default());
+
+            if self.is_vote_transaction(&transaction) {
+                vote_transaction_count += 1;
+                continue;
+            }
+
+            transactions.push(self.build_transaction(slot, tx_index, block.block_time, &transaction, meta)?);
+        }
+
+        let indexed_block = IndexedBlockModel::new(
+            slot,
+            block.parent_slot,
+            block.blockhash.clone(),
+            block.previous_blockhash.clone(),
+            block.block_time,
+            block.block_height,
+            block.transactions.len(),
+            vote_transaction_count,
+            total_fee,
+        );
+
+        Ok((indexed_block, transactions))
+    }
+}
@@ -0,0 +1,260 @@
+use std::sync::Arc;
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use color_eyre::{Report, Result};
+use serde_json::{json, Map, Value};
+use solana_sdk::pubkey::Pubkey;
+use super::{DecodedPayload, InstructionDecoder};
+use crate::core::domain::instruction::entity::anchor_idl_entity::{AnchorAccountData, AnchorIdl, IdlField};
+use crate::core::domain::instruction::entity::instruction_args_entity::InstructionArgs;
+
+/// # Description
+///     Anchor 鉴别器长度
+const DISCRIMINATOR_LEN: usize = 8;
+
+/// # Description
+///     类型嵌套的最大深度，防止 IDL 中的循环类型导致无限递归
+const MAX_DEPTH: usize = 32;
+
+/// # Description
+///     基于 Anchor IDL 的解码器：指令数据为 8 字节鉴别器 + Borsh 编码的参数
+/// # Fields
+///     program: String - 程序名称，取自 IDL
+///     idl: Arc<AnchorIdl> - 程序的 IDL
+pub struct AnchorDecoder {
+    program: String,
+    idl: Arc<AnchorIdl>,
+}
+
+impl AnchorDecoder {
+    /// # Description
+    ///     创建 Anchor 解码器
+    /// # Param
+    ///     idl: Arc<AnchorIdl> - 程序的 IDL
+    /// # Return
+    ///     Self: Anchor 解码器
+    pub fn new(idl: Arc<AnchorIdl>) -> Self {
+        Self { program: idl.program_name(), idl }
+    }
+}
+
+impl InstructionDecoder for AnchorDecoder {
+    fn program(&self) -> &str {
+        &self.program
+    }
+
+    fn decode(&self, data: &[u8], _account_count: usize) -> Result<DecodedPayload, Report> {
+        let discriminator = data.get(..DISCRIMINATOR_LEN).ok_or_else(|| Report::msg("指令数据缺少鉴别器"))?;
+        let instruction = self.idl.instructions.iter()
+            .find(|instruction| self.idl.instruction_discriminator(instruction) == discriminator)
+            .ok_or_else(|| Report::msg("IDL 中不存在该指令"))?;
+
+        let mut reader = BorshReader::new(&self.idl, &data[DISCRIMINATOR_LEN..]);
+        let args = reader.read_fields(&instruction.args, 0)?;
+
+        let mut account_names = Vec::new();
+        for account in &instruction.accounts {
+            account.flatten("", &mut account_names);
+        }
+
+        Ok(DecodedPayload {
+            name: instruction.name.clone(),
+            args: InstructionArgs::Anchor(args),
+            account_names,
+        })
+    }
+}
+
+/// # Description
+///     按 IDL 解码账户数据：前 8 字节为账户鉴别器，之后为 Borsh 编码的账户结构
+/// # Param
+///     idl: &AnchorIdl - 程序的 IDL
+///     data: &[u8] - 账户数据
+/// # Return
+///     Result<AnchorAccountData, Report>: 账户类型与解码后的字段
+pub fn decode_account(idl: &AnchorIdl, data: &[u8]) -> Result<AnchorAccountData, Report> {
+    let discriminator = data.get(..DISCRIMINATOR_LEN).ok_or_else(|| Report::msg("账户数据缺少鉴别器"))?;
+    let account = idl.accounts.iter()
+        .find(|account| idl.account_discriminator(account) == discriminator)
+        .ok_or_else(|| Report::msg("IDL 中不存在该账户类型"))?;
+
+    let mut reader = BorshReader::new(idl, &data[DISCRIMINATOR_LEN..]);
+    let data = reader.read_defined(&account.name, 0)?;
+
+    Ok(AnchorAccountData { account_type: account.name.clone(), data })
+}
+
+/// # Description
+///     按 IDL 类型描述读取 Borsh 数据并转换为 JSON
+/// # Fields
+///     idl: &AnchorIdl - 用于查找自定义类型
+///     data: &[u8] - 待读取的数据
+///     offset: usize - 当前读取位置
+struct BorshReader<'a> {
+    idl: &'a AnchorIdl,
+    data: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> BorshReader<'a> {
+    fn new(idl: &'a AnchorIdl, data: &'a [u8]) -> Self {
+        Self { idl, data, offset: 0 }
+    }
+
+    /// # Description
+    ///     读取定长字节
+    fn take(&mut self, len: usize) -> Result<&'a [u8], Report> {
+        let bytes = self.data.get(self.offset..self.offset + len)
+            .ok_or_else(|| Report::msg("Borsh 数据长度不足"))?;
+        self.offset += len;
+        Ok(bytes)
+    }
+
+    fn take_array<const N: usize>(&mut self) -> Result<[u8; N], Report> {
+        let mut bytes = [0u8; N];
+        bytes.copy_from_slice(self.take(N)?);
+        Ok(bytes)
+    }
+
+    /// # Description
+    ///     读取 u32 长度前缀
+    fn read_len(&mut self) -> Result<usize, Report> {
+        Ok(u32::from_le_bytes(self.take_array()?) as usize)
+    }
+
+    /// # Description
+    ///     按字段列表读取为 JSON 对象
+    fn read_fields(&mut self, fields: &[IdlField], depth: usize) -> Result<Value, Report> {
+        let mut object = Map::new();
+        for field in fields {
+            object.insert(field.name.clone(), self.read_value(&field.ty, depth + 1)?);
+        }
+        Ok(Value::Object(object))
+    }
+
+    /// # Description
+    ///     按类型描述读取一个值
+    fn read_value(&mut self, ty: &Value, depth: usize) -> Result<Value, Report> {
+        if depth > MAX_DEPTH {
+            return Err(Report::msg("IDL 类型嵌套过深"));
+        }
+
+        if let Some(primitive) = ty.as_str() {
+            return self.read_primitive(primitive);
+        }
+
+        let object = ty.as_object().ok_or_else(|| Report::msg(format!("不支持的 IDL 类型 {}", ty)))?;
+        if let Some(inner) = object.get("vec") {
+            let len = self.read_len()?;
+            return (0..len).map(|_| self.read_value(inner, depth + 1)).collect::<Result<Vec<_>, _>>().map(Value::Array);
+        }
+        if let Some(inner) = object.get("option") {
+            return match self.take(1)?[0] {
+                0 => Ok(Value::Null),
+                _ => self.read_value(inner, depth + 1),
+            };
+        }
+        if let Some(inner) = object.get("coption") {
+            return match u32::from_le_bytes(self.take_array()?) {
+                0 => Ok(Value::Null),
+                _ => self.read_value(inner, depth + 1),
+            };
+        }
+        if let Some(array) = object.get("array").and_then(Value::as_array) {
+            let (inner, len) = match (array.first(), array.get(1).and_then(Value::as_u64)) {
+                (Some(inner), Some(len)) => (inner, len as usize),
+                _ => return Err(Report::msg("不支持的 IDL 数组长度")),
+            };
+            return (0..len).map(|_| self.read_value(inner, depth + 1)).collect::<Result<Vec<_>, _>>().map(Value::Array);
+        }
+        if let Some(defined) = object.get("defined") {
+            let name = defined.as_str()
+                .or_else(|| defined.get("name").and_then(Value::as_str))
+                .ok_or_else(|| Report::msg("不支持的 IDL 自定义类型"))?;
+            return self.read_defined(name, depth + 1);
+        }
+
+        Err(Report::msg(format!("不支持的 IDL 类型 {}", ty)))
+    }
+
+    /// # Description
+    ///     读取基础类型
+    fn read_primitive(&mut self, primitive: &str) -> Result<Value, Report> {
+        Ok(match primitive {
+            "bool" => json!(self.take(1)?[0] != 0),
+            "u8" => json!(self.take(1)?[0]),
+            "i8" => json!(self.take(1)?[0] as i8),
+            "u16" => json!(u16::from_le_bytes(self.take_array()?)),
+            "i16" => json!(i16::from_le_bytes(self.take_array()?)),
+            "u32" => json!(u32::from_le_bytes(self.take_array()?)),
+            "i32" => json!(i32::from_le_bytes(self.take_array()?)),
+            "f32" => json!(f32::from_le_bytes(self.take_array()?)),
+            "u64" => json!(u64::from_le_bytes(self.take_array()?)),
+            "i64" => json!(i64::from_le_bytes(self.take_array()?)),
+            "f64" => json!(f64::from_le_bytes(self.take_array()?)),
+            "u128" => json!(u128::from_le_bytes(self.take_array()?).to_string()),
+            "i128" => json!(i128::from_le_bytes(self.take_array()?).to_string()),
+            "publicKey" | "pubkey" => json!(Pubkey::new_from_array(self.take_array()?).to_string()),
+            "string" => {
+                let len = self.read_len()?;
+                json!(String::from_utf8_lossy(self.take(len)?))
+            }
+            "bytes" => {
+                let len = self.read_len()?;
+                json!(STANDARD.encode(self.take(len)?))
+            }
+            other => return Err(Report::msg(format!("不支持的 IDL 类型 {}", other))),
+        })
+    }
+
+    /// # Description
+    ///     读取自定义类型：struct（具名或元组字段）、enum（1 字节变体序号）或类型别名
+    fn read_defined(&mut self, name: &str, depth: usize) -> Result<Value, Report> {
+        let idl = self.idl;
+        let def = idl.type_def(name).ok_or_else(|| Report::msg(format!("IDL 中不存在类型 {}", name)))?;
+
+        match def.get("kind").and_then(Value::as_str) {
+            Some("struct") => self.read_struct_fields(def.get("fields"), depth),
+            Some("enum") => {
+                let index = usize::from(self.take(1)?[0]);
+                let variant = def.get("variants")
+                    .and_then(Value::as_array)
+                    .and_then(|variants| variants.get(index))
+                    .ok_or_else(|| Report::msg(format!("类型 {} 不存在变体 {}", name, index)))?;
+                let variant_name = variant.get("name").and_then(Value::as_str).unwrap_or_default().to_string();
+                match variant.get("fields") {
+                    None => Ok(json!(variant_name)),
+                    fields => {
+                        let mut object = Map::new();
+                        object.insert(variant_name, self.read_struct_fields(fields, depth)?);
+                        Ok(Value::Object(object))
+                    }
+                }
+            }
+            Some("type") => {
+                let alias = def.get("alias").ok_or_else(|| Report::msg(format!("类型 {} 缺少别名定义", name)))?;
+                self.read_value(alias, depth + 1)
+            }
+            _ => Err(Report::msg(format!("不支持的 IDL 类型定义 {}", name))),
+        }
+    }
+
+    /// # Description
+    ///     读取结构体字段，具名字段为对象，元组字段为数组
+    fn read_struct_fields(&mut self, fields: Option<&Value>, depth: usize) -> Result<Value, Report> {
+        let fields = fields.and_then(Value::as_array).map(Vec::as_slice).unwrap_or_default();
+        let named = fields.iter().all(|field| field.get("name").is_some() && field.get("type").is_some());
+
+        if named {
+            let fields: Vec<IdlField> = fields.iter()
+                .map(|field| serde_json::from_value(field.clone()))
+                .collect::<Result<_, _>>()?;
+            self.read_fields(&fields, depth)
+        } else {
+            fields.iter()
+                .map(|ty| self.read_value(ty, depth + 1))
+                .collect::<Result<Vec<_>, _>>()
+                .map(Value::Array)
+        }
+    }
+}
@@ -0,0 +1,30 @@
+use color_eyre::{Report, Result};
+use super::{DecodedPayload, InstructionDecoder};
+use crate::core::domain::instruction::entity::instruction_args_entity::InstructionArgs;
+
+/// # Description
+///     创建关联代币账户指令的账户名称
+const CREATE_ACCOUNTS: &[&str] = &["fundingAccount", "associatedAccount", "wallet", "mint", "systemProgram", "tokenProgram"];
+
+/// # Description
+///     关联代币账户（ATA）程序解码器，空数据为旧版的 create 指令
+pub struct AssociatedTokenDecoder;
+
+impl InstructionDecoder for AssociatedTokenDecoder {
+    fn program(&self) -> &str {
+        "spl-associated-token-account"
+    }
+
+    fn decode(&self, data: &[u8], _account_count: usize) -> Result<DecodedPayload, Report> {
+        Ok(match data.first() {
+            None | Some(0) => DecodedPayload::new("create", InstructionArgs::None, CREATE_ACCOUNTS),
+            Some(1) => DecodedPayload::new("createIdempotent", InstructionArgs::None, CREATE_ACCOUNTS),
+            Some(2) => DecodedPayload::new(
+                "recoverNested",
+                InstructionArgs::None,
+                &["nestedAccount", "nestedMint", "destinationAccount", "ownerAccount", "ownerMint", "wallet", "tokenProgram"],
+            ),
+            Some(_) => return Err(Report::msg("无法解析的关联代币账户指令")),
+        })
+    }
+}
@@ -0,0 +1,26 @@
+use color_eyre::{Report, Result};
+use super::{read_u32, read_u64, DecodedPayload, InstructionDecoder};
+use crate::core::domain::instruction::entity::instruction_args_entity::{ComputeBudgetArgs, InstructionArgs};
+
+/// # Description
+///     计算预算程序解码器，指令数据为 borsh 编码：1 字节 tag + 参数，不涉及账户
+pub struct ComputeBudgetDecoder;
+
+impl InstructionDecoder for ComputeBudgetDecoder {
+    fn program(&self) -> &str {
+        "compute-budget"
+    }
+
+    fn decode(&self, data: &[u8], _account_count: usize) -> Result<DecodedPayload, Report> {
+        let (name, args) = match data.first() {
+            Some(0) => ("requestUnits", ComputeBudgetArgs::RequestUnits { units: read_u32(data, 1)?, additional_fee: read_u32(data, 5)? }),
+            Some(1) => ("requestHeapFrame", ComputeBudgetArgs::RequestHeapFrame { bytes: read_u32(data, 1)? }),
+            Some(2) => ("setComputeUnitLimit", ComputeBudgetArgs::SetComputeUnitLimit { units: read_u32(data, 1)? }),
+            Some(3) => ("setComputeUnitPrice", ComputeBudgetArgs::SetComputeUnitPrice { micro_lamports: read_u64(data, 1)? }),
+            Some(4) => ("setLoadedAccountsDataSizeLimit", ComputeBudgetArgs::SetLoadedAccountsDataSizeLimit { bytes: read_u32(data, 1)? }),
+            _ => return Err(Report::msg("无法解析的计算预算指令")),
+        };
+
+        Ok(DecodedPayload::new(name, InstructionArgs::ComputeBudget(args), &[]))
+    }
+}
@@ -0,0 +1,19 @@
+use color_eyre::{Report, Result};
+use super::{DecodedPayload, InstructionDecoder};
+use crate::core::domain::instruction::entity::instruction_args_entity::InstructionArgs;
+
+/// # Description
+///     Memo 程序解码器，指令数据即 UTF-8 备注，涉及的账户均为签名者
+pub struct MemoDecoder;
+
+impl InstructionDecoder for MemoDecoder {
+    fn program(&self) -> &str {
+        "spl-memo"
+    }
+
+    fn decode(&self, data: &[u8], account_count: usize) -> Result<DecodedPayload, Report> {
+        let memo = String::from_utf8_lossy(data).to_string();
+        let signers = vec!["signer"; account_count];
+        Ok(DecodedPayload::new("memo", InstructionArgs::Memo { memo }, &signers))
+    }
+}
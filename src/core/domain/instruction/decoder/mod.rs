@@ -0,0 +1,144 @@
+pub mod system_decoder;
+pub mod token_decoder;
+pub mod associated_token_decoder;
+pub mod memo_decoder;
+pub mod compute_budget_decoder;
+pub mod stake_decoder;
+pub mod vote_decoder;
+pub mod token_swap_decoder;
+pub mod raydium_amm_decoder;
+pub mod anchor_decoder;
+
+use color_eyre::{Report, Result};
+use super::entity::instruction_args_entity::InstructionArgs;
+
+/// # Description
+///     系统程序
+pub const SYSTEM_PROGRAM_ID: &str = "11111111111111111111111111111111";
+/// # Description
+///     SPL Token 程序
+pub const TOKEN_PROGRAM_ID: &str = "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA";
+/// # Description
+///     Token-2022 程序
+pub const TOKEN_2022_PROGRAM_ID: &str = "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb";
+/// # Description
+///     关联代币账户（ATA）程序
+pub const ATA_PROGRAM_ID: &str = "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL";
+/// # Description
+///     Memo 程序 v2
+pub const MEMO_PROGRAM_ID: &str = "MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr";
+/// # Description
+///     Memo 程序 v1
+pub const MEMO_V1_PROGRAM_ID: &str = "Memo1UhkJRfHyvLMcVucJwxXeuD728EqVDDwQDxFMNo";
+/// # Description
+///     计算预算程序
+pub const COMPUTE_BUDGET_PROGRAM_ID: &str = "ComputeBudget111111111111111111111111111111";
+/// # Description
+///     质押程序
+pub const STAKE_PROGRAM_ID: &str = "Stake11111111111111111111111111111111111111";
+/// # Description
+///     投票程序
+pub const VOTE_PROGRAM_ID: &str = "Vote111111111111111111111111111111111111111";
+/// # Description
+///     spl-token-swap 程序
+pub const TOKEN_SWAP_PROGRAM_ID: &str = "SwapsVeCiPHMUAtzQWZw7RjsKjgCjhwU55QGu4U1Szw";
+/// # Description
+///     Raydium AMM v4 程序
+pub const RAYDIUM_AMM_PROGRAM_ID: &str = "675kPX9MHTjS2zt1qfr1NYHuzeLXfQM9H24wFSUt1Mp8";
+
+/// # Description
+///     解码器的输出
+/// # Fields
+///     name: String - 指令名称（小驼峰）
+///     args: InstructionArgs - 指令参数
+///     account_names: Vec<String> - 按指令顺序排列的账户名称
+#[derive(Debug, Clone, PartialEq)]
+pub struct DecodedPayload {
+    pub name: String,
+    pub args: InstructionArgs,
+    pub account_names: Vec<String>,
+}
+
+impl DecodedPayload {
+    /// # Description
+    ///     创建解码输出
+    /// # Params
+    ///     name: &str - 指令名称
+    ///     args: InstructionArgs - 指令参数
+    ///     account_names: &[&str] - 账户名称
+    /// # Return
+    ///     Self: 解码输出
+    pub fn new(name: &str, args: InstructionArgs, account_names: &[&str]) -> Self {
+        Self {
+            name: name.to_string(),
+            args,
+            account_names: account_names.iter().map(|name| name.to_string()).collect(),
+        }
+    }
+}
+
+/// # Description
+///     单个程序的指令解码器。解码器按程序地址注册到 InstructionDomainService，
+///     同一个解码器可以注册到多个程序地址（如 SPL Token 与 Token-2022）
+pub trait InstructionDecoder: Send + Sync {
+    /// # Description
+    ///     程序名称
+    fn program(&self) -> &str;
+
+    /// # Description
+    ///     解码指令数据
+    /// # Param
+    ///     data: &[u8] - 指令数据
+    ///     account_count: usize - 指令涉及的账户数量，部分程序按账户数量区分布局
+    /// # Return
+    ///     Result<DecodedPayload, Report>: 指令名称、参数与账户名称
+    fn decode(&self, data: &[u8], account_count: usize) -> Result<DecodedPayload, Report>;
+}
+
+/// # Description
+///     读取小端 u32
+pub(crate) fn read_u32(data: &[u8], offset: usize) -> Result<u32, Report> {
+    data.get(offset..offset + 4)
+        .and_then(|bytes| bytes.try_into().ok())
+        .map(u32::from_le_bytes)
+        .ok_or_else(|| Report::msg("指令数据长度不足"))
+}
+
+/// # Description
+///     读取小端 u64
+pub(crate) fn read_u64(data: &[u8], offset: usize) -> Result<u64, Report> {
+    data.get(offset..offset + 8)
+        .and_then(|bytes| bytes.try_into().ok())
+        .map(u64::from_le_bytes)
+        .ok_or_else(|| Report::msg("指令数据长度不足"))
+}
+
+/// # Description
+///     读取小端 i64
+pub(crate) fn read_i64(data: &[u8], offset: usize) -> Result<i64, Report> {
+    data.get(offset..offset + 8)
+        .and_then(|bytes| bytes.try_into().ok())
+        .map(i64::from_le_bytes)
+        .ok_or_else(|| Report::msg("指令数据长度不足"))
+}
+
+/// # Description
+///     读取 32 字节公钥并转为 base58 字符串
+pub(crate) fn read_pubkey(data: &[u8], offset: usize) -> Result<String, Report> {
+    data.get(offset..offset + 32)
+        .and_then(|bytes| solana_sdk::pubkey::Pubkey::try_from(bytes).ok())
+        .map(|pubkey| pubkey.to_string())
+        .ok_or_else(|| Report::msg("指令数据长度不足"))
+}
+
+/// # Description
+///     取枚举 Debug 输出中的变体名，并转换为小驼峰作为指令名称
+pub(crate) fn variant_name<T: std::fmt::Debug>(value: &T) -> String {
+    let debug = format!("{:?}", value);
+    let name = debug.split(|c: char| !c.is_alphanumeric()).next().unwrap_or_default();
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(first) => first.to_lowercase().chain(chars).collect(),
+        None => "unknown".to_string(),
+    }
+}
@@ -0,0 +1,109 @@
+use color_eyre::{Report, Result};
+use super::{read_u64, DecodedPayload, InstructionDecoder};
+use crate::core::domain::instruction::entity::instruction_args_entity::{InstructionArgs, RaydiumAmmArgs};
+
+/// # Description
+///     兑换指令的账户名称（18 个账户，包含 ammTargetOrders）。
+///     新版客户端省略 ammTargetOrders，只传 17 个账户
+const SWAP_ACCOUNTS: &[&str] = &[
+    "tokenProgram", "amm", "ammAuthority", "ammOpenOrders", "ammTargetOrders", "poolCoinTokenAccount",
+    "poolPcTokenAccount", "serumProgram", "serumMarket", "serumBids", "serumAsks", "serumEventQueue",
+    "serumCoinVaultAccount", "serumPcVaultAccount", "serumVaultSigner", "userSourceTokenAccount",
+    "userDestinationTokenAccount", "userSourceOwner",
+];
+
+/// # Description
+///     Raydium AMM v4 指令 tag 与名称，未展开参数的指令只识别名称
+const INSTRUCTION_NAMES: &[(u8, &str)] = &[
+    (0, "initialize"),
+    (2, "monitorStep"),
+    (5, "migrateToOpenBook"),
+    (6, "setParams"),
+    (7, "withdrawPnl"),
+    (8, "withdrawSrm"),
+    (10, "preInitialize"),
+    (12, "simulateInfo"),
+    (13, "adminCancelOrders"),
+    (14, "createConfigAccount"),
+    (15, "updateConfigAccount"),
+];
+
+/// # Description
+///     Raydium AMM v4 解码器，指令数据为 1 字节 tag + 小端参数
+pub struct RaydiumAmmDecoder;
+
+/// # Description
+///     按账户数量返回兑换指令的账户名称
+fn swap_accounts(account_count: usize) -> Vec<&'static str> {
+    if account_count == SWAP_ACCOUNTS.len() - 1 {
+        SWAP_ACCOUNTS.iter().copied().filter(|name| *name != "ammTargetOrders").collect()
+    } else {
+        SWAP_ACCOUNTS.to_vec()
+    }
+}
+
+impl InstructionDecoder for RaydiumAmmDecoder {
+    fn program(&self) -> &str {
+        "raydium-amm"
+    }
+
+    fn decode(&self, data: &[u8], account_count: usize) -> Result<DecodedPayload, Report> {
+        let amm = |args: RaydiumAmmArgs| InstructionArgs::RaydiumAmm(args);
+        let tag = *data.first().ok_or_else(|| Report::msg("无法解析的 Raydium AMM 指令"))?;
+
+        Ok(match tag {
+            1 => DecodedPayload::new(
+                "initialize2",
+                amm(RaydiumAmmArgs::Initialize2 {
+                    nonce: *data.get(1).ok_or_else(|| Report::msg("指令数据长度不足"))?,
+                    open_time: read_u64(data, 2)?,
+                    init_pc_amount: read_u64(data, 10)?,
+                    init_coin_amount: read_u64(data, 18)?,
+                }),
+                &[
+                    "tokenProgram", "associatedTokenProgram", "systemProgram", "rentSysvar", "amm", "ammAuthority",
+                    "ammOpenOrders", "lpMint", "coinMint", "pcMint", "poolCoinTokenAccount", "poolPcTokenAccount",
+                    "ammTargetOrders", "ammConfig", "createFeeDestination", "marketProgram", "market", "userWallet",
+                    "userTokenCoin", "userTokenPc", "userLpTokenAccount",
+                ],
+            ),
+            3 => DecodedPayload::new(
+                "deposit",
+                amm(RaydiumAmmArgs::Deposit { max_coin_amount: read_u64(data, 1)?, max_pc_amount: read_u64(data, 9)?, base_side: read_u64(data, 17)? }),
+                &[
+                    "tokenProgram", "amm", "ammAuthority", "ammOpenOrders", "ammTargetOrders", "lpMint",
+                    "poolCoinTokenAccount", "poolPcTokenAccount", "serumMarket", "userCoinTokenAccount",
+                    "userPcTokenAccount", "userLpTokenAccount", "userOwner", "serumEventQueue",
+                ],
+            ),
+            4 => DecodedPayload::new(
+                "withdraw",
+                amm(RaydiumAmmArgs::Withdraw { amount: read_u64(data, 1)? }),
+                &[
+                    "tokenProgram", "amm", "ammAuthority", "ammOpenOrders", "ammTargetOrders", "lpMint",
+                    "poolCoinTokenAccount", "poolPcTokenAccount", "poolWithdrawQueue", "poolTempLpTokenAccount",
+                    "serumProgram", "serumMarket", "serumCoinVaultAccount", "serumPcVaultAccount", "serumVaultSigner",
+                    "userLpTokenAccount", "userCoinTokenAccount", "userPcTokenAccount", "userOwner", "serumEventQueue",
+                    "serumBids", "serumAsks",
+                ],
+            ),
+            9 => DecodedPayload::new(
+                "swapBaseIn",
+                amm(RaydiumAmmArgs::SwapBaseIn { amount_in: read_u64(data, 1)?, minimum_amount_out: read_u64(data, 9)? }),
+                &swap_accounts(account_count),
+            ),
+            11 => DecodedPayload::new(
+                "swapBaseOut",
+                amm(RaydiumAmmArgs::SwapBaseOut { max_amount_in: read_u64(data, 1)?, amount_out: read_u64(data, 9)? }),
+                &swap_accounts(account_count),
+            ),
+            _ => {
+                let name = INSTRUCTION_NAMES.iter()
+                    .find(|(t, _)| *t == tag)
+                    .map(|(_, name)| *name)
+                    .ok_or_else(|| Report::msg("无法解析的 Raydium AMM 指令"))?;
+                DecodedPayload::new(name, InstructionArgs::None, &[])
+            }
+        })
+    }
+}
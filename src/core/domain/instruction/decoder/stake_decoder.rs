@@ -0,0 +1,97 @@
+use color_eyre::{Report, Result};
+use super::{read_i64, read_pubkey, read_u32, read_u64, DecodedPayload, InstructionDecoder};
+use crate::core::domain::instruction::entity::instruction_args_entity::{InstructionArgs, StakeArgs};
+
+/// # Description
+///     质押程序解码器。指令数据为 bincode 编码的 StakeInstruction：4 字节小端 tag + 参数，
+///     直接按布局读取，避免依赖各版本 SDK 中 StakeInstruction 的变体差异
+pub struct StakeDecoder;
+
+/// # Description
+///     StakeAuthorize 枚举值对应的权限名称
+fn authority_type(value: u32) -> String {
+    match value {
+        0 => "staker",
+        1 => "withdrawer",
+        _ => "unknown",
+    }.to_string()
+}
+
+impl InstructionDecoder for StakeDecoder {
+    fn program(&self) -> &str {
+        "stake"
+    }
+
+    fn decode(&self, data: &[u8], _account_count: usize) -> Result<DecodedPayload, Report> {
+        let stake = |args: StakeArgs| InstructionArgs::Stake(args);
+        let lamports = || -> Result<InstructionArgs, Report> { Ok(stake(StakeArgs::Lamports { lamports: read_u64(data, 4)? })) };
+
+        Ok(match read_u32(data, 0)? {
+            0 => DecodedPayload::new(
+                "initialize",
+                stake(StakeArgs::Initialize {
+                    staker: read_pubkey(data, 4)?,
+                    withdrawer: read_pubkey(data, 36)?,
+                    lockup_unix_timestamp: read_i64(data, 68)?,
+                    lockup_epoch: read_u64(data, 76)?,
+                    custodian: read_pubkey(data, 84)?,
+                }),
+                &["stakeAccount", "rentSysvar"],
+            ),
+            1 => DecodedPayload::new(
+                "authorize",
+                stake(StakeArgs::Authorize { new_authority: read_pubkey(data, 4)?, authority_type: authority_type(read_u32(data, 36)?) }),
+                &["stakeAccount", "clockSysvar", "authority", "lockupAuthority"],
+            ),
+            2 => DecodedPayload::new(
+                "delegateStake",
+                InstructionArgs::None,
+                &["stakeAccount", "voteAccount", "clockSysvar", "stakeHistorySysvar", "stakeConfig", "stakeAuthority"],
+            ),
+            3 => DecodedPayload::new("split", lamports()?, &["stakeAccount", "newSplitAccount", "stakeAuthority"]),
+            4 => DecodedPayload::new(
+                "withdraw",
+                lamports()?,
+                &["stakeAccount", "destination", "clockSysvar", "stakeHistorySysvar", "withdrawAuthority", "custodian"],
+            ),
+            5 => DecodedPayload::new("deactivate", InstructionArgs::None, &["stakeAccount", "clockSysvar", "stakeAuthority"]),
+            6 => DecodedPayload::new("setLockup", InstructionArgs::None, &["stakeAccount", "lockupAuthority"]),
+            7 => DecodedPayload::new(
+                "merge",
+                InstructionArgs::None,
+                &["destination", "source", "clockSysvar", "stakeHistorySysvar", "stakeAuthority"],
+            ),
+            8 => DecodedPayload::new(
+                "authorizeWithSeed",
+                InstructionArgs::None,
+                &["stakeAccount", "authorityBase", "clockSysvar", "lockupAuthority"],
+            ),
+            9 => DecodedPayload::new("initializeChecked", InstructionArgs::None, &["stakeAccount", "rentSysvar", "staker", "withdrawer"]),
+            10 => DecodedPayload::new(
+                "authorizeChecked",
+                stake(StakeArgs::AuthorizeChecked { authority_type: authority_type(read_u32(data, 4)?) }),
+                &["stakeAccount", "clockSysvar", "authority", "newAuthority", "lockupAuthority"],
+            ),
+            11 => DecodedPayload::new(
+                "authorizeCheckedWithSeed",
+                InstructionArgs::None,
+                &["stakeAccount", "authorityBase", "clockSysvar", "newAuthority", "lockupAuthority"],
+            ),
+            12 => DecodedPayload::new("setLockupChecked", InstructionArgs::None, &["stakeAccount", "lockupAuthority", "newLockupAuthority"]),
+            13 => DecodedPayload::new("getMinimumDelegation", InstructionArgs::None, &[]),
+            14 => DecodedPayload::new(
+                "deactivateDelinquent",
+                InstructionArgs::None,
+                &["stakeAccount", "delinquentVoteAccount", "referenceVoteAccount"],
+            ),
+            15 => DecodedPayload::new(
+                "redelegate",
+                InstructionArgs::None,
+                &["stakeAccount", "uninitializedStakeAccount", "voteAccount", "stakeConfig", "stakeAuthority"],
+            ),
+            16 => DecodedPayload::new("moveStake", lamports()?, &["source", "destination", "stakeAuthority"]),
+            17 => DecodedPayload::new("moveLamports", lamports()?, &["source", "destination", "stakeAuthority"]),
+            _ => return Err(Report::msg("无法解析的质押程序指令")),
+        })
+    }
+}
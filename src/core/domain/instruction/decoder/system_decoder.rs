@@ -0,0 +1,88 @@
+use color_eyre::{Report, Result};
+use solana_sdk::system_instruction::SystemInstruction;
+use super::{DecodedPayload, InstructionDecoder};
+use crate::core::domain::instruction::entity::instruction_args_entity::{InstructionArgs, SystemArgs};
+
+/// # Description
+///     系统程序解码器，指令数据为 bincode 编码的 SystemInstruction
+pub struct SystemDecoder;
+
+impl InstructionDecoder for SystemDecoder {
+    fn program(&self) -> &str {
+        "system"
+    }
+
+    fn decode(&self, data: &[u8], _account_count: usize) -> Result<DecodedPayload, Report> {
+        let instruction = bincode::deserialize::<SystemInstruction>(data)
+            .map_err(|_| Report::msg("无法解析的系统程序指令"))?;
+        let system = |args: SystemArgs| InstructionArgs::System(args);
+
+        Ok(match instruction {
+            SystemInstruction::CreateAccount { lamports, space, owner } => DecodedPayload::new(
+                "createAccount",
+                system(SystemArgs::CreateAccount { lamports, space, owner: owner.to_string() }),
+                &["fundingAccount", "newAccount"],
+            ),
+            SystemInstruction::Assign { owner } => DecodedPayload::new(
+                "assign",
+                system(SystemArgs::Assign { owner: owner.to_string() }),
+                &["account"],
+            ),
+            SystemInstruction::Transfer { lamports } => DecodedPayload::new(
+                "transfer",
+                system(SystemArgs::Transfer { lamports }),
+                &["source", "destination"],
+            ),
+            SystemInstruction::CreateAccountWithSeed { base, seed, lamports, space, owner } => DecodedPayload::new(
+                "createAccountWithSeed",
+                system(SystemArgs::CreateAccountWithSeed { base: base.to_string(), seed, lamports, space, owner: owner.to_string() }),
+                &["fundingAccount", "createdAccount", "baseAccount"],
+            ),
+            SystemInstruction::AdvanceNonceAccount => DecodedPayload::new(
+                "advanceNonceAccount",
+                InstructionArgs::None,
+                &["nonceAccount", "recentBlockhashesSysvar", "nonceAuthority"],
+            ),
+            SystemInstruction::WithdrawNonceAccount(lamports) => DecodedPayload::new(
+                "withdrawNonceAccount",
+                system(SystemArgs::WithdrawNonceAccount { lamports }),
+                &["nonceAccount", "recipient", "recentBlockhashesSysvar", "rentSysvar", "nonceAuthority"],
+            ),
+            SystemInstruction::InitializeNonceAccount(authority) => DecodedPayload::new(
+                "initializeNonceAccount",
+                system(SystemArgs::NonceAuthority { authority: authority.to_string() }),
+                &["nonceAccount", "recentBlockhashesSysvar", "rentSysvar"],
+            ),
+            SystemInstruction::AuthorizeNonceAccount(authority) => DecodedPayload::new(
+                "authorizeNonceAccount",
+                system(SystemArgs::NonceAuthority { authority: authority.to_string() }),
+                &["nonceAccount", "nonceAuthority"],
+            ),
+            SystemInstruction::Allocate { space } => DecodedPayload::new(
+                "allocate",
+                system(SystemArgs::Allocate { space }),
+                &["account"],
+            ),
+            SystemInstruction::AllocateWithSeed { base, seed, space, owner } => DecodedPayload::new(
+                "allocateWithSeed",
+                system(SystemArgs::AllocateWithSeed { base: base.to_string(), seed, space, owner: owner.to_string() }),
+                &["account", "baseAccount"],
+            ),
+            SystemInstruction::AssignWithSeed { base, seed, owner } => DecodedPayload::new(
+                "assignWithSeed",
+                system(SystemArgs::AssignWithSeed { base: base.to_string(), seed, owner: owner.to_string() }),
+                &["account", "baseAccount"],
+            ),
+            SystemInstruction::TransferWithSeed { lamports, from_seed, from_owner } => DecodedPayload::new(
+                "transferWithSeed",
+                system(SystemArgs::TransferWithSeed { lamports, from_seed, from_owner: from_owner.to_string() }),
+                &["source", "baseAccount", "destination"],
+            ),
+            SystemInstruction::UpgradeNonceAccount => DecodedPayload::new(
+                "upgradeNonceAccount",
+                InstructionArgs::None,
+                &["nonceAccount"],
+            ),
+        })
+    }
+}
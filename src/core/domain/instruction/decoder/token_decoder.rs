@@ -0,0 +1,136 @@
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use color_eyre::{Report, Result};
+use solana_sdk::pubkey::Pubkey;
+use spl_token::instruction::TokenInstruction;
+use super::{variant_name, DecodedPayload, InstructionDecoder};
+use crate::core::domain::instruction::entity::instruction_args_entity::{InstructionArgs, TokenArgs};
+
+/// # Description
+///     Token-2022 在 SPL Token 指令之后新增的指令，按 tag 排列：(tag, 指令名称, 账户名称)。
+///     扩展类指令的第二个字节为子指令，参数保留原始数据
+const TOKEN_2022_INSTRUCTIONS: &[(u8, &str, &[&str])] = &[
+    (25, "initializeMintCloseAuthority", &["mint"]),
+    (26, "transferFeeExtension", &[]),
+    (27, "confidentialTransferExtension", &[]),
+    (28, "defaultAccountStateExtension", &[]),
+    (29, "reallocate", &["account", "payer", "systemProgram", "owner"]),
+    (30, "memoTransferExtension", &[]),
+    (31, "createNativeMint", &["payer", "nativeMint", "systemProgram"]),
+    (32, "initializeNonTransferableMint", &["mint"]),
+    (33, "interestBearingMintExtension", &[]),
+    (34, "cpiGuardExtension", &[]),
+    (35, "initializePermanentDelegate", &["mint"]),
+    (36, "transferHookExtension", &[]),
+    (37, "confidentialTransferFeeExtension", &[]),
+    (38, "withdrawExcessLamports", &["source", "destination", "authority"]),
+    (39, "metadataPointerExtension", &[]),
+    (40, "groupPointerExtension", &[]),
+    (41, "groupMemberPointerExtension", &[]),
+];
+
+/// # Description
+///     SPL Token / Token-2022 解码器。两个程序共享前 25 条指令的布局，
+///     Token-2022 的扩展指令只识别名称
+/// # Fields
+///     program: &'static str - 程序名称
+///     extensions: bool - 是否识别 Token-2022 扩展指令
+pub struct TokenDecoder {
+    program: &'static str,
+    extensions: bool,
+}
+
+impl TokenDecoder {
+    /// # Description
+    ///     SPL Token 程序解码器
+    pub fn token() -> Self {
+        Self { program: "spl-token", extensions: false }
+    }
+
+    /// # Description
+    ///     Token-2022 程序解码器
+    pub fn token_2022() -> Self {
+        Self { program: "spl-token-2022", extensions: true }
+    }
+}
+
+impl InstructionDecoder for TokenDecoder {
+    fn program(&self) -> &str {
+        self.program
+    }
+
+    fn decode(&self, data: &[u8], _account_count: usize) -> Result<DecodedPayload, Report> {
+        if self.extensions {
+            if let Some((_, name, accounts)) = data.first().and_then(|tag| TOKEN_2022_INSTRUCTIONS.iter().find(|(t, _, _)| t == tag)) {
+                let args = TokenArgs::Extension { extension: name.to_string(), data: STANDARD.encode(&data[1..]) };
+                return Ok(DecodedPayload::new(name, InstructionArgs::Token(args), accounts));
+            }
+        }
+
+        let instruction = TokenInstruction::unpack(data)
+            .map_err(|_| Report::msg("无法解析的代币程序指令"))?;
+        let name = variant_name(&instruction);
+        let token = |args: TokenArgs| InstructionArgs::Token(args);
+        let amount = |amount: u64| InstructionArgs::Token(TokenArgs::Amount { amount });
+        let checked = |amount: u64, decimals: u8| InstructionArgs::Token(TokenArgs::AmountChecked { amount, decimals });
+
+        let (args, accounts): (InstructionArgs, &[&str]) = match instruction {
+            TokenInstruction::InitializeMint { decimals, mint_authority, freeze_authority } => (
+                token(TokenArgs::InitializeMint {
+                    decimals,
+                    mint_authority: mint_authority.to_string(),
+                    freeze_authority: Option::<Pubkey>::from(freeze_authority).map(|key| key.to_string()),
+                }),
+                &["mint", "rentSysvar"],
+            ),
+            TokenInstruction::InitializeMint2 { decimals, mint_authority, freeze_authority } => (
+                token(TokenArgs::InitializeMint {
+                    decimals,
+                    mint_authority: mint_authority.to_string(),
+                    freeze_authority: Option::<Pubkey>::from(freeze_authority).map(|key| key.to_string()),
+                }),
+                &["mint"],
+            ),
+            TokenInstruction::InitializeAccount => (InstructionArgs::None, &["account", "mint", "owner", "rentSysvar"]),
+            TokenInstruction::InitializeAccount2 { owner } => (
+                token(TokenArgs::InitializeAccount { owner: owner.to_string() }),
+                &["account", "mint", "rentSysvar"],
+            ),
+            TokenInstruction::InitializeAccount3 { owner } => (
+                token(TokenArgs::InitializeAccount { owner: owner.to_string() }),
+                &["account", "mint"],
+            ),
+            TokenInstruction::InitializeMultisig { m } => (token(TokenArgs::InitializeMultisig { m }), &["multisig", "rentSysvar"]),
+            TokenInstruction::InitializeMultisig2 { m } => (token(TokenArgs::InitializeMultisig { m }), &["multisig"]),
+            TokenInstruction::Transfer { amount: value } => (amount(value), &["source", "destination", "authority"]),
+            TokenInstruction::Approve { amount: value } => (amount(value), &["source", "delegate", "owner"]),
+            TokenInstruction::Revoke => (InstructionArgs::None, &["source", "owner"]),
+            TokenInstruction::SetAuthority { authority_type, new_authority } => (
+                token(TokenArgs::SetAuthority {
+                    authority_type: variant_name(&authority_type),
+                    new_authority: Option::<Pubkey>::from(new_authority).map(|key| key.to_string()),
+                }),
+                &["account", "currentAuthority"],
+            ),
+            TokenInstruction::MintTo { amount: value } => (amount(value), &["mint", "account", "mintAuthority"]),
+            TokenInstruction::Burn { amount: value } => (amount(value), &["account", "mint", "authority"]),
+            TokenInstruction::CloseAccount => (InstructionArgs::None, &["account", "destination", "owner"]),
+            TokenInstruction::FreezeAccount => (InstructionArgs::None, &["account", "mint", "freezeAuthority"]),
+            TokenInstruction::ThawAccount => (InstructionArgs::None, &["account", "mint", "freezeAuthority"]),
+            TokenInstruction::TransferChecked { amount, decimals } => (checked(amount, decimals), &["source", "mint", "destination", "authority"]),
+            TokenInstruction::ApproveChecked { amount, decimals } => (checked(amount, decimals), &["source", "mint", "delegate", "owner"]),
+            TokenInstruction::MintToChecked { amount, decimals } => (checked(amount, decimals), &["mint", "account", "mintAuthority"]),
+            TokenInstruction::BurnChecked { amount, decimals } => (checked(amount, decimals), &["account", "mint", "authority"]),
+            TokenInstruction::SyncNative => (InstructionArgs::None, &["account"]),
+            TokenInstruction::GetAccountDataSize => (InstructionArgs::None, &["mint"]),
+            TokenInstruction::InitializeImmutableOwner => (InstructionArgs::None, &["account"]),
+            TokenInstruction::AmountToUiAmount { amount: value } => (amount(value), &["mint"]),
+            TokenInstruction::UiAmountToAmount { ui_amount } => (
+                token(TokenArgs::UiAmountToAmount { ui_amount: ui_amount.to_string() }),
+                &["mint"],
+            ),
+        };
+
+        Ok(DecodedPayload::new(&name, args, accounts))
+    }
+}
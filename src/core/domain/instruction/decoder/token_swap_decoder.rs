@@ -0,0 +1,84 @@
+use color_eyre::{Report, Result};
+use spl_token_swap::instruction::SwapInstruction;
+use super::{DecodedPayload, InstructionDecoder};
+use crate::core::domain::instruction::entity::instruction_args_entity::{InstructionArgs, TokenSwapArgs};
+
+/// # Description
+///     spl-token-swap 程序解码器，Initialize 的费率与曲线参数不展开
+pub struct TokenSwapDecoder;
+
+impl InstructionDecoder for TokenSwapDecoder {
+    fn program(&self) -> &str {
+        "spl-token-swap"
+    }
+
+    fn decode(&self, data: &[u8], _account_count: usize) -> Result<DecodedPayload, Report> {
+        let instruction = SwapInstruction::unpack(data)
+            .map_err(|_| Report::msg("无法解析的 spl-token-swap 指令"))?;
+        let swap = |args: TokenSwapArgs| InstructionArgs::TokenSwap(args);
+
+        Ok(match instruction {
+            SwapInstruction::Initialize(_) => DecodedPayload::new(
+                "initialize",
+                InstructionArgs::None,
+                &["swap", "swapAuthority", "tokenA", "tokenB", "poolMint", "feeAccount", "destination", "tokenProgram"],
+            ),
+            SwapInstruction::Swap(args) => DecodedPayload::new(
+                "swap",
+                swap(TokenSwapArgs::Swap { amount_in: args.amount_in, minimum_amount_out: args.minimum_amount_out }),
+                &[
+                    "swap", "swapAuthority", "userTransferAuthority", "source", "swapSource", "swapDestination", "destination",
+                    "poolMint", "poolFeeAccount", "sourceMint", "destinationMint", "sourceTokenProgram", "destinationTokenProgram",
+                    "poolTokenProgram", "hostFeeAccount",
+                ],
+            ),
+            SwapInstruction::DepositAllTokenTypes(args) => DecodedPayload::new(
+                "depositAllTokenTypes",
+                swap(TokenSwapArgs::DepositAllTokenTypes {
+                    pool_token_amount: args.pool_token_amount,
+                    maximum_token_a_amount: args.maximum_token_a_amount,
+                    maximum_token_b_amount: args.maximum_token_b_amount,
+                }),
+                &[
+                    "swap", "swapAuthority", "userTransferAuthority", "sourceA", "sourceB", "tokenA", "tokenB", "poolMint",
+                    "destination", "tokenAMint", "tokenBMint", "tokenATokenProgram", "tokenBTokenProgram", "poolTokenProgram",
+                ],
+            ),
+            SwapInstruction::WithdrawAllTokenTypes(args) => DecodedPayload::new(
+                "withdrawAllTokenTypes",
+                swap(TokenSwapArgs::WithdrawAllTokenTypes {
+                    pool_token_amount: args.pool_token_amount,
+                    minimum_token_a_amount: args.minimum_token_a_amount,
+                    minimum_token_b_amount: args.minimum_token_b_amount,
+                }),
+                &[
+                    "swap", "swapAuthority", "userTransferAuthority", "poolMint", "source", "tokenA", "tokenB", "destinationA",
+                    "destinationB", "feeAccount", "tokenAMint", "tokenBMint", "poolTokenProgram", "tokenATokenProgram",
+                    "tokenBTokenProgram",
+                ],
+            ),
+            SwapInstruction::DepositSingleTokenTypeExactAmountIn(args) => DecodedPayload::new(
+                "depositSingleTokenTypeExactAmountIn",
+                swap(TokenSwapArgs::DepositSingleTokenTypeExactAmountIn {
+                    source_token_amount: args.source_token_amount,
+                    minimum_pool_token_amount: args.minimum_pool_token_amount,
+                }),
+                &[
+                    "swap", "swapAuthority", "userTransferAuthority", "source", "tokenA", "tokenB", "poolMint", "destination",
+                    "sourceMint", "sourceTokenProgram", "poolTokenProgram",
+                ],
+            ),
+            SwapInstruction::WithdrawSingleTokenTypeExactAmountOut(args) => DecodedPayload::new(
+                "withdrawSingleTokenTypeExactAmountOut",
+                swap(TokenSwapArgs::WithdrawSingleTokenTypeExactAmountOut {
+                    destination_token_amount: args.destination_token_amount,
+                    maximum_pool_token_amount: args.maximum_pool_token_amount,
+                }),
+                &[
+                    "swap", "swapAuthority", "userTransferAuthority", "poolMint", "source", "tokenA", "tokenB", "destination",
+                    "feeAccount", "destinationMint", "poolTokenProgram", "destinationTokenProgram",
+                ],
+            ),
+        })
+    }
+}
@@ -0,0 +1,85 @@
+use color_eyre::{Report, Result};
+use super::{read_pubkey, read_u32, read_u64, DecodedPayload, InstructionDecoder};
+use crate::core::domain::instruction::entity::instruction_args_entity::{InstructionArgs, VoteArgs};
+
+/// # Description
+///     投票类指令的账户名称
+const VOTE_ACCOUNTS: &[&str] = &["voteAccount", "slotHashesSysvar", "clockSysvar", "voteAuthority"];
+
+/// # Description
+///     更新投票状态类指令的账户名称
+const VOTE_STATE_ACCOUNTS: &[&str] = &["voteAccount", "voteAuthority"];
+
+/// # Description
+///     投票程序解码器。指令数据为 bincode 编码的 VoteInstruction：4 字节小端 tag + 参数，
+///     投票内容本身（slots、tower 等）不展开
+pub struct VoteDecoder;
+
+/// # Description
+///     VoteAuthorize 枚举值对应的权限名称
+fn authority_type(value: u32) -> String {
+    match value {
+        0 => "voter",
+        1 => "withdrawer",
+        _ => "unknown",
+    }.to_string()
+}
+
+impl InstructionDecoder for VoteDecoder {
+    fn program(&self) -> &str {
+        "vote"
+    }
+
+    fn decode(&self, data: &[u8], _account_count: usize) -> Result<DecodedPayload, Report> {
+        let vote = |args: VoteArgs| InstructionArgs::Vote(args);
+
+        Ok(match read_u32(data, 0)? {
+            0 => DecodedPayload::new(
+                "initializeAccount",
+                vote(VoteArgs::InitializeAccount {
+                    node: read_pubkey(data, 4)?,
+                    authorized_voter: read_pubkey(data, 36)?,
+                    authorized_withdrawer: read_pubkey(data, 68)?,
+                    commission: *data.get(100).ok_or_else(|| Report::msg("指令数据长度不足"))?,
+                }),
+                &["voteAccount", "rentSysvar", "clockSysvar", "node"],
+            ),
+            1 => DecodedPayload::new(
+                "authorize",
+                vote(VoteArgs::Authorize { new_authority: read_pubkey(data, 4)?, authority_type: authority_type(read_u32(data, 36)?) }),
+                &["voteAccount", "clockSysvar", "authority"],
+            ),
+            2 => DecodedPayload::new("vote", InstructionArgs::None, VOTE_ACCOUNTS),
+            3 => DecodedPayload::new(
+                "withdraw",
+                vote(VoteArgs::Withdraw { lamports: read_u64(data, 4)? }),
+                &["voteAccount", "destination", "withdrawAuthority"],
+            ),
+            4 => DecodedPayload::new("updateValidatorIdentity", InstructionArgs::None, &["voteAccount", "newIdentity", "withdrawAuthority"]),
+            5 => DecodedPayload::new(
+                "updateCommission",
+                vote(VoteArgs::UpdateCommission { commission: *data.get(4).ok_or_else(|| Report::msg("指令数据长度不足"))? }),
+                &["voteAccount", "withdrawAuthority"],
+            ),
+            6 => DecodedPayload::new("voteSwitch", InstructionArgs::None, VOTE_ACCOUNTS),
+            7 => DecodedPayload::new(
+                "authorizeChecked",
+                vote(VoteArgs::AuthorizeChecked { authority_type: authority_type(read_u32(data, 4)?) }),
+                &["voteAccount", "clockSysvar", "authority", "newAuthority"],
+            ),
+            8 => DecodedPayload::new("updateVoteState", InstructionArgs::None, VOTE_STATE_ACCOUNTS),
+            9 => DecodedPayload::new("updateVoteStateSwitch", InstructionArgs::None, VOTE_STATE_ACCOUNTS),
+            10 => DecodedPayload::new("authorizeWithSeed", InstructionArgs::None, &["voteAccount", "clockSysvar", "authorityBase"]),
+            11 => DecodedPayload::new(
+                "authorizeCheckedWithSeed",
+                InstructionArgs::None,
+                &["voteAccount", "clockSysvar", "authorityBase", "newAuthority"],
+            ),
+            12 => DecodedPayload::new("compactUpdateVoteState", InstructionArgs::None, VOTE_STATE_ACCOUNTS),
+            13 => DecodedPayload::new("compactUpdateVoteStateSwitch", InstructionArgs::None, VOTE_STATE_ACCOUNTS),
+            14 => DecodedPayload::new("towerSync", InstructionArgs::None, VOTE_STATE_ACCOUNTS),
+            15 => DecodedPayload::new("towerSyncSwitch", InstructionArgs::None, VOTE_STATE_ACCOUNTS),
+            _ => return Err(Report::msg("无法解析的投票程序指令")),
+        })
+    }
+}
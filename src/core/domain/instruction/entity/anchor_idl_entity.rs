@@ -0,0 +1,212 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use solana_sdk::hash::hashv;
+
+/// # Description
+///     Anchor IDL，兼容 0.30 之前（camelCase 名称、isMut/isSigner、无 discriminator）
+///     与 0.30 之后（snake_case 名称、writable/signer、显式 discriminator）两种格式。
+///     类型描述保留为 JSON，由解码器按 Borsh 规则解释
+/// # Fields
+///     address: Option<String> - 程序地址（0.30 格式）
+///     name: Option<String> - 程序名称（旧格式）
+///     metadata: Option<IdlMetadata> - 元数据
+///     instructions: Vec<IdlInstruction> - 指令定义
+///     accounts: Vec<IdlAccount> - 账户定义
+///     types: Vec<IdlTypeDef> - 自定义类型
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AnchorIdl {
+    #[serde(default)]
+    pub address: Option<String>,
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub metadata: Option<IdlMetadata>,
+    #[serde(default)]
+    pub instructions: Vec<IdlInstruction>,
+    #[serde(default)]
+    pub accounts: Vec<IdlAccount>,
+    #[serde(default)]
+    pub types: Vec<IdlTypeDef>,
+}
+
+/// # Description
+///     IDL 元数据
+/// # Fields
+///     name: Option<String> - 程序名称（0.30 格式）
+///     address: Option<String> - 程序地址（旧格式由 anchor build 写入）
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IdlMetadata {
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub address: Option<String>,
+}
+
+/// # Description
+///     IDL 指令定义
+/// # Fields
+///     name: String - 指令名称
+///     discriminator: Option<Vec<u8>> - 8 字节鉴别器，旧格式为空时按 sha256("global:<snake_name>") 计算
+///     accounts: Vec<IdlAccountItem> - 指令账户
+///     args: Vec<IdlField> - 指令参数
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IdlInstruction {
+    pub name: String,
+    #[serde(default)]
+    pub discriminator: Option<Vec<u8>>,
+    #[serde(default)]
+    pub accounts: Vec<IdlAccountItem>,
+    #[serde(default)]
+    pub args: Vec<IdlField>,
+}
+
+/// # Description
+///     指令账户，可以是单个账户或账户组
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum IdlAccountItem {
+    Group {
+        name: String,
+        accounts: Vec<IdlAccountItem>,
+    },
+    Single {
+        name: String,
+        #[serde(default, alias = "isMut")]
+        writable: bool,
+        #[serde(default, alias = "isSigner")]
+        signer: bool,
+    },
+}
+
+/// # Description
+///     带名称的字段
+/// # Fields
+///     name: String - 字段名称
+///     ty: Value - 类型描述
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IdlField {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub ty: Value,
+}
+
+/// # Description
+///     IDL 账户定义
+/// # Fields
+///     name: String - 账户类型名称
+///     discriminator: Option<Vec<u8>> - 8 字节鉴别器，旧格式为空时按 sha256("account:<Name>") 计算
+///     ty: Option<Value> - 账户结构（旧格式内联，0.30 格式在 types 中）
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IdlAccount {
+    pub name: String,
+    #[serde(default)]
+    pub discriminator: Option<Vec<u8>>,
+    #[serde(default, rename = "type")]
+    pub ty: Option<Value>,
+}
+
+/// # Description
+///     IDL 自定义类型
+/// # Fields
+///     name: String - 类型名称
+///     ty: Value - 类型定义（struct / enum / type）
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IdlTypeDef {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub ty: Value,
+}
+
+/// # Description
+///     按 IDL 解码出的账户数据
+/// # Fields
+///     account_type: String - 账户类型名称
+///     data: Value - 解码后的字段
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct AnchorAccountData {
+    pub account_type: String,
+    pub data: Value,
+}
+
+impl AnchorIdl {
+    /// # Description
+    ///     程序名称，IDL 未给出时为 anchor
+    pub fn program_name(&self) -> String {
+        self.metadata.as_ref()
+            .and_then(|metadata| metadata.name.clone())
+            .or_else(|| self.name.clone())
+            .unwrap_or_else(|| "anchor".to_string())
+    }
+
+    /// # Description
+    ///     IDL 中记录的程序地址
+    pub fn program_address(&self) -> Option<String> {
+        self.address.clone().or_else(|| self.metadata.as_ref().and_then(|metadata| metadata.address.clone()))
+    }
+
+    /// # Description
+    ///     指令鉴别器
+    pub fn instruction_discriminator(&self, instruction: &IdlInstruction) -> Vec<u8> {
+        instruction.discriminator.clone()
+            .unwrap_or_else(|| sighash("global", &to_snake_case(&instruction.name)))
+    }
+
+    /// # Description
+    ///     账户鉴别器
+    pub fn account_discriminator(&self, account: &IdlAccount) -> Vec<u8> {
+        account.discriminator.clone()
+            .unwrap_or_else(|| sighash("account", &account.name))
+    }
+
+    /// # Description
+    ///     按名称查找自定义类型或账户结构
+    pub fn type_def(&self, name: &str) -> Option<&Value> {
+        self.types.iter()
+            .find(|def| def.name == name)
+            .map(|def| &def.ty)
+            .or_else(|| self.accounts.iter().find(|account| account.name == name).and_then(|account| account.ty.as_ref()))
+    }
+}
+
+impl IdlAccountItem {
+    /// # Description
+    ///     展开账户组，组内账户命名为 组名.账户名
+    /// # Param
+    ///     prefix: &str - 上级组名，顶层为空
+    ///     names: &mut Vec<String> - 输出的账户名称
+    pub fn flatten(&self, prefix: &str, names: &mut Vec<String>) {
+        let qualify = |name: &str| if prefix.is_empty() { name.to_string() } else { format!("{}.{}", prefix, name) };
+        match self {
+            IdlAccountItem::Single { name, .. } => names.push(qualify(name)),
+            IdlAccountItem::Group { name, accounts } => {
+                let group = qualify(name);
+                for account in accounts {
+                    account.flatten(&group, names);
+                }
+            }
+        }
+    }
+}
+
+/// # Description
+///     Anchor 鉴别器：sha256("<namespace>:<name>") 的前 8 字节
+fn sighash(namespace: &str, name: &str) -> Vec<u8> {
+    hashv(&[namespace.as_bytes(), ":".as_bytes(), name.as_bytes()]).to_bytes()[..8].to_vec()
+}
+
+/// # Description
+///     小驼峰名称转 snake_case，旧格式 IDL 的指令名称由 Rust 函数名转为小驼峰
+fn to_snake_case(name: &str) -> String {
+    let mut snake = String::with_capacity(name.len() + 4);
+    for (index, c) in name.chars().enumerate() {
+        if c.is_uppercase() {
+            if index > 0 {
+                snake.push('_');
+            }
+            snake.extend(c.to_lowercase());
+        } else {
+            snake.push(c);
+        }
+    }
+    snake
+}
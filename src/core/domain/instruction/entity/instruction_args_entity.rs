@@ -0,0 +1,112 @@
+use serde::Serialize;
+use serde_json::Value;
+
+/// # Description
+///     指令参数。指令名称由 ParsedInstruction.name 给出，这里只保留参数本身；
+///     没有参数的指令为 None，Anchor 程序的参数按 IDL 解码为 JSON，无法识别的指令保留原始数据
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum InstructionArgs {
+    None,
+    System(SystemArgs),
+    Token(TokenArgs),
+    Memo { memo: String },
+    ComputeBudget(ComputeBudgetArgs),
+    Stake(StakeArgs),
+    Vote(VoteArgs),
+    TokenSwap(TokenSwapArgs),
+    RaydiumAmm(RaydiumAmmArgs),
+    Anchor(Value),
+    Raw { data: String },
+}
+
+/// # Description
+///     系统程序指令参数
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum SystemArgs {
+    CreateAccount { lamports: u64, space: u64, owner: String },
+    Assign { owner: String },
+    Transfer { lamports: u64 },
+    CreateAccountWithSeed { base: String, seed: String, lamports: u64, space: u64, owner: String },
+    WithdrawNonceAccount { lamports: u64 },
+    NonceAuthority { authority: String },
+    Allocate { space: u64 },
+    AllocateWithSeed { base: String, seed: String, space: u64, owner: String },
+    AssignWithSeed { base: String, seed: String, owner: String },
+    TransferWithSeed { lamports: u64, from_seed: String, from_owner: String },
+}
+
+/// # Description
+///     SPL Token / Token-2022 指令参数
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum TokenArgs {
+    InitializeMint { decimals: u8, mint_authority: String, freeze_authority: Option<String> },
+    InitializeMultisig { m: u8 },
+    Amount { amount: u64 },
+    SetAuthority { authority_type: String, new_authority: Option<String> },
+    AmountChecked { amount: u64, decimals: u8 },
+    InitializeAccount { owner: String },
+    UiAmountToAmount { ui_amount: String },
+    Extension { extension: String, data: String },
+}
+
+/// # Description
+///     计算预算程序指令参数
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum ComputeBudgetArgs {
+    RequestUnits { units: u32, additional_fee: u32 },
+    RequestHeapFrame { bytes: u32 },
+    SetComputeUnitLimit { units: u32 },
+    SetComputeUnitPrice { micro_lamports: u64 },
+    SetLoadedAccountsDataSizeLimit { bytes: u32 },
+}
+
+/// # Description
+///     质押程序指令参数
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum StakeArgs {
+    Initialize { staker: String, withdrawer: String, lockup_unix_timestamp: i64, lockup_epoch: u64, custodian: String },
+    Authorize { new_authority: String, authority_type: String },
+    Lamports { lamports: u64 },
+    AuthorizeChecked { authority_type: String },
+}
+
+/// # Description
+///     投票程序指令参数
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum VoteArgs {
+    InitializeAccount { node: String, authorized_voter: String, authorized_withdrawer: String, commission: u8 },
+    Authorize { new_authority: String, authority_type: String },
+    Withdraw { lamports: u64 },
+    UpdateCommission { commission: u8 },
+    AuthorizeChecked { authority_type: String },
+}
+
+/// # Description
+///     spl-token-swap 指令参数
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum TokenSwapArgs {
+    Swap { amount_in: u64, minimum_amount_out: u64 },
+    DepositAllTokenTypes { pool_token_amount: u64, maximum_token_a_amount: u64, maximum_token_b_amount: u64 },
+    WithdrawAllTokenTypes { pool_token_amount: u64, minimum_token_a_amount: u64, minimum_token_b_amount: u64 },
+    DepositSingleTokenTypeExactAmountIn { source_token_amount: u64, minimum_pool_token_amount: u64 },
+    WithdrawSingleTokenTypeExactAmountOut { destination_token_amount: u64, maximum_pool_token_amount: u64 },
+}
+
+/// # Description
+///     Raydium AMM v4 指令参数
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum RaydiumAmmArgs {
+    Initialize2 { nonce: u8, open_time: u64, init_pc_amount: u64, init_coin_amount: u64 },
+    Deposit { max_coin_amount: u64, max_pc_amount: u64, base_side: u64 },
+    Withdraw { amount: u64 },
+    SwapBaseIn { amount_in: u64, minimum_amount_out: u64 },
+    SwapBaseOut { max_amount_in: u64, amount_out: u64 },
+}
@@ -0,0 +1,3 @@
+pub mod parsed_instruction_entity;
+pub mod instruction_args_entity;
+pub mod anchor_idl_entity;
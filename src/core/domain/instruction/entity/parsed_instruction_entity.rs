@@ -0,0 +1,95 @@
+use serde::Serialize;
+use super::instruction_args_entity::InstructionArgs;
+
+/// # Description
+///     交易中的一个账户及其签名、可写标记
+/// # Fields
+///     pubkey: String - 账户地址
+///     is_signer: bool - 是否为签名账户
+///     is_writable: bool - 是否可写
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct AccountKey {
+    pub pubkey: String,
+    pub is_signer: bool,
+    pub is_writable: bool,
+}
+
+/// # Description
+///     v0 交易通过地址查找表加载的账户
+/// # Fields
+///     writable: Vec<String> - 可写账户，按查找顺序排列
+///     readonly: Vec<String> - 只读账户，按查找顺序排列
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct LoadedAddresses {
+    pub writable: Vec<String>,
+    pub readonly: Vec<String>,
+}
+
+/// # Description
+///     指令中带名称的账户
+/// # Fields
+///     name: String - 账户在指令中的角色名称，超出已知布局的账户命名为 remaining
+///     pubkey: String - 账户地址
+///     is_signer: bool - 是否为签名账户
+///     is_writable: bool - 是否可写
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct NamedAccount {
+    pub name: String,
+    pub pubkey: String,
+    pub is_signer: bool,
+    pub is_writable: bool,
+}
+
+/// # Description
+///     解码后的类型化指令
+/// # Fields
+///     program_id: String - 程序地址
+///     program: String - 程序名称，未注册解码器的程序为 unknown
+///     name: String - 指令名称（小驼峰）
+///     accounts: Vec<NamedAccount> - 按指令顺序排列的带名称账户
+///     args: InstructionArgs - 指令参数
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ParsedInstruction {
+    pub program_id: String,
+    pub program: String,
+    pub name: String,
+    pub accounts: Vec<NamedAccount>,
+    pub args: InstructionArgs,
+}
+
+impl ParsedInstruction {
+    /// # Description
+    ///     按名称查找指令中的账户地址
+    /// # Param
+    ///     name: &str - 账户名称
+    /// # Return
+    ///     Option<&str>: 账户地址，不存在时返回 None
+    pub fn account(&self, name: &str) -> Option<&str> {
+        self.accounts.iter()
+            .find(|account| account.name == name)
+            .map(|account| account.pubkey.as_str())
+    }
+
+    /// # Description
+    ///     指令涉及的全部账户地址
+    /// # Return
+    ///     Vec<String>: 按指令顺序排列的账户地址
+    pub fn account_pubkeys(&self) -> Vec<String> {
+        self.accounts.iter().map(|account| account.pubkey.clone()).collect()
+    }
+}
+
+/// # Description
+///     解码后的交易
+/// # Fields
+///     signature: Option<String> - 交易签名，未签名的交易为 None
+///     version: String - 消息版本，legacy 或 0
+///     accounts: Vec<AccountKey> - 全部账户（静态账户在前，随后是查找表加载的可写、只读账户）
+///     instructions: Vec<ParsedInstruction> - 解码后的指令
+#[derive(Debug, Clone, Serialize)]
+pub struct ParsedTransaction {
+    pub signature: Option<String>,
+    pub version: String,
+    pub accounts: Vec<AccountKey>,
+    pub instructions: Vec<ParsedInstruction>,
+}
@@ -0,0 +1,20 @@
+use std::sync::Arc;
+use crate::core::domain::instruction::service::InstructionDomainService;
+
+pub struct InstructionDomain {
+    pub domain_service: Arc<InstructionDomainService>,
+}
+
+impl InstructionDomain {
+    /// # Description
+    ///     初始化指令解码领域
+    /// # Return
+    ///     Self: 初始化后的指令解码领域实例
+    pub async fn new() -> Self {
+        let domain_service = Arc::new(InstructionDomainService::new());
+
+        Self {
+            domain_service
+        }
+    }
+}
@@ -0,0 +1,6 @@
+pub mod entity;
+pub mod decoder;
+pub mod service;
+mod instruction_domain;
+
+pub use instruction_domain::InstructionDomain;
@@ -0,0 +1,436 @@
+use std::collections::HashMap;
+use std::io::Read;
+use std::sync::{Arc, RwLock};
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use color_eyre::{Report, Result};
+use flate2::read::ZlibDecoder;
+use solana_sdk::message::VersionedMessage;
+use solana_sdk::native_token::LAMPORTS_PER_SOL;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signature;
+use solana_sdk::transaction::VersionedTransaction;
+use super::decoder::anchor_decoder::{decode_account, AnchorDecoder};
+use super::decoder::associated_token_decoder::AssociatedTokenDecoder;
+use super::decoder::compute_budget_decoder::ComputeBudgetDecoder;
+use super::decoder::memo_decoder::MemoDecoder;
+use super::decoder::raydium_amm_decoder::RaydiumAmmDecoder;
+use super::decoder::stake_decoder::StakeDecoder;
+use super::decoder::system_decoder::SystemDecoder;
+use super::decoder::token_decoder::TokenDecoder;
+use super::decoder::token_swap_decoder::TokenSwapDecoder;
+use super::decoder::vote_decoder::VoteDecoder;
+use super::decoder::{
+    InstructionDecoder, ATA_PROGRAM_ID, COMPUTE_BUDGET_PROGRAM_ID, MEMO_PROGRAM_ID, MEMO_V1_PROGRAM_ID, RAYDIUM_AMM_PROGRAM_ID,
+    STAKE_PROGRAM_ID, SYSTEM_PROGRAM_ID, TOKEN_2022_PROGRAM_ID, TOKEN_PROGRAM_ID, TOKEN_SWAP_PROGRAM_ID, VOTE_PROGRAM_ID,
+};
+use super::entity::anchor_idl_entity::{AnchorAccountData, AnchorIdl};
+use super::entity::instruction_args_entity::{
+    ComputeBudgetArgs, InstructionArgs, RaydiumAmmArgs, StakeArgs, SystemArgs, TokenArgs, TokenSwapArgs,
+};
+use super::entity::parsed_instruction_entity::{AccountKey, LoadedAddresses, NamedAccount, ParsedInstruction, ParsedTransaction};
+
+/// # Description
+///     地址查找表账户数据中元数据部分的长度，之后按 32 字节依次存放地址
+const LOOKUP_TABLE_META_SIZE: usize = 56;
+
+/// # Description
+///     Anchor 链上 IDL 账户的地址种子
+const IDL_SEED: &str = "anchor:idl";
+
+/// # Description
+///     Anchor 链上 IDL 账户中压缩数据之前的长度：8 字节鉴别器 + 32 字节 authority + 4 字节数据长度
+const IDL_ACCOUNT_HEADER_SIZE: usize = 44;
+
+/// # Description
+///     链上 IDL 解压后的最大字节数，防止压缩炸弹耗尽内存
+const MAX_IDL_BYTES: u64 = 4 * 1024 * 1024;
+
+/// # Description
+///     内置解码器的程序地址，不允许被 IDL 覆盖
+const BUILTIN_PROGRAM_IDS: &[&str] = &[
+    SYSTEM_PROGRAM_ID, TOKEN_PROGRAM_ID, TOKEN_2022_PROGRAM_ID, ATA_PROGRAM_ID, MEMO_PROGRAM_ID, MEMO_V1_PROGRAM_ID,
+    COMPUTE_BUDGET_PROGRAM_ID, STAKE_PROGRAM_ID, VOTE_PROGRAM_ID, TOKEN_SWAP_PROGRAM_ID, RAYDIUM_AMM_PROGRAM_ID,
+];
+
+/// # Description
+///     指令解码领域服务，维护按程序地址注册的解码器，并把交易中的编译指令解码为带名称账户的类型化指令
+/// # Fields
+///     decoders: RwLock<HashMap<String, Arc<dyn InstructionDecoder>>> - 程序地址到解码器的映射
+///     idls: RwLock<HashMap<String, Arc<AnchorIdl>>> - 程序地址到 Anchor IDL 的映射，用于解码账户数据
+pub struct InstructionDomainService {
+    decoders: RwLock<HashMap<String, Arc<dyn InstructionDecoder>>>,
+    idls: RwLock<HashMap<String, Arc<AnchorIdl>>>,
+}
+
+impl InstructionDomainService {
+    /// # Description
+    ///     创建新的指令解码领域服务实例，并注册内置程序的解码器
+    /// # Return
+    ///     Self: 指令解码领域服务实例
+    pub fn new() -> Self {
+        let service = Self { decoders: RwLock::new(HashMap::new()), idls: RwLock::new(HashMap::new()) };
+        let memo: Arc<dyn InstructionDecoder> = Arc::new(MemoDecoder);
+
+        service.register(SYSTEM_PROGRAM_ID, Arc::new(SystemDecoder));
+        service.register(TOKEN_PROGRAM_ID, Arc::new(TokenDecoder::token()));
+        service.register(TOKEN_2022_PROGRAM_ID, Arc::new(TokenDecoder::token_2022()));
+        service.register(ATA_PROGRAM_ID, Arc::new(AssociatedTokenDecoder));
+        service.register(MEMO_PROGRAM_ID, memo.clone());
+        service.register(MEMO_V1_PROGRAM_ID, memo);
+        service.register(COMPUTE_BUDGET_PROGRAM_ID, Arc::new(ComputeBudgetDecoder));
+        service.register(STAKE_PROGRAM_ID, Arc::new(StakeDecoder));
+        service.register(VOTE_PROGRAM_ID, Arc::new(VoteDecoder));
+        service.register(TOKEN_SWAP_PROGRAM_ID, Arc::new(TokenSwapDecoder));
+        service.register(RAYDIUM_AMM_PROGRAM_ID, Arc::new(RaydiumAmmDecoder));
+
+        service
+    }
+
+    /// # Description
+    ///     注册（或替换）某个程序的解码器
+    /// # Param
+    ///     program_id: &str - 程序地址
+    ///     decoder: Arc<dyn InstructionDecoder> - 解码器
+    pub fn register(&self, program_id: &str, decoder: Arc<dyn InstructionDecoder>) {
+        if let Ok(mut decoders) = self.decoders.write() {
+            decoders.insert(program_id.to_string(), decoder);
+        }
+    }
+
+    /// # Description
+    ///     已注册解码器的程序地址
+    /// # Return
+    ///     Vec<String>: 程序地址，按字典序排列
+    pub fn registered_programs(&self) -> Vec<String> {
+        let mut programs: Vec<String> = self.decoders.read()
+            .map(|decoders| decoders.keys().cloned().collect())
+            .unwrap_or_default();
+        programs.sort();
+        programs
+    }
+
+    /// # Description
+    ///     注册 Anchor 程序的 IDL，同时注册基于 IDL 的指令解码器；内置程序的解码器不允许被覆盖
+    /// # Param
+    ///     program_id: &str - 程序地址
+    ///     idl: AnchorIdl - 程序的 IDL
+    /// # Return
+    ///     Result<(), Report>: 注册结果，程序为内置程序时返回错误
+    pub fn register_idl(&self, program_id: &str, idl: AnchorIdl) -> Result<(), Report> {
+        if BUILTIN_PROGRAM_IDS.contains(&program_id) {
+            return Err(Report::msg(format!("程序 {} 使用内置解码器，不能注册 IDL", program_id)));
+        }
+
+        let idl = Arc::new(idl);
+        self.register(program_id, Arc::new(AnchorDecoder::new(idl.clone())));
+        if let Ok(mut idls) = self.idls.write() {
+            idls.insert(program_id.to_string(), idl);
+        }
+
+        Ok(())
+    }
+
+    /// # Description
+    ///     查找程序的 Anchor IDL
+    /// # Param
+    ///     program_id: &str - 程序地址
+    /// # Return
+    ///     Option<Arc<AnchorIdl>>: 已注册的 IDL
+    pub fn find_idl(&self, program_id: &str) -> Option<Arc<AnchorIdl>> {
+        self.idls.read().ok().and_then(|idls| idls.get(program_id).cloned())
+    }
+
+    /// # Description
+    ///     已注册的全部 Anchor IDL
+    /// # Return
+    ///     Vec<(String, Arc<AnchorIdl>)>: 程序地址与 IDL，按程序地址排序
+    pub fn registered_idls(&self) -> Vec<(String, Arc<AnchorIdl>)> {
+        let mut idls: Vec<(String, Arc<AnchorIdl>)> = self.idls.read()
+            .map(|idls| idls.iter().map(|(program_id, idl)| (program_id.clone(), idl.clone())).collect())
+            .unwrap_or_default();
+        idls.sort_by(|a, b| a.0.cmp(&b.0));
+        idls
+    }
+
+    /// # Description
+    ///     解析 IDL JSON
+    /// # Param
+    ///     json: &[u8] - IDL 文件内容
+    /// # Return
+    ///     Result<AnchorIdl, Report>: 解析后的 IDL
+    pub fn parse_idl(&self, json: &[u8]) -> Result<AnchorIdl, Report> {
+        serde_json::from_slice(json).map_err(|e| Report::msg(format!("IDL 格式错误: {}", e)))
+    }
+
+    /// # Description
+    ///     计算 Anchor 程序链上 IDL 账户的地址：以无种子 PDA 为 base，种子 anchor:idl
+    /// # Param
+    ///     program_id: &Pubkey - 程序地址
+    /// # Return
+    ///     Result<Pubkey, Report>: IDL 账户地址
+    pub fn idl_address(&self, program_id: &Pubkey) -> Result<Pubkey, Report> {
+        let (base, _) = Pubkey::find_program_address(&[], program_id);
+        Ok(Pubkey::create_with_seed(&base, IDL_SEED, program_id)?)
+    }
+
+    /// # Description
+    ///     解析链上 IDL 账户数据：跳过鉴别器与 authority，读取长度前缀后 zlib 解压出 IDL JSON，解压结果超过上限时拒绝
+    /// # Param
+    ///     data: &[u8] - IDL 账户数据
+    /// # Return
+    ///     Result<AnchorIdl, Report>: 解析后的 IDL
+    pub fn parse_idl_account(&self, data: &[u8]) -> Result<AnchorIdl, Report> {
+        let len = data.get(IDL_ACCOUNT_HEADER_SIZE - 4..IDL_ACCOUNT_HEADER_SIZE)
+            .and_then(|bytes| bytes.try_into().ok())
+            .map(u32::from_le_bytes)
+            .ok_or_else(|| Report::msg("IDL 账户数据格式错误"))? as usize;
+        let compressed = data.get(IDL_ACCOUNT_HEADER_SIZE..IDL_ACCOUNT_HEADER_SIZE + len)
+            .ok_or_else(|| Report::msg("IDL 账户数据长度不足"))?;
+
+        let mut json = Vec::new();
+        ZlibDecoder::new(compressed).take(MAX_IDL_BYTES + 1).read_to_end(&mut json)
+            .map_err(|e| Report::msg(format!("IDL 解压失败: {}", e)))?;
+        if json.len() as u64 > MAX_IDL_BYTES {
+            return Err(Report::msg(format!("IDL 超过 {} 字节上限", MAX_IDL_BYTES)));
+        }
+        self.parse_idl(&json)
+    }
+
+    /// # Description
+    ///     按所有者程序的 IDL 解码账户数据
+    /// # Param
+    ///     owner: &str - 账户所有者程序地址
+    ///     data: &[u8] - 账户数据
+    /// # Return
+    ///     Option<AnchorAccountData>: 解码结果，程序没有 IDL 或数据不匹配时为 None
+    pub fn decode_anchor_account(&self, owner: &str, data: &[u8]) -> Option<AnchorAccountData> {
+        let idl = self.find_idl(owner)?;
+        decode_account(&idl, data).ok()
+    }
+
+    /// # Description
+    ///     解码单条指令。未注册的程序或解码失败的指令保留原始数据，账户按顺序命名
+    /// # Param
+    ///     program_id: &str - 程序地址
+    ///     data: &[u8] - 指令数据
+    ///     accounts: &[AccountKey] - 指令涉及的账户，按指令中的顺序
+    /// # Return
+    ///     ParsedInstruction: 解码后的指令
+    pub fn decode(&self, program_id: &str, data: &[u8], accounts: &[AccountKey]) -> ParsedInstruction {
+        let decoder = self.decoders.read().ok().and_then(|decoders| decoders.get(program_id).cloned());
+        let program = decoder.as_ref().map(|decoder| decoder.program().to_string()).unwrap_or_else(|| "unknown".to_string());
+
+        let (name, args, account_names) = match decoder.map(|decoder| decoder.decode(data, accounts.len())) {
+            Some(Ok(payload)) => (payload.name, payload.args, payload.account_names),
+            _ => ("unknown".to_string(), InstructionArgs::Raw { data: STANDARD.encode(data) }, Vec::new()),
+        };
+
+        let accounts = accounts.iter()
+            .enumerate()
+            .map(|(index, account)| NamedAccount {
+                name: account_names.get(index).cloned().unwrap_or_else(|| "remaining".to_string()),
+                pubkey: account.pubkey.clone(),
+                is_signer: account.is_signer,
+                is_writable: account.is_writable,
+            })
+            .collect();
+
+        ParsedInstruction {
+            program_id: program_id.to_string(),
+            program,
+            name,
+            accounts,
+            args,
+        }
+    }
+
+    /// # Description
+    ///     按消息头计算全部账户的签名、可写标记。顺序与编译指令中的账户索引一致：
+    ///     静态账户在前，随后是查找表加载的可写账户、只读账户
+    /// # Param
+    ///     message: &VersionedMessage - 交易消息
+    ///     loaded: &LoadedAddresses - 查找表加载的账户，legacy 交易为空
+    /// # Return
+    ///     Vec<AccountKey>: 全部账户
+    pub fn account_keys(&self, message: &VersionedMessage, loaded: &LoadedAddresses) -> Vec<AccountKey> {
+        let header = message.header();
+        let static_keys = message.static_account_keys();
+        let signers = usize::from(header.num_required_signatures);
+        let writable_signers = signers.saturating_sub(usize::from(header.num_readonly_signed_accounts));
+        let writable_unsigned = static_keys.len()
+            .saturating_sub(signers)
+            .saturating_sub(usize::from(header.num_readonly_unsigned_accounts));
+
+        let static_accounts = static_keys.iter().enumerate().map(|(index, key)| AccountKey {
+            pubkey: key.to_string(),
+            is_signer: index < signers,
+            is_writable: if index < signers { index < writable_signers } else { index - signers < writable_unsigned },
+        });
+        let loaded_accounts = loaded.writable.iter()
+            .map(|key| (key, true))
+            .chain(loaded.readonly.iter().map(|key| (key, false)))
+            .map(|(key, is_writable)| AccountKey { pubkey: key.clone(), is_signer: false, is_writable });
+
+        static_accounts.chain(loaded_accounts).collect()
+    }
+
+    /// # Description
+    ///     解码交易中的全部顶层指令
+    /// # Param
+    ///     transaction: &VersionedTransaction - 交易
+    ///     loaded: &LoadedAddresses - 查找表加载的账户，legacy 交易为空
+    /// # Return
+    ///     ParsedTransaction: 解码后的交易
+    pub fn decode_transaction(&self, transaction: &VersionedTransaction, loaded: &LoadedAddresses) -> ParsedTransaction {
+        let accounts = self.account_keys(&transaction.message, loaded);
+
+        let instructions = transaction.message.instructions()
+            .iter()
+            .map(|instruction| {
+                let program_id = accounts.get(usize::from(instruction.program_id_index))
+                    .map(|account| account.pubkey.clone())
+                    .unwrap_or_default();
+                let instruction_accounts: Vec<AccountKey> = instruction.accounts.iter()
+                    .filter_map(|index| accounts.get(usize::from(*index)).cloned())
+                    .collect();
+                self.decode(&program_id, &instruction.data, &instruction_accounts)
+            })
+            .collect();
+
+        ParsedTransaction {
+            signature: transaction.signatures.first()
+                .filter(|signature| **signature != Signature::default())
+                .map(|signature| signature.to_string()),
+            version: match transaction.message {
+                VersionedMessage::Legacy(_) => "legacy".to_string(),
+                VersionedMessage::V0(_) => "0".to_string(),
+            },
+            accounts,
+            instructions,
+        }
+    }
+
+    /// # Description
+    ///     解析地址查找表账户数据中的地址列表
+    /// # Param
+    ///     data: &[u8] - 查找表账户数据
+    /// # Return
+    ///     Result<Vec<String>, Report>: 表中的地址，按索引排列
+    pub fn lookup_table_addresses(&self, data: &[u8]) -> Result<Vec<String>, Report> {
+        let addresses = data.get(LOOKUP_TABLE_META_SIZE..)
+            .filter(|addresses| addresses.len() % 32 == 0)
+            .ok_or_else(|| Report::msg("地址查找表数据格式错误"))?;
+
+        Ok(addresses.chunks_exact(32)
+            .filter_map(|chunk| Pubkey::try_from(chunk).ok())
+            .map(|pubkey| pubkey.to_string())
+            .collect())
+    }
+
+    /// # Description
+    ///     用查找表内容解析 v0 交易加载的账户，legacy 交易返回空
+    /// # Param
+    ///     message: &VersionedMessage - 交易消息
+    ///     tables: &HashMap<String, Vec<String>> - 查找表地址到表内地址列表的映射
+    /// # Return
+    ///     Result<LoadedAddresses, Report>: 加载的可写、只读账户
+    pub fn resolve_lookups(&self, message: &VersionedMessage, tables: &HashMap<String, Vec<String>>) -> Result<LoadedAddresses, Report> {
+        let mut loaded = LoadedAddresses::default();
+
+        for lookup in message.address_table_lookups().unwrap_or_default() {
+            let table_key = lookup.account_key.to_string();
+            let table = tables.get(&table_key)
+                .ok_or_else(|| Report::msg(format!("缺少地址查找表 {}", table_key)))?;
+            let resolve = |index: &u8| table.get(usize::from(*index))
+                .cloned()
+                .ok_or_else(|| Report::msg(format!("地址查找表 {} 中不存在索引 {}", table_key, index)));
+
+            for index in &lookup.writable_indexes {
+                loaded.writable.push(resolve(index)?);
+            }
+            for index in &lookup.readonly_indexes {
+                loaded.readonly.push(resolve(index)?);
+            }
+        }
+
+        Ok(loaded)
+    }
+
+    /// # Description
+    ///     生成指令的中文可读描述
+    /// # Param
+    ///     instruction: &ParsedInstruction - 解码后的指令
+    /// # Return
+    ///     String: 可读描述
+    pub fn describe(&self, instruction: &ParsedInstruction) -> String {
+        let account = |name: &str| instruction.account(name).unwrap_or("?").to_string();
+
+        match (instruction.program.as_str(), instruction.name.as_str(), &instruction.args) {
+            ("unknown", _, _) => "未知程序指令".to_string(),
+            (program, "unknown", _) => format!("无法解析的 {} 指令", program),
+            (_, "transfer", InstructionArgs::System(SystemArgs::Transfer { lamports }))
+            | (_, "transferWithSeed", InstructionArgs::System(SystemArgs::TransferWithSeed { lamports, .. })) => {
+                format!("转账 {} SOL：{} → {}", lamports_to_sol(*lamports), account("source"), account("destination"))
+            }
+            (_, _, InstructionArgs::System(SystemArgs::CreateAccount { lamports, space, owner })) => format!(
+                "创建账户 {}（{} 字节，存入 {} SOL，所有者 {}）",
+                account("newAccount"), space, lamports_to_sol(*lamports), owner,
+            ),
+            ("system", _, _) => "系统程序指令".to_string(),
+            ("spl-token" | "spl-token-2022", "transfer", InstructionArgs::Token(TokenArgs::Amount { amount })) => {
+                format!("代币转账 {}（原始数量）：{} → {}", amount, account("source"), account("destination"))
+            }
+            ("spl-token" | "spl-token-2022", "transferChecked", InstructionArgs::Token(TokenArgs::AmountChecked { amount, decimals })) => format!(
+                "代币转账 {} {}：{} → {}",
+                *amount as f64 / 10f64.powi(*decimals as i32), account("mint"), account("source"), account("destination"),
+            ),
+            ("spl-token" | "spl-token-2022", "closeAccount", _) => {
+                format!("关闭代币账户 {}，租金退回 {}", account("account"), account("destination"))
+            }
+            ("spl-token" | "spl-token-2022", "syncNative", _) => format!("同步 WSOL 账户 {} 余额", account("account")),
+            ("spl-token" | "spl-token-2022", _, _) => "代币程序指令".to_string(),
+            ("spl-associated-token-account", "create", _) => {
+                format!("为 {} 创建 {} 的关联代币账户 {}", account("wallet"), account("mint"), account("associatedAccount"))
+            }
+            ("spl-associated-token-account", "createIdempotent", _) => format!(
+                "为 {} 创建 {} 的关联代币账户 {}（已存在则跳过）",
+                account("wallet"), account("mint"), account("associatedAccount"),
+            ),
+            ("spl-associated-token-account", _, _) => "找回嵌套的关联代币账户".to_string(),
+            (_, _, InstructionArgs::Memo { memo }) => format!("备注：{}", memo),
+            (_, _, InstructionArgs::ComputeBudget(args)) => match args {
+                ComputeBudgetArgs::RequestUnits { units, .. } => format!("申请计算单元 {}", units),
+                ComputeBudgetArgs::RequestHeapFrame { bytes } => format!("申请堆内存 {} 字节", bytes),
+                ComputeBudgetArgs::SetComputeUnitLimit { units } => format!("计算单元上限 {}", units),
+                ComputeBudgetArgs::SetComputeUnitPrice { micro_lamports } => format!("计算单元价格 {} micro-lamports", micro_lamports),
+                ComputeBudgetArgs::SetLoadedAccountsDataSizeLimit { bytes } => format!("加载账户数据上限 {} 字节", bytes),
+            },
+            ("stake", "delegateStake", _) => format!("委托质押账户 {} 至验证者 {}", account("stakeAccount"), account("voteAccount")),
+            ("stake", "withdraw", InstructionArgs::Stake(StakeArgs::Lamports { lamports })) => {
+                format!("从质押账户 {} 提取 {} SOL 至 {}", account("stakeAccount"), lamports_to_sol(*lamports), account("destination"))
+            }
+            ("stake", "deactivate", _) => format!("停用质押账户 {}", account("stakeAccount")),
+            ("stake", _, _) => "质押程序指令".to_string(),
+            ("vote", _, _) => "投票程序指令".to_string(),
+            (_, _, InstructionArgs::TokenSwap(TokenSwapArgs::Swap { amount_in, minimum_amount_out })) => {
+                format!("兑换：输入 {}（原始数量），最少得到 {}", amount_in, minimum_amount_out)
+            }
+            ("spl-token-swap", _, _) => "spl-token-swap 指令".to_string(),
+            (_, _, InstructionArgs::RaydiumAmm(RaydiumAmmArgs::SwapBaseIn { amount_in, minimum_amount_out })) => {
+                format!("兑换：输入 {}（原始数量），最少得到 {}", amount_in, minimum_amount_out)
+            }
+            (_, _, InstructionArgs::RaydiumAmm(RaydiumAmmArgs::SwapBaseOut { max_amount_in, amount_out })) => {
+                format!("兑换：最多输入 {}（原始数量），得到 {}", max_amount_in, amount_out)
+            }
+            ("raydium-amm", _, _) => "Raydium AMM 指令".to_string(),
+            (program, name, _) => format!("{} 程序指令 {}", program, name),
+        }
+    }
+}
+
+/// # Description
+///     lamports 换算为 SOL
+fn lamports_to_sol(lamports: u64) -> f64 {
+    lamports as f64 / LAMPORTS_PER_SOL as f64
+}
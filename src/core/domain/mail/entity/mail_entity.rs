@@ -0,0 +1,27 @@
+use serde::{Deserialize, Serialize};
+
+/// # Description
+///     待发送的邮件
+/// # Fields
+///     to: String - 收件人
+///     subject: String - 主题
+///     body: String - 纯文本正文
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MailMessage {
+    pub to: String,
+    pub subject: String,
+    pub body: String,
+}
+
+/// # Description
+///     邮件模板
+/// # Param
+///     RegisterCode: 注册验证码
+///     PasswordReset: 忘记密码的重置链接
+///     PasswordChanged: 密码已被重置或修改的通知
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MailTemplate {
+    RegisterCode { code: String, ttl_minutes: u64 },
+    PasswordReset { link: String, ttl_minutes: u64 },
+    PasswordChanged,
+}
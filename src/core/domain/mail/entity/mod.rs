@@ -0,0 +1 @@
+pub mod mail_entity;
\ No newline at end of file
@@ -0,0 +1,20 @@
+use std::sync::Arc;
+use crate::core::domain::mail::service::MailDomainService;
+
+pub struct MailDomain {
+    pub domain_service: Arc<MailDomainService>,
+}
+
+impl MailDomain {
+    /// # Description
+    ///     初始化邮件领域
+    /// # Return
+    ///     Self: 初始化后的邮件领域实例
+    pub async fn new() -> Self {
+        let domain_service = Arc::new(MailDomainService::new());
+
+        Self {
+            domain_service
+        }
+    }
+}
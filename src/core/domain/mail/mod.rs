@@ -0,0 +1,6 @@
+pub mod entity;
+pub mod repository;
+pub mod service;
+mod mail_domain;
+
+pub use mail_domain::MailDomain;
\ No newline at end of file
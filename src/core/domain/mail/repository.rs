@@ -0,0 +1,10 @@
+use axum::async_trait;
+use color_eyre::{Report, Result};
+use crate::core::domain::mail::entity::mail_entity::MailMessage;
+
+/// # Description
+///     邮件发送接口：SMTP 用于生产，文件与内存发件箱用于本地调试和测试
+#[async_trait]
+pub trait MailTransportInterface: Send + Sync {
+    async fn send(&self, message: &MailMessage) -> Result<(), Report>;
+}
@@ -0,0 +1,83 @@
+use color_eyre::{Report, Result};
+use super::entity::mail_entity::{MailMessage, MailTemplate};
+
+/// # Description
+///     邮件领域服务，负责邮箱校验与模板渲染
+pub struct MailDomainService;
+
+impl MailDomainService {
+    /// # Description
+    ///     创建新的邮件领域服务实例
+    /// # Return
+    ///     Self: 邮件领域服务实例
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// # Description
+    ///     校验并规范化邮箱：去掉首尾空白并转小写，要求 本地部分@域名，域名至少包含一个点
+    /// # Param
+    ///     email: &str - 邮箱
+    /// # Return
+    ///     Result<String, Report>: 规范化后的邮箱
+    pub fn normalize_email(&self, email: &str) -> Result<String, Report> {
+        let email = email.trim().to_lowercase();
+        let invalid = || Report::msg("邮箱格式不正确");
+
+        let (local, domain) = email.split_once('@').ok_or_else(invalid)?;
+        let valid_local = !local.is_empty()
+            && local.len() <= 64
+            && !local.starts_with('.')
+            && !local.ends_with('.')
+            && local.chars().all(|c| c.is_ascii_alphanumeric() || "._%+-".contains(c));
+        let valid_domain = domain.len() <= 253
+            && domain.split('.').count() >= 2
+            && domain.split('.').all(|label| {
+                !label.is_empty()
+                    && !label.starts_with('-')
+                    && !label.ends_with('-')
+                    && label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+            });
+        if !valid_local || !valid_domain || email.len() > 254 {
+            return Err(invalid());
+        }
+
+        Ok(email)
+    }
+
+    /// # Description
+    ///     按模板渲染邮件
+    /// # Param
+    ///     app_name: &str - 程序名称，出现在主题与落款中
+    ///     to: &str - 收件人
+    ///     template: &MailTemplate - 模板
+    /// # Return
+    ///     MailMessage: 邮件
+    pub fn render(&self, app_name: &str, to: &str, template: &MailTemplate) -> MailMessage {
+        let (subject, body) = match template {
+            MailTemplate::RegisterCode { code, ttl_minutes } => (
+                format!("【{}】注册验证码", app_name),
+                format!(
+                    "您好：\n\n您正在注册 {}，验证码为：{}\n验证码 {} 分钟内有效，请勿告诉他人。\n\n如果这不是您本人的操作，请忽略本邮件。\n\n{}",
+                    app_name, code, ttl_minutes, app_name
+                ),
+            ),
+            MailTemplate::PasswordReset { link, ttl_minutes } => (
+                format!("【{}】重置密码", app_name),
+                format!(
+                    "您好：\n\n我们收到了重置您 {} 账号密码的请求，请在 {} 分钟内打开以下链接设置新密码：\n{}\n\n如果这不是您本人的操作，请忽略本邮件，您的密码不会改变。\n\n{}",
+                    app_name, ttl_minutes, link, app_name
+                ),
+            ),
+            MailTemplate::PasswordChanged => (
+                format!("【{}】密码已修改", app_name),
+                format!(
+                    "您好：\n\n您的 {} 账号密码刚刚被修改。\n如果这不是您本人的操作，请立即联系我们。\n\n{}",
+                    app_name, app_name
+                ),
+            ),
+        };
+
+        MailMessage { to: to.to_string(), subject, body }
+    }
+}
@@ -0,0 +1,119 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// # Description
+///     OpenBook / Serum 市场账户中与行情相关的字段
+/// # Fields
+///     address: String - 市场地址
+///     coin_mint: String - 基础代币 mint
+///     pc_mint: String - 计价代币 mint
+///     bids: String - 买单 slab 账户
+///     asks: String - 卖单 slab 账户
+///     event_queue: String - 事件队列账户
+///     coin_lot_size: u64 - 基础代币最小下单单位（原生数量）
+///     pc_lot_size: u64 - 计价代币最小价格单位（原生数量）
+///     coin_decimals: u8 - 基础代币精度
+///     pc_decimals: u8 - 计价代币精度
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MarketMeta {
+    pub address: String,
+    pub coin_mint: String,
+    pub pc_mint: String,
+    pub bids: String,
+    pub asks: String,
+    pub event_queue: String,
+    pub coin_lot_size: u64,
+    pub pc_lot_size: u64,
+    pub coin_decimals: u8,
+    pub pc_decimals: u8,
+}
+
+/// # Description
+///     slab 中的一笔挂单，价格与数量均为 lot 单位
+/// # Fields
+///     price_lots: u64 - 价格（lot）
+///     quantity_lots: u64 - 数量（lot）
+///     owner: String - open orders 账户
+#[derive(Debug, Clone, PartialEq)]
+pub struct SlabOrder {
+    pub price_lots: u64,
+    pub quantity_lots: u64,
+    pub owner: String,
+}
+
+/// # Description
+///     事件队列中的一条事件，数量均为原生单位
+/// # Fields
+///     fill: bool - 是否为成交事件
+///     bid: bool - 事件所属订单是否为买单
+///     maker: bool - 是否为挂单方
+///     native_qty_released: u64 - 释放给用户的数量
+///     native_qty_paid: u64 - 用户支付的数量
+///     native_fee_or_rebate: u64 - 手续费或返佣
+///     order_id: u128 - 订单号
+///     owner: String - open orders 账户
+#[derive(Debug, Clone, PartialEq)]
+pub struct MarketEvent {
+    pub fill: bool,
+    pub bid: bool,
+    pub maker: bool,
+    pub native_qty_released: u64,
+    pub native_qty_paid: u64,
+    pub native_fee_or_rebate: u64,
+    pub order_id: u128,
+    pub owner: String,
+}
+
+/// # Description
+///     L2 档位
+/// # Fields
+///     price: f64 - 价格
+///     size: f64 - 该价格上的总数量
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BookLevel {
+    pub price: f64,
+    pub size: f64,
+}
+
+/// # Description
+///     归一化后的 L2 订单簿
+/// # Fields
+///     market: String - 市场地址
+///     bids: Vec<BookLevel> - 买盘，价格从高到低
+///     asks: Vec<BookLevel> - 卖盘，价格从低到高
+///     updated_at: DateTime<Utc> - 读取时间
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OrderBook {
+    pub market: String,
+    pub bids: Vec<BookLevel>,
+    pub asks: Vec<BookLevel>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// # Description
+///     归一化后的成交记录，以吃单方视角描述
+/// # Fields
+///     side: String - 吃单方向（buy / sell）
+///     price: f64 - 成交价格
+///     size: f64 - 成交数量
+///     order_id: String - 吃单订单号
+///     owner: String - 吃单 open orders 账户
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MarketFill {
+    pub side: String,
+    pub price: f64,
+    pub size: f64,
+    pub order_id: String,
+    pub owner: String,
+}
+
+/// # Description
+///     市场行情快照，用于推送
+/// # Fields
+///     book: OrderBook - 订单簿
+///     fills: Vec<MarketFill> - 最近成交，从新到旧
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MarketSnapshot {
+    pub book: OrderBook,
+    pub fills: Vec<MarketFill>,
+}
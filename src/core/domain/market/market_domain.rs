@@ -0,0 +1,20 @@
+use std::sync::Arc;
+use crate::core::domain::market::service::MarketDomainService;
+
+pub struct MarketDomain {
+    pub domain_service: Arc<MarketDomainService>,
+}
+
+impl MarketDomain {
+    /// # Description
+    ///     初始化市场行情领域
+    /// # Return
+    ///     Self: 初始化后的市场行情领域实例
+    pub async fn new() -> Self {
+        let domain_service = Arc::new(MarketDomainService::new());
+
+        Self {
+            domain_service
+        }
+    }
+}
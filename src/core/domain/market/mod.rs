@@ -0,0 +1,5 @@
+pub mod entity;
+pub mod service;
+mod market_domain;
+
+pub use market_domain::MarketDomain;
@@ -0,0 +1,131 @@
+use chrono::{DateTime, Utc};
+use super::entity::{BookLevel, MarketEvent, MarketFill, MarketMeta, OrderBook, SlabOrder};
+
+/// # Description
+///     市场行情领域服务，负责把 lot / 原生单位换算为 UI 价格和数量
+pub struct MarketDomainService {}
+
+impl MarketDomainService {
+    /// # Description
+    ///     创建新的市场行情领域服务
+    /// # Return
+    ///     MarketDomainService: 市场行情领域服务实例
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    /// # Description
+    ///     由买卖 slab 构建 L2 订单簿，同价位合并
+    /// # Param
+    ///     market: &MarketMeta - 市场信息
+    ///     bids: Vec<SlabOrder> - 买单
+    ///     asks: Vec<SlabOrder> - 卖单
+    ///     depth: usize - 每侧档位数量
+    ///     now: DateTime<Utc> - 读取时间
+    /// # Return
+    ///     OrderBook: L2 订单簿
+    pub fn build_book(&self, market: &MarketMeta, bids: Vec<SlabOrder>, asks: Vec<SlabOrder>, depth: usize, now: DateTime<Utc>) -> OrderBook {
+        OrderBook {
+            market: market.address.clone(),
+            bids: self.levels(market, bids, true, depth),
+            asks: self.levels(market, asks, false, depth),
+            updated_at: now,
+        }
+    }
+
+    /// # Description
+    ///     将成交事件换算为吃单方视角的成交记录，挂单方事件与非成交事件被忽略
+    /// # Param
+    ///     market: &MarketMeta - 市场信息
+    ///     events: Vec<MarketEvent> - 事件，从新到旧
+    ///     limit: usize - 最大数量
+    /// # Return
+    ///     Vec<MarketFill>: 成交记录，从新到旧
+    pub fn fills(&self, market: &MarketMeta, events: Vec<MarketEvent>, limit: usize) -> Vec<MarketFill> {
+        events
+            .into_iter()
+            .filter(|event| event.fill && !event.maker)
+            .filter_map(|event| self.fill(market, &event))
+            .take(limit)
+            .collect()
+    }
+
+    /// # Description
+    ///     lot 价格换算为 UI 价格
+    /// # Param
+    ///     market: &MarketMeta - 市场信息
+    ///     price_lots: u64 - lot 价格
+    /// # Return
+    ///     f64: UI 价格
+    pub fn price_lots_to_number(&self, market: &MarketMeta, price_lots: u64) -> f64 {
+        (price_lots as f64 * market.pc_lot_size as f64 * Self::multiplier(market.coin_decimals))
+            / (market.coin_lot_size as f64 * Self::multiplier(market.pc_decimals))
+    }
+
+    /// # Description
+    ///     lot 数量换算为 UI 数量
+    /// # Param
+    ///     market: &MarketMeta - 市场信息
+    ///     quantity_lots: u64 - lot 数量
+    /// # Return
+    ///     f64: UI 数量
+    pub fn size_lots_to_number(&self, market: &MarketMeta, quantity_lots: u64) -> f64 {
+        (quantity_lots as f64 * market.coin_lot_size as f64) / Self::multiplier(market.coin_decimals)
+    }
+
+    fn levels(&self, market: &MarketMeta, mut orders: Vec<SlabOrder>, descending: bool, depth: usize) -> Vec<BookLevel> {
+        if descending {
+            orders.sort_by(|a, b| b.price_lots.cmp(&a.price_lots));
+        } else {
+            orders.sort_by(|a, b| a.price_lots.cmp(&b.price_lots));
+        }
+
+        let mut levels: Vec<(u64, u64)> = Vec::new();
+        for order in orders {
+            match levels.last_mut() {
+                Some((price, quantity)) if *price == order.price_lots => *quantity += order.quantity_lots,
+                _ => {
+                    if levels.len() == depth {
+                        break;
+                    }
+                    levels.push((order.price_lots, order.quantity_lots));
+                }
+            }
+        }
+
+        levels
+            .into_iter()
+            .map(|(price, quantity)| BookLevel {
+                price: self.price_lots_to_number(market, price),
+                size: self.size_lots_to_number(market, quantity),
+            })
+            .collect()
+    }
+
+    fn fill(&self, market: &MarketMeta, event: &MarketEvent) -> Option<MarketFill> {
+        // 吃单方支付的手续费计入成交额，需要还原为手续费前的计价代币数量
+        let (pc_native, coin_native) = if event.bid {
+            (event.native_qty_paid.checked_sub(event.native_fee_or_rebate)?, event.native_qty_released)
+        } else {
+            (event.native_qty_released.checked_add(event.native_fee_or_rebate)?, event.native_qty_paid)
+        };
+        if coin_native == 0 {
+            return None;
+        }
+
+        let size = coin_native as f64 / Self::multiplier(market.coin_decimals);
+        let price = (pc_native as f64 / Self::multiplier(market.pc_decimals)) / size;
+
+        Some(MarketFill {
+            side: if event.bid { "buy" } else { "sell" }.to_string(),
+            price,
+            size,
+            order_id: event.order_id.to_string(),
+            owner: event.owner.clone(),
+        })
+    }
+
+    fn multiplier(decimals: u8) -> f64 {
+        10f64.powi(decimals as i32)
+    }
+}
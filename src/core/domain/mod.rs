@@ -1,5 +1,25 @@
 pub mod user;
 mod domain;
 pub mod wallet;
+pub mod price;
+pub mod order;
+pub mod dca;
+pub mod market;
+pub mod indexer;
+pub mod network;
+pub mod history;
+pub mod instruction;
+pub mod account;
+pub mod webhook;
+pub mod watcher;
+pub mod stream;
+pub mod reorg;
+pub mod auth;
+pub mod verification;
+pub mod mail;
+pub mod sms;
+pub mod two_factor;
+pub mod withdrawal;
+pub mod throttle;
 
 pub use domain::DomainLayer;
\ No newline at end of file
@@ -0,0 +1,2 @@
+pub mod network_sample_entity;
+pub mod network_stats_entity;
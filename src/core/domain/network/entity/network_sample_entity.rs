@@ -0,0 +1,65 @@
+use chrono::Utc;
+use sea_orm::{ActiveModelBehavior, ActiveValue, DeriveEntityModel, DerivePrimaryKey, DeriveRelation, EnumIter, PrimaryKeyTrait};
+use sea_orm::entity::prelude::*;
+use sea_orm::prelude::DateTimeUtc;
+use serde::{Deserialize, Serialize};
+
+/// # Description
+///     该结构体代表单个区块的网络吞吐采样，并映射到数据库中的 `network_sample` 表，按出块时间组成时间序列。
+/// # Param
+///     slot: 主键，区块所在 slot
+///     block_time: 出块时间（unix 秒）
+///     transaction_count: 区块内交易总数
+///     non_vote_transaction_count: 区块内非投票交易数量
+///     total_fee: 区块内交易手续费合计（lamports）
+///     compute_units: 区块内交易消耗的计算单元合计
+///     created_at: 采样时间
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "network_sample")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub slot: i64,
+    pub block_time: i64,
+    pub transaction_count: i32,
+    pub non_vote_transaction_count: i32,
+    pub total_fee: i64,
+    pub compute_units: i64,
+    pub created_at: DateTimeUtc,
+}
+
+impl Model {
+    /// # Description
+    ///     创建新的区块采样
+    /// # Param
+    ///     slot: u64 - 区块 slot
+    ///     block_time: i64 - 出块时间
+    ///     transaction_count: usize - 交易总数
+    ///     non_vote_transaction_count: usize - 非投票交易数量
+    ///     total_fee: u64 - 手续费合计
+    ///     compute_units: u64 - 计算单元合计
+    /// # Return
+    ///     ActiveModel
+    pub fn new(
+        slot: u64,
+        block_time: i64,
+        transaction_count: usize,
+        non_vote_transaction_count: usize,
+        total_fee: u64,
+        compute_units: u64,
+    ) -> ActiveModel {
+        ActiveModel {
+            slot: ActiveValue::set(slot as i64),
+            block_time: ActiveValue::set(block_time),
+            transaction_count: ActiveValue::set(transaction_count as i32),
+            non_vote_transaction_count: ActiveValue::set(non_vote_transaction_count as i32),
+            total_fee: ActiveValue::set(total_fee as i64),
+            compute_units: ActiveValue::set(compute_units as i64),
+            created_at: ActiveValue::set(Utc::now()),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
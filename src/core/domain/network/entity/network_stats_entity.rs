@@ -0,0 +1,49 @@
+use serde::Serialize;
+
+/// # Description
+///     一个时间桶内的网络吞吐统计
+/// # Fields
+///     start_time: i64 - 桶起始时间（unix 秒，包含）
+///     end_time: i64 - 桶结束时间（unix 秒，不包含）
+///     blocks: u64 - 采样区块数量
+///     transactions: u64 - 交易总数
+///     non_vote_transactions: u64 - 非投票交易数量
+///     tps: f64 - 每秒交易量（含投票交易）
+///     non_vote_tps: f64 - 每秒非投票交易量
+///     total_fee: u64 - 手续费合计（lamports）
+///     avg_fee_per_block: f64 - 平均每个区块的手续费（lamports）
+///     compute_units: u64 - 计算单元合计
+///     avg_compute_units_per_block: f64 - 平均每个区块消耗的计算单元
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct NetworkBucket {
+    pub start_time: i64,
+    pub end_time: i64,
+    pub blocks: u64,
+    pub transactions: u64,
+    pub non_vote_transactions: u64,
+    pub tps: f64,
+    pub non_vote_tps: f64,
+    pub total_fee: u64,
+    pub avg_fee_per_block: f64,
+    pub compute_units: u64,
+    pub avg_compute_units_per_block: f64,
+}
+
+/// # Description
+///     滑动窗口内的网络吞吐统计
+/// # Fields
+///     window: i64 - 窗口长度（秒）
+///     granularity: i64 - 时间桶长度（秒）
+///     first_slot: Option<i64> - 窗口内最早的采样 slot
+///     last_slot: Option<i64> - 窗口内最新的采样 slot
+///     summary: NetworkBucket - 整个窗口的汇总
+///     series: Vec<NetworkBucket> - 按时间升序的时间桶
+#[derive(Debug, Clone, Serialize)]
+pub struct NetworkStats {
+    pub window: i64,
+    pub granularity: i64,
+    pub first_slot: Option<i64>,
+    pub last_slot: Option<i64>,
+    pub summary: NetworkBucket,
+    pub series: Vec<NetworkBucket>,
+}
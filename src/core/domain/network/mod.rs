@@ -0,0 +1,6 @@
+pub mod entity;
+pub mod repository;
+pub mod service;
+mod network_domain;
+
+pub use network_domain::NetworkDomain;
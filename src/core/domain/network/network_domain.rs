@@ -0,0 +1,20 @@
+use std::sync::Arc;
+use crate::core::domain::network::service::NetworkDomainService;
+
+pub struct NetworkDomain {
+    pub domain_service: Arc<NetworkDomainService>,
+}
+
+impl NetworkDomain {
+    /// # Description
+    ///     初始化网络统计领域
+    /// # Return
+    ///     Self: 初始化后的网络统计领域实例
+    pub async fn new() -> Self {
+        let domain_service = Arc::new(NetworkDomainService::new());
+
+        Self {
+            domain_service
+        }
+    }
+}
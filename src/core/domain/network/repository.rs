@@ -0,0 +1,13 @@
+use axum::async_trait;
+use color_eyre::{Report, Result};
+use crate::core::domain::network::entity::network_sample_entity::{ActiveModel as NetworkSampleActiveModel, Model as NetworkSampleModel};
+
+/// # Description
+///     网络吞吐采样仓储接口
+#[async_trait]
+pub trait NetworkRepositoryInterface: Send + Sync {
+    async fn find_latest(&self) -> Result<Option<NetworkSampleModel>, Report>;
+    async fn find_since(&self, block_time: i64) -> Result<Vec<NetworkSampleModel>, Report>;
+    async fn save_samples(&self, samples: Vec<NetworkSampleActiveModel>) -> Result<(), Report>;
+    async fn delete_before(&self, block_time: i64) -> Result<u64, Report>;
+}
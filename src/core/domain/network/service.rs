@@ -0,0 +1,184 @@
+use color_eyre::{Report, Result};
+use solana_sdk::transaction::VersionedTransaction;
+use solana_transaction_status::EncodedConfirmedBlock;
+use super::entity::network_sample_entity::{ActiveModel as NetworkSampleActiveModel, Model as NetworkSampleModel};
+use super::entity::network_stats_entity::{NetworkBucket, NetworkStats};
+
+/// # Description
+///     网络统计领域服务，负责区块采样与滑动窗口聚合
+pub struct NetworkDomainService;
+
+impl NetworkDomainService {
+    /// # Description
+    ///     创建新的网络统计领域服务实例
+    /// # Return
+    ///     Self: 网络统计领域服务实例
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// # Description
+    ///     计算每秒交易量，时间跨度为 0 时返回 0
+    /// # Param
+    ///     transactions: u64 - 交易数量
+    ///     seconds: i64 - 时间跨度（秒）
+    /// # Return
+    ///     f64: 每秒交易量
+    pub fn tps(&self, transactions: u64, seconds: i64) -> f64 {
+        let tps = transactions as f64 / seconds as f64;
+        if tps.is_nan() || tps.is_infinite() {
+            return 0.0;
+        }
+
+        tps
+    }
+
+    /// # Description
+    ///     统计单个区块的交易数量、手续费与计算单元，生成采样记录
+    /// # Param
+    ///     slot: u64 - 区块 slot
+    ///     block: &EncodedConfirmedBlock - 链上区块（交易需为 base64 编码）
+    ///     is_vote: impl Fn(&VersionedTransaction) -> bool - 投票交易判定
+    /// # Return
+    ///     Result<Option<NetworkSampleActiveModel>, Report>: 区块没有出块时间时无法放入时间序列，返回 None
+    pub fn summarize_block(
+        &self,
+        slot: u64,
+        block: &EncodedConfirmedBlock,
+        is_vote: impl Fn(&VersionedTransaction) -> bool,
+    ) -> Result<Option<NetworkSampleActiveModel>, Report> {
+        let Some(block_time) = block.block_time else {
+            return Ok(None);
+        };
+
+        let mut non_vote_transaction_count = 0;
+        let mut total_fee: u64 = 0;
+        let mut compute_units: u64 = 0;
+
+        for (tx_index, transaction_status) in block.transactions.iter().enumerate() {
+            let transaction = transaction_status.transaction.decode()
+                .ok_or_else(|| Report::msg(format!("slot {} 第 {} 笔交易解码失败", slot, tx_index)))?;
+
+            if let Some(meta) = &transaction_status.meta {
+                total_fee = total_fee.saturating_add(meta.fee);
+                compute_units = compute_units.saturating_add(Option::<u64>::from(meta.compute_units_consumed.clone()).unwrap_or_default());
+            }
+
+            if !is_vote(&transaction) {
+                non_vote_transaction_count += 1;
+            }
+        }
+
+        Ok(Some(NetworkSampleModel::new(
+            slot,
+            block_time,
+            block.transactions.len(),
+            non_vote_transaction_count,
+            total_fee,
+            compute_units,
+        )))
+    }
+
+    /// # Description
+    ///     校验统计查询参数
+    /// # Param
+    ///     window: i64 - 窗口长度（秒）
+    ///     granularity: i64 - 时间桶长度（秒）
+    ///     retention: i64 - 采样保留时长（秒）
+    ///     max_points: i64 - 最多返回的时间桶数量
+    /// # Return
+    ///     Result<(), Report>: 参数合法返回 Ok()
+    pub fn validate_query(&self, window: i64, granularity: i64, retention: i64, max_points: i64) -> Result<(), Report> {
+        if window <= 0 || granularity <= 0 {
+            return Err(Report::msg("窗口与粒度必须大于 0"));
+        }
+        if granularity > window {
+            return Err(Report::msg("粒度不能大于窗口"));
+        }
+        if window > retention {
+            return Err(Report::msg(format!("窗口不能超过采样保留时长 {} 秒", retention)));
+        }
+        if (window + granularity - 1) / granularity > max_points {
+            return Err(Report::msg(format!("时间桶数量不能超过 {}", max_points)));
+        }
+
+        Ok(())
+    }
+
+    /// # Description
+    ///     把采样按时间桶聚合。窗口为 [window_end - window, window_end)，时间桶从窗口起点开始对齐，最后一个桶可能不足一个粒度
+    /// # Param
+    ///     samples: &[NetworkSampleModel] - 区块采样
+    ///     window: i64 - 窗口长度（秒）
+    ///     granularity: i64 - 时间桶长度（秒）
+    ///     window_end: i64 - 窗口结束时间（unix 秒，不包含）
+    /// # Return
+    ///     NetworkStats: 窗口汇总与时间序列
+    pub fn aggregate(&self, samples: &[NetworkSampleModel], window: i64, granularity: i64, window_end: i64) -> NetworkStats {
+        let window_start = window_end - window;
+        let in_window: Vec<&NetworkSampleModel> = samples.iter()
+            .filter(|sample| sample.block_time >= window_start && sample.block_time < window_end)
+            .collect();
+
+        let mut series = Vec::new();
+        let mut bucket_start = window_start;
+        while bucket_start < window_end {
+            let bucket_end = (bucket_start + granularity).min(window_end);
+            let bucket_samples = in_window.iter()
+                .copied()
+                .filter(|sample| sample.block_time >= bucket_start && sample.block_time < bucket_end);
+            series.push(self.bucket(bucket_start, bucket_end, bucket_samples));
+            bucket_start = bucket_end;
+        }
+
+        NetworkStats {
+            window,
+            granularity,
+            first_slot: in_window.iter().map(|sample| sample.slot).min(),
+            last_slot: in_window.iter().map(|sample| sample.slot).max(),
+            summary: self.bucket(window_start, window_end, in_window.iter().copied()),
+            series,
+        }
+    }
+
+    /// # Description
+    ///     汇总一个时间桶内的采样
+    /// # Param
+    ///     start_time: i64 - 桶起始时间（包含）
+    ///     end_time: i64 - 桶结束时间（不包含）
+    ///     samples: impl Iterator<Item = &NetworkSampleModel> - 桶内采样
+    /// # Return
+    ///     NetworkBucket: 时间桶统计
+    fn bucket<'a>(&self, start_time: i64, end_time: i64, samples: impl Iterator<Item = &'a NetworkSampleModel>) -> NetworkBucket {
+        let mut blocks: u64 = 0;
+        let mut transactions: u64 = 0;
+        let mut non_vote_transactions: u64 = 0;
+        let mut total_fee: u64 = 0;
+        let mut compute_units: u64 = 0;
+
+        for sample in samples {
+            blocks += 1;
+            transactions += sample.transaction_count as u64;
+            non_vote_transactions += sample.non_vote_transaction_count as u64;
+            total_fee += sample.total_fee as u64;
+            compute_units += sample.compute_units as u64;
+        }
+
+        let per_block = |value: u64| if blocks == 0 { 0.0 } else { value as f64 / blocks as f64 };
+        let seconds = end_time - start_time;
+
+        NetworkBucket {
+            start_time,
+            end_time,
+            blocks,
+            transactions,
+            non_vote_transactions,
+            tps: self.tps(transactions, seconds),
+            non_vote_tps: self.tps(non_vote_transactions, seconds),
+            total_fee,
+            avg_fee_per_block: per_block(total_fee),
+            compute_units,
+            avg_compute_units_per_block: per_block(compute_units),
+        }
+    }
+}
@@ -0,0 +1,2 @@
+pub mod order_entity;
+pub mod order_event_entity;
@@ -0,0 +1,224 @@
+use chrono::{DateTime, Utc};
+use sea_orm::{ActiveModelBehavior, ActiveValue, DeriveEntityModel, DerivePrimaryKey, DeriveRelation, EnumIter, PrimaryKeyTrait};
+use sea_orm::entity::prelude::*;
+use sea_orm::prelude::DateTimeUtc;
+use serde::{Deserialize, Serialize};
+
+/// # Description
+///     条件单触发方向，价格为输入代币以输出代币计价的价格
+/// # Param
+///     Above: 价格上涨到阈值及以上时触发（限价卖出 / 止盈）
+///     Below: 价格下跌到阈值及以下时触发（止损）
+#[derive(Clone, Debug, PartialEq, Eq, EnumIter, DeriveActiveEnum, Serialize, Deserialize)]
+#[sea_orm(rs_type = "String", db_type = "String(StringLen::N(8))")]
+#[serde(rename_all = "lowercase")]
+pub enum OrderDirection {
+    #[sea_orm(string_value = "above")]
+    Above,
+    #[sea_orm(string_value = "below")]
+    Below,
+}
+
+/// # Description
+///     条件单状态
+/// # Param
+///     Open: 等待触发
+///     Triggered: 已触发，正在提交兑换
+///     Filled: 兑换成功
+///     Failed: 兑换失败
+///     Cancelled: 用户取消或已过期
+#[derive(Clone, Debug, PartialEq, Eq, EnumIter, DeriveActiveEnum, Serialize, Deserialize)]
+#[sea_orm(rs_type = "String", db_type = "String(StringLen::N(16))")]
+#[serde(rename_all = "lowercase")]
+pub enum OrderStatus {
+    #[sea_orm(string_value = "open")]
+    Open,
+    #[sea_orm(string_value = "triggered")]
+    Triggered,
+    #[sea_orm(string_value = "filled")]
+    Filled,
+    #[sea_orm(string_value = "failed")]
+    Failed,
+    #[sea_orm(string_value = "cancelled")]
+    Cancelled,
+}
+
+/// # Description
+///     该结构体代表条件兑换单（限价单 / 止损单），并映射到数据库中的 `swap_order` 表。
+/// # Param
+///     id: 主键，自动递增
+///     user_id: 下单用户，关联 user 表
+///     wallet_id: 执行兑换的钱包，关联 wallet 表
+///     input_mint: 卖出代币 mint 地址
+///     output_mint: 买入代币 mint 地址
+///     amount_in: 卖出数量
+///     trigger_price: 触发价格（1 个输入代币可换得的输出代币数量）
+///     direction: 触发方向
+///     max_slippage: 最大滑点比例
+///     status: 订单状态
+///     trigger_at_price: 触发时的预言机价格
+///     signature: 兑换交易签名
+///     error: 失败原因
+///     expires_at: 过期时间
+///     triggered_at: 触发时间
+///     created_at: 创建时间
+///     updated_at: 更新时间
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "swap_order")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+    pub user_id: i32,
+    pub wallet_id: i32,
+    pub input_mint: String,
+    pub output_mint: String,
+    pub amount_in: f64,
+    pub trigger_price: f64,
+    pub direction: OrderDirection,
+    pub max_slippage: f64,
+    pub status: OrderStatus,
+    pub trigger_at_price: Option<f64>,
+    pub signature: Option<String>,
+    pub error: Option<String>,
+    pub expires_at: Option<DateTime<Utc>>,
+    pub triggered_at: Option<DateTime<Utc>>,
+    pub created_at: DateTimeUtc,
+    pub updated_at: DateTimeUtc,
+}
+
+impl Model {
+    /// # Description
+    ///     创建新的条件单
+    /// # Param
+    ///     user_id: i32 - 用户id
+    ///     wallet_id: i32 - 钱包id
+    ///     input_mint: String - 卖出代币
+    ///     output_mint: String - 买入代币
+    ///     amount_in: f64 - 卖出数量
+    ///     trigger_price: f64 - 触发价格
+    ///     direction: OrderDirection - 触发方向
+    ///     max_slippage: f64 - 最大滑点比例
+    ///     expires_at: Option<DateTime<Utc>> - 过期时间
+    /// # Return
+    ///     ActiveModel
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        user_id: i32,
+        wallet_id: i32,
+        input_mint: String,
+        output_mint: String,
+        amount_in: f64,
+        trigger_price: f64,
+        direction: OrderDirection,
+        max_slippage: f64,
+        expires_at: Option<DateTime<Utc>>,
+    ) -> ActiveModel {
+        let now_datetime = Utc::now();
+
+        ActiveModel {
+            id: ActiveValue::NotSet,
+            user_id: ActiveValue::set(user_id),
+            wallet_id: ActiveValue::set(wallet_id),
+            input_mint: ActiveValue::set(input_mint),
+            output_mint: ActiveValue::set(output_mint),
+            amount_in: ActiveValue::set(amount_in),
+            trigger_price: ActiveValue::set(trigger_price),
+            direction: ActiveValue::set(direction),
+            max_slippage: ActiveValue::set(max_slippage),
+            status: ActiveValue::set(OrderStatus::Open),
+            trigger_at_price: Default::default(),
+            signature: Default::default(),
+            error: Default::default(),
+            expires_at: ActiveValue::set(expires_at),
+            triggered_at: Default::default(),
+            created_at: ActiveValue::set(now_datetime),
+            updated_at: ActiveValue::set(now_datetime),
+        }
+    }
+
+    /// # Description
+    ///     判断当前价格是否满足触发条件
+    /// # Param
+    ///     price: f64 - 当前价格
+    /// # Return
+    ///     bool: 是否触发
+    pub fn should_trigger(&self, price: f64) -> bool {
+        match self.direction {
+            OrderDirection::Above => price >= self.trigger_price,
+            OrderDirection::Below => price <= self.trigger_price,
+        }
+    }
+
+    /// # Description
+    ///     判断订单是否已过期
+    /// # Param
+    ///     now: DateTime<Utc> - 当前时间
+    /// # Return
+    ///     bool: 是否过期
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        self.expires_at.is_some_and(|expires_at| expires_at <= now)
+    }
+
+    /// # Description
+    ///     标记订单已触发
+    /// # Param
+    ///     price: f64 - 触发时的价格
+    /// # Return
+    ///     Result<(), String>: 状态不允许时返回错误信息
+    pub fn trigger(&mut self, price: f64) -> Result<(), String> {
+        self.transition(OrderStatus::Open, OrderStatus::Triggered)?;
+        self.trigger_at_price = Some(price);
+        self.triggered_at = Some(Utc::now());
+        Ok(())
+    }
+
+    /// # Description
+    ///     标记订单兑换成功
+    /// # Param
+    ///     signature: String - 兑换交易签名
+    /// # Return
+    ///     Result<(), String>: 状态不允许时返回错误信息
+    pub fn fill(&mut self, signature: String) -> Result<(), String> {
+        self.transition(OrderStatus::Triggered, OrderStatus::Filled)?;
+        self.signature = Some(signature);
+        Ok(())
+    }
+
+    /// # Description
+    ///     标记订单兑换失败
+    /// # Param
+    ///     reason: String - 失败原因
+    /// # Return
+    ///     Result<(), String>: 状态不允许时返回错误信息
+    pub fn fail(&mut self, reason: String) -> Result<(), String> {
+        self.transition(OrderStatus::Triggered, OrderStatus::Failed)?;
+        self.error = Some(reason);
+        Ok(())
+    }
+
+    /// # Description
+    ///     取消订单，只有未触发的订单可以取消
+    /// # Param
+    ///     reason: String - 取消原因
+    /// # Return
+    ///     Result<(), String>: 状态不允许时返回错误信息
+    pub fn cancel(&mut self, reason: String) -> Result<(), String> {
+        self.transition(OrderStatus::Open, OrderStatus::Cancelled)?;
+        self.error = Some(reason);
+        Ok(())
+    }
+
+    fn transition(&mut self, from: OrderStatus, to: OrderStatus) -> Result<(), String> {
+        if self.status != from {
+            return Err(format!("订单状态为 {:?}，无法变更为 {:?}", self.status, to));
+        }
+        self.status = to;
+        self.updated_at = Utc::now();
+        Ok(())
+    }
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,53 @@
+use chrono::Utc;
+use sea_orm::{ActiveModelBehavior, ActiveValue, DeriveEntityModel, DerivePrimaryKey, DeriveRelation, EnumIter, PrimaryKeyTrait};
+use sea_orm::prelude::DateTimeUtc;
+use serde::{Deserialize, Serialize};
+use super::order_entity::OrderStatus;
+
+/// # Description
+///     该结构体代表条件单的状态变更记录，并映射到数据库中的 `swap_order_event` 表。
+/// # Param
+///     id: 主键，自动递增
+///     order_id: 所属条件单，关联 swap_order 表
+///     status: 变更后的状态
+///     price: 变更时的预言机价格
+///     message: 备注（交易签名、失败原因等）
+///     created_at: 创建时间
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "swap_order_event")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+    pub order_id: i32,
+    pub status: OrderStatus,
+    pub price: Option<f64>,
+    pub message: Option<String>,
+    pub created_at: DateTimeUtc,
+}
+
+impl Model {
+    /// # Description
+    ///     创建新的状态变更记录
+    /// # Param
+    ///     order_id: i32 - 条件单id
+    ///     status: OrderStatus - 变更后的状态
+    ///     price: Option<f64> - 预言机价格
+    ///     message: Option<String> - 备注
+    /// # Return
+    ///     ActiveModel
+    pub fn new(order_id: i32, status: OrderStatus, price: Option<f64>, message: Option<String>) -> ActiveModel {
+        ActiveModel {
+            id: ActiveValue::NotSet,
+            order_id: ActiveValue::set(order_id),
+            status: ActiveValue::set(status),
+            price: ActiveValue::set(price),
+            message: ActiveValue::set(message),
+            created_at: ActiveValue::set(Utc::now()),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,6 @@
+pub mod entity;
+pub mod repository;
+pub mod service;
+mod order_domain;
+
+pub use order_domain::OrderDomain;
@@ -0,0 +1,20 @@
+use std::sync::Arc;
+use crate::core::domain::order::service::OrderDomainService;
+
+pub struct OrderDomain {
+    pub domain_service: Arc<OrderDomainService>,
+}
+
+impl OrderDomain {
+    /// # Description
+    ///     初始化条件单领域
+    /// # Return
+    ///     Self: 初始化后的条件单领域实例
+    pub async fn new() -> Self {
+        let domain_service = Arc::new(OrderDomainService::new());
+
+        Self {
+            domain_service
+        }
+    }
+}
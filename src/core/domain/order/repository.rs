@@ -0,0 +1,19 @@
+use axum::async_trait;
+use chrono::{DateTime, Utc};
+use color_eyre::{Report, Result};
+use crate::core::domain::order::entity::order_entity::{ActiveModel as OrderActiveModel, Model as OrderModel, OrderStatus};
+use crate::core::domain::order::entity::order_event_entity::{ActiveModel as OrderEventActiveModel, Model as OrderEventModel};
+
+/// # Description
+///     条件单仓储接口
+#[async_trait]
+pub trait OrderRepositoryInterface: Send + Sync {
+    async fn find_by_id(&self, id: i32) -> Result<Option<OrderModel>, Report>;
+    async fn find_by_user(&self, user_id: i32) -> Result<Vec<OrderModel>, Report>;
+    async fn find_open(&self, limit: u64) -> Result<Vec<OrderModel>, Report>;
+    async fn find_triggered_before(&self, cutoff: DateTime<Utc>) -> Result<Vec<OrderModel>, Report>;
+    async fn save(&self, order: OrderActiveModel) -> Result<OrderModel, Report>;
+    async fn save_if_status(&self, order: OrderModel, expected: OrderStatus) -> Result<bool, Report>;
+    async fn record_event(&self, event: OrderEventActiveModel) -> Result<(), Report>;
+    async fn find_events(&self, order_id: i32) -> Result<Vec<OrderEventModel>, Report>;
+}
@@ -0,0 +1,122 @@
+use chrono::{DateTime, Utc};
+use color_eyre::{Report, Result};
+use super::entity::order_entity::{ActiveModel as OrderActiveModel, Model as OrderModel, OrderDirection};
+
+/// # Description
+///     条件单在一轮评估中的处理结果
+/// # Param
+///     Hold: 条件未满足，继续等待
+///     Trigger(f64): 条件满足，携带触发价格
+///     Expire: 已过期，需要取消
+#[derive(Debug, Clone, PartialEq)]
+pub enum OrderDecision {
+    Hold,
+    Trigger(f64),
+    Expire,
+}
+
+/// # Description
+///     条件单领域服务
+pub struct OrderDomainService {}
+
+impl OrderDomainService {
+    /// # Description
+    ///     创建新的条件单领域服务
+    /// # Return
+    ///     OrderDomainService: 条件单领域服务实例
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    /// # Description
+    ///     校验参数并创建条件单实体
+    /// # Param
+    ///     user_id: i32 - 用户id
+    ///     wallet_id: i32 - 钱包id
+    ///     input_mint: String - 卖出代币
+    ///     output_mint: String - 买入代币
+    ///     amount_in: f64 - 卖出数量
+    ///     trigger_price: f64 - 触发价格
+    ///     direction: OrderDirection - 触发方向
+    ///     max_slippage: f64 - 用户设置的最大滑点比例
+    ///     slippage_limit: f64 - 系统允许的最大滑点比例
+    ///     expires_at: Option<DateTime<Utc>> - 过期时间
+    /// # Return
+    ///     Result<OrderActiveModel, Report>: 成功返回条件单实体，参数不合法时返回错误信息
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_order(
+        &self,
+        user_id: i32,
+        wallet_id: i32,
+        input_mint: String,
+        output_mint: String,
+        amount_in: f64,
+        trigger_price: f64,
+        direction: OrderDirection,
+        max_slippage: f64,
+        slippage_limit: f64,
+        expires_at: Option<DateTime<Utc>>,
+    ) -> Result<OrderActiveModel, Report> {
+        if input_mint == output_mint {
+            return Err(Report::msg("输入代币与输出代币不能相同"));
+        }
+        if !amount_in.is_finite() || amount_in <= 0.0 {
+            return Err(Report::msg("兑换数量必须大于 0"));
+        }
+        if !trigger_price.is_finite() || trigger_price <= 0.0 {
+            return Err(Report::msg("触发价格必须大于 0"));
+        }
+        if !(max_slippage > 0.0 && max_slippage <= slippage_limit) {
+            return Err(Report::msg(format!("滑点必须在 0 到 {} 之间", slippage_limit)));
+        }
+        if expires_at.is_some_and(|expires_at| expires_at <= Utc::now()) {
+            return Err(Report::msg("过期时间必须晚于当前时间"));
+        }
+
+        Ok(OrderModel::new(
+            user_id,
+            wallet_id,
+            input_mint,
+            output_mint,
+            amount_in,
+            trigger_price,
+            direction,
+            max_slippage,
+            expires_at,
+        ))
+    }
+
+    /// # Description
+    ///     根据当前价格评估条件单，过期判断优先于触发判断
+    /// # Param
+    ///     order: &OrderModel - 条件单
+    ///     price: Option<f64> - 当前价格，无法定价时为 None
+    ///     now: DateTime<Utc> - 当前时间
+    /// # Return
+    ///     OrderDecision: 评估结果
+    pub fn evaluate(&self, order: &OrderModel, price: Option<f64>, now: DateTime<Utc>) -> OrderDecision {
+        if order.is_expired(now) {
+            return OrderDecision::Expire;
+        }
+
+        match price {
+            Some(price) if order.should_trigger(price) => OrderDecision::Trigger(price),
+            _ => OrderDecision::Hold,
+        }
+    }
+
+    /// # Description
+    ///     由两个代币的美元价格计算交叉价格（1 个输入代币可换得的输出代币数量）
+    /// # Param
+    ///     price_in: f64 - 输入代币美元价格
+    ///     price_out: f64 - 输出代币美元价格
+    /// # Return
+    ///     Option<f64>: 交叉价格，价格无效时返回 None
+    pub fn cross_price(&self, price_in: f64, price_out: f64) -> Option<f64> {
+        if price_in <= 0.0 || price_out <= 0.0 {
+            return None;
+        }
+
+        Some(price_in / price_out)
+    }
+}
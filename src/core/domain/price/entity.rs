@@ -0,0 +1,100 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// # Description
+///     单个数据源给出的报价
+/// # Fields
+///     mint: String - 代币 mint 地址
+///     source: String - 数据源名称
+///     price: f64 - 以 USD 计价的价格
+///     confidence: Option<f64> - 置信区间（仅链上预言机提供）
+///     published_at: Option<DateTime<Utc>> - 数据源发布报价的时间，用于判断是否过期；数据源不提供发布时间时为 None，不参与过期检查
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PriceQuote {
+    pub mint: String,
+    pub source: String,
+    pub price: f64,
+    pub confidence: Option<f64>,
+    pub published_at: Option<DateTime<Utc>>,
+}
+
+/// # Description
+///     聚合多个数据源后得到的价格
+/// # Fields
+///     mint: String - 代币 mint 地址
+///     price: f64 - 有效报价的中位数
+///     sources: Vec<String> - 参与聚合的数据源
+///     rejected: Vec<String> - 因过期或偏离过大被剔除的数据源
+///     updated_at: DateTime<Utc> - 聚合时间
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct AggregatedPrice {
+    pub mint: String,
+    pub price: f64,
+    pub sources: Vec<String>,
+    pub rejected: Vec<String>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// # Description
+///     价格聚合策略
+/// # Fields
+///     max_staleness: i64 - 报价最大允许延迟（秒）
+///     max_deviation: f64 - 与中位数的最大偏离比例
+///     min_sources: usize - 聚合所需的最少有效数据源数量
+#[derive(Clone, Debug)]
+pub struct AggregationPolicy {
+    pub max_staleness: i64,
+    pub max_deviation: f64,
+    pub min_sources: usize,
+}
+
+/// # Description
+///     链上预言机账户解码后的价格，实际价格 = price * 10^exponent
+/// # Fields
+///     price: i128 - 价格尾数
+///     confidence: u128 - 置信区间尾数，与 price 使用同一指数
+///     exponent: i32 - 十进制指数
+///     publish_slot: u64 - 发布价格的 slot
+///     publish_time: i64 - 发布价格的 UNIX 时间戳（秒）
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct OnChainPrice {
+    pub price: i128,
+    pub confidence: u128,
+    pub exponent: i32,
+    pub publish_slot: u64,
+    pub publish_time: i64,
+}
+
+impl OnChainPrice {
+    /// # Description
+    ///     转换为浮点价格
+    /// # Return
+    ///     f64: 实际价格
+    pub fn ui_price(&self) -> f64 {
+        self.price as f64 * 10f64.powi(self.exponent)
+    }
+
+    /// # Description
+    ///     转换为浮点置信区间
+    /// # Return
+    ///     f64: 实际置信区间
+    pub fn ui_confidence(&self) -> f64 {
+        self.confidence as f64 * 10f64.powi(self.exponent)
+    }
+
+    /// # Description
+    ///     检查价格是否过期
+    /// # Param
+    ///     current_slot: u64 - 当前 slot
+    ///     max_staleness_slots: u64 - 最大允许落后的 slot 数
+    /// # Return
+    ///     Result<(), String>: 未过期返回 Ok()，过期返回错误信息
+    pub fn ensure_fresh(&self, current_slot: u64, max_staleness_slots: u64) -> Result<(), String> {
+        let lag = current_slot.saturating_sub(self.publish_slot);
+        if lag > max_staleness_slots {
+            Err(format!("链上价格已过期: 落后 {} 个 slot", lag))
+        } else {
+            Ok(())
+        }
+    }
+}
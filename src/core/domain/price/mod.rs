@@ -0,0 +1,6 @@
+pub mod entity;
+pub mod repository;
+pub mod service;
+mod price_domain;
+
+pub use price_domain::PriceDomain;
\ No newline at end of file
@@ -0,0 +1,20 @@
+use std::sync::Arc;
+use crate::core::domain::price::service::PriceDomainService;
+
+pub struct PriceDomain {
+    pub domain_service: Arc<PriceDomainService>,
+}
+
+impl PriceDomain {
+    /// # Description
+    ///     初始化价格领域
+    /// # Return
+    ///     Self: 初始化后的价格领域实例
+    pub async fn new() -> Self {
+        let domain_service = Arc::new(PriceDomainService::new());
+
+        Self {
+            domain_service
+        }
+    }
+}
@@ -0,0 +1,19 @@
+use axum::async_trait;
+use color_eyre::{Report, Result};
+use crate::core::domain::price::entity::{AggregatedPrice, PriceQuote};
+
+/// # Description
+///     价格数据源接口，HTTP API、链上预言机和 AMM 池子都实现该接口，测试时可替换为模拟数据源
+#[async_trait]
+pub trait PriceSourceInterface: Send + Sync {
+    fn name(&self) -> &str;
+    async fn fetch(&self, mint: &str) -> Result<Option<PriceQuote>, Report>;
+}
+
+/// # Description
+///     聚合价格缓存接口
+#[async_trait]
+pub trait PriceCacheInterface: Send + Sync {
+    async fn get(&self, mint: &str) -> Result<Option<AggregatedPrice>, Report>;
+    async fn set(&self, price: &AggregatedPrice, ttl: u64) -> Result<(), Report>;
+}
@@ -0,0 +1,112 @@
+use chrono::{DateTime, Utc};
+use color_eyre::{Report, Result};
+use crate::core::domain::price::entity::{AggregatedPrice, AggregationPolicy, PriceQuote};
+
+/// # Description
+///     价格领域服务，负责对多个数据源的报价进行过期过滤、异常值剔除和中位数聚合
+pub struct PriceDomainService {}
+
+impl PriceDomainService {
+    /// # Description
+    ///     创建新的价格服务
+    /// # Return
+    ///     PriceDomainService: 价格服务实例
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    /// # Description
+    ///     聚合报价
+    ///     1. 丢弃价格非法（非正数或非有限值）及超过 max_staleness 的报价，没有发布时间的报价不做过期检查
+    ///     2. 计算剩余报价的中位数，剔除偏离中位数超过 max_deviation 的报价
+    ///     3. 以剩余报价的中位数作为最终价格
+    /// # Param
+    ///     mint: &str - 代币 mint 地址
+    ///     quotes: Vec<PriceQuote> - 各数据源的报价
+    ///     policy: &AggregationPolicy - 聚合策略
+    ///     now: DateTime<Utc> - 当前时间
+    /// # Return
+    ///     Result<AggregatedPrice, Report>: 成功返回聚合价格，有效数据源不足时返回错误信息
+    pub fn aggregate(&self, mint: &str, quotes: Vec<PriceQuote>, policy: &AggregationPolicy, now: DateTime<Utc>) -> Result<AggregatedPrice, Report> {
+        let mut rejected = Vec::new();
+
+        // 过滤非法和过期的报价
+        let mut fresh = Vec::new();
+        for quote in quotes {
+            let stale = quote.published_at.is_some_and(|published_at| now.signed_duration_since(published_at).num_seconds() > policy.max_staleness);
+            if !quote.price.is_finite() || quote.price <= 0.0 || stale {
+                rejected.push(quote.source);
+            } else {
+                fresh.push(quote);
+            }
+        }
+
+        if fresh.is_empty() {
+            return Err(Report::msg(format!("{} 没有可用的价格数据源", mint)));
+        }
+
+        // 剔除偏离中位数过大的报价
+        let median = Self::median(fresh.iter().map(|q| q.price).collect());
+        let (accepted, outliers): (Vec<PriceQuote>, Vec<PriceQuote>) = fresh
+            .into_iter()
+            .partition(|q| ((q.price - median) / median).abs() <= policy.max_deviation);
+        rejected.extend(outliers.into_iter().map(|q| q.source));
+
+        if accepted.len() < policy.min_sources.max(1) {
+            return Err(Report::msg(format!(
+                "{} 有效价格数据源不足: {}/{}", mint, accepted.len(), policy.min_sources
+            )));
+        }
+
+        Ok(AggregatedPrice {
+            mint: mint.to_string(),
+            price: Self::median(accepted.iter().map(|q| q.price).collect()),
+            sources: accepted.into_iter().map(|q| q.source).collect(),
+            rejected,
+            updated_at: now,
+        })
+    }
+
+    /// # Description
+    ///     检查兑换报价的滑点，以预言机价格推导出的公允输出为基准
+    /// # Param
+    ///     amount_in: f64 - 输入代币数量
+    ///     price_in: f64 - 输入代币 USD 价格
+    ///     price_out: f64 - 输出代币 USD 价格
+    ///     quoted_out: f64 - 兑换路由给出的输出数量
+    ///     max_slippage: f64 - 最大允许滑点比例（例如 0.01 表示 1%）
+    /// # Return
+    ///     Result<f64, Report>: 成功返回实际滑点比例，超过上限返回错误信息
+    pub fn check_slippage(&self, amount_in: f64, price_in: f64, price_out: f64, quoted_out: f64, max_slippage: f64) -> Result<f64, Report> {
+        if price_in <= 0.0 || price_out <= 0.0 {
+            return Err(Report::msg("预言机价格非法"));
+        }
+
+        let fair_out = amount_in * price_in / price_out;
+        let slippage = (fair_out - quoted_out) / fair_out;
+
+        if slippage > max_slippage {
+            return Err(Report::msg(format!(
+                "滑点过大: 公允输出 {:.6}，报价输出 {:.6}，滑点 {:.2}%", fair_out, quoted_out, slippage * 100.0
+            )));
+        }
+
+        Ok(slippage)
+    }
+
+    /// # Description
+    ///     计算中位数，调用方需保证 values 非空
+    /// # Param
+    ///     values: Vec<f64> - 数值列表
+    /// # Return
+    ///     f64: 中位数
+    fn median(mut values: Vec<f64>) -> f64 {
+        values.sort_by(|a, b| a.total_cmp(b));
+        let mid = values.len() / 2;
+        if values.len() % 2 == 0 {
+            (values[mid - 1] + values[mid]) / 2.0
+        } else {
+            values[mid]
+        }
+    }
+}
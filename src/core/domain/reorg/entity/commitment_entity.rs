@@ -0,0 +1,45 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// # Description
+///     链上数据的确认级别。索引与充值按 confirmed 级别写入，所在 slot 被 finalized 后提升，
+///     所在分叉被丢弃时标记为 dropped
+/// # Param
+///     Confirmed: 已被超级多数投票确认，仍可能因分叉被丢弃
+///     Finalized: 已最终确认，不会再回滚
+///     Dropped: 所在分叉已被丢弃，数据不再有效
+#[derive(Clone, Copy, Debug, PartialEq, Eq, EnumIter, DeriveActiveEnum, Serialize, Deserialize)]
+#[sea_orm(rs_type = "String", db_type = "String(StringLen::N(16))")]
+#[serde(rename_all = "lowercase")]
+pub enum CommitmentLevel {
+    #[sea_orm(string_value = "confirmed")]
+    Confirmed,
+    #[sea_orm(string_value = "finalized")]
+    Finalized,
+    #[sea_orm(string_value = "dropped")]
+    Dropped,
+}
+
+/// # Description
+///     已索引区块在最终确认链上的结果
+/// # Param
+///     Finalized: 区块（或被跳过的 slot）与最终确认链一致
+///     Dropped: 区块所在分叉被丢弃，该 slot 事后被跳过
+///     Missed: 索引时记为跳过，但最终确认链在该 slot 有区块，需要重新索引
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BlockFinality {
+    Finalized,
+    Dropped,
+    Missed,
+}
+
+/// # Description
+///     已入账充值在最终确认链上的结果
+/// # Param
+///     Finalized: 交易已最终确认，slot 为最终所在 slot（分叉后重新打包时可能变化）
+///     Dropped: 交易未被最终确认或最终执行失败，需要回滚入账
+#[derive(Clone, Debug, PartialEq)]
+pub enum DepositFinality {
+    Finalized { slot: u64 },
+    Dropped { reason: String },
+}
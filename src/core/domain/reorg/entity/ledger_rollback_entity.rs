@@ -0,0 +1,70 @@
+use chrono::Utc;
+use sea_orm::{ActiveModelBehavior, ActiveValue, DeriveEntityModel, DerivePrimaryKey, DeriveRelation, EnumIter, PrimaryKeyTrait};
+use sea_orm::entity::prelude::*;
+use sea_orm::prelude::DateTimeUtc;
+use serde::{Deserialize, Serialize};
+use crate::core::domain::watcher::entity::deposit_entity::Model as DepositModel;
+
+/// # Description
+///     该结构体代表一次入账回滚的审计记录，并映射到数据库中的 `ledger_rollback` 表。
+///     充值所在交易被分叉丢弃后扣回入账金额，余额不足以全部扣回时记录差额
+/// # Param
+///     id: 主键，自动递增
+///     deposit_id: 被回滚的充值，关联 deposit 表
+///     wallet_id: 入账钱包
+///     user_id: 钱包所属用户
+///     signature: 交易签名
+///     slot: 入账时交易所在 slot
+///     amount: 入账金额（SOL）
+///     debited: 实际扣回金额（SOL）
+///     shortfall: 未能扣回的差额（SOL）
+///     reason: 回滚原因
+///     created_at: 回滚时间
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "ledger_rollback")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+    pub deposit_id: i32,
+    pub wallet_id: i32,
+    pub user_id: i32,
+    pub signature: String,
+    pub slot: i64,
+    pub amount: f64,
+    pub debited: f64,
+    pub shortfall: f64,
+    pub reason: String,
+    pub created_at: DateTimeUtc,
+}
+
+impl Model {
+    /// # Description
+    ///     创建新的回滚审计记录
+    /// # Param
+    ///     deposit: &DepositModel - 被回滚的充值
+    ///     debited: f64 - 实际扣回金额
+    ///     shortfall: f64 - 未能扣回的差额
+    ///     reason: String - 回滚原因
+    /// # Return
+    ///     ActiveModel
+    pub fn new(deposit: &DepositModel, debited: f64, shortfall: f64, reason: String) -> ActiveModel {
+        ActiveModel {
+            id: ActiveValue::NotSet,
+            deposit_id: ActiveValue::set(deposit.id),
+            wallet_id: ActiveValue::set(deposit.wallet_id),
+            user_id: ActiveValue::set(deposit.user_id),
+            signature: ActiveValue::set(deposit.signature.clone()),
+            slot: ActiveValue::set(deposit.slot),
+            amount: ActiveValue::set(deposit.amount),
+            debited: ActiveValue::set(debited),
+            shortfall: ActiveValue::set(shortfall),
+            reason: ActiveValue::set(reason),
+            created_at: ActiveValue::set(Utc::now()),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,2 @@
+pub mod commitment_entity;
+pub mod ledger_rollback_entity;
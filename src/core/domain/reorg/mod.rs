@@ -0,0 +1,6 @@
+pub mod entity;
+pub mod repository;
+pub mod service;
+mod reorg_domain;
+
+pub use reorg_domain::ReorgDomain;
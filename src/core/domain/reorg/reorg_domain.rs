@@ -0,0 +1,20 @@
+use std::sync::Arc;
+use crate::core::domain::reorg::service::ReorgDomainService;
+
+pub struct ReorgDomain {
+    pub domain_service: Arc<ReorgDomainService>,
+}
+
+impl ReorgDomain {
+    /// # Description
+    ///     初始化分叉回滚领域
+    /// # Return
+    ///     Self: 初始化后的分叉回滚领域实例
+    pub async fn new() -> Self {
+        let domain_service = Arc::new(ReorgDomainService::new());
+
+        Self {
+            domain_service
+        }
+    }
+}
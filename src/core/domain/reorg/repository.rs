@@ -0,0 +1,12 @@
+use axum::async_trait;
+use color_eyre::{Report, Result};
+use crate::core::domain::reorg::entity::ledger_rollback_entity::Model as LedgerRollbackModel;
+use crate::core::domain::watcher::entity::deposit_entity::Model as DepositModel;
+
+/// # Description
+///     入账回滚仓储接口
+#[async_trait]
+pub trait LedgerRollbackRepositoryInterface: Send + Sync {
+    async fn rollback(&self, deposit: &DepositModel, reason: String) -> Result<Option<LedgerRollbackModel>, Report>;
+    async fn list(&self, user_id: Option<i32>, limit: u64) -> Result<Vec<LedgerRollbackModel>, Report>;
+}
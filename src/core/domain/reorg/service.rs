@@ -0,0 +1,76 @@
+use crate::core::domain::indexer::entity::indexed_block_entity::Model as IndexedBlockModel;
+use super::entity::commitment_entity::{BlockFinality, DepositFinality};
+
+/// # Description
+///     分叉回滚领域服务，负责比对已写入的数据与最终确认链，并计算入账回滚金额
+pub struct ReorgDomainService;
+
+impl ReorgDomainService {
+    /// # Description
+    ///     创建新的分叉回滚领域服务实例
+    /// # Return
+    ///     Self: 分叉回滚领域服务实例
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// # Description
+    ///     比对已索引区块与最终确认链上的出块情况
+    /// # Param
+    ///     block: &IndexedBlockModel - 已索引区块（slot 不大于最新的 finalized slot）
+    ///     finalized: &[u64] - 节点返回的 finalized 级别已出块 slot 列表，升序
+    /// # Return
+    ///     BlockFinality: 区块结果
+    pub fn block_finality(&self, block: &IndexedBlockModel, finalized: &[u64]) -> BlockFinality {
+        let produced = finalized.binary_search(&(block.slot as u64)).is_ok();
+        match (block.skipped, produced) {
+            (false, true) | (true, false) => BlockFinality::Finalized,
+            (false, false) => BlockFinality::Dropped,
+            (true, true) => BlockFinality::Missed,
+        }
+    }
+
+    /// # Description
+    ///     判断充值交易的最终结果。交易查不到状态、入账 slot 已被 finalized 越过且已连续多轮确认不在最终确认链上时，
+    ///     说明所在分叉已被丢弃；单次查不到可能只是节点未保留历史，不足以回滚。仍处于 confirmed 级别时继续等待
+    /// # Param
+    ///     deposit_slot: u64 - 入账时交易所在 slot
+    ///     status: Option<(u64, Option<String>, bool)> - 交易状态（所在 slot, 失败原因, 是否已 finalized），查不到时为 None
+    ///     finalized_slot: u64 - 最新的 finalized slot
+    ///     misses: u32 - 已连续确认交易不在最终确认链上的轮数（含本轮）
+    ///     required_misses: u32 - 判定丢弃所需的连续轮数
+    /// # Return
+    ///     Option<DepositFinality>: 最终结果，尚无结论时返回 None
+    pub fn deposit_finality(
+        &self,
+        deposit_slot: u64,
+        status: Option<(u64, Option<String>, bool)>,
+        finalized_slot: u64,
+        misses: u32,
+        required_misses: u32,
+    ) -> Option<DepositFinality> {
+        match status {
+            Some((_, _, false)) => None,
+            Some((slot, None, true)) => Some(DepositFinality::Finalized { slot }),
+            Some((slot, Some(error), true)) => Some(DepositFinality::Dropped {
+                reason: format!("交易在 slot {} 最终执行失败: {}", slot, error),
+            }),
+            None if deposit_slot <= finalized_slot && misses >= required_misses.max(1) => Some(DepositFinality::Dropped {
+                reason: format!("交易所在 slot {} 的分叉已被丢弃（连续 {} 轮未在最终确认链上找到）", deposit_slot, misses),
+            }),
+            None => None,
+        }
+    }
+
+    /// # Description
+    ///     计算回滚时实际扣回的金额，入账后已被转出的部分无法扣回，记为差额
+    /// # Param
+    ///     balance: f64 - 钱包当前余额
+    ///     amount: f64 - 需要回滚的入账金额
+    /// # Return
+    ///     (f64, f64): (实际扣回金额, 差额)
+    pub fn rollback_amount(&self, balance: f64, amount: f64) -> (f64, f64) {
+        let debited = amount.min(balance.max(0.0));
+        (debited, amount - debited)
+    }
+}
@@ -0,0 +1 @@
+pub mod sms_entity;
\ No newline at end of file
@@ -0,0 +1,25 @@
+use serde::{Deserialize, Serialize};
+
+/// # Description
+///     待发送的短信
+/// # Fields
+///     to: String - E.164 格式的手机号
+///     text: String - 短信内容
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SmsMessage {
+    pub to: String,
+    pub text: String,
+}
+
+/// # Description
+///     短信模板
+/// # Param
+///     RegisterCode: 注册验证码
+///     LoginCode: 登录验证码
+///     BindMobileCode: 绑定新手机号验证码
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SmsTemplate {
+    RegisterCode { code: String, ttl_minutes: u64 },
+    LoginCode { code: String, ttl_minutes: u64 },
+    BindMobileCode { code: String, ttl_minutes: u64 },
+}
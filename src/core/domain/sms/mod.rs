@@ -0,0 +1,6 @@
+pub mod entity;
+pub mod repository;
+pub mod service;
+mod sms_domain;
+
+pub use sms_domain::SmsDomain;
\ No newline at end of file
@@ -0,0 +1,10 @@
+use axum::async_trait;
+use color_eyre::{Report, Result};
+use crate::core::domain::sms::entity::sms_entity::SmsMessage;
+
+/// # Description
+///     短信服务商接口，接入具体服务商时实现该接口
+#[async_trait]
+pub trait SmsProviderInterface: Send + Sync {
+    async fn send(&self, message: &SmsMessage) -> Result<(), Report>;
+}
@@ -0,0 +1,92 @@
+use color_eyre::{Report, Result};
+use super::entity::sms_entity::{SmsMessage, SmsTemplate};
+
+/// # Description
+///     短信领域服务，负责手机号规范化与短信模板渲染
+pub struct SmsDomainService;
+
+impl SmsDomainService {
+    /// # Description
+    ///     创建新的短信领域服务实例
+    /// # Return
+    ///     Self: 短信领域服务实例
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// # Description
+    ///     把手机号规范化为 E.164 格式（+国家码+号码，共 8-15 位数字）。
+    ///     允许空格、连字符、点和括号分隔；以 00 开头视为国际前缀；不带国家码时去掉一个前导 0 后补默认国家码。
+    ///     中国大陆号码（+86）额外要求为 1 开头的 11 位手机号
+    /// # Param
+    ///     mobile: &str - 用户输入的手机号
+    ///     default_country_code: &str - 默认国家码，如 86，为空时要求输入带国家码
+    /// # Return
+    ///     Result<String, Report>: E.164 格式的手机号
+    pub fn normalize_mobile(&self, mobile: &str, default_country_code: &str) -> Result<String, Report> {
+        let invalid = || Report::msg("手机号格式不正确");
+
+        let compact: String = mobile.trim().chars().filter(|c| !matches!(c, ' ' | '-' | '.' | '(' | ')')).collect();
+        let digits = if let Some(rest) = compact.strip_prefix('+') {
+            rest.to_string()
+        } else if let Some(rest) = compact.strip_prefix("00") {
+            rest.to_string()
+        } else if default_country_code.is_empty() {
+            return Err(Report::msg("手机号需要包含国家码"));
+        } else {
+            let national = compact.strip_prefix('0').unwrap_or(&compact);
+            format!("{}{}", default_country_code, national)
+        };
+
+        if digits.len() < 8 || digits.len() > 15 || !digits.chars().all(|c| c.is_ascii_digit()) || digits.starts_with('0') {
+            return Err(invalid());
+        }
+        if let Some(national) = digits.strip_prefix("86") {
+            if national.len() != 11 || !national.starts_with('1') {
+                return Err(invalid());
+            }
+        }
+
+        Ok(format!("+{}", digits))
+    }
+
+    /// # Description
+    ///     日志中展示的手机号，只保留国家码与末四位
+    /// # Param
+    ///     mobile: &str - E.164 格式的手机号
+    /// # Return
+    ///     String: 脱敏后的手机号
+    pub fn mask_mobile(&self, mobile: &str) -> String {
+        let visible = mobile.len().saturating_sub(4);
+        let prefix = mobile.len().min(3);
+        if visible <= prefix {
+            return "*".repeat(mobile.len());
+        }
+
+        format!("{}{}{}", &mobile[..prefix], "*".repeat(visible - prefix), &mobile[visible..])
+    }
+
+    /// # Description
+    ///     按模板渲染短信
+    /// # Param
+    ///     app_name: &str - 程序名称，作为短信签名
+    ///     to: &str - E.164 格式的手机号
+    ///     template: &SmsTemplate - 模板
+    /// # Return
+    ///     SmsMessage: 短信
+    pub fn render(&self, app_name: &str, to: &str, template: &SmsTemplate) -> SmsMessage {
+        let text = match template {
+            SmsTemplate::RegisterCode { code, ttl_minutes } => format!(
+                "【{}】您的注册验证码为 {}，{} 分钟内有效，请勿告诉他人。", app_name, code, ttl_minutes
+            ),
+            SmsTemplate::LoginCode { code, ttl_minutes } => format!(
+                "【{}】您的登录验证码为 {}，{} 分钟内有效。如非本人操作，请忽略本短信。", app_name, code, ttl_minutes
+            ),
+            SmsTemplate::BindMobileCode { code, ttl_minutes } => format!(
+                "【{}】您正在绑定该手机号，验证码为 {}，{} 分钟内有效。如非本人操作，请忽略本短信。", app_name, code, ttl_minutes
+            ),
+        };
+
+        SmsMessage { to: to.to_string(), text }
+    }
+}
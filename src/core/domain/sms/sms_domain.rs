@@ -0,0 +1,20 @@
+use std::sync::Arc;
+use crate::core::domain::sms::service::SmsDomainService;
+
+pub struct SmsDomain {
+    pub domain_service: Arc<SmsDomainService>,
+}
+
+impl SmsDomain {
+    /// # Description
+    ///     初始化短信领域
+    /// # Return
+    ///     Self: 初始化后的短信领域实例
+    pub async fn new() -> Self {
+        let domain_service = Arc::new(SmsDomainService::new());
+
+        Self {
+            domain_service
+        }
+    }
+}
@@ -0,0 +1 @@
+pub mod stream_event_entity;
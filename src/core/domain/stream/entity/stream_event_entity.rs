@@ -0,0 +1,83 @@
+use std::str::FromStr;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// # Description
+///     推送主题
+/// # Param
+///     Balance: 钱包余额变化
+///     Transaction: 交易状态变化（充值入账、转出确认或失败、兑换成交等）
+///     Price: 价格变动
+///     System: 连接控制消息（订阅确认、积压提示、错误），始终推送且不可订阅
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum StreamTopic {
+    Balance,
+    Transaction,
+    Price,
+    System,
+}
+
+impl StreamTopic {
+    /// # Description
+    ///     客户端可以订阅的主题
+    pub const SUBSCRIBABLE: [StreamTopic; 3] = [StreamTopic::Balance, StreamTopic::Transaction, StreamTopic::Price];
+
+    /// # Description
+    ///     主题名称，与 JSON 中的取值一致
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            StreamTopic::Balance => "balance",
+            StreamTopic::Transaction => "transaction",
+            StreamTopic::Price => "price",
+            StreamTopic::System => "system",
+        }
+    }
+}
+
+impl FromStr for StreamTopic {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "balance" => Ok(StreamTopic::Balance),
+            "transaction" => Ok(StreamTopic::Transaction),
+            "price" => Ok(StreamTopic::Price),
+            "system" => Ok(StreamTopic::System),
+            other => Err(format!("未知的推送主题 {}", other)),
+        }
+    }
+}
+
+/// # Description
+///     推送给客户端的事件
+/// # Fields
+///     topic: StreamTopic - 主题
+///     event: String - 事件名称
+///     user_id: Option<i32> - 接收用户，为 None 时推送给所有订阅该主题的连接，不下发给客户端
+///     data: Value - 事件数据
+///     created_at: DateTime<Utc> - 事件时间
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct StreamEvent {
+    pub topic: StreamTopic,
+    pub event: String,
+    #[serde(skip_serializing)]
+    pub user_id: Option<i32>,
+    pub data: Value,
+    pub created_at: DateTime<Utc>,
+}
+
+/// # Description
+///     WebSocket 客户端发来的命令
+/// # Param
+///     Subscribe: 增加订阅主题
+///     Unsubscribe: 取消订阅主题
+///     Ping: 应用层心跳
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+#[serde(tag = "action", rename_all = "lowercase")]
+pub enum StreamCommand {
+    Subscribe { topics: Vec<StreamTopic> },
+    Unsubscribe { topics: Vec<StreamTopic> },
+    Ping,
+}
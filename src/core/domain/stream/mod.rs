@@ -0,0 +1,5 @@
+pub mod entity;
+pub mod service;
+mod stream_domain;
+
+pub use stream_domain::StreamDomain;
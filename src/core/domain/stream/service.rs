@@ -0,0 +1,119 @@
+use std::collections::HashSet;
+use chrono::Utc;
+use color_eyre::{Report, Result};
+use serde_json::{json, Value};
+use super::entity::stream_event_entity::{StreamCommand, StreamEvent, StreamTopic};
+
+/// # Description
+///     实时推送领域服务，负责主题解析、事件可见性与连接控制消息
+pub struct StreamDomainService;
+
+impl StreamDomainService {
+    /// # Description
+    ///     创建新的实时推送领域服务实例
+    /// # Return
+    ///     Self: 实时推送领域服务实例
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// # Description
+    ///     生成事件
+    /// # Param
+    ///     topic: StreamTopic - 主题
+    ///     user_id: Option<i32> - 接收用户，None 为公共事件
+    ///     event: &str - 事件名称
+    ///     data: Value - 事件数据
+    /// # Return
+    ///     StreamEvent: 事件
+    pub fn event(&self, topic: StreamTopic, user_id: Option<i32>, event: &str, data: Value) -> StreamEvent {
+        StreamEvent { topic, event: event.to_string(), user_id, data, created_at: Utc::now() }
+    }
+
+    /// # Description
+    ///     解析连接时指定的主题（逗号分隔），未指定时订阅全部主题
+    /// # Param
+    ///     topics: Option<&str> - 主题列表
+    /// # Return
+    ///     Result<HashSet<StreamTopic>, Report>: 订阅的主题
+    pub fn parse_topics(&self, topics: Option<&str>) -> Result<HashSet<StreamTopic>, Report> {
+        let topics: Vec<&str> = topics.unwrap_or_default()
+            .split(',')
+            .map(str::trim)
+            .filter(|topic| !topic.is_empty())
+            .collect();
+        if topics.is_empty() {
+            return Ok(StreamTopic::SUBSCRIBABLE.into_iter().collect());
+        }
+
+        let topics = topics.into_iter()
+            .map(|topic| topic.parse::<StreamTopic>().map_err(Report::msg))
+            .collect::<Result<Vec<StreamTopic>, Report>>()?;
+        self.subscribable(topics)
+    }
+
+    /// # Description
+    ///     执行客户端命令，返回回复给客户端的控制消息
+    /// # Param
+    ///     topics: &mut HashSet<StreamTopic> - 连接当前订阅的主题
+    ///     command: &str - 命令 JSON
+    /// # Return
+    ///     StreamEvent: 订阅确认、pong 或错误消息
+    pub fn apply_command(&self, topics: &mut HashSet<StreamTopic>, command: &str) -> StreamEvent {
+        let result = serde_json::from_str::<StreamCommand>(command)
+            .map_err(|e| Report::msg(format!("命令格式错误: {}", e)))
+            .and_then(|command| match command {
+                StreamCommand::Subscribe { topics: added } => {
+                    topics.extend(self.subscribable(added)?);
+                    Ok(self.subscribed(topics))
+                }
+                StreamCommand::Unsubscribe { topics: removed } => {
+                    for topic in self.subscribable(removed)? {
+                        topics.remove(&topic);
+                    }
+                    Ok(self.subscribed(topics))
+                }
+                StreamCommand::Ping => Ok(self.event(StreamTopic::System, None, "pong", json!({}))),
+            });
+
+        result.unwrap_or_else(|e| self.event(StreamTopic::System, None, "error", json!({ "message": e.to_string() })))
+    }
+
+    /// # Description
+    ///     判断事件是否推送给连接：控制消息始终推送，其余事件需订阅了主题且属于该用户或为公共事件
+    /// # Param
+    ///     event: &StreamEvent - 事件
+    ///     user_id: i32 - 连接所属用户
+    ///     topics: &HashSet<StreamTopic> - 连接订阅的主题
+    /// # Return
+    ///     bool
+    pub fn visible(&self, event: &StreamEvent, user_id: i32, topics: &HashSet<StreamTopic>) -> bool {
+        let owned = event.user_id.map_or(true, |owner| owner == user_id);
+        owned && (event.topic == StreamTopic::System || topics.contains(&event.topic))
+    }
+
+    /// # Description
+    ///     订阅确认消息，列出连接当前订阅的主题
+    pub fn subscribed(&self, topics: &HashSet<StreamTopic>) -> StreamEvent {
+        let mut topics: Vec<StreamTopic> = topics.iter().copied().collect();
+        topics.sort_by_key(|topic| StreamTopic::SUBSCRIBABLE.iter().position(|item| item == topic));
+        self.event(StreamTopic::System, None, "subscribed", json!({ "topics": topics }))
+    }
+
+    /// # Description
+    ///     积压提示：客户端消费过慢，服务端丢弃了最早的事件
+    /// # Param
+    ///     skipped: u64 - 丢弃的事件数量
+    pub fn lagged(&self, skipped: u64) -> StreamEvent {
+        self.event(StreamTopic::System, None, "lagged", json!({ "skipped": skipped }))
+    }
+
+    /// # Description
+    ///     校验主题均可订阅
+    fn subscribable(&self, topics: Vec<StreamTopic>) -> Result<HashSet<StreamTopic>, Report> {
+        if topics.contains(&StreamTopic::System) {
+            return Err(Report::msg("system 主题不可订阅"));
+        }
+        Ok(topics.into_iter().collect())
+    }
+}
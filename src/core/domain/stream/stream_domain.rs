@@ -0,0 +1,20 @@
+use std::sync::Arc;
+use crate::core::domain::stream::service::StreamDomainService;
+
+pub struct StreamDomain {
+    pub domain_service: Arc<StreamDomainService>,
+}
+
+impl StreamDomain {
+    /// # Description
+    ///     初始化实时推送领域
+    /// # Return
+    ///     Self: 初始化后的实时推送领域实例
+    pub async fn new() -> Self {
+        let domain_service = Arc::new(StreamDomainService::new());
+
+        Self {
+            domain_service
+        }
+    }
+}
@@ -0,0 +1 @@
+pub mod throttle_entity;
\ No newline at end of file
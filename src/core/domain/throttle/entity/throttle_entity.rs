@@ -0,0 +1,78 @@
+use std::fmt;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+/// # Description
+///     限流维度
+/// # Param
+///     Identifier: 按登录标识（账号、手机号或邮箱）统计
+///     Ip: 按客户端 IP 统计
+///     TwoFactor: 按用户统计两步验证失败，登录挑战与敏感操作再次验证共用
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ThrottleScope {
+    Identifier,
+    Ip,
+    TwoFactor,
+}
+
+impl ThrottleScope {
+    /// # Description
+    ///     存储键中使用的维度名称
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ThrottleScope::Identifier => "identifier",
+            ThrottleScope::Ip => "ip",
+            ThrottleScope::TwoFactor => "two_factor",
+        }
+    }
+}
+
+/// # Description
+///     单个维度的限流策略
+/// # Fields
+///     free_attempts: u32 - 不加延迟的失败次数
+///     base_delay_ms: u64 - 超出后的首次延迟（毫秒）
+///     max_delay_ms: u64 - 单次延迟上限（毫秒）
+///     max_failures: u32 - 窗口内允许的失败次数，达到后锁定
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ThrottlePolicy {
+    pub free_attempts: u32,
+    pub base_delay_ms: u64,
+    pub max_delay_ms: u64,
+    pub max_failures: u32,
+}
+
+/// # Description
+///     锁定事件，锁定发生时传给锁定通知钩子
+/// # Fields
+///     scope: ThrottleScope - 触发锁定的维度
+///     subject: String - 被锁定的登录标识或 IP
+///     failures: u32 - 窗口内的失败次数
+///     lockout_duration: u64 - 锁定时长（秒）
+///     locked_at: DateTime<Utc> - 锁定时间
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct LockoutEvent {
+    pub scope: ThrottleScope,
+    pub subject: String,
+    pub failures: u32,
+    pub lockout_duration: u64,
+    pub locked_at: DateTime<Utc>,
+}
+
+/// # Description
+///     登录被限流的错误，表现层据此返回 429 与 Retry-After
+/// # Fields
+///     retry_after: u64 - 距离解除锁定的秒数
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LoginThrottled {
+    pub retry_after: u64,
+}
+
+impl fmt::Display for LoginThrottled {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "登录失败次数过多，请 {} 秒后再试", self.retry_after)
+    }
+}
+
+impl std::error::Error for LoginThrottled {}
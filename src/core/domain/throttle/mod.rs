@@ -0,0 +1,6 @@
+pub mod entity;
+pub mod repository;
+pub mod service;
+mod throttle_domain;
+
+pub use throttle_domain::ThrottleDomain;
\ No newline at end of file
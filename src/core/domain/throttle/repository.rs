@@ -0,0 +1,21 @@
+use axum::async_trait;
+use color_eyre::{Report, Result};
+use crate::core::domain::throttle::entity::throttle_entity::LockoutEvent;
+
+/// # Description
+///     登录失败计数存储接口：窗口内失败次数与锁定标记，Redis 与进程内存两种实现
+#[async_trait]
+pub trait AttemptStoreInterface: Send + Sync {
+    async fn failures(&self, key: &str) -> Result<u32, Report>;
+    async fn record_failure(&self, key: &str, window: u64) -> Result<u32, Report>;
+    async fn lock(&self, key: &str, ttl: u64) -> Result<(), Report>;
+    async fn locked_for(&self, key: &str) -> Result<Option<u64>, Report>;
+    async fn clear(&self, key: &str) -> Result<(), Report>;
+}
+
+/// # Description
+///     锁定通知钩子，账号或 IP 被锁定时调用，例如写日志、通知用户或告警
+#[async_trait]
+pub trait LockoutHookInterface: Send + Sync {
+    async fn on_lockout(&self, event: &LockoutEvent) -> Result<(), Report>;
+}
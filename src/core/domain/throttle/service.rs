@@ -0,0 +1,64 @@
+use crate::core::domain::throttle::entity::throttle_entity::{ThrottlePolicy, ThrottleScope};
+
+/// # Description
+///     登录限流领域服务，负责存储键、渐进延迟与锁定判定
+pub struct ThrottleDomainService;
+
+impl ThrottleDomainService {
+    /// # Description
+    ///     创建新的登录限流领域服务实例
+    /// # Return
+    ///     Self: 登录限流领域服务实例
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// # Description
+    ///     失败计数的存储键
+    /// # Param
+    ///     scope: ThrottleScope - 限流维度
+    ///     subject: &str - 登录标识或 IP
+    /// # Return
+    ///     String: 存储键
+    pub fn failure_key(&self, scope: ThrottleScope, subject: &str) -> String {
+        format!("login:failures:{}:{}", scope.as_str(), subject)
+    }
+
+    /// # Description
+    ///     锁定标记的存储键
+    /// # Param
+    ///     scope: ThrottleScope - 限流维度
+    ///     subject: &str - 登录标识或 IP
+    /// # Return
+    ///     String: 存储键
+    pub fn lock_key(&self, scope: ThrottleScope, subject: &str) -> String {
+        format!("login:lock:{}:{}", scope.as_str(), subject)
+    }
+
+    /// # Description
+    ///     计算下一次登录前的延迟：免延迟次数内为 0，此后从基础延迟开始每次翻倍，不超过上限
+    /// # Param
+    ///     failures: u32 - 窗口内的失败次数
+    ///     policy: &ThrottlePolicy - 限流策略
+    /// # Return
+    ///     u64: 延迟（毫秒）
+    pub fn delay_ms(&self, failures: u32, policy: &ThrottlePolicy) -> u64 {
+        if failures < policy.free_attempts.max(1) || policy.base_delay_ms == 0 {
+            return 0;
+        }
+
+        let exponent = (failures - policy.free_attempts.max(1)).min(32);
+        policy.base_delay_ms.saturating_mul(1u64 << exponent).min(policy.max_delay_ms)
+    }
+
+    /// # Description
+    ///     失败次数是否达到锁定阈值，阈值为 0 表示不锁定
+    /// # Param
+    ///     failures: u32 - 窗口内的失败次数
+    ///     policy: &ThrottlePolicy - 限流策略
+    /// # Return
+    ///     bool: 是否锁定
+    pub fn should_lock(&self, failures: u32, policy: &ThrottlePolicy) -> bool {
+        policy.max_failures > 0 && failures >= policy.max_failures
+    }
+}
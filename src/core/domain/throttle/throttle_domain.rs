@@ -0,0 +1,20 @@
+use std::sync::Arc;
+use crate::core::domain::throttle::service::ThrottleDomainService;
+
+pub struct ThrottleDomain {
+    pub domain_service: Arc<ThrottleDomainService>,
+}
+
+impl ThrottleDomain {
+    /// # Description
+    ///     初始化登录限流领域
+    /// # Return
+    ///     Self: 初始化后的登录限流领域实例
+    pub async fn new() -> Self {
+        let domain_service = Arc::new(ThrottleDomainService::new());
+
+        Self {
+            domain_service
+        }
+    }
+}
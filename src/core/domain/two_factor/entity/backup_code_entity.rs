@@ -0,0 +1,50 @@
+use chrono::{DateTime, Utc};
+use sea_orm::{ActiveModelBehavior, ActiveValue, DeriveEntityModel, DerivePrimaryKey, DeriveRelation, EnumIter, PrimaryKeyTrait};
+use sea_orm::entity::prelude::*;
+use sea_orm::prelude::DateTimeUtc;
+use serde::{Deserialize, Serialize};
+
+/// # Description
+///     该结构体代表一枚两步验证备用恢复码，并映射到数据库中的 `two_factor_backup_code` 表。
+///     只保存恢复码的 SHA-256 摘要，每枚只能使用一次
+/// # Param
+///     id: 主键，自动递增
+///     user_id: 用户，关联 user 表
+///     code_hash: 恢复码摘要
+///     used_at: 使用时间
+///     created_at: 创建时间
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "two_factor_backup_code")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+    pub user_id: i32,
+    #[serde(skip_serializing)]
+    pub code_hash: String,
+    pub used_at: Option<DateTime<Utc>>,
+    pub created_at: DateTimeUtc,
+}
+
+impl Model {
+    /// # Description
+    ///     创建新的恢复码
+    /// # Param
+    ///     user_id: i32 - 用户id
+    ///     code_hash: String - 恢复码摘要
+    /// # Return
+    ///     ActiveModel
+    pub fn new(user_id: i32, code_hash: String) -> ActiveModel {
+        ActiveModel {
+            id: ActiveValue::NotSet,
+            user_id: ActiveValue::set(user_id),
+            code_hash: ActiveValue::set(code_hash),
+            used_at: ActiveValue::set(None),
+            created_at: ActiveValue::set(Utc::now()),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,2 @@
+pub mod two_factor_entity;
+pub mod backup_code_entity;
\ No newline at end of file
@@ -0,0 +1,61 @@
+use chrono::{DateTime, Utc};
+use sea_orm::{ActiveModelBehavior, ActiveValue, DeriveEntityModel, DerivePrimaryKey, DeriveRelation, EnumIter, PrimaryKeyTrait};
+use sea_orm::entity::prelude::*;
+use sea_orm::prelude::DateTimeUtc;
+use serde::{Deserialize, Serialize};
+
+/// # Description
+///     该结构体代表用户的 TOTP 两步验证设置，并映射到数据库中的 `user_two_factor` 表。
+///     每个用户最多一条记录，开启前为待确认状态，确认后才参与登录与敏感操作校验
+/// # Param
+///     id: 主键，自动递增
+///     user_id: 用户，关联 user 表
+///     secret: Base32 编码的 TOTP 密钥
+///     enabled: 是否已确认开启
+///     last_used_step: 最近一次通过校验的时间步，同一时间步的验证码不能重复使用
+///     enabled_at: 开启时间
+///     created_at: 创建时间
+///     updated_at: 更新时间
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "user_two_factor")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+    pub user_id: i32,
+    #[serde(skip_serializing)]
+    pub secret: String,
+    pub enabled: bool,
+    pub last_used_step: i64,
+    pub enabled_at: Option<DateTime<Utc>>,
+    pub created_at: DateTimeUtc,
+    pub updated_at: DateTimeUtc,
+}
+
+impl Model {
+    /// # Description
+    ///     创建待确认的两步验证设置
+    /// # Param
+    ///     user_id: i32 - 用户id
+    ///     secret: String - Base32 编码的 TOTP 密钥
+    /// # Return
+    ///     ActiveModel
+    pub fn new(user_id: i32, secret: String) -> ActiveModel {
+        let now_datetime = Utc::now();
+
+        ActiveModel {
+            id: ActiveValue::NotSet,
+            user_id: ActiveValue::set(user_id),
+            secret: ActiveValue::set(secret),
+            enabled: ActiveValue::set(false),
+            last_used_step: ActiveValue::set(0),
+            enabled_at: ActiveValue::set(None),
+            created_at: ActiveValue::set(now_datetime),
+            updated_at: ActiveValue::set(now_datetime),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
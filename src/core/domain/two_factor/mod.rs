@@ -0,0 +1,6 @@
+pub mod entity;
+pub mod repository;
+pub mod service;
+mod two_factor_domain;
+
+pub use two_factor_domain::TwoFactorDomain;
\ No newline at end of file
@@ -0,0 +1,21 @@
+use axum::async_trait;
+use color_eyre::{Report, Result};
+use crate::core::domain::two_factor::entity::{
+    two_factor_entity::{ActiveModel as TwoFactorActiveModel, Model as TwoFactorModel},
+    backup_code_entity::{ActiveModel as BackupCodeActiveModel, Model as BackupCodeModel},
+};
+
+/// # Description
+///     两步验证仓储接口
+#[async_trait]
+pub trait TwoFactorRepositoryInterface: Send + Sync {
+    async fn find_by_user(&self, user_id: i32) -> Result<Option<TwoFactorModel>, Report>;
+    async fn create_pending(&self, two_factor: TwoFactorActiveModel) -> Result<TwoFactorModel, Report>;
+    async fn enable(&self, id: i32, step: i64, backup_codes: Vec<BackupCodeActiveModel>) -> Result<bool, Report>;
+    async fn advance_step(&self, id: i32, step: i64) -> Result<bool, Report>;
+    async fn replace_backup_codes(&self, user_id: i32, backup_codes: Vec<BackupCodeActiveModel>) -> Result<(), Report>;
+    async fn find_backup_code(&self, user_id: i32, code_hash: &str) -> Result<Option<BackupCodeModel>, Report>;
+    async fn use_backup_code(&self, id: i32) -> Result<bool, Report>;
+    async fn count_backup_codes(&self, user_id: i32) -> Result<u64, Report>;
+    async fn remove(&self, user_id: i32) -> Result<(), Report>;
+}
@@ -0,0 +1,170 @@
+use color_eyre::{Report, Result};
+use data_encoding::BASE32_NOPAD;
+use hmac::{Hmac, Mac};
+use rand::Rng;
+use sha1::Sha1;
+
+/// # Description
+///     恢复码字符集，去掉了容易混淆的 0/o、1/l/i
+const BACKUP_CODE_ALPHABET: &[u8] = b"abcdefghjkmnpqrstuvwxyz23456789";
+
+/// # Description
+///     两步验证领域服务，负责 TOTP（RFC 6238）密钥、otpauth 链接、验证码校验与恢复码
+pub struct TwoFactorDomainService;
+
+impl TwoFactorDomainService {
+    /// # Description
+    ///     创建新的两步验证领域服务实例
+    /// # Return
+    ///     Self: 两步验证领域服务实例
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// # Description
+    ///     生成 160 位随机 TOTP 密钥
+    /// # Return
+    ///     String: Base32 编码（无填充）的密钥
+    pub fn generate_secret(&self) -> String {
+        let bytes: [u8; 20] = rand::thread_rng().gen();
+        BASE32_NOPAD.encode(&bytes)
+    }
+
+    /// # Description
+    ///     生成验证器应用扫码使用的 otpauth 链接
+    /// # Param
+    ///     issuer: &str - 发行方名称
+    ///     account: &str - 用户账号
+    ///     secret: &str - Base32 编码的密钥
+    ///     digits: u32 - 验证码位数
+    ///     period: u64 - 时间步长（秒）
+    /// # Return
+    ///     String: otpauth://totp/ 链接
+    pub fn otpauth_uri(&self, issuer: &str, account: &str, secret: &str, digits: u32, period: u64) -> String {
+        format!(
+            "otpauth://totp/{}:{}?secret={}&issuer={}&algorithm=SHA1&digits={}&period={}",
+            percent_encode(issuer), percent_encode(account), secret, percent_encode(issuer), digits, period
+        )
+    }
+
+    /// # Description
+    ///     计算指定时间步的验证码：HMAC-SHA1(密钥, 时间步) 动态截断后取模
+    /// # Param
+    ///     secret: &str - Base32 编码的密钥
+    ///     step: i64 - 时间步（Unix 秒 / 步长）
+    ///     digits: u32 - 验证码位数
+    /// # Return
+    ///     Result<String, Report>: 补零后的验证码
+    pub fn totp(&self, secret: &str, step: i64, digits: u32) -> Result<String, Report> {
+        let key = BASE32_NOPAD.decode(secret.trim_end_matches('=').to_uppercase().as_bytes())
+            .map_err(|_| Report::msg("两步验证密钥不合法"))?;
+        let mut mac = Hmac::<Sha1>::new_from_slice(&key)
+            .map_err(|e| Report::msg(format!("两步验证密钥不合法: {}", e)))?;
+        mac.update(&(step as u64).to_be_bytes());
+        let digest = mac.finalize().into_bytes();
+
+        let offset = (digest[digest.len() - 1] & 0x0f) as usize;
+        let binary = u32::from_be_bytes([digest[offset] & 0x7f, digest[offset + 1], digest[offset + 2], digest[offset + 3]]);
+
+        Ok(format!("{:0width$}", binary % 10u32.pow(digits), width = digits as usize))
+    }
+
+    /// # Description
+    ///     校验验证码，允许前后 skew 个时间步的时钟偏差；
+    ///     不晚于 last_used_step 的时间步视为已使用，防止同一验证码被重放
+    /// # Param
+    ///     secret: &str - Base32 编码的密钥
+    ///     code: &str - 用户输入的验证码
+    ///     now: i64 - 当前 Unix 秒
+    ///     period: u64 - 时间步长（秒）
+    ///     digits: u32 - 验证码位数
+    ///     skew: u64 - 允许偏差的时间步数
+    ///     last_used_step: i64 - 最近一次通过校验的时间步
+    /// # Return
+    ///     Result<Option<i64>, Report>: 通过时返回匹配的时间步
+    pub fn verify_totp(
+        &self,
+        secret: &str,
+        code: &str,
+        now: i64,
+        period: u64,
+        digits: u32,
+        skew: u64,
+        last_used_step: i64,
+    ) -> Result<Option<i64>, Report> {
+        let code = code.trim();
+        if !self.is_totp_code(code, digits) {
+            return Ok(None);
+        }
+
+        let current = now / period as i64;
+        let skew = skew as i64;
+        for step in (current - skew)..=(current + skew) {
+            if step <= last_used_step {
+                continue;
+            }
+            if constant_time_eq(self.totp(secret, step, digits)?.as_bytes(), code.as_bytes()) {
+                return Ok(Some(step));
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// # Description
+    ///     输入是否为 TOTP 验证码格式（指定位数的纯数字），否则按恢复码处理
+    /// # Param
+    ///     code: &str - 用户输入
+    ///     digits: u32 - 验证码位数
+    /// # Return
+    ///     bool: 是否为 TOTP 验证码格式
+    pub fn is_totp_code(&self, code: &str, digits: u32) -> bool {
+        let code = code.trim();
+        code.len() == digits as usize && code.chars().all(|c| c.is_ascii_digit())
+    }
+
+    /// # Description
+    ///     生成一组恢复码，格式为 xxxxx-xxxxx
+    /// # Param
+    ///     count: usize - 数量
+    /// # Return
+    ///     Vec<String>: 恢复码明文，只在生成时展示一次
+    pub fn generate_backup_codes(&self, count: usize) -> Vec<String> {
+        let mut rng = rand::thread_rng();
+        (0..count)
+            .map(|_| {
+                let chars: String = (0..10)
+                    .map(|_| char::from(BACKUP_CODE_ALPHABET[rng.gen_range(0..BACKUP_CODE_ALPHABET.len())]))
+                    .collect();
+                format!("{}-{}", &chars[..5], &chars[5..])
+            })
+            .collect()
+    }
+
+    /// # Description
+    ///     规范化用户输入的恢复码：忽略大小写、空格与连字符，摘要前使用
+    /// # Param
+    ///     code: &str - 用户输入的恢复码
+    /// # Return
+    ///     String: 规范化后的恢复码
+    pub fn normalize_backup_code(&self, code: &str) -> String {
+        code.chars().filter(|c| !matches!(c, '-' | ' ')).collect::<String>().to_lowercase()
+    }
+}
+
+/// # Description
+///     按 RFC 3986 对 otpauth 链接中的文本进行百分号编码
+fn percent_encode(value: &str) -> String {
+    value.bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => char::from(byte).to_string(),
+            _ => format!("%{:02X}", byte),
+        })
+        .collect()
+}
+
+/// # Description
+///     定长比较，避免按字节提前返回泄露匹配位数
+fn constant_time_eq(left: &[u8], right: &[u8]) -> bool {
+    left.len() == right.len() && left.iter().zip(right).fold(0u8, |acc, (a, b)| acc | (a ^ b)) == 0
+}
@@ -0,0 +1,20 @@
+use std::sync::Arc;
+use crate::core::domain::two_factor::service::TwoFactorDomainService;
+
+pub struct TwoFactorDomain {
+    pub domain_service: Arc<TwoFactorDomainService>,
+}
+
+impl TwoFactorDomain {
+    /// # Description
+    ///     初始化两步验证领域
+    /// # Return
+    ///     Self: 初始化后的两步验证领域实例
+    pub async fn new() -> Self {
+        let domain_service = Arc::new(TwoFactorDomainService::new());
+
+        Self {
+            domain_service
+        }
+    }
+}
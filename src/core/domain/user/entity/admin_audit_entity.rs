@@ -0,0 +1,82 @@
+use chrono::Utc;
+use sea_orm::{ActiveModelBehavior, ActiveValue, DeriveEntityModel, DerivePrimaryKey, DeriveRelation, EnumIter, PrimaryKeyTrait};
+use sea_orm::entity::prelude::*;
+use sea_orm::prelude::DateTimeUtc;
+use serde::{Deserialize, Serialize};
+
+/// # Description
+///     管理员对用户执行的操作
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AdminAction {
+    EnableUser,
+    DisableUser,
+    ChangeLevel,
+    AssignRoles,
+    ForcePasswordReset,
+    ViewWallets,
+    RestoreUser,
+}
+
+impl AdminAction {
+    /// # Description
+    ///     操作编码，写入审计记录的 action 字段
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AdminAction::EnableUser => "enable_user",
+            AdminAction::DisableUser => "disable_user",
+            AdminAction::ChangeLevel => "change_level",
+            AdminAction::AssignRoles => "assign_roles",
+            AdminAction::ForcePasswordReset => "force_password_reset",
+            AdminAction::ViewWallets => "view_wallets",
+            AdminAction::RestoreUser => "restore_user",
+        }
+    }
+}
+
+/// # Description
+///     该结构体代表管理员操作审计记录，并映射到数据库中的 `admin_audit_log` 表。
+/// # Param
+///     id: 主键，自动递增
+///     actor_id: 执行操作的管理员用户id
+///     action: 操作编码，见 AdminAction
+///     target_user_id: 被操作的用户id
+///     detail: 操作详情，JSON 文本
+///     created_at: 操作时间
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "admin_audit_log")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+    pub actor_id: i32,
+    pub action: String,
+    pub target_user_id: i32,
+    pub detail: Option<String>,
+    pub created_at: DateTimeUtc,
+}
+
+impl Model {
+    /// # Description
+    ///     创建新的审计记录
+    /// # Param
+    ///     actor_id: i32 - 执行操作的管理员用户id
+    ///     action: AdminAction - 操作
+    ///     target_user_id: i32 - 被操作的用户id
+    ///     detail: Option<String> - 操作详情
+    /// # Return
+    ///     ActiveModel
+    pub fn new(actor_id: i32, action: AdminAction, target_user_id: i32, detail: Option<String>) -> ActiveModel {
+        ActiveModel {
+            id: ActiveValue::NotSet,
+            actor_id: ActiveValue::set(actor_id),
+            action: ActiveValue::set(action.as_str().to_string()),
+            target_user_id: ActiveValue::set(target_user_id),
+            detail: ActiveValue::set(detail),
+            created_at: ActiveValue::set(Utc::now()),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
\ No newline at end of file
@@ -1,2 +1,6 @@
 pub mod user_entity;
-pub mod role_entity;
\ No newline at end of file
+pub mod role_entity;
+pub mod permission_entity;
+pub mod role_permission_entity;
+pub mod user_role_entity;
+pub mod admin_audit_entity;
\ No newline at end of file
@@ -0,0 +1,79 @@
+use chrono::Utc;
+use sea_orm::{ActiveModelBehavior, ActiveValue, DeriveEntityModel, DerivePrimaryKey, DeriveRelation, EnumIter, PrimaryKeyTrait};
+use sea_orm::entity::prelude::*;
+use sea_orm::prelude::DateTimeUtc;
+use serde::{Deserialize, Serialize};
+
+/// # Description
+///     角色与权限管理
+pub const RBAC_ADMIN: &str = "rbac:admin";
+
+/// # Description
+///     管理员 Webhook 端点与全局投递记录
+pub const WEBHOOK_ADMIN: &str = "webhook:admin";
+
+/// # Description
+///     索引数据运维，包括入账回滚记录
+pub const INDEXER_ADMIN: &str = "indexer:admin";
+
+/// # Description
+///     指令解码运维，包括从链上拉取并注册 Anchor IDL
+pub const INSTRUCTION_ADMIN: &str = "instruction:admin";
+
+/// # Description
+///     用户管理，包括启用禁用、级别角色调整、强制重置密码与恢复注销账号
+pub const USER_ADMIN: &str = "user:admin";
+
+/// # Description
+///     内置权限及说明，启动时写入 permission 表并全部授予内置的 admin 角色
+pub const BUILTIN_PERMISSIONS: &[(&str, &str)] = &[
+    (RBAC_ADMIN, "管理角色、权限与用户角色分配"),
+    (WEBHOOK_ADMIN, "管理管理员 Webhook 端点与投递记录"),
+    (INDEXER_ADMIN, "查看索引数据与入账回滚记录"),
+    (USER_ADMIN, "查询与管理用户账号，查看用户钱包与审计记录"),
+    (INSTRUCTION_ADMIN, "从链上拉取并注册 Anchor IDL"),
+];
+
+/// # Description
+///     内置管理员角色名
+pub const ADMIN_ROLE: &str = "admin";
+
+/// # Description
+///     该结构体代表权限，并映射到数据库中的 `permission` 表。
+/// # Param
+///     id: 主键，自动递增
+///     code: 权限编码，唯一，格式为 资源:操作
+///     description: 权限说明
+///     created_at: 创建时间
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "permission")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+    pub code: String,
+    pub description: Option<String>,
+    pub created_at: DateTimeUtc,
+}
+
+impl Model {
+    /// # Description
+    ///     创建新的权限
+    /// # Param
+    ///     code: &str - 权限编码
+    ///     description: Option<String> - 权限说明
+    /// # Return
+    ///     ActiveModel
+    pub fn new(code: &str, description: Option<String>) -> ActiveModel {
+        ActiveModel {
+            id: ActiveValue::NotSet,
+            code: ActiveValue::set(code.to_string()),
+            description: ActiveValue::set(description),
+            created_at: ActiveValue::set(Utc::now()),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
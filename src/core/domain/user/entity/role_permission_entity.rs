@@ -0,0 +1,45 @@
+use chrono::Utc;
+use sea_orm::{ActiveModelBehavior, ActiveValue, DeriveEntityModel, DerivePrimaryKey, DeriveRelation, EnumIter, PrimaryKeyTrait};
+use sea_orm::entity::prelude::*;
+use sea_orm::prelude::DateTimeUtc;
+use serde::{Deserialize, Serialize};
+
+/// # Description
+///     该结构体代表角色拥有的权限，并映射到数据库中的 `role_permission` 表。
+/// # Param
+///     id: 主键，自动递增
+///     role_id: 角色，关联 role 表
+///     permission_id: 权限，关联 permission 表
+///     created_at: 授权时间
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "role_permission")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+    pub role_id: i32,
+    pub permission_id: i32,
+    pub created_at: DateTimeUtc,
+}
+
+impl Model {
+    /// # Description
+    ///     为角色授予权限
+    /// # Param
+    ///     role_id: i32 - 角色id
+    ///     permission_id: i32 - 权限id
+    /// # Return
+    ///     ActiveModel
+    pub fn new(role_id: i32, permission_id: i32) -> ActiveModel {
+        ActiveModel {
+            id: ActiveValue::NotSet,
+            role_id: ActiveValue::set(role_id),
+            permission_id: ActiveValue::set(permission_id),
+            created_at: ActiveValue::set(Utc::now()),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -156,6 +156,14 @@ impl Model {
     pub fn soft_delete(&mut self) {
         self.deleted_at = Some(Utc::now());
     }
+
+    /// # Description
+    ///     恢复已软删除的用户
+    /// # Return
+    ///     ()
+    pub fn restore(&mut self) {
+        self.deleted_at = None;
+    }
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
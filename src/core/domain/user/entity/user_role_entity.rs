@@ -0,0 +1,45 @@
+use chrono::Utc;
+use sea_orm::{ActiveModelBehavior, ActiveValue, DeriveEntityModel, DerivePrimaryKey, DeriveRelation, EnumIter, PrimaryKeyTrait};
+use sea_orm::entity::prelude::*;
+use sea_orm::prelude::DateTimeUtc;
+use serde::{Deserialize, Serialize};
+
+/// # Description
+///     该结构体代表用户拥有的角色，并映射到数据库中的 `user_role` 表。
+/// # Param
+///     id: 主键，自动递增
+///     user_id: 用户，关联 user 表
+///     role_id: 角色，关联 role 表
+///     created_at: 分配时间
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "user_role")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+    pub user_id: i32,
+    pub role_id: i32,
+    pub created_at: DateTimeUtc,
+}
+
+impl Model {
+    /// # Description
+    ///     为用户分配角色
+    /// # Param
+    ///     user_id: i32 - 用户id
+    ///     role_id: i32 - 角色id
+    /// # Return
+    ///     ActiveModel
+    pub fn new(user_id: i32, role_id: i32) -> ActiveModel {
+        ActiveModel {
+            id: ActiveValue::NotSet,
+            user_id: ActiveValue::set(user_id),
+            role_id: ActiveValue::set(role_id),
+            created_at: ActiveValue::set(Utc::now()),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
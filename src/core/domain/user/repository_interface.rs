@@ -1,11 +1,68 @@
 use axum::async_trait;
+use chrono::{DateTime, Utc};
 use color_eyre::Report;
 use crate::core::domain::user::entity::user_entity::{ActiveModel, Model as UserModel};
+use crate::core::domain::user::entity::role_entity::{ActiveModel as RoleActiveModel, Model as RoleModel};
+use crate::core::domain::user::entity::permission_entity::{ActiveModel as PermissionActiveModel, Model as PermissionModel};
+use crate::core::domain::user::entity::admin_audit_entity::{ActiveModel as AdminAuditActiveModel, Model as AdminAuditModel};
+
+/// # Description
+///     管理后台查询用户的过滤条件，None 表示不按该条件过滤
+/// # Fields
+///     keyword: Option<String> - 按账号、邮箱、手机号或昵称模糊匹配
+///     disabled: Option<bool> - 是否已禁用
+///     level: Option<i8> - 用户级别
+///     reg_type: Option<i8> - 注册类型
+///     created_from: Option<DateTime<Utc>> - 注册时间下限（含）
+///     created_to: Option<DateTime<Utc>> - 注册时间上限（含）
+///     deleted: Option<bool> - 是否已注销（软删除）
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct UserFilter {
+    pub keyword: Option<String>,
+    pub disabled: Option<bool>,
+    pub level: Option<i8>,
+    pub reg_type: Option<i8>,
+    pub created_from: Option<DateTime<Utc>>,
+    pub created_to: Option<DateTime<Utc>>,
+    pub deleted: Option<bool>,
+}
 
 #[async_trait]
 pub trait UserRepositoryInterface: Send + Sync {
     async fn save(&self, user: ActiveModel) -> Result<(), Report>;
+    async fn save_audited(&self, user: ActiveModel, audit: AdminAuditActiveModel) -> Result<(), Report>;
     async fn find_by_id(&self, id: i32) -> Result<Option<UserModel>, Report>;
     async fn find_by_account(&self, account: String) -> Result<Option<UserModel>, Report>;
     async fn find_by_mobile_or_email_account(&self, identifier: String) -> Result<Option<UserModel>, Report>;
+    async fn search(&self, filter: &UserFilter, offset: u64, limit: u64) -> Result<Vec<UserModel>, Report>;
+    async fn count(&self, filter: &UserFilter) -> Result<u64, Report>;
+}
+
+/// # Description
+///     角色与权限仓储接口
+#[async_trait]
+pub trait RoleRepositoryInterface: Send + Sync {
+    async fn find_roles(&self) -> Result<Vec<RoleModel>, Report>;
+    async fn find_role(&self, id: i32) -> Result<Option<RoleModel>, Report>;
+    async fn find_role_by_name(&self, name: &str) -> Result<Option<RoleModel>, Report>;
+    async fn find_roles_by_names(&self, names: &[String]) -> Result<Vec<RoleModel>, Report>;
+    async fn save_role(&self, role: RoleActiveModel) -> Result<RoleModel, Report>;
+    async fn delete_role(&self, id: i32) -> Result<(), Report>;
+    async fn find_permissions(&self) -> Result<Vec<PermissionModel>, Report>;
+    async fn find_permissions_by_codes(&self, codes: &[String]) -> Result<Vec<PermissionModel>, Report>;
+    async fn save_permission(&self, permission: PermissionActiveModel) -> Result<PermissionModel, Report>;
+    async fn find_role_permissions(&self, role_id: i32) -> Result<Vec<PermissionModel>, Report>;
+    async fn set_role_permissions(&self, role_id: i32, permission_ids: Vec<i32>) -> Result<(), Report>;
+    async fn find_user_roles(&self, user_id: i32) -> Result<Vec<RoleModel>, Report>;
+    async fn set_user_roles(&self, user_id: i32, role_ids: Vec<i32>, audit: Option<AdminAuditActiveModel>) -> Result<(), Report>;
+    async fn find_user_permissions(&self, user_id: i32) -> Result<Vec<String>, Report>;
+}
+
+/// # Description
+///     管理员操作审计仓储接口
+#[async_trait]
+pub trait AdminAuditRepositoryInterface: Send + Sync {
+    async fn save(&self, entry: AdminAuditActiveModel) -> Result<(), Report>;
+    async fn find_by_target(&self, target_user_id: i32, offset: u64, limit: u64) -> Result<Vec<AdminAuditModel>, Report>;
+    async fn count_by_target(&self, target_user_id: i32) -> Result<u64, Report>;
 }
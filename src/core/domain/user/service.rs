@@ -1,7 +1,21 @@
 use color_eyre::{Report, Result};
 use crate::core::domain::user::{
     entity::user_entity::{ActiveModel as UserActiveModel, Model as UserModel},
+    repository_interface::UserFilter,
 };
+use crate::core::domain::wallet::entity::Model as WalletModel;
+
+/// # Description
+///     昵称的最大长度（字符）
+const MAX_NICKNAME_LENGTH: usize = 32;
+
+/// # Description
+///     头像地址的最大长度
+const MAX_AVATAR_LENGTH: usize = 255;
+
+/// # Description
+///     管理后台搜索关键字的最大长度（字符）
+const MAX_KEYWORD_LENGTH: usize = 64;
 
 /// # Description
 ///     用户领域服务，处理与用户相关的应用逻辑
@@ -12,7 +26,7 @@ impl UserDomainService {
     ///     创建新的用户服务
     /// # Return
     ///     UserService: 用户服务实例
-    pub async fn new() -> Self {
+    pub fn new() -> Self {
         Self {}
     }
 
@@ -49,18 +63,198 @@ impl UserDomainService {
     }
 
     /// # Description
-    ///     为用户分配角色
+    ///     恢复已注销（软删除）的用户，未注销的用户不能恢复
     /// # Param
-    ///     mut user: UserModel - 要设置的用户
-    ///     role: String - 要设置的角色名称
+    ///     mut user: UserModel - 要恢复的用户
     /// # Return
-    ///      Result<UserModel, Report>: 成功返回新的 UserModel，失败返回错误信息
-    pub fn assign_role(&self, mut user: UserModel, role: String) -> Result<UserModel, Report> {
-        user.level = match role.as_str() {
-            "admin" => 1,
-            "user" => 0,
-            _ => return Err(Report::msg("无效的角色")),
-        };
+    ///     Result<UserModel, Report>: 成功返回恢复后的 UserModel，失败返回错误信息
+    pub fn restore_user(&self, mut user: UserModel) -> Result<UserModel, Report> {
+        if user.deleted_at.is_none() {
+            return Err(Report::msg("账号未注销，无需恢复"));
+        }
+        user.restore();
         Ok(user)
     }
+
+    /// # Description
+    ///     校验用户级别在允许的范围内
+    /// # Param
+    ///     level: i8 - 新的用户级别
+    ///     min_level: i8 - 最低级别
+    ///     max_level: i8 - 最高级别
+    /// # Return
+    ///     Result<(), Report>: 通过返回 Ok()，否则返回原因
+    pub fn validate_level(&self, level: i8, min_level: i8, max_level: i8) -> Result<(), Report> {
+        if level < min_level || level > max_level {
+            return Err(Report::msg(format!("用户级别必须为 {} 到 {}", min_level, max_level)));
+        }
+        Ok(())
+    }
+
+    /// # Description
+    ///     校验并规范化管理后台的用户过滤条件：关键字去掉首尾空白，空关键字视为不过滤，
+    ///     注册时间下限不能晚于上限
+    /// # Param
+    ///     filter: UserFilter - 过滤条件
+    /// # Return
+    ///     Result<UserFilter, Report>: 规范化后的过滤条件
+    pub fn normalize_user_filter(&self, mut filter: UserFilter) -> Result<UserFilter, Report> {
+        filter.keyword = filter.keyword
+            .map(|keyword| keyword.trim().to_string())
+            .filter(|keyword| !keyword.is_empty());
+        if let Some(keyword) = &filter.keyword {
+            if keyword.chars().count() > MAX_KEYWORD_LENGTH {
+                return Err(Report::msg(format!("搜索关键字不能超过 {} 个字符", MAX_KEYWORD_LENGTH)));
+            }
+        }
+        if let (Some(from), Some(to)) = (filter.created_from, filter.created_to) {
+            if from > to {
+                return Err(Report::msg("注册时间下限不能晚于上限"));
+            }
+        }
+        Ok(filter)
+    }
+
+    /// # Description
+    ///     校验用户可以正常使用：已禁用或已注销（软删除）的用户不能通过认证
+    /// # Param
+    ///     user: &UserModel - 要校验的用户
+    /// # Return
+    ///     Result<(), Report>: 可用返回 Ok()，否则返回原因
+    pub fn ensure_active(&self, user: &UserModel) -> Result<(), Report> {
+        if user.deleted_at.is_some() {
+            return Err(Report::msg("账号已注销"));
+        }
+        if user.disable {
+            return Err(Report::msg("账号已被禁用"));
+        }
+        Ok(())
+    }
+
+    /// # Description
+    ///     校验新密码强度：8-72 位（bcrypt 只使用前 72 字节），且同时包含字母和数字
+    /// # Param
+    ///     password: &str - 密码明文
+    /// # Return
+    ///     Result<(), Report>: 通过返回 Ok()，否则返回原因
+    pub fn validate_password(&self, password: &str) -> Result<(), Report> {
+        if password.len() < 8 || password.len() > 72 {
+            return Err(Report::msg("密码长度必须为 8-72 位"));
+        }
+        if !password.chars().any(|c| c.is_ascii_alphabetic()) || !password.chars().any(|c| c.is_ascii_digit()) {
+            return Err(Report::msg("密码必须同时包含字母和数字"));
+        }
+        Ok(())
+    }
+
+    /// # Description
+    ///     校验并规范化昵称：去掉首尾空白，1-32 个字符，不能包含控制字符
+    /// # Param
+    ///     nickname: &str - 昵称
+    /// # Return
+    ///     Result<String, Report>: 规范化后的昵称
+    pub fn normalize_nickname(&self, nickname: &str) -> Result<String, Report> {
+        let nickname = nickname.trim();
+        if nickname.is_empty() || nickname.chars().count() > MAX_NICKNAME_LENGTH {
+            return Err(Report::msg(format!("昵称长度必须为 1-{} 个字符", MAX_NICKNAME_LENGTH)));
+        }
+        if nickname.chars().any(char::is_control) {
+            return Err(Report::msg("昵称不能包含控制字符"));
+        }
+        Ok(nickname.to_string())
+    }
+
+    /// # Description
+    ///     校验头像地址：http(s) 链接，不超过 255 个字符
+    /// # Param
+    ///     avatar: &str - 头像地址
+    /// # Return
+    ///     Result<String, Report>: 规范化后的头像地址
+    pub fn normalize_avatar(&self, avatar: &str) -> Result<String, Report> {
+        let avatar = avatar.trim();
+        let valid_scheme = avatar.starts_with("https://") || avatar.starts_with("http://");
+        if !valid_scheme || avatar.len() > MAX_AVATAR_LENGTH || avatar.chars().any(char::is_whitespace) {
+            return Err(Report::msg(format!("头像必须是不超过 {} 个字符的 http(s) 链接", MAX_AVATAR_LENGTH)));
+        }
+        Ok(avatar.to_string())
+    }
+
+    /// # Description
+    ///     校验账号能否注销：名下未删除的钱包必须全部没有余额，链上也不能留有 SOL 或 SPL 代币，避免注销后资产无人认领
+    /// # Param
+    ///     wallets: &[WalletModel] - 用户名下的钱包
+    ///     holdings: &[(String, f64)] - 钱包在链上的非零持仓（资产名称或 mint 地址，数量）
+    /// # Return
+    ///     Result<(), Report>: 可以注销返回 Ok()，否则返回原因
+    pub fn ensure_deletable(&self, wallets: &[WalletModel], holdings: &[(String, f64)]) -> Result<(), Report> {
+        let balance: f64 = wallets.iter()
+            .filter(|wallet| wallet.deleted_at.is_none())
+            .map(|wallet| wallet.balance)
+            .filter(|balance| *balance > 0.0)
+            .sum();
+        if balance > 0.0 {
+            return Err(Report::msg(format!("钱包中仍有 {} SOL 余额，请先提现后再注销账号", balance)));
+        }
+
+        let holdings: Vec<String> = holdings.iter()
+            .filter(|(_, amount)| *amount > 0.0)
+            .map(|(asset, amount)| format!("{} {}", amount, asset))
+            .collect();
+        if !holdings.is_empty() {
+            return Err(Report::msg(format!("钱包链上仍有资产 {}，请先转出后再注销账号", holdings.join(", "))));
+        }
+        Ok(())
+    }
+
+    /// # Description
+    ///     校验并规范化角色名：2-32 位小写字母、数字、下划线或连字符
+    /// # Param
+    ///     name: &str - 角色名
+    /// # Return
+    ///     Result<String, Report>: 规范化后的角色名
+    pub fn normalize_role_name(&self, name: &str) -> Result<String, Report> {
+        let name = name.trim().to_lowercase();
+        let valid_char = |c: char| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_' || c == '-';
+        if name.len() < 2 || name.len() > 32 || !name.chars().all(valid_char) {
+            return Err(Report::msg("角色名必须为 2-32 位小写字母、数字、下划线或连字符"));
+        }
+        Ok(name)
+    }
+
+    /// # Description
+    ///     校验并规范化权限编码，格式为 资源:操作，如 webhook:admin
+    /// # Param
+    ///     code: &str - 权限编码
+    /// # Return
+    ///     Result<String, Report>: 规范化后的权限编码
+    pub fn normalize_permission_code(&self, code: &str) -> Result<String, Report> {
+        let code = code.trim().to_lowercase();
+        let valid_part = |part: &str| !part.is_empty() && part.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_' || c == '-');
+        match code.split_once(':') {
+            Some((resource, action)) if code.len() <= 64 && valid_part(resource) && valid_part(action) => Ok(code),
+            _ => Err(Report::msg("权限编码格式必须为 资源:操作")),
+        }
+    }
+
+    /// # Description
+    ///     检查权限集合是否包含所需权限
+    /// # Param
+    ///     permissions: &[String] - 用户经由角色获得的权限编码
+    ///     required: &str - 所需权限
+    /// # Return
+    ///     bool: 是否拥有
+    pub fn has_permission(&self, permissions: &[String], required: &str) -> bool {
+        permissions.iter().any(|permission| permission == required)
+    }
+
+    /// # Description
+    ///     找出请求中不存在的名称，用于提示未知的角色或权限
+    /// # Param
+    ///     requested: &[String] - 请求的名称
+    ///     found: &[String] - 已存在的名称
+    /// # Return
+    ///     Vec<String>: 不存在的名称
+    pub fn missing(&self, requested: &[String], found: &[String]) -> Vec<String> {
+        requested.iter().filter(|name| !found.contains(name)).cloned().collect()
+    }
 }
@@ -14,7 +14,7 @@ impl UserDomain {
     ///     Self: 初始化后的用户领域实例
     pub async fn new() -> Self {
         // 初始化用户服务并注入仓库
-        let domain_service = Arc::new(UserDomainService::new().await);
+        let domain_service = Arc::new(UserDomainService::new());
 
         Self {
             domain_service: domain_service.clone()
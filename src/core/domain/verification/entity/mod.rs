@@ -0,0 +1 @@
+pub mod verification_code_entity;
\ No newline at end of file
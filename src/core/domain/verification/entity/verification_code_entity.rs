@@ -0,0 +1,121 @@
+use chrono::{DateTime, Duration, Utc};
+use sea_orm::{ActiveModelBehavior, ActiveValue, DeriveEntityModel, DerivePrimaryKey, DeriveRelation, EnumIter, PrimaryKeyTrait};
+use sea_orm::entity::prelude::*;
+use sea_orm::prelude::DateTimeUtc;
+use serde::{Deserialize, Serialize};
+
+/// # Description
+///     验证码的发送渠道
+/// # Param
+///     Email: 邮件
+///     Sms: 短信
+///     Totp: 两步验证（验证器应用），仅用于登录挑战，不发送消息
+#[derive(Clone, Copy, Debug, PartialEq, Eq, EnumIter, DeriveActiveEnum, Serialize, Deserialize)]
+#[sea_orm(rs_type = "String", db_type = "String(StringLen::N(16))")]
+#[serde(rename_all = "snake_case")]
+pub enum VerificationChannel {
+    #[sea_orm(string_value = "email")]
+    Email,
+    #[sea_orm(string_value = "sms")]
+    Sms,
+    #[sea_orm(string_value = "totp")]
+    Totp,
+}
+
+/// # Description
+///     验证码的用途，不同用途的验证码互不通用
+/// # Param
+///     Register: 注册时验证邮箱或手机号
+///     ResetPassword: 忘记密码时的重置令牌
+///     Login: 短信验证码登录
+///     TwoFactorLogin: 密码通过后等待两步验证的登录挑战
+///     BindMobile: 修改资料时验证新手机号
+#[derive(Clone, Copy, Debug, PartialEq, Eq, EnumIter, DeriveActiveEnum, Serialize, Deserialize)]
+#[sea_orm(rs_type = "String", db_type = "String(StringLen::N(32))")]
+#[serde(rename_all = "snake_case")]
+pub enum VerificationPurpose {
+    #[sea_orm(string_value = "register")]
+    Register,
+    #[sea_orm(string_value = "reset_password")]
+    ResetPassword,
+    #[sea_orm(string_value = "login")]
+    Login,
+    #[sea_orm(string_value = "two_factor_login")]
+    TwoFactorLogin,
+    #[sea_orm(string_value = "bind_mobile")]
+    BindMobile,
+}
+
+/// # Description
+///     该结构体代表一次发出的验证码或重置令牌，并映射到数据库中的 `verification_code` 表。
+///     只保存验证码的 SHA-256 摘要，明文只出现在发给用户的消息中
+/// # Param
+///     id: 主键，自动递增
+///     channel: 发送渠道
+///     target: 接收方（邮箱、E.164 手机号，登录挑战为用户id）
+///     purpose: 用途
+///     code_hash: 验证码摘要
+///     attempts: 已校验失败次数
+///     expires_at: 过期时间
+///     consumed_at: 使用或作废时间
+///     created_at: 创建时间
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "verification_code")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+    pub channel: VerificationChannel,
+    pub target: String,
+    pub purpose: VerificationPurpose,
+    #[serde(skip_serializing)]
+    pub code_hash: String,
+    pub attempts: i32,
+    pub expires_at: DateTime<Utc>,
+    pub consumed_at: Option<DateTime<Utc>>,
+    pub created_at: DateTimeUtc,
+}
+
+impl Model {
+    /// # Description
+    ///     创建新的验证码记录
+    /// # Param
+    ///     channel: VerificationChannel - 发送渠道
+    ///     target: String - 接收方
+    ///     purpose: VerificationPurpose - 用途
+    ///     code_hash: String - 验证码摘要
+    ///     ttl: u64 - 有效期（秒）
+    /// # Return
+    ///     ActiveModel
+    pub fn new(channel: VerificationChannel, target: String, purpose: VerificationPurpose, code_hash: String, ttl: u64) -> ActiveModel {
+        let now_datetime = Utc::now();
+
+        ActiveModel {
+            id: ActiveValue::NotSet,
+            channel: ActiveValue::set(channel),
+            target: ActiveValue::set(target),
+            purpose: ActiveValue::set(purpose),
+            code_hash: ActiveValue::set(code_hash),
+            attempts: ActiveValue::set(0),
+            expires_at: ActiveValue::set(now_datetime + Duration::seconds(ttl as i64)),
+            consumed_at: ActiveValue::set(None),
+            created_at: ActiveValue::set(now_datetime),
+        }
+    }
+
+    /// # Description
+    ///     是否已过期
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        self.expires_at <= now
+    }
+
+    /// # Description
+    ///     是否已使用或已作废
+    pub fn is_consumed(&self) -> bool {
+        self.consumed_at.is_some()
+    }
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
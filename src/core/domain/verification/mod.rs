@@ -0,0 +1,6 @@
+pub mod entity;
+pub mod repository;
+pub mod service;
+mod verification_domain;
+
+pub use verification_domain::VerificationDomain;
\ No newline at end of file
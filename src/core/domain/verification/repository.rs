@@ -0,0 +1,16 @@
+use axum::async_trait;
+use color_eyre::{Report, Result};
+use crate::core::domain::verification::entity::verification_code_entity::{
+    ActiveModel as VerificationCodeActiveModel, Model as VerificationCodeModel, VerificationChannel, VerificationPurpose
+};
+
+/// # Description
+///     验证码仓储接口
+#[async_trait]
+pub trait VerificationRepositoryInterface: Send + Sync {
+    async fn find_latest(&self, channel: VerificationChannel, target: &str, purpose: VerificationPurpose) -> Result<Option<VerificationCodeModel>, Report>;
+    async fn find_by_hash(&self, channel: VerificationChannel, purpose: VerificationPurpose, code_hash: &str) -> Result<Option<VerificationCodeModel>, Report>;
+    async fn issue(&self, code: VerificationCodeActiveModel) -> Result<VerificationCodeModel, Report>;
+    async fn record_failure(&self, id: i32) -> Result<(), Report>;
+    async fn consume(&self, id: i32) -> Result<bool, Report>;
+}
@@ -0,0 +1,101 @@
+use chrono::{DateTime, Utc};
+use rand::Rng;
+use sha2::{Digest, Sha256};
+use super::entity::verification_code_entity::Model as VerificationCodeModel;
+
+/// # Description
+///     验证码校验结果
+/// # Param
+///     Passed: 校验通过
+///     Mismatch(i32): 验证码错误，附剩余可尝试次数
+///     Expired: 已过期、已使用或已被新验证码作废
+///     Exhausted: 错误次数已达上限
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum VerificationCheck {
+    Passed,
+    Mismatch(i32),
+    Expired,
+    Exhausted,
+}
+
+/// # Description
+///     验证码领域服务，负责验证码与重置令牌的生成、摘要、发送间隔与校验判定
+pub struct VerificationDomainService;
+
+impl VerificationDomainService {
+    /// # Description
+    ///     创建新的验证码领域服务实例
+    /// # Return
+    ///     Self: 验证码领域服务实例
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// # Description
+    ///     生成数字验证码
+    /// # Param
+    ///     length: usize - 位数
+    /// # Return
+    ///     String: 验证码
+    pub fn generate_code(&self, length: usize) -> String {
+        let mut rng = rand::thread_rng();
+        (0..length.max(4)).map(|_| char::from(b'0' + rng.gen_range(0..10))).collect()
+    }
+
+    /// # Description
+    ///     生成一次性令牌（32 字节随机数的十六进制），用于邮件中的重置链接
+    /// # Return
+    ///     String: 令牌
+    pub fn generate_token(&self) -> String {
+        let mut rng = rand::thread_rng();
+        (0..32).map(|_| format!("{:02x}", rng.gen::<u8>())).collect()
+    }
+
+    /// # Description
+    ///     验证码摘要，数据库只保存摘要
+    /// # Param
+    ///     secret: &str - 验证码或令牌明文
+    /// # Return
+    ///     String: SHA-256 十六进制摘要
+    pub fn digest(&self, secret: &str) -> String {
+        Sha256::digest(secret.trim().as_bytes()).iter().map(|byte| format!("{:02x}", byte)).collect()
+    }
+
+    /// # Description
+    ///     距离可以再次发送还需等待的秒数
+    /// # Param
+    ///     latest: Option<&VerificationCodeModel> - 最近一次发出的验证码
+    ///     now: DateTime<Utc> - 当前时间
+    ///     interval: u64 - 最小发送间隔（秒）
+    /// # Return
+    ///     Option<i64>: 需要等待的秒数，None 表示可以发送
+    pub fn cooldown(&self, latest: Option<&VerificationCodeModel>, now: DateTime<Utc>, interval: u64) -> Option<i64> {
+        let elapsed = (now - latest?.created_at).num_seconds();
+        let remaining = interval as i64 - elapsed;
+
+        (remaining > 0).then_some(remaining)
+    }
+
+    /// # Description
+    ///     校验用户输入的验证码
+    /// # Param
+    ///     code: &VerificationCodeModel - 最近一次发出的验证码
+    ///     input: &str - 用户输入
+    ///     now: DateTime<Utc> - 当前时间
+    ///     max_attempts: i32 - 最多允许的错误次数
+    /// # Return
+    ///     VerificationCheck: 校验结果
+    pub fn check(&self, code: &VerificationCodeModel, input: &str, now: DateTime<Utc>, max_attempts: i32) -> VerificationCheck {
+        if code.is_consumed() || code.is_expired(now) {
+            return VerificationCheck::Expired;
+        }
+        if code.attempts >= max_attempts {
+            return VerificationCheck::Exhausted;
+        }
+        if code.code_hash != self.digest(input) {
+            return VerificationCheck::Mismatch(max_attempts - code.attempts - 1);
+        }
+
+        VerificationCheck::Passed
+    }
+}
@@ -0,0 +1,20 @@
+use std::sync::Arc;
+use crate::core::domain::verification::service::VerificationDomainService;
+
+pub struct VerificationDomain {
+    pub domain_service: Arc<VerificationDomainService>,
+}
+
+impl VerificationDomain {
+    /// # Description
+    ///     初始化验证码领域
+    /// # Return
+    ///     Self: 初始化后的验证码领域实例
+    pub async fn new() -> Self {
+        let domain_service = Arc::new(VerificationDomainService::new());
+
+        Self {
+            domain_service
+        }
+    }
+}
@@ -34,7 +34,7 @@ impl From<WalletAddress> for String {
 ///     id: 主键，自动递增
 ///     user_id: 钱包拥有者，关联 user 表
 ///     pub_key: 钱包公钥
-///     privy_key: 钱包助记词
+///     privy_key: 钱包私钥，以主密钥加密后保存
 ///     balance: 钱包余额
 ///     disable: 钱包禁用状态
 ///     created_at: 创建时间
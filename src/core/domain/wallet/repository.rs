@@ -7,5 +7,8 @@ use crate::core::domain::wallet::entity::{ActiveModel as WalletActiveModel, Mode
 #[async_trait]
 pub trait WalletRepositoryInterface {
     async fn find_by_id(&self, id: u64) -> Result<Option<WalletModel>, Report>;
+    async fn find_by_user(&self, user_id: i32) -> Result<Vec<WalletModel>, Report>;
+    async fn find_active(&self) -> Result<Vec<WalletModel>, Report>;
+    async fn find_unsealed(&self) -> Result<Vec<WalletModel>, Report>;
     async fn save(&self, wallet: WalletActiveModel) -> Result<(), Report>;
 }
@@ -63,11 +63,11 @@ impl WalletDomainService {
     /// # Description
     ///     为钱包添加金额
     /// # Param
-    ///     id: u64: 钱包的唯一标识符
+    ///     wallet: &mut WalletModel: 需要入账的钱包
     ///     amount: f64: 要添加的金额
     /// # Return
     ///     Result<(), String>: 处理结果
-    pub fn deposit(&self, mut wallet: WalletModel, amount: f64) -> Result<(), Report> {
+    pub fn deposit(&self, wallet: &mut WalletModel, amount: f64) -> Result<(), Report> {
         wallet.update_balance(amount).map_err(|_e| {Report::msg("更新金额失败")})?;
 
         Ok(())
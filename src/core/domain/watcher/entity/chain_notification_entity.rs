@@ -0,0 +1,40 @@
+use serde::Serialize;
+
+/// # Description
+///     链上通知的来源
+/// # Param
+///     PubSub: WebSocket 订阅推送
+///     Polling: 连接断开期间的轮询补偿
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum NotificationSource {
+    PubSub,
+    Polling,
+}
+
+/// # Description
+///     统一的链上通知，WebSocket 推送与轮询结果都转换为该结构后再分发
+/// # Param
+///     Account: accountSubscribe，托管地址余额发生变化
+///     Logs: logsSubscribe，有交易涉及托管地址
+///     Signature: signatureSubscribe，被跟踪的交易已达到目标确认级别
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum ChainNotification {
+    Account {
+        address: String,
+        lamports: u64,
+        slot: u64,
+    },
+    Logs {
+        address: String,
+        signature: String,
+        slot: u64,
+        failed: bool,
+    },
+    Signature {
+        signature: String,
+        slot: u64,
+        error: Option<String>,
+    },
+}
@@ -0,0 +1,69 @@
+use chrono::Utc;
+use sea_orm::{ActiveModelBehavior, ActiveValue, DeriveEntityModel, DerivePrimaryKey, DeriveRelation, EnumIter, PrimaryKeyTrait};
+use sea_orm::entity::prelude::*;
+use sea_orm::prelude::DateTimeUtc;
+use serde::{Deserialize, Serialize};
+use crate::core::domain::reorg::entity::commitment_entity::CommitmentLevel;
+
+/// # Description
+///     该结构体代表一笔已入账的充值，并映射到数据库中的 `deposit` 表。
+///     同一钱包同一交易签名只入账一次，推送与轮询重复发现时据此去重
+/// # Param
+///     id: 主键，自动递增
+///     wallet_id: 入账钱包
+///     user_id: 钱包所属用户
+///     address: 托管地址
+///     signature: 交易签名
+///     slot: 交易所在 slot
+///     lamports: 入账金额（lamports）
+///     amount: 入账金额（SOL）
+///     commitment: 确认级别，按 confirmed 入账，最终确认后提升为 finalized，回滚后为 dropped
+///     created_at: 入账时间
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "deposit")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+    pub wallet_id: i32,
+    pub user_id: i32,
+    pub address: String,
+    pub signature: String,
+    pub slot: i64,
+    pub lamports: i64,
+    pub amount: f64,
+    pub commitment: CommitmentLevel,
+    pub created_at: DateTimeUtc,
+}
+
+impl Model {
+    /// # Description
+    ///     创建新的充值记录
+    /// # Param
+    ///     wallet_id: i32 - 入账钱包
+    ///     user_id: i32 - 钱包所属用户
+    ///     address: String - 托管地址
+    ///     signature: String - 交易签名
+    ///     slot: u64 - 交易所在 slot
+    ///     lamports: u64 - 入账金额（lamports）
+    /// # Return
+    ///     ActiveModel
+    pub fn new(wallet_id: i32, user_id: i32, address: String, signature: String, slot: u64, lamports: u64) -> ActiveModel {
+        ActiveModel {
+            id: ActiveValue::NotSet,
+            wallet_id: ActiveValue::set(wallet_id),
+            user_id: ActiveValue::set(user_id),
+            address: ActiveValue::set(address),
+            signature: ActiveValue::set(signature),
+            slot: ActiveValue::set(slot as i64),
+            lamports: ActiveValue::set(lamports as i64),
+            amount: ActiveValue::set(solana_sdk::native_token::lamports_to_sol(lamports)),
+            commitment: ActiveValue::set(CommitmentLevel::Confirmed),
+            created_at: ActiveValue::set(Utc::now()),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
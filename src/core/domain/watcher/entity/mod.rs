@@ -0,0 +1,3 @@
+pub mod chain_notification_entity;
+pub mod deposit_entity;
+pub mod tracked_signature_entity;
@@ -0,0 +1,45 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use serde_json::Value;
+
+/// # Description
+///     确认跟踪器中等待最终确认的转出交易
+/// # Fields
+///     signature: String - 交易签名
+///     user_id: i32 - 交易所属用户
+///     data: Value - 业务数据，确认或失败时原样带入通知事件
+///     submitted_at: DateTime<Utc> - 开始跟踪的时间
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct TrackedSignature {
+    pub signature: String,
+    pub user_id: i32,
+    pub data: Value,
+    pub submitted_at: DateTime<Utc>,
+}
+
+impl TrackedSignature {
+    /// # Description
+    ///     创建跟踪记录
+    /// # Param
+    ///     signature: String - 交易签名
+    ///     user_id: i32 - 交易所属用户
+    ///     data: Value - 业务数据
+    /// # Return
+    ///     Self
+    pub fn new(signature: String, user_id: i32, data: Value) -> Self {
+        Self { signature, user_id, data, submitted_at: Utc::now() }
+    }
+}
+
+/// # Description
+///     跟踪结果
+/// # Param
+///     Confirmed: 交易已最终确认
+///     Failed: 交易执行失败
+///     Expired: 超时仍未查到交易，视为被丢弃
+#[derive(Clone, Debug, PartialEq)]
+pub enum TrackOutcome {
+    Confirmed { slot: u64 },
+    Failed { slot: Option<u64>, error: String },
+    Expired,
+}
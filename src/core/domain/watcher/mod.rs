@@ -0,0 +1,6 @@
+pub mod entity;
+pub mod repository;
+pub mod service;
+mod watcher_domain;
+
+pub use watcher_domain::WatcherDomain;
@@ -0,0 +1,15 @@
+use axum::async_trait;
+use color_eyre::{Report, Result};
+use crate::core::domain::watcher::entity::deposit_entity::{ActiveModel as DepositActiveModel, Model as DepositModel};
+
+/// # Description
+///     充值记录仓储接口
+#[async_trait]
+pub trait DepositRepositoryInterface: Send + Sync {
+    async fn exists(&self, wallet_id: i32, signature: &str) -> Result<bool, Report>;
+    async fn find_latest(&self, wallet_id: i32) -> Result<Option<DepositModel>, Report>;
+    async fn save(&self, deposit: DepositActiveModel) -> Result<DepositModel, Report>;
+    async fn credit(&self, deposit: DepositActiveModel) -> Result<(DepositModel, f64), Report>;
+    async fn find_unfinalized(&self, max_slot: u64, limit: u64) -> Result<Vec<DepositModel>, Report>;
+    async fn finalize(&self, id: i32, slot: u64) -> Result<(), Report>;
+}
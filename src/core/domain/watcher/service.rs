@@ -0,0 +1,68 @@
+use std::time::Duration;
+use chrono::{DateTime, Utc};
+use super::entity::tracked_signature_entity::{TrackOutcome, TrackedSignature};
+
+/// # Description
+///     链上监听领域服务，负责充值识别、确认结果判定与重连退避
+pub struct WatcherDomainService;
+
+impl WatcherDomainService {
+    /// # Description
+    ///     创建新的链上监听领域服务实例
+    /// # Return
+    ///     Self: 链上监听领域服务实例
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// # Description
+    ///     计算交易为托管地址带来的充值金额。托管地址是手续费支付方时为本系统发起的交易，不计为充值
+    /// # Param
+    ///     address: &str - 托管地址
+    ///     accounts: &[String] - 交易涉及的全部账户，第一个为手续费支付方
+    ///     pre_balances: &[u64] - 交易前余额
+    ///     post_balances: &[u64] - 交易后余额
+    /// # Return
+    ///     Option<u64>: 充值金额（lamports），不是充值时返回 None
+    pub fn deposit_lamports(&self, address: &str, accounts: &[String], pre_balances: &[u64], post_balances: &[u64]) -> Option<u64> {
+        let index = accounts.iter().position(|account| account == address)?;
+        if index == 0 {
+            return None;
+        }
+
+        let pre = *pre_balances.get(index)?;
+        let post = *post_balances.get(index)?;
+        (post > pre).then(|| post - pre)
+    }
+
+    /// # Description
+    ///     根据查询到的交易状态判定跟踪结果
+    /// # Param
+    ///     tracked: &TrackedSignature - 跟踪中的交易
+    ///     status: Option<(u64, Option<String>, bool)> - 交易状态：(slot, 失败原因, 是否已最终确认)，未查到时为 None
+    ///     timeout: i64 - 最长等待时间（秒）
+    ///     now: DateTime<Utc> - 当前时间
+    /// # Return
+    ///     Option<TrackOutcome>: 跟踪结果，仍需等待时为 None
+    pub fn resolve(&self, tracked: &TrackedSignature, status: Option<(u64, Option<String>, bool)>, timeout: i64, now: DateTime<Utc>) -> Option<TrackOutcome> {
+        match status {
+            Some((slot, Some(error), _)) => Some(TrackOutcome::Failed { slot: Some(slot), error }),
+            Some((slot, None, true)) => Some(TrackOutcome::Confirmed { slot }),
+            _ if (now - tracked.submitted_at).num_seconds() >= timeout => Some(TrackOutcome::Expired),
+            _ => None,
+        }
+    }
+
+    /// # Description
+    ///     计算第 attempt 次重连前的等待时间，按 2 的指数退避并设上限
+    /// # Param
+    ///     attempt: u32 - 连续失败次数，从 1 开始
+    ///     base: u64 - 基础等待时间（秒）
+    ///     max: u64 - 最大等待时间（秒）
+    /// # Return
+    ///     Duration: 等待时间
+    pub fn reconnect_delay(&self, attempt: u32, base: u64, max: u64) -> Duration {
+        let factor = 1u64 << attempt.saturating_sub(1).min(30);
+        Duration::from_secs(base.max(1).saturating_mul(factor).min(max.max(1)))
+    }
+}
@@ -0,0 +1,20 @@
+use std::sync::Arc;
+use crate::core::domain::watcher::service::WatcherDomainService;
+
+pub struct WatcherDomain {
+    pub domain_service: Arc<WatcherDomainService>,
+}
+
+impl WatcherDomain {
+    /// # Description
+    ///     初始化链上监听领域
+    /// # Return
+    ///     Self: 初始化后的链上监听领域实例
+    pub async fn new() -> Self {
+        let domain_service = Arc::new(WatcherDomainService::new());
+
+        Self {
+            domain_service
+        }
+    }
+}
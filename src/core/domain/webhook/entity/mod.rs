@@ -0,0 +1,4 @@
+pub mod webhook_event_entity;
+pub mod webhook_endpoint_entity;
+pub mod webhook_delivery_entity;
+pub mod webhook_balance_alert_entity;
@@ -0,0 +1,50 @@
+use chrono::Utc;
+use sea_orm::{ActiveModelBehavior, ActiveValue, DeriveEntityModel, DerivePrimaryKey, DeriveRelation, EnumIter, PrimaryKeyTrait};
+use sea_orm::entity::prelude::*;
+use sea_orm::prelude::DateTimeUtc;
+use serde::{Deserialize, Serialize};
+
+/// # Description
+///     该结构体代表一条已发送的余额不足告警，并映射到数据库中的 `webhook_balance_alert` 表。
+///     同一端点与钱包只保留一条，余额恢复后删除，重启后不会重复告警
+/// # Param
+///     id: 主键，自动递增
+///     endpoint_id: 订阅端点，关联 webhook_endpoint 表
+///     wallet_id: 余额低于阈值的钱包，关联 wallet 表
+///     balance: 告警时的链上余额（SOL）
+///     created_at: 告警时间
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "webhook_balance_alert")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+    pub endpoint_id: i32,
+    pub wallet_id: i32,
+    pub balance: f64,
+    pub created_at: DateTimeUtc,
+}
+
+impl Model {
+    /// # Description
+    ///     创建新的余额不足告警记录
+    /// # Param
+    ///     endpoint_id: i32 - 订阅端点id
+    ///     wallet_id: i32 - 钱包id
+    ///     balance: f64 - 告警时的链上余额
+    /// # Return
+    ///     ActiveModel
+    pub fn new(endpoint_id: i32, wallet_id: i32, balance: f64) -> ActiveModel {
+        ActiveModel {
+            id: ActiveValue::NotSet,
+            endpoint_id: ActiveValue::set(endpoint_id),
+            wallet_id: ActiveValue::set(wallet_id),
+            balance: ActiveValue::set(balance),
+            created_at: ActiveValue::set(Utc::now()),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,134 @@
+use chrono::{DateTime, Utc};
+use sea_orm::{ActiveModelBehavior, ActiveValue, DeriveEntityModel, DerivePrimaryKey, DeriveRelation, EnumIter, PrimaryKeyTrait};
+use sea_orm::entity::prelude::*;
+use sea_orm::prelude::DateTimeUtc;
+use serde::{Deserialize, Serialize};
+use super::webhook_event_entity::WebhookEventType;
+
+/// # Description
+///     投递状态
+/// # Param
+///     Pending: 等待投递或等待重试
+///     Succeeded: 接收方返回 2xx
+///     Failed: 重试次数耗尽
+#[derive(Clone, Debug, PartialEq, Eq, EnumIter, DeriveActiveEnum, Serialize, Deserialize)]
+#[sea_orm(rs_type = "String", db_type = "String(StringLen::N(16))")]
+#[serde(rename_all = "lowercase")]
+pub enum DeliveryStatus {
+    #[sea_orm(string_value = "pending")]
+    Pending,
+    #[sea_orm(string_value = "succeeded")]
+    Succeeded,
+    #[sea_orm(string_value = "failed")]
+    Failed,
+}
+
+/// # Description
+///     该结构体代表一次 Webhook 投递及其结果，并映射到数据库中的 `webhook_delivery` 表。
+///     每个事件对每个订阅端点生成一条投递记录，重放时生成新的记录
+/// # Param
+///     id: 主键，自动递增
+///     endpoint_id: 订阅端点，关联 webhook_endpoint 表
+///     event_id: 事件id
+///     event_type: 事件类型
+///     payload: 请求体（事件 JSON）
+///     status: 投递状态
+///     attempts: 已尝试次数
+///     response_status: 最近一次响应的 HTTP 状态码
+///     error: 最近一次失败原因
+///     replay_of: 重放来源的投递记录
+///     next_attempt_at: 下次投递时间
+///     delivered_at: 投递成功时间
+///     created_at: 创建时间
+///     updated_at: 更新时间
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "webhook_delivery")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+    pub endpoint_id: i32,
+    pub event_id: String,
+    pub event_type: WebhookEventType,
+    #[sea_orm(column_type = "Text")]
+    pub payload: String,
+    pub status: DeliveryStatus,
+    pub attempts: i32,
+    pub response_status: Option<i32>,
+    pub error: Option<String>,
+    pub replay_of: Option<i32>,
+    pub next_attempt_at: Option<DateTime<Utc>>,
+    pub delivered_at: Option<DateTime<Utc>>,
+    pub created_at: DateTimeUtc,
+    pub updated_at: DateTimeUtc,
+}
+
+impl Model {
+    /// # Description
+    ///     创建新的投递记录，立即可投递
+    /// # Param
+    ///     endpoint_id: i32 - 订阅端点id
+    ///     event_id: String - 事件id
+    ///     event_type: WebhookEventType - 事件类型
+    ///     payload: String - 请求体
+    ///     replay_of: Option<i32> - 重放来源的投递记录
+    /// # Return
+    ///     ActiveModel
+    pub fn new(endpoint_id: i32, event_id: String, event_type: WebhookEventType, payload: String, replay_of: Option<i32>) -> ActiveModel {
+        let now_datetime = Utc::now();
+
+        ActiveModel {
+            id: ActiveValue::NotSet,
+            endpoint_id: ActiveValue::set(endpoint_id),
+            event_id: ActiveValue::set(event_id),
+            event_type: ActiveValue::set(event_type),
+            payload: ActiveValue::set(payload),
+            status: ActiveValue::set(DeliveryStatus::Pending),
+            attempts: ActiveValue::set(0),
+            response_status: ActiveValue::set(None),
+            error: ActiveValue::set(None),
+            replay_of: ActiveValue::set(replay_of),
+            next_attempt_at: ActiveValue::set(Some(now_datetime)),
+            delivered_at: ActiveValue::set(None),
+            created_at: ActiveValue::set(now_datetime),
+            updated_at: ActiveValue::set(now_datetime),
+        }
+    }
+
+    /// # Description
+    ///     记录投递成功
+    /// # Param
+    ///     response_status: i32 - HTTP 状态码
+    ///     now: DateTime<Utc> - 当前时间
+    pub fn record_success(&mut self, response_status: i32, now: DateTime<Utc>) {
+        self.attempts += 1;
+        self.status = DeliveryStatus::Succeeded;
+        self.response_status = Some(response_status);
+        self.error = None;
+        self.next_attempt_at = None;
+        self.delivered_at = Some(now);
+        self.updated_at = now;
+    }
+
+    /// # Description
+    ///     记录投递失败，有重试时间时继续等待，否则标记为失败
+    /// # Param
+    ///     response_status: Option<i32> - HTTP 状态码，请求未送达时为 None
+    ///     error: String - 失败原因
+    ///     retry_at: Option<DateTime<Utc>> - 重试时间
+    ///     now: DateTime<Utc> - 当前时间
+    pub fn record_failure(&mut self, response_status: Option<i32>, error: String, retry_at: Option<DateTime<Utc>>, now: DateTime<Utc>) {
+        self.attempts += 1;
+        self.response_status = response_status;
+        self.error = Some(error);
+        self.next_attempt_at = retry_at;
+        if retry_at.is_none() {
+            self.status = DeliveryStatus::Failed;
+        }
+        self.updated_at = now;
+    }
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
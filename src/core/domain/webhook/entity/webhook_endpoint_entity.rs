@@ -0,0 +1,84 @@
+use chrono::Utc;
+use sea_orm::{ActiveModelBehavior, ActiveValue, DeriveEntityModel, DerivePrimaryKey, DeriveRelation, EnumIter, PrimaryKeyTrait};
+use sea_orm::prelude::DateTimeUtc;
+use serde::{Deserialize, Serialize};
+use super::webhook_event_entity::WebhookEventType;
+
+/// # Description
+///     该结构体代表 Webhook 订阅端点，并映射到数据库中的 `webhook_endpoint` 表。
+/// # Param
+///     id: 主键，自动递增
+///     user_id: 所属用户，为空时为管理员端点，接收全部用户的事件
+///     url: 接收地址
+///     secret: 签名密钥，仅在创建时返回一次
+///     events: 订阅的事件类型，逗号分隔
+///     balance_threshold: 余额告警阈值（SOL），订阅 balance_below_threshold 时必填
+///     enabled: 是否启用
+///     created_at: 创建时间
+///     updated_at: 更新时间
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "webhook_endpoint")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+    pub user_id: Option<i32>,
+    pub url: String,
+    #[serde(skip_serializing)]
+    pub secret: String,
+    pub events: String,
+    pub balance_threshold: Option<f64>,
+    pub enabled: bool,
+    pub created_at: DateTimeUtc,
+    pub updated_at: DateTimeUtc,
+}
+
+impl Model {
+    /// # Description
+    ///     创建新的订阅端点
+    /// # Param
+    ///     user_id: Option<i32> - 所属用户，管理员端点为 None
+    ///     url: String - 接收地址
+    ///     secret: String - 签名密钥
+    ///     events: &[WebhookEventType] - 订阅的事件类型
+    ///     balance_threshold: Option<f64> - 余额告警阈值（SOL）
+    /// # Return
+    ///     ActiveModel
+    pub fn new(
+        user_id: Option<i32>,
+        url: String,
+        secret: String,
+        events: &[WebhookEventType],
+        balance_threshold: Option<f64>,
+    ) -> ActiveModel {
+        let now_datetime = Utc::now();
+
+        ActiveModel {
+            id: ActiveValue::NotSet,
+            user_id: ActiveValue::set(user_id),
+            url: ActiveValue::set(url),
+            secret: ActiveValue::set(secret),
+            events: ActiveValue::set(events.iter().map(WebhookEventType::as_str).collect::<Vec<_>>().join(",")),
+            balance_threshold: ActiveValue::set(balance_threshold),
+            enabled: ActiveValue::set(true),
+            created_at: ActiveValue::set(now_datetime),
+            updated_at: ActiveValue::set(now_datetime),
+        }
+    }
+
+    /// # Description
+    ///     订阅的事件类型，忽略无法识别的取值
+    pub fn event_types(&self) -> Vec<WebhookEventType> {
+        self.events.split(',').filter_map(|event| event.trim().parse().ok()).collect()
+    }
+
+    /// # Description
+    ///     是否订阅了该事件类型
+    pub fn subscribes(&self, event_type: &WebhookEventType) -> bool {
+        self.enabled && self.event_types().contains(event_type)
+    }
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
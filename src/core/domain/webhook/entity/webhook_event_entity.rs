@@ -0,0 +1,76 @@
+use std::str::FromStr;
+use chrono::{DateTime, Utc};
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// # Description
+///     Webhook 事件类型
+/// # Param
+///     DepositCredited: 充值已入账
+///     WithdrawalConfirmed: 提现（转出）已确认
+///     WithdrawalFailed: 提现（转出）失败
+///     SwapFilled: 兑换已成交
+///     BalanceBelowThreshold: 钱包余额低于订阅设置的阈值
+#[derive(Clone, Debug, PartialEq, Eq, Hash, EnumIter, DeriveActiveEnum, Serialize, Deserialize)]
+#[sea_orm(rs_type = "String", db_type = "String(StringLen::N(32))")]
+#[serde(rename_all = "snake_case")]
+pub enum WebhookEventType {
+    #[sea_orm(string_value = "deposit_credited")]
+    DepositCredited,
+    #[sea_orm(string_value = "withdrawal_confirmed")]
+    WithdrawalConfirmed,
+    #[sea_orm(string_value = "withdrawal_failed")]
+    WithdrawalFailed,
+    #[sea_orm(string_value = "swap_filled")]
+    SwapFilled,
+    #[sea_orm(string_value = "balance_below_threshold")]
+    BalanceBelowThreshold,
+}
+
+impl WebhookEventType {
+    /// # Description
+    ///     事件类型名称，与 JSON 及数据库中的取值一致
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WebhookEventType::DepositCredited => "deposit_credited",
+            WebhookEventType::WithdrawalConfirmed => "withdrawal_confirmed",
+            WebhookEventType::WithdrawalFailed => "withdrawal_failed",
+            WebhookEventType::SwapFilled => "swap_filled",
+            WebhookEventType::BalanceBelowThreshold => "balance_below_threshold",
+        }
+    }
+}
+
+impl FromStr for WebhookEventType {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "deposit_credited" => Ok(WebhookEventType::DepositCredited),
+            "withdrawal_confirmed" => Ok(WebhookEventType::WithdrawalConfirmed),
+            "withdrawal_failed" => Ok(WebhookEventType::WithdrawalFailed),
+            "swap_filled" => Ok(WebhookEventType::SwapFilled),
+            "balance_below_threshold" => Ok(WebhookEventType::BalanceBelowThreshold),
+            other => Err(format!("未知的事件类型 {}", other)),
+        }
+    }
+}
+
+/// # Description
+///     Webhook 事件，序列化后作为投递的请求体
+/// # Fields
+///     id: String - 事件id，重放时保持不变，接收方可据此去重
+///     event_type: WebhookEventType - 事件类型
+///     user_id: i32 - 事件所属用户
+///     created_at: DateTime<Utc> - 事件发生时间
+///     data: Value - 事件数据
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct WebhookEvent {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub event_type: WebhookEventType,
+    pub user_id: i32,
+    pub created_at: DateTime<Utc>,
+    pub data: Value,
+}
@@ -0,0 +1,6 @@
+pub mod entity;
+pub mod repository;
+pub mod service;
+mod webhook_domain;
+
+pub use webhook_domain::WebhookDomain;
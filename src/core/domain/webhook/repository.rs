@@ -0,0 +1,25 @@
+use axum::async_trait;
+use chrono::{DateTime, Utc};
+use color_eyre::{Report, Result};
+use crate::core::domain::webhook::entity::webhook_endpoint_entity::{ActiveModel as WebhookEndpointActiveModel, Model as WebhookEndpointModel};
+use crate::core::domain::webhook::entity::webhook_delivery_entity::{ActiveModel as WebhookDeliveryActiveModel, Model as WebhookDeliveryModel};
+use crate::core::domain::webhook::entity::webhook_balance_alert_entity::{ActiveModel as WebhookBalanceAlertActiveModel, Model as WebhookBalanceAlertModel};
+
+/// # Description
+///     Webhook 仓储接口
+#[async_trait]
+pub trait WebhookRepositoryInterface: Send + Sync {
+    async fn find_endpoint(&self, id: i32) -> Result<Option<WebhookEndpointModel>, Report>;
+    async fn find_endpoints(&self, user_id: Option<i32>) -> Result<Vec<WebhookEndpointModel>, Report>;
+    async fn find_subscribers(&self, user_id: i32) -> Result<Vec<WebhookEndpointModel>, Report>;
+    async fn find_threshold_endpoints(&self) -> Result<Vec<WebhookEndpointModel>, Report>;
+    async fn save_endpoint(&self, endpoint: WebhookEndpointActiveModel) -> Result<WebhookEndpointModel, Report>;
+    async fn delete_endpoint(&self, id: i32) -> Result<(), Report>;
+    async fn save_delivery(&self, delivery: WebhookDeliveryActiveModel) -> Result<WebhookDeliveryModel, Report>;
+    async fn find_delivery(&self, id: i32) -> Result<Option<WebhookDeliveryModel>, Report>;
+    async fn find_due_deliveries(&self, now: DateTime<Utc>, limit: u64) -> Result<Vec<WebhookDeliveryModel>, Report>;
+    async fn find_deliveries(&self, endpoint_id: i32, limit: u64) -> Result<Vec<WebhookDeliveryModel>, Report>;
+    async fn find_balance_alerts(&self, endpoint_ids: &[i32]) -> Result<Vec<WebhookBalanceAlertModel>, Report>;
+    async fn save_balance_alert(&self, alert: WebhookBalanceAlertActiveModel) -> Result<bool, Report>;
+    async fn delete_balance_alert(&self, endpoint_id: i32, wallet_id: i32) -> Result<(), Report>;
+}
@@ -0,0 +1,192 @@
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use chrono::{DateTime, Duration, Utc};
+use color_eyre::{Report, Result};
+use hmac::{Hmac, Mac};
+use rand::Rng;
+use serde_json::Value;
+use sha2::Sha256;
+use super::entity::webhook_endpoint_entity::{ActiveModel as WebhookEndpointActiveModel, Model as WebhookEndpointModel};
+use super::entity::webhook_event_entity::{WebhookEvent, WebhookEventType};
+
+/// # Description
+///     签名请求头的前缀，标明签名算法
+const SIGNATURE_SCHEME: &str = "sha256=";
+
+/// # Description
+///     Webhook 通知领域服务，负责订阅校验、事件生成、签名与重试退避
+pub struct WebhookDomainService;
+
+impl WebhookDomainService {
+    /// # Description
+    ///     创建新的 Webhook 通知领域服务实例
+    /// # Return
+    ///     Self: Webhook 通知领域服务实例
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// # Description
+    ///     校验参数并创建订阅端点，同时生成签名密钥
+    /// # Param
+    ///     user_id: Option<i32> - 所属用户，管理员端点为 None
+    ///     url: String - 接收地址，必须为 https
+    ///     events: Vec<WebhookEventType> - 订阅的事件类型
+    ///     balance_threshold: Option<f64> - 余额告警阈值（SOL）
+    /// # Return
+    ///     Result<(WebhookEndpointActiveModel, String), Report>: 订阅端点与签名密钥
+    pub fn create_endpoint(
+        &self,
+        user_id: Option<i32>,
+        url: String,
+        events: Vec<WebhookEventType>,
+        balance_threshold: Option<f64>,
+    ) -> Result<(WebhookEndpointActiveModel, String), Report> {
+        let url = url.trim().to_string();
+        if !url.starts_with("https://") || url.len() > 512 {
+            return Err(Report::msg("接收地址必须是 https 地址"));
+        }
+
+        let mut event_types: Vec<WebhookEventType> = Vec::new();
+        for event in events {
+            if !event_types.contains(&event) {
+                event_types.push(event);
+            }
+        }
+        if event_types.is_empty() {
+            return Err(Report::msg("至少需要订阅一种事件"));
+        }
+
+        let watches_balance = event_types.contains(&WebhookEventType::BalanceBelowThreshold);
+        match balance_threshold {
+            Some(threshold) if !threshold.is_finite() || threshold <= 0.0 => {
+                return Err(Report::msg("余额阈值必须大于 0"));
+            }
+            None if watches_balance => {
+                return Err(Report::msg("订阅余额不足事件时必须设置余额阈值"));
+            }
+            _ => {}
+        }
+        if watches_balance && user_id.is_none() {
+            return Err(Report::msg("管理员端点不支持余额不足事件"));
+        }
+
+        let secret = format!("whsec_{}", random_hex(32));
+        let endpoint = WebhookEndpointModel::new(user_id, url, secret.clone(), &event_types, balance_threshold);
+
+        Ok((endpoint, secret))
+    }
+
+    /// # Description
+    ///     生成事件
+    /// # Param
+    ///     user_id: i32 - 事件所属用户
+    ///     event_type: WebhookEventType - 事件类型
+    ///     data: Value - 事件数据
+    /// # Return
+    ///     WebhookEvent: 事件
+    pub fn build_event(&self, user_id: i32, event_type: WebhookEventType, data: Value) -> WebhookEvent {
+        WebhookEvent {
+            id: format!("evt_{}", random_hex(16)),
+            event_type,
+            user_id,
+            created_at: Utc::now(),
+            data,
+        }
+    }
+
+    /// # Description
+    ///     对请求体签名：HMAC-SHA256(secret, "<timestamp>.<body>")，时间戳参与签名以防重放
+    /// # Param
+    ///     secret: &str - 端点签名密钥
+    ///     timestamp: i64 - 发送时间（Unix 秒）
+    ///     body: &str - 请求体
+    /// # Return
+    ///     Result<String, Report>: sha256=<十六进制签名>
+    pub fn sign(&self, secret: &str, timestamp: i64, body: &str) -> Result<String, Report> {
+        let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes())
+            .map_err(|e| Report::msg(format!("签名密钥不合法: {}", e)))?;
+        mac.update(timestamp.to_string().as_bytes());
+        mac.update(b".");
+        mac.update(body.as_bytes());
+
+        Ok(format!("{}{}", SIGNATURE_SCHEME, to_hex(&mac.finalize().into_bytes())))
+    }
+
+    /// # Description
+    ///     计算投递失败后的重试时间，重试间隔按 2 的指数退避并设上限；达到最大尝试次数返回 None
+    /// # Param
+    ///     attempts: i32 - 已尝试次数（含本次）
+    ///     max_attempts: i32 - 最大尝试次数
+    ///     retry_base: i64 - 基础退避时间（秒）
+    ///     retry_max: i64 - 最大退避时间（秒）
+    ///     now: DateTime<Utc> - 当前时间
+    /// # Return
+    ///     Option<DateTime<Utc>>: 重试时间
+    pub fn retry_at(&self, attempts: i32, max_attempts: i32, retry_base: i64, retry_max: i64, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        if attempts >= max_attempts {
+            return None;
+        }
+
+        let factor = 1i64 << (attempts - 1).clamp(0, 30);
+        let delay = retry_base.saturating_mul(factor).min(retry_max);
+
+        Some(now + Duration::seconds(delay))
+    }
+
+    /// # Description
+    ///     接收地址解析出的 IP 是否允许投递：拒绝回环、内网、链路本地、组播等非公网地址，防止借 Webhook 访问内部服务
+    /// # Param
+    ///     ip: IpAddr - 解析出的地址
+    /// # Return
+    ///     bool: 是否为可投递的公网地址
+    pub fn is_public_ip(&self, ip: IpAddr) -> bool {
+        match ip {
+            IpAddr::V4(ip) => is_public_ipv4(ip),
+            IpAddr::V6(ip) => match ip.to_ipv4_mapped() {
+                Some(mapped) => is_public_ipv4(mapped),
+                None => is_public_ipv6(ip),
+            },
+        }
+    }
+}
+
+/// # Description
+///     IPv4 地址是否为公网地址，共享地址段 100.64.0.0/10 与保留段 240.0.0.0/4 也视为非公网
+fn is_public_ipv4(ip: Ipv4Addr) -> bool {
+    let octets = ip.octets();
+    !(ip.is_unspecified()
+        || ip.is_loopback()
+        || ip.is_private()
+        || ip.is_link_local()
+        || ip.is_broadcast()
+        || ip.is_multicast()
+        || ip.is_documentation()
+        || octets[0] == 0
+        || (octets[0] == 100 && (octets[1] & 0xc0) == 64)
+        || octets[0] >= 240)
+}
+
+/// # Description
+///     IPv6 地址是否为公网地址，唯一本地地址 fc00::/7 与链路本地地址 fe80::/10 视为非公网
+fn is_public_ipv6(ip: Ipv6Addr) -> bool {
+    let first = ip.segments()[0];
+    !(ip.is_unspecified()
+        || ip.is_loopback()
+        || ip.is_multicast()
+        || (first & 0xfe00) == 0xfc00
+        || (first & 0xffc0) == 0xfe80)
+}
+
+/// # Description
+///     随机字节的十六进制表示
+fn random_hex(len: usize) -> String {
+    let mut rng = rand::thread_rng();
+    let bytes: Vec<u8> = (0..len).map(|_| rng.gen()).collect();
+    to_hex(&bytes)
+}
+
+/// # Description
+///     字节转小写十六进制
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
@@ -0,0 +1,20 @@
+use std::sync::Arc;
+use crate::core::domain::webhook::service::WebhookDomainService;
+
+pub struct WebhookDomain {
+    pub domain_service: Arc<WebhookDomainService>,
+}
+
+impl WebhookDomain {
+    /// # Description
+    ///     初始化 Webhook 通知领域
+    /// # Return
+    ///     Self: 初始化后的 Webhook 通知领域实例
+    pub async fn new() -> Self {
+        let domain_service = Arc::new(WebhookDomainService::new());
+
+        Self {
+            domain_service
+        }
+    }
+}
@@ -0,0 +1 @@
+pub mod whitelist_address_entity;
\ No newline at end of file
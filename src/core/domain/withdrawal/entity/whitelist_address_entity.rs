@@ -0,0 +1,50 @@
+use chrono::Utc;
+use sea_orm::{ActiveModelBehavior, ActiveValue, DeriveEntityModel, DerivePrimaryKey, DeriveRelation, EnumIter, PrimaryKeyTrait};
+use sea_orm::entity::prelude::*;
+use sea_orm::prelude::DateTimeUtc;
+use serde::{Deserialize, Serialize};
+
+/// # Description
+///     该结构体代表用户的提现白名单地址，并映射到数据库中的 `withdrawal_whitelist` 表。
+///     提现只能转到白名单中的地址
+/// # Param
+///     id: 主键，自动递增
+///     user_id: 用户，关联 user 表
+///     address: 接收地址（Base58 公钥）
+///     label: 备注
+///     created_at: 添加时间
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "withdrawal_whitelist")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+    pub user_id: i32,
+    pub address: String,
+    pub label: Option<String>,
+    pub created_at: DateTimeUtc,
+}
+
+impl Model {
+    /// # Description
+    ///     创建新的白名单地址
+    /// # Param
+    ///     user_id: i32 - 用户id
+    ///     address: String - 接收地址
+    ///     label: Option<String> - 备注
+    /// # Return
+    ///     ActiveModel
+    pub fn new(user_id: i32, address: String, label: Option<String>) -> ActiveModel {
+        ActiveModel {
+            id: ActiveValue::NotSet,
+            user_id: ActiveValue::set(user_id),
+            address: ActiveValue::set(address),
+            label: ActiveValue::set(label),
+            created_at: ActiveValue::set(Utc::now()),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
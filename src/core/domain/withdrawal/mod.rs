@@ -0,0 +1,6 @@
+pub mod entity;
+pub mod repository;
+pub mod service;
+mod withdrawal_domain;
+
+pub use withdrawal_domain::WithdrawalDomain;
\ No newline at end of file
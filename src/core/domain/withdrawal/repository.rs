@@ -0,0 +1,15 @@
+use axum::async_trait;
+use color_eyre::{Report, Result};
+use crate::core::domain::withdrawal::entity::whitelist_address_entity::{
+    ActiveModel as WhitelistAddressActiveModel, Model as WhitelistAddressModel
+};
+
+/// # Description
+///     提现白名单仓储接口
+#[async_trait]
+pub trait WithdrawalRepositoryInterface: Send + Sync {
+    async fn find_whitelist(&self, user_id: i32) -> Result<Vec<WhitelistAddressModel>, Report>;
+    async fn find_address(&self, user_id: i32, address: &str) -> Result<Option<WhitelistAddressModel>, Report>;
+    async fn add_address(&self, address: WhitelistAddressActiveModel) -> Result<WhitelistAddressModel, Report>;
+    async fn remove_address(&self, user_id: i32, id: i32) -> Result<bool, Report>;
+}
@@ -0,0 +1,70 @@
+use std::str::FromStr;
+use color_eyre::{Report, Result};
+use solana_program::pubkey::Pubkey;
+use crate::core::domain::wallet::entity::Model as WalletModel;
+
+/// # Description
+///     白名单备注的最大长度
+const MAX_LABEL_LENGTH: usize = 64;
+
+/// # Description
+///     提现领域服务，负责白名单地址校验与提现金额校验
+pub struct WithdrawalDomainService;
+
+impl WithdrawalDomainService {
+    /// # Description
+    ///     创建新的提现领域服务实例
+    /// # Return
+    ///     Self: 提现领域服务实例
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// # Description
+    ///     校验并规范化接收地址
+    /// # Param
+    ///     address: &str - Base58 公钥
+    /// # Return
+    ///     Result<String, Report>: 规范化后的地址
+    pub fn normalize_address(&self, address: &str) -> Result<String, Report> {
+        Pubkey::from_str(address.trim())
+            .map(|pubkey| pubkey.to_string())
+            .map_err(|_| Report::msg("接收地址格式不正确"))
+    }
+
+    /// # Description
+    ///     规范化白名单备注，空白备注视为没有备注
+    /// # Param
+    ///     label: Option<String> - 备注
+    /// # Return
+    ///     Result<Option<String>, Report>: 规范化后的备注
+    pub fn normalize_label(&self, label: Option<String>) -> Result<Option<String>, Report> {
+        let label = label.map(|label| label.trim().to_string()).filter(|label| !label.is_empty());
+        if label.as_ref().is_some_and(|label| label.chars().count() > MAX_LABEL_LENGTH) {
+            return Err(Report::msg(format!("备注不能超过 {} 个字符", MAX_LABEL_LENGTH)));
+        }
+
+        Ok(label)
+    }
+
+    /// # Description
+    ///     校验钱包能否提现指定金额
+    /// # Param
+    ///     wallet: &WalletModel - 转出钱包
+    ///     amount: f64 - 提现金额（SOL）
+    /// # Return
+    ///     Result<(), Report>: 校验结果
+    pub fn check_withdrawal(&self, wallet: &WalletModel, amount: f64) -> Result<(), Report> {
+        if wallet.disable || wallet.deleted_at.is_some() {
+            return Err(Report::msg("钱包已禁用"));
+        }
+        if !amount.is_finite() || amount <= 0.0 {
+            return Err(Report::msg("提现金额必须大于 0"));
+        }
+        if amount > wallet.balance {
+            return Err(Report::msg("余额不足"));
+        }
+
+        Ok(())
+    }
+}
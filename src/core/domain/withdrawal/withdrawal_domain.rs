@@ -0,0 +1,20 @@
+use std::sync::Arc;
+use crate::core::domain::withdrawal::service::WithdrawalDomainService;
+
+pub struct WithdrawalDomain {
+    pub domain_service: Arc<WithdrawalDomainService>,
+}
+
+impl WithdrawalDomain {
+    /// # Description
+    ///     初始化提现领域
+    /// # Return
+    ///     Self: 初始化后的提现领域实例
+    pub async fn new() -> Self {
+        let domain_service = Arc::new(WithdrawalDomainService::new());
+
+        Self {
+            domain_service
+        }
+    }
+}
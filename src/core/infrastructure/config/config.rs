@@ -15,6 +15,20 @@ use config::{Config as Conf, File};
 ///     pub storage: StorageConfig, 存储配置
 ///     pub queue: QueueConfig, 消息队列配置
 ///     pub smtp: SmtpConfig, SMTP 邮件配置
+///     pub solana: SolanaConfig, Solana 节点配置
+///     pub oracle: OracleConfig, 价格预言机配置
+///     pub job: JobConfig, 后台任务配置
+///     pub market: MarketConfig, OpenBook 行情配置
+///     pub history: HistoryConfig, 钱包交易历史配置
+///     pub idl: IdlConfig, Anchor IDL 配置
+///     pub stream: StreamConfig, 实时推送配置
+///     pub rbac: RbacConfig, 角色权限配置
+///     pub mail: MailConfig, 邮件验证与找回密码配置
+///     pub sms: SmsConfig, 短信验证码配置
+///     pub two_factor: TwoFactorConfig, 两步验证配置
+///     pub login_throttle: LoginThrottleConfig, 登录限流与锁定配置
+///     pub admin: AdminConfig, 用户管理后台配置
+///     pub wallet: WalletConfig, 钱包配置
 #[derive(Debug, Deserialize)]
 #[allow(dead_code)]
 pub struct Config {
@@ -26,6 +40,20 @@ pub struct Config {
     pub storage: StorageConfig,
     pub queue: QueueConfig,
     pub smtp: SmtpConfig,
+    pub solana: SolanaConfig,
+    pub oracle: OracleConfig,
+    pub job: JobConfig,
+    pub market: MarketConfig,
+    pub history: HistoryConfig,
+    pub idl: IdlConfig,
+    pub stream: StreamConfig,
+    pub rbac: RbacConfig,
+    pub mail: MailConfig,
+    pub sms: SmsConfig,
+    pub two_factor: TwoFactorConfig,
+    pub login_throttle: LoginThrottleConfig,
+    pub admin: AdminConfig,
+    pub wallet: WalletConfig,
 }
 
 /// # Description
@@ -118,6 +146,11 @@ pub struct PostgresSQLConfig {
 ///     jwt_ttl: u64, JWT 的存活时间（秒）
 ///     jwt_blacklist_grace_period: u64, 黑名单宽限期（秒）
 ///     refresh_grace_period: u64, 刷新令牌宽限期（秒）
+///     token_driver: String, 黑名单与刷新令牌族存储驱动：redis / memory
+///     issuer: String, 令牌签发方（iss），验证时必须一致
+///     audience: String, 令牌受众（aud），验证时必须一致
+///     signing_kid: String, 签发密钥id，为空时使用 secret 以 HS256 签发
+///     keys: Vec<JwtKeyConfig>, 非对称密钥，轮换时保留旧公钥以继续验证已签发的令牌
 #[derive(Debug, Deserialize)]
 #[allow(dead_code)]
 pub struct JwtConfig {
@@ -125,6 +158,28 @@ pub struct JwtConfig {
     pub jwt_ttl: u64,
     pub jwt_blacklist_grace_period: u64,
     pub refresh_grace_period: u64,
+    pub token_driver: String,
+    pub issuer: String,
+    pub audience: String,
+    pub signing_kid: String,
+    pub keys: Vec<JwtKeyConfig>,
+}
+
+/// # Description
+///     #[derive(Debug, Deserialize)]
+///     JWT 非对称密钥配置
+/// # Fields
+///     kid: String, 密钥id，写入令牌头部
+///     algorithm: String, 签名算法：RS256 / EdDSA
+///     private_key: Option<String>, PEM 私钥文件路径，仅签发密钥需要
+///     public_key: String, PEM 公钥文件路径，用于验签与 JWKS
+#[derive(Clone, Debug, Deserialize)]
+#[allow(dead_code)]
+pub struct JwtKeyConfig {
+    pub kid: String,
+    pub algorithm: String,
+    pub private_key: Option<String>,
+    pub public_key: String,
 }
 
 #[derive(Debug, Deserialize)]
@@ -210,6 +265,485 @@ pub struct SmtpDetails {
     pub password: String,
 }
 
+/// # Description
+///     #[derive(Debug, Deserialize)]
+///     Solana 节点配置
+/// # Fields
+///     pub rpc_url: String, RPC 节点地址
+///     pub ws_url: String, PubSub 节点地址
+///     pub commitment: String, 默认确认级别（processed / confirmed / finalized）
+#[derive(Debug, Deserialize)]
+#[allow(dead_code)]
+pub struct SolanaConfig {
+    pub rpc_url: String,
+    pub ws_url: String,
+    pub commitment: String,
+}
+
+/// # Description
+///     #[derive(Debug, Deserialize)]
+///     价格预言机配置
+/// # Fields
+///     pub cache_driver: String, 缓存驱动（memory / redis）
+///     pub cache_ttl: u64, 价格缓存时间（秒）
+///     pub max_staleness: i64, 报价最大允许延迟（秒），超过则丢弃
+///     pub max_deviation: f64, 与中位数的最大偏离比例，超过则视为异常值
+///     pub min_sources: usize, 聚合所需的最少有效数据源数量
+///     pub http_timeout: u64, HTTP 数据源超时时间（秒）
+///     pub tokens: Vec<OracleTokenConfig>, 代币 mint 与交易符号的映射
+///     pub max_staleness_slots: u64, 链上价格最大允许落后的 slot 数
+///     pub pyth: Vec<OracleFeedConfig>, Pyth 链上价格账户
+///     pub switchboard: Vec<OracleFeedConfig>, Switchboard 聚合器账户
+///     pub pools: Vec<OraclePoolConfig>, 用于推导价格的 AMM 池子
+#[derive(Debug, Deserialize)]
+#[allow(dead_code)]
+pub struct OracleConfig {
+    pub cache_driver: String,
+    pub cache_ttl: u64,
+    pub max_staleness: i64,
+    pub max_deviation: f64,
+    pub min_sources: usize,
+    pub http_timeout: u64,
+    pub tokens: Vec<OracleTokenConfig>,
+    pub max_staleness_slots: u64,
+    pub pyth: Vec<OracleFeedConfig>,
+    pub switchboard: Vec<OracleFeedConfig>,
+    pub pools: Vec<OraclePoolConfig>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[allow(dead_code)]
+pub struct OracleTokenConfig {
+    pub mint: String,
+    pub symbol: String,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[allow(dead_code)]
+pub struct OracleFeedConfig {
+    pub mint: String,
+    pub account: String,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[allow(dead_code)]
+pub struct OraclePoolConfig {
+    pub mint: String,
+    pub base_vault: String,
+    pub quote_vault: String,
+    pub base_decimals: u8,
+    pub quote_decimals: u8,
+}
+
+/// # Description
+///     #[derive(Debug, Deserialize)]
+///     后台任务配置
+/// # Fields
+///     pub order: OrderJobConfig, 条件单评估任务配置
+///     pub dca: DcaJobConfig, 定投任务配置
+///     pub indexer: IndexerJobConfig, 区块索引任务配置
+///     pub network: NetworkJobConfig, 网络吞吐采样任务配置
+///     pub webhook: WebhookJobConfig, Webhook 投递任务配置
+///     pub pubsub: PubsubJobConfig, 链上订阅任务配置
+///     pub reorg: ReorgJobConfig, 最终确认与分叉回滚任务配置
+#[derive(Debug, Deserialize)]
+#[allow(dead_code)]
+pub struct JobConfig {
+    pub order: OrderJobConfig,
+    pub dca: DcaJobConfig,
+    pub indexer: IndexerJobConfig,
+    pub network: NetworkJobConfig,
+    pub webhook: WebhookJobConfig,
+    pub pubsub: PubsubJobConfig,
+    pub reorg: ReorgJobConfig,
+}
+
+/// # Description
+///     #[derive(Debug, Deserialize)]
+///     条件单评估任务配置
+/// # Fields
+///     pub enabled: bool, 是否启动评估任务
+///     pub interval: u64, 评估间隔（秒）
+///     pub batch_size: u64, 每轮最多评估的挂单数量
+///     pub max_slippage: f64, 允许用户设置的最大滑点比例
+#[derive(Debug, Deserialize, Clone)]
+#[allow(dead_code)]
+pub struct OrderJobConfig {
+    pub enabled: bool,
+    pub interval: u64,
+    pub batch_size: u64,
+    pub max_slippage: f64,
+}
+
+/// # Description
+///     #[derive(Debug, Deserialize)]
+///     定投（DCA）任务配置
+/// # Fields
+///     pub enabled: bool, 是否启动定投任务
+///     pub interval: u64, 扫描到期计划的间隔（秒）
+///     pub batch_size: u64, 每轮最多执行的计划数量
+///     pub min_interval: i64, 计划允许的最小执行间隔（秒）
+///     pub max_retries: i32, 单次执行失败后的最大重试次数
+///     pub retry_base: i64, 重试退避的基础时间（秒），每次重试翻倍
+///     pub retry_max: i64, 重试退避的最大时间（秒）
+///     pub max_slippage: f64, 兑换计划允许设置的最大滑点比例
+#[derive(Debug, Deserialize, Clone)]
+#[allow(dead_code)]
+pub struct DcaJobConfig {
+    pub enabled: bool,
+    pub interval: u64,
+    pub batch_size: u64,
+    pub min_interval: i64,
+    pub max_retries: i32,
+    pub retry_base: i64,
+    pub retry_max: i64,
+    pub max_slippage: f64,
+}
+
+/// # Description
+///     #[derive(Debug, Deserialize)]
+///     区块索引任务配置
+/// # Fields
+///     pub enabled: bool, 是否启动实时索引任务
+///     pub interval: u64, 追上最新 slot 后的轮询间隔（秒）
+///     pub batch_size: u64, 每批最多索引的 slot 数量
+///     pub concurrency: usize, 同时拉取区块的最大并发数
+///     pub start_slot: u64, 首次启动（没有检查点）时的起始 slot，0 表示从当前最新 slot 开始
+///     pub backfill: IndexerBackfillConfig, 历史区间回填配置
+#[derive(Debug, Deserialize, Clone)]
+#[allow(dead_code)]
+pub struct IndexerJobConfig {
+    pub enabled: bool,
+    pub interval: u64,
+    pub batch_size: u64,
+    pub concurrency: usize,
+    pub start_slot: u64,
+    pub backfill: IndexerBackfillConfig,
+}
+
+/// # Description
+///     #[derive(Debug, Deserialize)]
+///     区块索引回填配置，回填进度单独记录检查点，中断后从上次位置继续
+/// # Fields
+///     pub enabled: bool, 是否在启动时回填
+///     pub start_slot: u64, 回填起点（包含）
+///     pub end_slot: u64, 回填终点（包含）
+#[derive(Debug, Deserialize, Clone)]
+#[allow(dead_code)]
+pub struct IndexerBackfillConfig {
+    pub enabled: bool,
+    pub start_slot: u64,
+    pub end_slot: u64,
+}
+
+/// # Description
+///     #[derive(Debug, Deserialize)]
+///     网络吞吐采样任务配置
+/// # Fields
+///     pub enabled: bool, 是否启动采样任务
+///     pub interval: u64, 采样间隔（秒）
+///     pub batch_size: u64, 每轮最多采样的 slot 数量
+///     pub concurrency: usize, 同时拉取区块的最大并发数
+///     pub lookback_slots: u64, 首次启动（没有采样）时从最新 slot 往前采样的数量
+///     pub retention: i64, 采样保留时长（秒），同时是查询窗口的上限
+///     pub max_points: i64, 单次查询最多返回的时间桶数量
+///     pub default_window: i64, 默认窗口长度（秒）
+///     pub default_granularity: i64, 默认时间桶长度（秒）
+#[derive(Debug, Deserialize, Clone)]
+#[allow(dead_code)]
+pub struct NetworkJobConfig {
+    pub enabled: bool,
+    pub interval: u64,
+    pub batch_size: u64,
+    pub concurrency: usize,
+    pub lookback_slots: u64,
+    pub retention: i64,
+    pub max_points: i64,
+    pub default_window: i64,
+    pub default_granularity: i64,
+}
+
+/// # Description
+///     #[derive(Debug, Deserialize)]
+///     Webhook 投递任务配置
+/// # Fields
+///     pub enabled: bool, 是否启动投递任务
+///     pub interval: u64, 扫描待投递记录的间隔（秒），新事件会立即唤醒投递
+///     pub batch_size: u64, 每轮最多投递的记录数量
+///     pub timeout: u64, 单次投递的请求超时（秒）
+///     pub max_attempts: i32, 单条记录的最大尝试次数
+///     pub retry_base: i64, 重试退避的基础时间（秒），每次重试翻倍
+///     pub retry_max: i64, 重试退避的最大时间（秒）
+///     pub balance_interval: u64, 检查钱包余额阈值的间隔（秒），0 表示不检查
+#[derive(Debug, Deserialize, Clone)]
+#[allow(dead_code)]
+pub struct WebhookJobConfig {
+    pub enabled: bool,
+    pub interval: u64,
+    pub batch_size: u64,
+    pub timeout: u64,
+    pub max_attempts: i32,
+    pub retry_base: i64,
+    pub retry_max: i64,
+    pub balance_interval: u64,
+}
+
+/// # Description
+///     #[derive(Debug, Deserialize)]
+///     链上订阅（Solana PubSub）任务配置
+/// # Fields
+///     pub enabled: bool, 是否启动订阅任务
+///     pub poll_interval: u64, 轮询间隔（秒），连接断开时以轮询补偿充值扫描，确认跟踪始终轮询兜底
+///     pub refresh_interval: u64, 刷新托管地址列表的间隔（秒）
+///     pub reconnect_base: u64, 重连退避的基础时间（秒），每次失败翻倍
+///     pub reconnect_max: u64, 重连退避的最大时间（秒）
+///     pub heartbeat_timeout: u64, 超过该时间未收到 slot 推送视为连接已断开（秒）
+///     pub signature_limit: usize, 扫描托管地址时每页拉取的交易签名数量
+///     pub confirm_timeout: i64, 跟踪交易的最长等待时间（秒），超时视为被丢弃
+#[derive(Debug, Deserialize, Clone)]
+#[allow(dead_code)]
+pub struct PubsubJobConfig {
+    pub enabled: bool,
+    pub poll_interval: u64,
+    pub refresh_interval: u64,
+    pub reconnect_base: u64,
+    pub reconnect_max: u64,
+    pub heartbeat_timeout: u64,
+    pub signature_limit: usize,
+    pub confirm_timeout: i64,
+}
+
+/// # Description
+///     #[derive(Debug, Deserialize)]
+///     最终确认与分叉回滚任务配置：已索引区块与已入账充值在 finalized 后提升确认级别，
+///     所在分叉被丢弃的区块标记为跳过，被丢弃交易的入账予以回滚
+/// # Fields
+///     pub enabled: bool, 是否启动任务
+///     pub interval: u64, 核对间隔（秒）
+///     pub batch_size: u64, 每轮最多核对的区块数量与充值数量
+///     pub drop_confirmations: u32, 判定充值交易被丢弃前，需连续确认其不在最终确认链上的轮数
+#[derive(Debug, Deserialize, Clone)]
+#[allow(dead_code)]
+pub struct ReorgJobConfig {
+    pub enabled: bool,
+    pub interval: u64,
+    pub batch_size: u64,
+    pub drop_confirmations: u32,
+}
+
+/// # Description
+///     #[derive(Debug, Deserialize)]
+///     OpenBook 行情配置
+/// # Fields
+///     pub default_depth: usize, 默认订单簿档位数量
+///     pub max_depth: usize, 最大订单簿档位数量
+///     pub fill_limit: usize, 最近成交的最大返回数量
+///     pub stream_interval_ms: u64, 行情推送的轮询间隔（毫秒）
+#[derive(Debug, Deserialize)]
+#[allow(dead_code)]
+pub struct MarketConfig {
+    pub default_depth: usize,
+    pub max_depth: usize,
+    pub fill_limit: usize,
+    pub stream_interval_ms: u64,
+}
+
+/// # Description
+///     #[derive(Debug, Deserialize)]
+///     实时推送（/v1/stream）配置
+/// # Fields
+///     pub buffer: usize, 事件缓冲区大小，连接消费落后超过该数量时丢弃最早的事件
+///     pub max_lagged: u32, 单个连接允许发生积压丢弃的次数，超过后断开连接
+///     pub send_timeout: u64, WebSocket 单条消息的发送超时（秒），超时视为客户端过慢并断开
+///     pub heartbeat: u64, WebSocket ping 间隔（秒）
+///     pub price_interval: u64, 价格推送的检查间隔（秒），0 表示不推送价格
+#[derive(Debug, Deserialize)]
+#[allow(dead_code)]
+pub struct StreamConfig {
+    pub buffer: usize,
+    pub max_lagged: u32,
+    pub send_timeout: u64,
+    pub heartbeat: u64,
+    pub price_interval: u64,
+}
+
+/// # Description
+///     #[derive(Debug, Deserialize)]
+///     钱包交易历史配置
+/// # Fields
+///     pub default_page_size: u64, 默认每页数量
+///     pub max_page_size: u64, 每页最大数量
+///     pub sync_limit: usize, 单次向 RPC 拉取签名的最大数量
+///     pub concurrency: usize, 同时拉取交易详情的最大并发数
+#[derive(Debug, Deserialize)]
+#[allow(dead_code)]
+pub struct HistoryConfig {
+    pub default_page_size: u64,
+    pub max_page_size: u64,
+    pub sync_limit: usize,
+    pub concurrency: usize,
+}
+
+/// # Description
+///     #[derive(Debug, Deserialize)]
+///     Anchor IDL 配置
+/// # Fields
+///     pub directory: String, 本地 IDL 目录，启动时加载其中全部 .json 文件
+///     pub programs: Vec<String>, 启动时从链上 IDL 账户拉取 IDL 的程序地址
+#[derive(Debug, Deserialize)]
+#[allow(dead_code)]
+pub struct IdlConfig {
+    pub directory: String,
+    pub programs: Vec<String>,
+}
+
+/// # Description
+///     #[derive(Debug, Deserialize)]
+///     角色权限配置
+/// # Fields
+///     pub admin_accounts: Vec<String>, 启动时授予内置 admin 角色的用户账号，用于初始化第一个管理员
+#[derive(Debug, Deserialize)]
+#[allow(dead_code)]
+pub struct RbacConfig {
+    pub admin_accounts: Vec<String>,
+}
+
+/// # Description
+///     #[derive(Debug, Deserialize)]
+///     邮件验证与找回密码配置
+/// # Fields
+///     pub transport: String, 发送方式：smtp 使用 smtp.net_ease_163 发送；file 写入 outbox_dir；memory 只保存在进程内存
+///     pub outbox_dir: String, file 发件箱目录
+///     pub require_verification: bool, 邮箱注册是否必须先通过验证码
+///     pub code_length: usize, 验证码位数
+///     pub code_ttl: u64, 验证码有效期（秒）
+///     pub code_max_attempts: i32, 单个验证码最多允许的错误次数
+///     pub resend_interval: u64, 同一邮箱同一用途的最小发送间隔（秒）
+///     pub reset_token_ttl: u64, 重置密码链接有效期（秒）
+///     pub reset_url: String, 重置密码页面地址，令牌拼接在末尾
+#[derive(Debug, Deserialize)]
+#[allow(dead_code)]
+pub struct MailConfig {
+    pub transport: String,
+    pub outbox_dir: String,
+    pub require_verification: bool,
+    pub code_length: usize,
+    pub code_ttl: u64,
+    pub code_max_attempts: i32,
+    pub resend_interval: u64,
+    pub reset_token_ttl: u64,
+    pub reset_url: String,
+}
+
+/// # Description
+///     #[derive(Debug, Deserialize)]
+///     短信验证码配置
+/// # Fields
+///     pub provider: String, 短信服务商：log 只写入日志；memory 只保存在进程内存
+///     pub default_country_code: String, 输入不带国家码时使用的默认国家码，为空时要求输入带国家码
+///     pub require_verification: bool, 手机号注册是否必须先通过验证码
+///     pub code_length: usize, 验证码位数
+///     pub code_ttl: u64, 验证码有效期（秒）
+///     pub code_max_attempts: i32, 单个验证码最多允许的错误次数
+///     pub resend_interval: u64, 同一手机号同一用途的最小发送间隔（秒）
+#[derive(Debug, Deserialize)]
+#[allow(dead_code)]
+pub struct SmsConfig {
+    pub provider: String,
+    pub default_country_code: String,
+    pub require_verification: bool,
+    pub code_length: usize,
+    pub code_ttl: u64,
+    pub code_max_attempts: i32,
+    pub resend_interval: u64,
+}
+
+/// # Description
+///     #[derive(Debug, Deserialize)]
+///     两步验证配置
+/// # Fields
+///     pub issuer: String, 验证器应用中显示的发行方名称
+///     pub digits: u32, 验证码位数
+///     pub period: u64, 时间步长（秒）
+///     pub skew: u64, 允许的时钟偏差（时间步数）
+///     pub backup_code_count: usize, 每次生成的恢复码数量
+///     pub challenge_ttl: u64, 密码通过后完成两步验证的有效期（秒）
+///     pub challenge_max_attempts: i32, 单次登录挑战最多允许的错误次数
+///     pub max_failures: u32, 同一用户在窗口内允许的两步验证失败次数，达到后锁定，登录挑战与敏感操作共用
+///     pub failure_window: u64, 失败次数统计窗口（秒）
+///     pub lockout_duration: u64, 锁定时长（秒）
+#[derive(Debug, Deserialize)]
+#[allow(dead_code)]
+pub struct TwoFactorConfig {
+    pub issuer: String,
+    pub digits: u32,
+    pub period: u64,
+    pub skew: u64,
+    pub backup_code_count: usize,
+    pub challenge_ttl: u64,
+    pub challenge_max_attempts: i32,
+    pub max_failures: u32,
+    pub failure_window: u64,
+    pub lockout_duration: u64,
+}
+
+/// # Description
+///     #[derive(Debug, Deserialize)]
+///     登录限流与锁定配置
+/// # Fields
+///     pub driver: String, 失败计数存储驱动：redis（不可用时降级为进程内存） / memory
+///     pub window: u64, 失败次数统计窗口（秒），窗口内无新的失败时计数过期
+///     pub free_attempts: u32, 不加延迟的失败次数
+///     pub base_delay_ms: u64, 超出后的首次延迟（毫秒），此后每次失败翻倍
+///     pub max_delay_ms: u64, 单次延迟上限（毫秒）
+///     pub identifier_max_failures: u32, 同一账号在窗口内允许的失败次数，达到后锁定
+///     pub ip_max_failures: u32, 同一 IP 在窗口内允许的失败次数，达到后锁定
+///     pub lockout_duration: u64, 锁定时长（秒）
+///     pub trust_forwarded_for: bool, 是否信任 X-Forwarded-For 请求头，仅在可信反向代理之后开启
+///     pub trusted_proxy_hops: usize, 应用前的可信反向代理层数，从 X-Forwarded-For 右侧第该数量个地址取客户端 IP
+#[derive(Debug, Deserialize)]
+#[allow(dead_code)]
+pub struct LoginThrottleConfig {
+    pub driver: String,
+    pub window: u64,
+    pub free_attempts: u32,
+    pub base_delay_ms: u64,
+    pub max_delay_ms: u64,
+    pub identifier_max_failures: u32,
+    pub ip_max_failures: u32,
+    pub lockout_duration: u64,
+    pub trust_forwarded_for: bool,
+    pub trusted_proxy_hops: usize,
+}
+
+/// # Description
+///     #[derive(Debug, Deserialize)]
+///     用户管理后台配置
+/// # Fields
+///     pub default_page_size: u64, 用户列表默认每页数量
+///     pub max_page_size: u64, 用户列表每页最大数量
+///     pub min_level: i8, 允许设置的最低用户级别
+///     pub max_level: i8, 允许设置的最高用户级别
+#[derive(Debug, Deserialize)]
+#[allow(dead_code)]
+pub struct AdminConfig {
+    pub default_page_size: u64,
+    pub max_page_size: u64,
+    pub min_level: i8,
+    pub max_level: i8,
+}
+
+/// # Description
+///     #[derive(Debug, Deserialize)]
+///     钱包配置
+/// # Fields
+///     pub key_encryption_key: String, 钱包私钥加密主密钥，Base64 编码的 32 字节
+#[derive(Debug, Deserialize)]
+#[allow(dead_code)]
+pub struct WalletConfig {
+    pub key_encryption_key: String,
+}
+
 impl Config {
     /// # Description
     ///     解析程序配置文件
@@ -228,6 +762,20 @@ impl Config {
             .add_source(File::with_name("conf/redis.yaml"))
             .add_source(File::with_name("conf/smtp.yaml"))
             .add_source(File::with_name("conf/storage.yaml"))
+            .add_source(File::with_name("conf/solana.yaml"))
+            .add_source(File::with_name("conf/oracle.yaml"))
+            .add_source(File::with_name("conf/job.yaml"))
+            .add_source(File::with_name("conf/market.yaml"))
+            .add_source(File::with_name("conf/history.yaml"))
+            .add_source(File::with_name("conf/idl.yaml"))
+            .add_source(File::with_name("conf/stream.yaml"))
+            .add_source(File::with_name("conf/rbac.yaml"))
+            .add_source(File::with_name("conf/mail.yaml"))
+            .add_source(File::with_name("conf/sms.yaml"))
+            .add_source(File::with_name("conf/two_factor.yaml"))
+            .add_source(File::with_name("conf/login_throttle.yaml"))
+            .add_source(File::with_name("conf/admin.yaml"))
+            .add_source(File::with_name("conf/wallet.yaml"))
             .set_override("override", "1")?;
 
         let config = builder.build()?;
@@ -1,3 +1,3 @@
 mod config;
 
-pub use config::{Config, JwtConfig};
\ No newline at end of file
+pub use config::{Config, JwtConfig, JwtKeyConfig, OracleConfig, OracleFeedConfig, OraclePoolConfig, OracleTokenConfig, JobConfig, OrderJobConfig, DcaJobConfig, IndexerJobConfig, IndexerBackfillConfig, NetworkJobConfig, WebhookJobConfig, PubsubJobConfig, ReorgJobConfig, HistoryConfig, IdlConfig, StreamConfig, RbacConfig, MailConfig, SmsConfig, TwoFactorConfig, LoginThrottleConfig, AdminConfig, WalletConfig, SmtpDetails};
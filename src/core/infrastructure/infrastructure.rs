@@ -7,7 +7,14 @@ use crate::core::infrastructure::{
     log::Log,
     presentation::{ Persistence, model::migration::MigratorHandle },
     webserver::WebServer,
-    jwt::Jwt
+    jwt::Jwt,
+    rpc::Rpc,
+    redis::Redis,
+    oracle::PriceOracle,
+    mail::Mailer,
+    sms::SmsSender,
+    throttle::LoginThrottle,
+    keystore::KeyStore,
 };
 
 /// # Description
@@ -18,6 +25,13 @@ use crate::core::infrastructure::{
 ///     pub persistence: Arc<Persistence>, 持久化实例
 ///     pub webserver: Arc<WebServer>, WebServer 实例
 ///     pub jwt: Arc<Jwt>, Jwt 实例
+///     pub rpc: Arc<Rpc>, Solana RPC 实例
+///     pub redis: Arc<Redis>, Redis 实例
+///     pub oracle: Arc<PriceOracle>, 价格预言机实例
+///     pub mailer: Arc<Mailer>, 邮件发送实例
+///     pub sms: Arc<SmsSender>, 短信发送实例
+///     pub login_throttle: Arc<LoginThrottle>, 登录限流实例
+///     pub keystore: Arc<KeyStore>, 钱包私钥加密实例
 #[allow(dead_code)]
 pub struct InfrastructureLayer {
     pub config: Arc<Config>,
@@ -25,6 +39,13 @@ pub struct InfrastructureLayer {
     pub persistence: Arc<Persistence>,
     pub webserver: Arc<WebServer>,
     pub jwt: Arc<Jwt>,
+    pub rpc: Arc<Rpc>,
+    pub redis: Arc<Redis>,
+    pub oracle: Arc<PriceOracle>,
+    pub mailer: Arc<Mailer>,
+    pub sms: Arc<SmsSender>,
+    pub login_throttle: Arc<LoginThrottle>,
+    pub keystore: Arc<KeyStore>,
 }
 
 impl InfrastructureLayer {
@@ -48,16 +69,43 @@ impl InfrastructureLayer {
         let persistence = Arc::new(Persistence::new(Arc::clone(&config)).await?);
         info!("+InfrastructureLayer [Persistence] Instant persistence complete.");
 
-        // 迁移数据库
+        // 迁移数据库，只执行未应用的迁移，保留已有数据以便后台任务重启后继续运行
         let db = Arc::clone(&persistence);
-        MigratorHandle::down(&db.db, None).await?;
         MigratorHandle::up(&db.db, None).await?;
         info!("+InfrastructureLayer [Persistence] Migrator persistence complete.");
 
+        // Solana RPC 实例
+        let rpc = Arc::new(Rpc::new(Arc::clone(&config))?);
+        info!("+InfrastructureLayer [Rpc] Instant rpc complete.");
+
+        // Redis 实例
+        let redis = Arc::new(Redis::new(Arc::clone(&config))?);
+        info!("+InfrastructureLayer [Redis] Instant redis complete.");
+
         // JWT 实例
-        let jwt = Arc::new(Jwt::new(Arc::clone(&config)));
+        let jwt = Arc::new(Jwt::new(Arc::clone(&config), Arc::clone(&redis))?);
         info!("+InfrastructureLayer [JWT] JWT persistence complete.");
 
+        // 价格预言机实例
+        let oracle = Arc::new(PriceOracle::new(Arc::clone(&config), Arc::clone(&rpc), Arc::clone(&redis))?);
+        info!("+InfrastructureLayer [Oracle] Instant price oracle complete.");
+
+        // 邮件发送实例
+        let mailer = Arc::new(Mailer::new(Arc::clone(&config))?);
+        info!("+InfrastructureLayer [Mailer] Instant mailer complete.");
+
+        // 短信发送实例
+        let sms = Arc::new(SmsSender::new(Arc::clone(&config))?);
+        info!("+InfrastructureLayer [SmsSender] Instant sms sender complete.");
+
+        // 登录限流实例
+        let login_throttle = Arc::new(LoginThrottle::new(Arc::clone(&config), Arc::clone(&redis))?);
+        info!("+InfrastructureLayer [LoginThrottle] Instant login throttle complete.");
+
+        // 钱包私钥加密实例
+        let keystore = Arc::new(KeyStore::new(Arc::clone(&config))?);
+        info!("+InfrastructureLayer [KeyStore] Instant keystore complete.");
+
         // 启动 Web 服务
         let webserver = Arc::new(WebServer::new(Arc::clone(&config)).await?);
         info!("+InfrastructureLayer [WebServer] Instant webserver complete.");
@@ -68,6 +116,13 @@ impl InfrastructureLayer {
             persistence: Arc::clone(&persistence),
             webserver: Arc::clone(&webserver),
             jwt: Arc::clone(&jwt),
+            rpc: Arc::clone(&rpc),
+            redis: Arc::clone(&redis),
+            oracle: Arc::clone(&oracle),
+            mailer: Arc::clone(&mailer),
+            sms: Arc::clone(&sms),
+            login_throttle: Arc::clone(&login_throttle),
+            keystore: Arc::clone(&keystore),
         })
     }
 }
\ No newline at end of file
@@ -1,16 +1,25 @@
 use std::sync::Arc;
 use chrono::{Duration, Utc};
 use color_eyre::Report;
+use rand::Rng;
 use serde::{Deserialize, Serialize};
-use jsonwebtoken::{Algorithm, decode, DecodingKey, encode, EncodingKey, Header, Validation};
+use jsonwebtoken::{decode, decode_header, encode, Validation};
+use crate::core::domain::auth::{entity::token_entity::TokenKind, repository::TokenStoreInterface};
 use crate::core::infrastructure::config::{Config, JwtConfig };
+use crate::core::infrastructure::redis::Redis;
+use super::keys::{JsonWebKeySet, KeyRing};
+use super::token_store::{MemoryTokenStore, RedisTokenStore};
 
 /// Description
 ///     JWT
 /// Fields
 ///     pub jwt_config: Arc<JwtConfig>, 配置实例
+///     pub store: Arc<dyn TokenStoreInterface>, 黑名单与刷新令牌族存储
+///     keys: KeyRing, 签发与验签密钥
 pub struct Jwt {
     pub jwt_config: Arc<JwtConfig>,
+    pub store: Arc<dyn TokenStoreInterface>,
+    keys: KeyRing,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -21,13 +30,63 @@ pub struct CustomClaims {
     iss: String,
     nbf: usize,
     sub: String,
+    aud: String,
+    jti: String,
+    fam: String,
+    typ: TokenKind,
 }
 
-#[derive(Debug, Serialize)]
+impl CustomClaims {
+    /// Description
+    ///     token 所属主体，即用户id
+    pub fn subject(&self) -> &str {
+        &self.sub
+    }
+
+    /// Description
+    ///     token 唯一id，黑名单以此为键
+    pub fn id(&self) -> &str {
+        &self.jti
+    }
+
+    /// Description
+    ///     token 所属的刷新令牌族id，同一次登录签发的令牌共享
+    pub fn family(&self) -> &str {
+        &self.fam
+    }
+
+    /// Description
+    ///     token 过期时间（Unix 秒）
+    pub fn expires_at(&self) -> i64 {
+        self.exp as i64
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
 pub struct TokenOutPut {
     access_token: String,
     expires_in: u64,
+    refresh_token: String,
+    refresh_expires_in: u64,
     token_type: String,
+    #[serde(skip)]
+    access_jti: String,
+    #[serde(skip)]
+    refresh_jti: String,
+}
+
+impl TokenOutPut {
+    /// Description
+    ///     访问令牌 jti
+    pub fn access_id(&self) -> &str {
+        &self.access_jti
+    }
+
+    /// Description
+    ///     刷新令牌 jti
+    pub fn refresh_id(&self) -> &str {
+        &self.refresh_jti
+    }
 }
 
 impl Jwt {
@@ -35,60 +94,76 @@ impl Jwt {
     ///     初始化 JWT 信息
     /// # Param
     ///     config: Arc<Config>, 配置文件
+    ///     redis: Arc<Redis>, Redis 实例，token_driver 为 redis 时使用
     /// # Return
-    ///     Self: 初始化后的 JWT 实例
-    pub fn new(config: Arc<Config>) -> Self {
+    ///     Result<Self, Report>: 初始化后的 JWT 实例
+    pub fn new(config: Arc<Config>, redis: Arc<Redis>) -> Result<Self, Report> {
         let jwt_config = Arc::new(
             JwtConfig {
                 secret: config.jwt.secret.clone(),
                 jwt_ttl: config.jwt.jwt_ttl.clone(),
                 jwt_blacklist_grace_period: config.jwt.jwt_blacklist_grace_period.clone(),
                 refresh_grace_period: config.jwt.refresh_grace_period.clone(),
+                token_driver: config.jwt.token_driver.clone(),
+                issuer: config.jwt.issuer.clone(),
+                audience: config.jwt.audience.clone(),
+                signing_kid: config.jwt.signing_kid.clone(),
+                keys: config.jwt.keys.clone(),
             }
         );
 
-        Self { jwt_config }
+        // 签发与验签密钥
+        let keys = KeyRing::load(&jwt_config)?;
+
+        // 令牌状态存储
+        let store: Arc<dyn TokenStoreInterface> = match jwt_config.token_driver.as_str() {
+            "redis" => Arc::new(RedisTokenStore::new(redis)),
+            "memory" => Arc::new(MemoryTokenStore::new()),
+            driver => return Err(Report::msg(format!("+InfrastructureLayer [JWT] Unknown token driver: {}", driver))),
+        };
+
+        Ok(Self { jwt_config, store, keys })
     }
 
     /// Description
-    ///     生成 JWT
+    ///     刷新令牌有效期（秒）：比访问令牌多出 refresh_grace_period，访问令牌过期后仍可在该时间内刷新
+    pub fn refresh_ttl(&self) -> u64 {
+        self.jwt_config.jwt_ttl + self.jwt_config.refresh_grace_period
+    }
+
+    /// Description
+    ///     生成 JWT，同时签发访问令牌与刷新令牌
     /// Params
     ///     user_id: &str - 用户id
+    ///     family: &str - 刷新令牌族id
     /// Return
     ///     Result<TokenOutPut, Report>
     ///         - TokenOutPut jwt 信息输出
     ///         - Report 错误报告
-    pub fn create_jwt(&self, user_id: &str) -> Result<TokenOutPut, Report> {
-        let now = Utc::now();
-
-        // 自定义 claims
-        let claims = CustomClaims {
-            exp: (now + Duration::seconds(self.jwt_config.jwt_ttl as i64)).timestamp() as usize,
-            iat: now.timestamp() as usize,
-            iss: "GuardName".to_string(),
-            nbf: (now.timestamp() - 1000) as usize,
-            sub: user_id.to_string(),
-        };
+    pub fn create_jwt(&self, user_id: &str, family: &str) -> Result<TokenOutPut, Report> {
+        let access_jti = Self::random_id();
+        let refresh_jti = Self::random_id();
 
-        // 生成 access_token
-        let access_token = encode(
-            &Header::new(Algorithm::HS256),
-            &claims,
-            &EncodingKey::from_secret(self.jwt_config.secret.as_ref()),
-        )?;
+        // 生成 access_token 与 refresh_token
+        let access_token = self.encode_claims(user_id, family, &access_jti, TokenKind::Access, self.jwt_config.jwt_ttl)?;
+        let refresh_token = self.encode_claims(user_id, family, &refresh_jti, TokenKind::Refresh, self.refresh_ttl())?;
 
         // 输出 token 信息
         let token_data = TokenOutPut {
             access_token,
             expires_in: self.jwt_config.jwt_ttl,
+            refresh_token,
+            refresh_expires_in: self.refresh_ttl(),
             token_type: "Bearer".to_string(),
+            access_jti,
+            refresh_jti,
         };
 
         Ok(token_data)
     }
 
     /// Description
-    ///     验证 JWT
+    ///     验证访问令牌，刷新令牌不能用于访问接口
     /// Params
     ///     token: &str - token
     /// Return
@@ -96,13 +171,73 @@ impl Jwt {
     ///         - CustomClaims 自定义 Claims
     ///         - Report 错误报告
     pub fn validate_jwt(&self, token: &str) -> Result<CustomClaims, Report> {
+        self.decode_claims(token, TokenKind::Access)
+    }
+
+    /// Description
+    ///     验证刷新令牌
+    /// Params
+    ///     token: &str - token
+    /// Return
+    ///     Result<CustomClaims, Report>
+    ///         - CustomClaims 自定义 Claims
+    ///         - Report 错误报告
+    pub fn validate_refresh(&self, token: &str) -> Result<CustomClaims, Report> {
+        self.decode_claims(token, TokenKind::Refresh)
+    }
+
+    /// Description
+    ///     对外公布的验签公钥（JWKS），HS256 共享密钥不会公布
+    pub fn jwks(&self) -> &JsonWebKeySet {
+        self.keys.jwks()
+    }
+
+    /// Description
+    ///     生成随机的令牌id / 族id
+    pub fn random_id() -> String {
+        let mut rng = rand::thread_rng();
+        (0..16).map(|_| format!("{:02x}", rng.gen::<u8>())).collect()
+    }
+
+    /// Description
+    ///     签发指定类型的令牌
+    fn encode_claims(&self, user_id: &str, family: &str, jti: &str, kind: TokenKind, ttl: u64) -> Result<String, Report> {
+        let now = Utc::now();
+
+        // 自定义 claims
+        let claims = CustomClaims {
+            exp: (now + Duration::seconds(ttl as i64)).timestamp() as usize,
+            iat: now.timestamp() as usize,
+            iss: self.jwt_config.issuer.clone(),
+            nbf: (now.timestamp() - 1000) as usize,
+            sub: user_id.to_string(),
+            aud: self.jwt_config.audience.clone(),
+            jti: jti.to_string(),
+            fam: family.to_string(),
+            typ: kind,
+        };
+
+        let (header, key) = self.keys.signing();
+        Ok(encode(&header, &claims, key)?)
+    }
+
+    /// Description
+    ///     按头部 kid 选择公钥校验签名，并校验有效期、签发方、受众与令牌类型
+    fn decode_claims(&self, token: &str, kind: TokenKind) -> Result<CustomClaims, Report> {
+        let header = decode_header(token)?;
+        let (algorithm, key) = self.keys.verification(&header)?;
+
+        let mut validation = Validation::new(algorithm);
+        validation.set_issuer(&[&self.jwt_config.issuer]);
+        validation.set_audience(&[&self.jwt_config.audience]);
+
         // 校验 jwt 是否正确
-        let get_jwt = decode::<CustomClaims>(
-            token,
-            &DecodingKey::from_secret(self.jwt_config.secret.as_ref()),
-            &Validation::new(Algorithm::HS256),
-        ).map(|data| data.claims);
+        let claims = decode::<CustomClaims>(token, key, &validation).map(|data| data.claims)?;
+
+        if claims.typ != kind {
+            return Err(Report::msg("令牌类型不匹配"));
+        }
 
-        Ok(get_jwt?)
+        Ok(claims)
     }
 }
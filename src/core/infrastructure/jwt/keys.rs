@@ -0,0 +1,273 @@
+use std::collections::HashMap;
+use std::fs;
+use base64::Engine;
+use base64::engine::general_purpose::{STANDARD, URL_SAFE_NO_PAD};
+use color_eyre::{Report, Result};
+use jsonwebtoken::{Algorithm, DecodingKey, EncodingKey, Header};
+use serde::Serialize;
+use crate::core::infrastructure::config::{JwtConfig, JwtKeyConfig};
+
+/// # Description
+///     JWKS 中的单个公钥，字段遵循 RFC 7517 / RFC 8037
+/// # Fields
+///     kty: String - 密钥类型：RSA / OKP
+///     kid: String - 密钥id，与令牌头部的 kid 对应
+///     alg: String - 签名算法：RS256 / EdDSA
+///     usage: String - 用途，固定为 sig
+///     n / e: Option<String> - RSA 模数与指数（base64url）
+///     crv / x: Option<String> - OKP 曲线名与公钥（base64url）
+#[derive(Clone, Debug, Serialize)]
+pub struct JsonWebKey {
+    pub kty: String,
+    pub kid: String,
+    pub alg: String,
+    #[serde(rename = "use")]
+    pub usage: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub n: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub e: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub crv: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub x: Option<String>,
+}
+
+/// # Description
+///     JWKS 文档，/.well-known/jwks.json 的响应体
+#[derive(Clone, Debug, Serialize)]
+pub struct JsonWebKeySet {
+    pub keys: Vec<JsonWebKey>,
+}
+
+/// # Description
+///     验签公钥
+struct VerificationKey {
+    algorithm: Algorithm,
+    key: DecodingKey,
+}
+
+/// # Description
+///     签发与验签密钥集合。signing_kid 为空时使用共享密钥 HS256 签发且令牌不带 kid；
+///     否则使用对应的非对称私钥签发，其余只配置了公钥的密钥用于轮换期间继续验证旧令牌
+/// # Fields
+///     signing_kid: Option<String> - 签发密钥id
+///     algorithm: Algorithm - 签发算法
+///     encoding: EncodingKey - 签发私钥
+///     verification: HashMap<String, VerificationKey> - kid -> 验签公钥
+///     shared: Option<DecodingKey> - HS256 共享密钥，仅在使用 HS256 签发时接受不带 kid 的令牌
+///     jwks: JsonWebKeySet - 对外公布的公钥
+pub struct KeyRing {
+    signing_kid: Option<String>,
+    algorithm: Algorithm,
+    encoding: EncodingKey,
+    verification: HashMap<String, VerificationKey>,
+    shared: Option<DecodingKey>,
+    jwks: JsonWebKeySet,
+}
+
+impl KeyRing {
+    /// # Description
+    ///     从配置加载密钥，私钥与公钥均为 PEM 文件
+    /// # Param
+    ///     config: &JwtConfig - JWT 配置
+    /// # Return
+    ///     Result<KeyRing, Report>: 密钥集合
+    pub fn load(config: &JwtConfig) -> Result<Self, Report> {
+        let mut verification = HashMap::new();
+        let mut jwks = JsonWebKeySet { keys: Vec::new() };
+        let mut signing: Option<(String, Algorithm, EncodingKey)> = None;
+
+        for key_config in &config.keys {
+            let algorithm = parse_algorithm(&key_config.algorithm)?;
+            let public_pem = fs::read(&key_config.public_key)
+                .map_err(|e| Report::msg(format!("读取公钥 {} 失败: {}", key_config.public_key, e)))?;
+
+            let decoding = match algorithm {
+                Algorithm::RS256 => DecodingKey::from_rsa_pem(&public_pem)?,
+                _ => DecodingKey::from_ed_pem(&public_pem)?,
+            };
+            jwks.keys.push(public_jwk(key_config, algorithm, &String::from_utf8_lossy(&public_pem))?);
+            verification.insert(key_config.kid.clone(), VerificationKey { algorithm, key: decoding });
+
+            if key_config.kid == config.signing_kid {
+                signing = Some((key_config.kid.clone(), algorithm, encoding_key(key_config, algorithm)?));
+            }
+        }
+
+        if config.signing_kid.is_empty() {
+            return Ok(Self {
+                signing_kid: None,
+                algorithm: Algorithm::HS256,
+                encoding: EncodingKey::from_secret(config.secret.as_ref()),
+                verification,
+                shared: Some(DecodingKey::from_secret(config.secret.as_ref())),
+                jwks,
+            });
+        }
+
+        let (kid, algorithm, encoding) = signing
+            .ok_or_else(|| Report::msg(format!("签发密钥 {} 未配置", config.signing_kid)))?;
+
+        Ok(Self { signing_kid: Some(kid), algorithm, encoding, verification, shared: None, jwks })
+    }
+
+    /// # Description
+    ///     签发用的头部（含 kid）与私钥
+    pub fn signing(&self) -> (Header, &EncodingKey) {
+        let mut header = Header::new(self.algorithm);
+        header.kid = self.signing_kid.clone();
+
+        (header, &self.encoding)
+    }
+
+    /// # Description
+    ///     根据令牌头部选择验签公钥，算法必须与密钥配置一致，防止算法混淆
+    /// # Param
+    ///     header: &Header - 令牌头部
+    /// # Return
+    ///     Result<(Algorithm, &DecodingKey), Report>: 验签算法与公钥
+    pub fn verification(&self, header: &Header) -> Result<(Algorithm, &DecodingKey), Report> {
+        let (algorithm, key) = match &header.kid {
+            Some(kid) => self.verification.get(kid)
+                .map(|key| (key.algorithm, &key.key))
+                .ok_or_else(|| Report::msg(format!("未知的密钥id {}", kid)))?,
+            None => self.shared.as_ref()
+                .map(|key| (Algorithm::HS256, key))
+                .ok_or_else(|| Report::msg("令牌缺少密钥id"))?,
+        };
+
+        if header.alg != algorithm {
+            return Err(Report::msg("令牌签名算法不匹配"));
+        }
+
+        Ok((algorithm, key))
+    }
+
+    /// # Description
+    ///     对外公布的公钥集合
+    pub fn jwks(&self) -> &JsonWebKeySet {
+        &self.jwks
+    }
+}
+
+/// # Description
+///     解析配置中的签名算法
+fn parse_algorithm(algorithm: &str) -> Result<Algorithm, Report> {
+    match algorithm {
+        "RS256" => Ok(Algorithm::RS256),
+        "EdDSA" => Ok(Algorithm::EdDSA),
+        other => Err(Report::msg(format!("不支持的签名算法 {}", other))),
+    }
+}
+
+/// # Description
+///     读取签发私钥
+fn encoding_key(key_config: &JwtKeyConfig, algorithm: Algorithm) -> Result<EncodingKey, Report> {
+    let path = key_config.private_key.as_deref()
+        .ok_or_else(|| Report::msg(format!("签发密钥 {} 缺少私钥", key_config.kid)))?;
+    let private_pem = fs::read(path)
+        .map_err(|e| Report::msg(format!("读取私钥 {} 失败: {}", path, e)))?;
+
+    Ok(match algorithm {
+        Algorithm::RS256 => EncodingKey::from_rsa_pem(&private_pem)?,
+        _ => EncodingKey::from_ed_pem(&private_pem)?,
+    })
+}
+
+/// # Description
+///     由 PEM 公钥生成 JWK。支持 SubjectPublicKeyInfo（PUBLIC KEY）与 PKCS#1（RSA PUBLIC KEY）格式
+/// # Param
+///     key_config: &JwtKeyConfig - 密钥配置
+///     algorithm: Algorithm - 签名算法
+///     pem: &str - PEM 公钥
+/// # Return
+///     Result<JsonWebKey, Report>: JWK
+pub fn public_jwk(key_config: &JwtKeyConfig, algorithm: Algorithm, pem: &str) -> Result<JsonWebKey, Report> {
+    let der = STANDARD.decode(
+        pem.lines().filter(|line| !line.starts_with("-----")).collect::<String>().trim()
+    ).map_err(|e| Report::msg(format!("公钥 {} 不是合法的 PEM: {}", key_config.kid, e)))?;
+
+    // PKCS#1 直接是 RSAPublicKey，SubjectPublicKeyInfo 需要先取出 BIT STRING 中的公钥
+    let public_key = if pem.contains("BEGIN RSA PUBLIC KEY") {
+        der
+    } else {
+        let (_, spki, _) = der_element(&der, 0, 0x30)?;
+        let (_, _, next) = der_element(spki, 0, 0x30)?;
+        let (_, bits, _) = der_element(spki, next, 0x03)?;
+        bits.get(1..).ok_or_else(|| Report::msg("公钥格式不正确"))?.to_vec()
+    };
+
+    let mut jwk = JsonWebKey {
+        kty: String::new(),
+        kid: key_config.kid.clone(),
+        alg: key_config.algorithm.clone(),
+        usage: "sig".to_string(),
+        n: None,
+        e: None,
+        crv: None,
+        x: None,
+    };
+
+    match algorithm {
+        Algorithm::RS256 => {
+            let (_, rsa, _) = der_element(&public_key, 0, 0x30)?;
+            let (_, modulus, next) = der_element(rsa, 0, 0x02)?;
+            let (_, exponent, _) = der_element(rsa, next, 0x02)?;
+            jwk.kty = "RSA".to_string();
+            jwk.n = Some(URL_SAFE_NO_PAD.encode(strip_leading_zeros(modulus)));
+            jwk.e = Some(URL_SAFE_NO_PAD.encode(strip_leading_zeros(exponent)));
+        }
+        _ => {
+            if public_key.len() != 32 {
+                return Err(Report::msg(format!("公钥 {} 不是 Ed25519 公钥", key_config.kid)));
+            }
+            jwk.kty = "OKP".to_string();
+            jwk.crv = Some("Ed25519".to_string());
+            jwk.x = Some(URL_SAFE_NO_PAD.encode(&public_key));
+        }
+    }
+
+    Ok(jwk)
+}
+
+/// # Description
+///     读取一个 DER 元素并校验标签
+/// # Param
+///     der: &[u8] - DER 数据
+///     pos: usize - 元素起始位置
+///     tag: u8 - 期望的标签
+/// # Return
+///     Result<(u8, &[u8], usize), Report>: 标签、内容、下一个元素的起始位置
+fn der_element(der: &[u8], pos: usize, tag: u8) -> Result<(u8, &[u8], usize), Report> {
+    let malformed = || Report::msg("公钥格式不正确");
+
+    let actual = *der.get(pos).ok_or_else(malformed)?;
+    if actual != tag {
+        return Err(malformed());
+    }
+
+    let first = *der.get(pos + 1).ok_or_else(malformed)?;
+    let (len, header) = if first & 0x80 == 0 {
+        (first as usize, 2)
+    } else {
+        let count = (first & 0x7f) as usize;
+        if count == 0 || count > 4 {
+            return Err(malformed());
+        }
+        let bytes = der.get(pos + 2..pos + 2 + count).ok_or_else(malformed)?;
+        (bytes.iter().fold(0usize, |len, byte| (len << 8) | *byte as usize), 2 + count)
+    };
+
+    let start = pos + header;
+    let content = der.get(start..start + len).ok_or_else(malformed)?;
+
+    Ok((actual, content, start + len))
+}
+
+/// # Description
+///     去掉 DER INTEGER 为表示正数而补的前导 0
+fn strip_leading_zeros(bytes: &[u8]) -> &[u8] {
+    let start = bytes.iter().position(|byte| *byte != 0).unwrap_or(bytes.len().saturating_sub(1));
+    &bytes[start..]
+}
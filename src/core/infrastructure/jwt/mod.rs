@@ -1,3 +1,7 @@
 mod jwt;
+mod keys;
+mod token_store;
 
-pub use jwt::{Jwt, TokenOutPut};
\ No newline at end of file
+pub use jwt::{Jwt, TokenOutPut};
+pub use keys::{JsonWebKey, JsonWebKeySet, KeyRing, public_jwk};
+pub use token_store::{MemoryTokenStore, RedisTokenStore};
\ No newline at end of file
@@ -0,0 +1,203 @@
+use std::{collections::HashMap, sync::Arc, time::{Duration, Instant}};
+use axum::async_trait;
+use color_eyre::{Report, Result};
+use redis::{AsyncCommands, Script};
+use tokio::sync::RwLock;
+use crate::core::domain::auth::{entity::token_entity::RefreshFamily, repository::TokenStoreInterface};
+use crate::core::infrastructure::redis::Redis;
+
+/// # Description
+///     比较并替换：仅当键的当前值与期望值一致时写入新值，保证同一枚刷新令牌只能轮换一次
+const SWAP_SCRIPT: &str = r#"
+if redis.call('GET', KEYS[1]) == ARGV[1] then
+    redis.call('SET', KEYS[1], ARGV[2], 'EX', ARGV[3])
+    return 1
+end
+return 0
+"#;
+
+/// # Description
+///     进程内存令牌存储，单实例部署或 Redis 不可用时使用
+/// # Fields
+///     blacklist RwLock<HashMap<String, (i64, Instant)>>: jti -> (生效时间, 过期时间)
+///     families RwLock<HashMap<String, (RefreshFamily, Instant)>>: 族id -> (刷新令牌族, 过期时间)
+pub struct MemoryTokenStore {
+    blacklist: RwLock<HashMap<String, (i64, Instant)>>,
+    families: RwLock<HashMap<String, (RefreshFamily, Instant)>>,
+}
+
+impl MemoryTokenStore {
+    pub fn new() -> Self {
+        Self { blacklist: RwLock::new(HashMap::new()), families: RwLock::new(HashMap::new()) }
+    }
+}
+
+#[async_trait]
+impl TokenStoreInterface for MemoryTokenStore {
+    async fn blacklist(&self, jti: &str, effective_at: i64, ttl: u64) -> Result<(), Report> {
+        let now = Instant::now();
+        let mut blacklist = self.blacklist.write().await;
+        // 写入时顺带清理已过期的记录，避免无限增长
+        blacklist.retain(|_, (_, expires_at)| *expires_at > now);
+        blacklist.insert(jti.to_string(), (effective_at, now + Duration::from_secs(ttl)));
+
+        Ok(())
+    }
+
+    async fn blacklisted_at(&self, jti: &str) -> Result<Option<i64>, Report> {
+        let blacklist = self.blacklist.read().await;
+
+        Ok(blacklist
+            .get(jti)
+            .filter(|(_, expires_at)| *expires_at > Instant::now())
+            .map(|(effective_at, _)| *effective_at))
+    }
+
+    async fn family(&self, family_id: &str) -> Result<Option<RefreshFamily>, Report> {
+        let families = self.families.read().await;
+
+        Ok(families
+            .get(family_id)
+            .filter(|(_, expires_at)| *expires_at > Instant::now())
+            .map(|(family, _)| family.clone()))
+    }
+
+    async fn save_family(&self, family_id: &str, family: &RefreshFamily, ttl: u64) -> Result<(), Report> {
+        let now = Instant::now();
+        let mut families = self.families.write().await;
+        families.retain(|_, (_, expires_at)| *expires_at > now);
+        families.insert(family_id.to_string(), (family.clone(), now + Duration::from_secs(ttl)));
+
+        Ok(())
+    }
+
+    async fn swap_family(&self, family_id: &str, expected: &RefreshFamily, family: &RefreshFamily, ttl: u64) -> Result<bool, Report> {
+        let now = Instant::now();
+        let mut families = self.families.write().await;
+        let matches = families
+            .get(family_id)
+            .is_some_and(|(current, expires_at)| *expires_at > now && current == expected);
+        if matches {
+            families.insert(family_id.to_string(), (family.clone(), now + Duration::from_secs(ttl)));
+        }
+
+        Ok(matches)
+    }
+
+    async fn remove_family(&self, family_id: &str) -> Result<(), Report> {
+        self.families.write().await.remove(family_id);
+
+        Ok(())
+    }
+
+    async fn families(&self, user_id: i32) -> Result<Vec<(String, RefreshFamily)>, Report> {
+        let now = Instant::now();
+        let families = self.families.read().await;
+
+        Ok(families
+            .iter()
+            .filter(|(_, (family, expires_at))| *expires_at > now && family.user_id == user_id)
+            .map(|(family_id, (family, _))| (family_id.clone(), family.clone()))
+            .collect())
+    }
+}
+
+/// # Description
+///     Redis 令牌存储，多实例部署时共享黑名单与刷新令牌族
+/// # Fields
+///     redis Arc<Redis>: Redis 连接
+pub struct RedisTokenStore {
+    redis: Arc<Redis>,
+}
+
+impl RedisTokenStore {
+    pub fn new(redis: Arc<Redis>) -> Self {
+        Self { redis }
+    }
+
+    fn blacklist_key(jti: &str) -> String {
+        format!("jwt:blacklist:{}", jti)
+    }
+
+    fn family_key(family_id: &str) -> String {
+        format!("jwt:family:{}", family_id)
+    }
+
+    fn user_families_key(user_id: i32) -> String {
+        format!("jwt:user_families:{}", user_id)
+    }
+}
+
+#[async_trait]
+impl TokenStoreInterface for RedisTokenStore {
+    async fn blacklist(&self, jti: &str, effective_at: i64, ttl: u64) -> Result<(), Report> {
+        let mut conn = self.redis.connection().await?;
+        conn.set_ex::<_, _, ()>(Self::blacklist_key(jti), effective_at, ttl.max(1)).await?;
+
+        Ok(())
+    }
+
+    async fn blacklisted_at(&self, jti: &str) -> Result<Option<i64>, Report> {
+        let mut conn = self.redis.connection().await?;
+
+        Ok(conn.get(Self::blacklist_key(jti)).await?)
+    }
+
+    async fn family(&self, family_id: &str) -> Result<Option<RefreshFamily>, Report> {
+        let mut conn = self.redis.connection().await?;
+        let value: Option<String> = conn.get(Self::family_key(family_id)).await?;
+
+        match value {
+            Some(value) => Ok(Some(serde_json::from_str(&value)?)),
+            None => Ok(None),
+        }
+    }
+
+    async fn save_family(&self, family_id: &str, family: &RefreshFamily, ttl: u64) -> Result<(), Report> {
+        let mut conn = self.redis.connection().await?;
+        conn.set_ex::<_, _, ()>(Self::family_key(family_id), serde_json::to_string(family)?, ttl.max(1)).await?;
+        // 按用户索引刷新令牌族，用于列出会话；族过期或被删除后在读取时清理
+        let index_key = Self::user_families_key(family.user_id);
+        conn.sadd::<_, _, ()>(&index_key, family_id).await?;
+        conn.expire::<_, ()>(&index_key, ttl.max(1) as i64).await?;
+
+        Ok(())
+    }
+
+    async fn swap_family(&self, family_id: &str, expected: &RefreshFamily, family: &RefreshFamily, ttl: u64) -> Result<bool, Report> {
+        let mut conn = self.redis.connection().await?;
+        let swapped: i32 = Script::new(SWAP_SCRIPT)
+            .key(Self::family_key(family_id))
+            .arg(serde_json::to_string(expected)?)
+            .arg(serde_json::to_string(family)?)
+            .arg(ttl.max(1))
+            .invoke_async(&mut conn)
+            .await?;
+
+        Ok(swapped == 1)
+    }
+
+    async fn remove_family(&self, family_id: &str) -> Result<(), Report> {
+        let mut conn = self.redis.connection().await?;
+        conn.del::<_, ()>(Self::family_key(family_id)).await?;
+
+        Ok(())
+    }
+
+    async fn families(&self, user_id: i32) -> Result<Vec<(String, RefreshFamily)>, Report> {
+        let mut conn = self.redis.connection().await?;
+        let index_key = Self::user_families_key(user_id);
+        let family_ids: Vec<String> = conn.smembers(&index_key).await?;
+
+        let mut families = Vec::with_capacity(family_ids.len());
+        for family_id in family_ids {
+            let value: Option<String> = conn.get(Self::family_key(&family_id)).await?;
+            match value {
+                Some(value) => families.push((family_id, serde_json::from_str(&value)?)),
+                None => conn.srem::<_, _, ()>(&index_key, &family_id).await?,
+            }
+        }
+
+        Ok(families)
+    }
+}
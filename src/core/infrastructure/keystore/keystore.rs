@@ -0,0 +1,103 @@
+use std::sync::Arc;
+use aes_gcm_siv::aead::{Aead, KeyInit, Payload};
+use aes_gcm_siv::{Aes256GcmSiv, Nonce};
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use color_eyre::{Report, Result};
+use rand::RngCore;
+use crate::core::infrastructure::config::Config;
+
+/// 加密后私钥的前缀，用于区分尚未加密的历史数据
+pub const SEALED_PREFIX: &str = "enc:v1:";
+/// AES-GCM-SIV 随机数长度（字节）
+const NONCE_LEN: usize = 12;
+
+/// # Description
+///     钱包私钥加密组件，使用配置中的主密钥以 AES-256-GCM-SIV 加密私钥，
+///     钱包公钥作为关联数据，密文不能被挪到其它钱包上解密
+/// # Fields
+///     cipher Aes256GcmSiv: 加密器
+pub struct KeyStore {
+    cipher: Aes256GcmSiv,
+}
+
+impl KeyStore {
+    /// # Description
+    ///     根据配置创建私钥加密组件，主密钥为 Base64 编码的 32 字节
+    /// # Param
+    ///     config Arc<Config>: 配置
+    /// # Return
+    ///     Result<KeyStore, Report>: 私钥加密组件
+    pub fn new(config: Arc<Config>) -> Result<Self, Report> {
+        let key = STANDARD.decode(config.wallet.key_encryption_key.trim())
+            .map_err(|e| Report::msg(format!("钱包主密钥解码失败: {}", e)))?;
+        Self::from_key(&key)
+    }
+
+    /// # Description
+    ///     使用原始主密钥创建私钥加密组件
+    /// # Param
+    ///     key &[u8]: 32 字节主密钥
+    /// # Return
+    ///     Result<KeyStore, Report>: 私钥加密组件
+    pub fn from_key(key: &[u8]) -> Result<Self, Report> {
+        let cipher = Aes256GcmSiv::new_from_slice(key)
+            .map_err(|_| Report::msg("钱包主密钥长度必须为 32 字节"))?;
+
+        Ok(Self { cipher })
+    }
+
+    /// # Description
+    ///     加密私钥
+    /// # Param
+    ///     pub_key &str: 钱包公钥，作为关联数据
+    ///     privy_key &str: 私钥明文
+    /// # Return
+    ///     Result<String, Report>: 带前缀的密文
+    pub fn seal(&self, pub_key: &str, privy_key: &str) -> Result<String, Report> {
+        let mut nonce = [0u8; NONCE_LEN];
+        rand::thread_rng().fill_bytes(&mut nonce);
+
+        let ciphertext = self.cipher
+            .encrypt(Nonce::from_slice(&nonce), Payload { msg: privy_key.as_bytes(), aad: pub_key.as_bytes() })
+            .map_err(|_| Report::msg("钱包私钥加密失败"))?;
+
+        let mut sealed = nonce.to_vec();
+        sealed.extend_from_slice(&ciphertext);
+        Ok(format!("{}{}", SEALED_PREFIX, STANDARD.encode(sealed)))
+    }
+
+    /// # Description
+    ///     解密私钥，只在签名或导出时调用
+    /// # Param
+    ///     pub_key &str: 钱包公钥，作为关联数据
+    ///     sealed &str: 带前缀的密文
+    /// # Return
+    ///     Result<String, Report>: 私钥明文
+    pub fn open(&self, pub_key: &str, sealed: &str) -> Result<String, Report> {
+        let encoded = sealed.strip_prefix(SEALED_PREFIX)
+            .ok_or_else(|| Report::msg("钱包私钥未加密"))?;
+        let sealed = STANDARD.decode(encoded)
+            .map_err(|_| Report::msg("钱包私钥密文格式错误"))?;
+        if sealed.len() <= NONCE_LEN {
+            return Err(Report::msg("钱包私钥密文格式错误"));
+        }
+
+        let (nonce, ciphertext) = sealed.split_at(NONCE_LEN);
+        let plaintext = self.cipher
+            .decrypt(Nonce::from_slice(nonce), Payload { msg: ciphertext, aad: pub_key.as_bytes() })
+            .map_err(|_| Report::msg("钱包私钥解密失败"))?;
+
+        String::from_utf8(plaintext).map_err(|_| Report::msg("钱包私钥解密失败"))
+    }
+
+    /// # Description
+    ///     私钥字段是否已加密
+    /// # Param
+    ///     privy_key &str: 私钥字段
+    /// # Return
+    ///     bool: 已加密返回 true
+    pub fn is_sealed(&self, privy_key: &str) -> bool {
+        privy_key.starts_with(SEALED_PREFIX)
+    }
+}
@@ -0,0 +1,3 @@
+mod keystore;
+
+pub use keystore::{KeyStore, SEALED_PREFIX};
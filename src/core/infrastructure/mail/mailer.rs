@@ -0,0 +1,42 @@
+use std::sync::Arc;
+use color_eyre::{Report, Result};
+use crate::core::domain::mail::{entity::mail_entity::MailMessage, repository::MailTransportInterface};
+use crate::core::infrastructure::config::Config;
+use super::transport::{FileMailOutbox, MemoryMailOutbox, SmtpMailTransport};
+
+/// # Description
+///     邮件发送组件，按 mail.transport 选择 SMTP、文件或内存发件箱
+/// # Fields
+///     transport Arc<dyn MailTransportInterface>: 发送实现
+pub struct Mailer {
+    transport: Arc<dyn MailTransportInterface>,
+}
+
+impl Mailer {
+    /// # Description
+    ///     根据配置创建邮件发送组件
+    /// # Param
+    ///     config Arc<Config>: 配置
+    /// # Return
+    ///     Result<Mailer, Report>: 邮件发送组件
+    pub fn new(config: Arc<Config>) -> Result<Self, Report> {
+        let transport: Arc<dyn MailTransportInterface> = match config.mail.transport.as_str() {
+            "smtp" => Arc::new(SmtpMailTransport::new(&config.smtp.net_ease_163)?),
+            "file" => Arc::new(FileMailOutbox::new(&config.mail.outbox_dir)),
+            "memory" => Arc::new(MemoryMailOutbox::new()),
+            other => return Err(Report::msg(format!("不支持的邮件发送方式 {}", other))),
+        };
+
+        Ok(Self { transport })
+    }
+
+    /// # Description
+    ///     发送邮件
+    /// # Param
+    ///     message &MailMessage: 邮件
+    /// # Return
+    ///     Result<(), Report>: 发送结果
+    pub async fn send(&self, message: &MailMessage) -> Result<(), Report> {
+        self.transport.send(message).await
+    }
+}
@@ -0,0 +1,5 @@
+mod mailer;
+mod transport;
+
+pub use mailer::Mailer;
+pub use transport::{FileMailOutbox, MemoryMailOutbox, SmtpMailTransport};
\ No newline at end of file
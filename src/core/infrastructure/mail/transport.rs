@@ -0,0 +1,113 @@
+use std::path::PathBuf;
+use axum::async_trait;
+use chrono::Utc;
+use color_eyre::{Report, Result};
+use lettre::{AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor};
+use lettre::message::{header::ContentType, Mailbox};
+use lettre::transport::smtp::authentication::Credentials;
+use rand::Rng;
+use tokio::sync::RwLock;
+use crate::core::domain::mail::{entity::mail_entity::MailMessage, repository::MailTransportInterface};
+use crate::core::infrastructure::config::SmtpDetails;
+
+/// # Description
+///     SMTP 发送。465 端口使用 TLS 直连，25 端口使用明文，其余端口使用 STARTTLS
+/// # Fields
+///     from Mailbox: 发件人
+///     transport AsyncSmtpTransport<Tokio1Executor>: SMTP 连接池
+pub struct SmtpMailTransport {
+    from: Mailbox,
+    transport: AsyncSmtpTransport<Tokio1Executor>,
+}
+
+impl SmtpMailTransport {
+    pub fn new(details: &SmtpDetails) -> Result<Self, Report> {
+        let from = Mailbox::new(Some(details.name.clone()), details.user.parse()?);
+        let builder = match details.port {
+            465 => AsyncSmtpTransport::<Tokio1Executor>::relay(&details.host)?,
+            25 => AsyncSmtpTransport::<Tokio1Executor>::builder_dangerous(&details.host),
+            _ => AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(&details.host)?,
+        };
+        let transport = builder
+            .port(details.port)
+            .credentials(Credentials::new(details.user.clone(), details.password.clone()))
+            .build();
+
+        Ok(Self { from, transport })
+    }
+}
+
+#[async_trait]
+impl MailTransportInterface for SmtpMailTransport {
+    async fn send(&self, message: &MailMessage) -> Result<(), Report> {
+        let email = Message::builder()
+            .from(self.from.clone())
+            .to(message.to.parse()?)
+            .subject(message.subject.clone())
+            .header(ContentType::TEXT_PLAIN)
+            .body(message.body.clone())?;
+        self.transport.send(email).await?;
+
+        Ok(())
+    }
+}
+
+/// # Description
+///     文件发件箱，每封邮件写入一个 .eml 文件，本地调试时代替 SMTP
+/// # Fields
+///     directory PathBuf: 发件箱目录
+pub struct FileMailOutbox {
+    directory: PathBuf,
+}
+
+impl FileMailOutbox {
+    pub fn new(directory: &str) -> Self {
+        Self { directory: PathBuf::from(directory) }
+    }
+}
+
+#[async_trait]
+impl MailTransportInterface for FileMailOutbox {
+    async fn send(&self, message: &MailMessage) -> Result<(), Report> {
+        tokio::fs::create_dir_all(&self.directory).await?;
+
+        let now = Utc::now();
+        let name = format!("{}-{:08x}.eml", now.format("%Y%m%d%H%M%S%3f"), rand::thread_rng().gen::<u32>());
+        let content = format!(
+            "Date: {}\r\nTo: {}\r\nSubject: {}\r\nContent-Type: text/plain; charset=utf-8\r\n\r\n{}\r\n",
+            now.to_rfc2822(), message.to, message.subject, message.body
+        );
+        tokio::fs::write(self.directory.join(name), content).await?;
+
+        Ok(())
+    }
+}
+
+/// # Description
+///     内存发件箱，邮件只保存在进程内存中，用于测试
+/// # Fields
+///     messages RwLock<Vec<MailMessage>>: 已发送的邮件
+pub struct MemoryMailOutbox {
+    messages: RwLock<Vec<MailMessage>>,
+}
+
+impl MemoryMailOutbox {
+    pub fn new() -> Self {
+        Self { messages: RwLock::new(Vec::new()) }
+    }
+
+    /// # Description
+    ///     已发送的邮件
+    pub async fn messages(&self) -> Vec<MailMessage> {
+        self.messages.read().await.clone()
+    }
+}
+
+#[async_trait]
+impl MailTransportInterface for MemoryMailOutbox {
+    async fn send(&self, message: &MailMessage) -> Result<(), Report> {
+        self.messages.write().await.push(message.clone());
+
+        Ok(())
+    }
+}
@@ -5,5 +5,13 @@ pub mod config;
 pub mod utils;
 mod infrastructure;
 pub mod jwt;
+pub mod rpc;
+pub mod redis;
+pub mod oracle;
+pub mod openbook;
+pub mod mail;
+pub mod sms;
+pub mod throttle;
+pub mod keystore;
 
 pub use infrastructure::InfrastructureLayer;
\ No newline at end of file
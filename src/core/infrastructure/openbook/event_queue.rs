@@ -0,0 +1,68 @@
+use color_eyre::{Report, Result};
+use solana_program::pubkey::Pubkey;
+use crate::core::domain::market::entity::MarketEvent;
+use crate::core::infrastructure::oracle::decoder::read_bytes;
+use super::{strip_padding, FLAG_EVENT_QUEUE};
+
+/// 队列头部长度（不含标志位）：head u64, count u64, seq_num u64
+const HEADER_LEN: usize = 24;
+/// 事件长度
+const EVENT_LEN: usize = 88;
+/// 事件标志位
+const EVENT_FILL: u8 = 1;
+const EVENT_BID: u8 = 1 << 2;
+const EVENT_MAKER: u8 = 1 << 3;
+
+/// # Description
+///     事件队列解码器。事件布局：flags@0 owner_slot@1 fee_tier@2 native_qty_released@8
+///     native_qty_paid@16 native_fee_or_rebate@24 order_id@32 owner@48 client_order_id@80
+pub struct EventQueueAccount;
+
+impl EventQueueAccount {
+    /// # Description
+    ///     从最新事件开始倒序读取环形队列，包括已被 crank 消费但尚未覆盖的历史事件
+    /// # Param
+    ///     data: &[u8] - 账户数据
+    ///     limit: usize - 最多读取的事件数量
+    /// # Return
+    ///     Result<Vec<MarketEvent>, Report>: 事件列表，从新到旧
+    pub fn decode(data: &[u8], limit: usize) -> Result<Vec<MarketEvent>, Report> {
+        let body = strip_padding(data, FLAG_EVENT_QUEUE, "EventQueue")?;
+
+        let short = || Report::msg("EventQueue 账户数据长度不足");
+        let head = read_bytes::<8>(body, 0).map(u64::from_le_bytes).ok_or_else(short)? as usize;
+        let count = read_bytes::<8>(body, 8).map(u64::from_le_bytes).ok_or_else(short)? as usize;
+
+        let events = &body[HEADER_LEN.min(body.len())..];
+        let capacity = events.len() / EVENT_LEN;
+        if capacity == 0 || head >= capacity || count > capacity {
+            return Err(Report::msg("EventQueue 头部不合法"));
+        }
+
+        let newest = head + count + capacity - 1;
+        let mut result = Vec::new();
+        for i in 0..capacity.min(limit) {
+            let index = (newest - i) % capacity;
+            let event = &events[index * EVENT_LEN..(index + 1) * EVENT_LEN];
+
+            // 从未写入过的槽位全部为 0，说明更早的历史不存在
+            if event.iter().all(|byte| *byte == 0) {
+                break;
+            }
+
+            let flags = event[0];
+            result.push(MarketEvent {
+                fill: flags & EVENT_FILL != 0,
+                bid: flags & EVENT_BID != 0,
+                maker: flags & EVENT_MAKER != 0,
+                native_qty_released: read_bytes::<8>(event, 8).map(u64::from_le_bytes).ok_or_else(short)?,
+                native_qty_paid: read_bytes::<8>(event, 16).map(u64::from_le_bytes).ok_or_else(short)?,
+                native_fee_or_rebate: read_bytes::<8>(event, 24).map(u64::from_le_bytes).ok_or_else(short)?,
+                order_id: read_bytes::<16>(event, 32).map(u128::from_le_bytes).ok_or_else(short)?,
+                owner: Pubkey::new_from_array(read_bytes::<32>(event, 48).ok_or_else(short)?).to_string(),
+            });
+        }
+
+        Ok(result)
+    }
+}
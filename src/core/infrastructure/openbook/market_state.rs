@@ -0,0 +1,62 @@
+use color_eyre::{Report, Result};
+use solana_program::pubkey::Pubkey;
+use crate::core::infrastructure::oracle::decoder::read_bytes;
+use super::{strip_padding, FLAG_MARKET};
+
+/// # Description
+///     OpenBook / Serum v3 市场账户中与行情相关的字段
+/// # Fields
+///     coin_mint: Pubkey - 基础代币 mint
+///     pc_mint: Pubkey - 计价代币 mint
+///     bids: Pubkey - 买单 slab 账户
+///     asks: Pubkey - 卖单 slab 账户
+///     event_queue: Pubkey - 事件队列账户
+///     coin_lot_size: u64 - 基础代币 lot
+///     pc_lot_size: u64 - 计价代币 lot
+#[derive(Debug, Clone, PartialEq)]
+pub struct MarketStateLayout {
+    pub coin_mint: Pubkey,
+    pub pc_mint: Pubkey,
+    pub bids: Pubkey,
+    pub asks: Pubkey,
+    pub event_queue: Pubkey,
+    pub coin_lot_size: u64,
+    pub pc_lot_size: u64,
+}
+
+/// # Description
+///     MarketState 解码器，偏移量均相对于账户标志位之后：
+///     own_address@0 vault_signer_nonce@32 coin_mint@40 pc_mint@72 coin_vault@104 ... pc_vault@152
+///     req_q@208 event_q@240 bids@272 asks@304 coin_lot_size@336 pc_lot_size@344
+pub struct MarketStateAccount;
+
+impl MarketStateAccount {
+    /// # Description
+    ///     解码市场账户
+    /// # Param
+    ///     data: &[u8] - 账户数据
+    /// # Return
+    ///     Result<MarketStateLayout, Report>: 成功返回市场字段，账户类型不符时返回错误信息
+    pub fn decode(data: &[u8]) -> Result<MarketStateLayout, Report> {
+        let body = strip_padding(data, FLAG_MARKET, "Market")?;
+
+        let short = || Report::msg("Market 账户数据长度不足");
+        let pubkey_at = |offset| read_bytes::<32>(body, offset).map(Pubkey::new_from_array).ok_or_else(short);
+        let u64_at = |offset| read_bytes::<8>(body, offset).map(u64::from_le_bytes).ok_or_else(short);
+
+        let layout = MarketStateLayout {
+            coin_mint: pubkey_at(40)?,
+            pc_mint: pubkey_at(72)?,
+            event_queue: pubkey_at(240)?,
+            bids: pubkey_at(272)?,
+            asks: pubkey_at(304)?,
+            coin_lot_size: u64_at(336)?,
+            pc_lot_size: u64_at(344)?,
+        };
+        if layout.coin_lot_size == 0 || layout.pc_lot_size == 0 {
+            return Err(Report::msg("Market 账户 lot size 不合法"));
+        }
+
+        Ok(layout)
+    }
+}
@@ -0,0 +1,43 @@
+mod market_state;
+mod slab;
+mod event_queue;
+
+pub use market_state::{MarketStateAccount, MarketStateLayout};
+pub use slab::SlabAccount;
+pub use event_queue::EventQueueAccount;
+
+/// 账户数据头部填充 "serum"
+const HEAD_PADDING: &[u8; 5] = b"serum";
+/// 账户数据尾部填充 "padding"
+const TAIL_PADDING: &[u8; 7] = b"padding";
+
+/// 账户标志位
+const FLAG_INITIALIZED: u64 = 1;
+const FLAG_MARKET: u64 = 1 << 1;
+const FLAG_EVENT_QUEUE: u64 = 1 << 4;
+const FLAG_BIDS: u64 = 1 << 5;
+const FLAG_ASKS: u64 = 1 << 6;
+
+/// # Description
+///     去掉头尾填充并校验账户标志位，返回标志位之后的数据
+/// # Param
+///     data: &[u8] - 账户数据
+///     expected: u64 - 必须包含的标志位
+///     name: &str - 账户名称，用于错误信息
+/// # Return
+///     Result<&[u8], Report>: 标志位之后的数据
+fn strip_padding<'a>(data: &'a [u8], expected: u64, name: &str) -> color_eyre::Result<&'a [u8]> {
+    if data.len() < HEAD_PADDING.len() + 8 + TAIL_PADDING.len()
+        || &data[..HEAD_PADDING.len()] != HEAD_PADDING
+        || &data[data.len() - TAIL_PADDING.len()..] != TAIL_PADDING {
+        return Err(color_eyre::Report::msg(format!("{} 账户填充不合法", name)));
+    }
+
+    let body = &data[HEAD_PADDING.len()..data.len() - TAIL_PADDING.len()];
+    let flags = u64::from_le_bytes(body[..8].try_into()?);
+    if flags & (FLAG_INITIALIZED | expected) != FLAG_INITIALIZED | expected {
+        return Err(color_eyre::Report::msg(format!("不是 OpenBook {} 账户", name)));
+    }
+
+    Ok(&body[8..])
+}
@@ -0,0 +1,81 @@
+use color_eyre::{Report, Result};
+use solana_program::pubkey::Pubkey;
+use crate::core::domain::market::entity::SlabOrder;
+use crate::core::infrastructure::oracle::decoder::read_bytes;
+use super::{strip_padding, FLAG_ASKS, FLAG_BIDS};
+
+/// slab 头部长度：bump_index u64, free_list_len u64, free_list_head u32, root u32, leaf_count u64
+const HEADER_LEN: usize = 32;
+/// 节点长度
+const NODE_LEN: usize = 72;
+/// 节点类型
+const TAG_INNER: u32 = 1;
+const TAG_LEAF: u32 = 2;
+
+/// # Description
+///     买卖盘 slab（crit-bit 树）解码器。
+///     内部节点：tag@0 prefix_len@4 key@8 children@24；叶子节点：tag@0 owner_slot@4 fee_tier@5 key@8 owner@24 quantity@56
+///     key 的高 64 位为 lot 价格
+pub struct SlabAccount;
+
+impl SlabAccount {
+    /// # Description
+    ///     解码买单 slab
+    /// # Param
+    ///     data: &[u8] - 账户数据
+    /// # Return
+    ///     Result<Vec<SlabOrder>, Report>: 挂单列表（未排序）
+    pub fn decode_bids(data: &[u8]) -> Result<Vec<SlabOrder>, Report> {
+        Self::decode(strip_padding(data, FLAG_BIDS, "Bids")?)
+    }
+
+    /// # Description
+    ///     解码卖单 slab
+    /// # Param
+    ///     data: &[u8] - 账户数据
+    /// # Return
+    ///     Result<Vec<SlabOrder>, Report>: 挂单列表（未排序）
+    pub fn decode_asks(data: &[u8]) -> Result<Vec<SlabOrder>, Report> {
+        Self::decode(strip_padding(data, FLAG_ASKS, "Asks")?)
+    }
+
+    fn decode(body: &[u8]) -> Result<Vec<SlabOrder>, Report> {
+        let short = || Report::msg("slab 账户数据长度不足");
+        let root = read_bytes::<4>(body, 20).map(u32::from_le_bytes).ok_or_else(short)?;
+        let leaf_count = read_bytes::<8>(body, 24).map(u64::from_le_bytes).ok_or_else(short)?;
+
+        let nodes = &body[HEADER_LEN..];
+        let capacity = nodes.len() / NODE_LEN;
+        let mut orders = Vec::with_capacity(leaf_count as usize);
+        if leaf_count == 0 {
+            return Ok(orders);
+        }
+
+        // 只遍历从根节点可达的节点，空闲链表上的节点会被忽略
+        let mut stack = vec![root];
+        let mut visited = 0usize;
+        while let Some(index) = stack.pop() {
+            visited += 1;
+            if index as usize >= capacity || visited > capacity {
+                return Err(Report::msg("slab 节点索引越界"));
+            }
+
+            let node = &nodes[index as usize * NODE_LEN..(index as usize + 1) * NODE_LEN];
+            let key = read_bytes::<16>(node, 8).map(u128::from_le_bytes).ok_or_else(short)?;
+            match read_bytes::<4>(node, 0).map(u32::from_le_bytes).ok_or_else(short)? {
+                TAG_INNER => {
+                    stack.push(read_bytes::<4>(node, 28).map(u32::from_le_bytes).ok_or_else(short)?);
+                    stack.push(read_bytes::<4>(node, 24).map(u32::from_le_bytes).ok_or_else(short)?);
+                }
+                TAG_LEAF => orders.push(SlabOrder {
+                    price_lots: (key >> 64) as u64,
+                    quantity_lots: read_bytes::<8>(node, 56).map(u64::from_le_bytes).ok_or_else(short)?,
+                    owner: Pubkey::new_from_array(read_bytes::<32>(node, 24).ok_or_else(short)?).to_string(),
+                }),
+                tag => return Err(Report::msg(format!("slab 节点类型不合法: {}", tag))),
+            }
+        }
+
+        Ok(orders)
+    }
+}
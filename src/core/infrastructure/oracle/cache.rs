@@ -0,0 +1,78 @@
+use std::{collections::HashMap, sync::Arc, time::{Duration, Instant}};
+use axum::async_trait;
+use color_eyre::{Report, Result};
+use redis::AsyncCommands;
+use tokio::sync::RwLock;
+use crate::core::domain::price::{entity::AggregatedPrice, repository::PriceCacheInterface};
+use crate::core::infrastructure::redis::Redis;
+
+/// # Description
+///     进程内存价格缓存
+/// # Fields
+///     entries RwLock<HashMap<String, (AggregatedPrice, Instant)>>: mint -> (价格, 过期时间)
+pub struct MemoryPriceCache {
+    entries: RwLock<HashMap<String, (AggregatedPrice, Instant)>>,
+}
+
+impl MemoryPriceCache {
+    pub fn new() -> Self {
+        Self { entries: RwLock::new(HashMap::new()) }
+    }
+}
+
+#[async_trait]
+impl PriceCacheInterface for MemoryPriceCache {
+    async fn get(&self, mint: &str) -> Result<Option<AggregatedPrice>, Report> {
+        let entries = self.entries.read().await;
+
+        Ok(entries
+            .get(mint)
+            .filter(|(_, expires_at)| *expires_at > Instant::now())
+            .map(|(price, _)| price.clone()))
+    }
+
+    async fn set(&self, price: &AggregatedPrice, ttl: u64) -> Result<(), Report> {
+        let expires_at = Instant::now() + Duration::from_secs(ttl);
+        self.entries.write().await.insert(price.mint.clone(), (price.clone(), expires_at));
+
+        Ok(())
+    }
+}
+
+/// # Description
+///     Redis 价格缓存，多实例部署时共享
+/// # Fields
+///     redis Arc<Redis>: Redis 连接
+pub struct RedisPriceCache {
+    redis: Arc<Redis>,
+}
+
+impl RedisPriceCache {
+    pub fn new(redis: Arc<Redis>) -> Self {
+        Self { redis }
+    }
+
+    fn key(mint: &str) -> String {
+        format!("oracle:price:{}", mint)
+    }
+}
+
+#[async_trait]
+impl PriceCacheInterface for RedisPriceCache {
+    async fn get(&self, mint: &str) -> Result<Option<AggregatedPrice>, Report> {
+        let mut conn = self.redis.connection().await?;
+        let value: Option<String> = conn.get(Self::key(mint)).await?;
+
+        match value {
+            Some(value) => Ok(Some(serde_json::from_str(&value)?)),
+            None => Ok(None),
+        }
+    }
+
+    async fn set(&self, price: &AggregatedPrice, ttl: u64) -> Result<(), Report> {
+        let mut conn = self.redis.connection().await?;
+        conn.set_ex::<_, _, ()>(Self::key(&price.mint), serde_json::to_string(price)?, ttl).await?;
+
+        Ok(())
+    }
+}
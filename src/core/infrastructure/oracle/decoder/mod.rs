@@ -0,0 +1,11 @@
+mod pyth;
+mod switchboard;
+
+pub use pyth::PythPriceAccount;
+pub use switchboard::SwitchboardAggregator;
+
+/// # Description
+///     按小端序从账户数据中读取定长字节，长度不足时返回 None
+pub(crate) fn read_bytes<const N: usize>(data: &[u8], offset: usize) -> Option<[u8; N]> {
+    data.get(offset..offset + N)?.try_into().ok()
+}
@@ -0,0 +1,47 @@
+use color_eyre::{Report, Result};
+use crate::core::domain::price::entity::OnChainPrice;
+use super::read_bytes;
+
+/// Pyth 价格账户魔数
+const MAGIC: u32 = 0xa1b2c3d4;
+/// Pyth 价格账户类型
+const ACCOUNT_TYPE_PRICE: u32 = 3;
+/// 聚合价格状态：交易中
+const STATUS_TRADING: u32 = 1;
+
+/// # Description
+///     Pyth 价格账户解码器，只读取聚合价格相关字段
+///     偏移量：magic@0 atype@8 expo@20 timestamp@96 agg.price@208 agg.conf@216 agg.status@224 agg.pub_slot@232
+pub struct PythPriceAccount;
+
+impl PythPriceAccount {
+    /// # Description
+    ///     解码 Pyth 价格账户
+    /// # Param
+    ///     data &[u8]: 账户数据
+    /// # Return
+    ///     Result<OnChainPrice, Report>: 成功返回链上价格，账户类型不符或价格不可交易时返回错误信息
+    pub fn decode(data: &[u8]) -> Result<OnChainPrice, Report> {
+        let u32_at = |offset| read_bytes::<4>(data, offset).map(u32::from_le_bytes);
+        let i32_at = |offset| read_bytes::<4>(data, offset).map(i32::from_le_bytes);
+        let i64_at = |offset| read_bytes::<8>(data, offset).map(i64::from_le_bytes);
+        let u64_at = |offset| read_bytes::<8>(data, offset).map(u64::from_le_bytes);
+
+        let short = || Report::msg("Pyth 价格账户数据长度不足");
+
+        if u32_at(0).ok_or_else(short)? != MAGIC || u32_at(8).ok_or_else(short)? != ACCOUNT_TYPE_PRICE {
+            return Err(Report::msg("不是 Pyth 价格账户"));
+        }
+        if u32_at(224).ok_or_else(short)? != STATUS_TRADING {
+            return Err(Report::msg("Pyth 价格当前不可交易"));
+        }
+
+        Ok(OnChainPrice {
+            price: i64_at(208).ok_or_else(short)? as i128,
+            confidence: u64_at(216).ok_or_else(short)? as u128,
+            exponent: i32_at(20).ok_or_else(short)?,
+            publish_slot: u64_at(232).ok_or_else(short)?,
+            publish_time: i64_at(96).ok_or_else(short)?,
+        })
+    }
+}
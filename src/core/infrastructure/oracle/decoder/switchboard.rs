@@ -0,0 +1,57 @@
+use color_eyre::{Report, Result};
+use crate::core::domain::price::entity::OnChainPrice;
+use super::read_bytes;
+
+/// AggregatorAccountData 的 Anchor 账户鉴别器
+const DISCRIMINATOR: [u8; 8] = [217, 230, 65, 101, 201, 162, 27, 125];
+
+/// latest_confirmed_round 各字段偏移量（含 8 字节鉴别器，结构体为 packed 布局）
+const ROUND_OPEN_SLOT: usize = 350;
+const ROUND_OPEN_TIMESTAMP: usize = 358;
+const RESULT_MANTISSA: usize = 366;
+const RESULT_SCALE: usize = 382;
+const STD_DEVIATION_MANTISSA: usize = 386;
+const STD_DEVIATION_SCALE: usize = 402;
+
+/// # Description
+///     Switchboard V2 聚合器账户解码器，读取最近一次确认轮次的结果
+pub struct SwitchboardAggregator;
+
+impl SwitchboardAggregator {
+    /// # Description
+    ///     解码 Switchboard 聚合器账户，标准差作为置信区间
+    /// # Param
+    ///     data &[u8]: 账户数据
+    /// # Return
+    ///     Result<OnChainPrice, Report>: 成功返回链上价格，账户类型不符时返回错误信息
+    pub fn decode(data: &[u8]) -> Result<OnChainPrice, Report> {
+        if data.get(..8) != Some(&DISCRIMINATOR[..]) {
+            return Err(Report::msg("不是 Switchboard 聚合器账户"));
+        }
+
+        let short = || Report::msg("Switchboard 聚合器账户数据长度不足");
+        let i128_at = |offset| read_bytes::<16>(data, offset).map(i128::from_le_bytes).ok_or_else(short);
+        let u32_at = |offset| read_bytes::<4>(data, offset).map(u32::from_le_bytes).ok_or_else(short);
+
+        let scale = u32_at(RESULT_SCALE)?;
+        let std_mantissa = i128_at(STD_DEVIATION_MANTISSA)?.unsigned_abs();
+
+        // 标准差与结果的小数位可能不同，统一到结果的小数位；小数位来自账户数据，需防止溢出
+        let std_scale = u32_at(STD_DEVIATION_SCALE)?;
+        let confidence = if std_scale > scale {
+            10u128.checked_pow(std_scale - scale).map_or(0, |factor| std_mantissa / factor)
+        } else {
+            10u128.checked_pow(scale - std_scale)
+                .and_then(|factor| std_mantissa.checked_mul(factor))
+                .ok_or_else(|| Report::msg("Switchboard 聚合器标准差超出范围"))?
+        };
+
+        Ok(OnChainPrice {
+            price: i128_at(RESULT_MANTISSA)?,
+            confidence,
+            exponent: -(scale as i32),
+            publish_slot: read_bytes::<8>(data, ROUND_OPEN_SLOT).map(u64::from_le_bytes).ok_or_else(short)?,
+            publish_time: read_bytes::<8>(data, ROUND_OPEN_TIMESTAMP).map(i64::from_le_bytes).ok_or_else(short)?,
+        })
+    }
+}
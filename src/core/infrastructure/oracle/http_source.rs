@@ -0,0 +1,117 @@
+use std::{collections::HashMap, sync::Arc, time::{Duration, Instant}};
+use axum::async_trait;
+use chrono::DateTime;
+use color_eyre::{Report, Result};
+use tokio::sync::Mutex;
+use crate::core::domain::price::{entity::PriceQuote, repository::PriceSourceInterface};
+use crate::core::infrastructure::config::OracleTokenConfig;
+
+/// # Description
+///     CryptoCompare 价格数据源，需要通过 mint 找到交易符号
+/// # Fields
+///     client reqwest::Client: HTTP 客户端
+///     symbols HashMap<String, String>: mint -> 交易符号
+pub struct CryptoCompareSource {
+    client: reqwest::Client,
+    symbols: HashMap<String, String>,
+}
+
+impl CryptoCompareSource {
+    pub fn new(tokens: &[OracleTokenConfig], timeout: u64) -> Result<Self, Report> {
+        let client = reqwest::Client::builder().timeout(Duration::from_secs(timeout)).build()?;
+        let symbols = tokens.iter().map(|t| (t.mint.clone(), t.symbol.clone())).collect();
+
+        Ok(Self { client, symbols })
+    }
+}
+
+#[async_trait]
+impl PriceSourceInterface for CryptoCompareSource {
+    fn name(&self) -> &str {
+        "cryptocompare"
+    }
+
+    async fn fetch(&self, mint: &str) -> Result<Option<PriceQuote>, Report> {
+        let Some(symbol) = self.symbols.get(mint) else {
+            return Ok(None);
+        };
+
+        // pricemultifull 同时返回价格与最后更新时间，发布时间以上游为准，过期报价由聚合时剔除
+        let url = format!("https://min-api.cryptocompare.com/data/pricemultifull?fsyms={}&tsyms=USD", symbol);
+        let parsed = self.client.get(url).send().await?.json::<serde_json::Value>().await?;
+        let raw = &parsed["RAW"][symbol.as_str()]["USD"];
+
+        let (Some(price), Some(last_update)) = (raw["PRICE"].as_f64(), raw["LASTUPDATE"].as_i64()) else {
+            return Ok(None);
+        };
+
+        Ok(Some(PriceQuote {
+            mint: mint.to_string(),
+            source: self.name().to_string(),
+            price,
+            confidence: None,
+            published_at: Some(DateTime::from_timestamp(last_update, 0).ok_or_else(|| Report::msg("CryptoCompare 更新时间非法"))?),
+        }))
+    }
+}
+
+/// # Description
+///     Raydium 价格数据源，接口一次返回全部 mint -> USD 价格。
+///     价格表在 refresh 时间内复用，同一轮查询多个代币只请求一次；接口不提供发布时间，报价不参与过期检查
+/// # Fields
+///     client reqwest::Client: HTTP 客户端
+///     refresh Duration: 价格表复用时间
+///     prices Mutex<Option<(Instant, Arc<HashMap<String, f64>>)>>: 最近一次拉取的价格表及拉取时间
+pub struct RaydiumPriceSource {
+    client: reqwest::Client,
+    refresh: Duration,
+    prices: Mutex<Option<(Instant, Arc<HashMap<String, f64>>)>>,
+}
+
+impl RaydiumPriceSource {
+    pub fn new(timeout: u64, refresh: u64) -> Result<Self, Report> {
+        let client = reqwest::Client::builder().timeout(Duration::from_secs(timeout)).build()?;
+
+        Ok(Self { client, refresh: Duration::from_secs(refresh), prices: Mutex::new(None) })
+    }
+
+    /// # Description
+    ///     取价格表，过期时重新拉取；持锁拉取，并发查询的代币共用同一次请求
+    async fn prices(&self) -> Result<Arc<HashMap<String, f64>>, Report> {
+        let mut cached = self.prices.lock().await;
+        if let Some((fetched_at, prices)) = cached.as_ref() {
+            if fetched_at.elapsed() < self.refresh {
+                return Ok(prices.clone());
+            }
+        }
+
+        let prices = Arc::new(self.client
+            .get("https://api.raydium.io/v2/main/price")
+            .send()
+            .await?
+            .json::<HashMap<String, f64>>()
+            .await?);
+        *cached = Some((Instant::now(), prices.clone()));
+
+        Ok(prices)
+    }
+}
+
+#[async_trait]
+impl PriceSourceInterface for RaydiumPriceSource {
+    fn name(&self) -> &str {
+        "raydium"
+    }
+
+    async fn fetch(&self, mint: &str) -> Result<Option<PriceQuote>, Report> {
+        let prices = self.prices().await?;
+
+        Ok(prices.get(mint).map(|price| PriceQuote {
+            mint: mint.to_string(),
+            source: self.name().to_string(),
+            price: *price,
+            confidence: None,
+            published_at: None,
+        }))
+    }
+}
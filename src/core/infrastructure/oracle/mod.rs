@@ -0,0 +1,14 @@
+mod oracle;
+pub mod decoder;
+mod cache;
+mod http_source;
+mod pyth_source;
+mod switchboard_source;
+mod pool_source;
+
+pub use oracle::PriceOracle;
+pub use cache::{MemoryPriceCache, RedisPriceCache};
+pub use http_source::{CryptoCompareSource, RaydiumPriceSource};
+pub use pyth_source::PythPriceSource;
+pub use switchboard_source::SwitchboardPriceSource;
+pub use pool_source::PoolPriceSource;
@@ -0,0 +1,102 @@
+use std::sync::Arc;
+use color_eyre::Report;
+use futures::future::join_all;
+use tracing::warn;
+use crate::core::domain::price::{
+    entity::{AggregationPolicy, PriceQuote},
+    repository::{PriceCacheInterface, PriceSourceInterface},
+};
+use crate::core::infrastructure::{config::Config, redis::Redis, rpc::Rpc};
+use super::{CryptoCompareSource, MemoryPriceCache, PoolPriceSource, PythPriceSource, RaydiumPriceSource, RedisPriceCache, SwitchboardPriceSource};
+
+/// # Description
+///     【基础设施】价格预言机，汇总 HTTP API、Pyth / Switchboard 链上价格账户和 AMM 池子储备推导出的报价
+/// # Fields
+///     sources Vec<Arc<dyn PriceSourceInterface>>: 价格数据源
+///     cache Arc<dyn PriceCacheInterface>: 聚合价格缓存
+///     policy AggregationPolicy: 聚合策略
+///     cache_ttl u64: 缓存时间（秒）
+pub struct PriceOracle {
+    pub sources: Vec<Arc<dyn PriceSourceInterface>>,
+    pub cache: Arc<dyn PriceCacheInterface>,
+    pub policy: AggregationPolicy,
+    pub cache_ttl: u64,
+}
+
+impl PriceOracle {
+    /// # Description
+    ///     根据配置初始化价格预言机
+    /// # Param
+    ///     config Arc<Config>: 配置
+    ///     rpc Arc<Rpc>: Solana RPC 连接
+    ///     redis Arc<Redis>: Redis 连接
+    /// # Return
+    ///     Result<PriceOracle, Report>
+    ///         - PriceOracle: 价格预言机实例
+    ///         - Report: 错误报告
+    pub fn new(config: Arc<Config>, rpc: Arc<Rpc>, redis: Arc<Redis>) -> Result<Self, Report> {
+        // 读取数据
+        let oracle_config = &config.oracle;
+
+        let sources: Vec<Arc<dyn PriceSourceInterface>> = vec![
+            Arc::new(CryptoCompareSource::new(&oracle_config.tokens, oracle_config.http_timeout)?),
+            Arc::new(RaydiumPriceSource::new(oracle_config.http_timeout, oracle_config.cache_ttl)?),
+            Arc::new(PythPriceSource::new(rpc.client.clone(), &oracle_config.pyth, oracle_config.max_staleness_slots)?),
+            Arc::new(SwitchboardPriceSource::new(rpc.client.clone(), &oracle_config.switchboard, oracle_config.max_staleness_slots)?),
+            Arc::new(PoolPriceSource::new(rpc.client.clone(), &oracle_config.pools)?),
+        ];
+
+        // 根据缓存驱动选择缓存实现
+        let cache: Arc<dyn PriceCacheInterface> = match oracle_config.cache_driver.as_str() {
+            "redis" => Arc::new(RedisPriceCache::new(redis)),
+            "memory" => Arc::new(MemoryPriceCache::new()),
+            driver => return Err(Report::msg(format!("+InfrastructureLayer [Oracle] Unknown cache driver: {}", driver))),
+        };
+
+        let policy = AggregationPolicy {
+            max_staleness: oracle_config.max_staleness,
+            max_deviation: oracle_config.max_deviation,
+            min_sources: oracle_config.min_sources,
+        };
+
+        Ok(Self::with_sources(sources, cache, policy, oracle_config.cache_ttl))
+    }
+
+    /// # Description
+    ///     使用指定的数据源和缓存创建价格预言机，便于测试时注入模拟实现
+    /// # Param
+    ///     sources Vec<Arc<dyn PriceSourceInterface>>: 价格数据源
+    ///     cache Arc<dyn PriceCacheInterface>: 聚合价格缓存
+    ///     policy AggregationPolicy: 聚合策略
+    ///     cache_ttl u64: 缓存时间（秒）
+    /// # Return
+    ///     PriceOracle: 价格预言机实例
+    pub fn with_sources(
+        sources: Vec<Arc<dyn PriceSourceInterface>>,
+        cache: Arc<dyn PriceCacheInterface>,
+        policy: AggregationPolicy,
+        cache_ttl: u64,
+    ) -> Self {
+        Self { sources, cache, policy, cache_ttl }
+    }
+
+    /// # Description
+    ///     并发向所有数据源查询报价，单个数据源失败只记录日志，不影响其他数据源
+    /// # Param
+    ///     mint &str: 代币 mint 地址
+    /// # Return
+    ///     Vec<PriceQuote>: 查询到的报价
+    pub async fn fetch_quotes(&self, mint: &str) -> Vec<PriceQuote> {
+        let tasks = self.sources.iter().map(|source| async move {
+            match source.fetch(mint).await {
+                Ok(quote) => quote,
+                Err(e) => {
+                    warn!("-InfrastructureLayer [Oracle] {} fetch {} failed: {}", source.name(), mint, e);
+                    None
+                }
+            }
+        });
+
+        join_all(tasks).await.into_iter().flatten().collect()
+    }
+}
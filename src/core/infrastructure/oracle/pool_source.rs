@@ -0,0 +1,84 @@
+use std::{collections::HashMap, str::FromStr, sync::Arc};
+use axum::async_trait;
+use chrono::Utc;
+use color_eyre::{Report, Result};
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_program::{program_pack::Pack, pubkey::Pubkey};
+use spl_token::state::Account as TokenAccount;
+use crate::core::domain::price::{entity::PriceQuote, repository::PriceSourceInterface};
+use crate::core::infrastructure::config::OraclePoolConfig;
+
+/// # Description
+///     AMM 池子隐含价格数据源，根据池子两侧金库的储备量推导价格（报价侧需为 USD 稳定币）
+/// # Fields
+///     client Arc<RpcClient>: RPC 客户端
+///     pools HashMap<String, OraclePoolConfig>: mint -> 池子配置
+pub struct PoolPriceSource {
+    client: Arc<RpcClient>,
+    pools: HashMap<String, OraclePoolConfig>,
+}
+
+impl PoolPriceSource {
+    pub fn new(client: Arc<RpcClient>, pools: &[OraclePoolConfig]) -> Result<Self, Report> {
+        // 启动时校验金库地址
+        for pool in pools {
+            Pubkey::from_str(&pool.base_vault)?;
+            Pubkey::from_str(&pool.quote_vault)?;
+        }
+
+        let pools = pools.iter().map(|p| (p.mint.clone(), p.clone())).collect();
+
+        Ok(Self { client, pools })
+    }
+
+    /// # Description
+    ///     根据储备量计算价格：(quote / 10^quote_decimals) / (base / 10^base_decimals)
+    /// # Param
+    ///     base_reserve u64: 基础代币储备
+    ///     quote_reserve u64: 报价代币储备
+    ///     pool &OraclePoolConfig: 池子配置
+    /// # Return
+    ///     Option<f64>: 储备为空时返回 None
+    pub fn implied_price(base_reserve: u64, quote_reserve: u64, pool: &OraclePoolConfig) -> Option<f64> {
+        if base_reserve == 0 {
+            return None;
+        }
+
+        let base = base_reserve as f64 / 10f64.powi(pool.base_decimals as i32);
+        let quote = quote_reserve as f64 / 10f64.powi(pool.quote_decimals as i32);
+
+        Some(quote / base)
+    }
+}
+
+#[async_trait]
+impl PriceSourceInterface for PoolPriceSource {
+    fn name(&self) -> &str {
+        "pool"
+    }
+
+    async fn fetch(&self, mint: &str) -> Result<Option<PriceQuote>, Report> {
+        let Some(pool) = self.pools.get(mint) else {
+            return Ok(None);
+        };
+
+        // 一次请求读取两侧金库
+        let vaults = [Pubkey::from_str(&pool.base_vault)?, Pubkey::from_str(&pool.quote_vault)?];
+        let accounts = self.client.get_multiple_accounts(&vaults).await?;
+
+        let mut reserves = Vec::with_capacity(2);
+        for account in accounts {
+            let account = account.ok_or_else(|| Report::msg("池子金库账户不存在"))?;
+            reserves.push(TokenAccount::unpack(&account.data)?.amount);
+        }
+
+        Ok(Self::implied_price(reserves[0], reserves[1], pool).map(|price| PriceQuote {
+            mint: mint.to_string(),
+            source: self.name().to_string(),
+            price,
+            confidence: None,
+            // 金库余额是本次从链上读取的，即为当前价格
+            published_at: Some(Utc::now()),
+        }))
+    }
+}
@@ -0,0 +1,71 @@
+use std::{collections::HashMap, str::FromStr, sync::Arc};
+use axum::async_trait;
+use chrono::DateTime;
+use color_eyre::{Report, Result};
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_program::pubkey::Pubkey;
+use crate::core::domain::price::{entity::{OnChainPrice, PriceQuote}, repository::PriceSourceInterface};
+use crate::core::infrastructure::config::OracleFeedConfig;
+use super::decoder::PythPriceAccount;
+
+/// # Description
+///     Pyth 链上价格数据源
+/// # Fields
+///     client Arc<RpcClient>: RPC 客户端
+///     accounts HashMap<String, Pubkey>: mint -> Pyth 价格账户
+///     max_staleness_slots u64: 最大允许落后的 slot 数
+pub struct PythPriceSource {
+    client: Arc<RpcClient>,
+    accounts: HashMap<String, Pubkey>,
+    max_staleness_slots: u64,
+}
+
+impl PythPriceSource {
+    pub fn new(client: Arc<RpcClient>, accounts: &[OracleFeedConfig], max_staleness_slots: u64) -> Result<Self, Report> {
+        let accounts = accounts
+            .iter()
+            .map(|a| Ok((a.mint.clone(), Pubkey::from_str(&a.account)?)))
+            .collect::<Result<HashMap<_, _>, Report>>()?;
+
+        Ok(Self { client, accounts, max_staleness_slots })
+    }
+
+    /// # Description
+    ///     读取并解码 Pyth 价格账户，并以当前 slot 检查价格是否过期
+    /// # Param
+    ///     account &Pubkey: Pyth 价格账户地址
+    /// # Return
+    ///     Result<OnChainPrice, Report>: 成功返回链上价格，失败返回错误信息
+    async fn read_price(&self, account: &Pubkey) -> Result<OnChainPrice, Report> {
+        let data = self.client.get_account_data(account).await?;
+        let price = PythPriceAccount::decode(&data)?;
+
+        let current_slot = self.client.get_slot().await?;
+        price.ensure_fresh(current_slot, self.max_staleness_slots).map_err(Report::msg)?;
+
+        Ok(price)
+    }
+}
+
+#[async_trait]
+impl PriceSourceInterface for PythPriceSource {
+    fn name(&self) -> &str {
+        "pyth"
+    }
+
+    async fn fetch(&self, mint: &str) -> Result<Option<PriceQuote>, Report> {
+        let Some(account) = self.accounts.get(mint) else {
+            return Ok(None);
+        };
+
+        let price = self.read_price(account).await?;
+
+        Ok(Some(PriceQuote {
+            mint: mint.to_string(),
+            source: self.name().to_string(),
+            price: price.ui_price(),
+            confidence: Some(price.ui_confidence()),
+            published_at: Some(DateTime::from_timestamp(price.publish_time, 0).ok_or_else(|| Report::msg("Pyth 发布时间非法"))?),
+        }))
+    }
+}
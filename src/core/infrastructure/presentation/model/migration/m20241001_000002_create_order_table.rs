@@ -0,0 +1,106 @@
+use sea_orm_migration::async_trait::async_trait;
+use sea_orm_migration::prelude::*;
+use sea_orm::DeriveIden;
+
+#[derive(DeriveIden)]
+#[allow(dead_code)]
+enum SwapOrder {
+    Table,
+    Id,
+    UserId,
+    WalletId,
+    InputMint,
+    OutputMint,
+    AmountIn,
+    TriggerPrice,
+    Direction,
+    MaxSlippage,
+    Status,
+    TriggerAtPrice,
+    Signature,
+    Error,
+    ExpiresAt,
+    TriggeredAt,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(DeriveIden)]
+#[allow(dead_code)]
+enum SwapOrderEvent {
+    Table,
+    Id,
+    OrderId,
+    Status,
+    Price,
+    Message,
+    CreatedAt,
+}
+
+#[derive(DeriveMigrationName)]
+pub struct MigratorHandle;
+
+#[async_trait]
+impl MigrationTrait for MigratorHandle {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager.create_table(
+            Table::create()
+                .table(SwapOrder::Table)
+                .if_not_exists()
+                .col(ColumnDef::new(SwapOrder::Id).integer().not_null().auto_increment().primary_key())
+                .col(ColumnDef::new(SwapOrder::UserId).integer().not_null())
+                .col(ColumnDef::new(SwapOrder::WalletId).integer().not_null())
+                .col(ColumnDef::new(SwapOrder::InputMint).string().not_null())
+                .col(ColumnDef::new(SwapOrder::OutputMint).string().not_null())
+                .col(ColumnDef::new(SwapOrder::AmountIn).double().not_null())
+                .col(ColumnDef::new(SwapOrder::TriggerPrice).double().not_null())
+                .col(ColumnDef::new(SwapOrder::Direction).string_len(8).not_null())
+                .col(ColumnDef::new(SwapOrder::MaxSlippage).double().not_null())
+                .col(ColumnDef::new(SwapOrder::Status).string_len(16).not_null())
+                .col(ColumnDef::new(SwapOrder::TriggerAtPrice).double())
+                .col(ColumnDef::new(SwapOrder::Signature).string())
+                .col(ColumnDef::new(SwapOrder::Error).text())
+                .col(ColumnDef::new(SwapOrder::ExpiresAt).date_time())
+                .col(ColumnDef::new(SwapOrder::TriggeredAt).date_time())
+                .col(ColumnDef::new(SwapOrder::CreatedAt).date_time().not_null())
+                .col(ColumnDef::new(SwapOrder::UpdatedAt).date_time().not_null())
+                .to_owned(),
+        ).await?;
+
+        manager.create_index(
+            Index::create()
+                .name("idx_swap_order_status")
+                .table(SwapOrder::Table)
+                .col(SwapOrder::Status)
+                .col(SwapOrder::CreatedAt)
+                .to_owned(),
+        ).await?;
+
+        manager.create_table(
+            Table::create()
+                .table(SwapOrderEvent::Table)
+                .if_not_exists()
+                .col(ColumnDef::new(SwapOrderEvent::Id).integer().not_null().auto_increment().primary_key())
+                .col(ColumnDef::new(SwapOrderEvent::OrderId).integer().not_null())
+                .col(ColumnDef::new(SwapOrderEvent::Status).string_len(16).not_null())
+                .col(ColumnDef::new(SwapOrderEvent::Price).double())
+                .col(ColumnDef::new(SwapOrderEvent::Message).text())
+                .col(ColumnDef::new(SwapOrderEvent::CreatedAt).date_time().not_null())
+                .to_owned(),
+        ).await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager.drop_table(
+            Table::drop().table(SwapOrder::Table).if_exists().to_owned()
+        ).await?;
+
+        manager.drop_table(
+            Table::drop().table(SwapOrderEvent::Table).if_exists().to_owned()
+        ).await?;
+
+        Ok(())
+    }
+}
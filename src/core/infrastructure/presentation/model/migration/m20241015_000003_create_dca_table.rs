@@ -0,0 +1,118 @@
+use sea_orm_migration::async_trait::async_trait;
+use sea_orm_migration::prelude::*;
+use sea_orm::DeriveIden;
+
+#[derive(DeriveIden)]
+#[allow(dead_code)]
+enum DcaSchedule {
+    Table,
+    Id,
+    UserId,
+    WalletId,
+    Kind,
+    InputMint,
+    OutputMint,
+    Recipient,
+    Amount,
+    MaxSlippage,
+    Cron,
+    IntervalSecs,
+    SpendCap,
+    Spent,
+    RetryCount,
+    Status,
+    NextRunAt,
+    LastRunAt,
+    LastError,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(DeriveIden)]
+#[allow(dead_code)]
+enum DcaExecution {
+    Table,
+    Id,
+    ScheduleId,
+    Attempt,
+    Amount,
+    Status,
+    Signature,
+    Error,
+    ScheduledFor,
+    ExecutedAt,
+}
+
+#[derive(DeriveMigrationName)]
+pub struct MigratorHandle;
+
+#[async_trait]
+impl MigrationTrait for MigratorHandle {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager.create_table(
+            Table::create()
+                .table(DcaSchedule::Table)
+                .if_not_exists()
+                .col(ColumnDef::new(DcaSchedule::Id).integer().not_null().auto_increment().primary_key())
+                .col(ColumnDef::new(DcaSchedule::UserId).integer().not_null())
+                .col(ColumnDef::new(DcaSchedule::WalletId).integer().not_null())
+                .col(ColumnDef::new(DcaSchedule::Kind).string_len(16).not_null())
+                .col(ColumnDef::new(DcaSchedule::InputMint).string().not_null())
+                .col(ColumnDef::new(DcaSchedule::OutputMint).string())
+                .col(ColumnDef::new(DcaSchedule::Recipient).string())
+                .col(ColumnDef::new(DcaSchedule::Amount).double().not_null())
+                .col(ColumnDef::new(DcaSchedule::MaxSlippage).double().not_null())
+                .col(ColumnDef::new(DcaSchedule::Cron).string())
+                .col(ColumnDef::new(DcaSchedule::IntervalSecs).big_integer())
+                .col(ColumnDef::new(DcaSchedule::SpendCap).double())
+                .col(ColumnDef::new(DcaSchedule::Spent).double().not_null())
+                .col(ColumnDef::new(DcaSchedule::RetryCount).integer().not_null())
+                .col(ColumnDef::new(DcaSchedule::Status).string_len(16).not_null())
+                .col(ColumnDef::new(DcaSchedule::NextRunAt).date_time().not_null())
+                .col(ColumnDef::new(DcaSchedule::LastRunAt).date_time())
+                .col(ColumnDef::new(DcaSchedule::LastError).text())
+                .col(ColumnDef::new(DcaSchedule::CreatedAt).date_time().not_null())
+                .col(ColumnDef::new(DcaSchedule::UpdatedAt).date_time().not_null())
+                .to_owned(),
+        ).await?;
+
+        manager.create_index(
+            Index::create()
+                .name("idx_dca_schedule_due")
+                .table(DcaSchedule::Table)
+                .col(DcaSchedule::Status)
+                .col(DcaSchedule::NextRunAt)
+                .to_owned(),
+        ).await?;
+
+        manager.create_table(
+            Table::create()
+                .table(DcaExecution::Table)
+                .if_not_exists()
+                .col(ColumnDef::new(DcaExecution::Id).integer().not_null().auto_increment().primary_key())
+                .col(ColumnDef::new(DcaExecution::ScheduleId).integer().not_null())
+                .col(ColumnDef::new(DcaExecution::Attempt).integer().not_null())
+                .col(ColumnDef::new(DcaExecution::Amount).double().not_null())
+                .col(ColumnDef::new(DcaExecution::Status).string_len(16).not_null())
+                .col(ColumnDef::new(DcaExecution::Signature).string())
+                .col(ColumnDef::new(DcaExecution::Error).text())
+                .col(ColumnDef::new(DcaExecution::ScheduledFor).date_time().not_null())
+                .col(ColumnDef::new(DcaExecution::ExecutedAt).date_time().not_null())
+                .to_owned(),
+        ).await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager.drop_table(
+            Table::drop().table(DcaSchedule::Table).if_exists().to_owned()
+        ).await?;
+
+        manager.drop_table(
+            Table::drop().table(DcaExecution::Table).if_exists().to_owned()
+        ).await?;
+
+        Ok(())
+    }
+}
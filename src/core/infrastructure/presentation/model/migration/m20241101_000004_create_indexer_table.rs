@@ -0,0 +1,136 @@
+use sea_orm_migration::async_trait::async_trait;
+use sea_orm_migration::prelude::*;
+use sea_orm::DeriveIden;
+
+#[derive(DeriveIden)]
+#[allow(dead_code)]
+enum IndexedBlock {
+    Table,
+    Slot,
+    ParentSlot,
+    Blockhash,
+    PreviousBlockhash,
+    BlockTime,
+    BlockHeight,
+    TransactionCount,
+    VoteTransactionCount,
+    TotalFee,
+    Skipped,
+    IndexedAt,
+}
+
+#[derive(DeriveIden)]
+#[allow(dead_code)]
+enum IndexedTransaction {
+    Table,
+    Id,
+    Signature,
+    Slot,
+    TxIndex,
+    BlockTime,
+    Fee,
+    Success,
+    Error,
+    ComputeUnits,
+    Accounts,
+    ProgramIds,
+    CreatedAt,
+}
+
+#[derive(DeriveIden)]
+#[allow(dead_code)]
+enum IndexerCheckpoint {
+    Table,
+    Name,
+    Slot,
+    UpdatedAt,
+}
+
+#[derive(DeriveMigrationName)]
+pub struct MigratorHandle;
+
+#[async_trait]
+impl MigrationTrait for MigratorHandle {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager.create_table(
+            Table::create()
+                .table(IndexedBlock::Table)
+                .if_not_exists()
+                .col(ColumnDef::new(IndexedBlock::Slot).big_integer().not_null().primary_key())
+                .col(ColumnDef::new(IndexedBlock::ParentSlot).big_integer())
+                .col(ColumnDef::new(IndexedBlock::Blockhash).string())
+                .col(ColumnDef::new(IndexedBlock::PreviousBlockhash).string())
+                .col(ColumnDef::new(IndexedBlock::BlockTime).big_integer())
+                .col(ColumnDef::new(IndexedBlock::BlockHeight).big_integer())
+                .col(ColumnDef::new(IndexedBlock::TransactionCount).integer().not_null())
+                .col(ColumnDef::new(IndexedBlock::VoteTransactionCount).integer().not_null())
+                .col(ColumnDef::new(IndexedBlock::TotalFee).big_integer().not_null())
+                .col(ColumnDef::new(IndexedBlock::Skipped).boolean().not_null())
+                .col(ColumnDef::new(IndexedBlock::IndexedAt).date_time().not_null())
+                .to_owned(),
+        ).await?;
+
+        manager.create_table(
+            Table::create()
+                .table(IndexedTransaction::Table)
+                .if_not_exists()
+                .col(ColumnDef::new(IndexedTransaction::Id).big_integer().not_null().auto_increment().primary_key())
+                .col(ColumnDef::new(IndexedTransaction::Signature).string_len(88).not_null())
+                .col(ColumnDef::new(IndexedTransaction::Slot).big_integer().not_null())
+                .col(ColumnDef::new(IndexedTransaction::TxIndex).integer().not_null())
+                .col(ColumnDef::new(IndexedTransaction::BlockTime).big_integer())
+                .col(ColumnDef::new(IndexedTransaction::Fee).big_integer().not_null())
+                .col(ColumnDef::new(IndexedTransaction::Success).boolean().not_null())
+                .col(ColumnDef::new(IndexedTransaction::Error).text())
+                .col(ColumnDef::new(IndexedTransaction::ComputeUnits).big_integer())
+                .col(ColumnDef::new(IndexedTransaction::Accounts).text().not_null())
+                .col(ColumnDef::new(IndexedTransaction::ProgramIds).text().not_null())
+                .col(ColumnDef::new(IndexedTransaction::CreatedAt).date_time().not_null())
+                .to_owned(),
+        ).await?;
+
+        manager.create_index(
+            Index::create()
+                .name("idx_indexed_transaction_signature")
+                .table(IndexedTransaction::Table)
+                .col(IndexedTransaction::Signature)
+                .to_owned(),
+        ).await?;
+
+        manager.create_index(
+            Index::create()
+                .name("idx_indexed_transaction_slot")
+                .table(IndexedTransaction::Table)
+                .col(IndexedTransaction::Slot)
+                .to_owned(),
+        ).await?;
+
+        manager.create_table(
+            Table::create()
+                .table(IndexerCheckpoint::Table)
+                .if_not_exists()
+                .col(ColumnDef::new(IndexerCheckpoint::Name).string_len(32).not_null().primary_key())
+                .col(ColumnDef::new(IndexerCheckpoint::Slot).big_integer().not_null())
+                .col(ColumnDef::new(IndexerCheckpoint::UpdatedAt).date_time().not_null())
+                .to_owned(),
+        ).await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager.drop_table(
+            Table::drop().table(IndexedBlock::Table).if_exists().to_owned()
+        ).await?;
+
+        manager.drop_table(
+            Table::drop().table(IndexedTransaction::Table).if_exists().to_owned()
+        ).await?;
+
+        manager.drop_table(
+            Table::drop().table(IndexerCheckpoint::Table).if_exists().to_owned()
+        ).await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,56 @@
+use sea_orm_migration::async_trait::async_trait;
+use sea_orm_migration::prelude::*;
+use sea_orm::DeriveIden;
+
+#[derive(DeriveIden)]
+#[allow(dead_code)]
+enum NetworkSample {
+    Table,
+    Slot,
+    BlockTime,
+    TransactionCount,
+    NonVoteTransactionCount,
+    TotalFee,
+    ComputeUnits,
+    CreatedAt,
+}
+
+#[derive(DeriveMigrationName)]
+pub struct MigratorHandle;
+
+#[async_trait]
+impl MigrationTrait for MigratorHandle {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager.create_table(
+            Table::create()
+                .table(NetworkSample::Table)
+                .if_not_exists()
+                .col(ColumnDef::new(NetworkSample::Slot).big_integer().not_null().primary_key())
+                .col(ColumnDef::new(NetworkSample::BlockTime).big_integer().not_null())
+                .col(ColumnDef::new(NetworkSample::TransactionCount).integer().not_null())
+                .col(ColumnDef::new(NetworkSample::NonVoteTransactionCount).integer().not_null())
+                .col(ColumnDef::new(NetworkSample::TotalFee).big_integer().not_null())
+                .col(ColumnDef::new(NetworkSample::ComputeUnits).big_integer().not_null())
+                .col(ColumnDef::new(NetworkSample::CreatedAt).date_time().not_null())
+                .to_owned(),
+        ).await?;
+
+        manager.create_index(
+            Index::create()
+                .name("idx_network_sample_block_time")
+                .table(NetworkSample::Table)
+                .col(NetworkSample::BlockTime)
+                .to_owned(),
+        ).await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager.drop_table(
+            Table::drop().table(NetworkSample::Table).if_exists().to_owned()
+        ).await?;
+
+        Ok(())
+    }
+}
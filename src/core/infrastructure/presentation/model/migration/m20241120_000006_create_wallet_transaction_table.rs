@@ -0,0 +1,77 @@
+use sea_orm_migration::async_trait::async_trait;
+use sea_orm_migration::prelude::*;
+use sea_orm::DeriveIden;
+
+#[derive(DeriveIden)]
+#[allow(dead_code)]
+enum WalletTransaction {
+    Table,
+    Id,
+    WalletId,
+    Signature,
+    Slot,
+    BlockTime,
+    Success,
+    Error,
+    Fee,
+    SolDelta,
+    TokenDeltas,
+    Instructions,
+    CreatedAt,
+}
+
+#[derive(DeriveMigrationName)]
+pub struct MigratorHandle;
+
+#[async_trait]
+impl MigrationTrait for MigratorHandle {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager.create_table(
+            Table::create()
+                .table(WalletTransaction::Table)
+                .if_not_exists()
+                .col(ColumnDef::new(WalletTransaction::Id).big_integer().not_null().auto_increment().primary_key())
+                .col(ColumnDef::new(WalletTransaction::WalletId).integer().not_null())
+                .col(ColumnDef::new(WalletTransaction::Signature).string_len(88).not_null())
+                .col(ColumnDef::new(WalletTransaction::Slot).big_integer().not_null())
+                .col(ColumnDef::new(WalletTransaction::BlockTime).big_integer())
+                .col(ColumnDef::new(WalletTransaction::Success).boolean().not_null())
+                .col(ColumnDef::new(WalletTransaction::Error).text())
+                .col(ColumnDef::new(WalletTransaction::Fee).big_integer().not_null())
+                .col(ColumnDef::new(WalletTransaction::SolDelta).big_integer().not_null())
+                .col(ColumnDef::new(WalletTransaction::TokenDeltas).text().not_null())
+                .col(ColumnDef::new(WalletTransaction::Instructions).text().not_null())
+                .col(ColumnDef::new(WalletTransaction::CreatedAt).date_time().not_null())
+                .to_owned(),
+        ).await?;
+
+        manager.create_index(
+            Index::create()
+                .name("idx_wallet_transaction_signature")
+                .table(WalletTransaction::Table)
+                .col(WalletTransaction::WalletId)
+                .col(WalletTransaction::Signature)
+                .unique()
+                .to_owned(),
+        ).await?;
+
+        manager.create_index(
+            Index::create()
+                .name("idx_wallet_transaction_slot")
+                .table(WalletTransaction::Table)
+                .col(WalletTransaction::WalletId)
+                .col(WalletTransaction::Slot)
+                .to_owned(),
+        ).await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager.drop_table(
+            Table::drop().table(WalletTransaction::Table).if_exists().to_owned()
+        ).await?;
+
+        Ok(())
+    }
+}
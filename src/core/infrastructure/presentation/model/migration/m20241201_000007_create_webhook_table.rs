@@ -0,0 +1,122 @@
+use sea_orm_migration::async_trait::async_trait;
+use sea_orm_migration::prelude::*;
+use sea_orm::DeriveIden;
+
+#[derive(DeriveIden)]
+#[allow(dead_code)]
+enum WebhookEndpoint {
+    Table,
+    Id,
+    UserId,
+    Url,
+    Secret,
+    Events,
+    BalanceThreshold,
+    Enabled,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(DeriveIden)]
+#[allow(dead_code)]
+enum WebhookDelivery {
+    Table,
+    Id,
+    EndpointId,
+    EventId,
+    EventType,
+    Payload,
+    Status,
+    Attempts,
+    ResponseStatus,
+    Error,
+    ReplayOf,
+    NextAttemptAt,
+    DeliveredAt,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(DeriveMigrationName)]
+pub struct MigratorHandle;
+
+#[async_trait]
+impl MigrationTrait for MigratorHandle {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager.create_table(
+            Table::create()
+                .table(WebhookEndpoint::Table)
+                .if_not_exists()
+                .col(ColumnDef::new(WebhookEndpoint::Id).integer().not_null().auto_increment().primary_key())
+                .col(ColumnDef::new(WebhookEndpoint::UserId).integer())
+                .col(ColumnDef::new(WebhookEndpoint::Url).string_len(512).not_null())
+                .col(ColumnDef::new(WebhookEndpoint::Secret).string().not_null())
+                .col(ColumnDef::new(WebhookEndpoint::Events).string().not_null())
+                .col(ColumnDef::new(WebhookEndpoint::BalanceThreshold).double())
+                .col(ColumnDef::new(WebhookEndpoint::Enabled).boolean().not_null())
+                .col(ColumnDef::new(WebhookEndpoint::CreatedAt).date_time().not_null())
+                .col(ColumnDef::new(WebhookEndpoint::UpdatedAt).date_time().not_null())
+                .to_owned(),
+        ).await?;
+
+        manager.create_index(
+            Index::create()
+                .name("idx_webhook_endpoint_user")
+                .table(WebhookEndpoint::Table)
+                .col(WebhookEndpoint::UserId)
+                .to_owned(),
+        ).await?;
+
+        manager.create_table(
+            Table::create()
+                .table(WebhookDelivery::Table)
+                .if_not_exists()
+                .col(ColumnDef::new(WebhookDelivery::Id).integer().not_null().auto_increment().primary_key())
+                .col(ColumnDef::new(WebhookDelivery::EndpointId).integer().not_null())
+                .col(ColumnDef::new(WebhookDelivery::EventId).string_len(64).not_null())
+                .col(ColumnDef::new(WebhookDelivery::EventType).string_len(32).not_null())
+                .col(ColumnDef::new(WebhookDelivery::Payload).text().not_null())
+                .col(ColumnDef::new(WebhookDelivery::Status).string_len(16).not_null())
+                .col(ColumnDef::new(WebhookDelivery::Attempts).integer().not_null())
+                .col(ColumnDef::new(WebhookDelivery::ResponseStatus).integer())
+                .col(ColumnDef::new(WebhookDelivery::Error).text())
+                .col(ColumnDef::new(WebhookDelivery::ReplayOf).integer())
+                .col(ColumnDef::new(WebhookDelivery::NextAttemptAt).date_time())
+                .col(ColumnDef::new(WebhookDelivery::DeliveredAt).date_time())
+                .col(ColumnDef::new(WebhookDelivery::CreatedAt).date_time().not_null())
+                .col(ColumnDef::new(WebhookDelivery::UpdatedAt).date_time().not_null())
+                .to_owned(),
+        ).await?;
+
+        manager.create_index(
+            Index::create()
+                .name("idx_webhook_delivery_status")
+                .table(WebhookDelivery::Table)
+                .col(WebhookDelivery::Status)
+                .col(WebhookDelivery::NextAttemptAt)
+                .to_owned(),
+        ).await?;
+
+        manager.create_index(
+            Index::create()
+                .name("idx_webhook_delivery_endpoint")
+                .table(WebhookDelivery::Table)
+                .col(WebhookDelivery::EndpointId)
+                .to_owned(),
+        ).await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager.drop_table(
+            Table::drop().table(WebhookEndpoint::Table).if_exists().to_owned()
+        ).await?;
+
+        manager.drop_table(
+            Table::drop().table(WebhookDelivery::Table).if_exists().to_owned()
+        ).await?;
+
+        Ok(())
+    }
+}
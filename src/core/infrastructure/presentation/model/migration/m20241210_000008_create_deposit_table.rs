@@ -0,0 +1,63 @@
+use sea_orm_migration::async_trait::async_trait;
+use sea_orm_migration::prelude::*;
+use sea_orm::DeriveIden;
+
+#[derive(DeriveIden)]
+#[allow(dead_code)]
+enum Deposit {
+    Table,
+    Id,
+    WalletId,
+    UserId,
+    Address,
+    Signature,
+    Slot,
+    Lamports,
+    Amount,
+    CreatedAt,
+}
+
+#[derive(DeriveMigrationName)]
+pub struct MigratorHandle;
+
+#[async_trait]
+impl MigrationTrait for MigratorHandle {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager.create_table(
+            Table::create()
+                .table(Deposit::Table)
+                .if_not_exists()
+                .col(ColumnDef::new(Deposit::Id).integer().not_null().auto_increment().primary_key())
+                .col(ColumnDef::new(Deposit::WalletId).integer().not_null())
+                .col(ColumnDef::new(Deposit::UserId).integer().not_null())
+                .col(ColumnDef::new(Deposit::Address).string_len(64).not_null())
+                .col(ColumnDef::new(Deposit::Signature).string_len(128).not_null())
+                .col(ColumnDef::new(Deposit::Slot).big_integer().not_null())
+                .col(ColumnDef::new(Deposit::Lamports).big_integer().not_null())
+                .col(ColumnDef::new(Deposit::Amount).double().not_null())
+                .col(ColumnDef::new(Deposit::CreatedAt).date_time().not_null())
+                .to_owned(),
+        ).await?;
+
+        // 同一钱包同一交易只入账一次
+        manager.create_index(
+            Index::create()
+                .name("idx_deposit_wallet_signature")
+                .table(Deposit::Table)
+                .col(Deposit::WalletId)
+                .col(Deposit::Signature)
+                .unique()
+                .to_owned(),
+        ).await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager.drop_table(
+            Table::drop().table(Deposit::Table).if_exists().to_owned()
+        ).await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,130 @@
+use sea_orm_migration::async_trait::async_trait;
+use sea_orm_migration::prelude::*;
+use sea_orm::DeriveIden;
+
+#[derive(DeriveIden)]
+#[allow(dead_code)]
+enum IndexedBlock {
+    Table,
+    Slot,
+    Commitment,
+}
+
+#[derive(DeriveIden)]
+#[allow(dead_code)]
+enum IndexedTransaction {
+    Table,
+    Commitment,
+}
+
+#[derive(DeriveIden)]
+#[allow(dead_code)]
+enum Deposit {
+    Table,
+    Commitment,
+}
+
+#[derive(DeriveIden)]
+#[allow(dead_code)]
+enum LedgerRollback {
+    Table,
+    Id,
+    DepositId,
+    WalletId,
+    UserId,
+    Signature,
+    Slot,
+    Amount,
+    Debited,
+    Shortfall,
+    Reason,
+    CreatedAt,
+}
+
+#[derive(DeriveMigrationName)]
+pub struct MigratorHandle;
+
+#[async_trait]
+impl MigrationTrait for MigratorHandle {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // 已有数据均按 confirmed 级别写入，由最终确认任务重新核对
+        manager.alter_table(
+            Table::alter()
+                .table(IndexedBlock::Table)
+                .add_column(ColumnDef::new(IndexedBlock::Commitment).string_len(16).not_null().default("confirmed"))
+                .to_owned(),
+        ).await?;
+        manager.alter_table(
+            Table::alter()
+                .table(IndexedTransaction::Table)
+                .add_column(ColumnDef::new(IndexedTransaction::Commitment).string_len(16).not_null().default("confirmed"))
+                .to_owned(),
+        ).await?;
+        manager.alter_table(
+            Table::alter()
+                .table(Deposit::Table)
+                .add_column(ColumnDef::new(Deposit::Commitment).string_len(16).not_null().default("confirmed"))
+                .to_owned(),
+        ).await?;
+
+        manager.create_table(
+            Table::create()
+                .table(LedgerRollback::Table)
+                .if_not_exists()
+                .col(ColumnDef::new(LedgerRollback::Id).integer().not_null().auto_increment().primary_key())
+                .col(ColumnDef::new(LedgerRollback::DepositId).integer().not_null())
+                .col(ColumnDef::new(LedgerRollback::WalletId).integer().not_null())
+                .col(ColumnDef::new(LedgerRollback::UserId).integer().not_null())
+                .col(ColumnDef::new(LedgerRollback::Signature).string_len(128).not_null())
+                .col(ColumnDef::new(LedgerRollback::Slot).big_integer().not_null())
+                .col(ColumnDef::new(LedgerRollback::Amount).double().not_null())
+                .col(ColumnDef::new(LedgerRollback::Debited).double().not_null())
+                .col(ColumnDef::new(LedgerRollback::Shortfall).double().not_null())
+                .col(ColumnDef::new(LedgerRollback::Reason).string_len(512).not_null())
+                .col(ColumnDef::new(LedgerRollback::CreatedAt).date_time().not_null())
+                .to_owned(),
+        ).await?;
+
+        // 同一笔充值只回滚一次
+        manager.create_index(
+            Index::create()
+                .name("idx_ledger_rollback_deposit")
+                .table(LedgerRollback::Table)
+                .col(LedgerRollback::DepositId)
+                .unique()
+                .to_owned(),
+        ).await?;
+
+        // 最终确认任务按确认级别与 slot 查找待核对的记录
+        manager.create_index(
+            Index::create()
+                .name("idx_indexed_block_commitment_slot")
+                .table(IndexedBlock::Table)
+                .col(IndexedBlock::Commitment)
+                .col(IndexedBlock::Slot)
+                .to_owned(),
+        ).await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager.drop_index(
+            Index::drop().name("idx_indexed_block_commitment_slot").table(IndexedBlock::Table).to_owned()
+        ).await?;
+        manager.drop_table(
+            Table::drop().table(LedgerRollback::Table).if_exists().to_owned()
+        ).await?;
+        manager.alter_table(
+            Table::alter().table(Deposit::Table).drop_column(Deposit::Commitment).to_owned()
+        ).await?;
+        manager.alter_table(
+            Table::alter().table(IndexedTransaction::Table).drop_column(IndexedTransaction::Commitment).to_owned()
+        ).await?;
+        manager.alter_table(
+            Table::alter().table(IndexedBlock::Table).drop_column(IndexedBlock::Commitment).to_owned()
+        ).await?;
+
+        Ok(())
+    }
+}
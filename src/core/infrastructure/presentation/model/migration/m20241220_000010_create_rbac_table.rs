@@ -0,0 +1,191 @@
+use sea_orm_migration::async_trait::async_trait;
+use sea_orm_migration::prelude::*;
+use sea_orm::DeriveIden;
+
+/// # Description
+///     初始迁移中的 role 表（user_id + permission），与实体的 account_id 不一致且从未被写入，重建为角色表
+#[derive(DeriveIden)]
+#[allow(dead_code)]
+enum LegacyRole {
+    #[sea_orm(iden = "role")]
+    Table,
+    Id,
+    UserId,
+    Permission,
+    CreatedAt,
+    UpdatedAt,
+    DeletedAt,
+}
+
+#[derive(DeriveIden)]
+#[allow(dead_code)]
+enum Role {
+    Table,
+    Id,
+    Name,
+    Description,
+    Builtin,
+    CreatedAt,
+    UpdatedAt,
+    DeletedAt,
+}
+
+#[derive(DeriveIden)]
+#[allow(dead_code)]
+enum Permission {
+    Table,
+    Id,
+    Code,
+    Description,
+    CreatedAt,
+}
+
+#[derive(DeriveIden)]
+#[allow(dead_code)]
+enum RolePermission {
+    Table,
+    Id,
+    RoleId,
+    PermissionId,
+    CreatedAt,
+}
+
+#[derive(DeriveIden)]
+#[allow(dead_code)]
+enum UserRole {
+    Table,
+    Id,
+    UserId,
+    RoleId,
+    CreatedAt,
+}
+
+#[derive(DeriveMigrationName)]
+pub struct MigratorHandle;
+
+#[async_trait]
+impl MigrationTrait for MigratorHandle {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager.drop_table(
+            Table::drop().table(LegacyRole::Table).if_exists().to_owned()
+        ).await?;
+
+        manager.create_table(
+            Table::create()
+                .table(Role::Table)
+                .if_not_exists()
+                .col(ColumnDef::new(Role::Id).integer().not_null().auto_increment().primary_key())
+                .col(ColumnDef::new(Role::Name).string_len(32).not_null())
+                .col(ColumnDef::new(Role::Description).string())
+                .col(ColumnDef::new(Role::Builtin).boolean().not_null())
+                .col(ColumnDef::new(Role::CreatedAt).date_time().not_null())
+                .col(ColumnDef::new(Role::UpdatedAt).date_time().not_null())
+                .col(ColumnDef::new(Role::DeletedAt).date_time())
+                .to_owned(),
+        ).await?;
+
+        manager.create_index(
+            Index::create()
+                .name("idx_role_name")
+                .table(Role::Table)
+                .col(Role::Name)
+                .to_owned(),
+        ).await?;
+
+        manager.create_table(
+            Table::create()
+                .table(Permission::Table)
+                .if_not_exists()
+                .col(ColumnDef::new(Permission::Id).integer().not_null().auto_increment().primary_key())
+                .col(ColumnDef::new(Permission::Code).string_len(64).unique_key().not_null())
+                .col(ColumnDef::new(Permission::Description).string())
+                .col(ColumnDef::new(Permission::CreatedAt).date_time().not_null())
+                .to_owned(),
+        ).await?;
+
+        manager.create_table(
+            Table::create()
+                .table(RolePermission::Table)
+                .if_not_exists()
+                .col(ColumnDef::new(RolePermission::Id).integer().not_null().auto_increment().primary_key())
+                .col(ColumnDef::new(RolePermission::RoleId).integer().not_null())
+                .col(ColumnDef::new(RolePermission::PermissionId).integer().not_null())
+                .col(ColumnDef::new(RolePermission::CreatedAt).date_time().not_null())
+                .to_owned(),
+        ).await?;
+
+        manager.create_index(
+            Index::create()
+                .name("idx_role_permission_unique")
+                .table(RolePermission::Table)
+                .col(RolePermission::RoleId)
+                .col(RolePermission::PermissionId)
+                .unique()
+                .to_owned(),
+        ).await?;
+
+        manager.create_table(
+            Table::create()
+                .table(UserRole::Table)
+                .if_not_exists()
+                .col(ColumnDef::new(UserRole::Id).integer().not_null().auto_increment().primary_key())
+                .col(ColumnDef::new(UserRole::UserId).integer().not_null())
+                .col(ColumnDef::new(UserRole::RoleId).integer().not_null())
+                .col(ColumnDef::new(UserRole::CreatedAt).date_time().not_null())
+                .to_owned(),
+        ).await?;
+
+        manager.create_index(
+            Index::create()
+                .name("idx_user_role_unique")
+                .table(UserRole::Table)
+                .col(UserRole::UserId)
+                .col(UserRole::RoleId)
+                .unique()
+                .to_owned(),
+        ).await?;
+
+        manager.create_index(
+            Index::create()
+                .name("idx_user_role_role")
+                .table(UserRole::Table)
+                .col(UserRole::RoleId)
+                .to_owned(),
+        ).await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager.drop_table(
+            Table::drop().table(UserRole::Table).if_exists().to_owned()
+        ).await?;
+
+        manager.drop_table(
+            Table::drop().table(RolePermission::Table).if_exists().to_owned()
+        ).await?;
+
+        manager.drop_table(
+            Table::drop().table(Permission::Table).if_exists().to_owned()
+        ).await?;
+
+        manager.drop_table(
+            Table::drop().table(Role::Table).if_exists().to_owned()
+        ).await?;
+
+        manager.create_table(
+            Table::create()
+                .table(LegacyRole::Table)
+                .if_not_exists()
+                .col(ColumnDef::new(LegacyRole::Id).integer().not_null().auto_increment().primary_key())
+                .col(ColumnDef::new(LegacyRole::UserId).integer().not_null())
+                .col(ColumnDef::new(LegacyRole::Permission).string().not_null())
+                .col(ColumnDef::new(LegacyRole::CreatedAt).date_time().not_null())
+                .col(ColumnDef::new(LegacyRole::UpdatedAt).date_time().not_null())
+                .col(ColumnDef::new(LegacyRole::DeletedAt).date_time())
+                .to_owned(),
+        ).await?;
+
+        Ok(())
+    }
+}
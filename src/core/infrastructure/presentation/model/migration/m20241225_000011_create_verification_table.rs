@@ -0,0 +1,72 @@
+use sea_orm_migration::async_trait::async_trait;
+use sea_orm_migration::prelude::*;
+use sea_orm::DeriveIden;
+
+#[derive(DeriveIden)]
+#[allow(dead_code)]
+enum VerificationCode {
+    Table,
+    Id,
+    Channel,
+    Target,
+    Purpose,
+    CodeHash,
+    Attempts,
+    ExpiresAt,
+    ConsumedAt,
+    CreatedAt,
+}
+
+#[derive(DeriveMigrationName)]
+pub struct MigratorHandle;
+
+#[async_trait]
+impl MigrationTrait for MigratorHandle {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager.create_table(
+            Table::create()
+                .table(VerificationCode::Table)
+                .if_not_exists()
+                .col(ColumnDef::new(VerificationCode::Id).integer().not_null().auto_increment().primary_key())
+                .col(ColumnDef::new(VerificationCode::Channel).string_len(16).not_null())
+                .col(ColumnDef::new(VerificationCode::Target).string_len(254).not_null())
+                .col(ColumnDef::new(VerificationCode::Purpose).string_len(32).not_null())
+                .col(ColumnDef::new(VerificationCode::CodeHash).string_len(64).not_null())
+                .col(ColumnDef::new(VerificationCode::Attempts).integer().not_null().default(0))
+                .col(ColumnDef::new(VerificationCode::ExpiresAt).date_time().not_null())
+                .col(ColumnDef::new(VerificationCode::ConsumedAt).date_time().null())
+                .col(ColumnDef::new(VerificationCode::CreatedAt).date_time().not_null())
+                .to_owned(),
+        ).await?;
+
+        // 按接收方查询最近一次验证码
+        manager.create_index(
+            Index::create()
+                .name("idx_verification_code_target")
+                .table(VerificationCode::Table)
+                .col(VerificationCode::Channel)
+                .col(VerificationCode::Target)
+                .col(VerificationCode::Purpose)
+                .to_owned(),
+        ).await?;
+
+        // 重置链接按令牌摘要查询
+        manager.create_index(
+            Index::create()
+                .name("idx_verification_code_hash")
+                .table(VerificationCode::Table)
+                .col(VerificationCode::CodeHash)
+                .to_owned(),
+        ).await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager.drop_table(
+            Table::drop().table(VerificationCode::Table).if_exists().to_owned()
+        ).await?;
+
+        Ok(())
+    }
+}
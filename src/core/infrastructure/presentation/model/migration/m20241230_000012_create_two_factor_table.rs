@@ -0,0 +1,122 @@
+use sea_orm_migration::async_trait::async_trait;
+use sea_orm_migration::prelude::*;
+use sea_orm::DeriveIden;
+
+#[derive(DeriveIden)]
+#[allow(dead_code)]
+enum UserTwoFactor {
+    Table,
+    Id,
+    UserId,
+    Secret,
+    Enabled,
+    LastUsedStep,
+    EnabledAt,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(DeriveIden)]
+#[allow(dead_code)]
+enum TwoFactorBackupCode {
+    Table,
+    Id,
+    UserId,
+    CodeHash,
+    UsedAt,
+    CreatedAt,
+}
+
+#[derive(DeriveIden)]
+#[allow(dead_code)]
+enum WithdrawalWhitelist {
+    Table,
+    Id,
+    UserId,
+    Address,
+    Label,
+    CreatedAt,
+}
+
+#[derive(DeriveMigrationName)]
+pub struct MigratorHandle;
+
+#[async_trait]
+impl MigrationTrait for MigratorHandle {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager.create_table(
+            Table::create()
+                .table(UserTwoFactor::Table)
+                .if_not_exists()
+                .col(ColumnDef::new(UserTwoFactor::Id).integer().not_null().auto_increment().primary_key())
+                .col(ColumnDef::new(UserTwoFactor::UserId).integer().unique_key().not_null())
+                .col(ColumnDef::new(UserTwoFactor::Secret).string_len(64).not_null())
+                .col(ColumnDef::new(UserTwoFactor::Enabled).boolean().not_null().default(false))
+                .col(ColumnDef::new(UserTwoFactor::LastUsedStep).big_integer().not_null().default(0))
+                .col(ColumnDef::new(UserTwoFactor::EnabledAt).date_time().null())
+                .col(ColumnDef::new(UserTwoFactor::CreatedAt).date_time().not_null())
+                .col(ColumnDef::new(UserTwoFactor::UpdatedAt).date_time().not_null())
+                .to_owned(),
+        ).await?;
+
+        manager.create_table(
+            Table::create()
+                .table(TwoFactorBackupCode::Table)
+                .if_not_exists()
+                .col(ColumnDef::new(TwoFactorBackupCode::Id).integer().not_null().auto_increment().primary_key())
+                .col(ColumnDef::new(TwoFactorBackupCode::UserId).integer().not_null())
+                .col(ColumnDef::new(TwoFactorBackupCode::CodeHash).string_len(64).not_null())
+                .col(ColumnDef::new(TwoFactorBackupCode::UsedAt).date_time().null())
+                .col(ColumnDef::new(TwoFactorBackupCode::CreatedAt).date_time().not_null())
+                .to_owned(),
+        ).await?;
+
+        // 按用户与摘要查询恢复码
+        manager.create_index(
+            Index::create()
+                .name("idx_two_factor_backup_code_user")
+                .table(TwoFactorBackupCode::Table)
+                .col(TwoFactorBackupCode::UserId)
+                .col(TwoFactorBackupCode::CodeHash)
+                .to_owned(),
+        ).await?;
+
+        manager.create_table(
+            Table::create()
+                .table(WithdrawalWhitelist::Table)
+                .if_not_exists()
+                .col(ColumnDef::new(WithdrawalWhitelist::Id).integer().not_null().auto_increment().primary_key())
+                .col(ColumnDef::new(WithdrawalWhitelist::UserId).integer().not_null())
+                .col(ColumnDef::new(WithdrawalWhitelist::Address).string_len(64).not_null())
+                .col(ColumnDef::new(WithdrawalWhitelist::Label).string_len(255).null())
+                .col(ColumnDef::new(WithdrawalWhitelist::CreatedAt).date_time().not_null())
+                .to_owned(),
+        ).await?;
+
+        manager.create_index(
+            Index::create()
+                .name("idx_withdrawal_whitelist_unique")
+                .table(WithdrawalWhitelist::Table)
+                .col(WithdrawalWhitelist::UserId)
+                .col(WithdrawalWhitelist::Address)
+                .unique()
+                .to_owned(),
+        ).await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager.drop_table(
+            Table::drop().table(WithdrawalWhitelist::Table).if_exists().to_owned()
+        ).await?;
+        manager.drop_table(
+            Table::drop().table(TwoFactorBackupCode::Table).if_exists().to_owned()
+        ).await?;
+        manager.drop_table(
+            Table::drop().table(UserTwoFactor::Table).if_exists().to_owned()
+        ).await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,55 @@
+use sea_orm_migration::async_trait::async_trait;
+use sea_orm_migration::prelude::*;
+use sea_orm::DeriveIden;
+
+#[derive(DeriveIden)]
+#[allow(dead_code)]
+enum AdminAuditLog {
+    Table,
+    Id,
+    ActorId,
+    Action,
+    TargetUserId,
+    Detail,
+    CreatedAt,
+}
+
+#[derive(DeriveMigrationName)]
+pub struct MigratorHandle;
+
+#[async_trait]
+impl MigrationTrait for MigratorHandle {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager.create_table(
+            Table::create()
+                .table(AdminAuditLog::Table)
+                .if_not_exists()
+                .col(ColumnDef::new(AdminAuditLog::Id).integer().not_null().auto_increment().primary_key())
+                .col(ColumnDef::new(AdminAuditLog::ActorId).integer().not_null())
+                .col(ColumnDef::new(AdminAuditLog::Action).string_len(32).not_null())
+                .col(ColumnDef::new(AdminAuditLog::TargetUserId).integer().not_null())
+                .col(ColumnDef::new(AdminAuditLog::Detail).text().null())
+                .col(ColumnDef::new(AdminAuditLog::CreatedAt).date_time().not_null())
+                .to_owned(),
+        ).await?;
+
+        // 按被操作用户查询审计记录
+        manager.create_index(
+            Index::create()
+                .name("idx_admin_audit_log_target")
+                .table(AdminAuditLog::Table)
+                .col(AdminAuditLog::TargetUserId)
+                .to_owned(),
+        ).await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager.drop_table(
+            Table::drop().table(AdminAuditLog::Table).if_exists().to_owned()
+        ).await?;
+
+        Ok(())
+    }
+}
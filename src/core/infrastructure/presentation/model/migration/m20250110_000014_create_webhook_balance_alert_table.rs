@@ -0,0 +1,55 @@
+use sea_orm_migration::async_trait::async_trait;
+use sea_orm_migration::prelude::*;
+use sea_orm::DeriveIden;
+
+#[derive(DeriveIden)]
+#[allow(dead_code)]
+enum WebhookBalanceAlert {
+    Table,
+    Id,
+    EndpointId,
+    WalletId,
+    Balance,
+    CreatedAt,
+}
+
+#[derive(DeriveMigrationName)]
+pub struct MigratorHandle;
+
+#[async_trait]
+impl MigrationTrait for MigratorHandle {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager.create_table(
+            Table::create()
+                .table(WebhookBalanceAlert::Table)
+                .if_not_exists()
+                .col(ColumnDef::new(WebhookBalanceAlert::Id).integer().not_null().auto_increment().primary_key())
+                .col(ColumnDef::new(WebhookBalanceAlert::EndpointId).integer().not_null())
+                .col(ColumnDef::new(WebhookBalanceAlert::WalletId).integer().not_null())
+                .col(ColumnDef::new(WebhookBalanceAlert::Balance).double().not_null())
+                .col(ColumnDef::new(WebhookBalanceAlert::CreatedAt).date_time().not_null())
+                .to_owned(),
+        ).await?;
+
+        // 同一端点与钱包只能有一条告警，多个实例同时检查时只有一个能写入并发送
+        manager.create_index(
+            Index::create()
+                .name("idx_webhook_balance_alert_endpoint_wallet")
+                .table(WebhookBalanceAlert::Table)
+                .col(WebhookBalanceAlert::EndpointId)
+                .col(WebhookBalanceAlert::WalletId)
+                .unique()
+                .to_owned(),
+        ).await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager.drop_table(
+            Table::drop().table(WebhookBalanceAlert::Table).if_exists().to_owned()
+        ).await?;
+
+        Ok(())
+    }
+}
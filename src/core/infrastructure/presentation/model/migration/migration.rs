@@ -1,6 +1,21 @@
 use sea_orm_migration::prelude::*;
 use sea_orm_migration::async_trait::async_trait;
-use crate::core::infrastructure::presentation::model::migration::m20220101_000001_create_table;
+use crate::core::infrastructure::presentation::model::migration::{
+    m20220101_000001_create_table,
+    m20241001_000002_create_order_table,
+    m20241015_000003_create_dca_table,
+    m20241101_000004_create_indexer_table,
+    m20241110_000005_create_network_table,
+    m20241120_000006_create_wallet_transaction_table,
+    m20241201_000007_create_webhook_table,
+    m20241210_000008_create_deposit_table,
+    m20241215_000009_add_commitment_columns,
+    m20241220_000010_create_rbac_table,
+    m20241225_000011_create_verification_table,
+    m20241230_000012_create_two_factor_table,
+    m20250105_000013_create_admin_audit_table,
+    m20250110_000014_create_webhook_balance_alert_table,
+};
 
 pub struct MigratorHandle;
 
@@ -9,6 +24,19 @@ impl MigratorTrait for MigratorHandle {
     fn migrations() -> Vec<Box<dyn MigrationTrait>> {
         vec![
             Box::new(m20220101_000001_create_table::MigratorHandle),
+            Box::new(m20241001_000002_create_order_table::MigratorHandle),
+            Box::new(m20241015_000003_create_dca_table::MigratorHandle),
+            Box::new(m20241101_000004_create_indexer_table::MigratorHandle),
+            Box::new(m20241110_000005_create_network_table::MigratorHandle),
+            Box::new(m20241120_000006_create_wallet_transaction_table::MigratorHandle),
+            Box::new(m20241201_000007_create_webhook_table::MigratorHandle),
+            Box::new(m20241210_000008_create_deposit_table::MigratorHandle),
+            Box::new(m20241215_000009_add_commitment_columns::MigratorHandle),
+            Box::new(m20241220_000010_create_rbac_table::MigratorHandle),
+            Box::new(m20241225_000011_create_verification_table::MigratorHandle),
+            Box::new(m20241230_000012_create_two_factor_table::MigratorHandle),
+            Box::new(m20250105_000013_create_admin_audit_table::MigratorHandle),
+            Box::new(m20250110_000014_create_webhook_balance_alert_table::MigratorHandle),
         ]
     }
 }
@@ -1,4 +1,17 @@
 mod migration;
 mod m20220101_000001_create_table;
+mod m20241001_000002_create_order_table;
+mod m20241015_000003_create_dca_table;
+mod m20241101_000004_create_indexer_table;
+mod m20241110_000005_create_network_table;
+mod m20241120_000006_create_wallet_transaction_table;
+mod m20241201_000007_create_webhook_table;
+mod m20241210_000008_create_deposit_table;
+mod m20241215_000009_add_commitment_columns;
+mod m20241220_000010_create_rbac_table;
+mod m20241225_000011_create_verification_table;
+mod m20241230_000012_create_two_factor_table;
+mod m20250105_000013_create_admin_audit_table;
+mod m20250110_000014_create_webhook_balance_alert_table;
 
-pub use migration::MigratorHandle;
\ No newline at end of file
+pub use migration::MigratorHandle;
@@ -0,0 +1,201 @@
+use chrono::{DateTime, Utc};
+use sea_orm::{DatabaseConnection, DbErr, EntityTrait, IntoActiveModel, QueryOrder, QuerySelect, TransactionTrait};
+use sea_orm::sea_query::Expr;
+use sea_orm::entity::prelude::*;
+use axum::async_trait;
+use std::sync::Arc;
+use color_eyre::{Report, Result};
+use crate::core::domain::dca::entity::dca_schedule_entity::{ActiveModel as DcaScheduleActiveModel, Column as DcaScheduleColumn, Entity as DcaScheduleEntity, Model as DcaScheduleModel, DcaStatus};
+use crate::core::domain::dca::entity::dca_execution_entity::{ActiveModel as DcaExecutionActiveModel, Column as DcaExecutionColumn, Entity as DcaExecutionEntity, Model as DcaExecutionModel};
+use crate::core::domain::dca::repository::DcaRepositoryInterface;
+
+/// # Description
+///     定投计划仓储实现
+/// # Fields
+///     db: Arc<DatabaseConnection>: 数据库连接
+pub struct DcaRepository {
+    db: Arc<DatabaseConnection>,
+}
+
+impl DcaRepository {
+    /// # Description
+    ///     创建新的定投计划仓储实例
+    /// # Param
+    ///     db: DatabaseConnection: 数据库连接
+    /// # Return
+    ///     DcaRepository: 定投计划仓储实例
+    pub async fn new(db: DatabaseConnection) -> Self {
+        Self { db: Arc::new(db) }
+    }
+}
+
+#[async_trait]
+impl DcaRepositoryInterface for DcaRepository {
+    /// # Description
+    ///     根据计划ID查找定投计划
+    /// # Param
+    ///     id: i32: 计划ID
+    /// # Return
+    ///     Result<Option<DcaScheduleModel>, Report>: 定投计划或错误信息
+    async fn find_by_id(&self, id: i32) -> Result<Option<DcaScheduleModel>, Report> {
+        let result = DcaScheduleEntity::find_by_id(id)
+            .one(self.db.as_ref())
+            .await?;
+
+        Ok(result)
+    }
+
+    /// # Description
+    ///     查询用户的全部定投计划，按创建时间倒序
+    /// # Param
+    ///     user_id: i32: 用户ID
+    /// # Return
+    ///     Result<Vec<DcaScheduleModel>, Report>: 定投计划列表或错误信息
+    async fn find_by_user(&self, user_id: i32) -> Result<Vec<DcaScheduleModel>, Report> {
+        let result = DcaScheduleEntity::find()
+            .filter(DcaScheduleColumn::UserId.eq(user_id))
+            .order_by_desc(DcaScheduleColumn::CreatedAt)
+            .all(self.db.as_ref())
+            .await?;
+
+        Ok(result)
+    }
+
+    /// # Description
+    ///     查询已到执行时间的运行中计划，最早到期的优先
+    /// # Param
+    ///     now: DateTime<Utc>: 当前时间
+    ///     limit: u64: 最大数量
+    /// # Return
+    ///     Result<Vec<DcaScheduleModel>, Report>: 定投计划列表或错误信息
+    async fn find_due(&self, now: DateTime<Utc>, limit: u64) -> Result<Vec<DcaScheduleModel>, Report> {
+        let result = DcaScheduleEntity::find()
+            .filter(DcaScheduleColumn::Status.eq(DcaStatus::Active))
+            .filter(DcaScheduleColumn::NextRunAt.lte(now))
+            .order_by_asc(DcaScheduleColumn::NextRunAt)
+            .limit(limit)
+            .all(self.db.as_ref())
+            .await?;
+
+        Ok(result)
+    }
+
+    /// # Description
+    ///     保存定投计划，新建或更新
+    /// # Param
+    ///     schedule: DcaScheduleActiveModel: 定投计划
+    /// # Return
+    ///     Result<DcaScheduleModel, Report>: 保存后的定投计划
+    async fn save(&self, schedule: DcaScheduleActiveModel) -> Result<DcaScheduleModel, Report> {
+        let schedule_model = schedule.save(self.db.as_ref()).await?;
+
+        Ok(schedule_model.try_into_model()?)
+    }
+
+    /// # Description
+    ///     仅当数据库中的状态仍为预期状态时才更新计划，避免执行任务覆盖用户的暂停或取消
+    /// # Param
+    ///     schedule: DcaScheduleModel: 已更新的定投计划
+    ///     expected: DcaStatus: 更新前的状态
+    /// # Return
+    ///     Result<bool, Report>: 是否更新成功
+    async fn save_if_status(&self, schedule: DcaScheduleModel, expected: DcaStatus) -> Result<bool, Report> {
+        let result = DcaScheduleEntity::update(schedule.into_active_model().reset_all())
+            .filter(DcaScheduleColumn::Status.eq(expected))
+            .exec(self.db.as_ref())
+            .await;
+
+        match result {
+            Ok(_) => Ok(true),
+            Err(DbErr::RecordNotUpdated) => Ok(false),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// # Description
+    ///     占用计划的本次执行：仅当计划仍在运行中且下次执行时间仍为本次到期时间时才写入，
+    ///     多个执行任务同时扫描到同一计划时只有一个能占用成功
+    /// # Param
+    ///     schedule: DcaScheduleModel: 已推进下次执行时间的定投计划
+    ///     scheduled_for: DateTime<Utc>: 本次到期时间，即占用前的下次执行时间
+    /// # Return
+    ///     Result<bool, Report>: 是否占用成功，未更新任何行说明已被其它任务占用
+    async fn claim_run(&self, schedule: DcaScheduleModel, scheduled_for: DateTime<Utc>) -> Result<bool, Report> {
+        let result = DcaScheduleEntity::update(schedule.into_active_model().reset_all())
+            .filter(DcaScheduleColumn::Status.eq(DcaStatus::Active))
+            .filter(DcaScheduleColumn::NextRunAt.eq(scheduled_for))
+            .exec(self.db.as_ref())
+            .await;
+
+        match result {
+            Ok(_) => Ok(true),
+            Err(DbErr::RecordNotUpdated) => Ok(false),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// # Description
+    ///     写回一次执行后的运行字段（累计支出、重试次数、执行时间、错误与下次执行时间），
+    ///     不覆盖状态；达到支出上限的计划只在状态仍为运行中时标记为完成，保留执行期间用户的暂停或取消
+    /// # Param
+    ///     schedule: DcaScheduleModel: 记录执行结果后的定投计划
+    /// # Return
+    ///     Result<(), Report>: 保存结果
+    async fn save_run(&self, schedule: DcaScheduleModel) -> Result<(), Report> {
+        let txn = self.db.begin().await?;
+
+        DcaScheduleEntity::update_many()
+            .col_expr(DcaScheduleColumn::Spent, Expr::value(schedule.spent))
+            .col_expr(DcaScheduleColumn::RetryCount, Expr::value(schedule.retry_count))
+            .col_expr(DcaScheduleColumn::LastRunAt, Expr::value(schedule.last_run_at))
+            .col_expr(DcaScheduleColumn::LastError, Expr::value(schedule.last_error.clone()))
+            .col_expr(DcaScheduleColumn::NextRunAt, Expr::value(schedule.next_run_at))
+            .col_expr(DcaScheduleColumn::UpdatedAt, Expr::value(schedule.updated_at))
+            .filter(DcaScheduleColumn::Id.eq(schedule.id))
+            .exec(&txn)
+            .await?;
+
+        if schedule.status == DcaStatus::Completed {
+            DcaScheduleEntity::update_many()
+                .col_expr(DcaScheduleColumn::Status, Expr::value(DcaStatus::Completed))
+                .filter(DcaScheduleColumn::Id.eq(schedule.id))
+                .filter(DcaScheduleColumn::Status.eq(DcaStatus::Active))
+                .exec(&txn)
+                .await?;
+        }
+
+        txn.commit().await?;
+
+        Ok(())
+    }
+
+    /// # Description
+    ///     记录一次执行结果
+    /// # Param
+    ///     execution: DcaExecutionActiveModel: 执行记录
+    /// # Return
+    ///     Result<(), Report>: 保存结果
+    async fn record_execution(&self, execution: DcaExecutionActiveModel) -> Result<(), Report> {
+        execution.insert(self.db.as_ref()).await?;
+
+        Ok(())
+    }
+
+    /// # Description
+    ///     查询计划最近的执行记录，按执行时间倒序
+    /// # Param
+    ///     schedule_id: i32: 计划ID
+    ///     limit: u64: 最大数量
+    /// # Return
+    ///     Result<Vec<DcaExecutionModel>, Report>: 执行记录列表
+    async fn find_executions(&self, schedule_id: i32, limit: u64) -> Result<Vec<DcaExecutionModel>, Report> {
+        let result = DcaExecutionEntity::find()
+            .filter(DcaExecutionColumn::ScheduleId.eq(schedule_id))
+            .order_by_desc(DcaExecutionColumn::Id)
+            .limit(limit)
+            .all(self.db.as_ref())
+            .await?;
+
+        Ok(result)
+    }
+}
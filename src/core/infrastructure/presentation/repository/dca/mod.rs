@@ -0,0 +1 @@
+pub mod dca_repository;
@@ -0,0 +1,145 @@
+use sea_orm::{ActiveModelTrait, DatabaseConnection, EntityTrait, PaginatorTrait, QueryOrder, QuerySelect, TransactionTrait};
+use sea_orm::sea_query::Expr;
+use sea_orm::entity::prelude::*;
+use axum::async_trait;
+use std::sync::Arc;
+use color_eyre::{Report, Result};
+use crate::core::domain::watcher::entity::deposit_entity::{ActiveModel as DepositActiveModel, Column as DepositColumn, Entity as DepositEntity, Model as DepositModel};
+use crate::core::domain::reorg::entity::commitment_entity::CommitmentLevel;
+use crate::core::domain::wallet::entity::{Column as WalletColumn, Entity as WalletEntity};
+use crate::core::domain::watcher::repository::DepositRepositoryInterface;
+
+/// # Description
+///     充值记录仓储实现
+/// # Fields
+///     db: Arc<DatabaseConnection>: 数据库连接
+pub struct DepositRepository {
+    db: Arc<DatabaseConnection>,
+}
+
+impl DepositRepository {
+    /// # Description
+    ///     创建新的充值记录仓储实例
+    /// # Param
+    ///     db: DatabaseConnection: 数据库连接
+    /// # Return
+    ///     DepositRepository: 充值记录仓储实例
+    pub async fn new(db: DatabaseConnection) -> Self {
+        Self { db: Arc::new(db) }
+    }
+}
+
+#[async_trait]
+impl DepositRepositoryInterface for DepositRepository {
+    /// # Description
+    ///     判断交易是否已为该钱包入账
+    /// # Param
+    ///     wallet_id: i32: 钱包id
+    ///     signature: &str: 交易签名
+    /// # Return
+    ///     Result<bool, Report>: 是否已入账
+    async fn exists(&self, wallet_id: i32, signature: &str) -> Result<bool, Report> {
+        let count = DepositEntity::find()
+            .filter(DepositColumn::WalletId.eq(wallet_id))
+            .filter(DepositColumn::Signature.eq(signature))
+            .count(self.db.as_ref())
+            .await?;
+
+        Ok(count > 0)
+    }
+
+    /// # Description
+    ///     查询钱包最近一笔充值
+    /// # Param
+    ///     wallet_id: i32: 钱包id
+    /// # Return
+    ///     Result<Option<DepositModel>, Report>: 充值记录或错误信息
+    async fn find_latest(&self, wallet_id: i32) -> Result<Option<DepositModel>, Report> {
+        let result = DepositEntity::find()
+            .filter(DepositColumn::WalletId.eq(wallet_id))
+            .order_by_desc(DepositColumn::Slot)
+            .one(self.db.as_ref())
+            .await?;
+
+        Ok(result)
+    }
+
+    /// # Description
+    ///     保存充值记录，重复的钱包与签名由唯一索引拒绝
+    /// # Param
+    ///     deposit: DepositActiveModel: 充值记录
+    /// # Return
+    ///     Result<DepositModel, Report>: 保存后的充值记录
+    async fn save(&self, deposit: DepositActiveModel) -> Result<DepositModel, Report> {
+        Ok(deposit.insert(self.db.as_ref()).await?)
+    }
+
+    /// # Description
+    ///     在同一事务中写入充值记录并原子地增加钱包余额，任一步失败时整体回滚，
+    ///     交易可在下次扫描时重新入账
+    /// # Param
+    ///     deposit: DepositActiveModel: 充值记录
+    /// # Return
+    ///     Result<(DepositModel, f64), Report>: (保存后的充值记录, 入账后的钱包余额)，钱包不存在时返回错误
+    async fn credit(&self, deposit: DepositActiveModel) -> Result<(DepositModel, f64), Report> {
+        let txn = self.db.begin().await?;
+
+        let deposit = deposit.insert(&txn).await?;
+        let result = WalletEntity::update_many()
+            .col_expr(WalletColumn::Balance, Expr::col(WalletColumn::Balance).add(deposit.amount))
+            .filter(WalletColumn::Id.eq(deposit.wallet_id))
+            .filter(WalletColumn::DeletedAt.is_null())
+            .exec(&txn)
+            .await?;
+        if result.rows_affected == 0 {
+            txn.rollback().await?;
+            return Err(Report::msg(format!("钱包 {} 不存在", deposit.wallet_id)));
+        }
+        let wallet = WalletEntity::find_by_id(deposit.wallet_id)
+            .one(&txn)
+            .await?
+            .ok_or_else(|| Report::msg(format!("钱包 {} 不存在", deposit.wallet_id)))?;
+
+        txn.commit().await?;
+
+        Ok((deposit, wallet.balance))
+    }
+
+    /// # Description
+    ///     查询尚未最终确认的充值，按 slot 升序
+    /// # Param
+    ///     max_slot: u64: 最新的 finalized slot，只返回入账 slot 不大于该 slot 的充值
+    ///     limit: u64: 最多返回的数量
+    /// # Return
+    ///     Result<Vec<DepositModel>, Report>: 充值记录列表
+    async fn find_unfinalized(&self, max_slot: u64, limit: u64) -> Result<Vec<DepositModel>, Report> {
+        let result = DepositEntity::find()
+            .filter(DepositColumn::Commitment.eq(CommitmentLevel::Confirmed))
+            .filter(DepositColumn::Slot.lte(max_slot as i64))
+            .order_by_asc(DepositColumn::Slot)
+            .limit(limit)
+            .all(self.db.as_ref())
+            .await?;
+
+        Ok(result)
+    }
+
+    /// # Description
+    ///     将充值提升为最终确认，并更新为交易最终所在的 slot
+    /// # Param
+    ///     id: i32: 充值id
+    ///     slot: u64: 交易最终所在 slot
+    /// # Return
+    ///     Result<(), Report>: 更新结果
+    async fn finalize(&self, id: i32, slot: u64) -> Result<(), Report> {
+        DepositEntity::update_many()
+            .col_expr(DepositColumn::Commitment, Expr::value(CommitmentLevel::Finalized))
+            .col_expr(DepositColumn::Slot, Expr::value(slot as i64))
+            .filter(DepositColumn::Id.eq(id))
+            .filter(DepositColumn::Commitment.eq(CommitmentLevel::Confirmed))
+            .exec(self.db.as_ref())
+            .await?;
+
+        Ok(())
+    }
+}
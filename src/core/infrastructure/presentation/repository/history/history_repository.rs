@@ -0,0 +1,128 @@
+use sea_orm::{DatabaseConnection, EntityTrait, PaginatorTrait, QueryOrder, QuerySelect, TransactionTrait};
+use sea_orm::entity::prelude::*;
+use axum::async_trait;
+use std::sync::Arc;
+use color_eyre::{Report, Result};
+use crate::core::domain::history::entity::wallet_transaction_entity::{ActiveModel as WalletTransactionActiveModel, Column as WalletTransactionColumn, Entity as WalletTransactionEntity, Model as WalletTransactionModel};
+use crate::core::domain::history::repository::HistoryRepositoryInterface;
+
+/// # Description
+///     钱包交易历史仓储实现
+/// # Fields
+///     db: Arc<DatabaseConnection>: 数据库连接
+pub struct HistoryRepository {
+    db: Arc<DatabaseConnection>,
+}
+
+impl HistoryRepository {
+    /// # Description
+    ///     创建新的钱包交易历史仓储实例
+    /// # Param
+    ///     db: DatabaseConnection: 数据库连接
+    /// # Return
+    ///     HistoryRepository: 钱包交易历史仓储实例
+    pub async fn new(db: DatabaseConnection) -> Self {
+        Self { db: Arc::new(db) }
+    }
+}
+
+#[async_trait]
+impl HistoryRepositoryInterface for HistoryRepository {
+    /// # Description
+    ///     分页查询钱包交易，最新的在前
+    /// # Param
+    ///     wallet_id: i32: 钱包ID
+    ///     offset: u64: 跳过的数量
+    ///     limit: u64: 最大数量
+    /// # Return
+    ///     Result<Vec<WalletTransactionModel>, Report>: 交易列表
+    async fn find_page(&self, wallet_id: i32, offset: u64, limit: u64) -> Result<Vec<WalletTransactionModel>, Report> {
+        let result = WalletTransactionEntity::find()
+            .filter(WalletTransactionColumn::WalletId.eq(wallet_id))
+            .order_by_desc(WalletTransactionColumn::Slot)
+            .order_by_asc(WalletTransactionColumn::Signature)
+            .offset(offset)
+            .limit(limit)
+            .all(self.db.as_ref())
+            .await?;
+
+        Ok(result)
+    }
+
+    /// # Description
+    ///     统计钱包已缓存的交易数量
+    /// # Param
+    ///     wallet_id: i32: 钱包ID
+    /// # Return
+    ///     Result<u64, Report>: 交易数量
+    async fn count(&self, wallet_id: i32) -> Result<u64, Report> {
+        let result = WalletTransactionEntity::find()
+            .filter(WalletTransactionColumn::WalletId.eq(wallet_id))
+            .count(self.db.as_ref())
+            .await?;
+
+        Ok(result)
+    }
+
+    /// # Description
+    ///     查找钱包已缓存的最新交易
+    /// # Param
+    ///     wallet_id: i32: 钱包ID
+    /// # Return
+    ///     Result<Option<WalletTransactionModel>, Report>: 交易或错误信息
+    async fn find_newest(&self, wallet_id: i32) -> Result<Option<WalletTransactionModel>, Report> {
+        let result = WalletTransactionEntity::find()
+            .filter(WalletTransactionColumn::WalletId.eq(wallet_id))
+            .order_by_desc(WalletTransactionColumn::Slot)
+            .order_by_asc(WalletTransactionColumn::Signature)
+            .one(self.db.as_ref())
+            .await?;
+
+        Ok(result)
+    }
+
+    /// # Description
+    ///     查找钱包已缓存的最早交易
+    /// # Param
+    ///     wallet_id: i32: 钱包ID
+    /// # Return
+    ///     Result<Option<WalletTransactionModel>, Report>: 交易或错误信息
+    async fn find_oldest(&self, wallet_id: i32) -> Result<Option<WalletTransactionModel>, Report> {
+        let result = WalletTransactionEntity::find()
+            .filter(WalletTransactionColumn::WalletId.eq(wallet_id))
+            .order_by_asc(WalletTransactionColumn::Slot)
+            .order_by_desc(WalletTransactionColumn::Signature)
+            .one(self.db.as_ref())
+            .await?;
+
+        Ok(result)
+    }
+
+    /// # Description
+    ///     批量缓存钱包交易，已缓存的签名先删除再写入
+    /// # Param
+    ///     wallet_id: i32: 钱包ID
+    ///     transactions: Vec<WalletTransactionActiveModel>: 交易列表
+    /// # Return
+    ///     Result<(), Report>: 保存结果
+    async fn save_all(&self, wallet_id: i32, transactions: Vec<WalletTransactionActiveModel>) -> Result<(), Report> {
+        if transactions.is_empty() {
+            return Ok(());
+        }
+
+        let signatures: Vec<String> = transactions.iter()
+            .filter_map(|transaction| transaction.signature.clone().take())
+            .collect();
+
+        let txn = self.db.begin().await?;
+        WalletTransactionEntity::delete_many()
+            .filter(WalletTransactionColumn::WalletId.eq(wallet_id))
+            .filter(WalletTransactionColumn::Signature.is_in(signatures))
+            .exec(&txn)
+            .await?;
+        WalletTransactionEntity::insert_many(transactions).exec(&txn).await?;
+        txn.commit().await?;
+
+        Ok(())
+    }
+}
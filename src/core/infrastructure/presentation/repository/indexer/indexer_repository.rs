@@ -0,0 +1,228 @@
+use sea_orm::{DatabaseConnection, EntityTrait, QueryOrder, QuerySelect, TransactionTrait};
+use sea_orm::entity::prelude::*;
+use sea_orm::sea_query::{Expr, OnConflict};
+use axum::async_trait;
+use std::sync::Arc;
+use color_eyre::{Report, Result};
+use crate::core::domain::indexer::entity::indexed_block_entity::{ActiveModel as IndexedBlockActiveModel, Entity as IndexedBlockEntity, Column as IndexedBlockColumn, Model as IndexedBlockModel};
+use crate::core::domain::indexer::entity::indexed_transaction_entity::{ActiveModel as IndexedTransactionActiveModel, Column as IndexedTransactionColumn, Entity as IndexedTransactionEntity, Model as IndexedTransactionModel};
+use crate::core::domain::indexer::entity::indexer_checkpoint_entity::{Column as IndexerCheckpointColumn, Entity as IndexerCheckpointEntity, Model as IndexerCheckpointModel};
+use crate::core::domain::indexer::repository::IndexerRepositoryInterface;
+use crate::core::domain::reorg::entity::commitment_entity::CommitmentLevel;
+
+/// # Description
+///     区块索引仓储实现
+/// # Fields
+///     db: Arc<DatabaseConnection>: 数据库连接
+pub struct IndexerRepository {
+    db: Arc<DatabaseConnection>,
+}
+
+impl IndexerRepository {
+    /// # Description
+    ///     创建新的区块索引仓储实例
+    /// # Param
+    ///     db: DatabaseConnection: 数据库连接
+    /// # Return
+    ///     IndexerRepository: 区块索引仓储实例
+    pub async fn new(db: DatabaseConnection) -> Self {
+        Self { db: Arc::new(db) }
+    }
+}
+
+#[async_trait]
+impl IndexerRepositoryInterface for IndexerRepository {
+    /// # Description
+    ///     根据名称查找索引检查点
+    /// # Param
+    ///     name: &str: 检查点名称
+    /// # Return
+    ///     Result<Option<IndexerCheckpointModel>, Report>: 检查点或错误信息
+    async fn find_checkpoint(&self, name: &str) -> Result<Option<IndexerCheckpointModel>, Report> {
+        let result = IndexerCheckpointEntity::find_by_id(name.to_string())
+            .one(self.db.as_ref())
+            .await?;
+
+        Ok(result)
+    }
+
+    /// # Description
+    ///     写入索引检查点，已存在时覆盖
+    /// # Param
+    ///     name: &str: 检查点名称
+    ///     slot: u64: 已完成索引的最后一个 slot
+    /// # Return
+    ///     Result<(), Report>: 保存结果
+    async fn save_checkpoint(&self, name: &str, slot: u64) -> Result<(), Report> {
+        IndexerCheckpointEntity::insert(IndexerCheckpointModel::new(name, slot))
+            .on_conflict(
+                OnConflict::column(IndexerCheckpointColumn::Name)
+                    .update_columns([IndexerCheckpointColumn::Slot, IndexerCheckpointColumn::UpdatedAt])
+                    .to_owned()
+            )
+            .exec_without_returning(self.db.as_ref())
+            .await?;
+
+        Ok(())
+    }
+
+    /// # Description
+    ///     在同一事务中保存区块及其交易。先删除该 slot 已有的记录，重复索引同一 slot 不会产生重复数据
+    /// # Param
+    ///     block: IndexedBlockActiveModel: 区块记录
+    ///     transactions: Vec<IndexedTransactionActiveModel>: 区块内的非投票交易
+    /// # Return
+    ///     Result<(), Report>: 保存结果
+    async fn save_block(&self, block: IndexedBlockActiveModel, transactions: Vec<IndexedTransactionActiveModel>) -> Result<(), Report> {
+        let slot = block.slot.clone().take().ok_or_else(|| Report::msg("区块记录缺少 slot"))?;
+        let txn = self.db.begin().await?;
+
+        IndexedTransactionEntity::delete_many()
+            .filter(IndexedTransactionColumn::Slot.eq(slot))
+            .exec(&txn)
+            .await?;
+        IndexedBlockEntity::delete_by_id(slot).exec(&txn).await?;
+
+        block.insert(&txn).await?;
+        if !transactions.is_empty() {
+            IndexedTransactionEntity::insert_many(transactions).exec(&txn).await?;
+        }
+
+        txn.commit().await?;
+
+        Ok(())
+    }
+
+    /// # Description
+    ///     根据 slot 查找区块记录
+    /// # Param
+    ///     slot: u64: 区块 slot
+    /// # Return
+    ///     Result<Option<IndexedBlockModel>, Report>: 区块记录或错误信息
+    async fn find_block(&self, slot: u64) -> Result<Option<IndexedBlockModel>, Report> {
+        let result = IndexedBlockEntity::find_by_id(slot as i64)
+            .one(self.db.as_ref())
+            .await?;
+
+        Ok(result)
+    }
+
+    /// # Description
+    ///     查找 slot 最大的区块记录
+    /// # Return
+    ///     Result<Option<IndexedBlockModel>, Report>: 区块记录或错误信息
+    async fn find_latest_block(&self) -> Result<Option<IndexedBlockModel>, Report> {
+        let result = IndexedBlockEntity::find()
+            .order_by_desc(IndexedBlockColumn::Slot)
+            .one(self.db.as_ref())
+            .await?;
+
+        Ok(result)
+    }
+
+    /// # Description
+    ///     根据签名查找交易记录
+    /// # Param
+    ///     signature: &str: 交易签名
+    /// # Return
+    ///     Result<Option<IndexedTransactionModel>, Report>: 交易记录或错误信息
+    async fn find_transaction(&self, signature: &str) -> Result<Option<IndexedTransactionModel>, Report> {
+        let result = IndexedTransactionEntity::find()
+            .filter(IndexedTransactionColumn::Signature.eq(signature))
+            .one(self.db.as_ref())
+            .await?;
+
+        Ok(result)
+    }
+
+    /// # Description
+    ///     查询区块内的全部非投票交易，按区块内序号排列
+    /// # Param
+    ///     slot: u64: 区块 slot
+    /// # Return
+    ///     Result<Vec<IndexedTransactionModel>, Report>: 交易记录列表
+    async fn find_transactions_by_slot(&self, slot: u64) -> Result<Vec<IndexedTransactionModel>, Report> {
+        let result = IndexedTransactionEntity::find()
+            .filter(IndexedTransactionColumn::Slot.eq(slot as i64))
+            .order_by_asc(IndexedTransactionColumn::TxIndex)
+            .all(self.db.as_ref())
+            .await?;
+
+        Ok(result)
+    }
+
+    /// # Description
+    ///     查询尚未最终确认的区块，按 slot 升序
+    /// # Param
+    ///     max_slot: u64: 最新的 finalized slot，只返回不大于该 slot 的区块
+    ///     limit: u64: 最多返回的数量
+    /// # Return
+    ///     Result<Vec<IndexedBlockModel>, Report>: 区块记录列表
+    async fn find_unfinalized_blocks(&self, max_slot: u64, limit: u64) -> Result<Vec<IndexedBlockModel>, Report> {
+        let result = IndexedBlockEntity::find()
+            .filter(IndexedBlockColumn::Commitment.eq(CommitmentLevel::Confirmed))
+            .filter(IndexedBlockColumn::Slot.lte(max_slot as i64))
+            .order_by_asc(IndexedBlockColumn::Slot)
+            .limit(limit)
+            .all(self.db.as_ref())
+            .await?;
+
+        Ok(result)
+    }
+
+    /// # Description
+    ///     在同一事务中更新区块及其交易的确认级别
+    /// # Param
+    ///     slots: Vec<i64>: 区块 slot 列表
+    ///     commitment: CommitmentLevel: 确认级别
+    /// # Return
+    ///     Result<(), Report>: 更新结果
+    async fn set_commitment(&self, slots: Vec<i64>, commitment: CommitmentLevel) -> Result<(), Report> {
+        if slots.is_empty() {
+            return Ok(());
+        }
+        let txn = self.db.begin().await?;
+
+        IndexedBlockEntity::update_many()
+            .col_expr(IndexedBlockColumn::Commitment, Expr::value(commitment))
+            .filter(IndexedBlockColumn::Slot.is_in(slots.clone()))
+            .exec(&txn)
+            .await?;
+        IndexedTransactionEntity::update_many()
+            .col_expr(IndexedTransactionColumn::Commitment, Expr::value(commitment))
+            .filter(IndexedTransactionColumn::Slot.is_in(slots))
+            .exec(&txn)
+            .await?;
+
+        txn.commit().await?;
+
+        Ok(())
+    }
+
+    /// # Description
+    ///     标记所在分叉被丢弃的区块：区块记为跳过，区块与交易的确认级别记为 dropped。
+    ///     保留原有的区块哈希与交易，便于事后核对
+    /// # Param
+    ///     slot: u64: 区块 slot
+    /// # Return
+    ///     Result<(), Report>: 更新结果
+    async fn mark_dropped(&self, slot: u64) -> Result<(), Report> {
+        let txn = self.db.begin().await?;
+
+        IndexedBlockEntity::update_many()
+            .col_expr(IndexedBlockColumn::Skipped, Expr::value(true))
+            .col_expr(IndexedBlockColumn::Commitment, Expr::value(CommitmentLevel::Dropped))
+            .filter(IndexedBlockColumn::Slot.eq(slot as i64))
+            .exec(&txn)
+            .await?;
+        IndexedTransactionEntity::update_many()
+            .col_expr(IndexedTransactionColumn::Commitment, Expr::value(CommitmentLevel::Dropped))
+            .filter(IndexedTransactionColumn::Slot.eq(slot as i64))
+            .exec(&txn)
+            .await?;
+
+        txn.commit().await?;
+
+        Ok(())
+    }
+}
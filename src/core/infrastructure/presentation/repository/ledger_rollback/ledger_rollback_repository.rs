@@ -0,0 +1,100 @@
+use sea_orm::{ActiveModelTrait, DatabaseConnection, EntityTrait, QueryOrder, QuerySelect, TransactionTrait};
+use sea_orm::entity::prelude::*;
+use sea_orm::sea_query::Expr;
+use axum::async_trait;
+use std::sync::Arc;
+use color_eyre::{Report, Result};
+use crate::core::domain::reorg::entity::commitment_entity::CommitmentLevel;
+use crate::core::domain::reorg::entity::ledger_rollback_entity::{Column as LedgerRollbackColumn, Entity as LedgerRollbackEntity, Model as LedgerRollbackModel};
+use crate::core::domain::reorg::repository::LedgerRollbackRepositoryInterface;
+use crate::core::domain::reorg::service::ReorgDomainService;
+use crate::core::domain::wallet::entity::{Column as WalletColumn, Entity as WalletEntity};
+use crate::core::domain::watcher::entity::deposit_entity::{Column as DepositColumn, Entity as DepositEntity, Model as DepositModel};
+
+/// # Description
+///     入账回滚仓储实现
+/// # Fields
+///     db: Arc<DatabaseConnection>: 数据库连接
+pub struct LedgerRollbackRepository {
+    db: Arc<DatabaseConnection>,
+}
+
+impl LedgerRollbackRepository {
+    /// # Description
+    ///     创建新的入账回滚仓储实例
+    /// # Param
+    ///     db: DatabaseConnection: 数据库连接
+    /// # Return
+    ///     LedgerRollbackRepository: 入账回滚仓储实例
+    pub async fn new(db: DatabaseConnection) -> Self {
+        Self { db: Arc::new(db) }
+    }
+}
+
+#[async_trait]
+impl LedgerRollbackRepositoryInterface for LedgerRollbackRepository {
+    /// # Description
+    ///     在同一事务中回滚一笔充值：充值标记为 dropped、按锁定后的钱包余额扣回入账金额并写入审计记录，
+    ///     已转出无法扣回的部分记为差额。充值已不处于 confirmed 级别（已被回滚或已最终确认）时不做任何修改
+    /// # Param
+    ///     deposit: &DepositModel: 被回滚的充值
+    ///     reason: String: 回滚原因
+    /// # Return
+    ///     Result<Option<LedgerRollbackModel>, Report>: 审计记录，未回滚时为 None
+    async fn rollback(&self, deposit: &DepositModel, reason: String) -> Result<Option<LedgerRollbackModel>, Report> {
+        let txn = self.db.begin().await?;
+
+        let result = DepositEntity::update_many()
+            .col_expr(DepositColumn::Commitment, Expr::value(CommitmentLevel::Dropped))
+            .filter(DepositColumn::Id.eq(deposit.id))
+            .filter(DepositColumn::Commitment.eq(CommitmentLevel::Confirmed))
+            .exec(&txn)
+            .await?;
+        if result.rows_affected == 0 {
+            txn.rollback().await?;
+            return Ok(None);
+        }
+
+        let balance = WalletEntity::find_by_id(deposit.wallet_id)
+            .lock_exclusive()
+            .one(&txn)
+            .await?
+            .map_or(0.0, |wallet| wallet.balance);
+        let (debited, shortfall) = ReorgDomainService::new().rollback_amount(balance, deposit.amount);
+
+        if debited > 0.0 {
+            WalletEntity::update_many()
+                .col_expr(WalletColumn::Balance, Expr::col(WalletColumn::Balance).sub(debited))
+                .filter(WalletColumn::Id.eq(deposit.wallet_id))
+                .exec(&txn)
+                .await?;
+        }
+        let rollback = LedgerRollbackModel::new(deposit, debited, shortfall, reason).insert(&txn).await?;
+
+        txn.commit().await?;
+
+        Ok(Some(rollback))
+    }
+
+    /// # Description
+    ///     查询回滚审计记录，按时间倒序
+    /// # Param
+    ///     user_id: Option<i32>: 所属用户，None 查询全部
+    ///     limit: u64: 最多返回的数量
+    /// # Return
+    ///     Result<Vec<LedgerRollbackModel>, Report>: 审计记录列表
+    async fn list(&self, user_id: Option<i32>, limit: u64) -> Result<Vec<LedgerRollbackModel>, Report> {
+        let mut query = LedgerRollbackEntity::find();
+        if let Some(user_id) = user_id {
+            query = query.filter(LedgerRollbackColumn::UserId.eq(user_id));
+        }
+
+        let result = query
+            .order_by_desc(LedgerRollbackColumn::Id)
+            .limit(limit)
+            .all(self.db.as_ref())
+            .await?;
+
+        Ok(result)
+    }
+}
@@ -0,0 +1 @@
+pub mod ledger_rollback_repository;
\ No newline at end of file
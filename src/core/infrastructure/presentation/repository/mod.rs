@@ -1,5 +1,16 @@
 pub mod user;
 pub mod wallet;
+pub mod order;
+pub mod dca;
+pub mod indexer;
+pub mod network;
+pub mod history;
+pub mod webhook;
+pub mod deposit;
+pub mod ledger_rollback;
+pub mod verification;
+pub mod two_factor;
+pub mod withdrawal;
 pub(crate) mod repository;
 
 pub use repository::Repository;
\ No newline at end of file
@@ -0,0 +1,100 @@
+use sea_orm::{DatabaseConnection, EntityTrait, QueryOrder, TransactionTrait};
+use sea_orm::entity::prelude::*;
+use axum::async_trait;
+use std::sync::Arc;
+use color_eyre::{Report, Result};
+use crate::core::domain::network::entity::network_sample_entity::{ActiveModel as NetworkSampleActiveModel, Column as NetworkSampleColumn, Entity as NetworkSampleEntity, Model as NetworkSampleModel};
+use crate::core::domain::network::repository::NetworkRepositoryInterface;
+
+/// # Description
+///     网络吞吐采样仓储实现
+/// # Fields
+///     db: Arc<DatabaseConnection>: 数据库连接
+pub struct NetworkRepository {
+    db: Arc<DatabaseConnection>,
+}
+
+impl NetworkRepository {
+    /// # Description
+    ///     创建新的网络吞吐采样仓储实例
+    /// # Param
+    ///     db: DatabaseConnection: 数据库连接
+    /// # Return
+    ///     NetworkRepository: 网络吞吐采样仓储实例
+    pub async fn new(db: DatabaseConnection) -> Self {
+        Self { db: Arc::new(db) }
+    }
+}
+
+#[async_trait]
+impl NetworkRepositoryInterface for NetworkRepository {
+    /// # Description
+    ///     查找 slot 最大的采样
+    /// # Return
+    ///     Result<Option<NetworkSampleModel>, Report>: 采样或错误信息
+    async fn find_latest(&self) -> Result<Option<NetworkSampleModel>, Report> {
+        let result = NetworkSampleEntity::find()
+            .order_by_desc(NetworkSampleColumn::Slot)
+            .one(self.db.as_ref())
+            .await?;
+
+        Ok(result)
+    }
+
+    /// # Description
+    ///     查询指定出块时间之后的全部采样，按 slot 升序
+    /// # Param
+    ///     block_time: i64: 起始出块时间（包含）
+    /// # Return
+    ///     Result<Vec<NetworkSampleModel>, Report>: 采样列表
+    async fn find_since(&self, block_time: i64) -> Result<Vec<NetworkSampleModel>, Report> {
+        let result = NetworkSampleEntity::find()
+            .filter(NetworkSampleColumn::BlockTime.gte(block_time))
+            .order_by_asc(NetworkSampleColumn::Slot)
+            .all(self.db.as_ref())
+            .await?;
+
+        Ok(result)
+    }
+
+    /// # Description
+    ///     批量保存采样，已存在的 slot 先删除再写入
+    /// # Param
+    ///     samples: Vec<NetworkSampleActiveModel>: 采样列表
+    /// # Return
+    ///     Result<(), Report>: 保存结果
+    async fn save_samples(&self, samples: Vec<NetworkSampleActiveModel>) -> Result<(), Report> {
+        if samples.is_empty() {
+            return Ok(());
+        }
+
+        let slots: Vec<i64> = samples.iter()
+            .filter_map(|sample| sample.slot.clone().take())
+            .collect();
+
+        let txn = self.db.begin().await?;
+        NetworkSampleEntity::delete_many()
+            .filter(NetworkSampleColumn::Slot.is_in(slots))
+            .exec(&txn)
+            .await?;
+        NetworkSampleEntity::insert_many(samples).exec(&txn).await?;
+        txn.commit().await?;
+
+        Ok(())
+    }
+
+    /// # Description
+    ///     删除指定出块时间之前的采样
+    /// # Param
+    ///     block_time: i64: 截止出块时间（不包含）
+    /// # Return
+    ///     Result<u64, Report>: 删除的数量
+    async fn delete_before(&self, block_time: i64) -> Result<u64, Report> {
+        let result = NetworkSampleEntity::delete_many()
+            .filter(NetworkSampleColumn::BlockTime.lt(block_time))
+            .exec(self.db.as_ref())
+            .await?;
+
+        Ok(result.rows_affected)
+    }
+}
@@ -0,0 +1,156 @@
+use chrono::{DateTime, Utc};
+use sea_orm::{DatabaseConnection, DbErr, EntityTrait, IntoActiveModel, QueryOrder, QuerySelect};
+use sea_orm::entity::prelude::*;
+use axum::async_trait;
+use std::sync::Arc;
+use color_eyre::{Report, Result};
+use crate::core::domain::order::entity::order_entity::{ActiveModel as OrderActiveModel, Column as OrderColumn, Entity as OrderEntity, Model as OrderModel, OrderStatus};
+use crate::core::domain::order::entity::order_event_entity::{ActiveModel as OrderEventActiveModel, Column as OrderEventColumn, Entity as OrderEventEntity, Model as OrderEventModel};
+use crate::core::domain::order::repository::OrderRepositoryInterface;
+
+/// # Description
+///     条件单仓储实现
+/// # Fields
+///     db: Arc<DatabaseConnection>: 数据库连接
+pub struct OrderRepository {
+    db: Arc<DatabaseConnection>,
+}
+
+impl OrderRepository {
+    /// # Description
+    ///     创建新的条件单仓储实例
+    /// # Param
+    ///     db: DatabaseConnection: 数据库连接
+    /// # Return
+    ///     OrderRepository: 条件单仓储实例
+    pub async fn new(db: DatabaseConnection) -> Self {
+        Self { db: Arc::new(db) }
+    }
+}
+
+#[async_trait]
+impl OrderRepositoryInterface for OrderRepository {
+    /// # Description
+    ///     根据条件单ID查找条件单
+    /// # Param
+    ///     id: i32: 条件单ID
+    /// # Return
+    ///     Result<Option<OrderModel>, Report>: 条件单实体或错误信息
+    async fn find_by_id(&self, id: i32) -> Result<Option<OrderModel>, Report> {
+        let result = OrderEntity::find_by_id(id)
+            .one(self.db.as_ref())
+            .await?;
+
+        Ok(result)
+    }
+
+    /// # Description
+    ///     查询用户的全部条件单，按创建时间倒序
+    /// # Param
+    ///     user_id: i32: 用户ID
+    /// # Return
+    ///     Result<Vec<OrderModel>, Report>: 条件单列表或错误信息
+    async fn find_by_user(&self, user_id: i32) -> Result<Vec<OrderModel>, Report> {
+        let result = OrderEntity::find()
+            .filter(OrderColumn::UserId.eq(user_id))
+            .order_by_desc(OrderColumn::CreatedAt)
+            .all(self.db.as_ref())
+            .await?;
+
+        Ok(result)
+    }
+
+    /// # Description
+    ///     查询等待触发的条件单，按创建时间正序，先下单先评估
+    /// # Param
+    ///     limit: u64: 最大数量
+    /// # Return
+    ///     Result<Vec<OrderModel>, Report>: 条件单列表或错误信息
+    async fn find_open(&self, limit: u64) -> Result<Vec<OrderModel>, Report> {
+        let result = OrderEntity::find()
+            .filter(OrderColumn::Status.eq(OrderStatus::Open))
+            .order_by_asc(OrderColumn::CreatedAt)
+            .limit(limit)
+            .all(self.db.as_ref())
+            .await?;
+
+        Ok(result)
+    }
+
+    /// # Description
+    ///     查询在指定时间之前触发、仍停留在已触发状态的条件单
+    /// # Param
+    ///     cutoff: DateTime<Utc>: 触发时间上限
+    /// # Return
+    ///     Result<Vec<OrderModel>, Report>: 条件单列表或错误信息
+    async fn find_triggered_before(&self, cutoff: DateTime<Utc>) -> Result<Vec<OrderModel>, Report> {
+        let result = OrderEntity::find()
+            .filter(OrderColumn::Status.eq(OrderStatus::Triggered))
+            .filter(OrderColumn::TriggeredAt.lt(cutoff))
+            .order_by_asc(OrderColumn::TriggeredAt)
+            .all(self.db.as_ref())
+            .await?;
+
+        Ok(result)
+    }
+
+    /// # Description
+    ///     保存条件单实体，新建或更新
+    /// # Param
+    ///     order: OrderActiveModel: 条件单实体
+    /// # Return
+    ///     Result<OrderModel, Report>: 保存后的条件单实体
+    async fn save(&self, order: OrderActiveModel) -> Result<OrderModel, Report> {
+        let order_model = order.save(self.db.as_ref()).await?;
+
+        Ok(order_model.try_into_model()?)
+    }
+
+    /// # Description
+    ///     仅当数据库中的状态仍为预期状态时才更新条件单，避免评估任务与用户取消并发覆盖
+    /// # Param
+    ///     order: OrderModel: 已完成状态变更的条件单
+    ///     expected: OrderStatus: 变更前的状态
+    /// # Return
+    ///     Result<bool, Report>: 是否更新成功
+    async fn save_if_status(&self, order: OrderModel, expected: OrderStatus) -> Result<bool, Report> {
+        let result = OrderEntity::update(order.into_active_model().reset_all())
+            .filter(OrderColumn::Status.eq(expected))
+            .exec(self.db.as_ref())
+            .await;
+
+        match result {
+            Ok(_) => Ok(true),
+            Err(DbErr::RecordNotUpdated) => Ok(false),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// # Description
+    ///     记录条件单状态变更
+    /// # Param
+    ///     event: OrderEventActiveModel: 状态变更记录
+    /// # Return
+    ///     Result<(), Report>: 保存结果
+    async fn record_event(&self, event: OrderEventActiveModel) -> Result<(), Report> {
+        event.insert(self.db.as_ref()).await?;
+
+        Ok(())
+    }
+
+    /// # Description
+    ///     查询条件单的状态变更记录
+    /// # Param
+    ///     order_id: i32: 条件单ID
+    /// # Return
+    ///     Result<Vec<OrderEventModel>, Report>: 状态变更记录列表
+    async fn find_events(&self, order_id: i32) -> Result<Vec<OrderEventModel>, Report> {
+        let result = OrderEventEntity::find()
+            .filter(OrderEventColumn::OrderId.eq(order_id))
+            .order_by_asc(OrderEventColumn::Id)
+            .all(self.db.as_ref())
+            .await?;
+
+        Ok(result)
+    }
+}
@@ -1,26 +1,91 @@
 use std::sync::Arc;
 use sea_orm::DatabaseConnection;
 use crate::core::infrastructure::presentation::repository::user::user_repository::UserRepository;
+use crate::core::infrastructure::presentation::repository::user::role_repository::RoleRepository;
+use crate::core::infrastructure::presentation::repository::user::admin_audit_repository::AdminAuditRepository;
 use crate::core::infrastructure::presentation::repository::wallet::wallet_repository::WalletRepository;
+use crate::core::infrastructure::presentation::repository::order::order_repository::OrderRepository;
+use crate::core::infrastructure::presentation::repository::dca::dca_repository::DcaRepository;
+use crate::core::infrastructure::presentation::repository::indexer::indexer_repository::IndexerRepository;
+use crate::core::infrastructure::presentation::repository::network::network_repository::NetworkRepository;
+use crate::core::infrastructure::presentation::repository::history::history_repository::HistoryRepository;
+use crate::core::infrastructure::presentation::repository::webhook::webhook_repository::WebhookRepository;
+use crate::core::infrastructure::presentation::repository::deposit::deposit_repository::DepositRepository;
+use crate::core::infrastructure::presentation::repository::ledger_rollback::ledger_rollback_repository::LedgerRollbackRepository;
+use crate::core::infrastructure::presentation::repository::verification::verification_repository::VerificationRepository;
+use crate::core::infrastructure::presentation::repository::two_factor::two_factor_repository::TwoFactorRepository;
+use crate::core::infrastructure::presentation::repository::withdrawal::withdrawal_repository::WithdrawalRepository;
 
 /// # Description
 ///     【基础设施】持久性连接组件实例的向上层暴露的数据仓库
 /// # Param
 ///     user_repository Arc<UserRepository>: 用户仓库
+///     role_repository Arc<RoleRepository>: 角色与权限仓库
+///     admin_audit_repository Arc<AdminAuditRepository>: 管理员操作审计仓库
 ///     wallet_repository Arc<WalletRepository>: 钱包仓库
+///     order_repository Arc<OrderRepository>: 条件单仓库
+///     dca_repository Arc<DcaRepository>: 定投计划仓库
+///     indexer_repository Arc<IndexerRepository>: 区块索引仓库
+///     network_repository Arc<NetworkRepository>: 网络吞吐采样仓库
+///     history_repository Arc<HistoryRepository>: 钱包交易历史仓库
+///     webhook_repository Arc<WebhookRepository>: Webhook 仓库
+///     deposit_repository Arc<DepositRepository>: 充值记录仓库
+///     ledger_rollback_repository Arc<LedgerRollbackRepository>: 入账回滚仓库
+///     verification_repository Arc<VerificationRepository>: 验证码仓库
+///     two_factor_repository Arc<TwoFactorRepository>: 两步验证仓库
+///     withdrawal_repository Arc<WithdrawalRepository>: 提现白名单仓库
 pub struct Repository {
     pub user_repository: Arc<UserRepository>,
-    pub wallet_repository: Arc<WalletRepository>
+    pub role_repository: Arc<RoleRepository>,
+    pub admin_audit_repository: Arc<AdminAuditRepository>,
+    pub wallet_repository: Arc<WalletRepository>,
+    pub order_repository: Arc<OrderRepository>,
+    pub dca_repository: Arc<DcaRepository>,
+    pub indexer_repository: Arc<IndexerRepository>,
+    pub network_repository: Arc<NetworkRepository>,
+    pub history_repository: Arc<HistoryRepository>,
+    pub webhook_repository: Arc<WebhookRepository>,
+    pub deposit_repository: Arc<DepositRepository>,
+    pub ledger_rollback_repository: Arc<LedgerRollbackRepository>,
+    pub verification_repository: Arc<VerificationRepository>,
+    pub two_factor_repository: Arc<TwoFactorRepository>,
+    pub withdrawal_repository: Arc<WithdrawalRepository>,
 }
 
 impl Repository {
     pub async fn new(db: DatabaseConnection) -> Self {
         let user_repository = Arc::new(UserRepository::new(db.clone()).await);
+        let role_repository = Arc::new(RoleRepository::new(db.clone()).await);
+        let admin_audit_repository = Arc::new(AdminAuditRepository::new(db.clone()).await);
         let wallet_repository = Arc::new(WalletRepository::new(db.clone()).await);
+        let order_repository = Arc::new(OrderRepository::new(db.clone()).await);
+        let dca_repository = Arc::new(DcaRepository::new(db.clone()).await);
+        let indexer_repository = Arc::new(IndexerRepository::new(db.clone()).await);
+        let network_repository = Arc::new(NetworkRepository::new(db.clone()).await);
+        let history_repository = Arc::new(HistoryRepository::new(db.clone()).await);
+        let webhook_repository = Arc::new(WebhookRepository::new(db.clone()).await);
+        let deposit_repository = Arc::new(DepositRepository::new(db.clone()).await);
+        let ledger_rollback_repository = Arc::new(LedgerRollbackRepository::new(db.clone()).await);
+        let verification_repository = Arc::new(VerificationRepository::new(db.clone()).await);
+        let two_factor_repository = Arc::new(TwoFactorRepository::new(db.clone()).await);
+        let withdrawal_repository = Arc::new(WithdrawalRepository::new(db.clone()).await);
 
         Self {
             user_repository,
-            wallet_repository
+            role_repository,
+            admin_audit_repository,
+            wallet_repository,
+            order_repository,
+            dca_repository,
+            indexer_repository,
+            network_repository,
+            history_repository,
+            webhook_repository,
+            deposit_repository,
+            ledger_rollback_repository,
+            verification_repository,
+            two_factor_repository,
+            withdrawal_repository,
         }
     }
 }
\ No newline at end of file
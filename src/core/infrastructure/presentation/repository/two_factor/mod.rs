@@ -0,0 +1 @@
+pub mod two_factor_repository;
\ No newline at end of file
@@ -0,0 +1,233 @@
+use sea_orm::{ActiveModelTrait, DatabaseConnection, EntityTrait, PaginatorTrait, TransactionTrait};
+use sea_orm::entity::prelude::*;
+use sea_orm::sea_query::Expr;
+use axum::async_trait;
+use chrono::Utc;
+use std::sync::Arc;
+use color_eyre::{Report, Result};
+use crate::core::domain::two_factor::entity::two_factor_entity::{
+    ActiveModel as TwoFactorActiveModel, Column as TwoFactorColumn, Entity as TwoFactorEntity, Model as TwoFactorModel
+};
+use crate::core::domain::two_factor::entity::backup_code_entity::{
+    ActiveModel as BackupCodeActiveModel, Column as BackupCodeColumn, Entity as BackupCodeEntity, Model as BackupCodeModel
+};
+use crate::core::domain::two_factor::repository::TwoFactorRepositoryInterface;
+
+/// # Description
+///     两步验证仓储实现
+/// # Fields
+///     db: Arc<DatabaseConnection>: 数据库连接
+pub struct TwoFactorRepository {
+    db: Arc<DatabaseConnection>,
+}
+
+impl TwoFactorRepository {
+    /// # Description
+    ///     创建新的两步验证仓储实例
+    /// # Param
+    ///     db: DatabaseConnection: 数据库连接
+    /// # Return
+    ///     TwoFactorRepository: 两步验证仓储实例
+    pub async fn new(db: DatabaseConnection) -> Self {
+        Self { db: Arc::new(db) }
+    }
+}
+
+#[async_trait]
+impl TwoFactorRepositoryInterface for TwoFactorRepository {
+    /// # Description
+    ///     查找用户的两步验证设置
+    /// # Param
+    ///     user_id: i32: 用户id
+    /// # Return
+    ///     Result<Option<TwoFactorModel>, Report>: 两步验证设置
+    async fn find_by_user(&self, user_id: i32) -> Result<Option<TwoFactorModel>, Report> {
+        let result = TwoFactorEntity::find()
+            .filter(TwoFactorColumn::UserId.eq(user_id))
+            .one(self.db.as_ref())
+            .await?;
+
+        Ok(result)
+    }
+
+    /// # Description
+    ///     登记待确认的两步验证设置，替换该用户之前未确认的设置；已开启时不做修改
+    /// # Param
+    ///     two_factor: TwoFactorActiveModel: 待确认的设置
+    /// # Return
+    ///     Result<TwoFactorModel, Report>: 保存后的设置
+    async fn create_pending(&self, two_factor: TwoFactorActiveModel) -> Result<TwoFactorModel, Report> {
+        let user_id = two_factor.user_id.clone().take().ok_or_else(|| Report::msg("两步验证设置缺少用户"))?;
+        let txn = self.db.begin().await?;
+
+        TwoFactorEntity::delete_many()
+            .filter(TwoFactorColumn::UserId.eq(user_id))
+            .filter(TwoFactorColumn::Enabled.eq(false))
+            .exec(&txn)
+            .await?;
+        let two_factor_model = two_factor.insert(&txn).await?;
+
+        txn.commit().await?;
+
+        Ok(two_factor_model)
+    }
+
+    /// # Description
+    ///     确认开启两步验证并写入恢复码，只有仍处于待确认状态时才会成功
+    /// # Param
+    ///     id: i32: 两步验证设置id
+    ///     step: i64: 确认时通过校验的时间步
+    ///     backup_codes: Vec<BackupCodeActiveModel>: 恢复码
+    /// # Return
+    ///     Result<bool, Report>: 是否由本次请求开启
+    async fn enable(&self, id: i32, step: i64, backup_codes: Vec<BackupCodeActiveModel>) -> Result<bool, Report> {
+        let txn = self.db.begin().await?;
+        let now = Utc::now();
+
+        let result = TwoFactorEntity::update_many()
+            .col_expr(TwoFactorColumn::Enabled, Expr::value(true))
+            .col_expr(TwoFactorColumn::LastUsedStep, Expr::value(step))
+            .col_expr(TwoFactorColumn::EnabledAt, Expr::value(now))
+            .col_expr(TwoFactorColumn::UpdatedAt, Expr::value(now))
+            .filter(TwoFactorColumn::Id.eq(id))
+            .filter(TwoFactorColumn::Enabled.eq(false))
+            .exec(&txn)
+            .await?;
+        if result.rows_affected != 1 {
+            return Ok(false);
+        }
+
+        // 恢复码归属取自刚开启的设置，而不是调用方传入的恢复码
+        let two_factor = TwoFactorEntity::find_by_id(id)
+            .one(&txn)
+            .await?
+            .ok_or_else(|| Report::msg("两步验证设置不存在"))?;
+        BackupCodeEntity::delete_many()
+            .filter(BackupCodeColumn::UserId.eq(two_factor.user_id))
+            .exec(&txn)
+            .await?;
+        if !backup_codes.is_empty() {
+            BackupCodeEntity::insert_many(backup_codes).exec(&txn).await?;
+        }
+
+        txn.commit().await?;
+
+        Ok(true)
+    }
+
+    /// # Description
+    ///     记录通过校验的时间步，只有晚于上次记录时才会成功，防止并发请求重放同一验证码
+    /// # Param
+    ///     id: i32: 两步验证设置id
+    ///     step: i64: 时间步
+    /// # Return
+    ///     Result<bool, Report>: 是否由本次请求记录
+    async fn advance_step(&self, id: i32, step: i64) -> Result<bool, Report> {
+        let result = TwoFactorEntity::update_many()
+            .col_expr(TwoFactorColumn::LastUsedStep, Expr::value(step))
+            .col_expr(TwoFactorColumn::UpdatedAt, Expr::value(Utc::now()))
+            .filter(TwoFactorColumn::Id.eq(id))
+            .filter(TwoFactorColumn::LastUsedStep.lt(step))
+            .exec(self.db.as_ref())
+            .await?;
+
+        Ok(result.rows_affected == 1)
+    }
+
+    /// # Description
+    ///     重新生成恢复码，旧恢复码全部作废
+    /// # Param
+    ///     user_id: i32: 用户id
+    ///     backup_codes: Vec<BackupCodeActiveModel>: 新恢复码
+    /// # Return
+    ///     Result<(), Report>: 保存结果
+    async fn replace_backup_codes(&self, user_id: i32, backup_codes: Vec<BackupCodeActiveModel>) -> Result<(), Report> {
+        let txn = self.db.begin().await?;
+
+        BackupCodeEntity::delete_many()
+            .filter(BackupCodeColumn::UserId.eq(user_id))
+            .exec(&txn)
+            .await?;
+        if !backup_codes.is_empty() {
+            BackupCodeEntity::insert_many(backup_codes).exec(&txn).await?;
+        }
+
+        txn.commit().await?;
+
+        Ok(())
+    }
+
+    /// # Description
+    ///     根据摘要查找用户尚未使用的恢复码
+    /// # Param
+    ///     user_id: i32: 用户id
+    ///     code_hash: &str: 恢复码摘要
+    /// # Return
+    ///     Result<Option<BackupCodeModel>, Report>: 恢复码
+    async fn find_backup_code(&self, user_id: i32, code_hash: &str) -> Result<Option<BackupCodeModel>, Report> {
+        let result = BackupCodeEntity::find()
+            .filter(BackupCodeColumn::UserId.eq(user_id))
+            .filter(BackupCodeColumn::CodeHash.eq(code_hash))
+            .filter(BackupCodeColumn::UsedAt.is_null())
+            .one(self.db.as_ref())
+            .await?;
+
+        Ok(result)
+    }
+
+    /// # Description
+    ///     使用恢复码，只有尚未使用时才会成功
+    /// # Param
+    ///     id: i32: 恢复码id
+    /// # Return
+    ///     Result<bool, Report>: 是否由本次请求使用
+    async fn use_backup_code(&self, id: i32) -> Result<bool, Report> {
+        let result = BackupCodeEntity::update_many()
+            .col_expr(BackupCodeColumn::UsedAt, Expr::value(Utc::now()))
+            .filter(BackupCodeColumn::Id.eq(id))
+            .filter(BackupCodeColumn::UsedAt.is_null())
+            .exec(self.db.as_ref())
+            .await?;
+
+        Ok(result.rows_affected == 1)
+    }
+
+    /// # Description
+    ///     统计用户剩余可用的恢复码
+    /// # Param
+    ///     user_id: i32: 用户id
+    /// # Return
+    ///     Result<u64, Report>: 剩余数量
+    async fn count_backup_codes(&self, user_id: i32) -> Result<u64, Report> {
+        let count = BackupCodeEntity::find()
+            .filter(BackupCodeColumn::UserId.eq(user_id))
+            .filter(BackupCodeColumn::UsedAt.is_null())
+            .count(self.db.as_ref())
+            .await?;
+
+        Ok(count)
+    }
+
+    /// # Description
+    ///     关闭两步验证，删除设置与全部恢复码
+    /// # Param
+    ///     user_id: i32: 用户id
+    /// # Return
+    ///     Result<(), Report>: 删除结果
+    async fn remove(&self, user_id: i32) -> Result<(), Report> {
+        let txn = self.db.begin().await?;
+
+        TwoFactorEntity::delete_many()
+            .filter(TwoFactorColumn::UserId.eq(user_id))
+            .exec(&txn)
+            .await?;
+        BackupCodeEntity::delete_many()
+            .filter(BackupCodeColumn::UserId.eq(user_id))
+            .exec(&txn)
+            .await?;
+
+        txn.commit().await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,77 @@
+use sea_orm::{ActiveModelTrait, DatabaseConnection, EntityTrait, PaginatorTrait, QueryOrder, QuerySelect};
+use sea_orm::entity::prelude::*;
+use axum::async_trait;
+use std::sync::Arc;
+use color_eyre::{Report, Result};
+use crate::core::domain::user::entity::admin_audit_entity::{ActiveModel as AdminAuditActiveModel, Column as AdminAuditColumn, Entity as AdminAuditEntity, Model as AdminAuditModel};
+use crate::core::domain::user::repository_interface::AdminAuditRepositoryInterface;
+
+/// # Description
+///     管理员操作审计仓储实现
+/// # Fields
+///     db: Arc<DatabaseConnection>: 数据库连接
+pub struct AdminAuditRepository {
+    db: Arc<DatabaseConnection>,
+}
+
+impl AdminAuditRepository {
+    /// # Description
+    ///     创建新的管理员操作审计仓储实例
+    /// # Param
+    ///     db: DatabaseConnection: 数据库连接
+    /// # Return
+    ///     AdminAuditRepository: 管理员操作审计仓储实例
+    pub async fn new(db: DatabaseConnection) -> Self {
+        Self { db: Arc::new(db) }
+    }
+}
+
+#[async_trait]
+impl AdminAuditRepositoryInterface for AdminAuditRepository {
+    /// # Description
+    ///     写入一条审计记录
+    /// # Param
+    ///     entry: AdminAuditActiveModel: 审计记录
+    /// # Return
+    ///     Result<(), Report>: 写入结果
+    async fn save(&self, entry: AdminAuditActiveModel) -> Result<(), Report> {
+        entry.insert(self.db.as_ref()).await?;
+
+        Ok(())
+    }
+
+    /// # Description
+    ///     分页查询针对某个用户的审计记录，最新的在前
+    /// # Param
+    ///     target_user_id: i32: 被操作的用户id
+    ///     offset: u64: 跳过的数量
+    ///     limit: u64: 最大数量
+    /// # Return
+    ///     Result<Vec<AdminAuditModel>, Report>: 审计记录
+    async fn find_by_target(&self, target_user_id: i32, offset: u64, limit: u64) -> Result<Vec<AdminAuditModel>, Report> {
+        let result = AdminAuditEntity::find()
+            .filter(AdminAuditColumn::TargetUserId.eq(target_user_id))
+            .order_by_desc(AdminAuditColumn::Id)
+            .offset(offset)
+            .limit(limit)
+            .all(self.db.as_ref())
+            .await?;
+
+        Ok(result)
+    }
+
+    /// # Description
+    ///     统计针对某个用户的审计记录数量
+    /// # Param
+    ///     target_user_id: i32: 被操作的用户id
+    /// # Return
+    ///     Result<u64, Report>: 记录数量
+    async fn count_by_target(&self, target_user_id: i32) -> Result<u64, Report> {
+        let result = AdminAuditEntity::find()
+            .filter(AdminAuditColumn::TargetUserId.eq(target_user_id))
+            .count(self.db.as_ref())
+            .await?;
+
+        Ok(result)
+    }
+}
\ No newline at end of file
@@ -1,2 +1,3 @@
 pub mod role_repository;
-pub mod user_repository;
\ No newline at end of file
+pub mod user_repository;
+pub mod admin_audit_repository;
\ No newline at end of file
@@ -1,18 +1,330 @@
-use sea_orm::{DatabaseConnection};
+use sea_orm::{ActiveModelTrait, DatabaseConnection, EntityTrait, QueryOrder, TransactionTrait};
+use sea_orm::entity::prelude::*;
+use sea_orm::sea_query::Expr;
+use axum::async_trait;
+use chrono::Utc;
 use std::sync::Arc;
+use color_eyre::{Report, Result};
+use crate::core::domain::user::entity::role_entity::{ActiveModel as RoleActiveModel, Column as RoleColumn, Entity as RoleEntity, Model as RoleModel};
+use crate::core::domain::user::entity::permission_entity::{ActiveModel as PermissionActiveModel, Column as PermissionColumn, Entity as PermissionEntity, Model as PermissionModel};
+use crate::core::domain::user::entity::role_permission_entity::{Column as RolePermissionColumn, Entity as RolePermissionEntity, Model as RolePermissionModel};
+use crate::core::domain::user::entity::admin_audit_entity::ActiveModel as AdminAuditActiveModel;
+use crate::core::domain::user::entity::user_role_entity::{Column as UserRoleColumn, Entity as UserRoleEntity, Model as UserRoleModel};
+use crate::core::domain::user::repository_interface::RoleRepositoryInterface;
 
-#[allow(dead_code)]
+/// # Description
+///     角色与权限仓储实现
+/// # Fields
+///     db: Arc<DatabaseConnection>: 数据库连接
 pub struct RoleRepository {
     db: Arc<DatabaseConnection>,
 }
 
 impl RoleRepository {
+    /// # Description
+    ///     创建新的角色与权限仓储实例
+    /// # Param
+    ///     db: DatabaseConnection: 数据库连接
+    /// # Return
+    ///     RoleRepository: 角色与权限仓储实例
     pub async fn new(db: DatabaseConnection) -> Self {
         Self { db: Arc::new(db) }
     }
 }
 
-// #[async_trait]
-// impl RoleRepositoryInterface for RoleRepository {
-//
-// }
\ No newline at end of file
+#[async_trait]
+impl RoleRepositoryInterface for RoleRepository {
+    /// # Description
+    ///     查询全部未删除的角色
+    /// # Return
+    ///     Result<Vec<RoleModel>, Report>: 角色列表
+    async fn find_roles(&self) -> Result<Vec<RoleModel>, Report> {
+        let result = RoleEntity::find()
+            .filter(RoleColumn::DeletedAt.is_null())
+            .order_by_asc(RoleColumn::Id)
+            .all(self.db.as_ref())
+            .await?;
+
+        Ok(result)
+    }
+
+    /// # Description
+    ///     根据id查找未删除的角色
+    /// # Param
+    ///     id: i32: 角色id
+    /// # Return
+    ///     Result<Option<RoleModel>, Report>: 角色
+    async fn find_role(&self, id: i32) -> Result<Option<RoleModel>, Report> {
+        let result = RoleEntity::find_by_id(id)
+            .filter(RoleColumn::DeletedAt.is_null())
+            .one(self.db.as_ref())
+            .await?;
+
+        Ok(result)
+    }
+
+    /// # Description
+    ///     根据角色名查找未删除的角色
+    /// # Param
+    ///     name: &str: 角色名
+    /// # Return
+    ///     Result<Option<RoleModel>, Report>: 角色
+    async fn find_role_by_name(&self, name: &str) -> Result<Option<RoleModel>, Report> {
+        let result = RoleEntity::find()
+            .filter(RoleColumn::Name.eq(name))
+            .filter(RoleColumn::DeletedAt.is_null())
+            .one(self.db.as_ref())
+            .await?;
+
+        Ok(result)
+    }
+
+    /// # Description
+    ///     根据角色名批量查找未删除的角色
+    /// # Param
+    ///     names: &[String]: 角色名
+    /// # Return
+    ///     Result<Vec<RoleModel>, Report>: 角色列表
+    async fn find_roles_by_names(&self, names: &[String]) -> Result<Vec<RoleModel>, Report> {
+        if names.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let result = RoleEntity::find()
+            .filter(RoleColumn::Name.is_in(names.to_vec()))
+            .filter(RoleColumn::DeletedAt.is_null())
+            .all(self.db.as_ref())
+            .await?;
+
+        Ok(result)
+    }
+
+    /// # Description
+    ///     保存角色，新建或更新
+    /// # Param
+    ///     role: RoleActiveModel: 角色
+    /// # Return
+    ///     Result<RoleModel, Report>: 保存后的角色
+    async fn save_role(&self, role: RoleActiveModel) -> Result<RoleModel, Report> {
+        let role_model = role.save(self.db.as_ref()).await?;
+
+        Ok(role_model.try_into_model()?)
+    }
+
+    /// # Description
+    ///     软删除角色，并在同一事务中移除其权限与用户分配
+    /// # Param
+    ///     id: i32: 角色id
+    /// # Return
+    ///     Result<(), Report>: 删除结果
+    async fn delete_role(&self, id: i32) -> Result<(), Report> {
+        let txn = self.db.begin().await?;
+        let now = Utc::now();
+
+        RoleEntity::update_many()
+            .col_expr(RoleColumn::DeletedAt, Expr::value(now))
+            .col_expr(RoleColumn::UpdatedAt, Expr::value(now))
+            .filter(RoleColumn::Id.eq(id))
+            .exec(&txn)
+            .await?;
+        RolePermissionEntity::delete_many()
+            .filter(RolePermissionColumn::RoleId.eq(id))
+            .exec(&txn)
+            .await?;
+        UserRoleEntity::delete_many()
+            .filter(UserRoleColumn::RoleId.eq(id))
+            .exec(&txn)
+            .await?;
+
+        txn.commit().await?;
+
+        Ok(())
+    }
+
+    /// # Description
+    ///     查询全部权限
+    /// # Return
+    ///     Result<Vec<PermissionModel>, Report>: 权限列表
+    async fn find_permissions(&self) -> Result<Vec<PermissionModel>, Report> {
+        let result = PermissionEntity::find()
+            .order_by_asc(PermissionColumn::Code)
+            .all(self.db.as_ref())
+            .await?;
+
+        Ok(result)
+    }
+
+    /// # Description
+    ///     根据权限编码批量查找权限
+    /// # Param
+    ///     codes: &[String]: 权限编码
+    /// # Return
+    ///     Result<Vec<PermissionModel>, Report>: 权限列表
+    async fn find_permissions_by_codes(&self, codes: &[String]) -> Result<Vec<PermissionModel>, Report> {
+        if codes.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let result = PermissionEntity::find()
+            .filter(PermissionColumn::Code.is_in(codes.to_vec()))
+            .all(self.db.as_ref())
+            .await?;
+
+        Ok(result)
+    }
+
+    /// # Description
+    ///     保存权限
+    /// # Param
+    ///     permission: PermissionActiveModel: 权限
+    /// # Return
+    ///     Result<PermissionModel, Report>: 保存后的权限
+    async fn save_permission(&self, permission: PermissionActiveModel) -> Result<PermissionModel, Report> {
+        let permission_model = permission.save(self.db.as_ref()).await?;
+
+        Ok(permission_model.try_into_model()?)
+    }
+
+    /// # Description
+    ///     查询角色拥有的权限
+    /// # Param
+    ///     role_id: i32: 角色id
+    /// # Return
+    ///     Result<Vec<PermissionModel>, Report>: 权限列表
+    async fn find_role_permissions(&self, role_id: i32) -> Result<Vec<PermissionModel>, Report> {
+        let permission_ids: Vec<i32> = RolePermissionEntity::find()
+            .filter(RolePermissionColumn::RoleId.eq(role_id))
+            .all(self.db.as_ref())
+            .await?
+            .into_iter()
+            .map(|grant| grant.permission_id)
+            .collect();
+        if permission_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let result = PermissionEntity::find()
+            .filter(PermissionColumn::Id.is_in(permission_ids))
+            .order_by_asc(PermissionColumn::Code)
+            .all(self.db.as_ref())
+            .await?;
+
+        Ok(result)
+    }
+
+    /// # Description
+    ///     在同一事务中替换角色的全部权限
+    /// # Param
+    ///     role_id: i32: 角色id
+    ///     permission_ids: Vec<i32>: 权限id
+    /// # Return
+    ///     Result<(), Report>: 保存结果
+    async fn set_role_permissions(&self, role_id: i32, permission_ids: Vec<i32>) -> Result<(), Report> {
+        let txn = self.db.begin().await?;
+
+        RolePermissionEntity::delete_many()
+            .filter(RolePermissionColumn::RoleId.eq(role_id))
+            .exec(&txn)
+            .await?;
+        for permission_id in permission_ids {
+            RolePermissionModel::new(role_id, permission_id).insert(&txn).await?;
+        }
+
+        txn.commit().await?;
+
+        Ok(())
+    }
+
+    /// # Description
+    ///     查询用户拥有的未删除角色
+    /// # Param
+    ///     user_id: i32: 用户id
+    /// # Return
+    ///     Result<Vec<RoleModel>, Report>: 角色列表
+    async fn find_user_roles(&self, user_id: i32) -> Result<Vec<RoleModel>, Report> {
+        let role_ids: Vec<i32> = UserRoleEntity::find()
+            .filter(UserRoleColumn::UserId.eq(user_id))
+            .all(self.db.as_ref())
+            .await?
+            .into_iter()
+            .map(|assignment| assignment.role_id)
+            .collect();
+        if role_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let result = RoleEntity::find()
+            .filter(RoleColumn::Id.is_in(role_ids))
+            .filter(RoleColumn::DeletedAt.is_null())
+            .order_by_asc(RoleColumn::Id)
+            .all(self.db.as_ref())
+            .await?;
+
+        Ok(result)
+    }
+
+    /// # Description
+    ///     在同一事务中替换用户的全部角色，管理后台操作时一并写入审计记录
+    /// # Param
+    ///     user_id: i32: 用户id
+    ///     role_ids: Vec<i32>: 角色id
+    ///     audit: Option<AdminAuditActiveModel>: 审计记录
+    /// # Return
+    ///     Result<(), Report>: 保存结果
+    async fn set_user_roles(&self, user_id: i32, role_ids: Vec<i32>, audit: Option<AdminAuditActiveModel>) -> Result<(), Report> {
+        let txn = self.db.begin().await?;
+
+        UserRoleEntity::delete_many()
+            .filter(UserRoleColumn::UserId.eq(user_id))
+            .exec(&txn)
+            .await?;
+        for role_id in role_ids {
+            UserRoleModel::new(user_id, role_id).insert(&txn).await?;
+        }
+        if let Some(audit) = audit {
+            audit.insert(&txn).await?;
+        }
+
+        txn.commit().await?;
+
+        Ok(())
+    }
+
+    /// # Description
+    ///     查询用户经由全部未删除角色获得的权限编码
+    /// # Param
+    ///     user_id: i32: 用户id
+    /// # Return
+    ///     Result<Vec<String>, Report>: 权限编码，去重
+    async fn find_user_permissions(&self, user_id: i32) -> Result<Vec<String>, Report> {
+        let role_ids: Vec<i32> = self.find_user_roles(user_id).await?
+            .into_iter()
+            .map(|role| role.id)
+            .collect();
+        if role_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let permission_ids: Vec<i32> = RolePermissionEntity::find()
+            .filter(RolePermissionColumn::RoleId.is_in(role_ids))
+            .all(self.db.as_ref())
+            .await?
+            .into_iter()
+            .map(|grant| grant.permission_id)
+            .collect();
+        if permission_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let result = PermissionEntity::find()
+            .filter(PermissionColumn::Id.is_in(permission_ids))
+            .order_by_asc(PermissionColumn::Code)
+            .all(self.db.as_ref())
+            .await?
+            .into_iter()
+            .map(|permission| permission.code)
+            .collect();
+
+        Ok(result)
+    }
+}
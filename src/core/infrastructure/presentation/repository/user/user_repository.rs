@@ -1,6 +1,7 @@
-use crate::core::domain::user::entity::user_entity::{Model as UserModel, Entity as UserEntity, ActiveModel as UserActiveModel};
-use crate::core::domain::user::repository_interface::UserRepositoryInterface;
-use sea_orm::{Condition, DatabaseConnection, EntityTrait};
+use crate::core::domain::user::entity::user_entity::{Model as UserModel, Entity as UserEntity, ActiveModel as UserActiveModel, Column as UserColumn};
+use crate::core::domain::user::entity::admin_audit_entity::ActiveModel as AdminAuditActiveModel;
+use crate::core::domain::user::repository_interface::{UserFilter, UserRepositoryInterface};
+use sea_orm::{Condition, DatabaseConnection, EntityTrait, PaginatorTrait, QueryOrder, QuerySelect, TransactionTrait};
 use sea_orm::entity::prelude::*;
 use sea_orm::ActiveModelTrait;
 use async_trait::async_trait;
@@ -15,6 +16,46 @@ impl UserRepository {
     pub async fn new(db: DatabaseConnection) -> Self {
         Self { db: Arc::new(db) }
     }
+
+    /// # Description
+    ///     把管理后台的过滤条件转换为查询条件
+    /// # Param
+    ///     filter &UserFilter: 过滤条件
+    /// # Return
+    ///     Condition: 查询条件
+    fn condition(filter: &UserFilter) -> Condition {
+        let mut condition = Condition::all();
+        if let Some(keyword) = &filter.keyword {
+            condition = condition.add(
+                Condition::any()
+                    .add(UserColumn::Account.contains(keyword))
+                    .add(UserColumn::Email.contains(keyword))
+                    .add(UserColumn::Mobile.contains(keyword))
+                    .add(UserColumn::Nickname.contains(keyword))
+            );
+        }
+        if let Some(disabled) = filter.disabled {
+            condition = condition.add(UserColumn::Disable.eq(disabled));
+        }
+        if let Some(level) = filter.level {
+            condition = condition.add(UserColumn::Level.eq(level));
+        }
+        if let Some(reg_type) = filter.reg_type {
+            condition = condition.add(UserColumn::RegType.eq(reg_type));
+        }
+        if let Some(created_from) = filter.created_from {
+            condition = condition.add(UserColumn::CreatedAt.gte(created_from));
+        }
+        if let Some(created_to) = filter.created_to {
+            condition = condition.add(UserColumn::CreatedAt.lte(created_to));
+        }
+        match filter.deleted {
+            Some(true) => condition = condition.add(UserColumn::DeletedAt.is_not_null()),
+            Some(false) => condition = condition.add(UserColumn::DeletedAt.is_null()),
+            None => {}
+        }
+        condition
+    }
 }
 
 #[async_trait]
@@ -35,6 +76,24 @@ impl UserRepositoryInterface for UserRepository {
         Ok(())
     }
 
+    /// # Description
+    ///     在同一事务中保存用户并写入管理员操作审计记录，任一失败都不生效
+    /// # Param
+    ///     user UserActiveModel: 需要保存的用户实体
+    ///     audit AdminAuditActiveModel: 审计记录
+    /// # Return
+    ///     Result<(), Report>: 保存结果
+    async fn save_audited(&self, user: UserActiveModel, audit: AdminAuditActiveModel) -> Result<(), Report> {
+        let txn = self.db.begin().await?;
+
+        user.save(&txn).await?;
+        audit.insert(&txn).await?;
+
+        txn.commit().await?;
+
+        Ok(())
+    }
+
     /// # Description
     ///     根据用户ID查找用户
     /// # Param
@@ -88,4 +147,39 @@ impl UserRepositoryInterface for UserRepository {
 
         Ok(result)
     }
+
+    /// # Description
+    ///     按过滤条件分页查询用户，最新注册的在前
+    /// # Param
+    ///     filter &UserFilter: 过滤条件
+    ///     offset u64: 跳过的数量
+    ///     limit u64: 最大数量
+    /// # Return
+    ///     Result<Vec<UserModel>, Report>: 用户列表
+    async fn search(&self, filter: &UserFilter, offset: u64, limit: u64) -> Result<Vec<UserModel>, Report> {
+        let result = UserEntity::find()
+            .filter(Self::condition(filter))
+            .order_by_desc(UserColumn::Id)
+            .offset(offset)
+            .limit(limit)
+            .all(self.db.as_ref())
+            .await?;
+
+        Ok(result)
+    }
+
+    /// # Description
+    ///     统计符合过滤条件的用户数量
+    /// # Param
+    ///     filter &UserFilter: 过滤条件
+    /// # Return
+    ///     Result<u64, Report>: 用户数量
+    async fn count(&self, filter: &UserFilter) -> Result<u64, Report> {
+        let result = UserEntity::find()
+            .filter(Self::condition(filter))
+            .count(self.db.as_ref())
+            .await?;
+
+        Ok(result)
+    }
 }
\ No newline at end of file
@@ -0,0 +1 @@
+pub mod verification_repository;
\ No newline at end of file
@@ -0,0 +1,135 @@
+use sea_orm::{ActiveModelTrait, DatabaseConnection, EntityTrait, QueryOrder, TransactionTrait};
+use sea_orm::entity::prelude::*;
+use sea_orm::sea_query::Expr;
+use axum::async_trait;
+use chrono::Utc;
+use std::sync::Arc;
+use color_eyre::{Report, Result};
+use crate::core::domain::verification::entity::verification_code_entity::{
+    ActiveModel as VerificationCodeActiveModel, Column as VerificationCodeColumn, Entity as VerificationCodeEntity,
+    Model as VerificationCodeModel, VerificationChannel, VerificationPurpose
+};
+use crate::core::domain::verification::repository::VerificationRepositoryInterface;
+
+/// # Description
+///     验证码仓储实现
+/// # Fields
+///     db: Arc<DatabaseConnection>: 数据库连接
+pub struct VerificationRepository {
+    db: Arc<DatabaseConnection>,
+}
+
+impl VerificationRepository {
+    /// # Description
+    ///     创建新的验证码仓储实例
+    /// # Param
+    ///     db: DatabaseConnection: 数据库连接
+    /// # Return
+    ///     VerificationRepository: 验证码仓储实例
+    pub async fn new(db: DatabaseConnection) -> Self {
+        Self { db: Arc::new(db) }
+    }
+}
+
+#[async_trait]
+impl VerificationRepositoryInterface for VerificationRepository {
+    /// # Description
+    ///     查找接收方最近一次发出的验证码，含已使用和已过期的记录，用于判断发送间隔
+    /// # Param
+    ///     channel: VerificationChannel: 发送渠道
+    ///     target: &str: 接收方
+    ///     purpose: VerificationPurpose: 用途
+    /// # Return
+    ///     Result<Option<VerificationCodeModel>, Report>: 最近一次的验证码
+    async fn find_latest(&self, channel: VerificationChannel, target: &str, purpose: VerificationPurpose) -> Result<Option<VerificationCodeModel>, Report> {
+        let result = VerificationCodeEntity::find()
+            .filter(VerificationCodeColumn::Channel.eq(channel))
+            .filter(VerificationCodeColumn::Target.eq(target))
+            .filter(VerificationCodeColumn::Purpose.eq(purpose))
+            .order_by_desc(VerificationCodeColumn::Id)
+            .one(self.db.as_ref())
+            .await?;
+
+        Ok(result)
+    }
+
+    /// # Description
+    ///     根据令牌摘要查找验证码
+    /// # Param
+    ///     channel: VerificationChannel: 发送渠道
+    ///     purpose: VerificationPurpose: 用途
+    ///     code_hash: &str: 令牌摘要
+    /// # Return
+    ///     Result<Option<VerificationCodeModel>, Report>: 验证码
+    async fn find_by_hash(&self, channel: VerificationChannel, purpose: VerificationPurpose, code_hash: &str) -> Result<Option<VerificationCodeModel>, Report> {
+        let result = VerificationCodeEntity::find()
+            .filter(VerificationCodeColumn::CodeHash.eq(code_hash))
+            .filter(VerificationCodeColumn::Channel.eq(channel))
+            .filter(VerificationCodeColumn::Purpose.eq(purpose))
+            .one(self.db.as_ref())
+            .await?;
+
+        Ok(result)
+    }
+
+    /// # Description
+    ///     发出新的验证码，并在同一事务中作废该接收方同一用途下尚未使用的旧验证码
+    /// # Param
+    ///     code: VerificationCodeActiveModel: 新验证码
+    /// # Return
+    ///     Result<VerificationCodeModel, Report>: 保存后的验证码
+    async fn issue(&self, code: VerificationCodeActiveModel) -> Result<VerificationCodeModel, Report> {
+        let channel = code.channel.clone().take().ok_or_else(|| Report::msg("验证码缺少发送渠道"))?;
+        let target = code.target.clone().take().ok_or_else(|| Report::msg("验证码缺少接收方"))?;
+        let purpose = code.purpose.clone().take().ok_or_else(|| Report::msg("验证码缺少用途"))?;
+
+        let txn = self.db.begin().await?;
+
+        VerificationCodeEntity::update_many()
+            .col_expr(VerificationCodeColumn::ConsumedAt, Expr::value(Utc::now()))
+            .filter(VerificationCodeColumn::Channel.eq(channel))
+            .filter(VerificationCodeColumn::Target.eq(target))
+            .filter(VerificationCodeColumn::Purpose.eq(purpose))
+            .filter(VerificationCodeColumn::ConsumedAt.is_null())
+            .exec(&txn)
+            .await?;
+        let code_model = code.insert(&txn).await?;
+
+        txn.commit().await?;
+
+        Ok(code_model)
+    }
+
+    /// # Description
+    ///     记录一次校验失败
+    /// # Param
+    ///     id: i32: 验证码id
+    /// # Return
+    ///     Result<(), Report>: 更新结果
+    async fn record_failure(&self, id: i32) -> Result<(), Report> {
+        VerificationCodeEntity::update_many()
+            .col_expr(VerificationCodeColumn::Attempts, Expr::col(VerificationCodeColumn::Attempts).add(1))
+            .filter(VerificationCodeColumn::Id.eq(id))
+            .exec(self.db.as_ref())
+            .await?;
+
+        Ok(())
+    }
+
+    /// # Description
+    ///     使用验证码，只有尚未使用时才会成功，防止并发请求重复使用同一验证码
+    /// # Param
+    ///     id: i32: 验证码id
+    /// # Return
+    ///     Result<bool, Report>: 是否由本次请求使用
+    async fn consume(&self, id: i32) -> Result<bool, Report> {
+        let result = VerificationCodeEntity::update_many()
+            .col_expr(VerificationCodeColumn::ConsumedAt, Expr::value(Utc::now()))
+            .filter(VerificationCodeColumn::Id.eq(id))
+            .filter(VerificationCodeColumn::ConsumedAt.is_null())
+            .exec(self.db.as_ref())
+            .await?;
+
+        Ok(result.rows_affected == 1)
+    }
+}
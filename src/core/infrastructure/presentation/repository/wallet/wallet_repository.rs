@@ -5,6 +5,7 @@ use std::sync::Arc;
 use color_eyre::{Report, Result};
 use crate::core::domain::wallet::entity::{ActiveModel as WalletActiveModel, Entity as WalletEntity, Model as WalletModel};
 use crate::core::domain::wallet::repository::WalletRepositoryInterface;
+use crate::core::infrastructure::keystore::SEALED_PREFIX;
 
 /// # Description
 ///     钱包仓储实现
@@ -45,6 +46,52 @@ impl WalletRepositoryInterface for WalletRepository {
         Ok(result?)
     }
 
+    /// # Description
+    ///     查询用户未删除的钱包
+    /// # Param
+    ///     user_id: i32: 用户ID
+    /// # Return
+    ///     Result<Vec<WalletModel>, Report>: 钱包列表或错误信息
+    async fn find_by_user(&self, user_id: i32) -> Result<Vec<WalletModel>, Report> {
+        let result = WalletEntity::find()
+            .filter(<WalletEntity as EntityTrait>::Column::UserId.eq(user_id))
+            .filter(<WalletEntity as EntityTrait>::Column::DeletedAt.is_null())
+            .all(self.db.as_ref())
+            .await?;
+
+        Ok(result)
+    }
+
+    /// # Description
+    ///     查询全部启用中、未删除且有公钥的钱包，即需要监听的托管地址
+    /// # Return
+    ///     Result<Vec<WalletModel>, Report>: 钱包列表或错误信息
+    async fn find_active(&self) -> Result<Vec<WalletModel>, Report> {
+        let result = WalletEntity::find()
+            .filter(<WalletEntity as EntityTrait>::Column::Disable.eq(false))
+            .filter(<WalletEntity as EntityTrait>::Column::DeletedAt.is_null())
+            .filter(<WalletEntity as EntityTrait>::Column::PubKey.is_not_null())
+            .all(self.db.as_ref())
+            .await?;
+
+        Ok(result)
+    }
+
+    /// # Description
+    ///     查询私钥仍以明文保存的钱包，包括已禁用和已删除的钱包
+    /// # Return
+    ///     Result<Vec<WalletModel>, Report>: 钱包列表或错误信息
+    async fn find_unsealed(&self) -> Result<Vec<WalletModel>, Report> {
+        let result = WalletEntity::find()
+            .filter(<WalletEntity as EntityTrait>::Column::PrivyKey.is_not_null())
+            .filter(<WalletEntity as EntityTrait>::Column::PrivyKey.ne(""))
+            .filter(<WalletEntity as EntityTrait>::Column::PrivyKey.not_like(format!("{}%", SEALED_PREFIX)))
+            .all(self.db.as_ref())
+            .await?;
+
+        Ok(result)
+    }
+
     /// # Description
     ///     保存钱包实体
     /// # Param
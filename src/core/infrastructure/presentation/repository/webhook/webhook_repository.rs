@@ -0,0 +1,255 @@
+use sea_orm::{Condition, DatabaseConnection, EntityTrait, QueryOrder, QuerySelect, TransactionTrait};
+use sea_orm::sea_query::OnConflict;
+use sea_orm::entity::prelude::*;
+use axum::async_trait;
+use chrono::{DateTime, Utc};
+use std::sync::Arc;
+use color_eyre::{Report, Result};
+use crate::core::domain::webhook::entity::webhook_endpoint_entity::{ActiveModel as WebhookEndpointActiveModel, Column as WebhookEndpointColumn, Entity as WebhookEndpointEntity, Model as WebhookEndpointModel};
+use crate::core::domain::webhook::entity::webhook_delivery_entity::{ActiveModel as WebhookDeliveryActiveModel, Column as WebhookDeliveryColumn, DeliveryStatus, Entity as WebhookDeliveryEntity, Model as WebhookDeliveryModel};
+use crate::core::domain::webhook::entity::webhook_balance_alert_entity::{ActiveModel as WebhookBalanceAlertActiveModel, Column as WebhookBalanceAlertColumn, Entity as WebhookBalanceAlertEntity, Model as WebhookBalanceAlertModel};
+use crate::core::domain::webhook::repository::WebhookRepositoryInterface;
+
+/// # Description
+///     Webhook 仓储实现
+/// # Fields
+///     db: Arc<DatabaseConnection>: 数据库连接
+pub struct WebhookRepository {
+    db: Arc<DatabaseConnection>,
+}
+
+impl WebhookRepository {
+    /// # Description
+    ///     创建新的 Webhook 仓储实例
+    /// # Param
+    ///     db: DatabaseConnection: 数据库连接
+    /// # Return
+    ///     WebhookRepository: Webhook 仓储实例
+    pub async fn new(db: DatabaseConnection) -> Self {
+        Self { db: Arc::new(db) }
+    }
+}
+
+#[async_trait]
+impl WebhookRepositoryInterface for WebhookRepository {
+    /// # Description
+    ///     根据id查找订阅端点
+    /// # Param
+    ///     id: i32: 端点id
+    /// # Return
+    ///     Result<Option<WebhookEndpointModel>, Report>: 订阅端点或错误信息
+    async fn find_endpoint(&self, id: i32) -> Result<Option<WebhookEndpointModel>, Report> {
+        let result = WebhookEndpointEntity::find_by_id(id)
+            .one(self.db.as_ref())
+            .await?;
+
+        Ok(result)
+    }
+
+    /// # Description
+    ///     查询用户的订阅端点，user_id 为 None 时查询管理员端点
+    /// # Param
+    ///     user_id: Option<i32>: 用户id
+    /// # Return
+    ///     Result<Vec<WebhookEndpointModel>, Report>: 订阅端点列表
+    async fn find_endpoints(&self, user_id: Option<i32>) -> Result<Vec<WebhookEndpointModel>, Report> {
+        let condition = match user_id {
+            Some(user_id) => WebhookEndpointColumn::UserId.eq(user_id),
+            None => WebhookEndpointColumn::UserId.is_null(),
+        };
+        let result = WebhookEndpointEntity::find()
+            .filter(condition)
+            .order_by_asc(WebhookEndpointColumn::Id)
+            .all(self.db.as_ref())
+            .await?;
+
+        Ok(result)
+    }
+
+    /// # Description
+    ///     查询可能接收该用户事件的已启用端点：用户自己的端点与管理员端点
+    /// # Param
+    ///     user_id: i32: 用户id
+    /// # Return
+    ///     Result<Vec<WebhookEndpointModel>, Report>: 订阅端点列表
+    async fn find_subscribers(&self, user_id: i32) -> Result<Vec<WebhookEndpointModel>, Report> {
+        let result = WebhookEndpointEntity::find()
+            .filter(WebhookEndpointColumn::Enabled.eq(true))
+            .filter(
+                Condition::any()
+                    .add(WebhookEndpointColumn::UserId.eq(user_id))
+                    .add(WebhookEndpointColumn::UserId.is_null())
+            )
+            .all(self.db.as_ref())
+            .await?;
+
+        Ok(result)
+    }
+
+    /// # Description
+    ///     查询设置了余额阈值的已启用用户端点
+    /// # Return
+    ///     Result<Vec<WebhookEndpointModel>, Report>: 订阅端点列表
+    async fn find_threshold_endpoints(&self) -> Result<Vec<WebhookEndpointModel>, Report> {
+        let result = WebhookEndpointEntity::find()
+            .filter(WebhookEndpointColumn::Enabled.eq(true))
+            .filter(WebhookEndpointColumn::UserId.is_not_null())
+            .filter(WebhookEndpointColumn::BalanceThreshold.is_not_null())
+            .all(self.db.as_ref())
+            .await?;
+
+        Ok(result)
+    }
+
+    /// # Description
+    ///     保存订阅端点，新建或更新
+    /// # Param
+    ///     endpoint: WebhookEndpointActiveModel: 订阅端点
+    /// # Return
+    ///     Result<WebhookEndpointModel, Report>: 保存后的订阅端点
+    async fn save_endpoint(&self, endpoint: WebhookEndpointActiveModel) -> Result<WebhookEndpointModel, Report> {
+        let endpoint_model = endpoint.save(self.db.as_ref()).await?;
+
+        Ok(endpoint_model.try_into_model()?)
+    }
+
+    /// # Description
+    ///     删除订阅端点，投递记录保留
+    /// # Param
+    ///     id: i32: 端点id
+    /// # Return
+    ///     Result<(), Report>: 删除结果
+    async fn delete_endpoint(&self, id: i32) -> Result<(), Report> {
+        let txn = self.db.begin().await?;
+
+        WebhookBalanceAlertEntity::delete_many()
+            .filter(WebhookBalanceAlertColumn::EndpointId.eq(id))
+            .exec(&txn)
+            .await?;
+        WebhookEndpointEntity::delete_by_id(id)
+            .exec(&txn)
+            .await?;
+
+        txn.commit().await?;
+
+        Ok(())
+    }
+
+    /// # Description
+    ///     保存投递记录，新建或更新
+    /// # Param
+    ///     delivery: WebhookDeliveryActiveModel: 投递记录
+    /// # Return
+    ///     Result<WebhookDeliveryModel, Report>: 保存后的投递记录
+    async fn save_delivery(&self, delivery: WebhookDeliveryActiveModel) -> Result<WebhookDeliveryModel, Report> {
+        let delivery_model = delivery.save(self.db.as_ref()).await?;
+
+        Ok(delivery_model.try_into_model()?)
+    }
+
+    /// # Description
+    ///     根据id查找投递记录
+    /// # Param
+    ///     id: i32: 投递记录id
+    /// # Return
+    ///     Result<Option<WebhookDeliveryModel>, Report>: 投递记录或错误信息
+    async fn find_delivery(&self, id: i32) -> Result<Option<WebhookDeliveryModel>, Report> {
+        let result = WebhookDeliveryEntity::find_by_id(id)
+            .one(self.db.as_ref())
+            .await?;
+
+        Ok(result)
+    }
+
+    /// # Description
+    ///     查询到期的待投递记录，按计划时间正序
+    /// # Param
+    ///     now: DateTime<Utc>: 当前时间
+    ///     limit: u64: 最大数量
+    /// # Return
+    ///     Result<Vec<WebhookDeliveryModel>, Report>: 投递记录列表
+    async fn find_due_deliveries(&self, now: DateTime<Utc>, limit: u64) -> Result<Vec<WebhookDeliveryModel>, Report> {
+        let result = WebhookDeliveryEntity::find()
+            .filter(WebhookDeliveryColumn::Status.eq(DeliveryStatus::Pending))
+            .filter(WebhookDeliveryColumn::NextAttemptAt.lte(now))
+            .order_by_asc(WebhookDeliveryColumn::NextAttemptAt)
+            .limit(limit)
+            .all(self.db.as_ref())
+            .await?;
+
+        Ok(result)
+    }
+
+    /// # Description
+    ///     查询端点最近的投递记录，按id倒序
+    /// # Param
+    ///     endpoint_id: i32: 端点id
+    ///     limit: u64: 最大数量
+    /// # Return
+    ///     Result<Vec<WebhookDeliveryModel>, Report>: 投递记录列表
+    async fn find_deliveries(&self, endpoint_id: i32, limit: u64) -> Result<Vec<WebhookDeliveryModel>, Report> {
+        let result = WebhookDeliveryEntity::find()
+            .filter(WebhookDeliveryColumn::EndpointId.eq(endpoint_id))
+            .order_by_desc(WebhookDeliveryColumn::Id)
+            .limit(limit)
+            .all(self.db.as_ref())
+            .await?;
+
+        Ok(result)
+    }
+
+    /// # Description
+    ///     查询端点已发送且尚未恢复的余额不足告警
+    /// # Param
+    ///     endpoint_ids: &[i32]: 端点id
+    /// # Return
+    ///     Result<Vec<WebhookBalanceAlertModel>, Report>: 告警记录列表
+    async fn find_balance_alerts(&self, endpoint_ids: &[i32]) -> Result<Vec<WebhookBalanceAlertModel>, Report> {
+        if endpoint_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let result = WebhookBalanceAlertEntity::find()
+            .filter(WebhookBalanceAlertColumn::EndpointId.is_in(endpoint_ids.iter().copied()))
+            .all(self.db.as_ref())
+            .await?;
+
+        Ok(result)
+    }
+
+    /// # Description
+    ///     写入余额不足告警，同一端点与钱包已有告警时不写入
+    /// # Param
+    ///     alert: WebhookBalanceAlertActiveModel: 告警记录
+    /// # Return
+    ///     Result<bool, Report>: 是否新写入，false 表示已告警过（包括其它实例同时写入）
+    async fn save_balance_alert(&self, alert: WebhookBalanceAlertActiveModel) -> Result<bool, Report> {
+        let inserted = WebhookBalanceAlertEntity::insert(alert)
+            .on_conflict(
+                OnConflict::columns([WebhookBalanceAlertColumn::EndpointId, WebhookBalanceAlertColumn::WalletId])
+                    .do_nothing()
+                    .to_owned()
+            )
+            .exec_without_returning(self.db.as_ref())
+            .await?;
+
+        Ok(inserted > 0)
+    }
+
+    /// # Description
+    ///     余额恢复后删除告警记录，再次低于阈值时重新告警
+    /// # Param
+    ///     endpoint_id: i32: 端点id
+    ///     wallet_id: i32: 钱包id
+    /// # Return
+    ///     Result<(), Report>: 删除结果
+    async fn delete_balance_alert(&self, endpoint_id: i32, wallet_id: i32) -> Result<(), Report> {
+        WebhookBalanceAlertEntity::delete_many()
+            .filter(WebhookBalanceAlertColumn::EndpointId.eq(endpoint_id))
+            .filter(WebhookBalanceAlertColumn::WalletId.eq(wallet_id))
+            .exec(self.db.as_ref())
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1 @@
+pub mod withdrawal_repository;
\ No newline at end of file
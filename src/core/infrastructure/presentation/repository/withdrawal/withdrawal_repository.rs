@@ -0,0 +1,93 @@
+use sea_orm::{ActiveModelTrait, DatabaseConnection, EntityTrait, QueryOrder};
+use sea_orm::entity::prelude::*;
+use axum::async_trait;
+use std::sync::Arc;
+use color_eyre::{Report, Result};
+use crate::core::domain::withdrawal::entity::whitelist_address_entity::{
+    ActiveModel as WhitelistAddressActiveModel, Column as WhitelistAddressColumn, Entity as WhitelistAddressEntity,
+    Model as WhitelistAddressModel
+};
+use crate::core::domain::withdrawal::repository::WithdrawalRepositoryInterface;
+
+/// # Description
+///     提现白名单仓储实现
+/// # Fields
+///     db: Arc<DatabaseConnection>: 数据库连接
+pub struct WithdrawalRepository {
+    db: Arc<DatabaseConnection>,
+}
+
+impl WithdrawalRepository {
+    /// # Description
+    ///     创建新的提现白名单仓储实例
+    /// # Param
+    ///     db: DatabaseConnection: 数据库连接
+    /// # Return
+    ///     WithdrawalRepository: 提现白名单仓储实例
+    pub async fn new(db: DatabaseConnection) -> Self {
+        Self { db: Arc::new(db) }
+    }
+}
+
+#[async_trait]
+impl WithdrawalRepositoryInterface for WithdrawalRepository {
+    /// # Description
+    ///     查询用户的全部白名单地址
+    /// # Param
+    ///     user_id: i32: 用户id
+    /// # Return
+    ///     Result<Vec<WhitelistAddressModel>, Report>: 白名单地址
+    async fn find_whitelist(&self, user_id: i32) -> Result<Vec<WhitelistAddressModel>, Report> {
+        let result = WhitelistAddressEntity::find()
+            .filter(WhitelistAddressColumn::UserId.eq(user_id))
+            .order_by_asc(WhitelistAddressColumn::Id)
+            .all(self.db.as_ref())
+            .await?;
+
+        Ok(result)
+    }
+
+    /// # Description
+    ///     查找用户白名单中的地址
+    /// # Param
+    ///     user_id: i32: 用户id
+    ///     address: &str: 接收地址
+    /// # Return
+    ///     Result<Option<WhitelistAddressModel>, Report>: 白名单地址
+    async fn find_address(&self, user_id: i32, address: &str) -> Result<Option<WhitelistAddressModel>, Report> {
+        let result = WhitelistAddressEntity::find()
+            .filter(WhitelistAddressColumn::UserId.eq(user_id))
+            .filter(WhitelistAddressColumn::Address.eq(address))
+            .one(self.db.as_ref())
+            .await?;
+
+        Ok(result)
+    }
+
+    /// # Description
+    ///     添加白名单地址
+    /// # Param
+    ///     address: WhitelistAddressActiveModel: 白名单地址
+    /// # Return
+    ///     Result<WhitelistAddressModel, Report>: 保存后的白名单地址
+    async fn add_address(&self, address: WhitelistAddressActiveModel) -> Result<WhitelistAddressModel, Report> {
+        Ok(address.insert(self.db.as_ref()).await?)
+    }
+
+    /// # Description
+    ///     删除用户的白名单地址
+    /// # Param
+    ///     user_id: i32: 用户id
+    ///     id: i32: 白名单地址id
+    /// # Return
+    ///     Result<bool, Report>: 是否删除了记录
+    async fn remove_address(&self, user_id: i32, id: i32) -> Result<bool, Report> {
+        let result = WhitelistAddressEntity::delete_many()
+            .filter(WhitelistAddressColumn::Id.eq(id))
+            .filter(WhitelistAddressColumn::UserId.eq(user_id))
+            .exec(self.db.as_ref())
+            .await?;
+
+        Ok(result.rows_affected == 1)
+    }
+}
@@ -0,0 +1,3 @@
+mod redis;
+
+pub use self::redis::Redis;
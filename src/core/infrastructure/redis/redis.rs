@@ -0,0 +1,50 @@
+use std::sync::Arc;
+use color_eyre::Report;
+use redis::{aio::MultiplexedConnection, Client};
+use crate::core::infrastructure::config::Config;
+
+/// # Description
+///     【基础设施】Redis 连接组件实例
+/// # Fields
+///     client Client: Redis 客户端，创建时不会立即建立连接
+pub struct Redis {
+    pub client: Client,
+}
+
+impl Redis {
+    /// # Description
+    ///     新建 Redis 客户端
+    /// # Param
+    ///     config Arc<Config>: 配置
+    /// # Return
+    ///     Result<Redis, Report>
+    ///         - Redis: Redis 实例
+    ///         - Report: 错误报告
+    pub fn new(config: Arc<Config>) -> Result<Self, Report> {
+        // 读取数据
+        let redis_config = &config.redis;
+
+        // 构建连接字符串，密码为空时不携带认证信息
+        let url = match redis_config.password.as_deref() {
+            Some(password) if !password.is_empty() => format!(
+                "redis://:{}@{}:{}/{}",
+                password, redis_config.host, redis_config.port, redis_config.db
+            ),
+            _ => format!("redis://{}:{}/{}", redis_config.host, redis_config.port, redis_config.db),
+        };
+
+        let client = Client::open(url)?;
+
+        Ok(Self { client })
+    }
+
+    /// # Description
+    ///     获取一个多路复用的异步连接
+    /// # Return
+    ///     Result<MultiplexedConnection, Report>
+    ///         - MultiplexedConnection: Redis 连接
+    ///         - Report: 错误报告
+    pub async fn connection(&self) -> Result<MultiplexedConnection, Report> {
+        Ok(self.client.get_multiplexed_async_connection().await?)
+    }
+}
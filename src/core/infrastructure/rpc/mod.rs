@@ -0,0 +1,3 @@
+mod rpc;
+
+pub use rpc::Rpc;
@@ -0,0 +1,38 @@
+use std::sync::Arc;
+use color_eyre::Report;
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::commitment_config::CommitmentConfig;
+use crate::core::infrastructure::config::Config;
+
+/// # Description
+///     【基础设施】Solana RPC 连接组件实例
+/// # Fields
+///     client Arc<RpcClient>: 非阻塞 RPC 客户端
+///     ws_url String: PubSub 节点地址
+pub struct Rpc {
+    pub client: Arc<RpcClient>,
+    pub ws_url: String,
+}
+
+impl Rpc {
+    /// # Description
+    ///     新建 Solana RPC 连接
+    /// # Param
+    ///     config Arc<Config>: 配置
+    /// # Return
+    ///     Result<Rpc, Report>
+    ///         - Rpc: RPC 连接实例
+    ///         - Report: 错误报告
+    pub fn new(config: Arc<Config>) -> Result<Self, Report> {
+        // 读取数据
+        let solana_config = &config.solana;
+
+        // 解析默认确认级别
+        let commitment = solana_config.commitment.parse::<CommitmentConfig>()
+            .map_err(|e| Report::msg(format!("+InfrastructureLayer [Rpc] Invalid commitment: {}", e)))?;
+
+        let client = Arc::new(RpcClient::new_with_commitment(solana_config.rpc_url.clone(), commitment));
+
+        Ok(Self { client, ws_url: solana_config.ws_url.clone() })
+    }
+}
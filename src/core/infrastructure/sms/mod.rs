@@ -0,0 +1,5 @@
+mod sender;
+mod provider;
+
+pub use sender::SmsSender;
+pub use provider::{LogSmsProvider, MemorySmsOutbox};
\ No newline at end of file
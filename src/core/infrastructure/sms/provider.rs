@@ -0,0 +1,53 @@
+use axum::async_trait;
+use color_eyre::{Report, Result};
+use tokio::sync::RwLock;
+use tracing::info;
+use crate::core::domain::sms::{entity::sms_entity::SmsMessage, repository::SmsProviderInterface};
+
+/// # Description
+///     日志短信服务商，只把短信内容写入日志，本地调试时代替真实服务商
+pub struct LogSmsProvider;
+
+impl LogSmsProvider {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+#[async_trait]
+impl SmsProviderInterface for LogSmsProvider {
+    async fn send(&self, message: &SmsMessage) -> Result<(), Report> {
+        info!("[LogSmsProvider] to={} text={}", message.to, message.text);
+
+        Ok(())
+    }
+}
+
+/// # Description
+///     内存短信发件箱，短信只保存在进程内存中，用于测试
+/// # Fields
+///     messages RwLock<Vec<SmsMessage>>: 已发送的短信
+pub struct MemorySmsOutbox {
+    messages: RwLock<Vec<SmsMessage>>,
+}
+
+impl MemorySmsOutbox {
+    pub fn new() -> Self {
+        Self { messages: RwLock::new(Vec::new()) }
+    }
+
+    /// # Description
+    ///     已发送的短信
+    pub async fn messages(&self) -> Vec<SmsMessage> {
+        self.messages.read().await.clone()
+    }
+}
+
+#[async_trait]
+impl SmsProviderInterface for MemorySmsOutbox {
+    async fn send(&self, message: &SmsMessage) -> Result<(), Report> {
+        self.messages.write().await.push(message.clone());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,41 @@
+use std::sync::Arc;
+use color_eyre::{Report, Result};
+use crate::core::domain::sms::{entity::sms_entity::SmsMessage, repository::SmsProviderInterface};
+use crate::core::infrastructure::config::Config;
+use super::provider::{LogSmsProvider, MemorySmsOutbox};
+
+/// # Description
+///     短信发送组件，按 sms.provider 选择服务商
+/// # Fields
+///     provider Arc<dyn SmsProviderInterface>: 服务商实现
+pub struct SmsSender {
+    provider: Arc<dyn SmsProviderInterface>,
+}
+
+impl SmsSender {
+    /// # Description
+    ///     根据配置创建短信发送组件
+    /// # Param
+    ///     config Arc<Config>: 配置
+    /// # Return
+    ///     Result<SmsSender, Report>: 短信发送组件
+    pub fn new(config: Arc<Config>) -> Result<Self, Report> {
+        let provider: Arc<dyn SmsProviderInterface> = match config.sms.provider.as_str() {
+            "log" => Arc::new(LogSmsProvider::new()),
+            "memory" => Arc::new(MemorySmsOutbox::new()),
+            other => return Err(Report::msg(format!("不支持的短信服务商 {}", other))),
+        };
+
+        Ok(Self { provider })
+    }
+
+    /// # Description
+    ///     发送短信
+    /// # Param
+    ///     message &SmsMessage: 短信
+    /// # Return
+    ///     Result<(), Report>: 发送结果
+    pub async fn send(&self, message: &SmsMessage) -> Result<(), Report> {
+        self.provider.send(message).await
+    }
+}
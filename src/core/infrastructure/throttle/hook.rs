@@ -0,0 +1,26 @@
+use axum::async_trait;
+use color_eyre::{Report, Result};
+use tracing::warn;
+use crate::core::domain::throttle::{entity::throttle_entity::LockoutEvent, repository::LockoutHookInterface};
+
+/// # Description
+///     日志锁定通知钩子，默认注册，把锁定事件写入告警日志
+pub struct LogLockoutHook;
+
+impl LogLockoutHook {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+#[async_trait]
+impl LockoutHookInterface for LogLockoutHook {
+    async fn on_lockout(&self, event: &LockoutEvent) -> Result<(), Report> {
+        warn!(
+            "-InfrastructureLayer [LoginThrottle] {} {} locked for {}s after {} failures",
+            event.scope.as_str(), event.subject, event.lockout_duration, event.failures
+        );
+
+        Ok(())
+    }
+}
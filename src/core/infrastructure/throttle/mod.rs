@@ -0,0 +1,7 @@
+mod store;
+mod hook;
+mod throttle;
+
+pub use store::{FallbackAttemptStore, MemoryAttemptStore, RedisAttemptStore};
+pub use hook::LogLockoutHook;
+pub use throttle::LoginThrottle;
\ No newline at end of file
@@ -0,0 +1,215 @@
+use std::{collections::HashMap, sync::Arc, time::{Duration, Instant}};
+use axum::async_trait;
+use color_eyre::{Report, Result};
+use redis::{AsyncCommands, Script};
+use tokio::sync::RwLock;
+use tracing::warn;
+use crate::core::domain::throttle::repository::AttemptStoreInterface;
+use crate::core::infrastructure::redis::Redis;
+
+/// # Description
+///     失败计数自增，首次写入时设置统计窗口，保证计数与过期时间原子生效
+const INCR_SCRIPT: &str = r#"
+local count = redis.call('INCR', KEYS[1])
+if count == 1 then
+    redis.call('EXPIRE', KEYS[1], ARGV[1])
+end
+return count
+"#;
+
+/// # Description
+///     进程内存失败计数存储，单实例部署或 Redis 不可用时使用
+/// # Fields
+///     failures RwLock<HashMap<String, (u32, Instant)>>: 键 -> (失败次数, 过期时间)
+///     locks RwLock<HashMap<String, Instant>>: 键 -> 解锁时间
+pub struct MemoryAttemptStore {
+    failures: RwLock<HashMap<String, (u32, Instant)>>,
+    locks: RwLock<HashMap<String, Instant>>,
+}
+
+impl MemoryAttemptStore {
+    pub fn new() -> Self {
+        Self { failures: RwLock::new(HashMap::new()), locks: RwLock::new(HashMap::new()) }
+    }
+}
+
+#[async_trait]
+impl AttemptStoreInterface for MemoryAttemptStore {
+    async fn failures(&self, key: &str) -> Result<u32, Report> {
+        let failures = self.failures.read().await;
+
+        Ok(failures
+            .get(key)
+            .filter(|(_, expires_at)| *expires_at > Instant::now())
+            .map(|(count, _)| *count)
+            .unwrap_or(0))
+    }
+
+    async fn record_failure(&self, key: &str, window: u64) -> Result<u32, Report> {
+        let now = Instant::now();
+        let mut failures = self.failures.write().await;
+        // 写入时顺带清理已过期的记录，避免无限增长
+        failures.retain(|_, (_, expires_at)| *expires_at > now);
+        let entry = failures.entry(key.to_string()).or_insert((0, now + Duration::from_secs(window.max(1))));
+        entry.0 += 1;
+
+        Ok(entry.0)
+    }
+
+    async fn lock(&self, key: &str, ttl: u64) -> Result<(), Report> {
+        let now = Instant::now();
+        let mut locks = self.locks.write().await;
+        locks.retain(|_, unlock_at| *unlock_at > now);
+        locks.insert(key.to_string(), now + Duration::from_secs(ttl.max(1)));
+
+        Ok(())
+    }
+
+    async fn locked_for(&self, key: &str) -> Result<Option<u64>, Report> {
+        let locks = self.locks.read().await;
+        let now = Instant::now();
+
+        Ok(locks
+            .get(key)
+            .filter(|unlock_at| **unlock_at > now)
+            .map(|unlock_at| unlock_at.duration_since(now).as_secs().max(1)))
+    }
+
+    async fn clear(&self, key: &str) -> Result<(), Report> {
+        self.failures.write().await.remove(key);
+        self.locks.write().await.remove(key);
+
+        Ok(())
+    }
+}
+
+/// # Description
+///     Redis 失败计数存储，多实例部署时共享计数与锁定
+/// # Fields
+///     redis Arc<Redis>: Redis 连接
+pub struct RedisAttemptStore {
+    redis: Arc<Redis>,
+}
+
+impl RedisAttemptStore {
+    pub fn new(redis: Arc<Redis>) -> Self {
+        Self { redis }
+    }
+}
+
+#[async_trait]
+impl AttemptStoreInterface for RedisAttemptStore {
+    async fn failures(&self, key: &str) -> Result<u32, Report> {
+        let mut conn = self.redis.connection().await?;
+        let count: Option<u32> = conn.get(key).await?;
+
+        Ok(count.unwrap_or(0))
+    }
+
+    async fn record_failure(&self, key: &str, window: u64) -> Result<u32, Report> {
+        let mut conn = self.redis.connection().await?;
+        let count: u32 = Script::new(INCR_SCRIPT)
+            .key(key)
+            .arg(window.max(1))
+            .invoke_async(&mut conn)
+            .await?;
+
+        Ok(count)
+    }
+
+    async fn lock(&self, key: &str, ttl: u64) -> Result<(), Report> {
+        let mut conn = self.redis.connection().await?;
+        conn.set_ex::<_, _, ()>(key, 1, ttl.max(1)).await?;
+
+        Ok(())
+    }
+
+    async fn locked_for(&self, key: &str) -> Result<Option<u64>, Report> {
+        let mut conn = self.redis.connection().await?;
+        // TTL 对不存在的键返回 -2，对没有过期时间的键返回 -1
+        let ttl: i64 = conn.ttl(key).await?;
+
+        Ok((ttl > 0).then_some(ttl as u64))
+    }
+
+    async fn clear(&self, key: &str) -> Result<(), Report> {
+        let mut conn = self.redis.connection().await?;
+        conn.del::<_, ()>(key).await?;
+
+        Ok(())
+    }
+}
+
+/// # Description
+///     优先使用主存储，主存储出错时降级到备用存储，保证 Redis 故障时登录限流仍然生效
+/// # Fields
+///     primary Arc<dyn AttemptStoreInterface>: 主存储（Redis）
+///     fallback Arc<dyn AttemptStoreInterface>: 备用存储（进程内存）
+pub struct FallbackAttemptStore {
+    primary: Arc<dyn AttemptStoreInterface>,
+    fallback: Arc<dyn AttemptStoreInterface>,
+}
+
+impl FallbackAttemptStore {
+    pub fn new(primary: Arc<dyn AttemptStoreInterface>, fallback: Arc<dyn AttemptStoreInterface>) -> Self {
+        Self { primary, fallback }
+    }
+
+    fn degrade(operation: &str, e: &Report) {
+        warn!("-InfrastructureLayer [LoginThrottle] primary store {} failed, falling back to memory: {}", operation, e);
+    }
+}
+
+#[async_trait]
+impl AttemptStoreInterface for FallbackAttemptStore {
+    async fn failures(&self, key: &str) -> Result<u32, Report> {
+        match self.primary.failures(key).await {
+            Ok(count) => Ok(count),
+            Err(e) => {
+                Self::degrade("failures", &e);
+                self.fallback.failures(key).await
+            }
+        }
+    }
+
+    async fn record_failure(&self, key: &str, window: u64) -> Result<u32, Report> {
+        match self.primary.record_failure(key, window).await {
+            Ok(count) => Ok(count),
+            Err(e) => {
+                Self::degrade("record_failure", &e);
+                self.fallback.record_failure(key, window).await
+            }
+        }
+    }
+
+    async fn lock(&self, key: &str, ttl: u64) -> Result<(), Report> {
+        match self.primary.lock(key, ttl).await {
+            Ok(()) => Ok(()),
+            Err(e) => {
+                Self::degrade("lock", &e);
+                self.fallback.lock(key, ttl).await
+            }
+        }
+    }
+
+    async fn locked_for(&self, key: &str) -> Result<Option<u64>, Report> {
+        match self.primary.locked_for(key).await {
+            Ok(ttl) => Ok(ttl),
+            Err(e) => {
+                Self::degrade("locked_for", &e);
+                self.fallback.locked_for(key).await
+            }
+        }
+    }
+
+    async fn clear(&self, key: &str) -> Result<(), Report> {
+        // 备用存储中可能残留降级期间的计数，一并清理
+        let cleared = self.primary.clear(key).await;
+        self.fallback.clear(key).await?;
+        if let Err(e) = cleared {
+            Self::degrade("clear", &e);
+        }
+
+        Ok(())
+    }
+}
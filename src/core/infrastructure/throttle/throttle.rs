@@ -0,0 +1,35 @@
+use std::sync::Arc;
+use color_eyre::{Report, Result};
+use crate::core::domain::throttle::repository::AttemptStoreInterface;
+use crate::core::infrastructure::{config::Config, redis::Redis};
+use super::store::{FallbackAttemptStore, MemoryAttemptStore, RedisAttemptStore};
+
+/// # Description
+///     登录限流组件，按 login_throttle.driver 选择失败计数存储
+/// # Fields
+///     pub store: Arc<dyn AttemptStoreInterface>, 失败计数与锁定存储
+pub struct LoginThrottle {
+    pub store: Arc<dyn AttemptStoreInterface>,
+}
+
+impl LoginThrottle {
+    /// # Description
+    ///     根据配置创建登录限流组件，redis 驱动在 Redis 出错时降级为进程内存
+    /// # Param
+    ///     config Arc<Config>: 配置
+    ///     redis Arc<Redis>: Redis 实例
+    /// # Return
+    ///     Result<LoginThrottle, Report>: 登录限流组件
+    pub fn new(config: Arc<Config>, redis: Arc<Redis>) -> Result<Self, Report> {
+        let store: Arc<dyn AttemptStoreInterface> = match config.login_throttle.driver.as_str() {
+            "redis" => Arc::new(FallbackAttemptStore::new(
+                Arc::new(RedisAttemptStore::new(redis)),
+                Arc::new(MemoryAttemptStore::new()),
+            )),
+            "memory" => Arc::new(MemoryAttemptStore::new()),
+            other => return Err(Report::msg(format!("不支持的登录限流存储驱动 {}", other))),
+        };
+
+        Ok(Self { store })
+    }
+}
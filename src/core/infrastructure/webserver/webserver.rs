@@ -71,7 +71,8 @@ impl WebServer {
 
         // 启动 web 服务
         tokio::spawn(async move {
-            axum::serve(listener, app_router_log)
+            // 携带连接信息，供登录限流获取客户端 IP
+            axum::serve(listener, app_router_log.into_make_service_with_connect_info::<SocketAddr>())
                 .with_graceful_shutdown(async move { notify_shutdown.notified().await })
                 .await.map_err(|e| Report::msg(format!("+InfrastructureLayer [WebServer] WebServer run failed: {:?}" ,e.to_string())))
         });
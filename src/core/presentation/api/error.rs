@@ -1,3 +1,5 @@
+use axum::http::StatusCode;
+
 #[derive(Debug)]
 #[allow(dead_code)]
 pub enum ErrorCode {
@@ -38,4 +40,18 @@ impl ErrorCode {
             ErrorCode::ServerError => "Internal server error",
         }
     }
+
+    /// 错误码对应的 HTTP 状态码
+    pub fn status(&self) -> StatusCode {
+        match self {
+            ErrorCode::DefaultError => StatusCode::BAD_REQUEST,
+            ErrorCode::ValidateError => StatusCode::UNPROCESSABLE_ENTITY,
+            ErrorCode::TokenError => StatusCode::UNAUTHORIZED,
+            ErrorCode::Forbidden => StatusCode::FORBIDDEN,
+            ErrorCode::NotFound => StatusCode::NOT_FOUND,
+            ErrorCode::TooManyRequests => StatusCode::TOO_MANY_REQUESTS,
+            ErrorCode::UserNotFound => StatusCode::NOT_FOUND,
+            ErrorCode::ServerError => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
 }
\ No newline at end of file
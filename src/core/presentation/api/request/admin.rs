@@ -0,0 +1,50 @@
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+pub struct AdminUserQuery {
+    pub keyword: Option<String>,
+    pub disabled: Option<bool>,
+    pub level: Option<i8>,
+    pub reg_type: Option<i8>,
+    pub created_from: Option<DateTime<Utc>>,
+    pub created_to: Option<DateTime<Utc>>,
+    pub deleted: Option<bool>,
+    pub page: Option<u64>,
+    pub page_size: Option<u64>
+}
+
+#[derive(Deserialize)]
+pub struct SetUserStatusRequest {
+    pub user_id: i32,
+    pub disabled: bool
+}
+
+#[derive(Deserialize)]
+pub struct ChangeLevelRequest {
+    pub user_id: i32,
+    pub level: i8
+}
+
+#[derive(Deserialize)]
+pub struct AdminAssignRolesRequest {
+    pub user_id: i32,
+    pub roles: Vec<String>
+}
+
+#[derive(Deserialize)]
+pub struct AdminUserRequest {
+    pub user_id: i32
+}
+
+#[derive(Deserialize)]
+pub struct AdminUserWalletQuery {
+    pub user_id: i32
+}
+
+#[derive(Deserialize)]
+pub struct AdminAuditQuery {
+    pub user_id: i32,
+    pub page: Option<u64>,
+    pub page_size: Option<u64>
+}
@@ -0,0 +1,26 @@
+use serde::Deserialize;
+use crate::core::domain::dca::entity::dca_schedule_entity::DcaKind;
+
+#[derive(Deserialize)]
+pub struct CreateDcaRequest {
+    pub wallet_id: i32,
+    pub kind: DcaKind,
+    pub input_mint: String,
+    pub output_mint: Option<String>,
+    pub recipient: Option<String>,
+    pub amount: f64,
+    pub max_slippage: Option<f64>,
+    pub cron: Option<String>,
+    pub interval_secs: Option<i64>,
+    pub spend_cap: Option<f64>
+}
+
+#[derive(Deserialize)]
+pub struct DcaActionRequest {
+    pub schedule_id: i32
+}
+
+#[derive(Deserialize)]
+pub struct DcaQuery {
+    pub schedule_id: Option<i32>
+}
@@ -0,0 +1,7 @@
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+pub struct WalletTransactionQuery {
+    pub page: Option<u64>,
+    pub page_size: Option<u64>
+}
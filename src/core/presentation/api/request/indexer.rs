@@ -0,0 +1,17 @@
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+pub struct IndexedBlockQuery {
+    pub slot: u64
+}
+
+#[derive(Deserialize)]
+pub struct IndexedTransactionQuery {
+    pub signature: String
+}
+
+#[derive(Deserialize)]
+pub struct LedgerRollbackQuery {
+    pub user_id: Option<i32>,
+    pub limit: Option<u64>
+}
@@ -0,0 +1,11 @@
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+pub struct DecodeTransactionRequest {
+    pub transaction: String
+}
+
+#[derive(Deserialize)]
+pub struct FetchIdlRequest {
+    pub program_id: String
+}
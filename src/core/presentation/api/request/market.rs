@@ -0,0 +1,8 @@
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+pub struct MarketQuery {
+    pub market: String,
+    pub depth: Option<usize>,
+    pub limit: Option<usize>
+}
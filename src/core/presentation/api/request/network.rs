@@ -0,0 +1,7 @@
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+pub struct NetworkStatsQuery {
+    pub window: Option<i64>,
+    pub granularity: Option<i64>
+}
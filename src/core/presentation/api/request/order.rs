@@ -0,0 +1,25 @@
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use crate::core::domain::order::entity::order_entity::OrderDirection;
+
+#[derive(Deserialize)]
+pub struct PlaceOrderRequest {
+    pub wallet_id: i32,
+    pub input_mint: String,
+    pub output_mint: String,
+    pub amount_in: f64,
+    pub trigger_price: f64,
+    pub direction: OrderDirection,
+    pub max_slippage: f64,
+    pub expires_at: Option<DateTime<Utc>>
+}
+
+#[derive(Deserialize)]
+pub struct CancelOrderRequest {
+    pub order_id: i32
+}
+
+#[derive(Deserialize)]
+pub struct OrderQuery {
+    pub order_id: Option<i32>
+}
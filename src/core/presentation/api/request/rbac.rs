@@ -0,0 +1,37 @@
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+pub struct CreateRoleRequest {
+    pub name: String,
+    pub description: Option<String>,
+    pub permissions: Vec<String>
+}
+
+#[derive(Deserialize)]
+pub struct UpdateRoleRequest {
+    pub role_id: i32,
+    pub description: Option<String>,
+    pub permissions: Vec<String>
+}
+
+#[derive(Deserialize)]
+pub struct DeleteRoleRequest {
+    pub role_id: i32
+}
+
+#[derive(Deserialize)]
+pub struct CreatePermissionRequest {
+    pub code: String,
+    pub description: Option<String>
+}
+
+#[derive(Deserialize)]
+pub struct UserRoleQuery {
+    pub user_id: i32
+}
+
+#[derive(Deserialize)]
+pub struct AssignRolesRequest {
+    pub user_id: i32,
+    pub roles: Vec<String>
+}
@@ -0,0 +1,7 @@
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+pub struct StreamQuery {
+    pub access_token: Option<String>,
+    pub topics: Option<String>
+}
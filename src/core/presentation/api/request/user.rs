@@ -1,9 +1,11 @@
 use serde::Deserialize;
+use crate::core::domain::verification::entity::verification_code_entity::VerificationPurpose;
 
 #[derive(Deserialize)]
 pub struct RegisterRequest {
     pub identifier: String,
-    pub password: String
+    pub password: String,
+    pub code: Option<String>
 }
 
 #[derive(Deserialize)]
@@ -11,3 +13,66 @@ pub struct LoginRequest {
     pub identifier: String,
     pub password: String
 }
+
+#[derive(Deserialize)]
+pub struct RefreshTokenRequest {
+    pub refresh_token: String
+}
+
+#[derive(Deserialize)]
+pub struct EmailCodeRequest {
+    pub email: String
+}
+
+#[derive(Deserialize)]
+pub struct ForgotPasswordRequest {
+    pub email: String
+}
+
+#[derive(Deserialize)]
+pub struct ResetPasswordRequest {
+    pub token: String,
+    pub new_password: String
+}
+
+#[derive(Deserialize)]
+pub struct SmsCodeRequest {
+    pub mobile: String,
+    pub purpose: VerificationPurpose
+}
+
+#[derive(Deserialize)]
+pub struct OtpLoginRequest {
+    pub mobile: String,
+    pub code: String
+}
+
+#[derive(Deserialize)]
+pub struct TwoFactorLoginRequest {
+    pub challenge_token: String,
+    pub code: String
+}
+
+#[derive(Deserialize)]
+pub struct TwoFactorCodeRequest {
+    pub code: String
+}
+
+#[derive(Deserialize)]
+pub struct UpdateProfileRequest {
+    pub nickname: Option<String>,
+    pub avatar: Option<String>,
+    pub mobile: Option<String>,
+    pub code: Option<String>
+}
+
+#[derive(Deserialize)]
+pub struct ChangePasswordRequest {
+    pub old_password: String,
+    pub new_password: String
+}
+
+#[derive(Deserialize)]
+pub struct DeleteAccountRequest {
+    pub password: String
+}
\ No newline at end of file
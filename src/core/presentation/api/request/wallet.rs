@@ -6,4 +6,9 @@ pub struct GenerationRequest {
     pub user_id: i32,
     pub pub_key: String,
     pub privy_key: String
+}
+
+#[derive(Deserialize)]
+pub struct ExportKeyRequest {
+    pub wallet_id: i32
 }
\ No newline at end of file
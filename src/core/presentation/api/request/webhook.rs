@@ -0,0 +1,47 @@
+use serde::Deserialize;
+use crate::core::domain::webhook::entity::webhook_event_entity::WebhookEventType;
+
+#[derive(Deserialize)]
+pub struct CreateWebhookRequest {
+    pub url: String,
+    pub events: Vec<WebhookEventType>,
+    pub balance_threshold: Option<f64>
+}
+
+#[derive(Deserialize)]
+pub struct DeleteWebhookRequest {
+    pub endpoint_id: i32
+}
+
+#[derive(Deserialize)]
+pub struct DeliveryQuery {
+    pub endpoint_id: i32,
+    pub limit: Option<u64>
+}
+
+#[derive(Deserialize)]
+pub struct ReplayDeliveryRequest {
+    pub delivery_id: i32
+}
+
+#[derive(Deserialize)]
+pub struct AdminCreateWebhookRequest {
+    pub url: String,
+    pub events: Vec<WebhookEventType>
+}
+
+#[derive(Deserialize)]
+pub struct AdminDeleteWebhookRequest {
+    pub endpoint_id: i32
+}
+
+#[derive(Deserialize)]
+pub struct AdminDeliveryQuery {
+    pub endpoint_id: i32,
+    pub limit: Option<u64>
+}
+
+#[derive(Deserialize)]
+pub struct AdminReplayDeliveryRequest {
+    pub delivery_id: i32
+}
@@ -0,0 +1,19 @@
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+pub struct AddWhitelistRequest {
+    pub address: String,
+    pub label: Option<String>
+}
+
+#[derive(Deserialize)]
+pub struct RemoveWhitelistRequest {
+    pub id: i32
+}
+
+#[derive(Deserialize)]
+pub struct WithdrawRequest {
+    pub wallet_id: i32,
+    pub address: String,
+    pub amount: f64
+}
\ No newline at end of file
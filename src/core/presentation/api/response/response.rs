@@ -1,4 +1,5 @@
 use axum::Json;
+use axum::http::StatusCode;
 use serde::{Deserialize, Serialize};
 use serde_json::{json, Value};
 use crate::core::presentation::api::error::ErrorCode;
@@ -31,4 +32,9 @@ impl<T> Response<T> {
             message,
         }))
     }
+
+    /// Creates a failed response together with the HTTP status of the error code, used where the status matters (e.g. 401 from auth)
+    pub fn failed_with_status(code: ErrorCode, message: String) -> (StatusCode, Json<Value>) {
+        (code.status(), Self::failed(code, message))
+    }
 }
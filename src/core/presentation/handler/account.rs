@@ -0,0 +1,34 @@
+use std::sync::Arc;
+use axum::{extract::{Path, State}, response::IntoResponse};
+use crate::core::presentation::api::{
+    response::Response,
+    error::ErrorCode
+};
+use crate::core::application::ApplicationLayer;
+use crate::core::domain::account::entity::account_inspection_entity::AccountInspection;
+
+/// # Description
+///     账户查询任务处理
+pub struct AccountHandle;
+
+impl AccountHandle {
+    /// # Description
+    ///     查询链上账户，返回所有者、余额、可执行标记、免租状态及按所有者程序解码的数据
+    /// # Param
+    ///     State(application_layer): State<Arc<ApplicationLayer>> - 应用层
+    ///     Path(pubkey): Path<String> - 账户地址
+    /// # Return
+    ///     impl IntoResponse: 路由
+    pub async fn inspect(
+        State(application_layer): State<Arc<ApplicationLayer>>,
+        Path(pubkey): Path<String>,
+    ) -> impl IntoResponse {
+        match application_layer
+            .account_application
+            .inspect(&pubkey)
+            .await {
+            Ok(inspection) => Response::<AccountInspection>::success(Some(inspection)),
+            Err(e) => Response::<()>::failed(ErrorCode::ValidateError, e.to_string())
+        }
+    }
+}
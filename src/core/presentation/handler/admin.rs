@@ -0,0 +1,195 @@
+use std::sync::Arc;
+use axum::{Json, extract::{Query, State}, response::IntoResponse};
+use crate::core::presentation::{
+    api::{
+        request::admin::{
+            AdminUserQuery, SetUserStatusRequest, ChangeLevelRequest, AdminAssignRolesRequest, AdminUserRequest, AdminUserWalletQuery, AdminAuditQuery
+        },
+        response::Response,
+        error::ErrorCode
+    },
+    middleware::auth_middleware::AuthUser,
+};
+use crate::core::application::ApplicationLayer;
+use crate::core::application::admin::{AdminAuditPage, AdminPasswordReset, AdminUserPage, AdminUserView, AdminUserWallets};
+use crate::core::application::rbac::UserRoles;
+use crate::core::domain::user::repository_interface::UserFilter;
+
+/// # Description
+///     用户管理后台处理，仅拥有 user:admin 权限的用户可访问，修改用户与查看钱包都会写入审计记录
+pub struct AdminHandle;
+
+impl AdminHandle {
+    /// # Description
+    ///     按条件分页查询用户
+    /// # Param
+    ///     State(application_layer): State<Arc<ApplicationLayer>> - 应用层
+    ///     Query(query): Query<AdminUserQuery> - 过滤条件与分页参数
+    /// # Return
+    ///     impl IntoResponse: 路由
+    pub async fn list_users(
+        State(application_layer): State<Arc<ApplicationLayer>>,
+        Query(query): Query<AdminUserQuery>,
+    ) -> impl IntoResponse {
+        let filter = UserFilter {
+            keyword: query.keyword,
+            disabled: query.disabled,
+            level: query.level,
+            reg_type: query.reg_type,
+            created_from: query.created_from,
+            created_to: query.created_to,
+            deleted: query.deleted,
+        };
+
+        match application_layer.admin_application.list_users(filter, query.page, query.page_size).await {
+            Ok(page) => Response::<AdminUserPage>::success(Some(page)),
+            Err(e) => Response::<()>::failed(ErrorCode::ValidateError, e.to_string())
+        }
+    }
+
+    /// # Description
+    ///     启用或禁用用户
+    /// # Param
+    ///     State(application_layer): State<Arc<ApplicationLayer>> - 应用层
+    ///     auth_user: AuthUser - 当前登录的管理员
+    ///     Json(payload): Json<SetUserStatusRequest> - 请求 payload
+    /// # Return
+    ///     impl IntoResponse: 路由
+    pub async fn set_status(
+        State(application_layer): State<Arc<ApplicationLayer>>,
+        auth_user: AuthUser,
+        Json(payload): Json<SetUserStatusRequest>,
+    ) -> impl IntoResponse {
+        match application_layer
+            .admin_application
+            .set_disabled(auth_user.id, payload.user_id, payload.disabled)
+            .await {
+            Ok(user) => Response::<AdminUserView>::success(Some(user)),
+            Err(e) => Response::<()>::failed(ErrorCode::ValidateError, e.to_string())
+        }
+    }
+
+    /// # Description
+    ///     调整用户级别
+    /// # Param
+    ///     State(application_layer): State<Arc<ApplicationLayer>> - 应用层
+    ///     auth_user: AuthUser - 当前登录的管理员
+    ///     Json(payload): Json<ChangeLevelRequest> - 请求 payload
+    /// # Return
+    ///     impl IntoResponse: 路由
+    pub async fn change_level(
+        State(application_layer): State<Arc<ApplicationLayer>>,
+        auth_user: AuthUser,
+        Json(payload): Json<ChangeLevelRequest>,
+    ) -> impl IntoResponse {
+        match application_layer
+            .admin_application
+            .change_level(auth_user.id, payload.user_id, payload.level)
+            .await {
+            Ok(user) => Response::<AdminUserView>::success(Some(user)),
+            Err(e) => Response::<()>::failed(ErrorCode::ValidateError, e.to_string())
+        }
+    }
+
+    /// # Description
+    ///     设置用户的角色，整体替换
+    /// # Param
+    ///     State(application_layer): State<Arc<ApplicationLayer>> - 应用层
+    ///     auth_user: AuthUser - 当前登录的管理员
+    ///     Json(payload): Json<AdminAssignRolesRequest> - 请求 payload
+    /// # Return
+    ///     impl IntoResponse: 路由
+    pub async fn assign_roles(
+        State(application_layer): State<Arc<ApplicationLayer>>,
+        auth_user: AuthUser,
+        Json(payload): Json<AdminAssignRolesRequest>,
+    ) -> impl IntoResponse {
+        match application_layer
+            .admin_application
+            .assign_roles(auth_user.id, payload.user_id, payload.roles)
+            .await {
+            Ok(roles) => Response::<UserRoles>::success(Some(roles)),
+            Err(e) => Response::<()>::failed(ErrorCode::ValidateError, e.to_string())
+        }
+    }
+
+    /// # Description
+    ///     强制重置用户密码
+    /// # Param
+    ///     State(application_layer): State<Arc<ApplicationLayer>> - 应用层
+    ///     auth_user: AuthUser - 当前登录的管理员
+    ///     Json(payload): Json<AdminUserRequest> - 请求 payload
+    /// # Return
+    ///     impl IntoResponse: 路由
+    pub async fn force_password_reset(
+        State(application_layer): State<Arc<ApplicationLayer>>,
+        auth_user: AuthUser,
+        Json(payload): Json<AdminUserRequest>,
+    ) -> impl IntoResponse {
+        match application_layer
+            .admin_application
+            .force_password_reset(auth_user.id, payload.user_id)
+            .await {
+            Ok(reset) => Response::<AdminPasswordReset>::success(Some(reset)),
+            Err(e) => Response::<()>::failed(ErrorCode::ValidateError, e.to_string())
+        }
+    }
+
+    /// # Description
+    ///     查看用户的钱包与余额
+    /// # Param
+    ///     State(application_layer): State<Arc<ApplicationLayer>> - 应用层
+    ///     auth_user: AuthUser - 当前登录的管理员
+    ///     Query(query): Query<AdminUserWalletQuery> - 用户id
+    /// # Return
+    ///     impl IntoResponse: 路由
+    pub async fn user_wallets(
+        State(application_layer): State<Arc<ApplicationLayer>>,
+        auth_user: AuthUser,
+        Query(query): Query<AdminUserWalletQuery>,
+    ) -> impl IntoResponse {
+        match application_layer.admin_application.user_wallets(auth_user.id, query.user_id).await {
+            Ok(wallets) => Response::<AdminUserWallets>::success(Some(wallets)),
+            Err(e) => Response::<()>::failed(ErrorCode::DefaultError, e.to_string())
+        }
+    }
+
+    /// # Description
+    ///     恢复已注销的用户
+    /// # Param
+    ///     State(application_layer): State<Arc<ApplicationLayer>> - 应用层
+    ///     auth_user: AuthUser - 当前登录的管理员
+    ///     Json(payload): Json<AdminUserRequest> - 请求 payload
+    /// # Return
+    ///     impl IntoResponse: 路由
+    pub async fn restore_user(
+        State(application_layer): State<Arc<ApplicationLayer>>,
+        auth_user: AuthUser,
+        Json(payload): Json<AdminUserRequest>,
+    ) -> impl IntoResponse {
+        match application_layer
+            .admin_application
+            .restore_user(auth_user.id, payload.user_id)
+            .await {
+            Ok(user) => Response::<AdminUserView>::success(Some(user)),
+            Err(e) => Response::<()>::failed(ErrorCode::ValidateError, e.to_string())
+        }
+    }
+
+    /// # Description
+    ///     分页查询针对某个用户的管理员操作审计记录
+    /// # Param
+    ///     State(application_layer): State<Arc<ApplicationLayer>> - 应用层
+    ///     Query(query): Query<AdminAuditQuery> - 用户id与分页参数
+    /// # Return
+    ///     impl IntoResponse: 路由
+    pub async fn audit_log(
+        State(application_layer): State<Arc<ApplicationLayer>>,
+        Query(query): Query<AdminAuditQuery>,
+    ) -> impl IntoResponse {
+        match application_layer.admin_application.audit_log(query.user_id, query.page, query.page_size).await {
+            Ok(page) => Response::<AdminAuditPage>::success(Some(page)),
+            Err(e) => Response::<()>::failed(ErrorCode::ValidateError, e.to_string())
+        }
+    }
+}
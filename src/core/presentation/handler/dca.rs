@@ -0,0 +1,168 @@
+use std::sync::Arc;
+use axum::{Json, extract::{Query, State}, response::IntoResponse};
+use crate::core::presentation::api::{
+    request::dca::{CreateDcaRequest, DcaActionRequest, DcaQuery},
+    response::Response,
+    error::ErrorCode
+};
+use crate::core::application::ApplicationLayer;
+use crate::core::presentation::middleware::auth_middleware::AuthUser;
+use crate::core::application::dca::DcaDetail;
+use crate::core::domain::dca::entity::dca_schedule_entity::Model as DcaScheduleModel;
+use crate::core::domain::dca::service::{DcaCadence, DcaPlan};
+
+/// # Description
+///     定投任务处理
+pub struct DcaHandle;
+
+impl DcaHandle {
+    /// # Description
+    ///     创建定投计划
+    /// # Param
+    ///     State(application_layer): State<Arc<ApplicationLayer>> - 应用层
+    ///     auth_user: AuthUser - 当前登录用户
+    ///     Json(payload): Json<CreateDcaRequest> - 请求 payload
+    /// # Return
+    ///     impl IntoResponse: 路由
+    pub async fn create_schedule(
+        State(application_layer): State<Arc<ApplicationLayer>>,
+        auth_user: AuthUser,
+        Json(payload): Json<CreateDcaRequest>,
+    ) -> impl IntoResponse {
+        let cadence = match DcaCadence::from_parts(payload.cron.as_deref(), payload.interval_secs) {
+            Ok(cadence) => cadence,
+            Err(e) => return Response::<()>::failed(ErrorCode::ValidateError, e.to_string()),
+        };
+
+        let plan = DcaPlan {
+            kind: payload.kind,
+            input_mint: payload.input_mint,
+            output_mint: payload.output_mint,
+            recipient: payload.recipient,
+            amount: payload.amount,
+            max_slippage: payload.max_slippage.unwrap_or_default(),
+            cadence,
+            spend_cap: payload.spend_cap,
+        };
+
+        match application_layer
+            .dca_application
+            .create_schedule(auth_user.id, payload.wallet_id, plan)
+            .await {
+            Ok(schedule) => Response::<DcaScheduleModel>::success(Some(schedule)),
+            Err(e) => Response::<()>::failed(ErrorCode::ValidateError, e.to_string())
+        }
+    }
+
+    /// # Description
+    ///     暂停定投计划
+    /// # Param
+    ///     State(application_layer): State<Arc<ApplicationLayer>> - 应用层
+    ///     auth_user: AuthUser - 当前登录用户
+    ///     Json(payload): Json<DcaActionRequest> - 请求 payload
+    /// # Return
+    ///     impl IntoResponse: 路由
+    pub async fn pause_schedule(
+        State(application_layer): State<Arc<ApplicationLayer>>,
+        auth_user: AuthUser,
+        Json(payload): Json<DcaActionRequest>,
+    ) -> impl IntoResponse {
+        match application_layer
+            .dca_application
+            .pause_schedule(auth_user.id, payload.schedule_id)
+            .await {
+            Ok(schedule) => Response::<DcaScheduleModel>::success(Some(schedule)),
+            Err(e) => Response::<()>::failed(ErrorCode::DefaultError, e.to_string())
+        }
+    }
+
+    /// # Description
+    ///     恢复定投计划
+    /// # Param
+    ///     State(application_layer): State<Arc<ApplicationLayer>> - 应用层
+    ///     auth_user: AuthUser - 当前登录用户
+    ///     Json(payload): Json<DcaActionRequest> - 请求 payload
+    /// # Return
+    ///     impl IntoResponse: 路由
+    pub async fn resume_schedule(
+        State(application_layer): State<Arc<ApplicationLayer>>,
+        auth_user: AuthUser,
+        Json(payload): Json<DcaActionRequest>,
+    ) -> impl IntoResponse {
+        match application_layer
+            .dca_application
+            .resume_schedule(auth_user.id, payload.schedule_id)
+            .await {
+            Ok(schedule) => Response::<DcaScheduleModel>::success(Some(schedule)),
+            Err(e) => Response::<()>::failed(ErrorCode::DefaultError, e.to_string())
+        }
+    }
+
+    /// # Description
+    ///     取消定投计划
+    /// # Param
+    ///     State(application_layer): State<Arc<ApplicationLayer>> - 应用层
+    ///     auth_user: AuthUser - 当前登录用户
+    ///     Json(payload): Json<DcaActionRequest> - 请求 payload
+    /// # Return
+    ///     impl IntoResponse: 路由
+    pub async fn cancel_schedule(
+        State(application_layer): State<Arc<ApplicationLayer>>,
+        auth_user: AuthUser,
+        Json(payload): Json<DcaActionRequest>,
+    ) -> impl IntoResponse {
+        match application_layer
+            .dca_application
+            .cancel_schedule(auth_user.id, payload.schedule_id)
+            .await {
+            Ok(schedule) => Response::<DcaScheduleModel>::success(Some(schedule)),
+            Err(e) => Response::<()>::failed(ErrorCode::DefaultError, e.to_string())
+        }
+    }
+
+    /// # Description
+    ///     查询用户的定投计划列表
+    /// # Param
+    ///     State(application_layer): State<Arc<ApplicationLayer>> - 应用层
+    ///     auth_user: AuthUser - 当前登录用户
+    /// # Return
+    ///     impl IntoResponse: 路由
+    pub async fn list_schedules(
+        State(application_layer): State<Arc<ApplicationLayer>>,
+        auth_user: AuthUser,
+    ) -> impl IntoResponse {
+        match application_layer
+            .dca_application
+            .list_schedules(auth_user.id)
+            .await {
+            Ok(schedules) => Response::<Vec<DcaScheduleModel>>::success(Some(schedules)),
+            Err(e) => Response::<()>::failed(ErrorCode::DefaultError, e.to_string())
+        }
+    }
+
+    /// # Description
+    ///     查询定投计划详情及执行记录
+    /// # Param
+    ///     State(application_layer): State<Arc<ApplicationLayer>> - 应用层
+    ///     auth_user: AuthUser - 当前登录用户
+    ///     Query(query): Query<DcaQuery> - 查询参数
+    /// # Return
+    ///     impl IntoResponse: 路由
+    pub async fn get_schedule(
+        State(application_layer): State<Arc<ApplicationLayer>>,
+        auth_user: AuthUser,
+        Query(query): Query<DcaQuery>,
+    ) -> impl IntoResponse {
+        let Some(schedule_id) = query.schedule_id else {
+            return Response::<()>::failed(ErrorCode::ValidateError, "schedule_id 不能为空".to_string());
+        };
+
+        match application_layer
+            .dca_application
+            .get_schedule(auth_user.id, schedule_id)
+            .await {
+            Ok(detail) => Response::<DcaDetail>::success(Some(detail)),
+            Err(_) => Response::<()>::failed(ErrorCode::NotFound, "定投计划不存在".to_string())
+        }
+    }
+}
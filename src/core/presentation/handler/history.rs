@@ -0,0 +1,40 @@
+use std::sync::Arc;
+use axum::{extract::{Path, Query, State}, response::IntoResponse};
+use crate::core::presentation::api::{
+    request::history::WalletTransactionQuery,
+    response::Response,
+    error::ErrorCode
+};
+use crate::core::application::ApplicationLayer;
+use crate::core::presentation::middleware::auth_middleware::AuthUser;
+use crate::core::application::history::WalletTransactionPage;
+
+/// # Description
+///     钱包交易历史任务处理
+pub struct HistoryHandle;
+
+impl HistoryHandle {
+    /// # Description
+    ///     分页查询钱包交易历史
+    /// # Param
+    ///     State(application_layer): State<Arc<ApplicationLayer>> - 应用层
+    ///     auth_user: AuthUser - 当前登录用户
+    ///     Path(wallet_id): Path<i32> - 钱包id
+    ///     Query(query): Query<WalletTransactionQuery> - 分页参数
+    /// # Return
+    ///     impl IntoResponse: 路由
+    pub async fn list_transactions(
+        State(application_layer): State<Arc<ApplicationLayer>>,
+        auth_user: AuthUser,
+        Path(wallet_id): Path<i32>,
+        Query(query): Query<WalletTransactionQuery>,
+    ) -> impl IntoResponse {
+        match application_layer
+            .history_application
+            .list_transactions(auth_user.id, wallet_id, query.page, query.page_size)
+            .await {
+            Ok(page) => Response::<WalletTransactionPage>::success(Some(page)),
+            Err(e) => Response::<()>::failed(ErrorCode::DefaultError, e.to_string())
+        }
+    }
+}
@@ -0,0 +1,95 @@
+use std::sync::Arc;
+use axum::{extract::{Query, State}, response::IntoResponse};
+use crate::core::presentation::api::{
+    request::indexer::{IndexedBlockQuery, IndexedTransactionQuery, LedgerRollbackQuery},
+    response::Response,
+    error::ErrorCode
+};
+use crate::core::application::ApplicationLayer;
+use crate::core::application::indexer::{IndexedBlockDetail, IndexerStatus};
+use crate::core::domain::indexer::entity::indexed_transaction_entity::Model as IndexedTransactionModel;
+use crate::core::domain::reorg::entity::ledger_rollback_entity::Model as LedgerRollbackModel;
+
+/// # Description
+///     区块索引任务处理
+pub struct IndexerHandle;
+
+impl IndexerHandle {
+    /// # Description
+    ///     查询索引进度
+    /// # Param
+    ///     State(application_layer): State<Arc<ApplicationLayer>> - 应用层
+    /// # Return
+    ///     impl IntoResponse: 路由
+    pub async fn get_status(
+        State(application_layer): State<Arc<ApplicationLayer>>,
+    ) -> impl IntoResponse {
+        match application_layer
+            .indexer_application
+            .status()
+            .await {
+            Ok(status) => Response::<IndexerStatus>::success(Some(status)),
+            Err(e) => Response::<()>::failed(ErrorCode::DefaultError, e.to_string())
+        }
+    }
+
+    /// # Description
+    ///     查询已索引的区块及其非投票交易
+    /// # Param
+    ///     State(application_layer): State<Arc<ApplicationLayer>> - 应用层
+    ///     Query(query): Query<IndexedBlockQuery> - 区块 slot
+    /// # Return
+    ///     impl IntoResponse: 路由
+    pub async fn get_block(
+        State(application_layer): State<Arc<ApplicationLayer>>,
+        Query(query): Query<IndexedBlockQuery>,
+    ) -> impl IntoResponse {
+        match application_layer
+            .indexer_application
+            .get_block(query.slot)
+            .await {
+            Ok(detail) => Response::<IndexedBlockDetail>::success(Some(detail)),
+            Err(e) => Response::<()>::failed(ErrorCode::DefaultError, e.to_string())
+        }
+    }
+
+    /// # Description
+    ///     根据签名查询已索引的交易
+    /// # Param
+    ///     State(application_layer): State<Arc<ApplicationLayer>> - 应用层
+    ///     Query(query): Query<IndexedTransactionQuery> - 交易签名
+    /// # Return
+    ///     impl IntoResponse: 路由
+    pub async fn get_transaction(
+        State(application_layer): State<Arc<ApplicationLayer>>,
+        Query(query): Query<IndexedTransactionQuery>,
+    ) -> impl IntoResponse {
+        match application_layer
+            .indexer_application
+            .get_transaction(&query.signature)
+            .await {
+            Ok(transaction) => Response::<IndexedTransactionModel>::success(Some(transaction)),
+            Err(e) => Response::<()>::failed(ErrorCode::DefaultError, e.to_string())
+        }
+    }
+
+    /// # Description
+    ///     查询因分叉被回滚的充值入账审计记录
+    /// # Param
+    ///     State(application_layer): State<Arc<ApplicationLayer>> - 应用层
+    ///     Query(query): Query<LedgerRollbackQuery> - 查询参数
+    /// # Return
+    ///     impl IntoResponse: 路由
+    pub async fn list_rollbacks(
+        State(application_layer): State<Arc<ApplicationLayer>>,
+        Query(query): Query<LedgerRollbackQuery>,
+    ) -> impl IntoResponse {
+        match application_layer
+            .reorg_application
+            .list_rollbacks(query.user_id, query.limit)
+            .await {
+            Ok(rollbacks) => Response::<Vec<LedgerRollbackModel>>::success(Some(rollbacks)),
+            Err(e) => Response::<()>::failed(ErrorCode::DefaultError, e.to_string())
+        }
+    }
+}
@@ -0,0 +1,68 @@
+use std::sync::Arc;
+use axum::{Json, extract::State, response::IntoResponse};
+use crate::core::presentation::api::{
+    request::instruction::{DecodeTransactionRequest, FetchIdlRequest},
+    response::Response,
+    error::ErrorCode
+};
+use crate::core::application::ApplicationLayer;
+use crate::core::application::instruction::IdlSummary;
+use crate::core::domain::instruction::entity::parsed_instruction_entity::ParsedTransaction;
+
+/// # Description
+///     指令解码任务处理
+pub struct InstructionHandle;
+
+impl InstructionHandle {
+    /// # Description
+    ///     解码 base64 编码的交易，返回带名称账户的类型化指令
+    /// # Param
+    ///     State(application_layer): State<Arc<ApplicationLayer>> - 应用层
+    ///     Json(payload): Json<DecodeTransactionRequest> - 请求 payload
+    /// # Return
+    ///     impl IntoResponse: 路由
+    pub async fn decode_transaction(
+        State(application_layer): State<Arc<ApplicationLayer>>,
+        Json(payload): Json<DecodeTransactionRequest>,
+    ) -> impl IntoResponse {
+        match application_layer
+            .instruction_application
+            .decode_transaction(&payload.transaction)
+            .await {
+            Ok(transaction) => Response::<ParsedTransaction>::success(Some(transaction)),
+            Err(e) => Response::<()>::failed(ErrorCode::ValidateError, e.to_string())
+        }
+    }
+
+    /// # Description
+    ///     从链上 IDL 账户拉取 Anchor 程序的 IDL 并注册
+    /// # Param
+    ///     State(application_layer): State<Arc<ApplicationLayer>> - 应用层
+    ///     Json(payload): Json<FetchIdlRequest> - 请求 payload
+    /// # Return
+    ///     impl IntoResponse: 路由
+    pub async fn fetch_idl(
+        State(application_layer): State<Arc<ApplicationLayer>>,
+        Json(payload): Json<FetchIdlRequest>,
+    ) -> impl IntoResponse {
+        match application_layer
+            .instruction_application
+            .fetch_idl(&payload.program_id)
+            .await {
+            Ok(summary) => Response::<IdlSummary>::success(Some(summary)),
+            Err(e) => Response::<()>::failed(ErrorCode::ValidateError, e.to_string())
+        }
+    }
+
+    /// # Description
+    ///     查询已加载的 Anchor IDL
+    /// # Param
+    ///     State(application_layer): State<Arc<ApplicationLayer>> - 应用层
+    /// # Return
+    ///     impl IntoResponse: 路由
+    pub async fn list_idls(
+        State(application_layer): State<Arc<ApplicationLayer>>,
+    ) -> impl IntoResponse {
+        Response::<Vec<IdlSummary>>::success(Some(application_layer.instruction_application.list_idls()))
+    }
+}
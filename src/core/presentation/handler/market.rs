@@ -0,0 +1,105 @@
+use std::convert::Infallible;
+use std::sync::Arc;
+use axum::{extract::{Query, State}, response::IntoResponse};
+use axum::response::sse::{Event, KeepAlive, Sse};
+use futures::StreamExt;
+use crate::core::presentation::api::{
+    request::market::MarketQuery,
+    response::Response,
+    error::ErrorCode
+};
+use crate::core::application::ApplicationLayer;
+use crate::core::domain::market::entity::{MarketFill, MarketSnapshot, OrderBook};
+
+/// # Description
+///     OpenBook 行情任务处理
+pub struct MarketHandle;
+
+impl MarketHandle {
+    /// # Description
+    ///     查询 L2 订单簿
+    /// # Param
+    ///     State(application_layer): State<Arc<ApplicationLayer>> - 应用层
+    ///     Query(query): Query<MarketQuery> - 市场地址与档位数量
+    /// # Return
+    ///     impl IntoResponse: 路由
+    pub async fn get_order_book(
+        State(application_layer): State<Arc<ApplicationLayer>>,
+        Query(query): Query<MarketQuery>,
+    ) -> impl IntoResponse {
+        match application_layer
+            .market_application
+            .get_order_book(&query.market, query.depth)
+            .await {
+            Ok(book) => Response::<OrderBook>::success(Some(book)),
+            Err(e) => Response::<()>::failed(ErrorCode::DefaultError, e.to_string())
+        }
+    }
+
+    /// # Description
+    ///     查询最近成交
+    /// # Param
+    ///     State(application_layer): State<Arc<ApplicationLayer>> - 应用层
+    ///     Query(query): Query<MarketQuery> - 市场地址与成交数量
+    /// # Return
+    ///     impl IntoResponse: 路由
+    pub async fn get_fills(
+        State(application_layer): State<Arc<ApplicationLayer>>,
+        Query(query): Query<MarketQuery>,
+    ) -> impl IntoResponse {
+        match application_layer
+            .market_application
+            .get_fills(&query.market, query.limit)
+            .await {
+            Ok(fills) => Response::<Vec<MarketFill>>::success(Some(fills)),
+            Err(e) => Response::<()>::failed(ErrorCode::DefaultError, e.to_string())
+        }
+    }
+
+    /// # Description
+    ///     查询行情快照（订单簿 + 最近成交）
+    /// # Param
+    ///     State(application_layer): State<Arc<ApplicationLayer>> - 应用层
+    ///     Query(query): Query<MarketQuery> - 查询参数
+    /// # Return
+    ///     impl IntoResponse: 路由
+    pub async fn get_snapshot(
+        State(application_layer): State<Arc<ApplicationLayer>>,
+        Query(query): Query<MarketQuery>,
+    ) -> impl IntoResponse {
+        match application_layer
+            .market_application
+            .get_snapshot(&query.market, query.depth, query.limit)
+            .await {
+            Ok(snapshot) => Response::<MarketSnapshot>::success(Some(snapshot)),
+            Err(e) => Response::<()>::failed(ErrorCode::DefaultError, e.to_string())
+        }
+    }
+
+    /// # Description
+    ///     以 SSE 推送行情快照，仅在订单簿或成交变化时推送，读取失败时推送 error 事件
+    /// # Param
+    ///     State(application_layer): State<Arc<ApplicationLayer>> - 应用层
+    ///     Query(query): Query<MarketQuery> - 查询参数
+    /// # Return
+    ///     impl IntoResponse: SSE 响应
+    pub async fn stream(
+        State(application_layer): State<Arc<ApplicationLayer>>,
+        Query(query): Query<MarketQuery>,
+    ) -> impl IntoResponse {
+        let stream = application_layer
+            .market_application
+            .clone()
+            .watch(query.market, query.depth, query.limit)
+            .map(|result| {
+                let event = match result {
+                    Ok(snapshot) => Event::default().event("snapshot").json_data(&snapshot)
+                        .unwrap_or_else(|e| Event::default().event("error").data(e.to_string())),
+                    Err(e) => Event::default().event("error").data(e.to_string()),
+                };
+                Ok::<Event, Infallible>(event)
+            });
+
+        Sse::new(stream).keep_alive(KeepAlive::default())
+    }
+}
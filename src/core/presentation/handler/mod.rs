@@ -1,2 +1,17 @@
 pub(crate) mod user;
-pub mod wallet;
\ No newline at end of file
+pub mod wallet;
+pub mod price;
+pub mod order;
+pub mod dca;
+pub mod market;
+pub mod indexer;
+pub mod network;
+pub mod history;
+pub mod instruction;
+pub mod account;
+pub mod webhook;
+pub mod stream;
+pub mod rbac;
+pub mod two_factor;
+pub mod withdrawal;
+pub mod admin;
\ No newline at end of file
@@ -0,0 +1,35 @@
+use std::sync::Arc;
+use axum::{extract::{Query, State}, response::IntoResponse};
+use crate::core::presentation::api::{
+    request::network::NetworkStatsQuery,
+    response::Response,
+    error::ErrorCode
+};
+use crate::core::application::ApplicationLayer;
+use crate::core::domain::network::entity::network_stats_entity::NetworkStats;
+
+/// # Description
+///     网络统计任务处理
+pub struct NetworkHandle;
+
+impl NetworkHandle {
+    /// # Description
+    ///     查询滑动窗口内的网络吞吐统计
+    /// # Param
+    ///     State(application_layer): State<Arc<ApplicationLayer>> - 应用层
+    ///     Query(query): Query<NetworkStatsQuery> - 窗口与粒度（秒）
+    /// # Return
+    ///     impl IntoResponse: 路由
+    pub async fn get_stats(
+        State(application_layer): State<Arc<ApplicationLayer>>,
+        Query(query): Query<NetworkStatsQuery>,
+    ) -> impl IntoResponse {
+        match application_layer
+            .network_application
+            .get_stats(query.window, query.granularity)
+            .await {
+            Ok(stats) => Response::<NetworkStats>::success(Some(stats)),
+            Err(e) => Response::<()>::failed(ErrorCode::ValidateError, e.to_string())
+        }
+    }
+}
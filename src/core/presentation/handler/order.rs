@@ -0,0 +1,117 @@
+use std::sync::Arc;
+use axum::{Json, extract::{Query, State}, response::IntoResponse};
+use crate::core::presentation::api::{
+    request::order::{PlaceOrderRequest, CancelOrderRequest, OrderQuery},
+    response::Response,
+    error::ErrorCode
+};
+use crate::core::application::ApplicationLayer;
+use crate::core::presentation::middleware::auth_middleware::AuthUser;
+use crate::core::application::order::OrderDetail;
+use crate::core::domain::order::entity::order_entity::Model as OrderModel;
+
+/// # Description
+///     条件单任务处理
+pub struct OrderHandle;
+
+impl OrderHandle {
+    /// # Description
+    ///     创建限价单 / 止损单
+    /// # Param
+    ///     State(application_layer): State<Arc<ApplicationLayer>> - 应用层
+    ///     auth_user: AuthUser - 当前登录用户
+    ///     Json(payload): Json<PlaceOrderRequest> - 请求 payload
+    /// # Return
+    ///     impl IntoResponse: 路由
+    pub async fn place_order(
+        State(application_layer): State<Arc<ApplicationLayer>>,
+        auth_user: AuthUser,
+        Json(payload): Json<PlaceOrderRequest>,
+    ) -> impl IntoResponse {
+        match application_layer
+            .order_application
+            .place_order(
+                auth_user.id,
+                payload.wallet_id,
+                payload.input_mint,
+                payload.output_mint,
+                payload.amount_in,
+                payload.trigger_price,
+                payload.direction,
+                payload.max_slippage,
+                payload.expires_at,
+            )
+            .await {
+            Ok(order) => Response::<OrderModel>::success(Some(order)),
+            Err(e) => Response::<()>::failed(ErrorCode::ValidateError, e.to_string())
+        }
+    }
+
+    /// # Description
+    ///     取消未触发的条件单
+    /// # Param
+    ///     State(application_layer): State<Arc<ApplicationLayer>> - 应用层
+    ///     auth_user: AuthUser - 当前登录用户
+    ///     Json(payload): Json<CancelOrderRequest> - 请求 payload
+    /// # Return
+    ///     impl IntoResponse: 路由
+    pub async fn cancel_order(
+        State(application_layer): State<Arc<ApplicationLayer>>,
+        auth_user: AuthUser,
+        Json(payload): Json<CancelOrderRequest>,
+    ) -> impl IntoResponse {
+        match application_layer
+            .order_application
+            .cancel_order(auth_user.id, payload.order_id)
+            .await {
+            Ok(order) => Response::<OrderModel>::success(Some(order)),
+            Err(e) => Response::<()>::failed(ErrorCode::DefaultError, e.to_string())
+        }
+    }
+
+    /// # Description
+    ///     查询用户的条件单列表
+    /// # Param
+    ///     State(application_layer): State<Arc<ApplicationLayer>> - 应用层
+    ///     auth_user: AuthUser - 当前登录用户
+    /// # Return
+    ///     impl IntoResponse: 路由
+    pub async fn list_orders(
+        State(application_layer): State<Arc<ApplicationLayer>>,
+        auth_user: AuthUser,
+    ) -> impl IntoResponse {
+        match application_layer
+            .order_application
+            .list_orders(auth_user.id)
+            .await {
+            Ok(orders) => Response::<Vec<OrderModel>>::success(Some(orders)),
+            Err(e) => Response::<()>::failed(ErrorCode::DefaultError, e.to_string())
+        }
+    }
+
+    /// # Description
+    ///     查询条件单详情及状态变更记录
+    /// # Param
+    ///     State(application_layer): State<Arc<ApplicationLayer>> - 应用层
+    ///     auth_user: AuthUser - 当前登录用户
+    ///     Query(query): Query<OrderQuery> - 查询参数
+    /// # Return
+    ///     impl IntoResponse: 路由
+    pub async fn get_order(
+        State(application_layer): State<Arc<ApplicationLayer>>,
+        auth_user: AuthUser,
+        Query(query): Query<OrderQuery>,
+    ) -> impl IntoResponse {
+        let Some(order_id) = query.order_id else {
+            return Response::<()>::failed(ErrorCode::ValidateError, "order_id 不能为空".to_string());
+        };
+
+        match application_layer
+            .order_application
+            .get_order(auth_user.id, order_id)
+            .await {
+            Ok(detail) => Response::<OrderDetail>::success(Some(detail)),
+            Err(_) => Response::<()>::failed(ErrorCode::NotFound, "订单不存在".to_string())
+        }
+    }
+}
@@ -0,0 +1,46 @@
+use std::sync::Arc;
+use axum::{extract::{Query, State}, response::IntoResponse};
+use crate::core::presentation::api::{
+    request::price::PriceQuery,
+    response::Response,
+    error::ErrorCode
+};
+use crate::core::application::ApplicationLayer;
+use crate::core::domain::price::entity::AggregatedPrice;
+
+/// # Description
+///     价格任务处理
+pub struct PriceHandle;
+
+impl PriceHandle {
+    /// # Description
+    ///     批量查询代币价格
+    /// # Param
+    ///     State(application_layer): State<Arc<ApplicationLayer>> - 应用层
+    ///     Query(query): Query<PriceQuery> - 逗号分隔的 mint 地址
+    /// # Return
+    ///     impl IntoResponse: 路由
+    pub async fn get_prices(
+        State(application_layer): State<Arc<ApplicationLayer>>,
+        Query(query): Query<PriceQuery>,
+    ) -> impl IntoResponse {
+        let mints: Vec<String> = query.mints
+            .split(',')
+            .map(|mint| mint.trim().to_string())
+            .filter(|mint| !mint.is_empty())
+            .collect();
+
+        if mints.is_empty() {
+            return Response::<()>::failed(ErrorCode::ValidateError, "mints 不能为空".to_string());
+        }
+
+        // 根据结果返回响应
+        match application_layer
+            .price_application
+            .get_prices(mints)
+            .await {
+            Ok(prices) => Response::<Vec<AggregatedPrice>>::success(Some(prices)),
+            Err(e) => Response::<()>::failed(ErrorCode::DefaultError, e.to_string())
+        }
+    }
+}
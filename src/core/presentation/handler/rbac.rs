@@ -0,0 +1,162 @@
+use std::sync::Arc;
+use axum::{Json, extract::{Query, State}, response::IntoResponse};
+use crate::core::presentation::api::{
+    request::rbac::{
+        CreateRoleRequest, UpdateRoleRequest, DeleteRoleRequest, CreatePermissionRequest, UserRoleQuery, AssignRolesRequest
+    },
+    response::Response,
+    error::ErrorCode
+};
+use crate::core::application::ApplicationLayer;
+use crate::core::application::rbac::{RoleDetail, UserRoles};
+use crate::core::domain::user::entity::permission_entity::Model as PermissionModel;
+
+/// # Description
+///     角色与权限管理处理，仅拥有 rbac:admin 权限的用户可访问
+pub struct RbacHandle;
+
+impl RbacHandle {
+    /// # Description
+    ///     查询全部角色及其权限
+    /// # Param
+    ///     State(application_layer): State<Arc<ApplicationLayer>> - 应用层
+    /// # Return
+    ///     impl IntoResponse: 路由
+    pub async fn list_roles(
+        State(application_layer): State<Arc<ApplicationLayer>>,
+    ) -> impl IntoResponse {
+        match application_layer.rbac_application.list_roles().await {
+            Ok(roles) => Response::<Vec<RoleDetail>>::success(Some(roles)),
+            Err(e) => Response::<()>::failed(ErrorCode::DefaultError, e.to_string())
+        }
+    }
+
+    /// # Description
+    ///     创建角色
+    /// # Param
+    ///     State(application_layer): State<Arc<ApplicationLayer>> - 应用层
+    ///     Json(payload): Json<CreateRoleRequest> - 请求 payload
+    /// # Return
+    ///     impl IntoResponse: 路由
+    pub async fn create_role(
+        State(application_layer): State<Arc<ApplicationLayer>>,
+        Json(payload): Json<CreateRoleRequest>,
+    ) -> impl IntoResponse {
+        match application_layer
+            .rbac_application
+            .create_role(payload.name, payload.description, payload.permissions)
+            .await {
+            Ok(role) => Response::<RoleDetail>::success(Some(role)),
+            Err(e) => Response::<()>::failed(ErrorCode::ValidateError, e.to_string())
+        }
+    }
+
+    /// # Description
+    ///     更新角色说明与权限
+    /// # Param
+    ///     State(application_layer): State<Arc<ApplicationLayer>> - 应用层
+    ///     Json(payload): Json<UpdateRoleRequest> - 请求 payload
+    /// # Return
+    ///     impl IntoResponse: 路由
+    pub async fn update_role(
+        State(application_layer): State<Arc<ApplicationLayer>>,
+        Json(payload): Json<UpdateRoleRequest>,
+    ) -> impl IntoResponse {
+        match application_layer
+            .rbac_application
+            .update_role(payload.role_id, payload.description, payload.permissions)
+            .await {
+            Ok(role) => Response::<RoleDetail>::success(Some(role)),
+            Err(e) => Response::<()>::failed(ErrorCode::ValidateError, e.to_string())
+        }
+    }
+
+    /// # Description
+    ///     删除角色
+    /// # Param
+    ///     State(application_layer): State<Arc<ApplicationLayer>> - 应用层
+    ///     Json(payload): Json<DeleteRoleRequest> - 请求 payload
+    /// # Return
+    ///     impl IntoResponse: 路由
+    pub async fn delete_role(
+        State(application_layer): State<Arc<ApplicationLayer>>,
+        Json(payload): Json<DeleteRoleRequest>,
+    ) -> impl IntoResponse {
+        match application_layer.rbac_application.delete_role(payload.role_id).await {
+            Ok(_) => Response::<()>::success(None),
+            Err(e) => Response::<()>::failed(ErrorCode::ValidateError, e.to_string())
+        }
+    }
+
+    /// # Description
+    ///     查询全部权限
+    /// # Param
+    ///     State(application_layer): State<Arc<ApplicationLayer>> - 应用层
+    /// # Return
+    ///     impl IntoResponse: 路由
+    pub async fn list_permissions(
+        State(application_layer): State<Arc<ApplicationLayer>>,
+    ) -> impl IntoResponse {
+        match application_layer.rbac_application.list_permissions().await {
+            Ok(permissions) => Response::<Vec<PermissionModel>>::success(Some(permissions)),
+            Err(e) => Response::<()>::failed(ErrorCode::DefaultError, e.to_string())
+        }
+    }
+
+    /// # Description
+    ///     创建权限
+    /// # Param
+    ///     State(application_layer): State<Arc<ApplicationLayer>> - 应用层
+    ///     Json(payload): Json<CreatePermissionRequest> - 请求 payload
+    /// # Return
+    ///     impl IntoResponse: 路由
+    pub async fn create_permission(
+        State(application_layer): State<Arc<ApplicationLayer>>,
+        Json(payload): Json<CreatePermissionRequest>,
+    ) -> impl IntoResponse {
+        match application_layer
+            .rbac_application
+            .create_permission(payload.code, payload.description)
+            .await {
+            Ok(permission) => Response::<PermissionModel>::success(Some(permission)),
+            Err(e) => Response::<()>::failed(ErrorCode::ValidateError, e.to_string())
+        }
+    }
+
+    /// # Description
+    ///     查询用户的角色与权限
+    /// # Param
+    ///     State(application_layer): State<Arc<ApplicationLayer>> - 应用层
+    ///     Query(query): Query<UserRoleQuery> - 查询参数
+    /// # Return
+    ///     impl IntoResponse: 路由
+    pub async fn user_roles(
+        State(application_layer): State<Arc<ApplicationLayer>>,
+        Query(query): Query<UserRoleQuery>,
+    ) -> impl IntoResponse {
+        match application_layer.rbac_application.user_roles(query.user_id).await {
+            Ok(roles) => Response::<UserRoles>::success(Some(roles)),
+            Err(e) => Response::<()>::failed(ErrorCode::NotFound, e.to_string())
+        }
+    }
+
+    /// # Description
+    ///     设置用户的角色
+    /// # Param
+    ///     State(application_layer): State<Arc<ApplicationLayer>> - 应用层
+    ///     Json(payload): Json<AssignRolesRequest> - 请求 payload
+    /// # Return
+    ///     impl IntoResponse: 路由
+    pub async fn assign_roles(
+        State(application_layer): State<Arc<ApplicationLayer>>,
+        Json(payload): Json<AssignRolesRequest>,
+    ) -> impl IntoResponse {
+        match application_layer
+            .rbac_application
+            .assign_roles(payload.user_id, payload.roles, None)
+            .await {
+            Ok(roles) => Response::<UserRoles>::success(Some(roles)),
+            Err(e) => Response::<()>::failed(ErrorCode::ValidateError, e.to_string())
+        }
+    }
+}
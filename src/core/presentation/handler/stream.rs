@@ -0,0 +1,184 @@
+use std::convert::Infallible;
+use std::sync::Arc;
+use std::time::Duration;
+use axum::{
+    extract::{Query, State, ws::{Message, WebSocket, WebSocketUpgrade}},
+    http::HeaderMap,
+    response::{IntoResponse, Response as AxumResponse, sse::{Event, KeepAlive, Sse}},
+};
+use futures::{stream, StreamExt};
+use tracing::warn;
+use crate::core::presentation::{
+    api::{
+        request::stream::StreamQuery,
+        response::Response,
+        error::ErrorCode
+    },
+    middleware::auth_middleware::AuthMiddleware,
+};
+use crate::core::application::ApplicationLayer;
+use crate::core::application::stream::StreamSubscription;
+use crate::core::application::user::UserApplication;
+use crate::core::domain::stream::entity::stream_event_entity::StreamEvent;
+
+/// # Description
+///     实时推送处理：同一地址同时支持 WebSocket 与 SSE，请求携带 Upgrade 头时建立 WebSocket，否则返回 SSE 流
+pub struct StreamHandle;
+
+impl StreamHandle {
+    /// # Description
+    ///     建立推送连接。访问令牌取自 Authorization: Bearer 头或 access_token 查询参数
+    ///     （浏览器的 WebSocket 与 EventSource 无法设置请求头）；topics 为逗号分隔的订阅主题。
+    ///     连接期间每个心跳周期重新校验一次令牌，令牌过期、会话被注销或账号被禁用后断开连接
+    /// # Param
+    ///     State(application_layer): State<Arc<ApplicationLayer>> - 应用层
+    ///     Query(query): Query<StreamQuery> - 查询参数
+    ///     headers: HeaderMap - 请求头
+    ///     upgrade: Option<WebSocketUpgrade> - WebSocket 升级请求
+    /// # Return
+    ///     AxumResponse: WebSocket 升级响应或 SSE 流
+    pub async fn stream(
+        State(application_layer): State<Arc<ApplicationLayer>>,
+        Query(query): Query<StreamQuery>,
+        headers: HeaderMap,
+        upgrade: Option<WebSocketUpgrade>,
+    ) -> AxumResponse {
+        let stream_application = application_layer.stream_application.clone();
+
+        let token = AuthMiddleware::bearer_token(&headers).or(query.access_token);
+        let Some(token) = token else {
+            return Response::<()>::failed_with_status(ErrorCode::TokenError, "缺少访问令牌".to_string()).into_response();
+        };
+        let user_id = match application_layer.user_application.authenticate(&token).await {
+            Ok(user) => user.id,
+            Err(e) => return Response::<()>::failed_with_status(ErrorCode::TokenError, e.to_string()).into_response(),
+        };
+        let subscription = match stream_application.subscribe(user_id, query.topics.as_deref()) {
+            Ok(subscription) => subscription,
+            Err(e) => return Response::<()>::failed(ErrorCode::ValidateError, e.to_string()).into_response(),
+        };
+
+        let heartbeat = stream_application.heartbeat();
+        let session = StreamSession { user_application: application_layer.user_application.clone(), token };
+        match upgrade {
+            Some(upgrade) => {
+                let send_timeout = stream_application.send_timeout();
+                upgrade.on_upgrade(move |socket| serve_socket(socket, subscription, session, heartbeat, send_timeout))
+            }
+            None => {
+                let first = subscription.subscribed();
+                let mut recheck = tokio::time::interval(heartbeat);
+                recheck.reset();
+                let events = stream::unfold((subscription, session, recheck), |(mut subscription, session, mut recheck)| async move {
+                    loop {
+                        tokio::select! {
+                            event = subscription.next() => return event.map(|event| (event, (subscription, session, recheck))),
+                            _ = recheck.tick() => {
+                                if !session.is_valid().await {
+                                    return None;
+                                }
+                            }
+                        }
+                    }
+                });
+                let events = stream::once(async move { first })
+                    .chain(events)
+                    .map(|event| Ok::<Event, Infallible>(sse_event(&event)));
+
+                Sse::new(events).keep_alive(KeepAlive::new().interval(heartbeat)).into_response()
+            }
+        }
+    }
+}
+
+/// # Description
+///     推送连接的会话，用于在连接期间重新校验访问令牌
+/// # Fields
+///     user_application: Arc<UserApplication> - 用户应用
+///     token: String - 建立连接时使用的访问令牌
+struct StreamSession {
+    user_application: Arc<UserApplication>,
+    token: String,
+}
+
+impl StreamSession {
+    /// # Description
+    ///     令牌是否仍然有效：未过期、未被注销且用户未被禁用或删除
+    async fn is_valid(&self) -> bool {
+        match self.user_application.authenticate(&self.token).await {
+            Ok(_) => true,
+            Err(e) => {
+                warn!("-PresentationLayer [Stream] session closed: {}", e);
+                false
+            }
+        }
+    }
+}
+
+/// # Description
+///     WebSocket 连接循环：转发订阅事件、处理客户端命令，并定时发送 ping 与重新校验令牌。
+///     发送超时、订阅积压过多或令牌失效时断开连接，避免慢连接占用服务端资源
+async fn serve_socket(mut socket: WebSocket, mut subscription: StreamSubscription, session: StreamSession, heartbeat: Duration, send_timeout: Duration) {
+    let mut ping = tokio::time::interval(heartbeat);
+    ping.tick().await;
+
+    if !send_event(&mut socket, &subscription.subscribed(), send_timeout).await {
+        return;
+    }
+
+    loop {
+        tokio::select! {
+            message = socket.recv() => match message {
+                Some(Ok(Message::Text(command))) => {
+                    let reply = subscription.handle_command(&command);
+                    if !send_event(&mut socket, &reply, send_timeout).await {
+                        break;
+                    }
+                }
+                Some(Ok(Message::Close(_))) | Some(Err(_)) | None => break,
+                Some(Ok(_)) => {}
+            },
+            event = subscription.next() => match event {
+                Some(event) => {
+                    if !send_event(&mut socket, &event, send_timeout).await {
+                        break;
+                    }
+                }
+                None => break,
+            },
+            _ = ping.tick() => {
+                if !session.is_valid().await {
+                    break;
+                }
+                if !matches!(tokio::time::timeout(send_timeout, socket.send(Message::Ping(Vec::new()))).await, Ok(Ok(()))) {
+                    break;
+                }
+            }
+        }
+    }
+
+    let _ = socket.send(Message::Close(None)).await;
+}
+
+/// # Description
+///     在超时时间内发送一条事件，失败返回 false
+async fn send_event(socket: &mut WebSocket, event: &StreamEvent, send_timeout: Duration) -> bool {
+    let text = match serde_json::to_string(event) {
+        Ok(text) => text,
+        Err(e) => {
+            warn!("-PresentationLayer [Stream] event serialize failed: {}", e);
+            return true;
+        }
+    };
+
+    matches!(tokio::time::timeout(send_timeout, socket.send(Message::Text(text))).await, Ok(Ok(())))
+}
+
+/// # Description
+///     事件转换为 SSE 消息，SSE 的 event 字段为主题
+fn sse_event(event: &StreamEvent) -> Event {
+    Event::default()
+        .event(event.topic.as_str())
+        .json_data(event)
+        .unwrap_or_else(|_| Event::default().comment("serialize failed"))
+}
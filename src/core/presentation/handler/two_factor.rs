@@ -0,0 +1,109 @@
+use std::sync::Arc;
+use axum::{Json, extract::State, response::IntoResponse};
+use crate::core::presentation::{
+    api::{
+        request::user::TwoFactorCodeRequest,
+        response::Response,
+        error::ErrorCode
+    },
+    middleware::auth_middleware::AuthUser,
+};
+use crate::core::application::ApplicationLayer;
+use crate::core::application::two_factor::{TwoFactorEnrollment, TwoFactorStatus};
+
+/// # Description
+///     两步验证设置处理，需要登录
+pub struct TwoFactorHandle;
+
+impl TwoFactorHandle {
+    /// # Description
+    ///     查询两步验证状态
+    /// # Param
+    ///     State(application_layer): State<Arc<ApplicationLayer>> - 应用层
+    ///     auth_user: AuthUser - 当前登录用户
+    /// # Return
+    ///     impl IntoResponse: 路由
+    pub async fn status(
+        State(application_layer): State<Arc<ApplicationLayer>>,
+        auth_user: AuthUser,
+    ) -> impl IntoResponse {
+        match application_layer.two_factor_application.status(auth_user.id).await {
+            Ok(status) => Response::<TwoFactorStatus>::success(Some(status)),
+            Err(e) => Response::<()>::failed(ErrorCode::DefaultError, e.to_string())
+        }
+    }
+
+    /// # Description
+    ///     登记两步验证，返回密钥与 otpauth 链接
+    /// # Param
+    ///     State(application_layer): State<Arc<ApplicationLayer>> - 应用层
+    ///     auth_user: AuthUser - 当前登录用户
+    /// # Return
+    ///     impl IntoResponse: 路由
+    pub async fn enroll(
+        State(application_layer): State<Arc<ApplicationLayer>>,
+        auth_user: AuthUser,
+    ) -> impl IntoResponse {
+        match application_layer.two_factor_application.enroll(auth_user.id, &auth_user.account).await {
+            Ok(enrollment) => Response::<TwoFactorEnrollment>::success(Some(enrollment)),
+            Err(e) => Response::<()>::failed(ErrorCode::DefaultError, e.to_string())
+        }
+    }
+
+    /// # Description
+    ///     确认开启两步验证，返回恢复码
+    /// # Param
+    ///     State(application_layer): State<Arc<ApplicationLayer>> - 应用层
+    ///     auth_user: AuthUser - 当前登录用户
+    ///     Json(payload): Json<TwoFactorCodeRequest> - 请求 payload
+    /// # Return
+    ///     impl IntoResponse: 路由
+    pub async fn confirm(
+        State(application_layer): State<Arc<ApplicationLayer>>,
+        auth_user: AuthUser,
+        Json(payload): Json<TwoFactorCodeRequest>,
+    ) -> impl IntoResponse {
+        match application_layer.two_factor_application.confirm(auth_user.id, &payload.code).await {
+            Ok(codes) => Response::<Vec<String>>::success(Some(codes)),
+            Err(e) => Response::<()>::failed(ErrorCode::ValidateError, e.to_string())
+        }
+    }
+
+    /// # Description
+    ///     关闭两步验证
+    /// # Param
+    ///     State(application_layer): State<Arc<ApplicationLayer>> - 应用层
+    ///     auth_user: AuthUser - 当前登录用户
+    ///     Json(payload): Json<TwoFactorCodeRequest> - 请求 payload
+    /// # Return
+    ///     impl IntoResponse: 路由
+    pub async fn disable(
+        State(application_layer): State<Arc<ApplicationLayer>>,
+        auth_user: AuthUser,
+        Json(payload): Json<TwoFactorCodeRequest>,
+    ) -> impl IntoResponse {
+        match application_layer.two_factor_application.disable(auth_user.id, &payload.code).await {
+            Ok(_) => Response::<()>::success(None),
+            Err(e) => Response::<()>::failed(ErrorCode::ValidateError, e.to_string())
+        }
+    }
+
+    /// # Description
+    ///     重新生成恢复码
+    /// # Param
+    ///     State(application_layer): State<Arc<ApplicationLayer>> - 应用层
+    ///     auth_user: AuthUser - 当前登录用户
+    ///     Json(payload): Json<TwoFactorCodeRequest> - 请求 payload
+    /// # Return
+    ///     impl IntoResponse: 路由
+    pub async fn regenerate_backup_codes(
+        State(application_layer): State<Arc<ApplicationLayer>>,
+        auth_user: AuthUser,
+        Json(payload): Json<TwoFactorCodeRequest>,
+    ) -> impl IntoResponse {
+        match application_layer.two_factor_application.regenerate_backup_codes(auth_user.id, &payload.code).await {
+            Ok(codes) => Response::<Vec<String>>::success(Some(codes)),
+            Err(e) => Response::<()>::failed(ErrorCode::ValidateError, e.to_string())
+        }
+    }
+}
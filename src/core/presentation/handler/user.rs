@@ -1,16 +1,22 @@
 use std::sync::Arc;
-use axum::{Json, extract::State, response::IntoResponse};
-use tracing::info;
+use axum::{Json, extract::State, http::{HeaderMap, HeaderValue, header::RETRY_AFTER}, response::IntoResponse};
 use crate::core::presentation::{
     api::{
         request::{
-            user::{RegisterRequest, LoginRequest}
+            user::{
+                RegisterRequest, LoginRequest, RefreshTokenRequest, EmailCodeRequest, ForgotPasswordRequest, ResetPasswordRequest,
+                SmsCodeRequest, OtpLoginRequest, TwoFactorLoginRequest, UpdateProfileRequest, ChangePasswordRequest,
+                DeleteAccountRequest
+            }
         },
         response::Response, error::ErrorCode
-    }
+    },
+    middleware::{auth_middleware::{AuthMiddleware, AuthUser}, client_ip::ClientIp},
 };
 use crate::core::application::ApplicationLayer;
-use crate::core::infrastructure::jwt::TokenOutPut;
+use crate::core::application::user::{LoginOutcome, UserProfile, UserSession};
+use crate::core::domain::throttle::entity::throttle_entity::LoginThrottled;
+use crate::core::infrastructure::jwt::{JsonWebKeySet, TokenOutPut};
 
 /// # Description
 ///     用户任务处理
@@ -31,7 +37,7 @@ impl UserHandle {
         // 根据结果返回响应
         match application_layer
             .user_application
-            .register_user(payload.identifier, payload.password)
+            .register_user(payload.identifier, payload.password, payload.code)
             .await {
                 Ok(_) => Response::<String>::success(Some("".to_string())),
                 Err(e) => Response::<()>::failed(ErrorCode::DefaultError, e.to_string())
@@ -40,32 +46,326 @@ impl UserHandle {
 
 
     /// # Description
-    ///     用户登陆
+    ///     发送注册验证码到邮箱
+    /// # Param
+    ///     State(application_layer): State<Arc<ApplicationLayer>> - 应用层
+    ///     Json(payload): Json<EmailCodeRequest> - 请求 payload
+    /// # Return
+    ///     impl IntoResponse: 路由
+    pub async fn send_email_code(
+        State(application_layer): State<Arc<ApplicationLayer>>,
+        Json(payload): Json<EmailCodeRequest>,
+    ) -> impl IntoResponse {
+        match application_layer
+            .user_application
+            .send_register_code(payload.email)
+            .await {
+                Ok(_) => Response::<()>::success(None),
+                Err(e) => Response::<()>::failed(ErrorCode::DefaultError, e.to_string())
+        }
+    }
+
+    /// # Description
+    ///     发送注册或登录验证码到手机号
+    /// # Param
+    ///     State(application_layer): State<Arc<ApplicationLayer>> - 应用层
+    ///     Json(payload): Json<SmsCodeRequest> - 请求 payload
+    /// # Return
+    ///     impl IntoResponse: 路由
+    pub async fn send_sms_code(
+        State(application_layer): State<Arc<ApplicationLayer>>,
+        Json(payload): Json<SmsCodeRequest>,
+    ) -> impl IntoResponse {
+        match application_layer
+            .user_application
+            .send_sms_code(payload.mobile, payload.purpose)
+            .await {
+                Ok(_) => Response::<()>::success(None),
+                Err(e) => Response::<()>::failed(ErrorCode::DefaultError, e.to_string())
+        }
+    }
+
+    /// # Description
+    ///     忘记密码：发送重置密码链接到邮箱
+    /// # Param
+    ///     State(application_layer): State<Arc<ApplicationLayer>> - 应用层
+    ///     Json(payload): Json<ForgotPasswordRequest> - 请求 payload
+    /// # Return
+    ///     impl IntoResponse: 路由
+    pub async fn forgot_password(
+        State(application_layer): State<Arc<ApplicationLayer>>,
+        Json(payload): Json<ForgotPasswordRequest>,
+    ) -> impl IntoResponse {
+        match application_layer
+            .user_application
+            .forgot_password(payload.email)
+            .await {
+                Ok(_) => Response::<()>::success(None),
+                Err(e) => Response::<()>::failed(ErrorCode::DefaultError, e.to_string())
+        }
+    }
+
+    /// # Description
+    ///     使用邮件中的令牌重置密码
+    /// # Param
+    ///     State(application_layer): State<Arc<ApplicationLayer>> - 应用层
+    ///     Json(payload): Json<ResetPasswordRequest> - 请求 payload
+    /// # Return
+    ///     impl IntoResponse: 路由
+    pub async fn reset_password(
+        State(application_layer): State<Arc<ApplicationLayer>>,
+        Json(payload): Json<ResetPasswordRequest>,
+    ) -> impl IntoResponse {
+        match application_layer
+            .user_application
+            .reset_password(payload.token, payload.new_password)
+            .await {
+                Ok(_) => Response::<()>::success(None),
+                Err(e) => Response::<()>::failed(ErrorCode::ValidateError, e.to_string())
+        }
+    }
+
+    /// # Description
+    ///     用户登陆，失败次数过多被限流时返回 HTTP 429 与 Retry-After
     /// # Param
-    ///     None
+    ///     State(application_layer): State<Arc<ApplicationLayer>> - 应用层
+    ///     ClientIp(ip): ClientIp - 客户端 IP
+    ///     Json(payload): Json<LoginRequest> - 请求 payload
     /// # Return
-    ///     Router: 路由
+    ///     impl IntoResponse: 路由
     pub async fn login(
         State(application_layer): State<Arc<ApplicationLayer>>,
+        ClientIp(ip): ClientIp,
         Json(payload): Json<LoginRequest>,
     ) -> impl IntoResponse {
         // 根据结果返回响应
         match application_layer
             .user_application
-            .login_user(payload.identifier, payload.password)
+            .login_user(payload.identifier, payload.password, ip)
+            .await {
+                Ok(value) => Response::<LoginOutcome>::success(Some(value)).into_response(),
+                Err(e) => match e.downcast_ref::<LoginThrottled>() {
+                    Some(throttled) => {
+                        let mut response = Response::<()>::failed_with_status(ErrorCode::TooManyRequests, e.to_string()).into_response();
+                        response.headers_mut().insert(RETRY_AFTER, HeaderValue::from(throttled.retry_after));
+                        response
+                    }
+                    None => Response::<()>::failed(ErrorCode::DefaultError, e.to_string()).into_response(),
+                }
+            }
+    }
+
+    /// # Description
+    ///     短信验证码登录
+    /// # Param
+    ///     State(application_layer): State<Arc<ApplicationLayer>> - 应用层
+    ///     Json(payload): Json<OtpLoginRequest> - 请求 payload
+    /// # Return
+    ///     impl IntoResponse: 路由
+    pub async fn otp_login(
+        State(application_layer): State<Arc<ApplicationLayer>>,
+        Json(payload): Json<OtpLoginRequest>,
+    ) -> impl IntoResponse {
+        match application_layer
+            .user_application
+            .login_with_otp(payload.mobile, payload.code)
+            .await {
+                Ok(value) => Response::<LoginOutcome>::success(Some(value)),
+                Err(e) => Response::<()>::failed(ErrorCode::DefaultError, e.to_string())
+            }
+    }
+
+    /// # Description
+    ///     提交两步验证码完成登录
+    /// # Param
+    ///     State(application_layer): State<Arc<ApplicationLayer>> - 应用层
+    ///     Json(payload): Json<TwoFactorLoginRequest> - 请求 payload
+    /// # Return
+    ///     impl IntoResponse: 路由
+    pub async fn login_two_factor(
+        State(application_layer): State<Arc<ApplicationLayer>>,
+        Json(payload): Json<TwoFactorLoginRequest>,
+    ) -> impl IntoResponse {
+        match application_layer
+            .user_application
+            .login_two_factor(payload.challenge_token, payload.code)
             .await {
                 Ok(value) => Response::<TokenOutPut>::success(Some(value)),
+                Err(e) => Response::<()>::failed(ErrorCode::TokenError, e.to_string())
+            }
+    }
+
+    /// # Description
+    ///     刷新令牌：使用刷新令牌换取新的访问令牌与刷新令牌
+    /// # Param
+    ///     State(application_layer): State<Arc<ApplicationLayer>> - 应用层
+    ///     Json(payload): Json<RefreshTokenRequest> - 请求 payload
+    /// # Return
+    ///     impl IntoResponse: 路由
+    pub async fn refresh(
+        State(application_layer): State<Arc<ApplicationLayer>>,
+        Json(payload): Json<RefreshTokenRequest>,
+    ) -> impl IntoResponse {
+        match application_layer
+            .user_application
+            .refresh_token(&payload.refresh_token)
+            .await {
+                Ok(value) => Response::<TokenOutPut>::success(Some(value)),
+                Err(e) => Response::<()>::failed(ErrorCode::TokenError, e.to_string())
+            }
+    }
+
+    /// # Description
+    ///     退出登录：当前访问令牌加入黑名单，并作废对应的刷新令牌
+    /// # Param
+    ///     State(application_layer): State<Arc<ApplicationLayer>> - 应用层
+    ///     headers: HeaderMap - 请求头
+    /// # Return
+    ///     impl IntoResponse: 路由
+    pub async fn logout(
+        State(application_layer): State<Arc<ApplicationLayer>>,
+        headers: HeaderMap,
+    ) -> impl IntoResponse {
+        let Some(token) = AuthMiddleware::bearer_token(&headers) else {
+            return Response::<()>::failed(ErrorCode::TokenError, "缺少访问令牌".to_string());
+        };
+
+        match application_layer
+            .user_application
+            .logout(&token)
+            .await {
+                Ok(_) => Response::<()>::success(None),
                 Err(e) => Response::<()>::failed(ErrorCode::DefaultError, e.to_string())
             }
     }
 
     /// # Description
-    ///     用户找回
+    ///     JWKS：公布验签公钥，按 JWKS 标准格式直接返回，不包裹统一响应结构
+    /// # Param
+    ///     State(application_layer): State<Arc<ApplicationLayer>> - 应用层
+    /// # Return
+    ///     Json<JsonWebKeySet>: JWKS 文档
+    pub async fn jwks(
+        State(application_layer): State<Arc<ApplicationLayer>>,
+    ) -> Json<JsonWebKeySet> {
+        Json(application_layer.user_application.jwks())
+    }
+
+    /// # Description
+    ///     查询当前用户的资料
+    /// # Param
+    ///     State(application_layer): State<Arc<ApplicationLayer>> - 应用层
+    ///     auth_user: AuthUser - 当前登录用户
+    /// # Return
+    ///     impl IntoResponse: 路由
+    pub async fn retrieve(
+        State(application_layer): State<Arc<ApplicationLayer>>,
+        auth_user: AuthUser,
+    ) -> impl IntoResponse {
+        match application_layer.user_application.profile(auth_user.id).await {
+            Ok(profile) => Response::<UserProfile>::success(Some(profile)),
+            Err(e) => Response::<()>::failed(ErrorCode::NotFound, e.to_string())
+        }
+    }
+
+    /// # Description
+    ///     修改昵称、头像或手机号
     /// # Param
-    ///     None
+    ///     State(application_layer): State<Arc<ApplicationLayer>> - 应用层
+    ///     auth_user: AuthUser - 当前登录用户
+    ///     Json(payload): Json<UpdateProfileRequest> - 请求 payload
     /// # Return
-    ///     Router: 路由
-    pub async fn retrieve() {
-        info!("+ [Router] Retrieve handle");
+    ///     impl IntoResponse: 路由
+    pub async fn update_profile(
+        State(application_layer): State<Arc<ApplicationLayer>>,
+        auth_user: AuthUser,
+        Json(payload): Json<UpdateProfileRequest>,
+    ) -> impl IntoResponse {
+        match application_layer
+            .user_application
+            .update_profile(auth_user.id, payload.nickname, payload.avatar, payload.mobile, payload.code)
+            .await {
+                Ok(profile) => Response::<UserProfile>::success(Some(profile)),
+                Err(e) => Response::<()>::failed(ErrorCode::ValidateError, e.to_string())
+            }
+    }
+
+    /// # Description
+    ///     修改密码，其他会话随之下线
+    /// # Param
+    ///     State(application_layer): State<Arc<ApplicationLayer>> - 应用层
+    ///     auth_user: AuthUser - 当前登录用户
+    ///     headers: HeaderMap - 请求头
+    ///     Json(payload): Json<ChangePasswordRequest> - 请求 payload
+    /// # Return
+    ///     impl IntoResponse: 路由
+    pub async fn change_password(
+        State(application_layer): State<Arc<ApplicationLayer>>,
+        auth_user: AuthUser,
+        headers: HeaderMap,
+        Json(payload): Json<ChangePasswordRequest>,
+    ) -> impl IntoResponse {
+        let Some(token) = AuthMiddleware::bearer_token(&headers) else {
+            return Response::<()>::failed(ErrorCode::TokenError, "缺少访问令牌".to_string());
+        };
+
+        match application_layer
+            .user_application
+            .change_password(auth_user.id, &token, payload.old_password, payload.new_password)
+            .await {
+                Ok(_) => Response::<()>::success(None),
+                Err(e) => Response::<()>::failed(ErrorCode::ValidateError, e.to_string())
+            }
+    }
+
+    /// # Description
+    ///     注销当前账号
+    /// # Param
+    ///     State(application_layer): State<Arc<ApplicationLayer>> - 应用层
+    ///     auth_user: AuthUser - 当前登录用户
+    ///     headers: HeaderMap - 请求头
+    ///     Json(payload): Json<DeleteAccountRequest> - 请求 payload
+    /// # Return
+    ///     impl IntoResponse: 路由
+    pub async fn delete_account(
+        State(application_layer): State<Arc<ApplicationLayer>>,
+        auth_user: AuthUser,
+        headers: HeaderMap,
+        Json(payload): Json<DeleteAccountRequest>,
+    ) -> impl IntoResponse {
+        let Some(token) = AuthMiddleware::bearer_token(&headers) else {
+            return Response::<()>::failed(ErrorCode::TokenError, "缺少访问令牌".to_string());
+        };
+
+        match application_layer
+            .user_application
+            .delete_account(auth_user.id, &token, payload.password)
+            .await {
+                Ok(_) => Response::<()>::success(None),
+                Err(e) => Response::<()>::failed(ErrorCode::DefaultError, e.to_string())
+            }
+    }
+
+    /// # Description
+    ///     列出当前用户的登录会话
+    /// # Param
+    ///     State(application_layer): State<Arc<ApplicationLayer>> - 应用层
+    ///     auth_user: AuthUser - 当前登录用户
+    ///     headers: HeaderMap - 请求头
+    /// # Return
+    ///     impl IntoResponse: 路由
+    pub async fn sessions(
+        State(application_layer): State<Arc<ApplicationLayer>>,
+        auth_user: AuthUser,
+        headers: HeaderMap,
+    ) -> impl IntoResponse {
+        let Some(token) = AuthMiddleware::bearer_token(&headers) else {
+            return Response::<()>::failed(ErrorCode::TokenError, "缺少访问令牌".to_string());
+        };
+
+        match application_layer.user_application.sessions(auth_user.id, &token).await {
+            Ok(sessions) => Response::<Vec<UserSession>>::success(Some(sessions)),
+            Err(e) => Response::<()>::failed(ErrorCode::DefaultError, e.to_string())
+        }
     }
 }
\ No newline at end of file
@@ -2,14 +2,14 @@ use std::sync::Arc;
 use axum::{Json, extract::State, response::IntoResponse};
 use crate::core::presentation::{
     api::{
-        request::{
-            wallet::GenerationRequest,
-        },
+        request::wallet::ExportKeyRequest,
         response::Response,
         error::ErrorCode
     },
+    middleware::auth_middleware::AuthUser,
 };
 use crate::core::application::ApplicationLayer;
+use crate::core::application::wallet::ExportedKey;
 
 /// # Description
 ///     钱包任务处理
@@ -20,22 +20,42 @@ impl WalletHandle {
     ///     钱包生成
     /// # Param
     ///     State(application_layer): State<Arc<ApplicationLayer>> - 应用层
-    ///     Json(payload): Json<RegisterRequest> - 请求 payload
+    ///     auth_user: AuthUser - 当前登录用户
     /// # Return
     ///     impl IntoResponse: 路由
     pub async fn generation_wallet(
         State(application_layer): State<Arc<ApplicationLayer>>,
-        Json(_payload): Json<GenerationRequest>,
+        auth_user: AuthUser,
     ) -> impl IntoResponse {
         // 根据结果返回响应
         match application_layer
             .wallet_application
-            .generation_wallet()
+            .generation_wallet(auth_user.id)
             .await {
             Ok(_) => Response::<String>::success(Some("".to_string())),
             Err(e) => Response::<()>::failed(ErrorCode::DefaultError, e.to_string())
         }
     }
 
-
+    /// # Description
+    ///     导出钱包私钥，需要两步验证
+    /// # Param
+    ///     State(application_layer): State<Arc<ApplicationLayer>> - 应用层
+    ///     auth_user: AuthUser - 当前登录用户
+    ///     Json(payload): Json<ExportKeyRequest> - 请求 payload
+    /// # Return
+    ///     impl IntoResponse: 路由
+    pub async fn export_key(
+        State(application_layer): State<Arc<ApplicationLayer>>,
+        auth_user: AuthUser,
+        Json(payload): Json<ExportKeyRequest>,
+    ) -> impl IntoResponse {
+        match application_layer
+            .wallet_application
+            .export_key(auth_user.id, payload.wallet_id)
+            .await {
+            Ok(key) => Response::<ExportedKey>::success(Some(key)),
+            Err(e) => Response::<()>::failed(ErrorCode::NotFound, e.to_string())
+        }
+    }
 }
\ No newline at end of file
@@ -0,0 +1,227 @@
+use std::sync::Arc;
+use axum::{Json, extract::{Query, State}, response::IntoResponse};
+use crate::core::presentation::api::{
+    request::webhook::{
+        CreateWebhookRequest, DeleteWebhookRequest, DeliveryQuery, ReplayDeliveryRequest,
+        AdminCreateWebhookRequest, AdminDeleteWebhookRequest, AdminDeliveryQuery, AdminReplayDeliveryRequest
+    },
+    response::Response,
+    error::ErrorCode
+};
+use crate::core::application::ApplicationLayer;
+use crate::core::presentation::middleware::auth_middleware::AuthUser;
+use crate::core::application::webhook::WebhookEndpointCreated;
+use crate::core::domain::webhook::entity::webhook_endpoint_entity::Model as WebhookEndpointModel;
+use crate::core::domain::webhook::entity::webhook_delivery_entity::Model as WebhookDeliveryModel;
+
+/// # Description
+///     Webhook 订阅与投递记录处理，管理员接口操作 user_id 为空的全局端点
+pub struct WebhookHandle;
+
+impl WebhookHandle {
+    /// # Description
+    ///     创建订阅端点，签名密钥仅在创建时返回一次
+    /// # Param
+    ///     State(application_layer): State<Arc<ApplicationLayer>> - 应用层
+    ///     auth_user: AuthUser - 当前登录用户
+    ///     Json(payload): Json<CreateWebhookRequest> - 请求 payload
+    /// # Return
+    ///     impl IntoResponse: 路由
+    pub async fn create_endpoint(
+        State(application_layer): State<Arc<ApplicationLayer>>,
+        auth_user: AuthUser,
+        Json(payload): Json<CreateWebhookRequest>,
+    ) -> impl IntoResponse {
+        match application_layer
+            .webhook_application
+            .create_endpoint(Some(auth_user.id), payload.url, payload.events, payload.balance_threshold)
+            .await {
+            Ok(created) => Response::<WebhookEndpointCreated>::success(Some(created)),
+            Err(e) => Response::<()>::failed(ErrorCode::ValidateError, e.to_string())
+        }
+    }
+
+    /// # Description
+    ///     删除订阅端点
+    /// # Param
+    ///     State(application_layer): State<Arc<ApplicationLayer>> - 应用层
+    ///     auth_user: AuthUser - 当前登录用户
+    ///     Json(payload): Json<DeleteWebhookRequest> - 请求 payload
+    /// # Return
+    ///     impl IntoResponse: 路由
+    pub async fn delete_endpoint(
+        State(application_layer): State<Arc<ApplicationLayer>>,
+        auth_user: AuthUser,
+        Json(payload): Json<DeleteWebhookRequest>,
+    ) -> impl IntoResponse {
+        match application_layer
+            .webhook_application
+            .delete_endpoint(Some(auth_user.id), payload.endpoint_id)
+            .await {
+            Ok(_) => Response::<()>::success(None),
+            Err(e) => Response::<()>::failed(ErrorCode::NotFound, e.to_string())
+        }
+    }
+
+    /// # Description
+    ///     查询用户的订阅端点
+    /// # Param
+    ///     State(application_layer): State<Arc<ApplicationLayer>> - 应用层
+    ///     auth_user: AuthUser - 当前登录用户
+    /// # Return
+    ///     impl IntoResponse: 路由
+    pub async fn list_endpoints(
+        State(application_layer): State<Arc<ApplicationLayer>>,
+        auth_user: AuthUser,
+    ) -> impl IntoResponse {
+        match application_layer
+            .webhook_application
+            .list_endpoints(Some(auth_user.id))
+            .await {
+            Ok(endpoints) => Response::<Vec<WebhookEndpointModel>>::success(Some(endpoints)),
+            Err(e) => Response::<()>::failed(ErrorCode::DefaultError, e.to_string())
+        }
+    }
+
+    /// # Description
+    ///     查询订阅端点的投递记录
+    /// # Param
+    ///     State(application_layer): State<Arc<ApplicationLayer>> - 应用层
+    ///     auth_user: AuthUser - 当前登录用户
+    ///     Query(query): Query<DeliveryQuery> - 查询参数
+    /// # Return
+    ///     impl IntoResponse: 路由
+    pub async fn list_deliveries(
+        State(application_layer): State<Arc<ApplicationLayer>>,
+        auth_user: AuthUser,
+        Query(query): Query<DeliveryQuery>,
+    ) -> impl IntoResponse {
+        match application_layer
+            .webhook_application
+            .list_deliveries(Some(auth_user.id), query.endpoint_id, query.limit)
+            .await {
+            Ok(deliveries) => Response::<Vec<WebhookDeliveryModel>>::success(Some(deliveries)),
+            Err(e) => Response::<()>::failed(ErrorCode::NotFound, e.to_string())
+        }
+    }
+
+    /// # Description
+    ///     重放一次投递，生成新的投递记录并立即投递
+    /// # Param
+    ///     State(application_layer): State<Arc<ApplicationLayer>> - 应用层
+    ///     auth_user: AuthUser - 当前登录用户
+    ///     Json(payload): Json<ReplayDeliveryRequest> - 请求 payload
+    /// # Return
+    ///     impl IntoResponse: 路由
+    pub async fn replay_delivery(
+        State(application_layer): State<Arc<ApplicationLayer>>,
+        auth_user: AuthUser,
+        Json(payload): Json<ReplayDeliveryRequest>,
+    ) -> impl IntoResponse {
+        match application_layer
+            .webhook_application
+            .replay(Some(auth_user.id), payload.delivery_id)
+            .await {
+            Ok(delivery) => Response::<WebhookDeliveryModel>::success(Some(delivery)),
+            Err(e) => Response::<()>::failed(ErrorCode::NotFound, e.to_string())
+        }
+    }
+
+    /// # Description
+    ///     创建管理员订阅端点，接收所有用户的事件
+    /// # Param
+    ///     State(application_layer): State<Arc<ApplicationLayer>> - 应用层
+    ///     Json(payload): Json<AdminCreateWebhookRequest> - 请求 payload
+    /// # Return
+    ///     impl IntoResponse: 路由
+    pub async fn admin_create_endpoint(
+        State(application_layer): State<Arc<ApplicationLayer>>,
+        Json(payload): Json<AdminCreateWebhookRequest>,
+    ) -> impl IntoResponse {
+        match application_layer
+            .webhook_application
+            .create_endpoint(None, payload.url, payload.events, None)
+            .await {
+            Ok(created) => Response::<WebhookEndpointCreated>::success(Some(created)),
+            Err(e) => Response::<()>::failed(ErrorCode::ValidateError, e.to_string())
+        }
+    }
+
+    /// # Description
+    ///     删除管理员订阅端点
+    /// # Param
+    ///     State(application_layer): State<Arc<ApplicationLayer>> - 应用层
+    ///     Json(payload): Json<AdminDeleteWebhookRequest> - 请求 payload
+    /// # Return
+    ///     impl IntoResponse: 路由
+    pub async fn admin_delete_endpoint(
+        State(application_layer): State<Arc<ApplicationLayer>>,
+        Json(payload): Json<AdminDeleteWebhookRequest>,
+    ) -> impl IntoResponse {
+        match application_layer
+            .webhook_application
+            .delete_endpoint(None, payload.endpoint_id)
+            .await {
+            Ok(_) => Response::<()>::success(None),
+            Err(e) => Response::<()>::failed(ErrorCode::NotFound, e.to_string())
+        }
+    }
+
+    /// # Description
+    ///     查询管理员订阅端点
+    /// # Param
+    ///     State(application_layer): State<Arc<ApplicationLayer>> - 应用层
+    /// # Return
+    ///     impl IntoResponse: 路由
+    pub async fn admin_list_endpoints(
+        State(application_layer): State<Arc<ApplicationLayer>>,
+    ) -> impl IntoResponse {
+        match application_layer
+            .webhook_application
+            .list_endpoints(None)
+            .await {
+            Ok(endpoints) => Response::<Vec<WebhookEndpointModel>>::success(Some(endpoints)),
+            Err(e) => Response::<()>::failed(ErrorCode::DefaultError, e.to_string())
+        }
+    }
+
+    /// # Description
+    ///     查询管理员订阅端点的投递记录
+    /// # Param
+    ///     State(application_layer): State<Arc<ApplicationLayer>> - 应用层
+    ///     Query(query): Query<AdminDeliveryQuery> - 查询参数
+    /// # Return
+    ///     impl IntoResponse: 路由
+    pub async fn admin_list_deliveries(
+        State(application_layer): State<Arc<ApplicationLayer>>,
+        Query(query): Query<AdminDeliveryQuery>,
+    ) -> impl IntoResponse {
+        match application_layer
+            .webhook_application
+            .list_deliveries(None, query.endpoint_id, query.limit)
+            .await {
+            Ok(deliveries) => Response::<Vec<WebhookDeliveryModel>>::success(Some(deliveries)),
+            Err(e) => Response::<()>::failed(ErrorCode::NotFound, e.to_string())
+        }
+    }
+
+    /// # Description
+    ///     重放管理员订阅端点的一次投递
+    /// # Param
+    ///     State(application_layer): State<Arc<ApplicationLayer>> - 应用层
+    ///     Json(payload): Json<AdminReplayDeliveryRequest> - 请求 payload
+    /// # Return
+    ///     impl IntoResponse: 路由
+    pub async fn admin_replay_delivery(
+        State(application_layer): State<Arc<ApplicationLayer>>,
+        Json(payload): Json<AdminReplayDeliveryRequest>,
+    ) -> impl IntoResponse {
+        match application_layer
+            .webhook_application
+            .replay(None, payload.delivery_id)
+            .await {
+            Ok(delivery) => Response::<WebhookDeliveryModel>::success(Some(delivery)),
+            Err(e) => Response::<()>::failed(ErrorCode::NotFound, e.to_string())
+        }
+    }
+}
@@ -0,0 +1,98 @@
+use std::sync::Arc;
+use axum::{Json, extract::State, response::IntoResponse};
+use crate::core::presentation::{
+    api::{
+        request::withdrawal::{AddWhitelistRequest, RemoveWhitelistRequest, WithdrawRequest},
+        response::Response,
+        error::ErrorCode
+    },
+    middleware::auth_middleware::AuthUser,
+};
+use crate::core::application::ApplicationLayer;
+use crate::core::domain::withdrawal::entity::whitelist_address_entity::Model as WhitelistAddressModel;
+
+/// # Description
+///     提现处理，添加白名单地址与提现需要两步验证
+pub struct WithdrawalHandle;
+
+impl WithdrawalHandle {
+    /// # Description
+    ///     查询提现白名单
+    /// # Param
+    ///     State(application_layer): State<Arc<ApplicationLayer>> - 应用层
+    ///     auth_user: AuthUser - 当前登录用户
+    /// # Return
+    ///     impl IntoResponse: 路由
+    pub async fn list_whitelist(
+        State(application_layer): State<Arc<ApplicationLayer>>,
+        auth_user: AuthUser,
+    ) -> impl IntoResponse {
+        match application_layer.withdrawal_application.list_whitelist(auth_user.id).await {
+            Ok(addresses) => Response::<Vec<WhitelistAddressModel>>::success(Some(addresses)),
+            Err(e) => Response::<()>::failed(ErrorCode::DefaultError, e.to_string())
+        }
+    }
+
+    /// # Description
+    ///     添加白名单地址
+    /// # Param
+    ///     State(application_layer): State<Arc<ApplicationLayer>> - 应用层
+    ///     auth_user: AuthUser - 当前登录用户
+    ///     Json(payload): Json<AddWhitelistRequest> - 请求 payload
+    /// # Return
+    ///     impl IntoResponse: 路由
+    pub async fn add_whitelist(
+        State(application_layer): State<Arc<ApplicationLayer>>,
+        auth_user: AuthUser,
+        Json(payload): Json<AddWhitelistRequest>,
+    ) -> impl IntoResponse {
+        match application_layer
+            .withdrawal_application
+            .add_whitelist(auth_user.id, payload.address, payload.label)
+            .await {
+            Ok(address) => Response::<WhitelistAddressModel>::success(Some(address)),
+            Err(e) => Response::<()>::failed(ErrorCode::ValidateError, e.to_string())
+        }
+    }
+
+    /// # Description
+    ///     删除白名单地址
+    /// # Param
+    ///     State(application_layer): State<Arc<ApplicationLayer>> - 应用层
+    ///     auth_user: AuthUser - 当前登录用户
+    ///     Json(payload): Json<RemoveWhitelistRequest> - 请求 payload
+    /// # Return
+    ///     impl IntoResponse: 路由
+    pub async fn remove_whitelist(
+        State(application_layer): State<Arc<ApplicationLayer>>,
+        auth_user: AuthUser,
+        Json(payload): Json<RemoveWhitelistRequest>,
+    ) -> impl IntoResponse {
+        match application_layer.withdrawal_application.remove_whitelist(auth_user.id, payload.id).await {
+            Ok(_) => Response::<()>::success(None),
+            Err(e) => Response::<()>::failed(ErrorCode::NotFound, e.to_string())
+        }
+    }
+
+    /// # Description
+    ///     提现到白名单地址
+    /// # Param
+    ///     State(application_layer): State<Arc<ApplicationLayer>> - 应用层
+    ///     auth_user: AuthUser - 当前登录用户
+    ///     Json(payload): Json<WithdrawRequest> - 请求 payload
+    /// # Return
+    ///     impl IntoResponse: 路由
+    pub async fn withdraw(
+        State(application_layer): State<Arc<ApplicationLayer>>,
+        auth_user: AuthUser,
+        Json(payload): Json<WithdrawRequest>,
+    ) -> impl IntoResponse {
+        match application_layer
+            .withdrawal_application
+            .withdraw(auth_user.id, payload.wallet_id, payload.address, payload.amount)
+            .await {
+            Ok(signature) => Response::<String>::success(Some(signature)),
+            Err(e) => Response::<()>::failed(ErrorCode::DefaultError, e.to_string())
+        }
+    }
+}
@@ -1,9 +1,52 @@
+use std::sync::Arc;
 use axum::{
-    response::Response,
-    middleware::{Next},
-    extract::Request,
+    async_trait,
+    extract::{FromRequestParts, Request, State},
+    http::{HeaderMap, StatusCode, header::AUTHORIZATION, request::Parts},
+    middleware::Next,
+    response::{IntoResponse, Response as AxumResponse},
+    Json,
 };
-use tracing::info;
+use serde_json::Value;
+use tracing::{error, warn};
+use crate::core::application::ApplicationLayer;
+use crate::core::domain::user::entity::user_entity::Model as UserModel;
+use crate::core::presentation::api::{response::Response, error::ErrorCode};
+
+/// # Description
+///     已通过认证的用户，由认证中间件写入请求扩展，处理函数通过提取器获取
+/// # Fields
+///     id: i32 - 用户id
+///     account: String - 用户账号
+///     level: i8 - 用户级别
+#[derive(Clone, Debug)]
+pub struct AuthUser {
+    pub id: i32,
+    pub account: String,
+    pub level: i8,
+}
+
+impl From<UserModel> for AuthUser {
+    fn from(user: UserModel) -> Self {
+        Self { id: user.id, account: user.account, level: user.level }
+    }
+}
+
+#[async_trait]
+impl<S: Send + Sync> FromRequestParts<S> for AuthUser {
+    type Rejection = (StatusCode, Json<Value>);
+
+    /// # Description
+    ///     从请求扩展中取出认证用户，路由未挂载认证中间件时按未认证拒绝
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        parts.extensions.get::<AuthUser>().cloned()
+            .ok_or_else(|| Response::<()>::failed_with_status(ErrorCode::TokenError, "未登录".to_string()))
+    }
+}
+
+/// # Description
+///     两步验证码请求头，敏感操作通过该请求头提交 TOTP 验证码或恢复码
+pub const TWO_FACTOR_HEADER: &str = "x-two-factor-code";
 
 /// # Description
 ///     认证中间件
@@ -11,67 +54,111 @@ pub struct AuthMiddleware;
 
 impl AuthMiddleware {
     /// # Description
-    ///     认证
+    ///     认证：校验 Authorization: Bearer 访问令牌，加载用户并拒绝已禁用或已注销的用户，
+    ///     通过后把 AuthUser 写入请求扩展；失败返回 HTTP 401 与 TokenError
+    /// # Param
+    ///     State(application_layer): State<Arc<ApplicationLayer>> - 应用层
+    ///     request: Request - 请求
+    ///     next: Next - 后续处理
+    /// # Return
+    ///     AxumResponse: 响应
+    pub async fn auth(
+        State(application_layer): State<Arc<ApplicationLayer>>,
+        mut request: Request,
+        next: Next,
+    ) -> AxumResponse {
+        let Some(token) = Self::bearer_token(request.headers()) else {
+            return Response::<()>::failed_with_status(ErrorCode::TokenError, "缺少访问令牌".to_string()).into_response();
+        };
+
+        match application_layer.user_application.authenticate(&token).await {
+            Ok(user) => {
+                request.extensions_mut().insert(AuthUser::from(user));
+                next.run(request).await
+            }
+            Err(e) => {
+                warn!("-PresentationLayer [Auth] {} rejected: {}", request.uri().path(), e);
+                Response::<()>::failed_with_status(ErrorCode::TokenError, e.to_string()).into_response()
+            }
+        }
+    }
+
+    /// # Description
+    ///     权限校验：要求请求已通过认证且用户经由角色拥有指定权限，须挂载在认证中间件之内；
+    ///     未认证返回 HTTP 401，权限不足返回 HTTP 403
     /// # Param
-    ///     None
+    ///     State((application_layer, permission)): State<(Arc<ApplicationLayer>, &'static str)> - 应用层与所需权限编码
+    ///     request: Request - 请求
+    ///     next: Next - 后续处理
     /// # Return
-    ///     Router: 路由
-    pub async fn auth() {
-        info!("+ [Router] Register handle");
+    ///     AxumResponse: 响应
+    pub async fn require_permission(
+        State((application_layer, permission)): State<(Arc<ApplicationLayer>, &'static str)>,
+        request: Request,
+        next: Next,
+    ) -> AxumResponse {
+        let Some(auth_user) = request.extensions().get::<AuthUser>().cloned() else {
+            return Response::<()>::failed_with_status(ErrorCode::TokenError, "未登录".to_string()).into_response();
+        };
+
+        match application_layer.rbac_application.has_permission(auth_user.id, permission).await {
+            Ok(true) => next.run(request).await,
+            Ok(false) => {
+                warn!("-PresentationLayer [Rbac] user {} lacks {} for {}", auth_user.id, permission, request.uri().path());
+                Response::<()>::failed_with_status(ErrorCode::Forbidden, "权限不足".to_string()).into_response()
+            }
+            Err(e) => {
+                error!("-PresentationLayer [Rbac] permission check failed: {}", e);
+                Response::<()>::failed_with_status(ErrorCode::ServerError, "权限校验失败".to_string()).into_response()
+            }
+        }
     }
 
-    pub async fn my_middleware(
+    /// # Description
+    ///     两步验证（step-up）：敏感操作要求已开启两步验证，并在 x-two-factor-code 请求头中提交有效验证码，
+    ///     须挂载在认证中间件之内；未认证返回 HTTP 401，缺少或验证码错误返回 HTTP 403
+    /// # Param
+    ///     State(application_layer): State<Arc<ApplicationLayer>> - 应用层
+    ///     request: Request - 请求
+    ///     next: Next - 后续处理
+    /// # Return
+    ///     AxumResponse: 响应
+    pub async fn require_two_factor(
+        State(application_layer): State<Arc<ApplicationLayer>>,
         request: Request,
         next: Next,
-    ) -> Response {
-        // do something with `request`...
-        info!("+ [Router] Register handle");
+    ) -> AxumResponse {
+        let Some(auth_user) = request.extensions().get::<AuthUser>().cloned() else {
+            return Response::<()>::failed_with_status(ErrorCode::TokenError, "未登录".to_string()).into_response();
+        };
 
-        let response = next.run(request).await;
+        let Some(code) = request.headers().get(TWO_FACTOR_HEADER)
+            .and_then(|value| value.to_str().ok())
+            .map(|value| value.trim().to_string())
+            .filter(|value| !value.is_empty()) else {
+            return Response::<()>::failed_with_status(ErrorCode::Forbidden, "该操作需要两步验证码".to_string()).into_response();
+        };
 
-        // do something with `response`...
+        match application_layer.two_factor_application.verify(auth_user.id, &code).await {
+            Ok(_) => next.run(request).await,
+            Err(e) => {
+                warn!("-PresentationLayer [TwoFactor] user {} step-up rejected for {}: {}", auth_user.id, request.uri().path(), e);
+                Response::<()>::failed_with_status(ErrorCode::Forbidden, e.to_string()).into_response()
+            }
+        }
+    }
 
-        response
+    /// # Description
+    ///     读取 Authorization: Bearer 请求头中的访问令牌
+    /// # Param
+    ///     headers: &HeaderMap - 请求头
+    /// # Return
+    ///     Option<String>: 访问令牌
+    pub fn bearer_token(headers: &HeaderMap) -> Option<String> {
+        headers.get(AUTHORIZATION)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.strip_prefix("Bearer "))
+            .map(|value| value.trim().to_string())
+            .filter(|value| !value.is_empty())
     }
 }
-
-
-// use axum::body::{box_body, BoxBody};
-// use axum::extract::Extension;
-// use axum::handler::Handler;
-// use axum::http::{header, Request, Response, Uri};
-// use axum::response::{IntoResponse, Redirect};
-// use axum::{routing::get, Router};
-// use headers::{HeaderName, HeaderValue};
-// use tower_http::auth::{AsyncAuthorizeRequest, RequireAuthorizationLayer};
-// use tower_http::set_header::SetResponseHeaderLayer;
-//
-// #[derive(Clone)]
-// struct MyAuth {
-//     n: i32,
-// }
-// impl AsyncAuthorizeRequest<B> for MyAuth {
-//     type Output = i32;
-//     type ResponseBody = BoxBody;
-//     fn authorize<B>(&mut self, request: &Request<B>) -> Option<Self::Output> {
-//         #[rustfmt::skip]
-//         let i=request.headers().get(header::AUTHORIZATION).map(|header|{
-//             header.to_str().ok().map(|s|s.parse::<i32>().ok()).flatten()
-//         }).flatten()?;
-//         (i % self.n == 0).then(|| i)
-//     }
-//     fn on_authorized<B>(&mut self, request: &mut Request<B>, output: Self::Output) {
-//         request.extensions_mut().insert(output);
-//     }
-//     fn unauthorized_response<B>(&mut self, _request: &Request<B>) -> Response<Self::ResponseBody> {
-//         // use axum::body::{Bytes, Full};
-//         // let buf = Bytes::from("unauthorized!");
-//         // Response::builder()
-//         //     .status(StatusCode::UNAUTHORIZED)
-//         //     .body(box_body(Full::new(buf)))
-//         //     .unwrap()
-//         Redirect::to(Uri::from_static("/login"))
-//             .into_response()
-//             .map(|b| box_body(b))
-//     }
-// }
\ No newline at end of file
@@ -0,0 +1,60 @@
+use std::{convert::Infallible, net::{IpAddr, SocketAddr}, sync::Arc};
+use axum::{
+    async_trait,
+    extract::{ConnectInfo, FromRequestParts},
+    http::{HeaderMap, request::Parts},
+};
+use crate::core::application::ApplicationLayer;
+
+/// # Description
+///     反向代理传递客户端 IP 的请求头
+pub const FORWARDED_FOR_HEADER: &str = "x-forwarded-for";
+
+/// # Description
+///     客户端 IP：开启 trust_forwarded_for 时按可信代理层数从右往左取 X-Forwarded-For 中的地址，
+///     否则取 TCP 连接的对端地址；都取不到时为 "unknown"
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ClientIp(pub String);
+
+impl ClientIp {
+    /// # Description
+    ///     解析 X-Forwarded-For 请求头中由最外层可信代理记录的客户端 IP。
+    ///     左侧的地址可由客户端任意伪造，只有可信代理追加的最右侧 trusted_hops 个地址可信，
+    ///     取其中最左的一个，即从右往左第 trusted_hops 个地址
+    /// # Param
+    ///     headers: &HeaderMap - 请求头
+    ///     trusted_hops: usize - 应用前的可信反向代理层数，至少为 1
+    /// # Return
+    ///     Option<IpAddr>: 客户端 IP，地址数量不足或不是合法 IP 时为 None
+    pub fn forwarded_for(headers: &HeaderMap, trusted_hops: usize) -> Option<IpAddr> {
+        let entries: Vec<&str> = headers.get_all(FORWARDED_FOR_HEADER)
+            .iter()
+            .filter_map(|value| value.to_str().ok())
+            .flat_map(|value| value.split(','))
+            .map(str::trim)
+            .collect();
+
+        entries.len()
+            .checked_sub(trusted_hops.max(1))
+            .and_then(|index| entries.get(index))
+            .and_then(|value| value.parse().ok())
+    }
+}
+
+#[async_trait]
+impl FromRequestParts<Arc<ApplicationLayer>> for ClientIp {
+    type Rejection = Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, application_layer: &Arc<ApplicationLayer>) -> Result<Self, Self::Rejection> {
+        let throttle = &application_layer.login_throttle_application;
+        if throttle.trust_forwarded_for() {
+            if let Some(ip) = Self::forwarded_for(&parts.headers, throttle.trusted_proxy_hops()) {
+                return Ok(Self(ip.to_string()));
+            }
+        }
+
+        Ok(Self(parts.extensions.get::<ConnectInfo<SocketAddr>>()
+            .map(|ConnectInfo(addr)| addr.ip().to_string())
+            .unwrap_or_else(|| "unknown".to_string())))
+    }
+}
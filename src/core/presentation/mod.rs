@@ -1,5 +1,5 @@
 pub mod handler;
-mod middleware;
+pub(crate) mod middleware;
 pub(crate) mod router;
 mod presentation;
 pub mod api;
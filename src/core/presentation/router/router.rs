@@ -1,17 +1,29 @@
 use std::sync::Arc;
 use axum::{routing::get, Router as R};
-use tracing::info;
 use crate::core::presentation::handler::user::UserHandle;
-use axum::{
-    response::Response,
-    middleware::{Next},
-    extract::Request,
-};
+use axum::middleware;
 use axum::routing::post;
 use crate::core::application::ApplicationLayer;
 use crate::core::domain::DomainLayer;
 use crate::core::infrastructure::InfrastructureLayer;
 use crate::core::presentation::handler::wallet::WalletHandle;
+use crate::core::presentation::handler::price::PriceHandle;
+use crate::core::presentation::handler::order::OrderHandle;
+use crate::core::presentation::handler::dca::DcaHandle;
+use crate::core::presentation::handler::market::MarketHandle;
+use crate::core::presentation::handler::indexer::IndexerHandle;
+use crate::core::presentation::handler::network::NetworkHandle;
+use crate::core::presentation::handler::history::HistoryHandle;
+use crate::core::presentation::handler::instruction::InstructionHandle;
+use crate::core::presentation::handler::account::AccountHandle;
+use crate::core::presentation::handler::webhook::WebhookHandle;
+use crate::core::presentation::handler::stream::StreamHandle;
+use crate::core::presentation::handler::rbac::RbacHandle;
+use crate::core::presentation::handler::two_factor::TwoFactorHandle;
+use crate::core::presentation::handler::withdrawal::WithdrawalHandle;
+use crate::core::presentation::handler::admin::AdminHandle;
+use crate::core::domain::user::entity::permission_entity::{RBAC_ADMIN, WEBHOOK_ADMIN, INDEXER_ADMIN, USER_ADMIN, INSTRUCTION_ADMIN};
+use crate::core::presentation::middleware::auth_middleware::AuthMiddleware;
 
 /// # Description
 ///     【WebServer】路由接口
@@ -43,15 +55,43 @@ impl Router {
     ///     Router: 路由
     pub async fn v1_routes(&self) -> R {
         R::new()
+            .nest("/.well-known", self.well_known_management().await)
             .nest(
                 "/v1",
                 R::new()
                     .nest("/userManagement", self.user_management().await)
                     .nest("/walletManagement", self.wallet_management().await)
+                    .nest("/prices", self.price_management().await)
+                    .nest("/orderManagement", self.order_management().await)
+                    .nest("/dcaManagement", self.dca_management().await)
+                    .nest("/markets", self.market_management().await)
+                    .nest("/indexer", self.indexer_management().await)
+                    .nest("/network", self.network_management().await)
+                    .nest("/wallets", self.wallet_history_management().await)
+                    .nest("/instructions", self.instruction_management().await)
+                    .nest("/accounts", self.account_management().await)
+                    .nest("/webhooks", self.webhook_management().await)
+                    .nest("/stream", self.stream_management().await)
+                    .nest("/rbac", self.rbac_management().await)
+                    .nest("/twoFactor", self.two_factor_management().await)
+                    .nest("/withdrawals", self.withdrawal_management().await)
+                    .nest("/admin/users", self.admin_user_management().await)
             )
 
     }
 
+    /// # Description
+    ///     公开元数据路由组
+    /// # Param
+    ///     None
+    /// # Return
+    ///     Router: 路由
+    async fn well_known_management(&self) -> R {
+        R::new()
+            .route("/jwks.json", get(UserHandle::jwks))
+            .with_state(self.application_layer.clone())
+    }
+
     /// # Description
     ///     用户管理路由组
     /// # Param
@@ -61,7 +101,28 @@ impl Router {
     async fn user_management(&self) -> R {
         R::new()
             .route("/register", post(UserHandle::register))
+            .route("/email/code", post(UserHandle::send_email_code))
+            .route("/sms/code", post(UserHandle::send_sms_code))
+            .route("/password/forgot", post(UserHandle::forgot_password))
+            .route("/password/reset", post(UserHandle::reset_password))
             .route("/login", post(UserHandle::login))
+            .route("/login/otp", post(UserHandle::otp_login))
+            .route("/login/2fa", post(UserHandle::login_two_factor))
+            .route("/refresh", post(UserHandle::refresh))
+            .route(
+                "/logout",
+                post(UserHandle::logout)
+                    .route_layer(middleware::from_fn_with_state(self.application_layer.clone(), AuthMiddleware::auth)),
+            )
+            .nest(
+                "/me",
+                R::new()
+                    .route("/profile", get(UserHandle::retrieve).post(UserHandle::update_profile))
+                    .route("/password", post(UserHandle::change_password))
+                    .route("/delete", post(UserHandle::delete_account))
+                    .route("/sessions", get(UserHandle::sessions))
+                    .route_layer(middleware::from_fn_with_state(self.application_layer.clone(), AuthMiddleware::auth)),
+            )
             .with_state(self.application_layer.clone())
 
     }
@@ -75,41 +136,274 @@ impl Router {
     async fn wallet_management(&self) -> R {
         R::new()
             .route("/generation_wallet", get(WalletHandle::generation_wallet))
+            .route(
+                "/export_key",
+                post(WalletHandle::export_key)
+                    .route_layer(middleware::from_fn_with_state(self.application_layer.clone(), AuthMiddleware::require_two_factor)),
+            )
             // .route("/query_wallet_amount", get(WalletHandle::))
+            .route_layer(middleware::from_fn_with_state(self.application_layer.clone(), AuthMiddleware::auth))
             .with_state(self.application_layer.clone())
 
     }
-}
 
+    /// # Description
+    ///     价格路由组
+    /// # Param
+    ///     None
+    /// # Return
+    ///     Router: 路由
+    async fn price_management(&self) -> R {
+        R::new()
+            .route("/", get(PriceHandle::get_prices))
+            .with_state(self.application_layer.clone())
+    }
 
-/// # Description
-///     认证中间件
-pub struct AuthMiddleware;
+    /// # Description
+    ///     条件单（限价单 / 止损单）路由组
+    /// # Param
+    ///     None
+    /// # Return
+    ///     Router: 路由
+    async fn order_management(&self) -> R {
+        R::new()
+            .route("/place", post(OrderHandle::place_order))
+            .route("/cancel", post(OrderHandle::cancel_order))
+            .route("/list", get(OrderHandle::list_orders))
+            .route("/detail", get(OrderHandle::get_order))
+            .route_layer(middleware::from_fn_with_state(self.application_layer.clone(), AuthMiddleware::auth))
+            .with_state(self.application_layer.clone())
+    }
 
-impl AuthMiddleware {
     /// # Description
-    ///     认证
+    ///     定投计划路由组
     /// # Param
     ///     None
     /// # Return
     ///     Router: 路由
-    pub async fn auth() {
-        info!("+ [Router] Register handle");
+    async fn dca_management(&self) -> R {
+        R::new()
+            .route("/create", post(DcaHandle::create_schedule))
+            .route("/pause", post(DcaHandle::pause_schedule))
+            .route("/resume", post(DcaHandle::resume_schedule))
+            .route("/cancel", post(DcaHandle::cancel_schedule))
+            .route("/list", get(DcaHandle::list_schedules))
+            .route("/detail", get(DcaHandle::get_schedule))
+            .route_layer(middleware::from_fn_with_state(self.application_layer.clone(), AuthMiddleware::auth))
+            .with_state(self.application_layer.clone())
     }
 
-    pub async fn my_middleware(
-        request: Request,
-        next: Next,
-    ) -> Response {
-        // do something with `request`...
-        info!("+ [Router] Register handle");
+    /// # Description
+    ///     OpenBook 行情路由组
+    /// # Param
+    ///     None
+    /// # Return
+    ///     Router: 路由
+    async fn market_management(&self) -> R {
+        R::new()
+            .route("/orderbook", get(MarketHandle::get_order_book))
+            .route("/fills", get(MarketHandle::get_fills))
+            .route("/snapshot", get(MarketHandle::get_snapshot))
+            .route("/stream", get(MarketHandle::stream))
+            .with_state(self.application_layer.clone())
+    }
 
-        let response = next.run(request).await;
+    /// # Description
+    ///     区块索引路由组
+    /// # Param
+    ///     None
+    /// # Return
+    ///     Router: 路由
+    async fn indexer_management(&self) -> R {
+        R::new()
+            .route("/status", get(IndexerHandle::get_status))
+            .route("/block", get(IndexerHandle::get_block))
+            .route("/transaction", get(IndexerHandle::get_transaction))
+            .route(
+                "/rollbacks",
+                get(IndexerHandle::list_rollbacks)
+                    .route_layer(middleware::from_fn_with_state((self.application_layer.clone(), INDEXER_ADMIN), AuthMiddleware::require_permission))
+                    .route_layer(middleware::from_fn_with_state(self.application_layer.clone(), AuthMiddleware::auth)),
+            )
+            .with_state(self.application_layer.clone())
+    }
 
-        // do something with `response`...
+    /// # Description
+    ///     网络统计路由组
+    /// # Param
+    ///     None
+    /// # Return
+    ///     Router: 路由
+    async fn network_management(&self) -> R {
+        R::new()
+            .route("/stats", get(NetworkHandle::get_stats))
+            .with_state(self.application_layer.clone())
+    }
 
-        response
+    /// # Description
+    ///     钱包交易历史路由组
+    /// # Param
+    ///     None
+    /// # Return
+    ///     Router: 路由
+    async fn wallet_history_management(&self) -> R {
+        R::new()
+            .route("/:id/transactions", get(HistoryHandle::list_transactions))
+            .route_layer(middleware::from_fn_with_state(self.application_layer.clone(), AuthMiddleware::auth))
+            .with_state(self.application_layer.clone())
+    }
+
+    /// # Description
+    ///     指令解码路由组，拉取并注册 IDL 需要 instruction:admin 权限
+    /// # Param
+    ///     None
+    /// # Return
+    ///     Router: 路由
+    async fn instruction_management(&self) -> R {
+        R::new()
+            .route("/decode", post(InstructionHandle::decode_transaction))
+            .route(
+                "/idl",
+                get(InstructionHandle::list_idls).merge(
+                    post(InstructionHandle::fetch_idl)
+                        .route_layer(middleware::from_fn_with_state((self.application_layer.clone(), INSTRUCTION_ADMIN), AuthMiddleware::require_permission))
+                        .route_layer(middleware::from_fn_with_state(self.application_layer.clone(), AuthMiddleware::auth)),
+                ),
+            )
+            .with_state(self.application_layer.clone())
+    }
+
+    /// # Description
+    ///     账户查询路由组
+    /// # Param
+    ///     None
+    /// # Return
+    ///     Router: 路由
+    async fn account_management(&self) -> R {
+        R::new()
+            .route("/:pubkey", get(AccountHandle::inspect))
+            .with_state(self.application_layer.clone())
+    }
+
+    /// # Description
+    ///     Webhook 订阅路由组，admin 前缀下管理全局端点，需要 webhook:admin 权限
+    /// # Param
+    ///     None
+    /// # Return
+    ///     Router: 路由
+    async fn webhook_management(&self) -> R {
+        R::new()
+            .route("/endpoints/create", post(WebhookHandle::create_endpoint))
+            .route("/endpoints/delete", post(WebhookHandle::delete_endpoint))
+            .route("/endpoints/list", get(WebhookHandle::list_endpoints))
+            .route("/deliveries", get(WebhookHandle::list_deliveries))
+            .route("/deliveries/replay", post(WebhookHandle::replay_delivery))
+            .nest(
+                "/admin",
+                R::new()
+                    .route("/endpoints/create", post(WebhookHandle::admin_create_endpoint))
+                    .route("/endpoints/delete", post(WebhookHandle::admin_delete_endpoint))
+                    .route("/endpoints/list", get(WebhookHandle::admin_list_endpoints))
+                    .route("/deliveries", get(WebhookHandle::admin_list_deliveries))
+                    .route("/deliveries/replay", post(WebhookHandle::admin_replay_delivery))
+                    .route_layer(middleware::from_fn_with_state((self.application_layer.clone(), WEBHOOK_ADMIN), AuthMiddleware::require_permission)),
+            )
+            .route_layer(middleware::from_fn_with_state(self.application_layer.clone(), AuthMiddleware::auth))
+            .with_state(self.application_layer.clone())
+    }
+
+    /// # Description
+    ///     实时推送路由组（WebSocket / SSE）
+    /// # Param
+    ///     None
+    /// # Return
+    ///     Router: 路由
+    async fn stream_management(&self) -> R {
+        R::new()
+            .route("/", get(StreamHandle::stream))
+            .with_state(self.application_layer.clone())
+    }
+
+    /// # Description
+    ///     角色与权限管理路由组，需要 rbac:admin 权限
+    /// # Param
+    ///     None
+    /// # Return
+    ///     Router: 路由
+    async fn rbac_management(&self) -> R {
+        R::new()
+            .route("/roles/list", get(RbacHandle::list_roles))
+            .route("/roles/create", post(RbacHandle::create_role))
+            .route("/roles/update", post(RbacHandle::update_role))
+            .route("/roles/delete", post(RbacHandle::delete_role))
+            .route("/permissions/list", get(RbacHandle::list_permissions))
+            .route("/permissions/create", post(RbacHandle::create_permission))
+            .route("/users/roles", get(RbacHandle::user_roles))
+            .route("/users/assign", post(RbacHandle::assign_roles))
+            .route_layer(middleware::from_fn_with_state((self.application_layer.clone(), RBAC_ADMIN), AuthMiddleware::require_permission))
+            .route_layer(middleware::from_fn_with_state(self.application_layer.clone(), AuthMiddleware::auth))
+            .with_state(self.application_layer.clone())
+    }
+
+    /// # Description
+    ///     两步验证设置路由组
+    /// # Param
+    ///     None
+    /// # Return
+    ///     Router: 路由
+    async fn two_factor_management(&self) -> R {
+        R::new()
+            .route("/status", get(TwoFactorHandle::status))
+            .route("/enroll", post(TwoFactorHandle::enroll))
+            .route("/confirm", post(TwoFactorHandle::confirm))
+            .route("/disable", post(TwoFactorHandle::disable))
+            .route("/backup_codes", post(TwoFactorHandle::regenerate_backup_codes))
+            .route_layer(middleware::from_fn_with_state(self.application_layer.clone(), AuthMiddleware::auth))
+            .with_state(self.application_layer.clone())
+    }
+
+    /// # Description
+    ///     提现路由组，添加白名单地址与提现需要两步验证
+    /// # Param
+    ///     None
+    /// # Return
+    ///     Router: 路由
+    async fn withdrawal_management(&self) -> R {
+        R::new()
+            .route("/whitelist/list", get(WithdrawalHandle::list_whitelist))
+            .route("/whitelist/remove", post(WithdrawalHandle::remove_whitelist))
+            .route(
+                "/whitelist/add",
+                post(WithdrawalHandle::add_whitelist)
+                    .route_layer(middleware::from_fn_with_state(self.application_layer.clone(), AuthMiddleware::require_two_factor)),
+            )
+            .route(
+                "/submit",
+                post(WithdrawalHandle::withdraw)
+                    .route_layer(middleware::from_fn_with_state(self.application_layer.clone(), AuthMiddleware::require_two_factor)),
+            )
+            .route_layer(middleware::from_fn_with_state(self.application_layer.clone(), AuthMiddleware::auth))
+            .with_state(self.application_layer.clone())
     }
-}
 
-// .route_layer(middleware::from_fn(AuthMiddleware::my_middleware));
\ No newline at end of file
+    /// # Description
+    ///     用户管理后台路由组，需要 user:admin 权限
+    /// # Param
+    ///     None
+    /// # Return
+    ///     Router: 路由
+    async fn admin_user_management(&self) -> R {
+        R::new()
+            .route("/list", get(AdminHandle::list_users))
+            .route("/status", post(AdminHandle::set_status))
+            .route("/level", post(AdminHandle::change_level))
+            .route("/roles", post(AdminHandle::assign_roles))
+            .route("/password_reset", post(AdminHandle::force_password_reset))
+            .route("/wallets", get(AdminHandle::user_wallets))
+            .route("/restore", post(AdminHandle::restore_user))
+            .route("/audit", get(AdminHandle::audit_log))
+            .route_layer(middleware::from_fn_with_state((self.application_layer.clone(), USER_ADMIN), AuthMiddleware::require_permission))
+            .route_layer(middleware::from_fn_with_state(self.application_layer.clone(), AuthMiddleware::auth))
+            .with_state(self.application_layer.clone())
+    }
+}
\ No newline at end of file
@@ -0,0 +1,228 @@
+#!/usr/bin/env python3
+"""OpenBook (Serum v3) 解码测试使用的账户数据。
+
+    python3 openbook_fixtures.py capture [rpc_url]   从主网抓取 SOL/USDC 市场及其买卖盘、事件队列账户
+    python3 openbook_fixtures.py generate            按 serum-dex state.rs / critbit.rs 的布局离线生成同尺寸账户
+
+生成的 market.bin、bids.bin、asks.bin、event_queue.bin 由 src/test/mod.rs 中的 market_test 通过 include_bytes! 读取。
+"""
+import base64
+import json
+import random
+import struct
+import sys
+import urllib.request
+from pathlib import Path
+
+HERE = Path(__file__).resolve().parent
+SOL_USDC_MARKET = "8BnEgHoWFysVcuFFX7QztDmzuH8r5ZFvyP3sYwn1XTh6"
+MAINNET = "https://api.mainnet-beta.solana.com"
+
+B58 = "123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz"
+
+
+def b58decode(text):
+    num = 0
+    for char in text:
+        num = num * 58 + B58.index(char)
+    raw = num.to_bytes((num.bit_length() + 7) // 8, "big")
+    return b"\0" * (len(text) - len(text.lstrip("1"))) + raw
+
+
+def b58encode(raw):
+    num = int.from_bytes(raw, "big")
+    text = ""
+    while num:
+        num, rem = divmod(num, 58)
+        text = B58[rem] + text
+    return "1" * (len(raw) - len(raw.lstrip(b"\0"))) + text
+
+
+def write(name, data):
+    (HERE / name).write_bytes(data)
+    print(f"{name}: {len(data)} bytes")
+
+
+# ---------------------------------------------------------------- capture
+
+def rpc(url, method, params):
+    body = json.dumps({"jsonrpc": "2.0", "id": 1, "method": method, "params": params}).encode()
+    request = urllib.request.Request(url, body, {"Content-Type": "application/json"})
+    with urllib.request.urlopen(request, timeout=30) as response:
+        return json.load(response)["result"]
+
+
+def capture(url):
+    config = {"encoding": "base64", "commitment": "confirmed"}
+    market = base64.b64decode(rpc(url, "getAccountInfo", [SOL_USDC_MARKET, config])["value"]["data"][0])
+    body = market[13:]
+    event_queue, bids, asks = (b58encode(body[offset:offset + 32]) for offset in (240, 272, 304))
+
+    # 同一次请求读取，三个账户来自同一 slot
+    accounts = rpc(url, "getMultipleAccounts", [[bids, asks, event_queue], config])["value"]
+    write("market.bin", market)
+    for name, account in zip(("bids.bin", "asks.bin", "event_queue.bin"), accounts):
+        write(name, base64.b64decode(account["data"][0]))
+
+
+# ---------------------------------------------------------------- generate
+
+FLAG_INITIALIZED, FLAG_MARKET, FLAG_EVENT_QUEUE, FLAG_BIDS, FLAG_ASKS = 1, 1 << 1, 1 << 4, 1 << 5, 1 << 6
+SLAB_LEN = 65548
+EVENT_QUEUE_LEN = 262156
+NODE_LEN = 72
+EVENT_LEN = 88
+
+
+def wrap(flags, body, length):
+    data = b"serum" + struct.pack("<Q", flags) + body
+    return data + b"\0" * (length - len(data) - 7) + b"padding"
+
+
+def key(seed):
+    return random.Random(seed).randbytes(32)
+
+
+def market():
+    # MarketState: own_address vault_signer_nonce coin_mint pc_mint coin_vault coin_deposits_total coin_fees_accrued
+    # pc_vault pc_deposits_total pc_fees_accrued pc_dust_threshold req_q event_q bids asks coin_lot_size pc_lot_size
+    # fee_rate_bps referrer_rebates_accrued
+    body = b58decode(SOL_USDC_MARKET) + struct.pack("<Q", 1)
+    body += b58decode("So11111111111111111111111111111111111111112")
+    body += b58decode("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v")
+    body += key("coin_vault") + struct.pack("<QQ", 41_205_000_000_000, 1_000_000)
+    body += key("pc_vault") + struct.pack("<QQQ", 6_180_750_000_000, 2_472_300_000, 100)
+    body += key("req_q") + key("event_q") + key("bids") + key("asks")
+    body += struct.pack("<QQQQ", 1_000_000, 1, 0, 0)
+    return wrap(FLAG_INITIALIZED | FLAG_MARKET, body, 388)
+
+
+class Slab:
+    """crit-bit 树，与 serum_dex::critbit::Slab 相同的插入与节点布局。"""
+
+    def __init__(self):
+        self.nodes = []
+        self.root = None
+
+    def inner(self, prefix_len, node_key, children):
+        self.nodes.append(("inner", prefix_len, node_key, list(children)))
+        return len(self.nodes) - 1
+
+    def leaf(self, node_key, owner, quantity, owner_slot):
+        self.nodes.append(("leaf", node_key, owner, quantity, owner_slot))
+        return len(self.nodes) - 1
+
+    def key_of(self, index):
+        node = self.nodes[index]
+        return node[2] if node[0] == "inner" else node[1]
+
+    def insert(self, node_key, owner, quantity, owner_slot):
+        new_leaf = self.leaf(node_key, owner, quantity, owner_slot)
+        if self.root is None:
+            self.root = new_leaf
+            return
+
+        parent, parent_side, index = None, None, self.root
+        while True:
+            node = self.nodes[index]
+            shared = 128 - (self.key_of(index) ^ node_key).bit_length()
+            if node[0] == "inner" and shared >= node[1]:
+                side = (node_key >> (127 - node[1])) & 1
+                parent, parent_side, index = index, side, node[3][side]
+                continue
+
+            # 在 index 上方插入新的内部节点
+            children = [index, new_leaf] if (node_key >> (127 - shared)) & 1 else [new_leaf, index]
+            prefix = node_key & ~((1 << (128 - shared)) - 1) if shared else 0
+            new_inner = self.inner(shared, prefix, children)
+            if parent is None:
+                self.root = new_inner
+            else:
+                self.nodes[parent][3][parent_side] = new_inner
+            return
+
+    def encode(self, flags, free_nodes):
+        leaf_count = sum(1 for node in self.nodes if node[0] == "leaf")
+        nodes = b""
+        for node in self.nodes:
+            if node[0] == "inner":
+                _, prefix_len, node_key, children = node
+                raw = struct.pack("<II", 1, prefix_len) + node_key.to_bytes(16, "little") + struct.pack("<II", *children)
+            else:
+                _, node_key, owner, quantity, owner_slot = node
+                raw = struct.pack("<IBB2x", 2, owner_slot, 0) + node_key.to_bytes(16, "little") + owner
+                raw += struct.pack("<QQ", quantity, owner_slot * 1000 + 7)
+            nodes += raw.ljust(NODE_LEN, b"\0")
+
+        # 已释放的节点挂在空闲链表上，树上不可达
+        free_head = len(self.nodes)
+        for i in range(free_nodes):
+            tag, next_index = (4, 0) if i == free_nodes - 1 else (3, free_head + i + 1)
+            nodes += struct.pack("<II", tag, next_index).ljust(NODE_LEN, b"\0")
+
+        bump_index = len(self.nodes) + free_nodes
+        header = struct.pack("<QQIIQ", bump_index, free_nodes, free_head, self.root, leaf_count)
+        return wrap(FLAG_INITIALIZED | flags, header + nodes, SLAB_LEN)
+
+
+def book(flags, prices, bid):
+    slab = Slab()
+    rng = random.Random(flags)
+    for seq, price in enumerate(prices, start=1):
+        sequence = (~seq & (2 ** 64 - 1)) if bid else seq
+        slab.insert((price << 64) | sequence, key(f"owner{seq % 5}"), rng.randint(1, 5000), seq % 5)
+    return slab.encode(flags, 3)
+
+
+def event_queue():
+    # 头部 head count seq_num，事件：event_flags owner_slot fee_tier native_qty_released native_qty_paid
+    # native_fee_or_rebate order_id owner client_order_id
+    capacity = (EVENT_QUEUE_LEN - 12 - 8 - 24) // EVENT_LEN
+    rng = random.Random("events")
+    events = []
+    for seq in range(40):
+        price_lots = 150_120 + rng.randint(-40, 40)
+        coin = rng.randint(1, 200) * 1_000_000
+        pc = coin // 1_000_000 * price_lots
+        taker_bid = rng.random() < 0.5
+        order_id = (price_lots << 64) | seq
+        owner = key(f"owner{seq % 5}")
+        # 吃单、挂单各一条成交，偶尔夹一条撤单
+        if taker_bid:
+            fee = pc * 4 // 10_000
+            events.append(struct.pack("<BBB5xQQQ", 0b0101, 1, 0, coin, pc + fee, fee))
+            events[-1] += order_id.to_bytes(16, "little") + owner + struct.pack("<Q", seq)
+            events.append(struct.pack("<BBB5xQQQ", 0b1001, 2, 0, pc, coin, fee // 5))
+        else:
+            fee = pc * 4 // 10_000
+            events.append(struct.pack("<BBB5xQQQ", 0b0001, 1, 0, pc - fee, coin, fee))
+            events[-1] += order_id.to_bytes(16, "little") + owner + struct.pack("<Q", seq)
+            events.append(struct.pack("<BBB5xQQQ", 0b1101, 2, 0, coin, pc, fee // 5))
+        events[-1] += ((order_id + 1) << 0).to_bytes(16, "little") + key(f"owner{(seq + 1) % 5}") + struct.pack("<Q", seq)
+        if seq % 7 == 0:
+            events.append(struct.pack("<BBB5xQQQ", 0b0110, 3, 0, 0, 0, 0) + order_id.to_bytes(16, "little") + owner + struct.pack("<Q", seq))
+
+    # 前面的事件已被 crank 消费，最后 6 条待处理
+    count = 6
+    head = len(events) - count
+    body = struct.pack("<QQQ", head, count, len(events)) + b"".join(events)
+    assert len(events) < capacity
+    return wrap(FLAG_INITIALIZED | FLAG_EVENT_QUEUE, body, EVENT_QUEUE_LEN)
+
+
+def generate():
+    write("market.bin", market())
+    write("bids.bin", book(FLAG_BIDS, [150_100 - step * 10 for step in range(12)] + [150_100, 149_990], True))
+    write("asks.bin", book(FLAG_ASKS, [150_140 + step * 10 for step in range(12)] + [150_140, 150_250], False))
+    write("event_queue.bin", event_queue())
+
+
+if __name__ == "__main__":
+    command = sys.argv[1] if len(sys.argv) > 1 else ""
+    if command == "capture":
+        capture(sys.argv[2] if len(sys.argv) > 2 else MAINNET)
+    elif command == "generate":
+        generate()
+    else:
+        print(__doc__)
+        sys.exit(1)
@@ -1,6 +1,49 @@
 use serde::Deserialize;
 use solana_program::pubkey::Pubkey;
 
+#[cfg(test)]
+mod fixtures {
+    use chrono::Utc;
+    use crate::core::domain::user::entity::user_entity::Model as UserModel;
+    use crate::core::domain::wallet::entity::Model as WalletModel;
+
+    /// # Description
+    ///     测试用户，注册时未填写的手机号保存为空字符串
+    pub fn user(disable: bool, deleted: bool) -> UserModel {
+        UserModel {
+            id: 1,
+            account: "2024000001".to_string(),
+            nickname: None,
+            avatar: None,
+            mobile: Some(String::new()),
+            email: Some("user@example.com".to_string()),
+            password: "$2b$12$hash".to_string(),
+            disable,
+            level: 1,
+            reg_type: 0,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            deleted_at: if deleted { Some(Utc::now()) } else { None },
+        }
+    }
+
+    /// # Description
+    ///     测试钱包，不包含密钥
+    pub fn wallet(balance: f64, disable: bool, deleted: bool) -> WalletModel {
+        WalletModel {
+            id: 1,
+            user_id: 1,
+            pub_key: None,
+            privy_key: None,
+            balance,
+            disable,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            deleted_at: if deleted { Some(Utc::now()) } else { None },
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::bootstrap::Bootstrap;
@@ -31,11 +74,11 @@ mod tests {
         let user_application = bootstrap.application_layer.user_application.clone();
 
         // 测试用户注册
-        let register_result = user_application.register_user("18160114162".into(), "password123".into()).await;
+        let register_result = user_application.register_user("18160114162".into(), "password123".into(), None).await;
         assert!(register_result.is_ok(), "用户注册失败");
 
         // 测试用户登录
-        let login_result = user_application.login_user("18160114162".into(), "password123".into()).await;
+        let login_result = user_application.login_user("18160114162".into(), "password123".into(), "127.0.0.1".into()).await;
         info!("{}", format!("{:?}", login_result));
         assert!(login_result.is_ok(), "用户登录失败");
 
@@ -171,7 +214,7 @@ mod tests {
         // println!("User Transactions count: {:?}", user_transactions_count);
 
         // 获取 Solana 最近 5 分钟的交易量
-        // WalletApplication::calculate_for_range(&client, 2).await?;
+        // network_application.calculate_for_range(300).await?;
 
         // 获取 usdt 余额
         // WalletApplication::get_token_balance(&client, "DgkvEfTwetYaqYRzn9K2rGg4m8FBMaaX2XqcurwBpR8J").await?;
@@ -265,6 +308,7 @@ mod wallet_test {
 
     use crate::core::application::wallet::WalletApplication;
     use crate::core::domain::wallet::entity::WalletAddress;
+    use crate::core::infrastructure::keystore::KeyStore;
 
     #[tokio::test]
     async fn get_swap_token_amount() -> Result<(), Box<dyn Error>> {
@@ -321,4 +365,2165 @@ mod wallet_test {
 
         Ok(())
     }
+
+    #[test]
+    fn test_load_keypair() {
+        let keystore = KeyStore::from_key(&[7u8; 32]).unwrap();
+        let keypair = Keypair::new();
+        let pub_key = keypair.pubkey().to_string();
+        let privy_key = solana_sdk::signature::write_keypair(&keypair, &mut Vec::new()).unwrap();
+
+        let mut wallet = super::fixtures::wallet(0.0, false, false);
+        wallet.pub_key = Some(pub_key.clone());
+        wallet.privy_key = Some(keystore.seal(&pub_key, &privy_key).unwrap());
+        assert!(keystore.is_sealed(wallet.privy_key.as_deref().unwrap()));
+        assert!(!wallet.privy_key.as_deref().unwrap().contains(&privy_key));
+
+        let loaded = WalletApplication::load_keypair(&keystore, &wallet).unwrap();
+        assert_eq!(loaded.pubkey(), keypair.pubkey());
+        assert_eq!(WalletApplication::open_privy_key(&keystore, &wallet).unwrap(), privy_key);
+
+        // 密文挪到其它钱包、主密钥不同或私钥仍为明文时都不能解密
+        let mut moved = wallet.clone();
+        moved.pub_key = Some(Keypair::new().pubkey().to_string());
+        assert!(WalletApplication::load_keypair(&keystore, &moved).is_err());
+        assert!(WalletApplication::load_keypair(&KeyStore::from_key(&[8u8; 32]).unwrap(), &wallet).is_err());
+        let mut plaintext = wallet.clone();
+        plaintext.privy_key = Some(privy_key);
+        assert!(WalletApplication::load_keypair(&keystore, &plaintext).is_err());
+
+        // 缺失私钥的钱包不能用于签名
+        let mut missing = wallet.clone();
+        missing.privy_key = None;
+        assert!(WalletApplication::load_keypair(&keystore, &missing).is_err());
+        missing.privy_key = Some(String::new());
+        assert!(WalletApplication::load_keypair(&keystore, &missing).is_err());
+        assert!(KeyStore::from_key(&[7u8; 16]).is_err());
+    }
+}
+
+
+#[cfg(test)]
+mod price_test {
+    use std::error::Error;
+    use std::sync::Arc;
+    use axum::async_trait;
+    use chrono::{DateTime, Duration, Utc};
+    use color_eyre::Report;
+
+    use crate::core::domain::price::entity::{AggregationPolicy, PriceQuote};
+    use crate::core::domain::price::repository::{PriceCacheInterface, PriceSourceInterface};
+    use crate::core::domain::price::service::PriceDomainService;
+    use crate::core::infrastructure::oracle::{MemoryPriceCache, PriceOracle};
+    use crate::core::infrastructure::oracle::decoder::{PythPriceAccount, SwitchboardAggregator};
+
+    /// 模拟数据源，返回固定报价或错误
+    struct MockSource {
+        name: String,
+        price: Option<f64>,
+        age: i64,
+    }
+
+    #[async_trait]
+    impl PriceSourceInterface for MockSource {
+        fn name(&self) -> &str {
+            &self.name
+        }
+
+        async fn fetch(&self, mint: &str) -> Result<Option<PriceQuote>, Report> {
+            match self.price {
+                Some(price) => Ok(Some(PriceQuote {
+                    mint: mint.to_string(),
+                    source: self.name.clone(),
+                    price,
+                    confidence: None,
+                    published_at: Some(Utc::now() - Duration::seconds(self.age)),
+                })),
+                None => Err(Report::msg("source unavailable")),
+            }
+        }
+    }
+
+    fn mock(name: &str, price: Option<f64>, age: i64) -> Arc<dyn PriceSourceInterface> {
+        Arc::new(MockSource { name: name.to_string(), price, age })
+    }
+
+    fn policy() -> AggregationPolicy {
+        AggregationPolicy { max_staleness: 60, max_deviation: 0.05, min_sources: 2 }
+    }
+
+    #[tokio::test]
+    async fn test_aggregate_rejects_outliers_and_stale_quotes() -> Result<(), Box<dyn Error>> {
+        let oracle = PriceOracle::with_sources(
+            vec![
+                mock("a", Some(100.0), 0),
+                mock("b", Some(101.0), 0),
+                mock("c", Some(150.0), 0),   // 偏离中位数过大
+                mock("d", Some(99.0), 600),  // 已过期
+                mock("e", None, 0),          // 数据源异常
+            ],
+            Arc::new(MemoryPriceCache::new()),
+            policy(),
+            15,
+        );
+
+        let quotes = oracle.fetch_quotes("SOL").await;
+        assert_eq!(quotes.len(), 4);
+
+        let price = PriceDomainService::new().aggregate("SOL", quotes, &oracle.policy, Utc::now())?;
+        assert_eq!(price.price, 100.5);
+        assert_eq!(price.sources, vec!["a".to_string(), "b".to_string()]);
+        assert!(price.rejected.contains(&"c".to_string()));
+        assert!(price.rejected.contains(&"d".to_string()));
+
+        // 写入缓存后可以读出
+        oracle.cache.set(&price, oracle.cache_ttl).await?;
+        assert_eq!(oracle.cache.get("SOL").await?, Some(price));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_aggregate_requires_min_sources() {
+        let oracle = PriceOracle::with_sources(
+            vec![mock("a", Some(100.0), 0), mock("b", None, 0)],
+            Arc::new(MemoryPriceCache::new()),
+            policy(),
+            15,
+        );
+
+        let quotes = oracle.fetch_quotes("SOL").await;
+        assert!(PriceDomainService::new().aggregate("SOL", quotes, &oracle.policy, Utc::now()).is_err());
+    }
+
+    #[test]
+    fn test_aggregate_skips_staleness_for_quotes_without_publish_time() -> Result<(), Box<dyn Error>> {
+        let quote = |source: &str, price: f64, published_at: Option<DateTime<Utc>>| PriceQuote {
+            mint: "SOL".to_string(),
+            source: source.to_string(),
+            price,
+            confidence: None,
+            published_at,
+        };
+        let now = Utc::now();
+        let quotes = vec![
+            quote("pyth", 100.0, Some(now)),
+            quote("raydium", 100.2, None),
+            quote("cryptocompare", 99.8, Some(now - Duration::seconds(600))),
+        ];
+
+        let price = PriceDomainService::new().aggregate("SOL", quotes, &policy(), now)?;
+        assert_eq!(price.sources, vec!["pyth".to_string(), "raydium".to_string()]);
+        assert_eq!(price.rejected, vec!["cryptocompare".to_string()]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_decode_pyth_price_account() -> Result<(), Box<dyn Error>> {
+        let mut data = vec![0u8; 3312];
+        data[0..4].copy_from_slice(&0xa1b2c3d4u32.to_le_bytes());
+        data[8..12].copy_from_slice(&3u32.to_le_bytes());
+        data[20..24].copy_from_slice(&(-8i32).to_le_bytes());
+        data[96..104].copy_from_slice(&1_700_000_000i64.to_le_bytes());
+        data[208..216].copy_from_slice(&15_012_345_678i64.to_le_bytes());
+        data[216..224].copy_from_slice(&1_000_000u64.to_le_bytes());
+        data[224..228].copy_from_slice(&1u32.to_le_bytes());
+        data[232..240].copy_from_slice(&250_000_000u64.to_le_bytes());
+
+        let price = PythPriceAccount::decode(&data)?;
+        assert_eq!(price.exponent, -8);
+        assert_eq!(price.publish_slot, 250_000_000);
+        assert!((price.ui_price() - 150.12345678).abs() < 1e-9);
+        assert!((price.ui_confidence() - 0.01).abs() < 1e-12);
+        assert!(price.ensure_fresh(250_000_100, 150).is_ok());
+        assert!(price.ensure_fresh(250_000_151, 150).is_err());
+
+        // 非交易状态的价格不可用
+        data[224..228].copy_from_slice(&0u32.to_le_bytes());
+        assert!(PythPriceAccount::decode(&data).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_decode_switchboard_aggregator() -> Result<(), Box<dyn Error>> {
+        let mut data = vec![0u8; 3851];
+        data[0..8].copy_from_slice(&[217, 230, 65, 101, 201, 162, 27, 125]);
+        data[350..358].copy_from_slice(&250_000_000u64.to_le_bytes());
+        data[358..366].copy_from_slice(&1_700_000_000i64.to_le_bytes());
+        data[366..382].copy_from_slice(&150_250_000i128.to_le_bytes());
+        data[382..386].copy_from_slice(&6u32.to_le_bytes());
+        data[386..402].copy_from_slice(&12_345_678i128.to_le_bytes());
+        data[402..406].copy_from_slice(&9u32.to_le_bytes());
+
+        let price = SwitchboardAggregator::decode(&data)?;
+        assert_eq!(price.exponent, -6);
+        assert_eq!(price.confidence, 12_345);
+        assert_eq!(price.publish_time, 1_700_000_000);
+        assert!((price.ui_price() - 150.25).abs() < 1e-9);
+
+        // 小数位异常时不溢出：标准差小数位过大按 0 处理，结果小数位过大返回错误
+        data[402..406].copy_from_slice(&200u32.to_le_bytes());
+        assert_eq!(SwitchboardAggregator::decode(&data)?.confidence, 0);
+        data[382..386].copy_from_slice(&250u32.to_le_bytes());
+        data[402..406].copy_from_slice(&0u32.to_le_bytes());
+        assert!(SwitchboardAggregator::decode(&data).is_err());
+
+        // 鉴别器不匹配
+        data[0] = 0;
+        assert!(SwitchboardAggregator::decode(&data).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_check_slippage() {
+        let service = PriceDomainService::new();
+
+        // 1 SOL = 150 USD，报价 148.6 USDT 滑点约 0.93%
+        assert!(service.check_slippage(1.0, 150.0, 1.0, 148.6, 0.01).is_ok());
+        assert!(service.check_slippage(1.0, 150.0, 1.0, 147.0, 0.01).is_err());
+    }
+}
+
+#[cfg(test)]
+mod order_test {
+    use chrono::{Duration, Utc};
+
+    use crate::core::domain::order::entity::order_entity::{Model as OrderModel, OrderDirection, OrderStatus};
+    use crate::core::domain::order::service::{OrderDecision, OrderDomainService};
+
+    fn order(direction: OrderDirection, trigger_price: f64) -> OrderModel {
+        OrderModel {
+            id: 1,
+            user_id: 1,
+            wallet_id: 1,
+            input_mint: "So11111111111111111111111111111111111111112".to_string(),
+            output_mint: "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v".to_string(),
+            amount_in: 1.0,
+            trigger_price,
+            direction,
+            max_slippage: 0.01,
+            status: OrderStatus::Open,
+            trigger_at_price: None,
+            signature: None,
+            error: None,
+            expires_at: None,
+            triggered_at: None,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_evaluate_trigger_direction() {
+        let service = OrderDomainService::new();
+        let now = Utc::now();
+
+        // 止盈：价格涨到 160 及以上触发
+        let take_profit = order(OrderDirection::Above, 160.0);
+        assert_eq!(service.evaluate(&take_profit, Some(159.9), now), OrderDecision::Hold);
+        assert_eq!(service.evaluate(&take_profit, Some(160.0), now), OrderDecision::Trigger(160.0));
+
+        // 止损：价格跌到 140 及以下触发
+        let stop_loss = order(OrderDirection::Below, 140.0);
+        assert_eq!(service.evaluate(&stop_loss, Some(141.0), now), OrderDecision::Hold);
+        assert_eq!(service.evaluate(&stop_loss, Some(139.5), now), OrderDecision::Trigger(139.5));
+
+        // 无法定价时保持等待
+        assert_eq!(service.evaluate(&stop_loss, None, now), OrderDecision::Hold);
+
+        // 过期优先于触发
+        let mut expired = order(OrderDirection::Below, 140.0);
+        expired.expires_at = Some(now - Duration::seconds(1));
+        assert_eq!(service.evaluate(&expired, Some(100.0), now), OrderDecision::Expire);
+
+        assert_eq!(service.cross_price(150.0, 1.0), Some(150.0));
+        assert_eq!(service.cross_price(150.0, 0.0), None);
+    }
+
+    #[test]
+    fn test_order_lifecycle() {
+        let mut filled = order(OrderDirection::Above, 160.0);
+        assert!(filled.fill("sig".to_string()).is_err());
+        assert!(filled.trigger(161.0).is_ok());
+        assert!(filled.cancel("用户取消".to_string()).is_err());
+        assert!(filled.fill("sig".to_string()).is_ok());
+        assert_eq!(filled.status, OrderStatus::Filled);
+        assert_eq!(filled.trigger_at_price, Some(161.0));
+
+        let mut failed = order(OrderDirection::Below, 140.0);
+        assert!(failed.trigger(139.0).is_ok());
+        assert!(failed.fail("兑换失败".to_string()).is_ok());
+        assert_eq!(failed.status, OrderStatus::Failed);
+        assert!(failed.trigger(139.0).is_err());
+
+        let mut cancelled = order(OrderDirection::Below, 140.0);
+        assert!(cancelled.cancel("用户取消".to_string()).is_ok());
+        assert!(cancelled.trigger(139.0).is_err());
+        assert!(cancelled.fail("兑换失败".to_string()).is_err());
+    }
+
+    #[test]
+    fn test_create_order_validation() {
+        let service = OrderDomainService::new();
+        let sol = "So11111111111111111111111111111111111111112".to_string();
+        let usdc = "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v".to_string();
+
+        assert!(service.create_order(1, 1, sol.clone(), usdc.clone(), 1.0, 140.0, OrderDirection::Below, 0.01, 0.1, None).is_ok());
+        assert!(service.create_order(1, 1, sol.clone(), sol.clone(), 1.0, 140.0, OrderDirection::Below, 0.01, 0.1, None).is_err());
+        assert!(service.create_order(1, 1, sol.clone(), usdc.clone(), 0.0, 140.0, OrderDirection::Below, 0.01, 0.1, None).is_err());
+        assert!(service.create_order(1, 1, sol.clone(), usdc.clone(), 1.0, 140.0, OrderDirection::Below, 0.2, 0.1, None).is_err());
+        assert!(service.create_order(1, 1, sol, usdc, 1.0, 140.0, OrderDirection::Below, 0.01, 0.1, Some(Utc::now() - Duration::minutes(1))).is_err());
+    }
+}
+
+#[cfg(test)]
+mod dca_test {
+    use chrono::{Duration, TimeZone, Utc};
+    use sea_orm::ActiveValue;
+
+    use crate::core::domain::dca::entity::dca_schedule_entity::{DcaKind, DcaStatus};
+    use crate::core::domain::dca::service::{DcaCadence, DcaDomainService, DcaPlan};
+
+    fn plan(cadence: DcaCadence, spend_cap: Option<f64>) -> DcaPlan {
+        DcaPlan {
+            kind: DcaKind::Swap,
+            input_mint: "So11111111111111111111111111111111111111112".to_string(),
+            output_mint: Some("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v".to_string()),
+            recipient: None,
+            amount: 0.1,
+            max_slippage: 0.01,
+            cadence,
+            spend_cap,
+        }
+    }
+
+    #[test]
+    fn test_cadence_next_after() {
+        let now = Utc.with_ymd_and_hms(2024, 10, 1, 8, 30, 0).unwrap();
+
+        // 每天 09:00:00 执行
+        let daily = DcaCadence::Cron("0 0 9 * * *".to_string());
+        assert_eq!(daily.next_after(now).unwrap(), Utc.with_ymd_and_hms(2024, 10, 1, 9, 0, 0).unwrap());
+
+        // 每月 1 日 00:00:00 执行
+        let monthly = DcaCadence::Cron("0 0 0 1 * *".to_string());
+        assert_eq!(monthly.next_after(now).unwrap(), Utc.with_ymd_and_hms(2024, 11, 1, 0, 0, 0).unwrap());
+
+        let hourly = DcaCadence::Interval(3600);
+        assert_eq!(hourly.next_after(now).unwrap(), now + Duration::hours(1));
+
+        assert!(DcaCadence::Cron("not a cron".to_string()).next_after(now).is_err());
+        assert!(DcaCadence::from_parts(Some("0 0 9 * * *"), Some(60)).is_err());
+        assert!(DcaCadence::from_parts(None, None).is_err());
+    }
+
+    #[test]
+    fn test_create_schedule_validation() {
+        let service = DcaDomainService::new();
+        let now = Utc::now();
+
+        let schedule = service.create_schedule(1, 1, plan(DcaCadence::Interval(86400), Some(1.0)), 3600, 0.05, now).unwrap();
+        assert_eq!(schedule.next_run_at, ActiveValue::set(now + Duration::days(1)));
+
+        // 执行过于频繁
+        assert!(service.create_schedule(1, 1, plan(DcaCadence::Cron("0 * * * * *".to_string()), None), 3600, 0.05, now).is_err());
+        // 支出上限小于单次数量
+        assert!(service.create_schedule(1, 1, plan(DcaCadence::Interval(86400), Some(0.05)), 3600, 0.05, now).is_err());
+
+        // 转账计划必须指定接收地址
+        let mut transfer = plan(DcaCadence::Interval(86400), None);
+        transfer.kind = DcaKind::Transfer;
+        assert!(service.create_schedule(1, 1, transfer, 3600, 0.05, now).is_err());
+    }
+
+    #[test]
+    fn test_retry_backoff_and_spend_cap() {
+        let service = DcaDomainService::new();
+        let now = Utc::now();
+        let active = service.create_schedule(1, 1, plan(DcaCadence::Interval(86400), Some(0.25)), 3600, 0.05, now).unwrap();
+        let mut schedule = crate::core::domain::dca::entity::dca_schedule_entity::Model {
+            id: 1,
+            user_id: 1,
+            wallet_id: 1,
+            kind: DcaKind::Swap,
+            input_mint: active.input_mint.unwrap(),
+            output_mint: active.output_mint.unwrap(),
+            recipient: None,
+            amount: 0.1,
+            max_slippage: 0.01,
+            cron: None,
+            interval_secs: Some(86400),
+            spend_cap: Some(0.25),
+            spent: 0.0,
+            retry_count: 0,
+            status: DcaStatus::Active,
+            next_run_at: now,
+            last_run_at: None,
+            last_error: None,
+            created_at: now,
+            updated_at: now,
+        };
+
+        // 退避时间按 60、120、240 秒递增，超过重试次数后放弃本周期
+        let next = now + Duration::days(1);
+        for expected in [60, 120, 240] {
+            let retry_at = service.retry_at(&schedule, 3, 60, 200, now);
+            assert_eq!(retry_at, Some(now + Duration::seconds(expected.min(200))));
+            schedule.record_failure("rpc error".to_string(), retry_at, next);
+        }
+        assert_eq!(schedule.retry_count, 3);
+        assert_eq!(service.retry_at(&schedule, 3, 60, 200, now), None);
+        schedule.record_failure("rpc error".to_string(), None, next);
+        assert_eq!(schedule.retry_count, 0);
+        assert_eq!(schedule.next_run_at, next);
+
+        // 两次成功后累计 0.2，下一次将超过 0.25 的上限，计划完成
+        schedule.record_success(next);
+        assert_eq!(schedule.status, DcaStatus::Active);
+        schedule.record_success(next);
+        assert_eq!(schedule.status, DcaStatus::Completed);
+        assert!(!schedule.within_cap());
+    }
+}
+
+#[cfg(test)]
+mod market_test {
+    use std::error::Error;
+    use chrono::Utc;
+    use solana_program::pubkey::Pubkey;
+
+    use std::str::FromStr;
+
+    use crate::core::domain::market::entity::{BookLevel, MarketMeta};
+    use crate::core::domain::market::service::MarketDomainService;
+    use crate::core::infrastructure::openbook::{EventQueueAccount, MarketStateAccount, SlabAccount};
+
+    /// OpenBook SOL/USDC 市场
+    const SOL_USDC_MARKET: &str = "8BnEgHoWFysVcuFFX7QztDmzuH8r5ZFvyP3sYwn1XTh6";
+
+    /// 按 OpenBook 账户格式包装数据：头部 "serum" + 标志位 + 数据 + 尾部 "padding"
+    fn wrap(flags: u64, body: &[u8]) -> Vec<u8> {
+        let mut data = b"serum".to_vec();
+        data.extend_from_slice(&flags.to_le_bytes());
+        data.extend_from_slice(body);
+        data.extend_from_slice(b"padding");
+        data
+    }
+
+    fn leaf(price_lots: u64, seq: u64, quantity: u64, owner: &Pubkey) -> [u8; 72] {
+        let mut node = [0u8; 72];
+        node[0..4].copy_from_slice(&2u32.to_le_bytes());
+        node[8..24].copy_from_slice(&(((price_lots as u128) << 64) | seq as u128).to_le_bytes());
+        node[24..56].copy_from_slice(owner.as_ref());
+        node[56..64].copy_from_slice(&quantity.to_le_bytes());
+        node
+    }
+
+    fn inner(children: [u32; 2]) -> [u8; 72] {
+        let mut node = [0u8; 72];
+        node[0..4].copy_from_slice(&1u32.to_le_bytes());
+        node[24..28].copy_from_slice(&children[0].to_le_bytes());
+        node[28..32].copy_from_slice(&children[1].to_le_bytes());
+        node
+    }
+
+    fn slab(flags: u64, root: u32, leaf_count: u64, nodes: &[[u8; 72]]) -> Vec<u8> {
+        let mut body = vec![0u8; 32];
+        body[20..24].copy_from_slice(&root.to_le_bytes());
+        body[24..32].copy_from_slice(&leaf_count.to_le_bytes());
+        for node in nodes {
+            body.extend_from_slice(node);
+        }
+        wrap(flags, &body)
+    }
+
+    fn meta() -> MarketMeta {
+        // SOL/USDC：coin lot 0.001 SOL，pc lot 0.000001 USDC 对应 0.001 价格步长
+        MarketMeta {
+            address: "market".to_string(),
+            coin_mint: "coin".to_string(),
+            pc_mint: "pc".to_string(),
+            bids: "bids".to_string(),
+            asks: "asks".to_string(),
+            event_queue: "event_queue".to_string(),
+            coin_lot_size: 1_000_000,
+            pc_lot_size: 1,
+            coin_decimals: 9,
+            pc_decimals: 6,
+        }
+    }
+
+    #[test]
+    fn test_decode_market_state() -> Result<(), Box<dyn Error>> {
+        let coin_mint = Pubkey::new_unique();
+        let pc_mint = Pubkey::new_unique();
+        let event_queue = Pubkey::new_unique();
+        let bids = Pubkey::new_unique();
+        let asks = Pubkey::new_unique();
+
+        let mut body = vec![0u8; 368];
+        body[40..72].copy_from_slice(coin_mint.as_ref());
+        body[72..104].copy_from_slice(pc_mint.as_ref());
+        body[240..272].copy_from_slice(event_queue.as_ref());
+        body[272..304].copy_from_slice(bids.as_ref());
+        body[304..336].copy_from_slice(asks.as_ref());
+        body[336..344].copy_from_slice(&1_000_000u64.to_le_bytes());
+        body[344..352].copy_from_slice(&1u64.to_le_bytes());
+
+        let layout = MarketStateAccount::decode(&wrap(0b11, &body))?;
+        assert_eq!(layout.coin_mint, coin_mint);
+        assert_eq!(layout.pc_mint, pc_mint);
+        assert_eq!(layout.event_queue, event_queue);
+        assert_eq!(layout.bids, bids);
+        assert_eq!(layout.asks, asks);
+        assert_eq!(layout.coin_lot_size, 1_000_000);
+
+        // 标志位不是市场账户
+        assert!(MarketStateAccount::decode(&wrap(0b1, &body)).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_decode_slab_to_l2_book() -> Result<(), Box<dyn Error>> {
+        let owner = Pubkey::new_unique();
+        // 根节点 0、3 为内部节点，叶子 1、2、5；节点 4 在空闲链表上，从根不可达
+        let data = slab(0b100001, 0, 3, &[
+            inner([1, 3]),
+            leaf(150_000, 1, 2_000, &owner),
+            leaf(149_500, 2, 1_000, &owner),
+            inner([2, 5]),
+            leaf(1, 9, 9, &owner),
+            leaf(150_000, 3, 2_000, &owner),
+        ]);
+
+        let orders = SlabAccount::decode_bids(&data)?;
+        assert_eq!(orders.len(), 3);
+        assert!(SlabAccount::decode_asks(&data).is_err());
+
+        let service = MarketDomainService::new();
+        let book = service.build_book(&meta(), orders, Vec::new(), 10, Utc::now());
+        assert_eq!(book.bids, vec![
+            BookLevel { price: 150.0, size: 4.0 },
+            BookLevel { price: 149.5, size: 1.0 },
+        ]);
+
+        // 档位数量限制
+        let orders = SlabAccount::decode_bids(&data)?;
+        assert_eq!(service.build_book(&meta(), orders, Vec::new(), 1, Utc::now()).bids.len(), 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_decode_event_queue_fills() -> Result<(), Box<dyn Error>> {
+        let owner = Pubkey::new_unique();
+        let event = |flags: u8, released: u64, paid: u64, fee: u64, order_id: u128| {
+            let mut event = [0u8; 88];
+            event[0] = flags;
+            event[8..16].copy_from_slice(&released.to_le_bytes());
+            event[16..24].copy_from_slice(&paid.to_le_bytes());
+            event[24..32].copy_from_slice(&fee.to_le_bytes());
+            event[32..48].copy_from_slice(&order_id.to_le_bytes());
+            event[48..80].copy_from_slice(owner.as_ref());
+            event
+        };
+
+        // 容量 4 的环形队列，head = 2，count = 1：槽位 0、1 为已消费历史，槽位 2 为待处理事件，槽位 3 从未写入
+        let mut body = vec![0u8; 24];
+        body[0..8].copy_from_slice(&2u64.to_le_bytes());
+        body[8..16].copy_from_slice(&1u64.to_le_bytes());
+        // 吃单买入 1 SOL，支付 150.06 USDC（含 0.06 手续费）
+        body.extend_from_slice(&event(0b0101, 1_000_000_000, 150_060_000, 60_000, 7));
+        // 挂单方事件，应被忽略
+        body.extend_from_slice(&event(0b1001, 150_000_000, 1_000_000_000, 0, 8));
+        // 吃单卖出 2 SOL，得到 299.88 USDC（已扣 0.12 手续费）
+        body.extend_from_slice(&event(0b0001, 299_880_000, 2_000_000_000, 120_000, 9));
+        body.extend_from_slice(&[0u8; 88]);
+
+        let events = EventQueueAccount::decode(&wrap(0b10001, &body), 10)?;
+        assert_eq!(events.len(), 3);
+        assert_eq!(events[0].order_id, 9);
+
+        let fills = MarketDomainService::new().fills(&meta(), events, 10);
+        assert_eq!(fills.len(), 2);
+        assert_eq!(fills[0].side, "sell");
+        assert!((fills[0].price - 150.0).abs() < 1e-9);
+        assert!((fills[0].size - 2.0).abs() < 1e-9);
+        assert_eq!(fills[1].side, "buy");
+        assert!((fills[1].price - 150.0).abs() < 1e-9);
+        assert_eq!(fills[1].owner, owner.to_string());
+
+        Ok(())
+    }
+
+    /// 用 serum-dex 布局的完整尺寸账户数据校验解码，避免手工构造的片段与链上格式一起写错（见 fixtures/openbook/README.md）
+    #[test]
+    fn test_decode_market_fixtures() -> Result<(), Box<dyn Error>> {
+        let market = include_bytes!("fixtures/openbook/market.bin");
+        let bids = include_bytes!("fixtures/openbook/bids.bin");
+        let asks = include_bytes!("fixtures/openbook/asks.bin");
+        let event_queue = include_bytes!("fixtures/openbook/event_queue.bin");
+
+        let layout = MarketStateAccount::decode(market)?;
+        assert_eq!(layout.coin_mint, spl_token::native_mint::id());
+        assert_eq!(layout.pc_mint, Pubkey::from_str("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v")?);
+        assert!(layout.coin_lot_size > 0 && layout.pc_lot_size > 0);
+        assert!(MarketStateAccount::decode(bids).is_err());
+
+        let bid_orders = SlabAccount::decode_bids(bids)?;
+        let ask_orders = SlabAccount::decode_asks(asks)?;
+        assert!(!bid_orders.is_empty() && !ask_orders.is_empty());
+        assert!(SlabAccount::decode_asks(bids).is_err());
+        assert!(SlabAccount::decode_bids(asks).is_err());
+
+        let meta = MarketMeta {
+            address: SOL_USDC_MARKET.to_string(),
+            coin_mint: layout.coin_mint.to_string(),
+            pc_mint: layout.pc_mint.to_string(),
+            bids: layout.bids.to_string(),
+            asks: layout.asks.to_string(),
+            event_queue: layout.event_queue.to_string(),
+            coin_lot_size: layout.coin_lot_size,
+            pc_lot_size: layout.pc_lot_size,
+            coin_decimals: 9,
+            pc_decimals: 6,
+        };
+        let service = MarketDomainService::new();
+        let book = service.build_book(&meta, bid_orders, ask_orders, 20, Utc::now());
+        // 买盘从高到低，卖盘从低到高，且不交叉
+        assert!(book.bids.windows(2).all(|pair| pair[0].price > pair[1].price));
+        assert!(book.asks.windows(2).all(|pair| pair[0].price < pair[1].price));
+        let (bid, ask) = (book.bids.first().ok_or("买盘为空")?, book.asks.first().ok_or("卖盘为空")?);
+        assert!(bid.price < ask.price);
+
+        let events = EventQueueAccount::decode(event_queue, 50)?;
+        assert!(!events.is_empty());
+        assert!(EventQueueAccount::decode(bids, 50).is_err());
+        let fills = service.fills(&meta, events, 50);
+        assert!(!fills.is_empty());
+        for fill in fills {
+            assert!(fill.price > 0.0 && fill.size > 0.0);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod indexer_test {
+    use solana_sdk::instruction::{AccountMeta, Instruction};
+    use solana_sdk::message::{Message, VersionedMessage};
+    use solana_sdk::pubkey::Pubkey;
+    use solana_sdk::signature::Signature;
+    use solana_sdk::transaction::VersionedTransaction;
+    use solana_transaction_status::UiLoadedAddresses;
+
+    use crate::core::domain::indexer::service::IndexerDomainService;
+
+    fn transaction(instructions: &[Instruction], payer: &Pubkey) -> VersionedTransaction {
+        VersionedTransaction {
+            signatures: vec![Signature::default()],
+            message: VersionedMessage::Legacy(Message::new(instructions, Some(payer))),
+        }
+    }
+
+    #[test]
+    fn test_next_range_and_skipped_slots() {
+        let service = IndexerDomainService::new();
+
+        assert_eq!(service.next_range(Some(100), 100, 50), None);
+        assert_eq!(service.next_range(Some(100), 120, 50), Some((101, 120)));
+        assert_eq!(service.next_range(Some(100), 500, 50), Some((101, 150)));
+        // 从未索引过时从 slot 0 开始
+        assert_eq!(service.next_range(None, 0, 50), Some((0, 0)));
+        assert_eq!(service.next_range(None, 500, 50), Some((0, 49)));
+
+        // 103、105 没有出块
+        assert_eq!(service.skipped_slots(101, 106, &[101, 102, 104, 106]), vec![103, 105]);
+        assert!(service.skipped_slots(101, 102, &[101, 102]).is_empty());
+    }
+
+    #[test]
+    fn test_vote_transaction_classification() {
+        let service = IndexerDomainService::new();
+        let payer = Pubkey::new_unique();
+        let vote = Instruction::new_with_bytes(solana_sdk::vote::program::id(), &[], vec![AccountMeta::new(payer, true)]);
+        let other = Instruction::new_with_bytes(Pubkey::new_unique(), &[1], vec![]);
+
+        assert!(service.is_vote_transaction(&transaction(&[vote.clone()], &payer)));
+        // 混合了其他程序指令的交易按用户交易处理
+        assert!(!service.is_vote_transaction(&transaction(&[vote, other.clone()], &payer)));
+        assert!(!service.is_vote_transaction(&transaction(&[other], &payer)));
+    }
+
+    #[test]
+    fn test_account_keys_and_program_ids() {
+        let service = IndexerDomainService::new();
+        let payer = Pubkey::new_unique();
+        let program = Pubkey::new_unique();
+        let ix = Instruction::new_with_bytes(program, &[1], vec![AccountMeta::new(payer, true)]);
+        let tx = transaction(&[ix.clone(), ix], &payer);
+
+        let loaded = UiLoadedAddresses {
+            writable: vec!["LoadedWritable1111111111111111111111111111".to_string()],
+            readonly: vec!["LoadedReadonly1111111111111111111111111111".to_string()],
+        };
+        let accounts = service.account_keys(&tx, Some(&loaded));
+        assert_eq!(accounts, vec![
+            payer.to_string(),
+            program.to_string(),
+            loaded.writable[0].clone(),
+            loaded.readonly[0].clone(),
+        ]);
+
+        // 同一程序的多条指令只记录一次
+        assert_eq!(service.program_ids(&tx, &accounts), vec![program.to_string()]);
+    }
+}
+
+#[cfg(test)]
+mod network_test {
+    use chrono::Utc;
+
+    use crate::core::domain::network::entity::network_sample_entity::Model as NetworkSampleModel;
+    use crate::core::domain::network::service::NetworkDomainService;
+
+    fn sample(slot: i64, block_time: i64, transactions: i32, non_vote: i32, fee: i64, compute_units: i64) -> NetworkSampleModel {
+        NetworkSampleModel {
+            slot,
+            block_time,
+            transaction_count: transactions,
+            non_vote_transaction_count: non_vote,
+            total_fee: fee,
+            compute_units,
+            created_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_validate_query() {
+        let service = NetworkDomainService::new();
+
+        assert!(service.validate_query(300, 10, 86400, 500).is_ok());
+        assert!(service.validate_query(0, 10, 86400, 500).is_err());
+        assert!(service.validate_query(10, 60, 86400, 500).is_err());
+        assert!(service.validate_query(172800, 3600, 86400, 500).is_err());
+        // 3600 / 1 = 3600 个时间桶，超过上限
+        assert!(service.validate_query(3600, 1, 86400, 500).is_err());
+    }
+
+    #[test]
+    fn test_aggregate_buckets() {
+        let service = NetworkDomainService::new();
+        // 窗口 [1000, 1025)，粒度 10 秒：[1000, 1010) [1010, 1020) [1020, 1025)
+        let samples = vec![
+            sample(1, 990, 100, 10, 5000, 1_000),
+            sample(2, 1001, 1000, 200, 10_000, 2_000_000),
+            sample(3, 1002, 1000, 400, 30_000, 4_000_000),
+            sample(4, 1021, 500, 100, 5000, 1_000_000),
+        ];
+
+        let stats = service.aggregate(&samples, 25, 10, 1025);
+        assert_eq!(stats.first_slot, Some(2));
+        assert_eq!(stats.last_slot, Some(4));
+        assert_eq!(stats.series.len(), 3);
+
+        let first = &stats.series[0];
+        assert_eq!((first.start_time, first.end_time, first.blocks), (1000, 1010, 2));
+        assert!((first.tps - 200.0).abs() < 1e-9);
+        assert!((first.non_vote_tps - 60.0).abs() < 1e-9);
+        assert!((first.avg_fee_per_block - 20_000.0).abs() < 1e-9);
+        assert!((first.avg_compute_units_per_block - 3_000_000.0).abs() < 1e-9);
+
+        // 空桶不产生除零
+        assert_eq!(stats.series[1].blocks, 0);
+        assert_eq!(stats.series[1].avg_fee_per_block, 0.0);
+
+        // 最后一个桶只有 5 秒
+        assert_eq!(stats.series[2].end_time - stats.series[2].start_time, 5);
+        assert!((stats.series[2].tps - 100.0).abs() < 1e-9);
+
+        assert_eq!(stats.summary.transactions, 2500);
+        assert!((stats.summary.tps - 100.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_tps_zero_duration() {
+        let service = NetworkDomainService::new();
+
+        assert_eq!(service.tps(100, 0), 0.0);
+        assert!((service.tps(100, 4) - 25.0).abs() < 1e-9);
+    }
+}
+
+#[cfg(test)]
+mod history_test {
+    use solana_sdk::pubkey::Pubkey;
+    use solana_transaction_status::UiTransactionTokenBalance;
+
+    use crate::core::domain::history::service::HistoryDomainService;
+    use crate::core::domain::instruction::decoder::TOKEN_PROGRAM_ID;
+
+    fn token_balance(account_index: u8, mint: &str, owner: &str, amount: &str, decimals: u8) -> UiTransactionTokenBalance {
+        serde_json::from_value(serde_json::json!({
+            "accountIndex": account_index,
+            "mint": mint,
+            "uiTokenAmount": { "uiAmount": null, "decimals": decimals, "amount": amount, "uiAmountString": "" },
+            "owner": owner,
+            "programId": TOKEN_PROGRAM_ID,
+        })).unwrap()
+    }
+
+    #[test]
+    fn test_balance_deltas() {
+        let service = HistoryDomainService::new();
+        let wallet = Pubkey::new_unique().to_string();
+        let other = Pubkey::new_unique().to_string();
+        let usdc = "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v";
+
+        // 钱包支付 5000 lamports 手续费并转出 1 SOL
+        let accounts = vec![wallet.clone(), other.clone()];
+        assert_eq!(service.sol_delta(&wallet, &accounts, &[3_000_000_000, 0], &[1_999_995_000, 1_000_000_000]), -1_000_005_000);
+        assert_eq!(service.sol_delta("missing", &accounts, &[1, 2], &[3, 4]), 0);
+
+        let pre = vec![token_balance(2, usdc, &wallet, "5000000", 6), token_balance(3, usdc, &other, "0", 6)];
+        let post = vec![token_balance(2, usdc, &wallet, "3500000", 6), token_balance(3, usdc, &other, "1500000", 6)];
+        let deltas = service.token_deltas(&wallet, &pre, &post);
+        assert_eq!(deltas.len(), 1);
+        assert_eq!(deltas[0].amount, "-1500000");
+        assert!((deltas[0].ui_amount + 1.5).abs() < 1e-9);
+
+        // 新建的代币账户只出现在交易后余额中
+        let created = service.token_deltas(&other, &[], &post);
+        assert_eq!(created[0].amount, "1500000");
+    }
+}
+
+#[cfg(test)]
+mod instruction_test {
+    use std::collections::HashMap;
+    use solana_sdk::hash::Hash;
+    use solana_sdk::instruction::CompiledInstruction;
+    use solana_sdk::message::v0::{Message as V0Message, MessageAddressTableLookup};
+    use solana_sdk::message::{MessageHeader, VersionedMessage};
+    use solana_sdk::pubkey::Pubkey;
+    use solana_sdk::signature::Signature;
+    use solana_sdk::system_instruction::SystemInstruction;
+    use solana_sdk::transaction::VersionedTransaction;
+
+    use crate::core::domain::instruction::decoder::{
+        ATA_PROGRAM_ID, COMPUTE_BUDGET_PROGRAM_ID, MEMO_PROGRAM_ID, RAYDIUM_AMM_PROGRAM_ID, SYSTEM_PROGRAM_ID, TOKEN_PROGRAM_ID,
+    };
+    use crate::core::domain::instruction::entity::instruction_args_entity::{ComputeBudgetArgs, InstructionArgs, RaydiumAmmArgs};
+    use crate::core::domain::instruction::entity::parsed_instruction_entity::AccountKey;
+    use crate::core::domain::instruction::service::InstructionDomainService;
+
+    fn account(pubkey: &str, is_signer: bool, is_writable: bool) -> AccountKey {
+        AccountKey { pubkey: pubkey.to_string(), is_signer, is_writable }
+    }
+
+    #[test]
+    fn test_decode_known_programs() {
+        let service = InstructionDomainService::new();
+        let from = Pubkey::new_unique().to_string();
+        let to = Pubkey::new_unique().to_string();
+        let mint = Pubkey::new_unique().to_string();
+
+        let data = bincode::serialize(&SystemInstruction::Transfer { lamports: 1_500_000_000 }).unwrap();
+        let transfer = service.decode(SYSTEM_PROGRAM_ID, &data, &[account(&from, true, true), account(&to, false, true)]);
+        assert_eq!((transfer.program.as_str(), transfer.name.as_str()), ("system", "transfer"));
+        assert_eq!(transfer.account("destination"), Some(to.as_str()));
+        assert!(service.describe(&transfer).contains("1.5 SOL"));
+
+        // TransferChecked: tag 12 + amount + decimals
+        let mut data = vec![12u8];
+        data.extend_from_slice(&2_500_000u64.to_le_bytes());
+        data.push(6);
+        let accounts = [account(&from, false, true), account(&mint, false, false), account(&to, false, true), account(&from, true, false)];
+        let checked = service.decode(TOKEN_PROGRAM_ID, &data, &accounts);
+        assert_eq!(checked.name, "transferChecked");
+        let names: Vec<&str> = checked.accounts.iter().map(|account| account.name.as_str()).collect();
+        assert_eq!(names, vec!["source", "mint", "destination", "authority"]);
+        assert!(service.describe(&checked).contains("2.5"));
+
+        let mut data = vec![3u8];
+        data.extend_from_slice(&10_000u64.to_le_bytes());
+        let price = service.decode(COMPUTE_BUDGET_PROGRAM_ID, &data, &[]);
+        assert_eq!(price.args, InstructionArgs::ComputeBudget(ComputeBudgetArgs::SetComputeUnitPrice { micro_lamports: 10_000 }));
+
+        let memo = service.decode(MEMO_PROGRAM_ID, "order #42".as_bytes(), &[account(&from, true, false)]);
+        assert_eq!(service.describe(&memo), "备注：order #42");
+        assert_eq!(memo.accounts[0].name, "signer");
+
+        // 已注册程序的无法解析指令保留程序名称
+        let broken = service.decode(ATA_PROGRAM_ID, &[9], &[]);
+        assert_eq!((broken.program.as_str(), broken.name.as_str()), ("spl-associated-token-account", "unknown"));
+
+        let unknown = service.decode(&Pubkey::new_unique().to_string(), &[1, 2, 3], &[account(&from, false, false)]);
+        assert_eq!(unknown.program, "unknown");
+        assert_eq!(unknown.args, InstructionArgs::Raw { data: "AQID".to_string() });
+        assert_eq!(unknown.accounts[0].name, "remaining");
+    }
+
+    #[test]
+    fn test_raydium_swap_account_layouts() {
+        let service = InstructionDomainService::new();
+        let mut data = vec![9u8];
+        data.extend_from_slice(&1_000u64.to_le_bytes());
+        data.extend_from_slice(&990u64.to_le_bytes());
+
+        let keys: Vec<AccountKey> = (0..18).map(|_| account(&Pubkey::new_unique().to_string(), false, true)).collect();
+        let full = service.decode(RAYDIUM_AMM_PROGRAM_ID, &data, &keys);
+        assert_eq!(full.args, InstructionArgs::RaydiumAmm(RaydiumAmmArgs::SwapBaseIn { amount_in: 1_000, minimum_amount_out: 990 }));
+        assert_eq!(full.accounts[4].name, "ammTargetOrders");
+        assert_eq!(full.account("userSourceOwner"), Some(keys[17].pubkey.as_str()));
+
+        // 省略 ammTargetOrders 的 17 账户布局
+        let short = service.decode(RAYDIUM_AMM_PROGRAM_ID, &data, &keys[..17]);
+        assert_eq!(short.accounts[4].name, "poolCoinTokenAccount");
+        assert_eq!(short.account("userSourceOwner"), Some(keys[16].pubkey.as_str()));
+    }
+
+    #[test]
+    fn test_v0_lookup_table_resolution() {
+        let service = InstructionDomainService::new();
+        let payer = Pubkey::new_unique();
+        let table_key = Pubkey::new_unique();
+        let (first, second) = (Pubkey::new_unique(), Pubkey::new_unique());
+
+        let mut table_data = vec![0u8; 56];
+        table_data.extend_from_slice(first.as_ref());
+        table_data.extend_from_slice(second.as_ref());
+        let tables = HashMap::from([(table_key.to_string(), service.lookup_table_addresses(&table_data).unwrap())]);
+
+        let message = VersionedMessage::V0(V0Message {
+            header: MessageHeader { num_required_signatures: 1, num_readonly_signed_accounts: 0, num_readonly_unsigned_accounts: 1 },
+            account_keys: vec![payer, solana_sdk::system_program::id()],
+            recent_blockhash: Hash::default(),
+            instructions: vec![CompiledInstruction::new_from_raw_parts(
+                1,
+                bincode::serialize(&SystemInstruction::Transfer { lamports: 5 }).unwrap(),
+                vec![0, 2],
+            )],
+            address_table_lookups: vec![MessageAddressTableLookup { account_key: table_key, writable_indexes: vec![1], readonly_indexes: vec![] }],
+        });
+        let loaded = service.resolve_lookups(&message, &tables).unwrap();
+        assert_eq!(loaded.writable, vec![second.to_string()]);
+        assert!(service.resolve_lookups(&message, &HashMap::new()).is_err());
+
+        let transaction = VersionedTransaction { signatures: vec![Signature::default()], message };
+        let parsed = service.decode_transaction(&transaction, &loaded);
+        assert_eq!((parsed.version.as_str(), parsed.signature.as_deref()), ("0", None));
+        assert!(!parsed.accounts[1].is_writable);
+
+        let transfer = &parsed.instructions[0];
+        assert_eq!(transfer.name, "transfer");
+        let destination = &transfer.accounts[1];
+        assert_eq!((destination.pubkey.as_str(), destination.is_signer, destination.is_writable), (second.to_string().as_str(), false, true));
+        assert!(transfer.accounts[0].is_signer);
+    }
+
+    #[test]
+    fn test_anchor_idl_decoding() {
+        use std::io::Write;
+        use flate2::write::ZlibEncoder;
+        use solana_sdk::hash::hash;
+
+        let service = InstructionDomainService::new();
+        let program_id = Pubkey::new_unique().to_string();
+        let owner = Pubkey::new_unique();
+        let idl_json = serde_json::json!({
+            "version": "0.1.0",
+            "name": "vault",
+            "instructions": [{
+                "name": "initializeVault",
+                "accounts": [
+                    { "name": "vault", "isMut": true, "isSigner": false },
+                    { "name": "common", "accounts": [{ "name": "owner", "isMut": false, "isSigner": true }] }
+                ],
+                "args": [
+                    { "name": "amount", "type": "u64" },
+                    { "name": "label", "type": "string" },
+                    { "name": "side", "type": { "defined": "Side" } }
+                ]
+            }],
+            "accounts": [{
+                "name": "Vault",
+                "type": { "kind": "struct", "fields": [
+                    { "name": "owner", "type": "publicKey" },
+                    { "name": "balance", "type": "u64" },
+                    { "name": "tag", "type": { "option": "u16" } }
+                ] }
+            }],
+            "types": [{ "name": "Side", "type": { "kind": "enum", "variants": [{ "name": "Bid" }, { "name": "Ask" }] } }]
+        }).to_string();
+
+        // 链上 IDL 账户：鉴别器 + authority + 长度前缀 + zlib 压缩的 JSON
+        let mut encoder = ZlibEncoder::new(Vec::new(), flate2::Compression::default());
+        encoder.write_all(idl_json.as_bytes()).unwrap();
+        let compressed = encoder.finish().unwrap();
+        let mut account_data = vec![0u8; 40];
+        account_data.extend_from_slice(&(compressed.len() as u32).to_le_bytes());
+        account_data.extend_from_slice(&compressed);
+        let idl = service.parse_idl_account(&account_data).unwrap();
+        assert_eq!(idl.program_name(), "vault");
+        service.register_idl(&program_id, idl.clone()).unwrap();
+        // 内置程序的解码器不能被 IDL 覆盖
+        assert!(service.register_idl(SYSTEM_PROGRAM_ID, idl).is_err());
+
+        let mut data = hash(b"global:initialize_vault").to_bytes()[..8].to_vec();
+        data.extend_from_slice(&500u64.to_le_bytes());
+        data.extend_from_slice(&3u32.to_le_bytes());
+        data.extend_from_slice(b"abc");
+        data.push(1);
+        let keys = [account(&Pubkey::new_unique().to_string(), false, true), account(&owner.to_string(), true, false)];
+        let parsed = service.decode(&program_id, &data, &keys);
+        assert_eq!((parsed.program.as_str(), parsed.name.as_str()), ("vault", "initializeVault"));
+        assert_eq!(parsed.args, InstructionArgs::Anchor(serde_json::json!({ "amount": 500, "label": "abc", "side": "Ask" })));
+        assert_eq!(parsed.account("common.owner"), Some(owner.to_string().as_str()));
+
+        let mut vault = hash(b"account:Vault").to_bytes()[..8].to_vec();
+        vault.extend_from_slice(owner.as_ref());
+        vault.extend_from_slice(&42u64.to_le_bytes());
+        vault.extend_from_slice(&[1, 7, 0]);
+        let decoded = service.decode_anchor_account(&program_id, &vault).unwrap();
+        assert_eq!(decoded.account_type, "Vault");
+        assert_eq!(decoded.data, serde_json::json!({ "owner": owner.to_string(), "balance": 42, "tag": 7 }));
+
+        // 鉴别器不匹配时不解码
+        assert!(service.decode_anchor_account(&program_id, &[0u8; 16]).is_none());
+    }
+}
+
+#[cfg(test)]
+mod account_test {
+    use solana_sdk::account::Account;
+    use solana_sdk::bpf_loader_upgradeable::{self, UpgradeableLoaderState};
+    use solana_sdk::program_option::COption;
+    use solana_sdk::program_pack::Pack;
+    use solana_sdk::pubkey::Pubkey;
+    use solana_sdk::rent::Rent;
+    use spl_associated_token_account::get_associated_token_address;
+    use spl_token::state::{Account as TokenAccount, AccountState, Mint};
+
+    use crate::core::domain::account::entity::account_inspection_entity::DecodedAccountData;
+    use crate::core::domain::account::service::AccountDomainService;
+
+    fn account(owner: Pubkey, lamports: u64, data: Vec<u8>) -> Account {
+        Account { lamports, data, owner, executable: false, rent_epoch: 0 }
+    }
+
+    #[test]
+    fn test_inspect_token_accounts() {
+        let service = AccountDomainService::new();
+        let wallet = Pubkey::new_unique();
+        let mint = Pubkey::new_unique();
+
+        let mut data = vec![0u8; TokenAccount::LEN];
+        TokenAccount {
+            mint,
+            owner: wallet,
+            amount: 1_000,
+            state: AccountState::Initialized,
+            ..TokenAccount::default()
+        }.pack_into_slice(&mut data);
+        let lamports = Rent::default().minimum_balance(TokenAccount::LEN);
+        let token_account = account(spl_token::id(), lamports, data);
+
+        // 关联代币账户地址上的代币账户标记为 ATA
+        let ata = get_associated_token_address(&wallet, &mint);
+        let inspection = service.inspect(&ata, &token_account, None);
+        assert!(inspection.rent_exempt);
+        assert!(inspection.raw.is_none());
+        match inspection.decoded {
+            Some(DecodedAccountData::TokenAccount { owner, amount, state, is_associated, .. }) => {
+                assert_eq!((owner, amount, state.as_str(), is_associated), (wallet.to_string(), 1_000, "initialized", true));
+            }
+            other => panic!("unexpected decoded data: {:?}", other),
+        }
+
+        // 其他地址上的同一账户不是 ATA
+        let inspection = service.inspect(&Pubkey::new_unique(), &token_account, None);
+        assert!(matches!(inspection.decoded, Some(DecodedAccountData::TokenAccount { is_associated: false, .. })));
+
+        let mut data = vec![0u8; Mint::LEN];
+        Mint {
+            mint_authority: COption::Some(wallet),
+            supply: 5_000,
+            decimals: 6,
+            is_initialized: true,
+            freeze_authority: COption::None,
+        }.pack_into_slice(&mut data);
+        let inspection = service.inspect(&mint, &account(spl_token::id(), 0, data), None);
+        assert!(!inspection.rent_exempt);
+        assert_eq!(inspection.decoded, Some(DecodedAccountData::Mint {
+            program: spl_token::id().to_string(),
+            mint_authority: Some(wallet.to_string()),
+            supply: 5_000,
+            decimals: 6,
+            is_initialized: true,
+            freeze_authority: None,
+        }));
+    }
+
+    #[test]
+    fn test_inspect_upgradeable_program() {
+        let service = AccountDomainService::new();
+        let authority = Pubkey::new_unique();
+
+        let mut data = bincode::serialize(&UpgradeableLoaderState::ProgramData { slot: 42, upgrade_authority_address: Some(authority) }).unwrap();
+        data.resize(UpgradeableLoaderState::size_of_programdata_metadata() + 100, 0);
+        let inspection = service.inspect(&Pubkey::new_unique(), &account(bpf_loader_upgradeable::id(), 1, data), None);
+        assert_eq!(inspection.decoded, Some(DecodedAccountData::ProgramData {
+            slot: 42,
+            upgrade_authority: Some(authority.to_string()),
+            program_len: 100,
+        }));
+    }
+
+    #[test]
+    fn test_inspect_raw_fallback() {
+        let service = AccountDomainService::new();
+        let owner = Pubkey::new_unique();
+
+        // 未知程序的账户返回原始数据
+        let inspection = service.inspect(&Pubkey::new_unique(), &account(owner, 0, vec![0xde, 0xad, 0xbe, 0xef]), None);
+        assert!(inspection.decoded.is_none());
+        let raw = inspection.raw.unwrap();
+        assert_eq!((raw.base64.as_str(), raw.hex.as_str()), ("3q2+7w==", "deadbeef"));
+
+        // 调用方按 IDL 解码的结果优先于原始数据
+        let anchor = DecodedAccountData::Anchor { program: "vault".to_string(), account_type: "Vault".to_string(), data: serde_json::json!({}) };
+        let inspection = service.inspect(&Pubkey::new_unique(), &account(owner, 0, vec![1; 8]), Some(anchor.clone()));
+        assert_eq!((inspection.decoded, inspection.raw), (Some(anchor), None));
+
+        // 无数据的系统账户只需满足 0 字节的免租额度
+        let inspection = service.inspect(&Pubkey::new_unique(), &account(solana_sdk::system_program::id(), 1_000_000_000, vec![]), None);
+        assert_eq!((inspection.sol, inspection.rent_exempt, inspection.raw), (1.0, true, None));
+    }
+}
+
+#[cfg(test)]
+mod webhook_test {
+    use std::sync::{Arc, Mutex};
+    use axum::{extract::State, http::{HeaderMap, StatusCode}, routing::post, Router};
+    use chrono::{Duration, Utc};
+    use hmac::{Hmac, Mac};
+    use serde_json::json;
+    use sha2::Sha256;
+
+    use crate::core::application::webhook::WebhookApplication;
+    use crate::core::domain::webhook::entity::webhook_delivery_entity::{DeliveryStatus, Model as WebhookDeliveryModel};
+    use crate::core::domain::webhook::entity::webhook_event_entity::WebhookEventType;
+    use crate::core::domain::webhook::service::WebhookDomainService;
+
+    type Captured = Arc<Mutex<Vec<(HeaderMap, String)>>>;
+
+    /// 本地接收端：记录请求头和请求体，按路径返回 200 或 500
+    async fn spawn_receiver() -> (String, Captured) {
+        let captured: Captured = Arc::new(Mutex::new(Vec::new()));
+
+        async fn accept(State(captured): State<Captured>, headers: HeaderMap, body: String) -> StatusCode {
+            captured.lock().unwrap().push((headers, body));
+            StatusCode::OK
+        }
+        async fn reject(State(captured): State<Captured>, headers: HeaderMap, body: String) -> StatusCode {
+            captured.lock().unwrap().push((headers, body));
+            StatusCode::INTERNAL_SERVER_ERROR
+        }
+
+        let app = Router::new()
+            .route("/ok", post(accept))
+            .route("/fail", post(reject))
+            .with_state(captured.clone());
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            axum::serve(listener, app).await.unwrap();
+        });
+
+        (format!("http://{}", addr), captured)
+    }
+
+    fn delivery(payload: String) -> WebhookDeliveryModel {
+        let now = Utc::now();
+        WebhookDeliveryModel {
+            id: 7,
+            endpoint_id: 1,
+            event_id: "evt_test".to_string(),
+            event_type: WebhookEventType::SwapFilled,
+            payload,
+            status: DeliveryStatus::Pending,
+            attempts: 0,
+            response_status: None,
+            error: None,
+            replay_of: None,
+            next_attempt_at: Some(now),
+            delivered_at: None,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    #[test]
+    fn test_create_endpoint_validation() {
+        let service = WebhookDomainService::new();
+
+        assert!(service.create_endpoint(Some(1), "ftp://example.com".to_string(), vec![WebhookEventType::SwapFilled], None).is_err());
+        assert!(service.create_endpoint(Some(1), "http://example.com".to_string(), vec![WebhookEventType::SwapFilled], None).is_err());
+        assert!(service.create_endpoint(Some(1), "https://example.com".to_string(), vec![], None).is_err());
+        // 余额事件必须设置阈值，且管理员端点不支持
+        assert!(service.create_endpoint(Some(1), "https://example.com".to_string(), vec![WebhookEventType::BalanceBelowThreshold], None).is_err());
+        assert!(service.create_endpoint(None, "https://example.com".to_string(), vec![WebhookEventType::BalanceBelowThreshold], Some(1.0)).is_err());
+
+        let (endpoint, secret) = service.create_endpoint(
+            Some(1),
+            " https://example.com/hook ".to_string(),
+            vec![WebhookEventType::DepositCredited, WebhookEventType::DepositCredited, WebhookEventType::WithdrawalFailed],
+            None,
+        ).unwrap();
+        assert!(secret.starts_with("whsec_"));
+        assert_eq!(endpoint.url.clone().unwrap(), "https://example.com/hook");
+        assert_eq!(endpoint.events.clone().unwrap(), "deposit_credited,withdrawal_failed");
+    }
+
+    #[test]
+    fn test_is_public_ip() {
+        let service = WebhookDomainService::new();
+
+        for ip in ["127.0.0.1", "10.1.2.3", "172.16.0.1", "192.168.1.1", "169.254.169.254", "100.64.0.1", "0.0.0.0", "::1", "fd00::1", "fe80::1", "::ffff:127.0.0.1"] {
+            assert!(!service.is_public_ip(ip.parse().unwrap()), "{}", ip);
+        }
+        for ip in ["8.8.8.8", "1.1.1.1", "2606:4700::1111"] {
+            assert!(service.is_public_ip(ip.parse().unwrap()), "{}", ip);
+        }
+    }
+
+    #[test]
+    fn test_retry_backoff() {
+        let service = WebhookDomainService::new();
+        let now = Utc::now();
+
+        assert_eq!(service.retry_at(1, 5, 30, 600, now), Some(now + Duration::seconds(30)));
+        assert_eq!(service.retry_at(3, 5, 30, 600, now), Some(now + Duration::seconds(120)));
+        // 超过上限按上限退避，达到最大次数不再重试
+        assert_eq!(service.retry_at(4, 5, 100, 600, now), Some(now + Duration::seconds(600)));
+        assert_eq!(service.retry_at(5, 5, 30, 600, now), None);
+
+        let mut record = delivery("{}".to_string());
+        record.record_failure(Some(500), "HTTP 500".to_string(), Some(now), now);
+        assert_eq!((record.status.clone(), record.attempts), (DeliveryStatus::Pending, 1));
+        record.record_failure(None, "timeout".to_string(), None, now);
+        assert_eq!((record.status.clone(), record.attempts), (DeliveryStatus::Failed, 2));
+        record.record_success(200, now);
+        assert_eq!((record.status, record.delivered_at, record.next_attempt_at), (DeliveryStatus::Succeeded, Some(now), None));
+    }
+
+    #[test]
+    fn test_sign_payload() {
+        let service = WebhookDomainService::new();
+        let signature = service.sign("whsec_secret", 1_700_000_000, "{\"a\":1}").unwrap();
+
+        let mut mac = Hmac::<Sha256>::new_from_slice(b"whsec_secret").unwrap();
+        mac.update(b"1700000000.{\"a\":1}");
+        let expected: String = mac.finalize().into_bytes().iter().map(|byte| format!("{:02x}", byte)).collect();
+
+        assert_eq!(signature, format!("sha256={}", expected));
+        assert_ne!(signature, service.sign("whsec_secret", 1_700_000_001, "{\"a\":1}").unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_send_to_local_receiver() {
+        let (base_url, captured) = spawn_receiver().await;
+        let service = WebhookDomainService::new();
+        let client = reqwest::Client::new();
+
+        let event = service.build_event(1, WebhookEventType::SwapFilled, json!({ "order_id": 3 }));
+        let record = delivery(serde_json::to_string(&event).unwrap());
+        let timestamp = Utc::now().timestamp();
+        let signature = service.sign("whsec_secret", timestamp, &record.payload).unwrap();
+
+        let status = WebhookApplication::send(&client, &format!("{}/ok", base_url), &record, timestamp, &signature).await.unwrap();
+        assert_eq!(status, 200);
+        let status = WebhookApplication::send(&client, &format!("{}/fail", base_url), &record, timestamp, &signature).await.unwrap();
+        assert_eq!(status, 500);
+
+        // 接收方可以用请求头中的时间戳重新计算签名
+        let requests = captured.lock().unwrap();
+        assert_eq!(requests.len(), 2);
+        let (headers, body) = &requests[0];
+        assert_eq!(headers["x-webhook-event"], "swap_filled");
+        assert_eq!(headers["x-webhook-delivery"], "7");
+        let received_timestamp: i64 = headers["x-webhook-timestamp"].to_str().unwrap().parse().unwrap();
+        assert_eq!(headers["x-webhook-signature"].to_str().unwrap(), service.sign("whsec_secret", received_timestamp, body).unwrap());
+        assert_eq!(serde_json::from_str::<serde_json::Value>(body).unwrap()["type"], "swap_filled");
+
+        // 接收方不可达时返回错误
+        assert!(WebhookApplication::send(&client, "http://127.0.0.1:1/ok", &record, timestamp, &signature).await.is_err());
+    }
+}
+
+#[cfg(test)]
+mod watcher_test {
+    use std::time::Duration;
+    use chrono::{Duration as ChronoDuration, Utc};
+    use serde_json::json;
+
+    use crate::core::domain::watcher::entity::tracked_signature_entity::{TrackOutcome, TrackedSignature};
+    use crate::core::domain::watcher::service::WatcherDomainService;
+
+    #[test]
+    fn test_deposit_lamports() {
+        let service = WatcherDomainService::new();
+        let accounts = vec!["payer".to_string(), "custody".to_string(), "other".to_string()];
+
+        // 外部转入
+        assert_eq!(service.deposit_lamports("custody", &accounts, &[10, 5, 0], &[4, 8, 0]), Some(3));
+        // 余额减少、未参与交易、作为手续费支付方都不算充值
+        assert_eq!(service.deposit_lamports("custody", &accounts, &[10, 8, 0], &[10, 5, 3]), None);
+        assert_eq!(service.deposit_lamports("missing", &accounts, &[10, 5, 0], &[4, 8, 0]), None);
+        assert_eq!(service.deposit_lamports("payer", &accounts, &[10, 5, 0], &[20, 5, 0]), None);
+    }
+
+    #[test]
+    fn test_resolve_tracked_signature() {
+        let service = WatcherDomainService::new();
+        let now = Utc::now();
+        let mut tracked = TrackedSignature::new("sig".to_string(), 1, json!({}));
+        tracked.submitted_at = now - ChronoDuration::seconds(30);
+
+        assert_eq!(service.resolve(&tracked, Some((9, None, true)), 60, now), Some(TrackOutcome::Confirmed { slot: 9 }));
+        assert_eq!(
+            service.resolve(&tracked, Some((9, Some("InsufficientFunds".to_string()), false)), 60, now),
+            Some(TrackOutcome::Failed { slot: Some(9), error: "InsufficientFunds".to_string() }),
+        );
+        // 已确认但未最终确认、或尚未查到时继续等待，直到超时
+        assert_eq!(service.resolve(&tracked, Some((9, None, false)), 60, now), None);
+        assert_eq!(service.resolve(&tracked, None, 60, now), None);
+        assert_eq!(service.resolve(&tracked, None, 30, now), Some(TrackOutcome::Expired));
+    }
+
+    #[test]
+    fn test_reconnect_delay() {
+        let service = WatcherDomainService::new();
+
+        assert_eq!(service.reconnect_delay(1, 1, 60), Duration::from_secs(1));
+        assert_eq!(service.reconnect_delay(4, 1, 60), Duration::from_secs(8));
+        assert_eq!(service.reconnect_delay(10, 1, 60), Duration::from_secs(60));
+        assert_eq!(service.reconnect_delay(u32::MAX, 5, 60), Duration::from_secs(60));
+    }
+}
+
+#[cfg(test)]
+mod stream_test {
+    use std::collections::HashSet;
+    use serde_json::json;
+
+    use crate::core::domain::stream::entity::stream_event_entity::StreamTopic;
+    use crate::core::domain::stream::service::StreamDomainService;
+
+    #[test]
+    fn test_parse_topics() {
+        let service = StreamDomainService::new();
+
+        assert_eq!(service.parse_topics(None).unwrap(), StreamTopic::SUBSCRIBABLE.into_iter().collect());
+        assert_eq!(
+            service.parse_topics(Some("price, balance,")).unwrap(),
+            HashSet::from([StreamTopic::Price, StreamTopic::Balance]),
+        );
+        assert!(service.parse_topics(Some("system")).is_err());
+        assert!(service.parse_topics(Some("orders")).is_err());
+    }
+
+    #[test]
+    fn test_apply_command() {
+        let service = StreamDomainService::new();
+        let mut topics = HashSet::from([StreamTopic::Price]);
+
+        let reply = service.apply_command(&mut topics, r#"{"action":"subscribe","topics":["balance"]}"#);
+        assert_eq!(reply.event, "subscribed");
+        assert_eq!(reply.data, json!({ "topics": ["balance", "price"] }));
+
+        service.apply_command(&mut topics, r#"{"action":"unsubscribe","topics":["price"]}"#);
+        assert_eq!(topics, HashSet::from([StreamTopic::Balance]));
+
+        assert_eq!(service.apply_command(&mut topics, r#"{"action":"ping"}"#).event, "pong");
+        // 非法命令不改变订阅
+        assert_eq!(service.apply_command(&mut topics, r#"{"action":"subscribe","topics":["system"]}"#).event, "error");
+        assert_eq!(service.apply_command(&mut topics, "hello").event, "error");
+        assert_eq!(topics, HashSet::from([StreamTopic::Balance]));
+    }
+
+    #[test]
+    fn test_visible() {
+        let service = StreamDomainService::new();
+        let topics = HashSet::from([StreamTopic::Balance]);
+
+        assert!(service.visible(&service.event(StreamTopic::Balance, Some(1), "balance_changed", json!({})), 1, &topics));
+        // 其他用户的事件、未订阅主题的事件不推送
+        assert!(!service.visible(&service.event(StreamTopic::Balance, Some(2), "balance_changed", json!({})), 1, &topics));
+        assert!(!service.visible(&service.event(StreamTopic::Price, None, "price_tick", json!({})), 1, &topics));
+        // 控制消息始终推送
+        assert!(service.visible(&service.lagged(3), 1, &topics));
+    }
+}
+
+#[cfg(test)]
+mod reorg_test {
+    use chrono::Utc;
+
+    use crate::core::domain::indexer::entity::indexed_block_entity::Model as IndexedBlockModel;
+    use crate::core::domain::reorg::entity::commitment_entity::{BlockFinality, CommitmentLevel, DepositFinality};
+    use crate::core::domain::reorg::service::ReorgDomainService;
+
+    fn block(slot: i64, skipped: bool) -> IndexedBlockModel {
+        IndexedBlockModel {
+            slot,
+            parent_slot: None,
+            blockhash: None,
+            previous_blockhash: None,
+            block_time: None,
+            block_height: None,
+            transaction_count: 0,
+            vote_transaction_count: 0,
+            total_fee: 0,
+            skipped,
+            commitment: CommitmentLevel::Confirmed,
+            indexed_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_block_finality() {
+        let service = ReorgDomainService::new();
+        let finalized = [100, 101, 103];
+
+        assert_eq!(service.block_finality(&block(101, false), &finalized), BlockFinality::Finalized);
+        assert_eq!(service.block_finality(&block(102, true), &finalized), BlockFinality::Finalized);
+        // 出块的 slot 不在最终确认链上：事后被跳过
+        assert_eq!(service.block_finality(&block(102, false), &finalized), BlockFinality::Dropped);
+        // 记为跳过的 slot 在最终确认链上有区块：需要重新索引
+        assert_eq!(service.block_finality(&block(103, true), &finalized), BlockFinality::Missed);
+    }
+
+    #[test]
+    fn test_deposit_finality() {
+        let service = ReorgDomainService::new();
+
+        // 分叉后重新打包到新的 slot
+        assert_eq!(service.deposit_finality(100, Some((105, None, true)), 200, 0, 3), Some(DepositFinality::Finalized { slot: 105 }));
+        assert!(matches!(service.deposit_finality(100, Some((105, Some("InsufficientFunds".to_string()), true)), 200, 0, 3), Some(DepositFinality::Dropped { .. })));
+        // 连续多轮不在最终确认链上才判定丢弃
+        assert_eq!(service.deposit_finality(100, None, 200, 1, 3), None);
+        assert!(matches!(service.deposit_finality(100, None, 200, 3, 3), Some(DepositFinality::Dropped { .. })));
+        // 尚未最终确认时继续等待
+        assert_eq!(service.deposit_finality(100, Some((105, None, false)), 200, 0, 3), None);
+        assert_eq!(service.deposit_finality(300, None, 200, 3, 3), None);
+    }
+
+    #[test]
+    fn test_rollback_amount() {
+        let service = ReorgDomainService::new();
+
+        assert_eq!(service.rollback_amount(5.0, 2.0), (2.0, 0.0));
+        assert_eq!(service.rollback_amount(0.5, 2.0), (0.5, 1.5));
+        assert_eq!(service.rollback_amount(-1.0, 2.0), (0.0, 2.0));
+    }
+}
+
+#[cfg(test)]
+mod auth_test {
+
+    use crate::core::domain::auth::entity::token_entity::RefreshRotation;
+    use crate::core::domain::auth::repository::TokenStoreInterface;
+    use crate::core::domain::auth::service::AuthDomainService;
+    use crate::core::domain::user::service::UserDomainService;
+    use crate::core::infrastructure::jwt::MemoryTokenStore;
+    use super::fixtures::user;
+
+    #[test]
+    fn test_ensure_active() {
+        let service = UserDomainService::new();
+
+        assert!(service.ensure_active(&user(false, false)).is_ok());
+        assert_eq!(service.ensure_active(&user(true, false)).unwrap_err().to_string(), "账号已被禁用");
+        // 已注销的账号优先于禁用状态
+        assert_eq!(service.ensure_active(&user(true, true)).unwrap_err().to_string(), "账号已注销");
+    }
+
+    #[test]
+    fn test_refresh_rotation() {
+        let service = AuthDomainService::new();
+        let family = service.new_family(1, "r1".to_string(), "a1".to_string(), 1_000);
+        assert_eq!(service.rotation(&family, "r1", 1_005, 10), RefreshRotation::Rotate);
+
+        let rotated = service.rotate(&family, "r2".to_string(), "a2".to_string(), "{}".to_string(), 1_005);
+        assert_eq!(service.rotation(&rotated, "r2", 1_006, 10), RefreshRotation::Rotate);
+        // 宽限期内重复出示刚轮换掉的令牌：并发刷新
+        assert_eq!(service.rotation(&rotated, "r1", 1_010, 10), RefreshRotation::Replay("{}".to_string()));
+        // 宽限期之后或更早的令牌：重复使用
+        assert_eq!(service.rotation(&rotated, "r1", 1_020, 10), RefreshRotation::Reused);
+        assert_eq!(service.rotation(&rotated, "r0", 1_006, 10), RefreshRotation::Reused);
+    }
+
+    #[tokio::test]
+    async fn test_memory_token_store() {
+        let service = AuthDomainService::new();
+        let store = MemoryTokenStore::new();
+
+        store.blacklist("a1", 1_010, 60).await.unwrap();
+        assert!(!service.is_revoked(store.blacklisted_at("a1").await.unwrap(), 1_005));
+        assert!(service.is_revoked(store.blacklisted_at("a1").await.unwrap(), 1_010));
+        assert!(!service.is_revoked(store.blacklisted_at("a2").await.unwrap(), 1_010));
+
+        let family = service.new_family(1, "r1".to_string(), "a1".to_string(), 1_000);
+        let rotated = service.rotate(&family, "r2".to_string(), "a2".to_string(), "{}".to_string(), 1_005);
+        store.save_family("f1", &family, 60).await.unwrap();
+        assert!(store.swap_family("f1", &family, &rotated, 60).await.unwrap());
+        // 同一枚令牌只能轮换一次
+        assert!(!store.swap_family("f1", &family, &rotated, 60).await.unwrap());
+
+        store.remove_family("f1").await.unwrap();
+        assert!(store.family("f1").await.unwrap().is_none());
+    }
+}
+
+#[cfg(test)]
+mod router_test {
+    use std::error::Error;
+    use reqwest::StatusCode;
+    use crate::bootstrap::Bootstrap;
+    use crate::core::application::user::LoginOutcome;
+    use crate::core::presentation::router::Router;
+
+    /// 需要数据库与 Redis：注册一个没有角色的用户，经由完整路由访问用户管理接口
+    #[tokio::test]
+    async fn test_auth_and_permission_status() -> Result<(), Box<dyn Error>> {
+        let bootstrap = Bootstrap::run().await?;
+        let application_layer = bootstrap.application_layer.clone();
+        let routes = Router::new(bootstrap.infrastructure_layer.clone(), bootstrap.domain_layer.clone(), application_layer.clone())
+            .v1_routes()
+            .await;
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await?;
+        let url = format!("http://{}/v1/admin/users/list", listener.local_addr()?);
+        tokio::spawn(async move { axum::serve(listener, routes).await });
+
+        let mobile = format!("139{:08}", rand::random::<u32>() % 100_000_000);
+        application_layer.user_application.register_user(mobile.clone(), "password123".into(), None).await?;
+        let LoginOutcome::Authenticated(token) = application_layer.user_application.login_user(mobile, "password123".into(), "127.0.0.1".into()).await? else {
+            return Err("新用户不应开启两步验证".into());
+        };
+        let access_token = serde_json::to_value(&token)?["access_token"].as_str().unwrap_or_default().to_string();
+
+        let client = reqwest::Client::new();
+        // 缺少访问令牌
+        assert_eq!(client.get(&url).send().await?.status(), StatusCode::UNAUTHORIZED);
+        // 已登录但没有 user:admin 权限
+        assert_eq!(client.get(&url).bearer_auth(&access_token).send().await?.status(), StatusCode::FORBIDDEN);
+        // 退出登录后访问令牌进入黑名单
+        application_layer.user_application.logout(&access_token).await?;
+        assert_eq!(client.get(&url).bearer_auth(&access_token).send().await?.status(), StatusCode::UNAUTHORIZED);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod jwks_test {
+    use jsonwebtoken::Algorithm;
+
+    use crate::core::infrastructure::config::JwtKeyConfig;
+    use crate::core::infrastructure::jwt::public_jwk;
+
+    fn key(kid: &str, algorithm: &str) -> JwtKeyConfig {
+        JwtKeyConfig {
+            kid: kid.to_string(),
+            algorithm: algorithm.to_string(),
+            private_key: None,
+            public_key: String::new(),
+        }
+    }
+
+    #[test]
+    fn test_ed25519_jwk() {
+        // RFC 8037 附录 A.2 的公钥
+        let pem = "-----BEGIN PUBLIC KEY-----\nMCowBQYDK2VwAyEA11qYAYKxCrfVS/7TyWQHOg7hcvPapiMlrwIaaPcHURo=\n-----END PUBLIC KEY-----\n";
+        let jwk = public_jwk(&key("ed", "EdDSA"), Algorithm::EdDSA, pem).unwrap();
+
+        assert_eq!(jwk.kty, "OKP");
+        assert_eq!(jwk.crv.as_deref(), Some("Ed25519"));
+        assert_eq!(jwk.x.as_deref(), Some("11qYAYKxCrfVS_7TyWQHOg7hcvPapiMlrwIaaPcHURo"));
+        assert_eq!(jwk.kid, "ed");
+    }
+
+    #[test]
+    fn test_rsa_jwk() {
+        let pem = "-----BEGIN RSA PUBLIC KEY-----\nMAwCBQDDWhEHAgMBAAE=\n-----END RSA PUBLIC KEY-----\n";
+        let jwk = public_jwk(&key("rsa", "RS256"), Algorithm::RS256, pem).unwrap();
+
+        assert_eq!(jwk.kty, "RSA");
+        // 模数去掉 DER 补的前导 0
+        assert_eq!(jwk.n.as_deref(), Some("w1oRBw"));
+        assert_eq!(jwk.e.as_deref(), Some("AQAB"));
+        assert!(public_jwk(&key("rsa", "RS256"), Algorithm::RS256, "-----BEGIN RSA PUBLIC KEY-----\nMAw=\n-----END RSA PUBLIC KEY-----\n").is_err());
+    }
+}
+
+#[cfg(test)]
+mod rbac_test {
+    use crate::core::domain::user::entity::permission_entity::{BUILTIN_PERMISSIONS, RBAC_ADMIN, WEBHOOK_ADMIN};
+    use crate::core::domain::user::service::UserDomainService;
+
+    #[test]
+    fn test_normalize_role_name() {
+        let service = UserDomainService::new();
+
+        assert_eq!(service.normalize_role_name(" Operator ").unwrap(), "operator");
+        assert_eq!(service.normalize_role_name("risk_ops-2").unwrap(), "risk_ops-2");
+        assert!(service.normalize_role_name("a").is_err());
+        assert!(service.normalize_role_name("ops team").is_err());
+        assert!(service.normalize_role_name(&"x".repeat(33)).is_err());
+    }
+
+    #[test]
+    fn test_normalize_permission_code() {
+        let service = UserDomainService::new();
+
+        assert_eq!(service.normalize_permission_code("Webhook:Admin").unwrap(), WEBHOOK_ADMIN);
+        assert!(service.normalize_permission_code("webhook").is_err());
+        assert!(service.normalize_permission_code("webhook:").is_err());
+        assert!(service.normalize_permission_code(":admin").is_err());
+        assert!(service.normalize_permission_code("webhook:admin:all").is_err());
+        // 内置权限本身必须是合法编码
+        for (code, _) in BUILTIN_PERMISSIONS {
+            assert_eq!(service.normalize_permission_code(code).unwrap(), *code);
+        }
+    }
+
+    #[test]
+    fn test_has_permission_and_missing() {
+        let service = UserDomainService::new();
+        let permissions = vec![WEBHOOK_ADMIN.to_string()];
+
+        assert!(service.has_permission(&permissions, WEBHOOK_ADMIN));
+        assert!(!service.has_permission(&permissions, RBAC_ADMIN));
+        assert!(!service.has_permission(&[], WEBHOOK_ADMIN));
+
+        let requested = vec!["admin".to_string(), "auditor".to_string()];
+        assert_eq!(service.missing(&requested, &["admin".to_string()]), vec!["auditor".to_string()]);
+        assert!(service.missing(&requested, &requested).is_empty());
+    }
+}
+
+#[cfg(test)]
+mod mail_test {
+    use chrono::{Duration, Utc};
+
+    use crate::core::domain::mail::entity::mail_entity::{MailMessage, MailTemplate};
+    use crate::core::domain::mail::repository::MailTransportInterface;
+    use crate::core::domain::mail::service::MailDomainService;
+    use crate::core::domain::verification::entity::verification_code_entity::{Model as VerificationCodeModel, VerificationChannel, VerificationPurpose};
+    use crate::core::domain::verification::service::{VerificationCheck, VerificationDomainService};
+    use crate::core::domain::user::service::UserDomainService;
+    use crate::core::infrastructure::mail::{FileMailOutbox, MemoryMailOutbox};
+
+    fn code(service: &VerificationDomainService, secret: &str, attempts: i32, expired: bool, consumed: bool) -> VerificationCodeModel {
+        let now = Utc::now();
+        VerificationCodeModel {
+            id: 1,
+            channel: VerificationChannel::Email,
+            target: "user@example.com".to_string(),
+            purpose: VerificationPurpose::Register,
+            code_hash: service.digest(secret),
+            attempts,
+            expires_at: if expired { now - Duration::seconds(1) } else { now + Duration::seconds(600) },
+            consumed_at: if consumed { Some(now) } else { None },
+            created_at: now - Duration::seconds(30),
+        }
+    }
+
+    #[test]
+    fn test_normalize_email() {
+        let service = MailDomainService::new();
+
+        assert_eq!(service.normalize_email("  User.Name+tag@Example.COM ").unwrap(), "user.name+tag@example.com");
+        assert!(service.normalize_email("user@localhost").is_err());
+        assert!(service.normalize_email("@example.com").is_err());
+        assert!(service.normalize_email("user@@example.com").is_err());
+        assert!(service.normalize_email("user@-example.com").is_err());
+        assert!(service.normalize_email("user name@example.com").is_err());
+        assert!(service.normalize_email(".user@example.com").is_err());
+    }
+
+    #[test]
+    fn test_render_templates() {
+        let service = MailDomainService::new();
+
+        let message = service.render("learn_solana", "user@example.com", &MailTemplate::RegisterCode { code: "123456".to_string(), ttl_minutes: 10 });
+        assert_eq!(message.to, "user@example.com");
+        assert!(message.subject.contains("learn_solana"));
+        assert!(message.body.contains("123456") && message.body.contains("10 分钟"));
+
+        let link = "http://127.0.0.1:8000/reset-password?token=abc";
+        let message = service.render("learn_solana", "user@example.com", &MailTemplate::PasswordReset { link: link.to_string(), ttl_minutes: 30 });
+        assert!(message.body.contains(link));
+    }
+
+    #[test]
+    fn test_verification_check() {
+        let service = VerificationDomainService::new();
+        let now = Utc::now();
+
+        let generated = service.generate_code(6);
+        assert_eq!(generated.len(), 6);
+        assert!(generated.chars().all(|c| c.is_ascii_digit()));
+        assert_eq!(service.generate_token().len(), 64);
+
+        assert_eq!(service.check(&code(&service, "123456", 0, false, false), "123456", now, 5), VerificationCheck::Passed);
+        assert_eq!(service.check(&code(&service, "123456", 0, false, false), " 123456 ", now, 5), VerificationCheck::Passed);
+        assert_eq!(service.check(&code(&service, "123456", 1, false, false), "654321", now, 5), VerificationCheck::Mismatch(3));
+        assert_eq!(service.check(&code(&service, "123456", 5, false, false), "123456", now, 5), VerificationCheck::Exhausted);
+        assert_eq!(service.check(&code(&service, "123456", 0, true, false), "123456", now, 5), VerificationCheck::Expired);
+        assert_eq!(service.check(&code(&service, "123456", 0, false, true), "123456", now, 5), VerificationCheck::Expired);
+    }
+
+    #[test]
+    fn test_resend_cooldown() {
+        let service = VerificationDomainService::new();
+        let latest = code(&service, "123456", 0, false, false);
+
+        assert_eq!(service.cooldown(None, Utc::now(), 60), None);
+        // 30 秒前发送过，间隔 60 秒
+        assert!(service.cooldown(Some(&latest), Utc::now(), 60).is_some_and(|remaining| remaining > 0 && remaining <= 30));
+        assert_eq!(service.cooldown(Some(&latest), Utc::now(), 10), None);
+    }
+
+    #[test]
+    fn test_validate_password() {
+        let service = UserDomainService::new();
+
+        assert!(service.validate_password("password123").is_ok());
+        assert!(service.validate_password("pass1").is_err());
+        assert!(service.validate_password("passwordonly").is_err());
+        assert!(service.validate_password("1234567890").is_err());
+        assert!(service.validate_password(&format!("a1{}", "x".repeat(71))).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_outboxes() {
+        let message = MailMessage { to: "user@example.com".to_string(), subject: "subject".to_string(), body: "body".to_string() };
+
+        let memory = MemoryMailOutbox::new();
+        memory.send(&message).await.unwrap();
+        assert_eq!(memory.messages().await, vec![message.clone()]);
+
+        let directory = std::env::temp_dir().join(format!("mail_outbox_{}", VerificationDomainService::new().generate_token()));
+        let file = FileMailOutbox::new(directory.to_str().unwrap());
+        file.send(&message).await.unwrap();
+        let mut entries = std::fs::read_dir(&directory).unwrap();
+        let content = std::fs::read_to_string(entries.next().unwrap().unwrap().path()).unwrap();
+        assert!(content.contains("To: user@example.com") && content.contains("Subject: subject") && content.ends_with("body\r\n"));
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+}
+
+#[cfg(test)]
+mod sms_test {
+    use crate::core::domain::sms::entity::sms_entity::{SmsMessage, SmsTemplate};
+    use crate::core::domain::sms::repository::SmsProviderInterface;
+    use crate::core::domain::sms::service::SmsDomainService;
+    use crate::core::infrastructure::sms::{LogSmsProvider, MemorySmsOutbox};
+
+    #[test]
+    fn test_normalize_mobile() {
+        let service = SmsDomainService::new();
+
+        assert_eq!(service.normalize_mobile("138 0013 8000", "86").unwrap(), "+8613800138000");
+        assert_eq!(service.normalize_mobile("+86 138-0013-8000", "86").unwrap(), "+8613800138000");
+        assert_eq!(service.normalize_mobile("0086 13800138000", "86").unwrap(), "+8613800138000");
+        assert_eq!(service.normalize_mobile("+1 (415) 555.2671", "86").unwrap(), "+14155552671");
+        assert_eq!(service.normalize_mobile("07911 123456", "44").unwrap(), "+447911123456");
+        assert!(service.normalize_mobile("23800138000", "86").is_err());
+        assert!(service.normalize_mobile("1380013800", "86").is_err());
+        assert!(service.normalize_mobile("13800138000", "").is_err());
+        assert!(service.normalize_mobile("+0123456789", "86").is_err());
+        assert!(service.normalize_mobile("+1234567890123456", "86").is_err());
+        assert!(service.normalize_mobile("+86 138a0013800", "86").is_err());
+    }
+
+    #[test]
+    fn test_mask_and_render() {
+        let service = SmsDomainService::new();
+
+        assert_eq!(service.mask_mobile("+8613800138000"), "+86*******8000");
+
+        let message = service.render("learn_solana", "+8613800138000", &SmsTemplate::LoginCode { code: "123456".to_string(), ttl_minutes: 5 });
+        assert_eq!(message.to, "+8613800138000");
+        assert!(message.text.starts_with("【learn_solana】"));
+        assert!(message.text.contains("123456") && message.text.contains("5 分钟"));
+    }
+
+    #[tokio::test]
+    async fn test_providers() {
+        let message = SmsMessage { to: "+8613800138000".to_string(), text: "text".to_string() };
+
+        assert!(LogSmsProvider::new().send(&message).await.is_ok());
+
+        let memory = MemorySmsOutbox::new();
+        memory.send(&message).await.unwrap();
+        assert_eq!(memory.messages().await, vec![message]);
+    }
+}
+
+#[cfg(test)]
+mod two_factor_test {
+    use data_encoding::BASE32_NOPAD;
+    use crate::core::domain::two_factor::service::TwoFactorDomainService;
+    use crate::core::domain::withdrawal::service::WithdrawalDomainService;
+    use super::fixtures::wallet;
+
+    /// # Description
+    ///     RFC 6238 附录 B 的 SHA1 测试密钥
+    fn rfc_secret() -> String {
+        BASE32_NOPAD.encode(b"12345678901234567890")
+    }
+
+    #[test]
+    fn test_totp_rfc_vectors() {
+        let service = TwoFactorDomainService::new();
+        let secret = rfc_secret();
+
+        // T = 59 / 1111111109 / 2000000000，取 8 位结果的后 6 位
+        assert_eq!(service.totp(&secret, 59 / 30, 6).unwrap(), "287082");
+        assert_eq!(service.totp(&secret, 1111111109 / 30, 6).unwrap(), "081804");
+        assert_eq!(service.totp(&secret, 2000000000 / 30, 8).unwrap(), "69279037");
+        assert!(service.totp("not base32!", 1, 6).is_err());
+    }
+
+    #[test]
+    fn test_verify_totp_skew_and_replay() {
+        let service = TwoFactorDomainService::new();
+        let secret = rfc_secret();
+        let now = 1111111109;
+        let step = now / 30;
+        let previous = service.totp(&secret, step - 1, 6).unwrap();
+
+        assert_eq!(service.verify_totp(&secret, "081804", now, 30, 6, 1, 0).unwrap(), Some(step));
+        assert_eq!(service.verify_totp(&secret, &previous, now, 30, 6, 1, 0).unwrap(), Some(step - 1));
+        assert_eq!(service.verify_totp(&secret, &previous, now, 30, 6, 0, 0).unwrap(), None);
+        // 已使用的时间步不能重放
+        assert_eq!(service.verify_totp(&secret, "081804", now, 30, 6, 1, step).unwrap(), None);
+        assert_eq!(service.verify_totp(&secret, "000000", now, 30, 6, 1, 0).unwrap(), None);
+        assert_eq!(service.verify_totp(&secret, "08180", now, 30, 6, 1, 0).unwrap(), None);
+    }
+
+    #[test]
+    fn test_secret_and_otpauth_uri() {
+        let service = TwoFactorDomainService::new();
+        let secret = service.generate_secret();
+
+        assert_eq!(secret.len(), 32);
+        assert_eq!(BASE32_NOPAD.decode(secret.as_bytes()).unwrap().len(), 20);
+        assert_eq!(
+            service.otpauth_uri("learn solana", "alice@example.com", "ABC", 6, 30),
+            "otpauth://totp/learn%20solana:alice%40example.com?secret=ABC&issuer=learn%20solana&algorithm=SHA1&digits=6&period=30"
+        );
+    }
+
+    #[test]
+    fn test_backup_codes() {
+        let service = TwoFactorDomainService::new();
+        let codes = service.generate_backup_codes(10);
+
+        assert_eq!(codes.len(), 10);
+        for code in &codes {
+            assert_eq!(code.len(), 11);
+            assert_eq!(&code[5..6], "-");
+            assert!(!service.is_totp_code(code, 6));
+        }
+        assert_eq!(service.normalize_backup_code(" ABCDE-fghjk "), "abcdefghjk");
+        assert!(service.is_totp_code(" 123456 ", 6));
+        assert!(!service.is_totp_code("12345a", 6));
+    }
+
+    #[test]
+    fn test_withdrawal_checks() {
+        let service = WithdrawalDomainService::new();
+
+        assert_eq!(
+            service.normalize_address(" 11111111111111111111111111111111 ").unwrap(),
+            "11111111111111111111111111111111"
+        );
+        assert!(service.normalize_address("not-an-address").is_err());
+
+        assert_eq!(service.normalize_label(Some("  ".to_string())).unwrap(), None);
+        assert_eq!(service.normalize_label(Some(" 冷钱包 ".to_string())).unwrap(), Some("冷钱包".to_string()));
+        assert!(service.normalize_label(Some("a".repeat(65))).is_err());
+
+        assert!(service.check_withdrawal(&wallet(1.0, false, false), 0.5).is_ok());
+        assert!(service.check_withdrawal(&wallet(1.0, false, false), 1.5).is_err());
+        assert!(service.check_withdrawal(&wallet(1.0, false, false), 0.0).is_err());
+        assert!(service.check_withdrawal(&wallet(1.0, false, false), f64::NAN).is_err());
+        assert!(service.check_withdrawal(&wallet(1.0, true, false), 0.5).is_err());
+    }
+}
+
+#[cfg(test)]
+mod throttle_test {
+    use std::sync::Arc;
+    use axum::async_trait;
+    use axum::http::{HeaderMap, HeaderValue};
+    use color_eyre::{Report, Result};
+    use crate::core::domain::throttle::entity::throttle_entity::{LoginThrottled, ThrottlePolicy, ThrottleScope};
+    use crate::core::domain::throttle::repository::AttemptStoreInterface;
+    use crate::core::domain::throttle::service::ThrottleDomainService;
+    use crate::core::infrastructure::throttle::{FallbackAttemptStore, MemoryAttemptStore};
+    use crate::core::presentation::middleware::client_ip::ClientIp;
+
+    /// # Description
+    ///     始终出错的存储，模拟 Redis 不可用
+    struct FailingStore;
+
+    #[async_trait]
+    impl AttemptStoreInterface for FailingStore {
+        async fn failures(&self, _key: &str) -> Result<u32, Report> { Err(Report::msg("down")) }
+        async fn record_failure(&self, _key: &str, _window: u64) -> Result<u32, Report> { Err(Report::msg("down")) }
+        async fn lock(&self, _key: &str, _ttl: u64) -> Result<(), Report> { Err(Report::msg("down")) }
+        async fn locked_for(&self, _key: &str) -> Result<Option<u64>, Report> { Err(Report::msg("down")) }
+        async fn clear(&self, _key: &str) -> Result<(), Report> { Err(Report::msg("down")) }
+    }
+
+    fn policy() -> ThrottlePolicy {
+        ThrottlePolicy { free_attempts: 3, base_delay_ms: 500, max_delay_ms: 4000, max_failures: 10 }
+    }
+
+    #[test]
+    fn test_progressive_delay_and_lock() {
+        let service = ThrottleDomainService::new();
+        let policy = policy();
+
+        assert_eq!(service.delay_ms(0, &policy), 0);
+        assert_eq!(service.delay_ms(2, &policy), 0);
+        assert_eq!(service.delay_ms(3, &policy), 500);
+        assert_eq!(service.delay_ms(4, &policy), 1000);
+        assert_eq!(service.delay_ms(5, &policy), 2000);
+        assert_eq!(service.delay_ms(6, &policy), 4000);
+        assert_eq!(service.delay_ms(u32::MAX, &policy), 4000);
+        assert_eq!(service.delay_ms(0, &ThrottlePolicy { free_attempts: 0, ..policy }), 0);
+
+        assert!(!service.should_lock(9, &policy));
+        assert!(service.should_lock(10, &policy));
+        assert!(!service.should_lock(100, &ThrottlePolicy { max_failures: 0, ..policy }));
+
+        assert_eq!(service.failure_key(ThrottleScope::Identifier, "a@b.com"), "login:failures:identifier:a@b.com");
+        assert_eq!(service.lock_key(ThrottleScope::Ip, "127.0.0.1"), "login:lock:ip:127.0.0.1");
+        assert_eq!(service.failure_key(ThrottleScope::TwoFactor, "42"), "login:failures:two_factor:42");
+    }
+
+    #[tokio::test]
+    async fn test_memory_store() {
+        let store = MemoryAttemptStore::new();
+
+        assert_eq!(store.failures("k").await.unwrap(), 0);
+        assert_eq!(store.record_failure("k", 60).await.unwrap(), 1);
+        assert_eq!(store.record_failure("k", 60).await.unwrap(), 2);
+        assert_eq!(store.failures("k").await.unwrap(), 2);
+
+        assert_eq!(store.locked_for("k").await.unwrap(), None);
+        store.lock("k", 60).await.unwrap();
+        assert!(store.locked_for("k").await.unwrap().is_some_and(|ttl| ttl > 0 && ttl <= 60));
+
+        store.clear("k").await.unwrap();
+        assert_eq!(store.failures("k").await.unwrap(), 0);
+        assert_eq!(store.locked_for("k").await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn test_fallback_store() {
+        let fallback = Arc::new(MemoryAttemptStore::new());
+        let store = FallbackAttemptStore::new(Arc::new(FailingStore), fallback.clone());
+
+        assert_eq!(store.record_failure("k", 60).await.unwrap(), 1);
+        assert_eq!(store.failures("k").await.unwrap(), 1);
+        store.lock("k", 60).await.unwrap();
+        assert!(store.locked_for("k").await.unwrap().is_some());
+        assert_eq!(fallback.failures("k").await.unwrap(), 1);
+
+        store.clear("k").await.unwrap();
+        assert_eq!(fallback.locked_for("k").await.unwrap(), None);
+    }
+
+    #[test]
+    fn test_forwarded_for_and_error() {
+        let mut headers = HeaderMap::new();
+        assert_eq!(ClientIp::forwarded_for(&headers, 1), None);
+
+        // 最左侧的地址可被客户端伪造，按可信代理层数从右往左取
+        headers.insert("x-forwarded-for", HeaderValue::from_static(" 1.2.3.4, 203.0.113.7 , 10.0.0.1"));
+        assert_eq!(ClientIp::forwarded_for(&headers, 1).unwrap().to_string(), "10.0.0.1");
+        assert_eq!(ClientIp::forwarded_for(&headers, 2).unwrap().to_string(), "203.0.113.7");
+        assert_eq!(ClientIp::forwarded_for(&headers, 0).unwrap().to_string(), "10.0.0.1");
+        assert_eq!(ClientIp::forwarded_for(&headers, 4), None);
+
+        headers.insert("x-forwarded-for", HeaderValue::from_static("not-an-ip"));
+        assert_eq!(ClientIp::forwarded_for(&headers, 1), None);
+
+        let report = Report::new(LoginThrottled { retry_after: 30 });
+        assert_eq!(report.downcast_ref::<LoginThrottled>(), Some(&LoginThrottled { retry_after: 30 }));
+        assert!(report.to_string().contains("30"));
+    }
+}
+
+#[cfg(test)]
+mod profile_test {
+    use crate::core::domain::auth::entity::token_entity::RefreshFamily;
+    use crate::core::domain::auth::repository::TokenStoreInterface;
+    use crate::core::domain::auth::service::AuthDomainService;
+    use crate::core::domain::sms::entity::sms_entity::SmsTemplate;
+    use crate::core::domain::sms::service::SmsDomainService;
+    use crate::core::domain::user::service::UserDomainService;
+    use crate::core::infrastructure::jwt::MemoryTokenStore;
+    use super::fixtures::wallet;
+
+    #[test]
+    fn test_normalize_profile_fields() {
+        let service = UserDomainService::new();
+
+        assert_eq!(service.normalize_nickname("  小明 ").unwrap(), "小明");
+        assert!(service.normalize_nickname("   ").is_err());
+        assert!(service.normalize_nickname(&"名".repeat(33)).is_err());
+        assert!(service.normalize_nickname("a\u{0}b").is_err());
+
+        assert_eq!(service.normalize_avatar(" https://cdn.example.com/a.png ").unwrap(), "https://cdn.example.com/a.png");
+        assert!(service.normalize_avatar("javascript:alert(1)").is_err());
+        assert!(service.normalize_avatar("https://cdn.example.com/a b.png").is_err());
+        assert!(service.normalize_avatar(&format!("https://{}", "a".repeat(250))).is_err());
+    }
+
+    #[test]
+    fn test_ensure_deletable() {
+        let service = UserDomainService::new();
+
+        assert!(service.ensure_deletable(&[], &[]).is_ok());
+        assert!(service.ensure_deletable(&[wallet(0.0, false, false), wallet(5.0, false, true)], &[]).is_ok());
+        assert!(service.ensure_deletable(&[wallet(0.0, false, false), wallet(0.5, false, false)], &[]).is_err());
+
+        // 账本没有余额，但链上仍有 SOL 或 SPL 代币
+        assert!(service.ensure_deletable(&[wallet(0.0, false, false)], &[("SOL".to_string(), 0.002)]).is_err());
+        assert!(service.ensure_deletable(&[wallet(0.0, false, false)], &[("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v".to_string(), 3.0)]).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_session_families() {
+        let service = AuthDomainService::new();
+        let store = MemoryTokenStore::new();
+
+        let family = service.new_family(1, "r1".to_string(), "a1".to_string(), 1_000);
+        assert_eq!(family.created_at, 1_000);
+        let rotated = service.rotate(&family, "r2".to_string(), "a2".to_string(), "{}".to_string(), 1_005);
+        // 轮换不改变登录时间
+        assert_eq!((rotated.created_at, rotated.rotated_at), (1_000, 1_005));
+
+        store.save_family("f1", &rotated, 60).await.unwrap();
+        store.save_family("f2", &service.new_family(2, "r3".to_string(), "a3".to_string(), 1_000), 60).await.unwrap();
+        let families = store.families(1).await.unwrap();
+        assert_eq!(families.len(), 1);
+        assert_eq!(families[0].0, "f1");
+
+        store.remove_family("f1").await.unwrap();
+        assert!(store.families(1).await.unwrap().is_empty());
+
+        // 旧版本写入的刷新令牌族没有登录时间
+        let legacy: RefreshFamily = serde_json::from_str(
+            r#"{"user_id":1,"current":"r1","access":"a1","previous":null,"rotated_at":1000,"successor":null}"#
+        ).unwrap();
+        assert_eq!(legacy.created_at, 0);
+    }
+
+    #[test]
+    fn test_bind_mobile_template() {
+        let message = SmsDomainService::new().render(
+            "learn_solana",
+            "+8613800138000",
+            &SmsTemplate::BindMobileCode { code: "654321".to_string(), ttl_minutes: 5 },
+        );
+        assert!(message.text.contains("绑定") && message.text.contains("654321"));
+    }
+}
+
+#[cfg(test)]
+mod admin_test {
+    use chrono::{Duration, Utc};
+    use sea_orm::ActiveValue;
+    use crate::core::application::admin::{AdminUserView, AdminWalletView};
+    use crate::core::domain::user::entity::admin_audit_entity::{AdminAction, Model as AdminAuditModel};
+    use crate::core::domain::user::repository_interface::UserFilter;
+    use crate::core::domain::user::service::UserDomainService;
+    use crate::core::domain::wallet::entity::Model as WalletModel;
+    use super::fixtures::{user, wallet};
+
+    #[test]
+    fn test_normalize_user_filter() {
+        let service = UserDomainService::new();
+
+        let filter = service.normalize_user_filter(UserFilter { keyword: Some("  abc ".to_string()), ..Default::default() }).unwrap();
+        assert_eq!(filter.keyword.as_deref(), Some("abc"));
+        let filter = service.normalize_user_filter(UserFilter { keyword: Some("   ".to_string()), ..Default::default() }).unwrap();
+        assert_eq!(filter.keyword, None);
+        assert!(service.normalize_user_filter(UserFilter { keyword: Some("a".repeat(65)), ..Default::default() }).is_err());
+
+        let now = Utc::now();
+        let range = |from, to| UserFilter { created_from: Some(from), created_to: Some(to), ..Default::default() };
+        assert!(service.normalize_user_filter(range(now - Duration::days(1), now)).is_ok());
+        assert!(service.normalize_user_filter(range(now, now)).is_ok());
+        assert!(service.normalize_user_filter(range(now, now - Duration::days(1))).is_err());
+    }
+
+    #[test]
+    fn test_level_and_restore() {
+        let service = UserDomainService::new();
+
+        assert!(service.validate_level(1, 1, 9).is_ok());
+        assert!(service.validate_level(9, 1, 9).is_ok());
+        assert!(service.validate_level(0, 1, 9).is_err());
+        assert!(service.validate_level(10, 1, 9).is_err());
+
+        assert!(service.restore_user(user(false, false)).is_err());
+        let restored = service.restore_user(user(false, true)).unwrap();
+        assert!(restored.deleted_at.is_none());
+    }
+
+    #[test]
+    fn test_audit_entry() {
+        let entry = AdminAuditModel::new(1, AdminAction::ForcePasswordReset, 7, Some(r#"{"reset_link_sent":true}"#.to_string()));
+
+        assert_eq!(entry.action, ActiveValue::set("force_password_reset".to_string()));
+        assert_eq!(entry.actor_id, ActiveValue::set(1));
+        assert_eq!(entry.target_user_id, ActiveValue::set(7));
+        assert_eq!(AdminAction::DisableUser.as_str(), "disable_user");
+        assert_eq!(AdminAction::RestoreUser.as_str(), "restore_user");
+    }
+
+    #[test]
+    fn test_views_hide_secrets() {
+        let view = serde_json::to_value(AdminUserView::from(user(false, false))).unwrap();
+        assert!(view.get("password").is_none());
+        // 注册时未填写的手机号保存为空字符串，展示为空
+        assert!(view["mobile"].is_null());
+        assert_eq!(view["email"], "user@example.com");
+
+        let wallet = WalletModel {
+            pub_key: Some("pub".to_string()),
+            privy_key: Some("secret".to_string()),
+            ..wallet(1.5, false, false)
+        };
+        let view = serde_json::to_value(AdminWalletView::from(wallet)).unwrap();
+        assert!(view.get("privy_key").is_none());
+        assert_eq!(view["balance"], 1.5);
+    }
 }